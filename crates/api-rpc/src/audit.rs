@@ -0,0 +1,223 @@
+//! RPC Audit Log
+//!
+//! JSON-RPC middleware recording every mutating call (`AUDITED_METHODS`):
+//! method, caller, a params summary, outcome and latency. Entries always go
+//! to tracing (target `semantica::audit`) and, when a store is configured,
+//! to the audit log table queried by admin.audit.list.v1 ("who cancelled
+//! this job?").
+
+use jsonrpsee::server::middleware::rpc::RpcServiceT;
+use jsonrpsee::server::MethodResponse;
+use jsonrpsee::types::Request;
+use semantica_core::port::{AuditEntry, AuditLog, TimeProvider};
+use serde_json::{Map, Value};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{info, warn};
+
+/// Methods that change jobs or daemon settings
+pub const AUDITED_METHODS: &[&str] = &[
+    "dev.enqueue.v1",
+    "dev.cancel.v1",
+    "dev.cancel_by_subject.v1",
+    "job.retry.v1",
+    "job.delete.v1",
+    "dlq.requeue.v1",
+    "dlq.purge.v1",
+    "admin.config.set.v1",
+    "admin.maintenance.v1",
+    "admin.restore.v1",
+    "admin.job_type.disable.v1",
+    "admin.job_type.enable.v1",
+    "admin.retry_policy.set.v1",
+    "admin.retry_policy.reset.v1",
+    "admin.queue.pause.v1",
+    "admin.queue.resume.v1",
+    "admin.lockdown.v1",
+    "admin.drain.v1",
+];
+
+/// Caller recorded when the params name none
+const UNKNOWN_CALLER: &str = "unknown";
+
+/// Longest string param kept in the summary (chars)
+const MAX_VALUE_CHARS: usize = 80;
+
+/// Longest params summary (chars)
+const MAX_SUMMARY_CHARS: usize = 1000;
+
+/// Layer installing the audit middleware (see `RpcServer::with_audit_log`)
+#[derive(Clone)]
+pub struct AuditLayer {
+    store: Option<Arc<dyn AuditLog>>,
+    time_provider: Arc<dyn TimeProvider>,
+}
+
+impl AuditLayer {
+    /// Trace audited calls only
+    pub fn new(time_provider: Arc<dyn TimeProvider>) -> Self {
+        Self {
+            store: None,
+            time_provider,
+        }
+    }
+
+    /// Also record them in `store`
+    pub fn with_store(mut self, store: Arc<dyn AuditLog>) -> Self {
+        self.store = Some(store);
+        self
+    }
+}
+
+impl<S> tower::Layer<S> for AuditLayer {
+    type Service = Audit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Audit {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Audit<S> {
+    inner: S,
+    layer: AuditLayer,
+}
+
+impl<'a, S> RpcServiceT<'a> for Audit<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + 'a,
+{
+    type Future = Pin<Box<dyn Future<Output = MethodResponse> + Send + 'a>>;
+
+    fn call(&self, request: Request<'a>) -> Self::Future {
+        if !AUDITED_METHODS.contains(&request.method_name()) {
+            return Box::pin(self.inner.call(request));
+        }
+
+        let params: Value = request
+            .params()
+            .as_str()
+            .and_then(|raw| serde_json::from_str(raw).ok())
+            .unwrap_or(Value::Null);
+        let mut entry = AuditEntry {
+            timestamp: self.layer.time_provider.now_millis(),
+            method: request.method_name().to_string(),
+            caller: caller(&params),
+            job_id: params["job_id"].as_str().map(str::to_string),
+            params: summarize_params(&params),
+            error_code: None,
+            latency_ms: 0,
+        };
+        let store = self.layer.store.clone();
+        let started = Instant::now();
+        let response = self.inner.call(request);
+
+        Box::pin(async move {
+            let response = response.await;
+            entry.error_code = response.as_error_code();
+            entry.latency_ms = started.elapsed().as_millis() as i64;
+
+            info!(
+                target: "semantica::audit",
+                method = %entry.method,
+                caller = %entry.caller,
+                job_id = entry.job_id.as_deref().unwrap_or("-"),
+                params = %entry.params,
+                outcome = entry.error_code.map_or("ok".to_string(), |code| format!("error {}", code)),
+                latency_ms = entry.latency_ms,
+                "RPC audit"
+            );
+
+            // Off the response path: a slow or failing store never delays the caller
+            if let Some(store) = store {
+                tokio::spawn(async move {
+                    if let Err(e) = store.record(&entry).await {
+                        warn!(method = %entry.method, error = %e, "Failed to record audit entry");
+                    }
+                });
+            }
+
+            response
+        })
+    }
+}
+
+/// Who made the call: the `actor` param, else the `worker_id` param
+fn caller(params: &Value) -> String {
+    ["actor", "worker_id"]
+        .iter()
+        .find_map(|key| params[key].as_str())
+        .unwrap_or(UNKNOWN_CALLER)
+        .to_string()
+}
+
+/// Params as JSON with nested values (payloads, env) elided and long strings cut
+pub fn summarize_params(params: &Value) -> String {
+    let summary = match params {
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(key, value)| (key.clone(), summarize_value(value)))
+                .collect::<Map<_, _>>(),
+        ),
+        Value::Null => Value::Object(Map::new()),
+        other => summarize_value(other),
+    };
+    truncate(&summary.to_string(), MAX_SUMMARY_CHARS)
+}
+
+fn summarize_value(value: &Value) -> Value {
+    match value {
+        Value::String(s) => Value::String(truncate(s, MAX_VALUE_CHARS)),
+        Value::Array(items) => Value::String(format!("[{} items]", items.len())),
+        Value::Object(fields) => Value::String(format!("{{{} fields}}", fields.len())),
+        scalar => scalar.clone(),
+    }
+}
+
+fn truncate(s: &str, max_chars: usize) -> String {
+    match s.char_indices().nth(max_chars) {
+        Some((end, _)) => format!("{}...", &s[..end]),
+        None => s.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_params_summary_elides_payloads() {
+        let params = json!({
+            "job_type": "BUILD",
+            "priority": 3,
+            "payload": { "command": "cargo", "args": ["build"] },
+            "fallback_payloads": [{}, {}],
+            "subject_key": "x".repeat(200),
+        });
+
+        let summary: Value = serde_json::from_str(&summarize_params(&params)).unwrap();
+        assert_eq!(summary["job_type"], "BUILD");
+        assert_eq!(summary["priority"], 3);
+        assert_eq!(summary["payload"], "{2 fields}");
+        assert_eq!(summary["fallback_payloads"], "[2 items]");
+        assert_eq!(
+            summary["subject_key"].as_str().unwrap().len(),
+            MAX_VALUE_CHARS + 3
+        );
+        assert_eq!(summarize_params(&Value::Null), "{}");
+    }
+
+    #[test]
+    fn test_caller_from_actor_or_worker() {
+        assert_eq!(caller(&json!({ "job_id": "j1", "actor": "cli" })), "cli");
+        assert_eq!(caller(&json!({ "worker_id": "w-1" })), "w-1");
+        assert_eq!(caller(&json!({ "job_id": "j1" })), UNKNOWN_CALLER);
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! Maps application errors to JSON-RPC error codes (ADR-020).
 
+use crate::types::ThrottledData;
 use jsonrpsee::types::ErrorObjectOwned;
 use semantica_core::error::AppError;
 
@@ -40,3 +41,8 @@ pub fn to_rpc_error(err: AppError) -> ErrorObjectOwned {
         AppError::InvalidState(msg) => ErrorObjectOwned::owned(code::CONFLICT, msg, None::<()>),
     }
 }
+
+/// THROTTLED error carrying the retry hint (`data`) of the exhausted bucket
+pub fn throttled_error(message: impl Into<String>, data: ThrottledData) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(code::THROTTLED, message.into(), Some(data))
+}
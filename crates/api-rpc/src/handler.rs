@@ -2,22 +2,89 @@
 //!
 //! Implements the business logic for each JSON-RPC method.
 
-use crate::error::to_rpc_error;
-use crate::rate_limiter::RateLimiter;
-
-// Rate limiting defaults (configurable via env vars)
-const DEFAULT_RATE_LIMIT_BURST: u32 = 200;
-const DEFAULT_RATE_LIMIT_RATE: u32 = 100;
+use crate::error::{throttled_error, to_rpc_error};
+use crate::log_follow::LogFollower;
+use crate::rate_limiter::{rate_limits_from_env, QueueRateLimits, RateLimiter};
 use crate::types::{
-    CancelRequest, CancelResponse, EnqueueRequest, EnqueueResponse, MaintenanceRequest,
-    MaintenanceResponse, StatsRequest, StatsResponse, TailLogsRequest, TailLogsResponse,
+    ArchiveGetRequest, ArchiveGetResponse, ArtifactInfo, ArtifactReadRequest, ArtifactReadResponse,
+    ArtifactsListRequest, ArtifactsListResponse, AuditEntryInfo, AuditListRequest,
+    AuditListResponse, CancelBySubjectRequest, CancelBySubjectResponse, CancelRequest,
+    CancelResponse, ConfigGetRequest, ConfigSetRequest, ConfigSetResponse, DatabaseHealth,
+    DeleteJobRequest, DeleteJobResponse, DlqListRequest, DlqListResponse, DlqPurgeRequest,
+    DlqPurgeResponse, DlqRequeueError, DlqRequeueRequest, DlqRequeueResponse, DrainRequest,
+    DrainResponse, EngineCapabilities, EngineFeatures, EngineLimits, EnqueueRequest,
+    EnqueueResponse, FollowLogsRequest, HealthCheckRequest, HealthCheckResponse, InspectRequest,
+    InspectResponse, JobEventInfo, JobSummary, JobTypeStatsInfo, JobTypeSwitchRequest,
+    JobTypeSwitchResponse, LeasedJob, ListJobsRequest, ListJobsResponse, LockdownRequest,
+    LockdownResponse, MaintenanceHealth, MaintenanceHistoryRequest, MaintenanceHistoryResponse,
+    MaintenanceRequest, MaintenanceResponse, MetricsHistoryRequest, MetricsHistoryResponse,
+    QueueInfo, QueuePauseRequest, QueuePauseResponse, QueuesListRequest, QueuesListResponse,
+    ReportProgressRequest, ReportProgressResponse, RestoreRequest, RestoreResponse,
+    RetryPoliciesRequest, RetryPoliciesResponse, RetryPolicyResetRequest, RetryPolicySetRequest,
+    RetryRequest, RetryResponse, ServiceInfo, SnapshotRequest, StatsRequest, StatsResponse,
+    SubjectHistoryRequest, SubjectHistoryResponse, TailLogsRequest, TailLogsResponse, WaitRequest,
+    WaitResponse, WaitSloInfo, WorkerCompleteRequest, WorkerCompleteResponse, WorkerHealth,
+    WorkerHeartbeatRequest, WorkerHeartbeatResponse, WorkerLeaseRequest, WorkerLeaseResponse,
+    WorkerSlot,
 };
+use base64::prelude::{Engine, BASE64_STANDARD};
 use jsonrpsee::types::ErrorObjectOwned;
-use semantica_core::application::dev_task::enqueue;
-use semantica_core::domain::JobState;
+use semantica_core::application::dev_task::artifacts::{self, DEFAULT_ARTIFACT_READ_BYTES};
+use semantica_core::application::dev_task::dead_letter::{self, RequeueTarget, DEFAULT_DLQ_LIMIT};
+use semantica_core::application::dev_task::enqueue::{
+    MAX_PAYLOAD_DEPTH, MAX_PAYLOAD_SIZE_BYTES, MAX_SUBJECT_KEY_LEN,
+};
+use semantica_core::application::dev_task::subject_history::{self, DEFAULT_SUBJECT_HISTORY_LIMIT};
+use semantica_core::application::dev_task::{delete, enqueue, progress, rerun, EnqueueCoalescer};
+use semantica_core::application::lease::MAX_LEASE_MS;
+use semantica_core::application::metrics_history::{
+    DEFAULT_METRICS_HISTORY_LIMIT, MAX_METRICS_HISTORY_LIMIT,
+};
+use semantica_core::application::retry::RetryPolicy;
+use semantica_core::application::worker::constants::{
+    DEFAULT_RETRY_BASE_DELAY_MS, WORKER_STALE_TICK_MS,
+};
+use semantica_core::application::{
+    DispatchSignal, Drain, InterceptorChain, JobStateChange, LeaseOutcome, LeaseService, Lockdown,
+    QueuePauseRegistry, QueueValidators, RetryPolicies, RetryRule, RunningJobs, RuntimeConfig,
+    RuntimeSettings, SnapshotService, StateFeed, Termination, WorkerActivity,
+};
+use semantica_core::domain::job_event::actor;
+use semantica_core::domain::{JobEvent, JobId, JobState, LogLine, QueueSnapshot, QueueWaitSlos};
 use semantica_core::port::job_repository::JobRepository;
-use semantica_core::port::{IdProvider, Maintenance, TimeProvider, TransactionalJobRepository};
+use semantica_core::port::{
+    elapsed_ms, ArtifactStorage, AuditFilter, AuditLog, IdProvider, JobArchive, JobFilter,
+    Maintenance, PageRequest, TimeProvider, TransactionalJobRepository,
+};
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+
+// Enqueue coalescing window per subject (SEMANTICA_COALESCE_WINDOW_MS, 0 = disabled)
+const DEFAULT_COALESCE_WINDOW_MS: u64 = 500;
+
+// Cancel / retry metadata limits (ADR-040)
+const MAX_REASON_LEN: usize = 1024;
+const MAX_ACTOR_LEN: usize = 128;
+
+// job.wait.v1 / admin.drain.v1 timeouts (default stays below the SDK's 30s request timeout)
+const DEFAULT_WAIT_MS: u64 = 25_000;
+const MAX_WAIT_MS: u64 = 5 * 60 * 1000;
+
+// job.wait.v1 re-reads the job this often, for transitions not on the state
+// feed (e.g. superseded by a newer enqueue)
+const WAIT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+// admin.audit.list.v1 page size cap
+const MAX_AUDIT_ENTRIES: usize = 1000;
+
+// admin.queues.list.v1 throughput window (1h)
+const THROUGHPUT_WINDOW_MS: i64 = 60 * 60 * 1000;
+
+// admin.stats.v1 queue wait SLO window (24h) and offending job IDs shown per queue
+const WAIT_SLO_WINDOW_MS: i64 = 24 * 60 * 60 * 1000;
+const MAX_SLO_JOB_IDS: usize = 20;
 
 /// RPC Handler with injected dependencies
 pub struct RpcHandler {
@@ -27,6 +94,27 @@ pub struct RpcHandler {
     time_provider: Arc<dyn TimeProvider>,
     maintenance: Arc<dyn Maintenance>,
     rate_limiter: Arc<RateLimiter>,
+    queue_rate_limits: Arc<QueueRateLimits>,
+    wait_slos: QueueWaitSlos,
+    interceptors: Arc<InterceptorChain>,
+    validators: Arc<QueueValidators>,
+    coalescer: Arc<EnqueueCoalescer>,
+    lease_service: Arc<LeaseService>,
+    retry_policies: Arc<RetryPolicies>,
+    state_feed: Arc<StateFeed>,
+    dispatch: Arc<DispatchSignal>,
+    pauses: Arc<QueuePauseRegistry>,
+    lockdown: Arc<Lockdown>,
+    drain: Arc<Drain>,
+    worker_activity: Option<Arc<WorkerActivity>>,
+    running_jobs: Option<Arc<RunningJobs>>,
+    audit_log: Option<Arc<dyn AuditLog>>,
+    job_archive: Option<Arc<dyn JobArchive>>,
+    artifact_storage: Option<Arc<dyn ArtifactStorage>>,
+    log_dir: Option<PathBuf>,
+    runtime_config: Arc<RuntimeConfig>,
+    limits: EngineLimits,
+    uds: bool,
     start_time: std::time::Instant,
 }
 
@@ -39,15 +127,26 @@ impl RpcHandler {
         maintenance: Arc<dyn Maintenance>,
     ) -> Self {
         // Read rate limiting configuration from environment
-        let max_burst: u32 = std::env::var("SEMANTICA_RATE_LIMIT_BURST")
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(DEFAULT_RATE_LIMIT_BURST);
+        let (max_burst, rate_per_sec) = rate_limits_from_env();
 
-        let rate_per_sec: u32 = std::env::var("SEMANTICA_RATE_LIMIT_RATE")
+        let coalesce_window_ms: u64 = std::env::var("SEMANTICA_COALESCE_WINDOW_MS")
             .ok()
             .and_then(|s| s.parse().ok())
-            .unwrap_or(DEFAULT_RATE_LIMIT_RATE);
+            .unwrap_or(DEFAULT_COALESCE_WINDOW_MS);
+
+        let retry_policies = Arc::new(RetryPolicies::new(Arc::new(RetryPolicy::new(
+            time_provider.clone(),
+            DEFAULT_RETRY_BASE_DELAY_MS,
+        ))));
+        let lease_service = Arc::new(
+            LeaseService::new(
+                job_repo.clone(),
+                retry_policies.default_policy(),
+                time_provider.clone(),
+            )
+            .with_retry_policies(retry_policies.clone()),
+        );
+        let state_feed = Arc::new(StateFeed::new(time_provider.clone()));
 
         Self {
             tx_job_repo,
@@ -56,23 +155,205 @@ impl RpcHandler {
             time_provider,
             maintenance,
             rate_limiter: Arc::new(RateLimiter::new(max_burst, rate_per_sec)),
+            queue_rate_limits: Arc::new(QueueRateLimits::default()),
+            wait_slos: QueueWaitSlos::default(),
+            interceptors: Arc::new(InterceptorChain::new()),
+            validators: Arc::new(QueueValidators::new()),
+            coalescer: Arc::new(EnqueueCoalescer::new(std::time::Duration::from_millis(
+                coalesce_window_ms,
+            ))),
+            lease_service,
+            retry_policies,
+            state_feed,
+            dispatch: Arc::new(DispatchSignal::new()),
+            pauses: Arc::new(QueuePauseRegistry::new()),
+            lockdown: Arc::new(Lockdown::new()),
+            drain: Arc::new(Drain::new()),
+            worker_activity: None,
+            running_jobs: None,
+            audit_log: None,
+            job_archive: None,
+            artifact_storage: None,
+            log_dir: None,
+            uds: false,
+            runtime_config: Arc::new(RuntimeConfig::new(RuntimeSettings {
+                rate_limit_burst: max_burst,
+                rate_limit_per_sec: rate_per_sec,
+                ..Default::default()
+            })),
+            limits: EngineLimits {
+                max_payload_bytes: MAX_PAYLOAD_SIZE_BYTES,
+                max_payload_depth: MAX_PAYLOAD_DEPTH,
+                rate_limit_burst: max_burst,
+                rate_limit_per_sec: rate_per_sec,
+                coalesce_window_ms,
+                max_lease_ms: MAX_LEASE_MS,
+                queue_rate_limits: Default::default(),
+            },
             start_time: std::time::Instant::now(),
         }
     }
 
+    /// Attach an interceptor chain run around every enqueue
+    pub fn with_interceptors(mut self, interceptors: Arc<InterceptorChain>) -> Self {
+        self.interceptors = interceptors;
+        self
+    }
+
+    /// Attach per-queue payload rules checked on every enqueue
+    pub fn with_queue_validators(mut self, validators: Arc<QueueValidators>) -> Self {
+        self.validators = validators;
+        self
+    }
+
+    /// Share the per-job-type retry rules with the worker (admin.retry_policy.*)
+    pub fn with_retry_policies(mut self, retry_policies: Arc<RetryPolicies>) -> Self {
+        self.retry_policies = retry_policies;
+        self
+    }
+
+    /// Share a lease service with the daemon's lease reaper (worker.* methods)
+    pub fn with_lease_service(mut self, lease_service: Arc<LeaseService>) -> Self {
+        self.lease_service = lease_service;
+        self
+    }
+
+    /// Share the pause registry the worker and lease service consult
+    pub fn with_pause_registry(mut self, pauses: Arc<QueuePauseRegistry>) -> Self {
+        self.pauses = pauses;
+        self
+    }
+
+    /// Refuse enqueue / retry while this lockdown is engaged (shared with the worker)
+    pub fn with_lockdown(mut self, lockdown: Arc<Lockdown>) -> Self {
+        self.lockdown = lockdown;
+        self
+    }
+
+    /// Drain the worker and lease service sharing this switch (admin.drain.v1)
+    pub fn with_drain(mut self, drain: Arc<Drain>) -> Self {
+        self.drain = drain;
+        self
+    }
+
+    /// Share the runtime settings read by the worker and maintenance scheduler
+    ///
+    /// Its rate limits replace the ones read from the environment.
+    pub fn with_runtime_config(mut self, runtime_config: Arc<RuntimeConfig>) -> Self {
+        let settings = runtime_config.get();
+        self.rate_limiter
+            .set_limits(settings.rate_limit_burst, settings.rate_limit_per_sec);
+        self.runtime_config = runtime_config;
+        self
+    }
+
+    /// Apply every later change of the runtime rate limits (config set, reload)
+    ///
+    /// Ends when the runtime settings are dropped.
+    pub fn follow_rate_limits(&self) -> impl std::future::Future<Output = ()> + Send + 'static {
+        let mut changes = self.runtime_config.subscribe();
+        let rate_limiter = Arc::clone(&self.rate_limiter);
+        async move {
+            while changes.changed().await.is_ok() {
+                let settings = changes.borrow_and_update().clone();
+                rate_limiter.set_limits(settings.rate_limit_burst, settings.rate_limit_per_sec);
+            }
+        }
+    }
+
+    /// Advertise the Unix socket transport in the capabilities
+    pub fn with_uds(mut self, uds: bool) -> Self {
+        self.uds = uds;
+        self
+    }
+
+    /// Report the worker's execution slots in admin.stats.v1
+    pub fn with_worker_activity(mut self, activity: Arc<WorkerActivity>) -> Self {
+        self.worker_activity = Some(activity);
+        self
+    }
+
+    /// Stop the worker's execution of a RUNNING job when it is cancelled
+    pub fn with_running_jobs(mut self, running_jobs: Arc<RunningJobs>) -> Self {
+        self.running_jobs = Some(running_jobs);
+        self
+    }
+
+    /// Limit enqueues per queue (e.g. a file watcher flooding one queue)
+    pub fn with_queue_rate_limits(mut self, queue_rate_limits: QueueRateLimits) -> Self {
+        self.limits.queue_rate_limits = queue_rate_limits.limits();
+        self.queue_rate_limits = Arc::new(queue_rate_limits);
+        self
+    }
+
+    /// Report wait SLO compliance of these queues in admin.stats.v1
+    pub fn with_wait_slos(mut self, wait_slos: QueueWaitSlos) -> Self {
+        self.wait_slos = wait_slos;
+        self
+    }
+
+    /// Serve admin.audit.list.v1 from this store
+    pub fn with_audit_log(mut self, audit_log: Arc<dyn AuditLog>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    /// Serve admin.archive.get.v1 from the archive GC writes to
+    pub fn with_job_archive(mut self, job_archive: Arc<dyn JobArchive>) -> Self {
+        self.job_archive = Some(job_archive);
+        self
+    }
+
+    /// Delete the artifacts of jobs purged with job.delete.v1 from this storage
+    pub fn with_artifact_storage(mut self, storage: Arc<dyn ArtifactStorage>) -> Self {
+        self.artifact_storage = Some(storage);
+        self
+    }
+
+    /// Follow running jobs' logs in the directory the executor writes them to
+    pub fn with_log_dir(mut self, log_dir: impl Into<PathBuf>) -> Self {
+        self.log_dir = Some(log_dir.into());
+        self
+    }
+
+    /// Share the state feed the worker publishes to (jobs.subscribe.v1)
+    pub fn with_state_feed(mut self, state_feed: Arc<StateFeed>) -> Self {
+        self.state_feed = state_feed;
+        self
+    }
+
+    /// Wake the worker waiting on this signal whenever jobs become poppable
+    pub fn with_dispatch_signal(mut self, dispatch: Arc<DispatchSignal>) -> Self {
+        self.dispatch = dispatch;
+        self
+    }
+
+    /// jobs.subscribe.v1 - Receiver of job state transitions
+    pub fn subscribe_state_changes(&self) -> tokio::sync::broadcast::Receiver<JobStateChange> {
+        self.state_feed.subscribe()
+    }
+
     /// dev.enqueue.v1
     pub async fn enqueue(
         &self,
         params: EnqueueRequest,
     ) -> Result<EnqueueResponse, ErrorObjectOwned> {
         // Rate limiting check (DoS protection)
-        if !self.rate_limiter.check().await {
-            return Err(jsonrpsee::types::error::ErrorObject::owned(
-                4003, // THROTTLED
-                "Rate limit exceeded. Please slow down.",
-                None::<()>,
-            ));
+        self.rate_limiter.try_acquire().await.map_err(|throttled| {
+            throttled_error("Rate limit exceeded. Please slow down.", throttled)
+        })?;
+        if let Some(refusal) = self.lockdown.refusal("enqueue") {
+            return Err(to_rpc_error(refusal));
         }
+        self.queue_rate_limits
+            .try_acquire(&params.queue)
+            .await
+            .map_err(|throttled| {
+                throttled_error(
+                    format!("Enqueue rate limit of queue '{}' exceeded", params.queue),
+                    throttled,
+                )
+            })?;
 
         let req = enqueue::EnqueueRequest {
             job_type: params.job_type,
@@ -80,16 +361,37 @@ impl RpcHandler {
             subject_key: params.subject_key,
             payload: params.payload,
             priority: params.priority,
+            idempotency_key: params.idempotency_key,
+            traceparent: params.traceparent,
+            job_class: params.job_class,
+            fallback_payloads: params.fallback_payloads,
+            options: enqueue::EnqueueOptions {
+                schedule_at: params.schedule_at,
+                deadline: params.deadline,
+                ttl_ms: params.ttl_ms,
+                max_attempts: params.max_attempts,
+                backoff_factor: params.backoff_factor,
+                wait_for_idle: params.wait_for_idle,
+                require_charging: params.require_charging,
+                user_tag: params.user_tag,
+                execution_mode: params.execution_mode,
+                parent_job_id: params.parent_job_id,
+                chain_group_id: params.chain_group_id,
+            },
         };
 
-        let job_id = enqueue::execute(
+        let job_id = enqueue::execute_coalescing(
             self.tx_job_repo.as_ref(),
             self.id_provider.as_ref(),
             self.time_provider.as_ref(),
+            &self.interceptors,
+            &self.validators,
+            &self.coalescer,
             req,
         )
         .await
         .map_err(to_rpc_error)?;
+        self.dispatch.notify();
 
         Ok(EnqueueResponse {
             job_id,
@@ -101,16 +403,26 @@ impl RpcHandler {
     /// dev.cancel.v1
     pub async fn cancel(&self, params: CancelRequest) -> Result<CancelResponse, ErrorObjectOwned> {
         // Rate limiting check (DoS protection)
-        if !self.rate_limiter.check().await {
-            return Err(jsonrpsee::types::error::ErrorObject::owned(
-                4003, // THROTTLED
-                "Rate limit exceeded. Please slow down.",
-                None::<()>,
-            ));
+        self.rate_limiter.try_acquire().await.map_err(|throttled| {
+            throttled_error("Rate limit exceeded. Please slow down.", throttled)
+        })?;
+
+        let reason = params.reason.filter(|r| !r.trim().is_empty());
+        let actor = params.actor.unwrap_or_else(|| actor::RPC.to_string());
+        if reason.as_ref().is_some_and(|r| r.len() > MAX_REASON_LEN)
+            || actor.is_empty()
+            || actor.len() > MAX_ACTOR_LEN
+        {
+            return Err(to_rpc_error(semantica_core::error::AppError::Validation(
+                format!(
+                    "Cancel reason must be at most {} chars and actor 1-{} chars",
+                    MAX_REASON_LEN, MAX_ACTOR_LEN
+                ),
+            )));
         }
 
         // Check if job exists
-        let _job = self
+        let job = self
             .job_repo
             .find_by_id(&params.job_id)
             .await
@@ -129,9 +441,501 @@ impl RpcHandler {
             .await
             .map_err(to_rpc_error)?;
 
+        self.job_repo
+            .record_event(&JobEvent::new(
+                params.job_id.clone(),
+                JobState::Cancelled,
+                actor,
+                reason,
+                now,
+            ))
+            .await
+            .map_err(to_rpc_error)?;
+        self.state_feed
+            .publish(&job, Some(job.state.clone()), JobState::Cancelled);
+
+        // A RUNNING job keeps executing until its subprocess is killed. The
+        // worker may have popped it since it was read: ask the registry anyway
+        let termination = match &self.running_jobs {
+            Some(running_jobs) => running_jobs.cancel(&params.job_id).await,
+            None => Termination::NotRunning,
+        };
+
         Ok(CancelResponse {
             job_id: params.job_id,
             cancelled: true,
+            was_running: termination != Termination::NotRunning,
+            terminated: termination.is_terminated(),
+            pid: termination.pid(),
+        })
+    }
+
+    /// dev.cancel_by_subject.v1
+    pub async fn cancel_by_subject(
+        &self,
+        params: CancelBySubjectRequest,
+    ) -> Result<CancelBySubjectResponse, ErrorObjectOwned> {
+        self.rate_limiter.try_acquire().await.map_err(|throttled| {
+            throttled_error("Rate limit exceeded. Please slow down.", throttled)
+        })?;
+
+        // A pattern of wildcards only would cancel every queued job: refuse
+        let pattern = params.subject_pattern;
+        if pattern.len() > MAX_SUBJECT_KEY_LEN || !pattern.chars().any(|c| !matches!(c, '*' | '?'))
+        {
+            return Err(to_rpc_error(semantica_core::error::AppError::Validation(
+                format!(
+                    "subject_pattern must be at most {} chars and contain a literal part",
+                    MAX_SUBJECT_KEY_LEN
+                ),
+            )));
+        }
+
+        let reason = params.reason.filter(|r| !r.trim().is_empty());
+        let actor = params.actor.unwrap_or_else(|| actor::RPC.to_string());
+        if reason.as_ref().is_some_and(|r| r.len() > MAX_REASON_LEN)
+            || actor.is_empty()
+            || actor.len() > MAX_ACTOR_LEN
+        {
+            return Err(to_rpc_error(semantica_core::error::AppError::Validation(
+                format!(
+                    "Cancel reason must be at most {} chars and actor 1-{} chars",
+                    MAX_REASON_LEN, MAX_ACTOR_LEN
+                ),
+            )));
+        }
+
+        let job_ids = self
+            .job_repo
+            .cancel_by_subject(params.queue.as_deref(), &pattern, &actor, reason.as_deref())
+            .await
+            .map_err(to_rpc_error)?;
+
+        // Only look the queues up when someone is listening
+        if self.state_feed.subscriber_count() > 0 {
+            for job_id in &job_ids {
+                if let Ok(Some(job)) = self.job_repo.find_by_id(job_id).await {
+                    self.state_feed
+                        .publish(&job, Some(JobState::Queued), JobState::Cancelled);
+                }
+            }
+        }
+
+        Ok(CancelBySubjectResponse {
+            cancelled: job_ids.len(),
+            job_ids,
+        })
+    }
+
+    /// job.retry.v1
+    pub async fn retry(&self, params: RetryRequest) -> Result<RetryResponse, ErrorObjectOwned> {
+        self.rate_limiter.try_acquire().await.map_err(|throttled| {
+            throttled_error("Rate limit exceeded. Please slow down.", throttled)
+        })?;
+        if let Some(refusal) = self.lockdown.refusal("retry") {
+            return Err(to_rpc_error(refusal));
+        }
+
+        let reason = params.reason.filter(|r| !r.trim().is_empty());
+        let actor = params.actor.unwrap_or_else(|| actor::RPC.to_string());
+        if reason.as_ref().is_some_and(|r| r.len() > MAX_REASON_LEN)
+            || actor.is_empty()
+            || actor.len() > MAX_ACTOR_LEN
+        {
+            return Err(to_rpc_error(semantica_core::error::AppError::Validation(
+                format!(
+                    "Retry reason must be at most {} chars and actor 1-{} chars",
+                    MAX_REASON_LEN, MAX_ACTOR_LEN
+                ),
+            )));
+        }
+
+        if let Some(payload) = params.payload {
+            return self
+                .retry_with_payload(&params.job_id, payload, &actor, reason)
+                .await;
+        }
+
+        let job = rerun::execute(
+            self.job_repo.as_ref(),
+            self.time_provider.as_ref(),
+            &params.job_id,
+            &actor,
+            reason,
+        )
+        .await
+        .map_err(to_rpc_error)?;
+        self.dispatch.notify();
+        self.state_feed
+            .publish(&job, Some(JobState::Failed), job.state.clone());
+
+        Ok(RetryResponse {
+            job_id: job.id,
+            state: job.state.to_string(),
+            parent_job_id: None,
+        })
+    }
+
+    /// Enqueue a corrected copy of a finished job (job.retry.v1 with `payload`)
+    async fn retry_with_payload(
+        &self,
+        job_id: &JobId,
+        payload: serde_json::Value,
+        actor: &str,
+        reason: Option<String>,
+    ) -> Result<RetryResponse, ErrorObjectOwned> {
+        let original = self
+            .job_repo
+            .find_by_id(job_id)
+            .await
+            .map_err(to_rpc_error)?
+            .ok_or_else(|| {
+                to_rpc_error(semantica_core::error::AppError::NotFound(format!(
+                    "Job {} not found",
+                    job_id
+                )))
+            })?;
+        let req = rerun::corrected_request(&original, payload).map_err(to_rpc_error)?;
+
+        let new_id = enqueue::execute(
+            self.tx_job_repo.as_ref(),
+            self.id_provider.as_ref(),
+            self.time_provider.as_ref(),
+            &self.interceptors,
+            &self.validators,
+            req,
+        )
+        .await
+        .map_err(to_rpc_error)?;
+        self.job_repo
+            .record_event(&JobEvent::new(
+                new_id.clone(),
+                JobState::Queued,
+                actor,
+                Some(reason.unwrap_or_else(|| format!("retry of {} with edited payload", job_id))),
+                self.time_provider.now_millis(),
+            ))
+            .await
+            .map_err(to_rpc_error)?;
+        self.dispatch.notify();
+
+        Ok(RetryResponse {
+            job_id: new_id,
+            state: JobState::Queued.to_string(),
+            parent_job_id: Some(original.id),
+        })
+    }
+
+    /// job.delete.v1
+    pub async fn delete_job(
+        &self,
+        params: DeleteJobRequest,
+    ) -> Result<DeleteJobResponse, ErrorObjectOwned> {
+        self.rate_limiter.try_acquire().await.map_err(|throttled| {
+            throttled_error("Rate limit exceeded. Please slow down.", throttled)
+        })?;
+
+        let actor = params.actor.unwrap_or_else(|| actor::RPC.to_string());
+        if actor.is_empty() || actor.len() > MAX_ACTOR_LEN {
+            return Err(to_rpc_error(semantica_core::error::AppError::Validation(
+                format!("Delete actor must be 1-{} chars", MAX_ACTOR_LEN),
+            )));
+        }
+
+        let deleted = delete::execute(
+            self.job_repo.as_ref(),
+            self.artifact_storage.as_deref(),
+            &params.job_id,
+            params.force,
+        )
+        .await
+        .map_err(to_rpc_error)?;
+        tracing::info!(job_id = %params.job_id, actor = %actor, force = params.force, "Job purged");
+
+        let mut leftovers = deleted.artifacts_left;
+        let log_deleted = match &deleted.job.log_path {
+            Some(log_path) => match tokio::fs::remove_file(log_path).await {
+                Ok(()) => true,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => false,
+                Err(e) => {
+                    tracing::warn!(path = %log_path, error = %e, "Failed to delete log of purged job");
+                    leftovers.push(log_path.clone());
+                    false
+                }
+            },
+            None => false,
+        };
+
+        Ok(DeleteJobResponse {
+            job_id: deleted.job.id,
+            state: deleted.job.state.to_string(),
+            log_deleted,
+            artifacts_deleted: deleted.artifacts_deleted,
+            leftovers,
+        })
+    }
+
+    /// job.report_progress.v1
+    pub async fn report_progress(
+        &self,
+        params: ReportProgressRequest,
+    ) -> Result<ReportProgressResponse, ErrorObjectOwned> {
+        let job = progress::execute(
+            self.job_repo.as_ref(),
+            self.time_provider.as_ref(),
+            &params.job_id,
+            params.percent,
+            params.message,
+        )
+        .await
+        .map_err(to_rpc_error)?;
+        self.state_feed.publish_progress(&job);
+
+        let updated_at = job.progress.map_or(0, |p| p.updated_at);
+        Ok(ReportProgressResponse {
+            job_id: job.id,
+            percent: params.percent,
+            updated_at,
+        })
+    }
+
+    /// dev.inspect.v1
+    pub async fn inspect(
+        &self,
+        params: InspectRequest,
+    ) -> Result<InspectResponse, ErrorObjectOwned> {
+        let job = self
+            .job_repo
+            .find_by_id(&params.job_id)
+            .await
+            .map_err(to_rpc_error)?
+            .ok_or_else(|| {
+                to_rpc_error(semantica_core::error::AppError::NotFound(format!(
+                    "Job {} not found",
+                    params.job_id
+                )))
+            })?;
+
+        let events = self
+            .job_repo
+            .list_events(&job.id)
+            .await
+            .map_err(to_rpc_error)?
+            .into_iter()
+            .map(|event| JobEventInfo {
+                state: event.state.to_string(),
+                actor: event.actor,
+                reason: event.reason,
+                created_at: event.created_at,
+            })
+            .collect();
+
+        Ok(InspectResponse {
+            job_id: job.id,
+            job_type: job.job_type.as_str().to_string(),
+            queue: job.queue,
+            subject_key: job.subject_key,
+            generation: job.generation,
+            state: job.state.to_string(),
+            priority: job.priority,
+            attempts: job.attempts,
+            panic_count: job.panic_count,
+            next_attempt_at: job
+                .next_attempt_at
+                .filter(|_| job.state == JobState::Queued),
+            created_at: job.created_at,
+            started_at: job.started_at,
+            finished_at: job.finished_at,
+            job_class: job.job_class.to_string(),
+            fallback_payloads: job.fallback_payloads.len(),
+            traceparent: job.trace_id,
+            payload: params
+                .include_payload
+                .then(|| job.payload.as_value().clone()),
+            progress: job.progress.map(Into::into),
+            events,
+        })
+    }
+
+    /// dev.list.v1
+    pub async fn list_jobs(
+        &self,
+        params: ListJobsRequest,
+    ) -> Result<ListJobsResponse, ErrorObjectOwned> {
+        let state = params
+            .state
+            .as_deref()
+            .map(JobState::parse)
+            .transpose()
+            .map_err(|e| to_rpc_error(e.into()))?;
+        let filter = JobFilter {
+            queue: params.queue,
+            state,
+            subject_key: params.subject_key,
+        };
+        let limit = params.limit.unwrap_or(0);
+        let page = match params.after {
+            Some(cursor) => PageRequest::after(cursor, limit),
+            None => PageRequest::first(limit),
+        };
+
+        let page = self
+            .job_repo
+            .list_jobs(&filter, &page)
+            .await
+            .map_err(to_rpc_error)?;
+
+        Ok(ListJobsResponse {
+            jobs: page
+                .items
+                .into_iter()
+                .map(|job| JobSummary {
+                    job_id: job.id,
+                    job_type: job.job_type.as_str().to_string(),
+                    queue: job.queue,
+                    subject_key: job.subject_key,
+                    state: job.state.to_string(),
+                    priority: job.priority,
+                    attempts: job.attempts,
+                    created_at: job.created_at,
+                    started_at: job.started_at,
+                    finished_at: job.finished_at,
+                    progress: job.progress.map(Into::into),
+                })
+                .collect(),
+            next_cursor: page.next_cursor,
+        })
+    }
+
+    /// job.wait.v1 - Long-poll until the job is terminal or the timeout elapses
+    pub async fn wait(&self, params: WaitRequest) -> Result<WaitResponse, ErrorObjectOwned> {
+        let timeout_ms = params.timeout_ms.unwrap_or(DEFAULT_WAIT_MS);
+        if timeout_ms > MAX_WAIT_MS {
+            return Err(to_rpc_error(semantica_core::error::AppError::Validation(
+                format!("timeout_ms must be at most {}", MAX_WAIT_MS),
+            )));
+        }
+
+        // Subscribe before the first read so a transition in between is not missed
+        let mut changes = self.state_feed.subscribe();
+        let deadline = tokio::time::Instant::now() + Duration::from_millis(timeout_ms);
+
+        loop {
+            let job = self
+                .job_repo
+                .find_by_id(&params.job_id)
+                .await
+                .map_err(to_rpc_error)?
+                .ok_or_else(|| {
+                    to_rpc_error(semantica_core::error::AppError::NotFound(format!(
+                        "Job {} not found",
+                        params.job_id
+                    )))
+                })?;
+
+            let now = tokio::time::Instant::now();
+            if job.state.is_terminal() || now >= deadline {
+                return Ok(WaitResponse {
+                    job_id: job.id,
+                    terminal: job.state.is_terminal(),
+                    state: job.state.to_string(),
+                    result_summary: job.result_summary,
+                    finished_at: job.finished_at,
+                });
+            }
+
+            // Sleep until this job changes state, the next poll or the deadline
+            let wake_at = deadline.min(now + WAIT_POLL_INTERVAL);
+            loop {
+                match tokio::time::timeout_at(wake_at, changes.recv()).await {
+                    Ok(Ok(change)) if change.job_id != job.id => continue,
+                    Ok(Ok(_)) | Ok(Err(RecvError::Lagged(_))) | Err(_) => break,
+                    Ok(Err(RecvError::Closed)) => {
+                        tokio::time::sleep_until(wake_at).await;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// worker.lease.v1
+    pub async fn worker_lease(
+        &self,
+        params: WorkerLeaseRequest,
+    ) -> Result<WorkerLeaseResponse, ErrorObjectOwned> {
+        let leased = self
+            .lease_service
+            .lease(&params.queue, &params.worker_id, params.lease_ms)
+            .await
+            .map_err(to_rpc_error)?;
+
+        Ok(WorkerLeaseResponse {
+            job: leased.map(|(job, lease)| LeasedJob {
+                job_id: job.id,
+                job_type: job.job_type.as_str().to_string(),
+                queue: job.queue,
+                subject_key: job.subject_key,
+                generation: job.generation,
+                payload: job.payload.as_value().clone(),
+                attempts: job.attempts,
+                lease_expires_at: lease.expires_at,
+            }),
+        })
+    }
+
+    /// worker.heartbeat.v1
+    pub async fn worker_heartbeat(
+        &self,
+        params: WorkerHeartbeatRequest,
+    ) -> Result<WorkerHeartbeatResponse, ErrorObjectOwned> {
+        let lease = self
+            .lease_service
+            .heartbeat(
+                &params.job_id,
+                &params.worker_id,
+                params.lease_ms,
+                params.progress.as_deref(),
+            )
+            .await
+            .map_err(to_rpc_error)?;
+
+        Ok(WorkerHeartbeatResponse {
+            job_id: lease.job_id,
+            lease_expires_at: lease.expires_at,
+        })
+    }
+
+    /// worker.complete.v1
+    pub async fn worker_complete(
+        &self,
+        params: WorkerCompleteRequest,
+    ) -> Result<WorkerCompleteResponse, ErrorObjectOwned> {
+        let outcome = if params.success {
+            LeaseOutcome::Succeeded {
+                result: params.result,
+            }
+        } else {
+            LeaseOutcome::Failed {
+                error: params
+                    .error
+                    .unwrap_or_else(|| "worker reported failure".to_string()),
+                retryable: params.retryable,
+            }
+        };
+
+        let state = self
+            .lease_service
+            .complete(&params.job_id, &params.worker_id, outcome)
+            .await
+            .map_err(to_rpc_error)?;
+        if state == JobState::Queued {
+            self.dispatch.notify();
+        }
+
+        Ok(WorkerCompleteResponse {
+            job_id: params.job_id,
+            state: state.to_string(),
         })
     }
 
@@ -153,26 +957,228 @@ impl RpcHandler {
             })?;
 
         // Read log file if exists
-        let lines = if let Some(log_path) = &job.log_path {
-            std::fs::read_to_string(log_path)
-                .ok()
-                .map(|content| {
-                    let all_lines: Vec<&str> = content.lines().collect();
-                    let start = all_lines.len().saturating_sub(params.lines);
-                    all_lines[start..].iter().map(|s| s.to_string()).collect()
-                })
-                .unwrap_or_default()
-        } else {
-            vec![]
-        };
+        let content = job
+            .log_path
+            .as_ref()
+            .and_then(|log_path| std::fs::read_to_string(log_path).ok())
+            .unwrap_or_default();
+
+        // Untagged lines (older logs) have no stream and only match without a filter
+        let matching: Vec<(String, Option<LogLine>)> = content
+            .lines()
+            .filter_map(|record| match LogLine::parse_record(record) {
+                Some(entry) => params
+                    .stream
+                    .is_none_or(|stream| entry.stream == stream)
+                    .then(|| (entry.line.clone(), Some(entry))),
+                None => params.stream.is_none().then(|| (record.to_string(), None)),
+            })
+            .collect();
+        let start = matching.len().saturating_sub(params.lines);
+        let (lines, entries): (Vec<String>, Vec<Option<LogLine>>) =
+            matching.into_iter().skip(start).unzip();
 
         Ok(TailLogsResponse {
             job_id: params.job_id,
             log_path: job.log_path,
             lines,
+            entries: entries.into_iter().flatten().collect(),
+        })
+    }
+
+    /// job.artifacts.list.v1
+    pub async fn list_artifacts(
+        &self,
+        params: ArtifactsListRequest,
+    ) -> Result<ArtifactsListResponse, ErrorObjectOwned> {
+        let artifacts = artifacts::list(self.job_repo.as_ref(), &params.job_id)
+            .await
+            .map_err(to_rpc_error)?;
+
+        Ok(ArtifactsListResponse {
+            job_id: params.job_id,
+            artifacts: artifacts.into_iter().map(ArtifactInfo::from).collect(),
+        })
+    }
+
+    /// job.artifacts.read.v1
+    pub async fn read_artifact(
+        &self,
+        params: ArtifactReadRequest,
+    ) -> Result<ArtifactReadResponse, ErrorObjectOwned> {
+        let chunk = artifacts::read(
+            self.job_repo.as_ref(),
+            self.artifact_storage.as_deref(),
+            &params.job_id,
+            &params.uri,
+            params.offset,
+            params.max_bytes.unwrap_or(DEFAULT_ARTIFACT_READ_BYTES),
+        )
+        .await
+        .map_err(to_rpc_error)?;
+
+        Ok(ArtifactReadResponse {
+            job_id: params.job_id,
+            artifact: chunk.artifact.into(),
+            offset: chunk.offset,
+            len: chunk.data.len(),
+            data: BASE64_STANDARD.encode(&chunk.data),
+            eof: chunk.eof,
+        })
+    }
+
+    /// subject.history.v1
+    pub async fn subject_history(
+        &self,
+        params: SubjectHistoryRequest,
+    ) -> Result<SubjectHistoryResponse, ErrorObjectOwned> {
+        let entries = subject_history::list(
+            self.job_repo.as_ref(),
+            &params.subject_key,
+            params.limit.unwrap_or(DEFAULT_SUBJECT_HISTORY_LIMIT),
+        )
+        .await
+        .map_err(to_rpc_error)?;
+
+        Ok(SubjectHistoryResponse {
+            subject_key: params.subject_key,
+            entries: entries.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    /// dlq.list.v1
+    pub async fn dlq_list(
+        &self,
+        params: DlqListRequest,
+    ) -> Result<DlqListResponse, ErrorObjectOwned> {
+        let dead_letters = dead_letter::list(
+            self.job_repo.as_ref(),
+            params.queue.as_deref(),
+            params.limit.unwrap_or(DEFAULT_DLQ_LIMIT),
+        )
+        .await
+        .map_err(to_rpc_error)?;
+
+        Ok(DlqListResponse {
+            dead_letters: dead_letters.into_iter().map(Into::into).collect(),
         })
     }
 
+    /// dlq.requeue.v1
+    pub async fn dlq_requeue(
+        &self,
+        params: DlqRequeueRequest,
+    ) -> Result<DlqRequeueResponse, ErrorObjectOwned> {
+        self.rate_limiter.try_acquire().await.map_err(|throttled| {
+            throttled_error("Rate limit exceeded. Please slow down.", throttled)
+        })?;
+        if let Some(refusal) = self.lockdown.refusal("dlq requeue") {
+            return Err(to_rpc_error(refusal));
+        }
+
+        let reason = params.reason.filter(|r| !r.trim().is_empty());
+        let actor = params.actor.unwrap_or_else(|| actor::RPC.to_string());
+        if reason.as_ref().is_some_and(|r| r.len() > MAX_REASON_LEN)
+            || actor.is_empty()
+            || actor.len() > MAX_ACTOR_LEN
+        {
+            return Err(to_rpc_error(semantica_core::error::AppError::Validation(
+                format!(
+                    "Requeue reason must be at most {} chars and actor 1-{} chars",
+                    MAX_REASON_LEN, MAX_ACTOR_LEN
+                ),
+            )));
+        }
+
+        let target = if params.all {
+            RequeueTarget::All {
+                queue: params.queue,
+            }
+        } else {
+            RequeueTarget::Jobs(params.job_ids)
+        };
+        let report = dead_letter::requeue(
+            self.job_repo.as_ref(),
+            self.time_provider.as_ref(),
+            target,
+            &actor,
+            reason,
+        )
+        .await
+        .map_err(to_rpc_error)?;
+        if !report.requeued.is_empty() {
+            self.dispatch.notify();
+        }
+        for job in &report.requeued {
+            self.state_feed
+                .publish(job, Some(JobState::Failed), job.state.clone());
+        }
+
+        Ok(DlqRequeueResponse {
+            requeued: report.requeued.into_iter().map(|job| job.id).collect(),
+            errors: report
+                .errors
+                .into_iter()
+                .map(|(job_id, e)| DlqRequeueError {
+                    job_id,
+                    error: e.to_string(),
+                })
+                .collect(),
+        })
+    }
+
+    /// dlq.purge.v1
+    pub async fn dlq_purge(
+        &self,
+        params: DlqPurgeRequest,
+    ) -> Result<DlqPurgeResponse, ErrorObjectOwned> {
+        let purged = dead_letter::purge(
+            self.job_repo.as_ref(),
+            params.queue.as_deref(),
+            params.before,
+        )
+        .await
+        .map_err(to_rpc_error)?;
+
+        Ok(DlqPurgeResponse { purged })
+    }
+
+    /// logs.follow.v1 - Follower of a job's log (lines are sent by the subscription)
+    pub async fn follow_logs(
+        &self,
+        params: FollowLogsRequest,
+    ) -> Result<LogFollower, ErrorObjectOwned> {
+        // Subscribe before the lookup so the job finishing in between is not missed
+        let changes = self.state_feed.subscribe();
+        let job = self
+            .job_repo
+            .find_by_id(&params.job_id)
+            .await
+            .map_err(to_rpc_error)?
+            .ok_or_else(|| {
+                to_rpc_error(semantica_core::error::AppError::NotFound(format!(
+                    "Job {} not found",
+                    params.job_id
+                )))
+            })?;
+
+        // The path is stored after an attempt: until then it is where the executor writes
+        let path = job.log_path.map(PathBuf::from).or_else(|| {
+            self.log_dir
+                .as_ref()
+                .map(|dir| dir.join(format!("{}.log", job.id)))
+        });
+
+        Ok(LogFollower::new(
+            self.job_repo.clone(),
+            changes,
+            job.id,
+            params.stream,
+            path,
+            params.lines,
+        ))
+    }
+
     /// admin.stats.v1
     pub async fn stats(&self, _params: StatsRequest) -> Result<StatsResponse, ErrorObjectOwned> {
         const DEFAULT_QUEUE: &str = "default";
@@ -202,19 +1208,258 @@ impl RpcHandler {
             .await
             .map_err(to_rpc_error)?;
 
+        // TTL/deadline expiry is counted apart from failures (failure-rate alerting)
+        let mut expired = 0;
+        for state in [JobState::SkippedTtl, JobState::SkippedDeadline] {
+            expired += self
+                .job_repo
+                .count_by_state(DEFAULT_QUEUE, state)
+                .await
+                .map_err(to_rpc_error)?;
+        }
+
         // Get maintenance stats for DB size
         let stats = self.maintenance.get_stats().await.map_err(to_rpc_error)?;
 
         let total_jobs = stats.job_count;
 
+        let services = self
+            .job_repo
+            .list_active_services()
+            .await
+            .map_err(to_rpc_error)?
+            .into_iter()
+            .map(|job| ServiceInfo {
+                next_restart_at: job.schedule_at.filter(|_| job.state == JobState::Queued),
+                job_id: job.id,
+                job_type: job.job_type.as_str().to_string(),
+                subject_key: job.subject_key,
+                state: job.state.to_string(),
+                restarts: job.attempts,
+                started_at: job.started_at,
+            })
+            .collect();
+
+        let job_types = self
+            .job_repo
+            .job_type_stats()
+            .await
+            .map_err(to_rpc_error)?
+            .into_iter()
+            .map(JobTypeStatsInfo::from)
+            .collect();
+
+        let now = self.time_provider.now_millis();
+        let mut wait_slos = Vec::new();
+        for (queue, &target_ms) in self.wait_slos.targets() {
+            let report = self
+                .job_repo
+                .wait_slo_report(
+                    queue,
+                    target_ms,
+                    now,
+                    now - WAIT_SLO_WINDOW_MS,
+                    MAX_SLO_JOB_IDS,
+                )
+                .await
+                .map_err(to_rpc_error)?;
+            wait_slos.push(WaitSloInfo::from(report));
+        }
+
         Ok(StatsResponse {
             total_jobs,
             queued_jobs: queued,
             running_jobs: running,
             done_jobs: done,
             failed_jobs: failed,
+            expired_jobs: expired,
             db_size_bytes: stats.db_size_bytes,
             uptime_seconds: self.start_time.elapsed().as_secs() as i64,
+            schema_version: stats.schema_version,
+            engine_version: env!("CARGO_PKG_VERSION").to_string(),
+            capabilities: self.capabilities(),
+            slots: self.worker_slots(),
+            worker_restarts: self
+                .worker_activity
+                .as_ref()
+                .map_or(0, |activity| activity.restarts()),
+            services,
+            wait_slos,
+            job_types,
+        })
+    }
+
+    /// admin.queues.list.v1
+    pub async fn list_queues(
+        &self,
+        _params: QueuesListRequest,
+    ) -> Result<QueuesListResponse, ErrorObjectOwned> {
+        let now = self.time_provider.now_millis();
+        let queues = self
+            .job_repo
+            .queue_stats(now, now - THROUGHPUT_WINDOW_MS)
+            .await
+            .map_err(to_rpc_error)?
+            .into_iter()
+            .map(|stats| QueueInfo {
+                paused: self.pauses.is_paused(&stats.queue),
+                oldest_queued_age_ms: stats
+                    .oldest_queued_at
+                    .map(|created_at| elapsed_ms(now, created_at)),
+                queue: stats.queue,
+                queued: stats.queued,
+                running: stats.running,
+                done: stats.done,
+                failed: stats.failed,
+                throughput_last_hour: stats.finished_since,
+            })
+            .collect();
+
+        Ok(QueuesListResponse { queues })
+    }
+
+    /// health.check.v1 - Database, worker loop and maintenance status
+    ///
+    /// Not rate limited: supervisors poll it to tell a wedged daemon from a dead one.
+    pub async fn health_check(
+        &self,
+        _params: HealthCheckRequest,
+    ) -> Result<HealthCheckResponse, ErrorObjectOwned> {
+        let now = self.time_provider.now_millis();
+
+        let started = std::time::Instant::now();
+        let ping = self.job_repo.ping().await;
+        let database = DatabaseHealth {
+            reachable: ping.is_ok(),
+            latency_ms: started.elapsed().as_millis() as i64,
+            error: ping.err().map(|e| e.to_string()),
+        };
+
+        let uptime_ms = self.start_time.elapsed().as_millis() as i64;
+        let worker = self.worker_activity.as_ref().map(|activity| {
+            let last_tick_age_ms = activity.last_tick().map(|at| elapsed_ms(now, at));
+            let last_exit = activity.last_exit();
+            WorkerHealth {
+                // No tick yet right after startup is not a wedged loop
+                alive: !activity.is_down()
+                    && (activity.is_busy()
+                        || last_tick_age_ms.map_or(uptime_ms, |age| age) <= WORKER_STALE_TICK_MS),
+                busy: activity.is_busy(),
+                throttled: activity.is_throttled(),
+                paused: activity.is_paused(),
+                last_tick_at: activity.last_tick(),
+                last_tick_age_ms,
+                restarting: activity.is_down(),
+                restarts: activity.restarts(),
+                last_exit_at: last_exit.as_ref().map(|exit| exit.at),
+                last_exit_reason: last_exit.map(|exit| exit.reason),
+            }
+        });
+
+        // Unreadable history (e.g. database down) reads as "no run recorded"
+        let last_report = match self.maintenance.list_reports(1).await {
+            Ok(reports) => reports.into_iter().next(),
+            Err(_) => None,
+        };
+        let maintenance = MaintenanceHealth {
+            last_run_at: last_report.as_ref().map(|r| r.started_at),
+            last_run_age_ms: last_report.as_ref().map(|r| elapsed_ms(now, r.started_at)),
+            last_run_ok: last_report.as_ref().map(|r| r.errors.is_empty()),
+            errors: last_report.map(|r| r.errors).unwrap_or_default(),
+        };
+
+        let worker_alive = worker.as_ref().is_none_or(|w| w.alive);
+        let status = if !database.reachable {
+            "unavailable"
+        } else if !worker_alive || maintenance.last_run_ok == Some(false) {
+            "degraded"
+        } else {
+            "ok"
+        };
+
+        Ok(HealthCheckResponse {
+            status: status.to_string(),
+            ready: database.reachable && worker_alive,
+            uptime_seconds: self.start_time.elapsed().as_secs() as i64,
+            database,
+            worker,
+            maintenance,
+        })
+    }
+
+    fn worker_slots(&self) -> Vec<WorkerSlot> {
+        let Some(activity) = &self.worker_activity else {
+            return Vec::new();
+        };
+        let now = self.time_provider.now_millis();
+
+        activity
+            .slots()
+            .into_iter()
+            .enumerate()
+            .map(|(slot, occupant)| match occupant {
+                Some(occupant) => WorkerSlot {
+                    slot,
+                    running_ms: Some(elapsed_ms(now, occupant.since)),
+                    job_id: Some(occupant.job_id),
+                    job_type: Some(occupant.job_type),
+                    subject_key: Some(occupant.subject_key),
+                },
+                None => WorkerSlot {
+                    slot,
+                    job_id: None,
+                    job_type: None,
+                    subject_key: None,
+                    running_ms: None,
+                },
+            })
+            .collect()
+    }
+
+    fn capabilities(&self) -> EngineCapabilities {
+        let settings = self.runtime_config.get();
+        EngineCapabilities {
+            features: EngineFeatures {
+                backend: "sqlite".to_string(),
+                uds: self.uds,
+                auth: false,
+                events: true,
+                worker_leasing: true,
+                rest: cfg!(feature = "rest"),
+            },
+            limits: EngineLimits {
+                rate_limit_burst: settings.rate_limit_burst,
+                rate_limit_per_sec: settings.rate_limit_per_sec,
+                ..self.limits.clone()
+            },
+        }
+    }
+
+    /// admin.config.get.v1
+    pub async fn config_get(
+        &self,
+        _params: ConfigGetRequest,
+    ) -> Result<RuntimeSettings, ErrorObjectOwned> {
+        Ok(self.runtime_config.get())
+    }
+
+    /// admin.config.set.v1
+    pub async fn config_set(
+        &self,
+        params: ConfigSetRequest,
+    ) -> Result<ConfigSetResponse, ErrorObjectOwned> {
+        let settings = self
+            .runtime_config
+            .set(&params.key, params.value)
+            .map_err(to_rpc_error)?;
+        self.rate_limiter
+            .set_limits(settings.rate_limit_burst, settings.rate_limit_per_sec);
+
+        tracing::info!(key = %params.key, settings = ?settings, "Runtime setting updated");
+
+        Ok(ConfigSetResponse {
+            key: params.key,
+            settings,
         })
     }
 
@@ -225,6 +1470,25 @@ impl RpcHandler {
     ) -> Result<MaintenanceResponse, ErrorObjectOwned> {
         let stats_before = self.maintenance.get_stats().await.map_err(to_rpc_error)?;
 
+        if params.dry_run {
+            let preview = self
+                .maintenance
+                .preview_gc(params.retention_days, params.retention_days)
+                .await
+                .map_err(to_rpc_error)?;
+
+            return Ok(MaintenanceResponse {
+                vacuum_run: false,
+                jobs_deleted: preview.job_ids.len() as i64,
+                artifacts_deleted: (preview.log_files.len() + preview.artifacts.len()) as i64,
+                db_size_before: stats_before.db_size_bytes,
+                db_size_after: stats_before.db_size_bytes,
+                dry_run: true,
+                bytes_reclaimable: Some(preview.bytes_reclaimed()),
+                preview: Some(preview),
+            });
+        }
+
         // Run VACUUM if forced or needed
         let vacuum_run = if params.force_vacuum || stats_before.fragmentation_percent > 10.0 {
             self.maintenance.vacuum().await.map_err(to_rpc_error)?;
@@ -236,13 +1500,13 @@ impl RpcHandler {
         // Run garbage collection
         let jobs_deleted = self
             .maintenance
-            .gc_finished_jobs(30) // 30 days
+            .gc_finished_jobs(params.retention_days)
             .await
             .map_err(to_rpc_error)?;
 
         let artifacts_deleted = self
             .maintenance
-            .gc_artifacts(30) // 30 days
+            .gc_artifacts(params.retention_days)
             .await
             .map_err(to_rpc_error)?;
 
@@ -254,6 +1518,369 @@ impl RpcHandler {
             artifacts_deleted: artifacts_deleted as i64,
             db_size_before: stats_before.db_size_bytes,
             db_size_after: stats_after.db_size_bytes,
+            dry_run: false,
+            preview: None,
+            bytes_reclaimable: None,
+        })
+    }
+
+    /// admin.maintenance.history.v1
+    pub async fn maintenance_history(
+        &self,
+        params: MaintenanceHistoryRequest,
+    ) -> Result<MaintenanceHistoryResponse, ErrorObjectOwned> {
+        let reports = self
+            .maintenance
+            .list_reports(params.limit)
+            .await
+            .map_err(to_rpc_error)?;
+
+        Ok(MaintenanceHistoryResponse { reports })
+    }
+
+    /// admin.metrics.history.v1
+    pub async fn metrics_history(
+        &self,
+        params: MetricsHistoryRequest,
+    ) -> Result<MetricsHistoryResponse, ErrorObjectOwned> {
+        let limit = params.limit.unwrap_or(DEFAULT_METRICS_HISTORY_LIMIT);
+        if limit == 0 || limit > MAX_METRICS_HISTORY_LIMIT {
+            return Err(to_rpc_error(semantica_core::error::AppError::Validation(
+                format!("Limit must be 1-{}", MAX_METRICS_HISTORY_LIMIT),
+            )));
+        }
+
+        let snapshots = self
+            .job_repo
+            .list_metrics_snapshots(params.since.unwrap_or(0), limit)
+            .await
+            .map_err(to_rpc_error)?;
+
+        Ok(MetricsHistoryResponse {
+            snapshots: snapshots.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    /// admin.archive.get.v1
+    pub async fn archive_get(
+        &self,
+        params: ArchiveGetRequest,
+    ) -> Result<ArchiveGetResponse, ErrorObjectOwned> {
+        let job_archive = self.job_archive.as_ref().ok_or_else(|| {
+            to_rpc_error(semantica_core::error::AppError::Config(
+                "Job archive is not enabled on this daemon (SEMANTICA_ARCHIVE_DIR)".to_string(),
+            ))
+        })?;
+
+        let (archived, location) = job_archive
+            .get(&params.job_id)
+            .await
+            .map_err(to_rpc_error)?
+            .ok_or_else(|| {
+                to_rpc_error(semantica_core::error::AppError::NotFound(format!(
+                    "Job {} not found in the archive",
+                    params.job_id
+                )))
+            })?;
+
+        let job = serde_json::to_value(&archived.job).map_err(|e| {
+            to_rpc_error(semantica_core::error::AppError::Internal(format!(
+                "Failed to serialize archived job: {}",
+                e
+            )))
+        })?;
+
+        Ok(ArchiveGetResponse {
+            job_id: archived.job.id,
+            archived_at: archived.archived_at,
+            segment: location.segment,
+            job,
+            events: archived
+                .events
+                .into_iter()
+                .map(|event| JobEventInfo {
+                    state: event.state.to_string(),
+                    actor: event.actor,
+                    reason: event.reason,
+                    created_at: event.created_at,
+                })
+                .collect(),
+        })
+    }
+
+    /// admin.audit.list.v1
+    pub async fn audit_list(
+        &self,
+        params: AuditListRequest,
+    ) -> Result<AuditListResponse, ErrorObjectOwned> {
+        let audit_log = self.audit_log.as_ref().ok_or_else(|| {
+            to_rpc_error(semantica_core::error::AppError::Config(
+                "Audit log store is not enabled on this daemon".to_string(),
+            ))
+        })?;
+
+        let filter = AuditFilter {
+            method: params.method,
+            job_id: params.job_id,
+            caller: params.caller,
+            since: params.since,
+        };
+        let entries = audit_log
+            .list(&filter, params.limit.min(MAX_AUDIT_ENTRIES))
+            .await
+            .map_err(to_rpc_error)?;
+
+        Ok(AuditListResponse {
+            entries: entries.into_iter().map(AuditEntryInfo::from).collect(),
+        })
+    }
+
+    /// admin.snapshot.v1
+    pub async fn snapshot(
+        &self,
+        params: SnapshotRequest,
+    ) -> Result<QueueSnapshot, ErrorObjectOwned> {
+        self.snapshots()
+            .capture(params.queue.as_deref(), params.anonymize)
+            .await
+            .map_err(to_rpc_error)
+    }
+
+    /// admin.restore.v1
+    pub async fn restore(
+        &self,
+        params: RestoreRequest,
+    ) -> Result<RestoreResponse, ErrorObjectOwned> {
+        let summary = self
+            .snapshots()
+            .restore(&params.snapshot)
+            .await
+            .map_err(to_rpc_error)?;
+        self.dispatch.notify();
+
+        Ok(RestoreResponse {
+            jobs_restored: summary.jobs,
+            subjects_restored: summary.subjects,
+        })
+    }
+
+    fn snapshots(&self) -> SnapshotService {
+        SnapshotService::new(
+            self.job_repo.clone(),
+            self.tx_job_repo.clone(),
+            self.time_provider.clone(),
+        )
+    }
+
+    /// admin.job_type.disable.v1 / admin.job_type.enable.v1
+    pub async fn set_job_type_disabled(
+        &self,
+        params: JobTypeSwitchRequest,
+        disabled: bool,
+    ) -> Result<JobTypeSwitchResponse, ErrorObjectOwned> {
+        if params.job_type.is_empty() {
+            return Err(to_rpc_error(semantica_core::error::AppError::Validation(
+                "Job type cannot be empty".to_string(),
+            )));
+        }
+
+        self.job_repo
+            .set_job_type_disabled(&params.job_type, disabled)
+            .await
+            .map_err(to_rpc_error)?;
+
+        let disabled_job_types = self
+            .job_repo
+            .list_disabled_job_types()
+            .await
+            .map_err(to_rpc_error)?;
+
+        tracing::info!(job_type = %params.job_type, disabled, "Job type switch updated");
+        if !disabled {
+            self.dispatch.notify();
+        }
+
+        Ok(JobTypeSwitchResponse {
+            job_type: params.job_type,
+            disabled,
+            disabled_job_types,
+        })
+    }
+
+    /// admin.retry_policy.list.v1
+    pub async fn retry_policies(
+        &self,
+        _params: RetryPoliciesRequest,
+    ) -> Result<RetryPoliciesResponse, ErrorObjectOwned> {
+        let default = self.retry_policies.default_policy();
+
+        Ok(RetryPoliciesResponse {
+            default_base_delay_ms: default.base_delay_ms(),
+            max_delay_ms: default.max_delay_ms(),
+            retry_deadline_ms: default.retry_deadline_ms(),
+            max_panics: default.max_panics(),
+            policies: self
+                .retry_policies
+                .rules()
+                .into_iter()
+                .map(|(job_type, rule)| (job_type, rule.into()))
+                .collect(),
+        })
+    }
+
+    /// admin.retry_policy.set.v1
+    pub async fn set_retry_policy(
+        &self,
+        params: RetryPolicySetRequest,
+    ) -> Result<RetryPoliciesResponse, ErrorObjectOwned> {
+        let rule = RetryRule {
+            max_attempts: params.max_attempts,
+            base_delay_ms: params.base_delay_ms,
+            backoff_factor: params.backoff_factor,
+        };
+        self.retry_policies
+            .set(&params.job_type, rule.clone())
+            .map_err(to_rpc_error)?;
+
+        tracing::info!(job_type = %params.job_type, rule = ?rule, "Retry policy updated");
+
+        self.retry_policies(RetryPoliciesRequest {}).await
+    }
+
+    /// admin.retry_policy.reset.v1
+    pub async fn reset_retry_policy(
+        &self,
+        params: RetryPolicyResetRequest,
+    ) -> Result<RetryPoliciesResponse, ErrorObjectOwned> {
+        let changed = self.retry_policies.reset(&params.job_type);
+
+        tracing::info!(job_type = %params.job_type, changed, "Retry policy reset to default");
+
+        self.retry_policies(RetryPoliciesRequest {}).await
+    }
+
+    /// admin.queue.pause.v1 / admin.queue.resume.v1
+    pub async fn set_queue_paused(
+        &self,
+        params: QueuePauseRequest,
+        paused: bool,
+    ) -> Result<QueuePauseResponse, ErrorObjectOwned> {
+        if params.queue.is_empty() {
+            return Err(to_rpc_error(semantica_core::error::AppError::Validation(
+                "Queue cannot be empty".to_string(),
+            )));
+        }
+
+        let changed = if paused {
+            self.pauses.pause(&params.queue)
+        } else {
+            self.pauses.resume(&params.queue)
+        };
+
+        tracing::info!(queue = %params.queue, paused, changed, "Queue pause updated");
+        if !paused && changed {
+            self.dispatch.notify();
+        }
+
+        Ok(QueuePauseResponse {
+            queue: params.queue,
+            paused,
+            paused_queues: self.pauses.paused(),
+        })
+    }
+
+    /// admin.lockdown.v1
+    pub async fn lockdown(
+        &self,
+        params: LockdownRequest,
+    ) -> Result<LockdownResponse, ErrorObjectOwned> {
+        let reason = params.reason.filter(|r| !r.trim().is_empty());
+        let actor = params.actor.unwrap_or_else(|| actor::RPC.to_string());
+        if reason.as_ref().is_some_and(|r| r.len() > MAX_REASON_LEN)
+            || actor.is_empty()
+            || actor.len() > MAX_ACTOR_LEN
+        {
+            return Err(to_rpc_error(semantica_core::error::AppError::Validation(
+                format!(
+                    "Lockdown reason must be at most {} chars and actor 1-{} chars",
+                    MAX_REASON_LEN, MAX_ACTOR_LEN
+                ),
+            )));
+        }
+
+        let changed = match params.enabled {
+            Some(true) => {
+                let changed = self.lockdown.engage(
+                    actor.clone(),
+                    reason.clone(),
+                    self.time_provider.now_millis(),
+                );
+                if changed {
+                    tracing::warn!(actor = %actor, reason = ?reason, "Lockdown engaged, subprocess execution refused");
+                }
+                changed
+            }
+            Some(false) => {
+                let changed = self.lockdown.lift();
+                if changed {
+                    tracing::warn!(actor = %actor, "Lockdown lifted");
+                    self.dispatch.notify();
+                }
+                changed
+            }
+            None => false,
+        };
+
+        let state = self.lockdown.status();
+        Ok(LockdownResponse {
+            locked_down: state.is_some(),
+            since: state.as_ref().map(|s| s.since),
+            reason: state.as_ref().and_then(|s| s.reason.clone()),
+            actor: state.map(|s| s.actor),
+            changed,
+        })
+    }
+
+    /// admin.drain.v1
+    pub async fn drain(&self, params: DrainRequest) -> Result<DrainResponse, ErrorObjectOwned> {
+        let timeout_ms = params.timeout_ms.unwrap_or(DEFAULT_WAIT_MS);
+        if timeout_ms > MAX_WAIT_MS {
+            return Err(to_rpc_error(semantica_core::error::AppError::Validation(
+                format!("timeout_ms must be at most {}", MAX_WAIT_MS),
+            )));
+        }
+
+        let changed = if params.resume {
+            let changed = self.drain.stop();
+            if changed {
+                tracing::info!("Drain ended, popping again");
+                self.dispatch.notify();
+            }
+            changed
+        } else {
+            let changed = self.drain.start(self.time_provider.now_millis());
+            if changed {
+                tracing::warn!(
+                    in_flight = self.drain.in_flight(),
+                    "Drain started, no new jobs are popped"
+                );
+            }
+            if timeout_ms > 0 {
+                self.drain
+                    .wait_idle(Duration::from_millis(timeout_ms))
+                    .await;
+            }
+            changed
+        };
+
+        let in_flight = self.drain.in_flight();
+        let since = self.drain.since();
+        Ok(DrainResponse {
+            draining: since.is_some(),
+            since,
+            changed,
+            in_flight,
+            drained: since.is_some() && in_flight == 0,
         })
     }
 }
@@ -3,10 +3,17 @@
 //! Implements the JSON-RPC 2.0 server for Semantica Task Engine.
 //! Adheres to ADR-020 (API Contract).
 
+pub mod audit;
 pub mod error;
 pub mod handler;
+pub mod log_follow;
 mod rate_limiter;
+pub mod request_id;
+#[cfg(feature = "rest")]
+pub mod rest;
+pub mod schema;
 pub mod server;
 pub mod types;
 
+pub use rate_limiter::{rate_limits_from_env, QueueRateLimits};
 pub use server::RpcServer;
@@ -0,0 +1,288 @@
+//! Log follower behind `logs.follow.v1`
+//!
+//! Job logs are append-only files (one tagged JSON record per line), so
+//! following one means remembering the read offset and polling for growth.
+//! A job's log path is only stored once an attempt finished; while it runs
+//! the path is derived from the daemon's log directory like the executor does.
+//! The follow ends when the state feed (or a periodic re-read, for transitions
+//! not on the feed) shows the job finished.
+
+use crate::types::LogFollowEvent;
+use semantica_core::application::JobStateChange;
+use semantica_core::domain::{LogLine, LogStream};
+use semantica_core::error::Result;
+use semantica_core::port::JobRepository;
+use std::collections::VecDeque;
+use std::io::SeekFrom;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+use tracing::warn;
+
+/// How often a followed log file is checked for new lines
+pub const LOG_FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Polls between two job state checks (the state feed ends most follows sooner)
+pub const LOG_FOLLOW_STATE_CHECK_POLLS: u32 = 8;
+
+/// Follows one job's log until the job finished
+pub struct LogFollower {
+    job_repo: Arc<dyn JobRepository>,
+    changes: Receiver<JobStateChange>,
+    job_id: String,
+    tail: LogTail,
+    // Lines already written to send first, taken by the first `next`
+    backlog: Option<usize>,
+    polls: u32,
+    done: bool,
+}
+
+impl LogFollower {
+    /// Follow `job_id`; subscribe `changes` before the job was looked up
+    pub fn new(
+        job_repo: Arc<dyn JobRepository>,
+        changes: Receiver<JobStateChange>,
+        job_id: impl Into<String>,
+        stream: Option<LogStream>,
+        path: Option<PathBuf>,
+        backlog: usize,
+    ) -> Self {
+        Self {
+            job_repo,
+            changes,
+            job_id: job_id.into(),
+            tail: LogTail::new(path, stream),
+            backlog: Some(backlog),
+            polls: 0,
+            done: false,
+        }
+    }
+
+    /// Next lines to send (ending with `End`), None once the job finished
+    pub async fn next(&mut self) -> Option<Vec<LogFollowEvent>> {
+        if self.done {
+            return None;
+        }
+        if let Some(lines) = self.backlog.take() {
+            // Check the state right away: the job may have finished already
+            self.polls = LOG_FOLLOW_STATE_CHECK_POLLS;
+            return Some(self.backlog(lines).await);
+        }
+        loop {
+            let finished_hint = self.wait().await;
+            self.polls += 1;
+            if finished_hint || self.polls >= LOG_FOLLOW_STATE_CHECK_POLLS {
+                self.polls = 0;
+                match self.finished().await {
+                    Ok(Some(state)) => {
+                        self.done = true;
+                        return Some(self.finish(state).await);
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        warn!(job_id = %self.job_id, error = %e, "Failed to check followed job")
+                    }
+                }
+            }
+            let events = self.tail.read_new().await;
+            if !events.is_empty() {
+                return Some(events);
+            }
+        }
+    }
+
+    /// Sleep one poll interval; true early if the job may have finished
+    async fn wait(&mut self) -> bool {
+        let wake_at = tokio::time::Instant::now() + LOG_FOLLOW_POLL_INTERVAL;
+        loop {
+            match tokio::time::timeout_at(wake_at, self.changes.recv()).await {
+                Ok(Ok(change))
+                    if change.job_id == self.job_id && change.new_state.is_terminal() =>
+                {
+                    return true
+                }
+                Ok(Ok(_)) => continue,
+                Ok(Err(RecvError::Lagged(_))) => return true,
+                Ok(Err(RecvError::Closed)) => {
+                    tokio::time::sleep_until(wake_at).await;
+                    return false;
+                }
+                Err(_) => return false,
+            }
+        }
+    }
+
+    /// Everything written so far, keeping only the last `lines` events
+    async fn backlog(&mut self, lines: usize) -> Vec<LogFollowEvent> {
+        let mut backlog: VecDeque<LogFollowEvent> = VecDeque::with_capacity(lines);
+        for event in self.tail.read_new().await {
+            if backlog.len() == lines {
+                backlog.pop_front();
+            }
+            if lines > 0 {
+                backlog.push_back(event);
+            }
+        }
+        backlog.into()
+    }
+
+    /// State of the job once it finished (None while it may still write)
+    ///
+    /// Picks up the stored log path if none was known. A deleted job counts
+    /// as finished.
+    async fn finished(&mut self) -> Result<Option<String>> {
+        let Some(job) = self.job_repo.find_by_id(&self.job_id).await? else {
+            return Ok(Some("DELETED".to_string()));
+        };
+        if self.tail.path.is_none() {
+            self.tail.path = job.log_path.map(PathBuf::from);
+        }
+        Ok(job.state.is_terminal().then(|| job.state.to_string()))
+    }
+
+    /// Remaining lines, then the end event
+    async fn finish(&mut self, state: String) -> Vec<LogFollowEvent> {
+        let mut events = self.tail.read_new().await;
+        events.extend(self.tail.take_rest());
+        events.push(LogFollowEvent::End { state });
+        events
+    }
+}
+
+/// Read position in a log file
+struct LogTail {
+    path: Option<PathBuf>,
+    stream: Option<LogStream>,
+    offset: u64,
+    // Bytes after the last newline (a record still being written)
+    partial: Vec<u8>,
+}
+
+impl LogTail {
+    fn new(path: Option<PathBuf>, stream: Option<LogStream>) -> Self {
+        Self {
+            path,
+            stream,
+            offset: 0,
+            partial: Vec::new(),
+        }
+    }
+
+    /// Lines appended since the last read (a missing file reads as empty)
+    async fn read_new(&mut self) -> Vec<LogFollowEvent> {
+        let Some(path) = &self.path else {
+            return Vec::new();
+        };
+        let Ok(mut file) = tokio::fs::File::open(path).await else {
+            return Vec::new();
+        };
+        let len = file.metadata().await.map(|m| m.len()).unwrap_or(0);
+        if len < self.offset {
+            // Truncated or replaced: start over
+            self.offset = 0;
+            self.partial.clear();
+        }
+        if len == self.offset || file.seek(SeekFrom::Start(self.offset)).await.is_err() {
+            return Vec::new();
+        }
+        let mut appended = Vec::new();
+        if file.read_to_end(&mut appended).await.is_err() {
+            return Vec::new();
+        }
+        self.offset += appended.len() as u64;
+        self.partial.extend_from_slice(&appended);
+
+        let Some(end) = self.partial.iter().rposition(|&b| b == b'\n') else {
+            return Vec::new();
+        };
+        let complete: Vec<u8> = self.partial.drain(..=end).collect();
+        String::from_utf8_lossy(&complete)
+            .lines()
+            .filter_map(|record| self.to_event(record))
+            .collect()
+    }
+
+    /// The unterminated last line (the job will not complete it anymore)
+    fn take_rest(&mut self) -> Option<LogFollowEvent> {
+        if self.partial.is_empty() {
+            return None;
+        }
+        let rest = String::from_utf8_lossy(&std::mem::take(&mut self.partial)).into_owned();
+        self.to_event(&rest)
+    }
+
+    /// Untagged lines (older logs) have no stream and only match without a filter
+    fn to_event(&self, record: &str) -> Option<LogFollowEvent> {
+        match LogLine::parse_record(record) {
+            Some(entry) => self
+                .stream
+                .is_none_or(|stream| entry.stream == stream)
+                .then(|| LogFollowEvent::Line {
+                    line: entry.line.clone(),
+                    entry: Some(entry),
+                }),
+            None => self.stream.is_none().then(|| LogFollowEvent::Line {
+                line: record.to_string(),
+                entry: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn lines(events: &[LogFollowEvent]) -> Vec<&str> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                LogFollowEvent::Line { line, .. } => Some(line.as_str()),
+                LogFollowEvent::End { .. } => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_tail_reads_only_complete_new_lines() {
+        let path =
+            std::env::temp_dir().join(format!("semantica_follow_{}.log", uuid::Uuid::new_v4()));
+        let mut file = std::fs::File::create(&path).unwrap();
+        let mut tail = LogTail::new(Some(path.clone()), Some(LogStream::Stdout));
+
+        let out = LogLine::new(1, LogStream::Stdout, "built").to_record();
+        let err = LogLine::new(2, LogStream::Stderr, "warning").to_record();
+        write!(file, "{}\n{}\n{}", out, err, &out[..5]).unwrap();
+        assert_eq!(lines(&tail.read_new().await), vec!["built"]);
+        assert!(tail.read_new().await.is_empty(), "nothing appended");
+
+        // The half-written record completes
+        writeln!(file, "{}", &out[5..]).unwrap();
+        assert_eq!(lines(&tail.read_new().await), vec!["built"]);
+
+        write!(
+            file,
+            "{}",
+            LogLine::new(3, LogStream::Stdout, "cut").to_record()
+        )
+        .unwrap();
+        assert!(tail.read_new().await.is_empty());
+        assert_eq!(
+            lines(&tail.take_rest().into_iter().collect::<Vec<_>>()),
+            vec!["cut"]
+        );
+
+        // Truncated: start over
+        std::fs::write(&path, format!("{}\n", out)).unwrap();
+        assert_eq!(lines(&tail.read_new().await), vec!["built"]);
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(
+            tail.read_new().await.is_empty(),
+            "missing file reads as empty"
+        );
+    }
+}
@@ -3,15 +3,36 @@
 //! Prevents DoS attacks by limiting requests per second.
 //! Uses atomic operations to avoid lock contention under high load.
 
-use std::sync::atomic::{AtomicU64, Ordering};
+use crate::types::{QueueRateLimit, ThrottledData};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 
+// Global limits (configurable via env vars, tunable via admin.config.set.v1)
+const DEFAULT_RATE_LIMIT_BURST: u32 = 200;
+const DEFAULT_RATE_LIMIT_RATE: u32 = 100;
+
+/// Global (burst, tokens/sec) from SEMANTICA_RATE_LIMIT_BURST / SEMANTICA_RATE_LIMIT_RATE
+pub fn rate_limits_from_env() -> (u32, u32) {
+    let max_burst: u32 = std::env::var("SEMANTICA_RATE_LIMIT_BURST")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_RATE_LIMIT_BURST);
+
+    let rate_per_sec: u32 = std::env::var("SEMANTICA_RATE_LIMIT_RATE")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_RATE_LIMIT_RATE);
+
+    (max_burst, rate_per_sec)
+}
+
 /// Rate limiter using token bucket algorithm with atomic operations
 pub struct RateLimiter {
     state: Arc<AtomicState>,
-    max_tokens: u32,
-    refill_rate: u32, // tokens per second
+    max_tokens: AtomicU32,
+    refill_rate: AtomicU32, // tokens per second
 }
 
 struct AtomicState {
@@ -39,17 +60,26 @@ impl RateLimiter {
                 packed: AtomicU64::new(tokens_fixed),
                 creation_time: Instant::now(),
             }),
-            max_tokens,
-            refill_rate,
+            max_tokens: AtomicU32::new(max_tokens),
+            refill_rate: AtomicU32::new(refill_rate),
         }
     }
 
+    /// Change burst size and refill rate (takes effect on the next check)
+    pub fn set_limits(&self, max_tokens: u32, refill_rate: u32) {
+        self.max_tokens.store(max_tokens, Ordering::Relaxed);
+        self.refill_rate.store(refill_rate, Ordering::Relaxed);
+    }
+
     /// Check if request is allowed (consumes 1 token)
     ///
-    /// Returns true if allowed, false if rate limited
+    /// Returns Err (when the next token is available) if rate limited
     ///
     /// Uses atomic CAS loop to avoid lock contention
-    pub async fn check(&self) -> bool {
+    pub async fn try_acquire(&self) -> Result<(), ThrottledData> {
+        let max_tokens = self.max_tokens.load(Ordering::Relaxed);
+        let refill_rate = self.refill_rate.load(Ordering::Relaxed);
+
         // CAS loop to update tokens atomically
         loop {
             let packed = self.state.packed.load(Ordering::Acquire);
@@ -62,8 +92,8 @@ impl RateLimiter {
             let delta_ms = elapsed_ms.saturating_sub(last_refill_ms);
 
             // Refill tokens
-            let tokens_to_add = (delta_ms as u64 * self.refill_rate as u64) / 1000;
-            let new_tokens = ((tokens as u64 + tokens_to_add).min(self.max_tokens as u64)) as u32;
+            let tokens_to_add = (delta_ms as u64 * refill_rate as u64) / 1000;
+            let new_tokens = ((tokens as u64 + tokens_to_add).min(max_tokens as u64)) as u32;
 
             // Try to consume 1 token
             if new_tokens >= 1 {
@@ -77,7 +107,7 @@ impl RateLimiter {
                     Ordering::Release,
                     Ordering::Acquire,
                 ) {
-                    Ok(_) => return true,
+                    Ok(_) => return Ok(()),
                     Err(_) => continue, // Retry
                 }
             } else {
@@ -89,7 +119,17 @@ impl RateLimiter {
                     Ordering::Release,
                     Ordering::Acquire,
                 );
-                return false;
+                // The bucket was just refilled up to now: a token takes one refill interval
+                let retry_after_ms =
+                    (refill_rate > 0).then(|| 1000_u64.div_ceil(refill_rate as u64));
+                return Err(ThrottledData {
+                    scope: "global".to_string(),
+                    queue: None,
+                    remaining_tokens: new_tokens,
+                    burst: max_tokens,
+                    per_sec: refill_rate,
+                    retry_after_ms,
+                });
             }
         }
     }
@@ -103,6 +143,80 @@ impl RateLimiter {
     }
 }
 
+/// Per-queue enqueue limits, separate from the global RPC bucket
+///
+/// A flooded queue exhausts only its own bucket; queues without a configured
+/// limit are only subject to the global one.
+#[derive(Default)]
+pub struct QueueRateLimits {
+    limiters: HashMap<String, RateLimiter>,
+    limits: BTreeMap<String, QueueRateLimit>,
+}
+
+impl QueueRateLimits {
+    /// Parse comma-separated `queue:burst/rate` entries
+    ///
+    /// # Example
+    /// `code_intel:50/20,build:10/5` - code_intel: burst 50, 20 enqueues/sec
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let mut limits = Self::default();
+
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let parsed = entry.split_once(':').and_then(|(queue, limit)| {
+                let (burst, per_sec) = limit.split_once('/')?;
+                let limit = QueueRateLimit {
+                    burst: burst.trim().parse().ok()?,
+                    per_sec: per_sec.trim().parse().ok()?,
+                };
+                Some((queue.trim(), limit))
+            });
+            let Some((queue, limit)) = parsed.filter(|(q, l)| !q.is_empty() && l.burst > 0) else {
+                return Err(format!(
+                    "Invalid queue rate limit '{}' (expected queue:burst/rate)",
+                    entry
+                ));
+            };
+
+            limits.limiters.insert(
+                queue.to_string(),
+                RateLimiter::new(limit.burst, limit.per_sec),
+            );
+            limits.limits.insert(queue.to_string(), limit);
+        }
+
+        Ok(limits)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.limiters.is_empty()
+    }
+
+    /// Consume one token of `queue`'s bucket (always true for unlimited queues)
+    pub async fn check(&self, queue: &str) -> bool {
+        self.try_acquire(queue).await.is_ok()
+    }
+
+    /// Consume one token of `queue`'s bucket, or describe when to retry
+    pub async fn try_acquire(&self, queue: &str) -> Result<(), ThrottledData> {
+        match self.limiters.get(queue) {
+            Some(limiter) => limiter
+                .try_acquire()
+                .await
+                .map_err(|throttled| ThrottledData {
+                    scope: "queue".to_string(),
+                    queue: Some(queue.to_string()),
+                    ..throttled
+                }),
+            None => Ok(()),
+        }
+    }
+
+    /// Configured limits by queue (for capabilities)
+    pub fn limits(&self) -> BTreeMap<String, QueueRateLimit> {
+        self.limits.clone()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,11 +228,33 @@ mod tests {
 
         // Should allow 10 requests
         for _ in 0..10 {
-            assert!(limiter.check().await);
+            assert!(limiter.try_acquire().await.is_ok());
         }
 
         // 11th should be denied
-        assert!(!limiter.check().await);
+        assert!(limiter.try_acquire().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rejection_tells_when_to_retry() {
+        let limiter = RateLimiter::new(1, 4); // 1 token every 250ms
+        assert!(limiter.try_acquire().await.is_ok());
+
+        let throttled = limiter.try_acquire().await.unwrap_err();
+        assert_eq!(throttled.scope, "global");
+        assert_eq!(throttled.remaining_tokens, 0);
+        assert_eq!(throttled.retry_after_ms, Some(250));
+
+        let frozen = RateLimiter::new(1, 0);
+        assert!(frozen.try_acquire().await.is_ok());
+        assert_eq!(frozen.try_acquire().await.unwrap_err().retry_after_ms, None);
+
+        let queues = QueueRateLimits::from_spec("build:1/10").unwrap();
+        assert!(queues.try_acquire("build").await.is_ok());
+        let throttled = queues.try_acquire("build").await.unwrap_err();
+        assert_eq!(throttled.scope, "queue");
+        assert_eq!(throttled.queue.as_deref(), Some("build"));
+        assert_eq!(throttled.retry_after_ms, Some(100));
     }
 
     #[tokio::test]
@@ -127,15 +263,15 @@ mod tests {
 
         // Consume all tokens
         for _ in 0..5 {
-            assert!(limiter.check().await);
+            assert!(limiter.try_acquire().await.is_ok());
         }
-        assert!(!limiter.check().await);
+        assert!(limiter.try_acquire().await.is_err());
 
         // Wait 1 second for refill
         sleep(Duration::from_secs(1)).await;
 
         // Should have ~10 tokens now
-        assert!(limiter.check().await);
+        assert!(limiter.try_acquire().await.is_ok());
     }
 
     #[tokio::test]
@@ -151,7 +287,7 @@ mod tests {
             let handle = tokio::spawn(async move {
                 let mut allowed = 0;
                 for _ in 0..20 {
-                    if limiter_clone.check().await {
+                    if limiter_clone.try_acquire().await.is_ok() {
                         allowed += 1;
                     }
                 }
@@ -178,4 +314,41 @@ mod tests {
             total_allowed
         );
     }
+
+    #[tokio::test]
+    async fn test_rate_limiter_set_limits() {
+        let limiter = RateLimiter::new(1, 1);
+        assert!(limiter.try_acquire().await.is_ok());
+        assert!(limiter.try_acquire().await.is_err());
+
+        // Larger burst and faster refill apply without a new limiter
+        limiter.set_limits(5, 1000);
+        sleep(Duration::from_millis(20)).await;
+        for _ in 0..5 {
+            assert!(limiter.try_acquire().await.is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_queue_rate_limits_are_isolated() {
+        let limits = QueueRateLimits::from_spec("code_intel:2/1, build:5/5").unwrap();
+
+        assert!(limits.check("code_intel").await);
+        assert!(limits.check("code_intel").await);
+        assert!(!limits.check("code_intel").await);
+
+        // Other queues keep their own budget (or none at all)
+        assert!(limits.check("build").await);
+        assert!(limits.check("default").await);
+        assert_eq!(limits.limits()["code_intel"].burst, 2);
+    }
+
+    #[test]
+    fn test_queue_rate_limits_reject_invalid_spec() {
+        assert!(QueueRateLimits::from_spec("").unwrap().is_empty());
+        assert!(QueueRateLimits::from_spec("code_intel").is_err());
+        assert!(QueueRateLimits::from_spec("code_intel:10").is_err());
+        assert!(QueueRateLimits::from_spec("code_intel:0/5").is_err());
+        assert!(QueueRateLimits::from_spec(":10/5").is_err());
+    }
 }
@@ -0,0 +1,230 @@
+//! Request IDs
+//!
+//! Every call runs under a request ID: the optional `request_id` param, or
+//! one generated here (32 hex chars). It tags the call's tracing span, comes
+//! back in the `data` of error responses, and becomes the trace of a job
+//! enqueued without a traceparent, so a failed call in a client log can be
+//! found in the daemon log (and a job's execution traced to its enqueue).
+
+use crate::error::code;
+use jsonrpsee::server::middleware::rpc::RpcServiceT;
+use jsonrpsee::server::MethodResponse;
+use jsonrpsee::types::{ErrorObjectOwned, Id, Request};
+use serde_json::{json, Value};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use tracing::{info_span, Instrument};
+
+/// Longest accepted `request_id` param
+pub const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// ID of one RPC call (in the request extensions of every method call)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(String);
+
+impl RequestId {
+    pub fn generate() -> Self {
+        Self(uuid::Uuid::new_v4().simple().to_string())
+    }
+
+    /// Validate a client-supplied ID (printable ASCII, no spaces)
+    pub fn parse(value: &str) -> Result<Self, String> {
+        if value.is_empty() || value.len() > MAX_REQUEST_ID_LEN {
+            return Err(format!(
+                "request_id must be 1 to {} characters",
+                MAX_REQUEST_ID_LEN
+            ));
+        }
+        if !value.bytes().all(|b| b.is_ascii_graphic()) {
+            return Err("request_id must be printable ASCII without spaces".to_string());
+        }
+        Ok(Self(value.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// W3C traceparent whose trace ID is this request
+    ///
+    /// Generated IDs are used as the trace ID verbatim; other IDs are hashed
+    /// into one (the same request ID always maps to the same trace).
+    pub fn traceparent(&self) -> String {
+        let is_trace_id = self.0.len() == 32
+            && self.0.bytes().all(|b| b.is_ascii_hexdigit())
+            && !self.0.bytes().all(|b| b == b'0');
+        let trace_id = if is_trace_id {
+            self.0.to_ascii_lowercase()
+        } else {
+            format!(
+                "{:016x}{:016x}",
+                fnv1a(0xcbf2_9ce4_8422_2325, &self.0),
+                fnv1a(0x6c62_272e_07bb_0142, &self.0)
+            )
+        };
+        let span_id = &uuid::Uuid::new_v4().simple().to_string()[..16];
+        format!("00-{}-{}-01", trace_id, span_id)
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn fnv1a(seed: u64, value: &str) -> u64 {
+    value.bytes().fold(seed, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Layer assigning request IDs (outermost RPC middleware)
+#[derive(Clone, Default)]
+pub struct RequestIdLayer;
+
+impl<S> tower::Layer<S> for RequestIdLayer {
+    type Service = RequestIds<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestIds { inner }
+    }
+}
+
+#[derive(Clone)]
+pub struct RequestIds<S> {
+    inner: S,
+}
+
+impl<'a, S> RpcServiceT<'a> for RequestIds<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + 'a,
+{
+    type Future = Pin<Box<dyn Future<Output = MethodResponse> + Send + 'a>>;
+
+    fn call(&self, mut request: Request<'a>) -> Self::Future {
+        let id = request.id().into_owned();
+        let request_id = match request_id_param(&request) {
+            Some(Ok(request_id)) => request_id,
+            Some(Err(message)) => {
+                let error = ErrorObjectOwned::owned(code::VALIDATION_ERROR, message, None::<()>);
+                return Box::pin(std::future::ready(MethodResponse::error(id, error)));
+            }
+            None => RequestId::generate(),
+        };
+
+        let span = info_span!(
+            "rpc",
+            method = %request.method_name(),
+            request_id = %request_id
+        );
+        request.extensions_mut().insert(request_id.clone());
+        let response = self.inner.call(request).instrument(span);
+
+        Box::pin(async move {
+            let response = response.await;
+            if response.is_error() {
+                with_request_id(response, id, &request_id)
+            } else {
+                response
+            }
+        })
+    }
+}
+
+/// The `request_id` param of a by-name call (None = not supplied)
+fn request_id_param(request: &Request<'_>) -> Option<Result<RequestId, String>> {
+    let params: Value = serde_json::from_str(request.params().as_str()?).ok()?;
+    match params.get("request_id")? {
+        Value::String(value) => Some(RequestId::parse(value)),
+        Value::Null => None,
+        _ => Some(Err("request_id must be a string".to_string())),
+    }
+}
+
+/// Error response with `request_id` added to its `data` (an object, or
+/// wrapped as `{"value": ...}` when the method sent something else)
+fn with_request_id(
+    response: MethodResponse,
+    id: Id<'static>,
+    request_id: &RequestId,
+) -> MethodResponse {
+    let Ok(envelope) = serde_json::from_str::<Value>(response.as_result()) else {
+        return response;
+    };
+    let error = &envelope["error"];
+    let Some(error_code) = error["code"].as_i64() else {
+        return response;
+    };
+
+    let data = match error.get("data") {
+        None | Some(Value::Null) => json!({ "request_id": request_id.as_str() }),
+        Some(Value::Object(fields)) => {
+            let mut fields = fields.clone();
+            fields.insert("request_id".to_string(), json!(request_id.as_str()));
+            Value::Object(fields)
+        }
+        Some(other) => json!({ "value": other, "request_id": request_id.as_str() }),
+    };
+    let error = ErrorObjectOwned::owned(
+        error_code as i32,
+        error["message"].as_str().unwrap_or_default().to_string(),
+        Some(data),
+    );
+
+    MethodResponse::error(id, error).with_extensions(response.extensions().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_id_validation() {
+        assert!(RequestId::parse("client-42:enqueue").is_ok());
+        assert!(RequestId::parse("").is_err());
+        assert!(RequestId::parse("has space").is_err());
+        assert!(RequestId::parse(&"x".repeat(MAX_REQUEST_ID_LEN + 1)).is_err());
+        assert_eq!(RequestId::generate().as_str().len(), 32);
+    }
+
+    #[test]
+    fn test_traceparent_carries_the_request() {
+        let generated = RequestId::generate();
+        let traceparent = generated.traceparent();
+        assert!(traceparent.starts_with(&format!("00-{}-", generated)));
+
+        // Any other ID maps to a stable, valid trace ID
+        let custom = RequestId::parse("client-42").unwrap();
+        let trace_id = |tp: String| tp.split('-').nth(1).unwrap().to_string();
+        assert_eq!(
+            trace_id(custom.traceparent()),
+            trace_id(custom.traceparent())
+        );
+        assert_eq!(trace_id(custom.traceparent()).len(), 32);
+    }
+
+    #[test]
+    fn test_error_data_gets_the_request_id() {
+        let request_id = RequestId::parse("req-1").unwrap();
+        let plain = MethodResponse::error(
+            Id::Number(1),
+            ErrorObjectOwned::owned(code::NOT_FOUND, "gone", None::<()>),
+        );
+        let tagged = with_request_id(plain, Id::Number(1), &request_id);
+        let envelope: Value = serde_json::from_str(tagged.as_result()).unwrap();
+        assert_eq!(envelope["error"]["code"], code::NOT_FOUND);
+        assert_eq!(envelope["error"]["message"], "gone");
+        assert_eq!(envelope["error"]["data"]["request_id"], "req-1");
+
+        let throttled = MethodResponse::error(
+            Id::Number(2),
+            ErrorObjectOwned::owned(code::THROTTLED, "slow down", Some(json!({ "burst": 5 }))),
+        );
+        let tagged = with_request_id(throttled, Id::Number(2), &request_id);
+        let envelope: Value = serde_json::from_str(tagged.as_result()).unwrap();
+        assert_eq!(envelope["error"]["data"]["burst"], 5);
+        assert_eq!(envelope["error"]["data"]["request_id"], "req-1");
+    }
+}
@@ -0,0 +1,301 @@
+//! REST Gateway
+//!
+//! Thin HTTP facade over the JSON-RPC methods for curl scripts and webhook
+//! systems. Each route is rewritten into the matching JSON-RPC call, so the
+//! handlers (validation, rate limits, interceptors) are shared, and the
+//! JSON-RPC envelope is stripped from the response:
+//!
+//! | Route                  | Method           | Success |
+//! |------------------------|------------------|---------|
+//! | `POST /v1/jobs`        | `dev.enqueue.v1` | 201     |
+//! | `GET /v1/jobs/{id}`    | `dev.inspect.v1` | 200     |
+//! | `DELETE /v1/jobs/{id}` | `dev.cancel.v1`  | 200     |
+//!
+//! Errors keep their RPC code and message (`{"error": {"code", "message"}}`)
+//! with the HTTP status derived from the code (see `http_status`).
+
+use crate::error::code;
+use jsonrpsee::core::BoxError;
+use jsonrpsee::server::{HttpBody, HttpRequest, HttpResponse};
+use serde_json::{json, Value};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use http::header::{HeaderValue, ACCEPT, CONTENT_TYPE};
+use http::{Method, StatusCode, Uri};
+use http_body_util::BodyExt;
+use tower::{Layer, Service};
+
+/// Path prefix handled by the gateway (everything else is JSON-RPC)
+const REST_PREFIX: &str = "/v1/";
+
+/// Actor recorded on cancellations made through the gateway
+const REST_ACTOR: &str = "rest";
+
+/// REST route resolved from method + path
+#[derive(Debug, Clone, PartialEq)]
+enum Route {
+    Enqueue,
+    Inspect(String),
+    Cancel(String),
+}
+
+impl Route {
+    /// None: not a REST route. Some(Err): under /v1/ but unknown
+    fn resolve(method: &Method, path: &str) -> Option<Result<Self, StatusCode>> {
+        let rest = path.strip_prefix(REST_PREFIX)?;
+        let mut segments = rest.trim_end_matches('/').split('/');
+
+        let route = match (segments.next(), segments.next(), segments.next()) {
+            (Some("jobs"), None, None) => match *method {
+                Method::POST => Ok(Route::Enqueue),
+                _ => Err(StatusCode::METHOD_NOT_ALLOWED),
+            },
+            (Some("jobs"), Some(id), None) if !id.is_empty() => match *method {
+                Method::GET => Ok(Route::Inspect(id.to_string())),
+                Method::DELETE => Ok(Route::Cancel(id.to_string())),
+                _ => Err(StatusCode::METHOD_NOT_ALLOWED),
+            },
+            _ => Err(StatusCode::NOT_FOUND),
+        };
+        Some(route)
+    }
+
+    /// JSON-RPC method and params (`body` is the request body of POST routes)
+    fn rpc_call(&self, body: Value) -> (&'static str, Value) {
+        match self {
+            Route::Enqueue => ("dev.enqueue.v1", body),
+            Route::Inspect(job_id) => ("dev.inspect.v1", json!({ "job_id": job_id })),
+            Route::Cancel(job_id) => (
+                "dev.cancel.v1",
+                json!({ "job_id": job_id, "actor": REST_ACTOR }),
+            ),
+        }
+    }
+
+    fn success_status(&self) -> StatusCode {
+        match self {
+            Route::Enqueue => StatusCode::CREATED,
+            Route::Inspect(_) | Route::Cancel(_) => StatusCode::OK,
+        }
+    }
+}
+
+/// HTTP status for a JSON-RPC error code
+fn http_status(rpc_code: i64) -> StatusCode {
+    match rpc_code {
+        c if c == code::VALIDATION_ERROR as i64 => StatusCode::BAD_REQUEST,
+        c if c == code::NOT_FOUND as i64 => StatusCode::NOT_FOUND,
+        c if c == code::CONFLICT as i64 => StatusCode::CONFLICT,
+        c if c == code::THROTTLED as i64 => StatusCode::TOO_MANY_REQUESTS,
+        // Parse error / invalid params: the body did not match the request type
+        -32700 | -32602 => StatusCode::BAD_REQUEST,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+fn json_response(status: StatusCode, body: &Value) -> HttpResponse {
+    let mut response = HttpResponse::new(HttpBody::from(body.to_string()));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    response
+}
+
+fn error_response(status: StatusCode, rpc_code: i32, message: &str) -> HttpResponse {
+    json_response(
+        status,
+        &json!({ "error": { "code": rpc_code, "message": message } }),
+    )
+}
+
+/// Unwrap a JSON-RPC response into the REST response
+fn rest_response(route: &Route, envelope: &[u8]) -> HttpResponse {
+    let Ok(mut envelope) = serde_json::from_slice::<Value>(envelope) else {
+        return error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            code::INTERNAL_ERROR,
+            "Malformed JSON-RPC response",
+        );
+    };
+
+    if let Some(error) = envelope.get_mut("error").map(Value::take) {
+        let rpc_code = error.get("code").and_then(Value::as_i64).unwrap_or(0);
+        return json_response(http_status(rpc_code), &json!({ "error": error }));
+    }
+    let result = envelope
+        .get_mut("result")
+        .map(Value::take)
+        .unwrap_or(Value::Null);
+    json_response(route.success_status(), &result)
+}
+
+/// Tower layer installing the REST gateway in front of the JSON-RPC service
+#[derive(Debug, Clone)]
+pub struct RestGatewayLayer {
+    max_body_size: usize,
+}
+
+impl RestGatewayLayer {
+    /// Reject REST bodies larger than `max_body_size` bytes (as JSON-RPC does)
+    pub fn new(max_body_size: u32) -> Self {
+        Self {
+            max_body_size: max_body_size as usize,
+        }
+    }
+}
+
+impl<S> Layer<S> for RestGatewayLayer {
+    type Service = RestGateway<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RestGateway {
+            inner,
+            max_body_size: self.max_body_size,
+        }
+    }
+}
+
+/// See the module docs
+#[derive(Debug, Clone)]
+pub struct RestGateway<S> {
+    inner: S,
+    max_body_size: usize,
+}
+
+impl<S, B> Service<HttpRequest<B>> for RestGateway<S>
+where
+    S: Service<HttpRequest, Response = HttpResponse> + Clone + Send + 'static,
+    S::Error: Into<BoxError> + 'static,
+    S::Future: Send + 'static,
+    B: http_body::Body<Data = bytes::Bytes> + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    type Response = HttpResponse;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: HttpRequest<B>) -> Self::Future {
+        let route = match Route::resolve(req.method(), req.uri().path()) {
+            None => {
+                let fut = self.inner.call(req.map(HttpBody::new));
+                return Box::pin(async move { fut.await.map_err(Into::into) });
+            }
+            Some(Err(status)) => {
+                let message = format!("No route for {} {}", req.method(), req.uri().path());
+                return Box::pin(
+                    async move { Ok(error_response(status, code::NOT_FOUND, &message)) },
+                );
+            }
+            Some(Ok(route)) => route,
+        };
+
+        // The inner service was polled ready: swap in a fresh clone for the next call
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let max_body_size = self.max_body_size;
+
+        Box::pin(async move {
+            let (mut parts, body) = req.into_parts();
+
+            let body = if route == Route::Enqueue {
+                let bytes = match http_body_util::Limited::new(body, max_body_size)
+                    .collect()
+                    .await
+                {
+                    Ok(collected) => collected.to_bytes(),
+                    Err(_) => {
+                        return Ok(error_response(
+                            StatusCode::PAYLOAD_TOO_LARGE,
+                            code::VALIDATION_ERROR,
+                            "Request body too large",
+                        ))
+                    }
+                };
+                match serde_json::from_slice::<Value>(&bytes) {
+                    Ok(body) => body,
+                    Err(e) => {
+                        return Ok(error_response(
+                            StatusCode::BAD_REQUEST,
+                            code::VALIDATION_ERROR,
+                            &format!("Invalid JSON body: {}", e),
+                        ))
+                    }
+                }
+            } else {
+                Value::Null
+            };
+
+            let (method, params) = route.rpc_call(body);
+            let call = json!({ "jsonrpc": "2.0", "id": 0, "method": method, "params": params });
+
+            // RPC methods are accessed with POST on /
+            parts.method = Method::POST;
+            parts.uri = Uri::from_static("/");
+            parts
+                .headers
+                .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+            parts
+                .headers
+                .insert(ACCEPT, HeaderValue::from_static("application/json"));
+            parts.headers.remove(http::header::CONTENT_LENGTH);
+            let req = HttpRequest::from_parts(parts, HttpBody::from(call.to_string()));
+
+            let response = inner.call(req).await.map_err(Into::into)?;
+            let envelope = response.into_body().collect().await?.to_bytes();
+            Ok(rest_response(&route, &envelope))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_routes() {
+        assert_eq!(
+            Route::resolve(&Method::POST, "/v1/jobs"),
+            Some(Ok(Route::Enqueue))
+        );
+        assert_eq!(
+            Route::resolve(&Method::GET, "/v1/jobs/job-1"),
+            Some(Ok(Route::Inspect("job-1".to_string())))
+        );
+        assert_eq!(
+            Route::resolve(&Method::DELETE, "/v1/jobs/job-1/"),
+            Some(Ok(Route::Cancel("job-1".to_string())))
+        );
+        assert_eq!(
+            Route::resolve(&Method::GET, "/v1/jobs"),
+            Some(Err(StatusCode::METHOD_NOT_ALLOWED))
+        );
+        assert_eq!(
+            Route::resolve(&Method::GET, "/v1/queues"),
+            Some(Err(StatusCode::NOT_FOUND))
+        );
+        assert_eq!(Route::resolve(&Method::POST, "/"), None, "JSON-RPC");
+    }
+
+    #[test]
+    fn test_rpc_envelope_is_unwrapped() {
+        let ok = br#"{"jsonrpc":"2.0","id":0,"result":{"job_id":"j1","state":"QUEUED"}}"#;
+        let response = rest_response(&Route::Enqueue, ok);
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let missing = br#"{"jsonrpc":"2.0","id":0,"error":{"code":4001,"message":"gone"}}"#;
+        let response = rest_response(&Route::Inspect("j1".to_string()), missing);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        assert_eq!(http_status(4003), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(http_status(-32602), StatusCode::BAD_REQUEST);
+        assert_eq!(http_status(5001), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
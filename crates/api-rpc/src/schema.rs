@@ -0,0 +1,357 @@
+//! API Schemas
+//!
+//! JSON Schema of the `worker.*` methods and an OpenRPC document of the whole
+//! API, both derived from the request/response types so clients in other
+//! languages (Python, Node, ...) validate against and generate bindings from
+//! exactly what the daemon parses. Served by `worker.schema.v1` and
+//! `rpc.discover`, checked in as `schemas/worker_protocol.json` and
+//! `schemas/openrpc.json`.
+
+use crate::error::code;
+use crate::types::{
+    ArchiveGetRequest, ArchiveGetResponse, ArtifactReadRequest, ArtifactReadResponse,
+    ArtifactsListRequest, ArtifactsListResponse, AuditListRequest, AuditListResponse,
+    CancelBySubjectRequest, CancelBySubjectResponse, CancelRequest, CancelResponse,
+    ConfigGetRequest, ConfigSetRequest, ConfigSetResponse, DeleteJobRequest, DeleteJobResponse,
+    DlqListRequest, DlqListResponse, DlqPurgeRequest, DlqPurgeResponse, DlqRequeueRequest,
+    DlqRequeueResponse, DrainRequest, DrainResponse, EnqueueRequest, EnqueueResponse,
+    FollowLogsRequest, HealthCheckRequest, HealthCheckResponse, InspectRequest, InspectResponse,
+    JobTypeSwitchRequest, JobTypeSwitchResponse, ListJobsRequest, ListJobsResponse,
+    LockdownRequest, LockdownResponse, MaintenanceHistoryRequest, MaintenanceHistoryResponse,
+    MaintenanceRequest, MaintenanceResponse, MetricsHistoryRequest, MetricsHistoryResponse,
+    QueuePauseRequest, QueuePauseResponse, QueuesListRequest, QueuesListResponse,
+    ReportProgressRequest, ReportProgressResponse, RestoreRequest, RestoreResponse,
+    RetryPoliciesRequest, RetryPoliciesResponse, RetryPolicyResetRequest, RetryPolicySetRequest,
+    RetryRequest, RetryResponse, SnapshotRequest, StatsRequest, StatsResponse,
+    SubjectHistoryRequest, SubjectHistoryResponse, SubscribeRequest, TailLogsRequest,
+    TailLogsResponse, ThrottledData, WaitRequest, WaitResponse, WorkerCompleteRequest,
+    WorkerCompleteResponse, WorkerHeartbeatRequest, WorkerHeartbeatResponse, WorkerLeaseRequest,
+    WorkerLeaseResponse,
+};
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::{schema_for, JsonSchema};
+use serde_json::{json, Value};
+
+/// OpenRPC specification version of [`openrpc_document`]
+pub const OPENRPC_VERSION: &str = "1.2.6";
+
+/// Method serving [`openrpc_document`] (reserved by OpenRPC, not listed in it)
+pub const DISCOVER_METHOD: &str = "rpc.discover";
+
+/// Bump on incompatible changes to the worker.* payloads
+pub const WORKER_PROTOCOL_VERSION: u32 = 1;
+
+/// Schema document for all worker.* methods
+///
+/// Layout: `methods.<name>.params` / `methods.<name>.result` are standalone
+/// JSON Schemas (draft-07), `errors` maps JSON-RPC error codes to meanings.
+pub fn worker_protocol_schema() -> Value {
+    json!({
+        "title": "Semantica worker protocol",
+        "version": WORKER_PROTOCOL_VERSION,
+        "transport": "JSON-RPC 2.0 (params as a single positional object)",
+        "methods": {
+            "worker.lease.v1": method::<WorkerLeaseRequest, WorkerLeaseResponse>(),
+            "worker.heartbeat.v1": method::<WorkerHeartbeatRequest, WorkerHeartbeatResponse>(),
+            "worker.complete.v1": method::<WorkerCompleteRequest, WorkerCompleteResponse>(),
+        },
+        "errors": {
+            code::VALIDATION_ERROR.to_string(): "Invalid params (worker_id, lease_ms)",
+            code::NOT_FOUND.to_string(): "Job not found",
+            code::CONFLICT.to_string(): "Lease lost (expired, reassigned, or job cancelled): stop working on the job",
+            code::DB_ERROR.to_string(): "Storage error, retry later",
+        },
+    })
+}
+
+fn method<P: JsonSchema, R: JsonSchema>() -> Value {
+    json!({
+        "params": schema_for!(P),
+        "result": schema_for!(R),
+    })
+}
+
+/// OpenRPC document of every method
+///
+/// Params are passed by name (one JSON object), shared types live under
+/// `components.schemas`. Results typed in semantica-core (runtime settings,
+/// snapshots, maintenance reports, log lines) are described as free-form JSON.
+pub fn openrpc_document() -> Value {
+    let mut gen = SchemaSettings::draft07()
+        .with(|s| s.definitions_path = "#/components/schemas/".to_string())
+        .into_generator();
+
+    let methods = vec![
+        rpc_method::<EnqueueRequest, EnqueueResponse>(&mut gen, "dev.enqueue.v1"),
+        rpc_method::<CancelRequest, CancelResponse>(&mut gen, "dev.cancel.v1"),
+        rpc_method::<CancelBySubjectRequest, CancelBySubjectResponse>(
+            &mut gen,
+            "dev.cancel_by_subject.v1",
+        ),
+        rpc_method::<RetryRequest, RetryResponse>(&mut gen, "job.retry.v1"),
+        rpc_method::<DeleteJobRequest, DeleteJobResponse>(&mut gen, "job.delete.v1"),
+        rpc_method::<ReportProgressRequest, ReportProgressResponse>(
+            &mut gen,
+            "job.report_progress.v1",
+        ),
+        rpc_method::<InspectRequest, InspectResponse>(&mut gen, "dev.inspect.v1"),
+        rpc_method::<ListJobsRequest, ListJobsResponse>(&mut gen, "dev.list.v1"),
+        rpc_method::<WaitRequest, WaitResponse>(&mut gen, "job.wait.v1"),
+        rpc_method::<WorkerLeaseRequest, WorkerLeaseResponse>(&mut gen, "worker.lease.v1"),
+        rpc_method::<WorkerHeartbeatRequest, WorkerHeartbeatResponse>(
+            &mut gen,
+            "worker.heartbeat.v1",
+        ),
+        rpc_method::<WorkerCompleteRequest, WorkerCompleteResponse>(&mut gen, "worker.complete.v1"),
+        with_summary(
+            rpc_method::<(), Value>(&mut gen, "worker.schema.v1"),
+            "JSON Schema of the worker.* methods",
+        ),
+        rpc_method::<ArtifactsListRequest, ArtifactsListResponse>(
+            &mut gen,
+            "job.artifacts.list.v1",
+        ),
+        rpc_method::<ArtifactReadRequest, ArtifactReadResponse>(&mut gen, "job.artifacts.read.v1"),
+        rpc_method::<SubjectHistoryRequest, SubjectHistoryResponse>(&mut gen, "subject.history.v1"),
+        rpc_method::<DlqListRequest, DlqListResponse>(&mut gen, "dlq.list.v1"),
+        rpc_method::<DlqRequeueRequest, DlqRequeueResponse>(&mut gen, "dlq.requeue.v1"),
+        rpc_method::<DlqPurgeRequest, DlqPurgeResponse>(&mut gen, "dlq.purge.v1"),
+        rpc_method::<TailLogsRequest, TailLogsResponse>(&mut gen, "logs.tail.v1"),
+        rpc_method::<HealthCheckRequest, HealthCheckResponse>(&mut gen, "health.check.v1"),
+        rpc_method::<StatsRequest, StatsResponse>(&mut gen, "admin.stats.v1"),
+        rpc_method::<QueuesListRequest, QueuesListResponse>(&mut gen, "admin.queues.list.v1"),
+        rpc_method::<ConfigGetRequest, Value>(&mut gen, "admin.config.get.v1"),
+        rpc_method::<ConfigSetRequest, ConfigSetResponse>(&mut gen, "admin.config.set.v1"),
+        rpc_method::<MaintenanceRequest, MaintenanceResponse>(&mut gen, "admin.maintenance.v1"),
+        rpc_method::<AuditListRequest, AuditListResponse>(&mut gen, "admin.audit.list.v1"),
+        rpc_method::<ArchiveGetRequest, ArchiveGetResponse>(&mut gen, "admin.archive.get.v1"),
+        rpc_method::<MaintenanceHistoryRequest, MaintenanceHistoryResponse>(
+            &mut gen,
+            "admin.maintenance.history.v1",
+        ),
+        rpc_method::<MetricsHistoryRequest, MetricsHistoryResponse>(
+            &mut gen,
+            "admin.metrics.history.v1",
+        ),
+        rpc_method::<SnapshotRequest, Value>(&mut gen, "admin.snapshot.v1"),
+        rpc_method::<RestoreRequest, RestoreResponse>(&mut gen, "admin.restore.v1"),
+        rpc_method::<JobTypeSwitchRequest, JobTypeSwitchResponse>(
+            &mut gen,
+            "admin.job_type.disable.v1",
+        ),
+        rpc_method::<JobTypeSwitchRequest, JobTypeSwitchResponse>(
+            &mut gen,
+            "admin.job_type.enable.v1",
+        ),
+        rpc_method::<RetryPoliciesRequest, RetryPoliciesResponse>(
+            &mut gen,
+            "admin.retry_policy.list.v1",
+        ),
+        rpc_method::<RetryPolicySetRequest, RetryPoliciesResponse>(
+            &mut gen,
+            "admin.retry_policy.set.v1",
+        ),
+        rpc_method::<RetryPolicyResetRequest, RetryPoliciesResponse>(
+            &mut gen,
+            "admin.retry_policy.reset.v1",
+        ),
+        rpc_method::<QueuePauseRequest, QueuePauseResponse>(&mut gen, "admin.queue.pause.v1"),
+        rpc_method::<QueuePauseRequest, QueuePauseResponse>(&mut gen, "admin.queue.resume.v1"),
+        rpc_method::<LockdownRequest, LockdownResponse>(&mut gen, "admin.lockdown.v1"),
+        rpc_method::<DrainRequest, DrainResponse>(&mut gen, "admin.drain.v1"),
+        // WebSocket only: the result is the subscription ID, notifications
+        // (`jobs.state_changed`) carry one state transition each
+        with_summary(
+            rpc_method::<SubscribeRequest, Value>(&mut gen, "jobs.subscribe.v1"),
+            "Subscribe to job state transitions (jobs.state_changed notifications)",
+        ),
+        with_summary(
+            rpc_method::<(), Value>(&mut gen, "jobs.unsubscribe.v1"),
+            "Cancel a jobs.subscribe.v1 subscription",
+        ),
+        // WebSocket only: `logs.line` notifications carry `{"kind": "line", ...}`
+        // and a final `{"kind": "end", "state": ...}` once the job finished
+        with_summary(
+            rpc_method::<FollowLogsRequest, Value>(&mut gen, "logs.follow.v1"),
+            "Stream new lines of a job's log (logs.line notifications)",
+        ),
+        with_summary(
+            rpc_method::<(), Value>(&mut gen, "logs.unfollow.v1"),
+            "Cancel a logs.follow.v1 subscription",
+        ),
+    ];
+
+    // Retry hint sent with THROTTLED errors
+    let throttled_data =
+        serde_json::to_value(gen.subschema_for::<ThrottledData>()).unwrap_or_default();
+
+    json!({
+        "openrpc": OPENRPC_VERSION,
+        "info": {
+            "title": "Semantica Task Engine",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "methods": methods,
+        "components": {
+            "schemas": gen.definitions(),
+            "errors": {
+                "ValidationError": { "code": code::VALIDATION_ERROR, "message": "Invalid params" },
+                "NotFound": { "code": code::NOT_FOUND, "message": "Not found" },
+                "Conflict": { "code": code::CONFLICT, "message": "Conflicts with the current state" },
+                "Throttled": {
+                    "code": code::THROTTLED,
+                    "message": "Rate limited, retry later",
+                    "data": throttled_data,
+                },
+                "InternalError": { "code": code::INTERNAL_ERROR, "message": "Internal error" },
+                "DbError": { "code": code::DB_ERROR, "message": "Storage error, retry later" },
+                "SystemError": { "code": code::SYSTEM_ERROR, "message": "System error" },
+            },
+        },
+    })
+}
+
+/// Whether `name` is listed in [`openrpc_document`] (or serves it)
+pub fn is_documented(name: &str) -> bool {
+    name == DISCOVER_METHOD
+        || openrpc_document()["methods"]
+            .as_array()
+            .is_some_and(|methods| methods.iter().any(|m| m["name"] == name))
+}
+
+fn with_summary(mut method: Value, summary: &str) -> Value {
+    method["summary"] = json!(summary);
+    method
+}
+
+/// OpenRPC method object: one by-name param per field of `P`, result `R`
+fn rpc_method<P: JsonSchema, R: JsonSchema>(gen: &mut SchemaGenerator, name: &str) -> Value {
+    let params_schema = serde_json::to_value(gen.root_schema_for::<P>().schema).unwrap_or_default();
+    let required = &params_schema["required"];
+
+    let params: Vec<Value> = params_schema["properties"]
+        .as_object()
+        .map(|properties| {
+            properties
+                .iter()
+                .map(|(param, schema)| {
+                    json!({
+                        "name": param,
+                        "required": required
+                            .as_array()
+                            .is_some_and(|r| r.iter().any(|r| r == param.as_str())),
+                        "schema": schema,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Request doc comments read "<method> - <summary>", then optional details
+    let doc = params_schema["description"].as_str().unwrap_or_default();
+    let doc = doc.split_once(" - ").map_or(doc, |(_, doc)| doc);
+    let (summary, description) = doc.split_once("\n\n").unwrap_or((doc, ""));
+
+    json!({
+        "name": name,
+        "summary": summary,
+        "description": description,
+        "paramStructure": "by-name",
+        "params": params,
+        "result": {
+            "name": "result",
+            "schema": gen.subschema_for::<R>(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// Regenerate with `SEMANTICA_UPDATE_SCHEMA=1 cargo test -p semantica-api-rpc`
+    fn assert_checked_in(file: &str, schema: &Value) {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("schemas")
+            .join(file);
+        let mut generated = serde_json::to_string_pretty(schema).unwrap();
+        generated.push('\n');
+
+        if std::env::var("SEMANTICA_UPDATE_SCHEMA").is_ok() {
+            std::fs::write(&path, &generated).unwrap();
+        }
+
+        let checked_in = std::fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            checked_in == generated,
+            "schemas/{} is stale: rerun with SEMANTICA_UPDATE_SCHEMA=1",
+            file
+        );
+    }
+
+    #[test]
+    fn test_checked_in_schema_is_current() {
+        assert_checked_in("worker_protocol.json", &worker_protocol_schema());
+    }
+
+    #[test]
+    fn test_checked_in_openrpc_is_current() {
+        assert_checked_in("openrpc.json", &openrpc_document());
+    }
+
+    #[test]
+    fn test_openrpc_refs_resolve() {
+        let document = openrpc_document();
+        let text = document.to_string();
+        let schemas = document["components"]["schemas"].as_object().unwrap();
+
+        for reference in text.split("\"$ref\":\"#/components/schemas/").skip(1) {
+            let name = &reference[..reference.find('"').unwrap()];
+            assert!(schemas.contains_key(name), "unresolved $ref {}", name);
+        }
+    }
+
+    #[test]
+    fn test_openrpc_lists_params_by_name() {
+        let document = openrpc_document();
+        let enqueue = document["methods"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|m| m["name"] == "dev.enqueue.v1")
+            .unwrap();
+        let param = |name: &str| {
+            enqueue["params"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|p| p["name"] == name)
+                .unwrap()
+                .clone()
+        };
+
+        assert_eq!(enqueue["summary"], "Enqueue a job");
+        assert_eq!(param("job_type")["required"], true);
+        assert_eq!(param("traceparent")["required"], false);
+        assert!(is_documented("health.check.v1"));
+        assert!(!is_documented("no.such.method"));
+    }
+
+    #[test]
+    fn test_schema_marks_required_fields() {
+        let schema = worker_protocol_schema();
+        let required = &schema["methods"]["worker.complete.v1"]["params"]["required"];
+        let required: Vec<&str> = required
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_str().unwrap())
+            .collect();
+
+        assert!(required.contains(&"job_id"));
+        assert!(required.contains(&"worker_id"));
+        assert!(required.contains(&"success"));
+        assert!(!required.contains(&"retryable"), "retryable has a default");
+    }
+}
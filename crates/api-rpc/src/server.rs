@@ -1,50 +1,100 @@
 //! JSON-RPC Server
 //!
-//! Implements the JSON-RPC 2.0 server over Unix Domain Socket (macOS/Linux).
+//! Implements the JSON-RPC 2.0 server over TCP localhost and, optionally, a
+//! Unix Domain Socket (macOS/Linux). Both transports serve the same methods
+//! (HTTP and WebSocket), plus the REST gateway for common operations
+//! (see `rest`, behind the `rest` feature).
 
+use crate::audit::AuditLayer;
 use crate::handler::RpcHandler;
+use crate::rate_limiter::QueueRateLimits;
+use crate::request_id::{RequestId, RequestIdLayer};
 use crate::types::{
-    CancelRequest, EnqueueRequest, MaintenanceRequest, StatsRequest, TailLogsRequest,
+    ArchiveGetRequest, ArtifactReadRequest, ArtifactsListRequest, AuditListRequest,
+    CancelBySubjectRequest, CancelRequest, ConfigGetRequest, ConfigSetRequest, DeleteJobRequest,
+    DlqListRequest, DlqPurgeRequest, DlqRequeueRequest, DrainRequest, EnqueueRequest,
+    FollowLogsRequest, HealthCheckRequest, InspectRequest, JobTypeSwitchRequest, ListJobsRequest,
+    LockdownRequest, MaintenanceHistoryRequest, MaintenanceRequest, MetricsHistoryRequest,
+    QueuePauseRequest, QueuesListRequest, ReportProgressRequest, RestoreRequest,
+    RetryPoliciesRequest, RetryPolicyResetRequest, RetryPolicySetRequest, RetryRequest,
+    SnapshotRequest, StatsRequest, SubjectHistoryRequest, SubscribeRequest, TailLogsRequest,
+    WaitRequest, WorkerCompleteRequest, WorkerHeartbeatRequest, WorkerLeaseRequest,
 };
-use jsonrpsee::server::{Server, ServerHandle};
-use jsonrpsee::RpcModule;
+use jsonrpsee::server::{stop_channel, Methods, RpcServiceBuilder, Server, ServerHandle};
+use jsonrpsee::types::ErrorObjectOwned;
+use jsonrpsee::{RpcModule, SubscriptionMessage};
+use semantica_core::application::{
+    DispatchSignal, Drain, InterceptorChain, LeaseService, Lockdown, QueuePauseRegistry,
+    QueueValidators, RetryPolicies, RunningJobs, RuntimeConfig, StateFeed, SubscriptionFilter,
+    WorkerActivity,
+};
+use semantica_core::domain::QueueWaitSlos;
 use semantica_core::port::job_repository::JobRepository;
-use semantica_core::port::{IdProvider, Maintenance, TimeProvider, TransactionalJobRepository};
+use semantica_core::port::{
+    ArtifactStorage, AuditLog, IdProvider, JobArchive, Maintenance, TimeProvider,
+    TransactionalJobRepository,
+};
 use std::path::PathBuf;
 use std::sync::Arc;
-use tracing::info;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, info, warn};
 
 // ADR-020: RPC Server Configuration
-// Note: jsonrpsee doesn't support Unix sockets directly (hyper limitation)
-// Using TCP on localhost as secure alternative (no external access)
+// jsonrpsee only binds TCP itself: the Unix socket listener hands each
+// accepted connection to the same tower service (see serve_unix_socket)
 const DEFAULT_SOCKET_PATH: &str = "~/.semantica/semantica.sock";
 const DEFAULT_RPC_HOST: &str = "127.0.0.1";
 const DEFAULT_RPC_PORT: u16 = 9527;
 
+/// Socket file permissions: owner only (access control for the UDS transport)
+pub const DEFAULT_SOCKET_MODE: u32 = 0o600;
+
 // Security: Request size limits (ADR-040)
 const MAX_REQUEST_BODY_SIZE: u32 = 11_000_000; // 11MB (slightly larger than 10MB payload limit)
 
 /// RPC Server Configuration
 pub struct RpcServerConfig {
-    pub socket_path: PathBuf, // Reserved for future UDS support
+    /// Also serve on this Unix socket (None = TCP only)
+    pub socket_path: Option<PathBuf>,
+    /// Permissions of the socket file; only users allowed by them can connect
+    pub socket_mode: u32,
+    /// Serve on TCP `host:port` (false = Unix socket only)
+    pub tcp: bool,
     pub host: String,
     pub port: u16,
+    /// Already bound TCP socket (systemd socket activation), served instead of `host:port`
+    pub tcp_listener: Option<std::net::TcpListener>,
+    /// Already bound Unix socket (systemd socket activation); its file belongs
+    /// to whoever bound it and is left in place on stop
+    #[cfg(unix)]
+    pub unix_listener: Option<std::os::unix::net::UnixListener>,
 }
 
 impl Default for RpcServerConfig {
     fn default() -> Self {
         Self {
-            socket_path: shellexpand::tilde(DEFAULT_SOCKET_PATH).into_owned().into(),
+            socket_path: None,
+            socket_mode: DEFAULT_SOCKET_MODE,
+            tcp: true,
             host: DEFAULT_RPC_HOST.to_string(),
             port: DEFAULT_RPC_PORT,
+            tcp_listener: None,
+            #[cfg(unix)]
+            unix_listener: None,
         }
     }
 }
 
+/// Default Unix socket path (`~/.semantica/semantica.sock`)
+pub fn default_socket_path() -> PathBuf {
+    shellexpand::tilde(DEFAULT_SOCKET_PATH).into_owned().into()
+}
+
 /// RPC Server
 pub struct RpcServer {
     config: RpcServerConfig,
-    handler: Arc<RpcHandler>,
+    handler: RpcHandler,
+    audit: AuditLayer,
 }
 
 impl RpcServer {
@@ -58,52 +108,166 @@ impl RpcServer {
     ) -> Self {
         Self {
             config,
-            handler: Arc::new(RpcHandler::new(
+            audit: AuditLayer::new(time_provider.clone()),
+            handler: RpcHandler::new(
                 tx_job_repo,
                 job_repo,
                 id_provider,
                 time_provider,
                 maintenance,
-            )),
+            ),
         }
     }
 
+    /// Store audited calls here (admin.audit.list.v1); without it they are only traced
+    pub fn with_audit_log(mut self, audit_log: Arc<dyn AuditLog>) -> Self {
+        self.audit = self.audit.with_store(audit_log.clone());
+        self.handler = self.handler.with_audit_log(audit_log);
+        self
+    }
+
+    /// Delete artifacts of jobs purged with job.delete.v1 from this storage
+    pub fn with_artifact_storage(mut self, storage: Arc<dyn ArtifactStorage>) -> Self {
+        self.handler = self.handler.with_artifact_storage(storage);
+        self
+    }
+
+    /// Directory the executor writes job logs to (logs.follow.v1 of running jobs)
+    pub fn with_log_dir(mut self, log_dir: impl Into<std::path::PathBuf>) -> Self {
+        self.handler = self.handler.with_log_dir(log_dir);
+        self
+    }
+
+    /// Look up GC'd jobs in this archive (admin.archive.get.v1)
+    pub fn with_job_archive(mut self, job_archive: Arc<dyn JobArchive>) -> Self {
+        self.handler = self.handler.with_job_archive(job_archive);
+        self
+    }
+
+    /// Attach an interceptor chain run around every enqueue
+    pub fn with_interceptors(mut self, interceptors: Arc<InterceptorChain>) -> Self {
+        self.handler = self.handler.with_interceptors(interceptors);
+        self
+    }
+
+    /// Attach per-queue payload rules checked on every enqueue
+    pub fn with_queue_validators(mut self, validators: Arc<QueueValidators>) -> Self {
+        self.handler = self.handler.with_queue_validators(validators);
+        self
+    }
+
+    /// Serve worker.* methods with the given lease service (shared with the reaper)
+    pub fn with_lease_service(mut self, lease_service: Arc<LeaseService>) -> Self {
+        self.handler = self.handler.with_lease_service(lease_service);
+        self
+    }
+
+    /// Edit the per-job-type retry rules in this registry (shared with the worker)
+    pub fn with_retry_policies(mut self, retry_policies: Arc<RetryPolicies>) -> Self {
+        self.handler = self.handler.with_retry_policies(retry_policies);
+        self
+    }
+
+    /// Pause / resume queues in this registry (shared with the worker)
+    pub fn with_pause_registry(mut self, pauses: Arc<QueuePauseRegistry>) -> Self {
+        self.handler = self.handler.with_pause_registry(pauses);
+        self
+    }
+
+    /// Engage / lift the subprocess kill switch shared with the worker
+    pub fn with_lockdown(mut self, lockdown: Arc<Lockdown>) -> Self {
+        self.handler = self.handler.with_lockdown(lockdown);
+        self
+    }
+
+    /// Start / end drains of the worker and lease service sharing this switch
+    pub fn with_drain(mut self, drain: Arc<Drain>) -> Self {
+        self.handler = self.handler.with_drain(drain);
+        self
+    }
+
+    /// Serve admin.config.* from the runtime settings shared with the worker
+    pub fn with_runtime_config(mut self, runtime_config: Arc<RuntimeConfig>) -> Self {
+        self.handler = self.handler.with_runtime_config(runtime_config);
+        self
+    }
+
+    /// Show the worker's execution slots in admin.stats.v1
+    pub fn with_worker_activity(mut self, activity: Arc<WorkerActivity>) -> Self {
+        self.handler = self.handler.with_worker_activity(activity);
+        self
+    }
+
+    /// Kill the worker's execution of RUNNING jobs cancelled over RPC
+    pub fn with_running_jobs(mut self, running_jobs: Arc<RunningJobs>) -> Self {
+        self.handler = self.handler.with_running_jobs(running_jobs);
+        self
+    }
+
+    /// Throttle enqueues per queue on top of the global rate limit
+    pub fn with_queue_rate_limits(mut self, queue_rate_limits: QueueRateLimits) -> Self {
+        self.handler = self.handler.with_queue_rate_limits(queue_rate_limits);
+        self
+    }
+
+    /// Report how often these queues missed their wait targets (admin.stats.v1)
+    pub fn with_wait_slos(mut self, wait_slos: QueueWaitSlos) -> Self {
+        self.handler = self.handler.with_wait_slos(wait_slos);
+        self
+    }
+
+    /// Stream state transitions published to this feed (jobs.subscribe.v1)
+    pub fn with_state_feed(mut self, state_feed: Arc<StateFeed>) -> Self {
+        self.handler = self.handler.with_state_feed(state_feed);
+        self
+    }
+
+    /// Signal the worker on enqueue, retry and requeue (shared with the worker)
+    pub fn with_dispatch_signal(mut self, dispatch: Arc<DispatchSignal>) -> Self {
+        self.handler = self.handler.with_dispatch_signal(dispatch);
+        self
+    }
+
     /// Start the JSON-RPC server
     ///
-    /// Note: Uses TCP on localhost (not Unix socket) due to jsonrpsee/hyper limitations
-    /// Security: Only binds to 127.0.0.1 (no external access)
-    pub async fn start(self) -> Result<ServerHandle, String> {
-        let addr = format!("{}:{}", self.config.host, self.config.port);
-
-        info!(
-            host = %self.config.host,
-            port = %self.config.port,
-            "Starting JSON-RPC server on TCP (localhost only)"
-        );
-
-        // Build server with localhost-only binding
-        // Security: Limit request body size to prevent memory exhaustion (ADR-040)
-        let server = Server::builder()
-            .max_request_body_size(MAX_REQUEST_BODY_SIZE)
-            .build(&addr)
-            .await
-            .map_err(|e| format!("Failed to build server on {}: {}", addr, e))?;
+    /// Security: TCP only binds to 127.0.0.1 (no external access); the Unix
+    /// socket is restricted by its file permissions (`socket_mode`)
+    ///
+    /// The returned handle stops every transport.
+    pub async fn start(mut self) -> Result<ServerHandle, String> {
+        #[cfg(unix)]
+        let unix_listener = self.config.unix_listener.take();
+        #[cfg(not(unix))]
+        let unix_listener: Option<()> = None;
+        if !self.config.tcp && self.config.socket_path.is_none() && unix_listener.is_none() {
+            return Err("RPC server needs TCP or a Unix socket path".to_string());
+        }
 
         let mut module = RpcModule::new(());
+        let rpc_handler = Arc::new(
+            self.handler
+                .with_uds(self.config.socket_path.is_some() || unix_listener.is_some()),
+        );
+        // Rate limits changed by admin.config.set.v1 or a config reload (SIGHUP)
+        tokio::spawn(rpc_handler.follow_rate_limits());
 
         // Register methods
-        let handler = self.handler.clone();
+        let handler = rpc_handler.clone();
         module
-            .register_async_method("dev.enqueue.v1", move |params, _, _| {
+            .register_async_method("dev.enqueue.v1", move |params, _, extensions| {
                 let handler = handler.clone();
                 async move {
-                    let req: EnqueueRequest = params.parse()?;
+                    let mut req: EnqueueRequest = params.parse()?;
+                    // Without a caller trace the job's execution joins this request's
+                    if req.traceparent.is_none() {
+                        req.traceparent = extensions.get::<RequestId>().map(RequestId::traceparent);
+                    }
                     handler.enqueue(req).await
                 }
             })
             .map_err(|e| e.to_string())?;
 
-        let handler = self.handler.clone();
+        let handler = rpc_handler.clone();
         module
             .register_async_method("dev.cancel.v1", move |params, _, _| {
                 let handler = handler.clone();
@@ -114,7 +278,123 @@ impl RpcServer {
             })
             .map_err(|e| e.to_string())?;
 
-        let handler = self.handler.clone();
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("dev.cancel_by_subject.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: CancelBySubjectRequest = params.parse()?;
+                    handler.cancel_by_subject(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("job.retry.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: RetryRequest = params.parse()?;
+                    handler.retry(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("job.delete.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: DeleteJobRequest = params.parse()?;
+                    handler.delete_job(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("job.report_progress.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: ReportProgressRequest = params.parse()?;
+                    handler.report_progress(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("dev.inspect.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: InspectRequest = params.parse()?;
+                    handler.inspect(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("dev.list.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: ListJobsRequest = params.parse()?;
+                    handler.list_jobs(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("job.wait.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: WaitRequest = params.parse()?;
+                    handler.wait(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("worker.lease.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: WorkerLeaseRequest = params.parse()?;
+                    handler.worker_lease(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("worker.heartbeat.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: WorkerHeartbeatRequest = params.parse()?;
+                    handler.worker_heartbeat(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("worker.complete.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: WorkerCompleteRequest = params.parse()?;
+                    handler.worker_complete(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        module
+            .register_method("worker.schema.v1", |_, _, _| {
+                Ok::<_, ErrorObjectOwned>(crate::schema::worker_protocol_schema())
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
         module
             .register_async_method("logs.tail.v1", move |params, _, _| {
                 let handler = handler.clone();
@@ -125,8 +405,85 @@ impl RpcServer {
             })
             .map_err(|e| e.to_string())?;
 
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("job.artifacts.list.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: ArtifactsListRequest = params.parse()?;
+                    handler.list_artifacts(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("job.artifacts.read.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: ArtifactReadRequest = params.parse()?;
+                    handler.read_artifact(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("subject.history.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: SubjectHistoryRequest = params.parse()?;
+                    handler.subject_history(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("dlq.list.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: DlqListRequest = params.parse()?;
+                    handler.dlq_list(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("dlq.requeue.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: DlqRequeueRequest = params.parse()?;
+                    handler.dlq_requeue(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("dlq.purge.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: DlqPurgeRequest = params.parse()?;
+                    handler.dlq_purge(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("health.check.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: HealthCheckRequest = params.parse()?;
+                    handler.health_check(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
         // Admin APIs (Phase 4)
-        let handler = self.handler.clone();
+        let handler = rpc_handler.clone();
         module
             .register_async_method("admin.stats.v1", move |params, _, _| {
                 let handler = handler.clone();
@@ -137,7 +494,40 @@ impl RpcServer {
             })
             .map_err(|e| e.to_string())?;
 
-        let handler = self.handler.clone();
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.queues.list.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: QueuesListRequest = params.parse()?;
+                    handler.list_queues(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.config.get.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: ConfigGetRequest = params.parse()?;
+                    handler.config_get(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.config.set.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: ConfigSetRequest = params.parse()?;
+                    handler.config_set(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
         module
             .register_async_method("admin.maintenance.v1", move |params, _, _| {
                 let handler = handler.clone();
@@ -148,9 +538,493 @@ impl RpcServer {
             })
             .map_err(|e| e.to_string())?;
 
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.maintenance.history.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: MaintenanceHistoryRequest = params.parse()?;
+                    handler.maintenance_history(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.metrics.history.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: MetricsHistoryRequest = params.parse()?;
+                    handler.metrics_history(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.archive.get.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: ArchiveGetRequest = params.parse()?;
+                    handler.archive_get(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.audit.list.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: AuditListRequest = params.parse()?;
+                    handler.audit_list(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.snapshot.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: SnapshotRequest = params.parse()?;
+                    handler.snapshot(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.restore.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: RestoreRequest = params.parse()?;
+                    handler.restore(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.job_type.disable.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: JobTypeSwitchRequest = params.parse()?;
+                    handler.set_job_type_disabled(req, true).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.job_type.enable.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: JobTypeSwitchRequest = params.parse()?;
+                    handler.set_job_type_disabled(req, false).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.retry_policy.list.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: RetryPoliciesRequest = params.parse()?;
+                    handler.retry_policies(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.retry_policy.set.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: RetryPolicySetRequest = params.parse()?;
+                    handler.set_retry_policy(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.retry_policy.reset.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: RetryPolicyResetRequest = params.parse()?;
+                    handler.reset_retry_policy(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.queue.pause.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: QueuePauseRequest = params.parse()?;
+                    handler.set_queue_paused(req, true).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.queue.resume.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: QueuePauseRequest = params.parse()?;
+                    handler.set_queue_paused(req, false).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.lockdown.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: LockdownRequest = params.parse()?;
+                    handler.lockdown(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        let handler = rpc_handler.clone();
+        module
+            .register_async_method("admin.drain.v1", move |params, _, _| {
+                let handler = handler.clone();
+                async move {
+                    let req: DrainRequest = params.parse()?;
+                    handler.drain(req).await
+                }
+            })
+            .map_err(|e| e.to_string())?;
+
+        // Push: one `jobs.state_changed` notification per transition (WebSocket only)
+        let handler = rpc_handler.clone();
+        module
+            .register_subscription(
+                "jobs.subscribe.v1",
+                "jobs.state_changed",
+                "jobs.unsubscribe.v1",
+                move |params, pending, _, _| {
+                    let mut changes = handler.subscribe_state_changes();
+                    // No params = every event
+                    let filter: Result<SubscribeRequest, _> = match params.as_str() {
+                        None => Ok(SubscribeRequest::default()),
+                        Some(_) => params.parse(),
+                    };
+                    async move {
+                        let filter = match filter {
+                            Ok(filter) => SubscriptionFilter::from(filter),
+                            Err(e) => {
+                                pending.reject(e).await;
+                                return Ok(());
+                            }
+                        };
+                        let sink = pending.accept().await?;
+                        loop {
+                            let change = match changes.recv().await {
+                                Ok(change) => change,
+                                Err(RecvError::Lagged(missed)) => {
+                                    warn!(missed, "State subscriber lagging, events dropped");
+                                    continue;
+                                }
+                                Err(RecvError::Closed) => return Ok(()),
+                            };
+                            if !filter.matches(&change) {
+                                continue;
+                            }
+                            sink.send(SubscriptionMessage::from_json(&change)?).await?;
+                        }
+                    }
+                },
+            )
+            .map_err(|e| e.to_string())?;
+
+        // Push: `logs.line` notifications until the job finished (WebSocket only)
+        let handler = rpc_handler.clone();
+        module
+            .register_subscription(
+                "logs.follow.v1",
+                "logs.line",
+                "logs.unfollow.v1",
+                move |params, pending, _, _| {
+                    let handler = handler.clone();
+                    async move {
+                        let follower = match params.parse::<FollowLogsRequest>() {
+                            Ok(req) => handler.follow_logs(req).await,
+                            Err(e) => Err(e),
+                        };
+                        let mut follower = match follower {
+                            Ok(follower) => follower,
+                            Err(e) => {
+                                pending.reject(e).await;
+                                return Ok(());
+                            }
+                        };
+                        let sink = pending.accept().await?;
+                        loop {
+                            let events = tokio::select! {
+                                events = follower.next() => events,
+                                _ = sink.closed() => return Ok(()),
+                            };
+                            let Some(events) = events else {
+                                return Ok(());
+                            };
+                            for event in events {
+                                sink.send(SubscriptionMessage::from_json(&event)?).await?;
+                            }
+                        }
+                    }
+                },
+            )
+            .map_err(|e| e.to_string())?;
+
+        // Served last so it can describe everything registered above
+        let document = crate::schema::openrpc_document();
+        module
+            .register_method(crate::schema::DISCOVER_METHOD, move |_, _, _| {
+                Ok::<_, ErrorObjectOwned>(document.clone())
+            })
+            .map_err(|e| e.to_string())?;
+
+        let undocumented: Vec<&str> = module
+            .method_names()
+            .filter(|name| !crate::schema::is_documented(name))
+            .collect();
+        if !undocumented.is_empty() {
+            warn!(methods = ?undocumented, "RPC methods missing from the OpenRPC document");
+        }
+        debug_assert!(
+            undocumented.is_empty(),
+            "add {:?} to schema::openrpc_document",
+            undocumented
+        );
+
+        let methods: Methods = module.into();
+        let (stop_handle, server_handle) = stop_channel();
+
+        if let Some(socket_path) = &self.config.socket_path {
+            serve_unix_socket(
+                socket_path,
+                self.config.socket_mode,
+                methods.clone(),
+                stop_handle.clone(),
+                self.audit.clone(),
+            )
+            .await?;
+        }
+        #[cfg(unix)]
+        if let Some(listener) = unix_listener {
+            serve_unix_listener(
+                listener,
+                methods.clone(),
+                stop_handle.clone(),
+                self.audit.clone(),
+            )?;
+        }
+
+        if self.config.tcp {
+            // Build server with localhost-only binding
+            // Security: Limit request body size to prevent memory exhaustion (ADR-040)
+            let builder = Server::builder()
+                .max_request_body_size(MAX_REQUEST_BODY_SIZE)
+                .set_http_middleware(rest_gateway())
+                .set_rpc_middleware(rpc_middleware(self.audit.clone()));
+            let server = match self.config.tcp_listener.take() {
+                // Bound by whoever passed it: its address is theirs to choose
+                Some(listener) => {
+                    let addr = listener
+                        .local_addr()
+                        .map_err(|e| format!("Invalid TCP listener: {}", e))?;
+                    info!(%addr, "Starting JSON-RPC server on a passed TCP socket");
+                    listener
+                        .set_nonblocking(true)
+                        .and_then(|()| builder.build_from_tcp(listener))
+                        .map_err(|e| format!("Failed to build server on {}: {}", addr, e))?
+                }
+                None => {
+                    let addr = format!("{}:{}", self.config.host, self.config.port);
+                    info!(
+                        host = %self.config.host,
+                        port = %self.config.port,
+                        "Starting JSON-RPC server on TCP (localhost only)"
+                    );
+                    builder
+                        .build(&addr)
+                        .await
+                        .map_err(|e| format!("Failed to build server on {}: {}", addr, e))?
+                }
+            };
+            let tcp_handle = server.start(methods);
+
+            // One handle for both transports
+            tokio::spawn(async move {
+                stop_handle.shutdown().await;
+                let _ = tcp_handle.stop();
+            });
+        }
+
         info!("JSON-RPC server started successfully");
+        Ok(server_handle)
+    }
+}
+
+/// RPC middleware of every transport: request IDs, then the audit log
+fn rpc_middleware(
+    audit: AuditLayer,
+) -> RpcServiceBuilder<
+    tower::layer::util::Stack<
+        AuditLayer,
+        tower::layer::util::Stack<RequestIdLayer, tower::layer::util::Identity>,
+    >,
+> {
+    RpcServiceBuilder::new().layer(RequestIdLayer).layer(audit)
+}
 
-        let handle = server.start(module);
-        Ok(handle)
+/// HTTP middleware serving the REST routes on every transport
+#[cfg(feature = "rest")]
+fn rest_gateway() -> tower::ServiceBuilder<
+    tower::layer::util::Stack<crate::rest::RestGatewayLayer, tower::layer::util::Identity>,
+> {
+    tower::ServiceBuilder::new().layer(crate::rest::RestGatewayLayer::new(MAX_REQUEST_BODY_SIZE))
+}
+
+/// Built without the REST gateway: JSON-RPC only
+#[cfg(not(feature = "rest"))]
+fn rest_gateway() -> tower::ServiceBuilder<tower::layer::util::Identity> {
+    tower::ServiceBuilder::new()
+}
+
+/// Serve `methods` on a Unix socket until `stop_handle` fires
+///
+/// A stale socket file left by a crashed daemon is replaced; a live one
+/// (another daemon answering) is an error. The file is removed on stop.
+#[cfg(unix)]
+async fn serve_unix_socket(
+    path: &std::path::Path,
+    mode: u32,
+    methods: Methods,
+    stop_handle: jsonrpsee::server::StopHandle,
+    audit: AuditLayer,
+) -> Result<(), String> {
+    use std::os::unix::fs::PermissionsExt;
+    use tokio::net::{UnixListener, UnixStream};
+
+    if path.exists() {
+        if UnixStream::connect(path).await.is_ok() {
+            return Err(format!("Another daemon is listening on {}", path.display()));
+        }
+        std::fs::remove_file(path)
+            .map_err(|e| format!("Failed to remove stale socket {}: {}", path.display(), e))?;
     }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+
+    let listener = UnixListener::bind(path)
+        .map_err(|e| format!("Failed to bind {}: {}", path.display(), e))?;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(mode))
+        .map_err(|e| format!("Failed to set permissions of {}: {}", path.display(), e))?;
+
+    info!(path = %path.display(), mode = format!("{:o}", mode), "Starting JSON-RPC server on Unix socket");
+    spawn_unix_accept_loop(
+        listener,
+        Some(path.to_path_buf()),
+        methods,
+        stop_handle,
+        audit,
+    );
+    Ok(())
+}
+
+/// Serve `methods` on an already bound Unix socket (its file is left alone)
+#[cfg(unix)]
+fn serve_unix_listener(
+    listener: std::os::unix::net::UnixListener,
+    methods: Methods,
+    stop_handle: jsonrpsee::server::StopHandle,
+    audit: AuditLayer,
+) -> Result<(), String> {
+    let listener = listener
+        .set_nonblocking(true)
+        .and_then(|()| tokio::net::UnixListener::from_std(listener))
+        .map_err(|e| format!("Invalid Unix socket listener: {}", e))?;
+    let path = listener
+        .local_addr()
+        .ok()
+        .and_then(|addr| addr.as_pathname().map(|path| path.display().to_string()));
+    info!(path = ?path, "Starting JSON-RPC server on a passed Unix socket");
+    spawn_unix_accept_loop(listener, None, methods, stop_handle, audit);
+    Ok(())
+}
+
+/// Accept connections until `stop_handle` fires, then remove `owned_path`
+#[cfg(unix)]
+fn spawn_unix_accept_loop(
+    listener: tokio::net::UnixListener,
+    owned_path: Option<PathBuf>,
+    methods: Methods,
+    stop_handle: jsonrpsee::server::StopHandle,
+    audit: AuditLayer,
+) {
+    let service_builder = Server::builder()
+        .max_request_body_size(MAX_REQUEST_BODY_SIZE)
+        .set_http_middleware(rest_gateway())
+        .set_rpc_middleware(rpc_middleware(audit))
+        .to_service_builder();
+
+    tokio::spawn(async move {
+        loop {
+            let stream = tokio::select! {
+                accepted = listener.accept() => match accepted {
+                    Ok((stream, _)) => stream,
+                    Err(e) => {
+                        warn!(error = %e, "Unix socket accept failed");
+                        continue;
+                    }
+                },
+                _ = stop_handle.clone().shutdown() => break,
+            };
+
+            let service = service_builder
+                .clone()
+                .build(methods.clone(), stop_handle.clone());
+            let stopped = stop_handle.clone().shutdown();
+            tokio::spawn(async move {
+                if let Err(e) =
+                    jsonrpsee::server::serve_with_graceful_shutdown(stream, service, stopped).await
+                {
+                    debug!(error = %e, "Unix socket connection closed with error");
+                }
+            });
+        }
+
+        match owned_path {
+            Some(path) => {
+                let _ = std::fs::remove_file(&path);
+                info!(path = %path.display(), "Unix socket closed");
+            }
+            None => info!("Unix socket closed"),
+        }
+    });
+}
+
+#[cfg(not(unix))]
+async fn serve_unix_socket(
+    path: &std::path::Path,
+    _mode: u32,
+    _methods: Methods,
+    _stop_handle: jsonrpsee::server::StopHandle,
+    _audit: AuditLayer,
+) -> Result<(), String> {
+    Err(format!(
+        "Unix socket {} is not supported on this platform",
+        path.display()
+    ))
 }
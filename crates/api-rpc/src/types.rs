@@ -2,10 +2,19 @@
 //!
 //! Defines the JSON-RPC method parameters and results (ADR-020).
 
+use schemars::JsonSchema;
+use semantica_core::application::dev_task::subject_history;
+use semantica_core::application::{RetryRule, RuntimeSettings, SubscriptionFilter, UpdateKind};
+use semantica_core::domain::{
+    Artifact, DeadLetter, JobProgress, JobTypeStats, LogLine, LogStream, MetricsSnapshot,
+    QueueSnapshot, WaitSloReport,
+};
+use semantica_core::port::{AuditEntry, GcPreview, MaintenanceReport};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// dev.enqueue.v1 - Enqueue a job
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct EnqueueRequest {
     pub job_type: String,
     pub queue: String,
@@ -13,9 +22,55 @@ pub struct EnqueueRequest {
     pub payload: serde_json::Value,
     #[serde(default)]
     pub priority: i32,
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
+    /// W3C traceparent (`00-<trace-id>-<span-id>-<flags>`) linking the job to the caller's trace
+    #[serde(default)]
+    pub traceparent: Option<String>,
+    /// TASK (default) or SERVICE: runs indefinitely, restarted with backoff when it exits
+    #[serde(default)]
+    pub job_class: Option<String>,
+    /// Payloads of the retries, in order (attempt 2 runs the first one, the
+    /// last one is reused once they run out), e.g. a full re-index after an
+    /// incremental one failed
+    #[serde(default)]
+    pub fallback_payloads: Vec<serde_json::Value>,
+    /// Earliest start (epoch ms)
+    #[serde(default)]
+    pub schedule_at: Option<i64>,
+    /// Epoch ms after which the job is skipped (SKIPPED_DEADLINE); must be after the start
+    #[serde(default)]
+    pub deadline: Option<i64>,
+    /// Skip the job (SKIPPED_TTL) if it is still queued this long after enqueue
+    #[serde(default)]
+    pub ttl_ms: Option<i64>,
+    /// Total attempts including the first (1-100, default 3)
+    #[serde(default)]
+    pub max_attempts: Option<i32>,
+    /// Retry delay multiplier per attempt (1.0-10.0, default 2.0)
+    #[serde(default)]
+    pub backoff_factor: Option<f64>,
+    /// Run only while the system is idle
+    #[serde(default)]
+    pub wait_for_idle: bool,
+    /// Run only while on AC power
+    #[serde(default)]
+    pub require_charging: bool,
+    /// User-defined tag (filter with dev.list.v1)
+    #[serde(default)]
+    pub user_tag: Option<String>,
+    /// IN_PROCESS (default) or SUBPROCESS
+    #[serde(default)]
+    pub execution_mode: Option<String>,
+    /// Job this one was spawned by
+    #[serde(default)]
+    pub parent_job_id: Option<String>,
+    /// Chain/batch group identifier
+    #[serde(default)]
+    pub chain_group_id: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct EnqueueResponse {
     pub job_id: String,
     pub state: String,
@@ -23,65 +78,1232 @@ pub struct EnqueueResponse {
 }
 
 /// dev.cancel.v1 - Cancel a job
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct CancelRequest {
     pub job_id: String,
+    /// Free-form reason recorded in the job's event history
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// Who is cancelling (e.g. "cli", "sdk:<client_id>"); defaults to "rpc"
+    #[serde(default)]
+    pub actor: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct CancelResponse {
     pub job_id: String,
     pub cancelled: bool,
+    /// The job was executing in this daemon's worker
+    pub was_running: bool,
+    /// Its execution is over (subprocess killed and reaped, or aborted)
+    pub terminated: bool,
+    /// Subprocess that was killed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+}
+
+/// dev.cancel_by_subject.v1 - Cancel QUEUED jobs whose subject_key matches a glob
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CancelBySubjectRequest {
+    /// Glob over subject_key (`*`, `?`, `[...]`), e.g. `src/foo.rs::*`
+    pub subject_pattern: String,
+    /// Restrict to one queue (None = every queue)
+    #[serde(default)]
+    pub queue: Option<String>,
+    #[serde(default)]
+    pub reason: Option<String>,
+    #[serde(default)]
+    pub actor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct CancelBySubjectResponse {
+    pub cancelled: usize,
+    pub job_ids: Vec<String>,
+}
+
+/// job.retry.v1 - Re-run a FAILED job (attempts reset, history kept)
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RetryRequest {
+    pub job_id: String,
+    /// Free-form reason recorded in the job's event history
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// Who is retrying (e.g. "cli", "sdk:<client_id>"); defaults to "rpc"
+    #[serde(default)]
+    pub actor: Option<String>,
+    /// Corrected payload: enqueue a copy of the finished job with it (linked
+    /// by parent_job_id) instead of requeueing the job itself
+    #[serde(default)]
+    pub payload: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct RetryResponse {
+    /// The requeued job, or the corrected copy
+    pub job_id: String,
+    pub state: String,
+    /// Original job of a corrected copy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_job_id: Option<String>,
+}
+
+/// job.delete.v1 - Purge one job with its history, artifacts and log
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DeleteJobRequest {
+    pub job_id: String,
+    /// Also delete a QUEUED job (RUNNING jobs must be cancelled first)
+    #[serde(default)]
+    pub force: bool,
+    /// Who is deleting (recorded in the audit log); defaults to "rpc"
+    #[serde(default)]
+    pub actor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DeleteJobResponse {
+    pub job_id: String,
+    /// State the job was in
+    pub state: String,
+    pub log_deleted: bool,
+    pub artifacts_deleted: usize,
+    /// Log file or artifacts that could not be removed (delete them by hand)
+    pub leftovers: Vec<String>,
+}
+
+/// job.report_progress.v1 - Report how far a RUNNING job got
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ReportProgressRequest {
+    pub job_id: String,
+    /// 0-100
+    pub percent: u8,
+    /// Short status line (e.g. "parsed 400/1000 files", max 512 chars)
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ReportProgressResponse {
+    pub job_id: String,
+    pub percent: u8,
+    pub updated_at: i64,
+}
+
+/// Last progress reported by a job
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct JobProgressInfo {
+    pub percent: u8,
+    pub message: Option<String>,
+    pub updated_at: i64,
+}
+
+impl From<JobProgress> for JobProgressInfo {
+    fn from(progress: JobProgress) -> Self {
+        Self {
+            percent: progress.percent,
+            message: progress.message,
+            updated_at: progress.updated_at,
+        }
+    }
+}
+
+/// dev.inspect.v1 - Job details with event history
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct InspectRequest {
+    pub job_id: String,
+    /// Also return the payload (e.g. to edit it for a corrected retry)
+    #[serde(default)]
+    pub include_payload: bool,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct InspectResponse {
+    pub job_id: String,
+    pub job_type: String,
+    pub queue: String,
+    pub subject_key: String,
+    pub generation: i64,
+    pub state: String,
+    pub priority: i32,
+    pub attempts: i32,
+    /// Executions that panicked (the job is quarantined at the daemon's limit)
+    pub panic_count: i32,
+    /// QUEUED retry waiting out its backoff until then (epoch ms)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_attempt_at: Option<i64>,
+    pub created_at: i64,
+    pub started_at: Option<i64>,
+    pub finished_at: Option<i64>,
+    /// TASK or SERVICE
+    pub job_class: String,
+    /// Fallback payloads declared at enqueue (retry N runs fallback N)
+    pub fallback_payloads: usize,
+    /// Trace context supplied at enqueue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub traceparent: Option<String>,
+    /// Only with `include_payload`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<serde_json::Value>,
+    /// Last progress reported while RUNNING (cleared when popped again)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<JobProgressInfo>,
+    /// Oldest first
+    pub events: Vec<JobEventInfo>,
+}
+
+/// dev.list.v1 - Jobs matching a filter, oldest first (cursor pagination)
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListJobsRequest {
+    #[serde(default)]
+    pub queue: Option<String>,
+    /// QUEUED, RUNNING, DONE, FAILED, ...
+    #[serde(default)]
+    pub state: Option<String>,
+    #[serde(default)]
+    pub subject_key: Option<String>,
+    /// `next_cursor` of the previous page
+    #[serde(default)]
+    pub after: Option<String>,
+    /// Page size (default 50, max 500)
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ListJobsResponse {
+    pub jobs: Vec<JobSummary>,
+    /// Cursor of the next page (None = last page)
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct JobSummary {
+    pub job_id: String,
+    pub job_type: String,
+    pub queue: String,
+    pub subject_key: String,
+    pub state: String,
+    pub priority: i32,
+    pub attempts: i32,
+    pub created_at: i64,
+    pub started_at: Option<i64>,
+    pub finished_at: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<JobProgressInfo>,
+}
+
+/// job.wait.v1 - Block until a job is finished
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct WaitRequest {
+    pub job_id: String,
+    /// Give up after this long (default 25s, max 5min)
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct WaitResponse {
+    pub job_id: String,
+    pub state: String,
+    /// false = timed out before the job finished (`state` is the current one)
+    pub terminal: bool,
+    pub result_summary: Option<String>,
+    pub finished_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct JobEventInfo {
+    pub state: String,
+    pub actor: String,
+    pub reason: Option<String>,
+    pub created_at: i64,
 }
 
 /// logs.tail.v1 - Tail job logs
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct TailLogsRequest {
     pub job_id: String,
     #[serde(default = "default_lines")]
     pub lines: usize,
+    /// Only lines of this stream ("stdout" | "stderr"), None = both
+    #[serde(default)]
+    #[schemars(with = "Option<String>")]
+    pub stream: Option<LogStream>,
 }
 
 fn default_lines() -> usize {
     50
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct TailLogsResponse {
     pub job_id: String,
     pub log_path: Option<String>,
+    /// Line text (untagged lines from older logs included when not filtering)
     pub lines: Vec<String>,
+    /// Tagged lines with stream and timestamp
+    #[schemars(with = "Vec<serde_json::Value>")]
+    pub entries: Vec<LogLine>,
+}
+
+/// job.artifacts.list.v1 - Artifacts registered for a job
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ArtifactsListRequest {
+    pub job_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ArtifactInfo {
+    pub uri: String,
+    /// Unknown (None) for artifacts registered before metadata was recorded
+    pub size_bytes: Option<u64>,
+    /// SHA-256 of the content (hex)
+    pub checksum: Option<String>,
+    pub created_at: Option<i64>,
+}
+
+impl From<Artifact> for ArtifactInfo {
+    fn from(artifact: Artifact) -> Self {
+        Self {
+            uri: artifact.uri,
+            size_bytes: artifact.size_bytes,
+            checksum: artifact.checksum,
+            created_at: artifact.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ArtifactsListResponse {
+    pub job_id: String,
+    pub artifacts: Vec<ArtifactInfo>,
+}
+
+/// job.artifacts.read.v1 - Bounded chunk of an artifact's content
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ArtifactReadRequest {
+    pub job_id: String,
+    /// URI as listed by job.artifacts.list.v1
+    pub uri: String,
+    #[serde(default)]
+    pub offset: u64,
+    /// Chunk size (default 65536, max 1048576)
+    #[serde(default)]
+    pub max_bytes: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ArtifactReadResponse {
+    pub job_id: String,
+    pub artifact: ArtifactInfo,
+    pub offset: u64,
+    /// Length of this chunk in bytes
+    pub len: usize,
+    /// Chunk content, base64 (standard alphabet, padded)
+    pub data: String,
+    /// No content follows this chunk (read again from `offset + len` otherwise)
+    pub eof: bool,
+}
+
+/// subject.history.v1 - Recent successful results of a subject
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SubjectHistoryRequest {
+    pub subject_key: String,
+    /// Entries to return, newest first (default 10, max 1000)
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct SubjectHistoryEntry {
+    pub generation: i64,
+    pub job_id: String,
+    /// SHA-256 of the job's stdout (hex), None if no output was captured
+    pub result_hash: Option<String>,
+    pub duration_ms: i64,
+    pub finished_at: i64,
+    /// Result differs from the previous run (None if unknown)
+    pub changed: Option<bool>,
+}
+
+impl From<subject_history::SubjectHistoryEntry> for SubjectHistoryEntry {
+    fn from(entry: subject_history::SubjectHistoryEntry) -> Self {
+        Self {
+            generation: entry.result.generation,
+            job_id: entry.result.job_id,
+            result_hash: entry.result.result_hash,
+            duration_ms: entry.result.duration_ms,
+            finished_at: entry.result.finished_at,
+            changed: entry.changed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct SubjectHistoryResponse {
+    pub subject_key: String,
+    pub entries: Vec<SubjectHistoryEntry>,
+}
+
+/// dlq.list.v1 - Jobs that failed for good, newest first
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DlqListRequest {
+    /// Only this queue (None = all)
+    #[serde(default)]
+    pub queue: Option<String>,
+    /// Entries to return (default 50, max 1000)
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DeadLetterInfo {
+    pub job_id: String,
+    pub queue: String,
+    pub job_type: String,
+    pub subject_key: String,
+    pub attempts: i32,
+    /// Error of the last attempt (truncated)
+    pub last_error: String,
+    pub dead_at: i64,
+}
+
+impl From<DeadLetter> for DeadLetterInfo {
+    fn from(entry: DeadLetter) -> Self {
+        Self {
+            job_id: entry.job_id,
+            queue: entry.queue,
+            job_type: entry.job_type,
+            subject_key: entry.subject_key,
+            attempts: entry.attempts,
+            last_error: entry.last_error,
+            dead_at: entry.dead_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DlqListResponse {
+    pub dead_letters: Vec<DeadLetterInfo>,
+}
+
+/// dlq.requeue.v1 - Re-run dead-lettered jobs (like job.retry.v1)
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DlqRequeueRequest {
+    /// Jobs to requeue (ignored with `all`)
+    #[serde(default)]
+    pub job_ids: Vec<String>,
+    /// Requeue every dead-lettered job (of `queue` if set)
+    #[serde(default)]
+    pub all: bool,
+    #[serde(default)]
+    pub queue: Option<String>,
+    /// Free-form reason recorded in the jobs' event history
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// Who is requeueing; defaults to "rpc"
+    #[serde(default)]
+    pub actor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DlqRequeueError {
+    pub job_id: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DlqRequeueResponse {
+    pub requeued: Vec<String>,
+    /// Jobs that could not be requeued (e.g. not FAILED anymore)
+    pub errors: Vec<DlqRequeueError>,
+}
+
+/// dlq.purge.v1 - Drop dead-letter entries (the jobs themselves are kept)
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DlqPurgeRequest {
+    /// Only this queue (None = all)
+    #[serde(default)]
+    pub queue: Option<String>,
+    /// Only entries dead before this timestamp (ms)
+    #[serde(default)]
+    pub before: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DlqPurgeResponse {
+    pub purged: u64,
+}
+
+/// logs.follow.v1 - Stream a job's log as it is written
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct FollowLogsRequest {
+    pub job_id: String,
+    /// Lines already written to send first (like `tail -f`)
+    #[serde(default = "default_follow_lines")]
+    pub lines: usize,
+    /// Only lines of this stream ("stdout" | "stderr"), None = both
+    #[serde(default)]
+    #[schemars(with = "Option<String>")]
+    pub stream: Option<LogStream>,
+}
+
+fn default_follow_lines() -> usize {
+    10
+}
+
+/// logs.follow.v1 - One `logs.line` notification
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum LogFollowEvent {
+    /// A line of the log (entry is None for untagged lines from older logs)
+    Line {
+        line: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        entry: Option<LogLine>,
+    },
+    /// The job finished, the subscription closes after this event
+    End { state: String },
+}
+
+/// jobs.subscribe.v1 - Server-side filter of a subscription (unset = everything)
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct SubscribeRequest {
+    #[serde(default)]
+    pub queue: Option<String>,
+    #[serde(default)]
+    pub job_type: Option<String>,
+    /// Jobs whose subject_key starts with this prefix
+    #[serde(default)]
+    pub subject_prefix: Option<String>,
+    /// Jobs with this user_tag
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Jobs of this chain (chain_group_id)
+    #[serde(default)]
+    pub chain: Option<String>,
+    /// Event kinds to deliver ("state" | "progress"), None = all
+    #[serde(default)]
+    #[schemars(with = "Option<Vec<String>>")]
+    pub kinds: Option<Vec<UpdateKind>>,
+}
+
+impl From<SubscribeRequest> for SubscriptionFilter {
+    fn from(req: SubscribeRequest) -> Self {
+        Self {
+            queue: req.queue,
+            job_type: req.job_type,
+            subject_prefix: req.subject_prefix,
+            tag: req.tag,
+            chain: req.chain,
+            kinds: req.kinds,
+        }
+    }
 }
 
 /// admin.stats.v1 - Get system statistics
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct StatsRequest {
     // No parameters needed
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct StatsResponse {
     pub total_jobs: i64,
     pub queued_jobs: i64,
     pub running_jobs: i64,
     pub done_jobs: i64,
     pub failed_jobs: i64,
+    /// SKIPPED_TTL + SKIPPED_DEADLINE (not included in failed_jobs)
+    pub expired_jobs: i64,
     pub db_size_bytes: i64,
     pub uptime_seconds: i64,
+    /// Latest applied DB migration
+    pub schema_version: i64,
+    pub engine_version: String,
+    pub capabilities: EngineCapabilities,
+    /// Worker execution slots, by index (empty when no worker is attached)
+    pub slots: Vec<WorkerSlot>,
+    /// Times the worker loop was restarted after terminating unexpectedly
+    pub worker_restarts: u64,
+    /// QUEUED/RUNNING service jobs (not counted in the slots)
+    pub services: Vec<ServiceInfo>,
+    /// Queue wait SLO compliance over the last 24h, per queue with a target
+    /// (SEMANTICA_QUEUE_WAIT_SLOS)
+    pub wait_slos: Vec<WaitSloInfo>,
+    /// Counts, failure rate and mean run time per job type (every queue,
+    /// jobs not yet garbage collected)
+    pub job_types: Vec<JobTypeStatsInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct JobTypeStatsInfo {
+    pub job_type: String,
+    pub queued: i64,
+    pub running: i64,
+    pub done: i64,
+    pub failed: i64,
+    /// SKIPPED_TTL + SKIPPED_DEADLINE
+    pub expired: i64,
+    /// FAILED / (DONE + FAILED), 0.0 when none finished
+    pub failure_rate: f64,
+    /// Mean run time of DONE and FAILED jobs
+    pub avg_duration_ms: Option<f64>,
+}
+
+impl From<JobTypeStats> for JobTypeStatsInfo {
+    fn from(stats: JobTypeStats) -> Self {
+        Self {
+            failure_rate: stats.failure_rate(),
+            job_type: stats.job_type,
+            queued: stats.queued,
+            running: stats.running,
+            done: stats.done,
+            failed: stats.failed,
+            expired: stats.expired,
+            avg_duration_ms: stats.avg_duration_ms,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct WaitSloInfo {
+    pub queue: String,
+    /// A due job should start within this many ms
+    pub target_ms: i64,
+    /// Jobs started in the window
+    pub started: i64,
+    /// Started jobs that waited longer than the target
+    pub violations: i64,
+    /// QUEUED jobs already waiting longer than the target
+    pub waiting_over: i64,
+    /// Share of started jobs that met the target (1.0 when none started)
+    pub met_ratio: f64,
+    /// Longest wait of an offending job
+    pub max_wait_ms: Option<i64>,
+    /// Offending jobs, longest wait first (at most 20)
+    pub job_ids: Vec<String>,
+}
+
+impl From<WaitSloReport> for WaitSloInfo {
+    fn from(report: WaitSloReport) -> Self {
+        let met_ratio = if report.started > 0 {
+            (report.started - report.violations) as f64 / report.started as f64
+        } else {
+            1.0
+        };
+        Self {
+            queue: report.queue,
+            target_ms: report.target_ms,
+            started: report.started,
+            violations: report.violations,
+            waiting_over: report.waiting_over,
+            met_ratio,
+            max_wait_ms: report.max_wait_ms,
+            job_ids: report.job_ids,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct WorkerSlot {
+    pub slot: usize,
+    /// None = idle
+    pub job_id: Option<String>,
+    pub job_type: Option<String>,
+    pub subject_key: Option<String>,
+    /// How long the current job has occupied the slot
+    pub running_ms: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ServiceInfo {
+    pub job_id: String,
+    pub job_type: String,
+    pub subject_key: String,
+    /// RUNNING, or QUEUED while waiting for its restart
+    pub state: String,
+    /// Consecutive quick exits (reset by a stable run)
+    pub restarts: i32,
+    pub started_at: Option<i64>,
+    /// When a QUEUED service is started again
+    pub next_restart_at: Option<i64>,
+}
+
+/// health.check.v1 - Liveness/readiness (no rate limit)
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct HealthCheckRequest {
+    // No parameters needed
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct HealthCheckResponse {
+    /// "ok", "degraded" (worker loop wedged or last maintenance failed) or
+    /// "unavailable" (database unreachable)
+    pub status: String,
+    /// Database reachable and worker loop not wedged
+    pub ready: bool,
+    pub uptime_seconds: i64,
+    pub database: DatabaseHealth,
+    /// None when no worker is attached
+    pub worker: Option<WorkerHealth>,
+    pub maintenance: MaintenanceHealth,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DatabaseHealth {
+    pub reachable: bool,
+    pub latency_ms: i64,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct WorkerHealth {
+    /// Executing a job, or the loop ticked recently
+    pub alive: bool,
+    pub busy: bool,
+    pub throttled: bool,
+    pub paused: bool,
+    pub last_tick_at: Option<i64>,
+    pub last_tick_age_ms: Option<i64>,
+    /// The worker loop terminated and waits to be restarted
+    pub restarting: bool,
+    /// Times the worker loop was restarted after terminating unexpectedly
+    pub restarts: u64,
+    /// Latest unexpected termination (epoch ms) and its panic message or error
+    pub last_exit_at: Option<i64>,
+    pub last_exit_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct MaintenanceHealth {
+    /// Start of the latest scheduled run (None = no run recorded)
+    pub last_run_at: Option<i64>,
+    pub last_run_age_ms: Option<i64>,
+    pub last_run_ok: Option<bool>,
+    /// Errors of the latest run
+    pub errors: Vec<String>,
+}
+
+/// admin.config.get.v1 - Current runtime settings (response: `RuntimeSettings`)
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ConfigGetRequest {
+    // No parameters needed
+}
+
+/// admin.config.set.v1 - Change one runtime setting without a restart
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ConfigSetRequest {
+    /// Setting name (e.g. "cpu_throttle_percent")
+    pub key: String,
+    /// New value, typed like the setting (number)
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ConfigSetResponse {
+    pub key: String,
+    /// Every setting after the change
+    #[schemars(with = "serde_json::Value")]
+    pub settings: RuntimeSettings,
+}
+
+/// admin.queues.list.v1 - Every queue present in the jobs table
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct QueuesListRequest {
+    // No parameters needed
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct QueuesListResponse {
+    /// Sorted by name
+    pub queues: Vec<QueueInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct QueueInfo {
+    pub queue: String,
+    pub queued: i64,
+    pub running: i64,
+    pub done: i64,
+    pub failed: i64,
+    /// Wait of the oldest due QUEUED job (None = nothing waiting)
+    pub oldest_queued_age_ms: Option<i64>,
+    /// DONE + FAILED jobs finished in the last hour
+    pub throughput_last_hour: i64,
+    /// Paused with admin.queue.pause.v1
+    pub paused: bool,
+}
+
+/// Optional engine features and configured limits (compatibility checks)
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct EngineCapabilities {
+    pub features: EngineFeatures,
+    pub limits: EngineLimits,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct EngineFeatures {
+    /// Storage backend ("sqlite")
+    pub backend: String,
+    /// RPC over Unix Domain Socket (TCP localhost otherwise)
+    pub uds: bool,
+    /// Authenticated RPC
+    pub auth: bool,
+    /// Push subscription to job events
+    pub events: bool,
+    /// worker.* leasing RPCs
+    pub worker_leasing: bool,
+    /// REST gateway next to JSON-RPC (left out of minimal builds)
+    pub rest: bool,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct EngineLimits {
+    pub max_payload_bytes: usize,
+    pub max_payload_depth: usize,
+    pub rate_limit_burst: u32,
+    pub rate_limit_per_sec: u32,
+    /// 0 = enqueue coalescing disabled
+    pub coalesce_window_ms: u64,
+    pub max_lease_ms: i64,
+    /// Enqueue limits of individual queues (on top of the global bucket)
+    pub queue_rate_limits: BTreeMap<String, QueueRateLimit>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct QueueRateLimit {
+    pub burst: u32,
+    pub per_sec: u32,
+}
+
+/// `data` of a THROTTLED (4003) error: when the request may be retried
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct ThrottledData {
+    /// "global" (every RPC call) or "queue" (enqueues of one queue)
+    pub scope: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+    /// Whole tokens left in the bucket
+    pub remaining_tokens: u32,
+    pub burst: u32,
+    pub per_sec: u32,
+    /// Wait before the next token is available (None: the bucket never refills)
+    pub retry_after_ms: Option<u64>,
 }
 
 /// admin.maintenance.v1 - Run manual maintenance
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct MaintenanceRequest {
     #[serde(default)]
     pub force_vacuum: bool,
+    /// Only report what would be deleted (no GC, no VACUUM)
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Keep finished jobs and their artifacts this many days
+    #[serde(default = "default_manual_retention_days")]
+    pub retention_days: i64,
 }
 
-#[derive(Debug, Clone, Serialize)]
+fn default_manual_retention_days() -> i64 {
+    30
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct MaintenanceResponse {
     pub vacuum_run: bool,
+    /// Deleted, or that would be deleted on a dry run
     pub jobs_deleted: i64,
     pub artifacts_deleted: i64,
     pub db_size_before: i64,
     pub db_size_after: i64,
+    pub dry_run: bool,
+    /// Dry run only: the jobs, log files and artifacts a run would delete
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<serde_json::Value>")]
+    pub preview: Option<GcPreview>,
+    /// Dry run only: space a run (plus VACUUM) would reclaim
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_reclaimable: Option<u64>,
+}
+
+/// admin.maintenance.history.v1 - Recent scheduled maintenance reports
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MaintenanceHistoryRequest {
+    #[serde(default = "default_history_limit")]
+    pub limit: usize,
+}
+
+fn default_history_limit() -> usize {
+    10
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct MaintenanceHistoryResponse {
+    /// Newest first
+    #[schemars(with = "Vec<serde_json::Value>")]
+    pub reports: Vec<MaintenanceReport>,
+}
+
+/// admin.metrics.history.v1 - Throughput snapshots for trends
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MetricsHistoryRequest {
+    /// Only snapshots taken after this time (epoch ms)
+    #[serde(default)]
+    pub since: Option<i64>,
+    /// Newest snapshots to return (default 60, at most 10080)
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct MetricsHistoryResponse {
+    /// Oldest first
+    pub snapshots: Vec<MetricsSnapshotInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct MetricsSnapshotInfo {
+    /// End of the sampling window (epoch ms)
+    pub taken_at: i64,
+    pub window_ms: i64,
+    /// Jobs that finished DONE / FAILED during the window
+    pub completed: i64,
+    pub failed: i64,
+    pub completed_per_minute: f64,
+    pub failed_per_minute: f64,
+    /// QUEUED / RUNNING jobs of every queue at `taken_at`
+    pub queued: i64,
+    pub running: i64,
+}
+
+impl From<MetricsSnapshot> for MetricsSnapshotInfo {
+    fn from(snapshot: MetricsSnapshot) -> Self {
+        Self {
+            completed_per_minute: snapshot.completed_per_minute(),
+            failed_per_minute: snapshot.failed_per_minute(),
+            taken_at: snapshot.taken_at,
+            window_ms: snapshot.window_ms,
+            completed: snapshot.completed,
+            failed: snapshot.failed,
+            queued: snapshot.queued,
+            running: snapshot.running,
+        }
+    }
+}
+
+/// admin.archive.get.v1 - A job deleted by GC, from the job archive
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ArchiveGetRequest {
+    pub job_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ArchiveGetResponse {
+    pub job_id: String,
+    /// When GC archived the job
+    pub archived_at: i64,
+    /// Segment file holding the job
+    pub segment: String,
+    /// Job row as it was when archived
+    pub job: serde_json::Value,
+    /// Oldest first
+    pub events: Vec<JobEventInfo>,
+}
+
+/// admin.audit.list.v1 - Recorded mutating RPC calls, newest first
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct AuditListRequest {
+    /// e.g. "dev.cancel.v1"
+    #[serde(default)]
+    pub method: Option<String>,
+    #[serde(default)]
+    pub job_id: Option<String>,
+    #[serde(default)]
+    pub caller: Option<String>,
+    /// Only calls at or after this time (epoch ms)
+    #[serde(default)]
+    pub since: Option<i64>,
+    #[serde(default = "default_audit_limit")]
+    pub limit: usize,
+}
+
+fn default_audit_limit() -> usize {
+    50
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct AuditListResponse {
+    pub entries: Vec<AuditEntryInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct AuditEntryInfo {
+    pub timestamp: i64,
+    pub method: String,
+    /// `actor` / `worker_id` param of the call ("unknown" when absent)
+    pub caller: String,
+    pub job_id: Option<String>,
+    /// Params with payloads and other nested values elided
+    pub params: String,
+    /// None = succeeded
+    pub error_code: Option<i32>,
+    pub latency_ms: i64,
+}
+
+impl From<AuditEntry> for AuditEntryInfo {
+    fn from(entry: AuditEntry) -> Self {
+        Self {
+            timestamp: entry.timestamp,
+            method: entry.method,
+            caller: entry.caller,
+            job_id: entry.job_id,
+            params: entry.params,
+            error_code: entry.error_code,
+            latency_ms: entry.latency_ms,
+        }
+    }
+}
+
+/// admin.snapshot.v1 - Capture jobs and subject generations
+///
+/// The response is the snapshot itself (the file format read by admin.restore.v1).
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SnapshotRequest {
+    /// Only this queue (None = every queue)
+    #[serde(default)]
+    pub queue: Option<String>,
+    /// Replace payloads and env_vars with hashes/sizes (shareable dump)
+    #[serde(default)]
+    pub anonymize: bool,
+}
+
+/// admin.restore.v1 - Load a snapshot into this engine (all or nothing)
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RestoreRequest {
+    #[schemars(with = "serde_json::Value")]
+    pub snapshot: QueueSnapshot,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct RestoreResponse {
+    pub jobs_restored: usize,
+    pub subjects_restored: usize,
+}
+
+/// admin.job_type.disable.v1 / admin.job_type.enable.v1 - Toggle a job type
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct JobTypeSwitchRequest {
+    pub job_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct JobTypeSwitchResponse {
+    pub job_type: String,
+    pub disabled: bool,
+    pub disabled_job_types: Vec<String>,
+}
+
+/// admin.retry_policy.list.v1 - Retry rules per job type
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RetryPoliciesRequest {
+    // No parameters needed
+}
+
+/// admin.retry_policy.set.v1 - Set (replace) the retry rule of a job type
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RetryPolicySetRequest {
+    pub job_type: String,
+    /// Total attempts including the first (1-100), replacing the jobs' own value
+    #[serde(default)]
+    pub max_attempts: Option<i32>,
+    /// Delay before the first retry in ms (0-3600000)
+    #[serde(default)]
+    pub base_delay_ms: Option<i64>,
+    /// Delay multiplier per attempt (1.0-10.0), replacing the jobs' own value
+    #[serde(default)]
+    pub backoff_factor: Option<f64>,
+}
+
+/// admin.retry_policy.reset.v1 - Back to the default policy
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RetryPolicyResetRequest {
+    pub job_type: String,
+}
+
+/// Overrides of one job type (unset = the job's own value / the default)
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct RetryRuleInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_delay_ms: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backoff_factor: Option<f64>,
+}
+
+impl From<RetryRule> for RetryRuleInfo {
+    fn from(rule: RetryRule) -> Self {
+        Self {
+            max_attempts: rule.max_attempts,
+            base_delay_ms: rule.base_delay_ms,
+            backoff_factor: rule.backoff_factor,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct RetryPoliciesResponse {
+    /// Base delay of job types without a rule (ms)
+    pub default_base_delay_ms: i64,
+    /// Upper bound of the delay between two retries (ms, all job types)
+    pub max_delay_ms: i64,
+    /// Time after creation past which jobs are not retried (ms, all job types)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_deadline_ms: Option<i64>,
+    /// Panicked executions before a job is quarantined (all job types)
+    pub max_panics: i32,
+    /// Rules by job type
+    pub policies: BTreeMap<String, RetryRuleInfo>,
+}
+
+/// admin.queue.pause.v1 / admin.queue.resume.v1 - Stop or restart popping a queue
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct QueuePauseRequest {
+    pub queue: String,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct QueuePauseResponse {
+    pub queue: String,
+    pub paused: bool,
+    pub paused_queues: Vec<String>,
+}
+
+/// admin.lockdown.v1 - Engage, lift or query the subprocess kill switch
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct LockdownRequest {
+    /// true = engage, false = lift, None = only report the current state
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    #[serde(default)]
+    pub reason: Option<String>,
+    #[serde(default)]
+    pub actor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct LockdownResponse {
+    pub locked_down: bool,
+    /// Engaged at (ms), None while lifted
+    pub since: Option<i64>,
+    pub reason: Option<String>,
+    pub actor: Option<String>,
+    /// false if the switch already was in the requested position
+    pub changed: bool,
+}
+
+/// admin.drain.v1 - Stop popping and wait for the jobs in flight (before a restart)
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DrainRequest {
+    /// true = end the drain and pop again
+    #[serde(default)]
+    pub resume: bool,
+    /// Wait this long for the jobs in flight (default 25s, max 5min, 0 = only report)
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DrainResponse {
+    pub draining: bool,
+    /// Drain started at (ms), None while popping
+    pub since: Option<i64>,
+    /// false if the daemon already was in the requested mode
+    pub changed: bool,
+    /// Jobs claimed by the worker and not finished yet
+    pub in_flight: usize,
+    /// Draining and nothing in flight: the daemon can be stopped
+    pub drained: bool,
+}
+
+/// worker.lease.v1 - Lease the next job of a queue (external workers)
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct WorkerLeaseRequest {
+    pub worker_id: String,
+    #[serde(default = "default_queue")]
+    pub queue: String,
+    /// Lease duration in ms (default 30000, 1000..=600000)
+    #[serde(default)]
+    pub lease_ms: Option<i64>,
+}
+
+fn default_queue() -> String {
+    "default".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct WorkerLeaseResponse {
+    /// None when the queue has no runnable job
+    pub job: Option<LeasedJob>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct LeasedJob {
+    pub job_id: String,
+    pub job_type: String,
+    pub queue: String,
+    pub subject_key: String,
+    pub generation: i64,
+    pub payload: serde_json::Value,
+    /// Previous attempts (0 on first run)
+    pub attempts: i32,
+    /// Heartbeat (worker.heartbeat.v1) before this time or the job is reclaimed
+    pub lease_expires_at: i64,
+}
+
+/// worker.heartbeat.v1 - Renew a lease and report progress
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct WorkerHeartbeatRequest {
+    pub job_id: String,
+    pub worker_id: String,
+    #[serde(default)]
+    pub lease_ms: Option<i64>,
+    /// Free-form progress message (kept until the next heartbeat)
+    #[serde(default)]
+    pub progress: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct WorkerHeartbeatResponse {
+    pub job_id: String,
+    pub lease_expires_at: i64,
+}
+
+/// worker.complete.v1 - Report the outcome of a leased job
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct WorkerCompleteRequest {
+    pub job_id: String,
+    pub worker_id: String,
+    pub success: bool,
+    /// Result data stored in the attempt record (success only)
+    #[serde(default)]
+    pub result: Option<serde_json::Value>,
+    /// Error message (failure only)
+    #[serde(default)]
+    pub error: Option<String>,
+    /// false = fail permanently even if attempts remain
+    #[serde(default = "default_retryable")]
+    pub retryable: bool,
+}
+
+fn default_retryable() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct WorkerCompleteResponse {
+    pub job_id: String,
+    /// New job state (QUEUED when a failure is retried)
+    pub state: String,
 }
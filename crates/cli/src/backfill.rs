@@ -0,0 +1,295 @@
+//! `semantica backfill` - Enqueue one job per file matching a glob
+//!
+//! Files are walked client-side in sorted order and enqueued in batches. After
+//! every batch the last enqueued path is written to a state file, so an
+//! interrupted run resumes after it. Every job carries an idempotency key
+//! derived from the run, so a batch replayed after a crash creates no duplicates.
+
+use crate::{call_rpc, JsonRpcError};
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// RPC error code of a rate-limited request (retried after a pause)
+pub(crate) const THROTTLED: i32 = 4003;
+
+/// Pause before retrying a rate-limited enqueue
+const THROTTLE_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Subjects shown by --dry-run
+const DRY_RUN_PREVIEW: usize = 10;
+
+pub struct BackfillArgs {
+    pub glob: String,
+    pub root: PathBuf,
+    pub job_type: String,
+    pub queue: String,
+    pub priority: i32,
+    pub payload: Option<String>,
+    pub batch_size: usize,
+    pub state_file: PathBuf,
+    pub restart: bool,
+    pub dry_run: bool,
+}
+
+/// Progress of a backfill run (stored between batches)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct BackfillState {
+    run_id: String,
+    glob: String,
+    job_type: String,
+    queue: String,
+    enqueued: usize,
+    /// Subjects up to and including this one are enqueued (sorted order)
+    last_subject: Option<String>,
+}
+
+impl BackfillState {
+    fn new(args: &BackfillArgs) -> Self {
+        Self {
+            run_id: chrono::Utc::now().timestamp_millis().to_string(),
+            glob: args.glob.clone(),
+            job_type: args.job_type.clone(),
+            queue: args.queue.clone(),
+            enqueued: 0,
+            last_subject: None,
+        }
+    }
+
+    fn is_same_backfill(&self, args: &BackfillArgs) -> bool {
+        self.glob == args.glob && self.job_type == args.job_type && self.queue == args.queue
+    }
+
+    /// Subjects not enqueued yet
+    fn pending<'a>(&self, subjects: &'a [String]) -> &'a [String] {
+        match &self.last_subject {
+            Some(last) => &subjects[subjects.partition_point(|s| s <= last)..],
+            None => subjects,
+        }
+    }
+
+    fn idempotency_key(&self, subject: &str) -> String {
+        format!("backfill:{}:{:016x}", self.run_id, fnv1a(subject))
+    }
+}
+
+pub async fn run(rpc_url: &str, args: BackfillArgs) -> Result<()> {
+    let subjects = collect_subjects(&args.root, &args.glob)?;
+    let payload_template: Option<serde_json::Value> = args
+        .payload
+        .as_deref()
+        .map(serde_json::from_str)
+        .transpose()
+        .context("Invalid JSON payload")?;
+
+    let mut state = match load_state(&args.state_file)? {
+        Some(state) if args.restart => {
+            println!("Discarding previous backfill {}", state.run_id);
+            BackfillState::new(&args)
+        }
+        Some(state) if state.is_same_backfill(&args) => {
+            println!(
+                "Resuming backfill {} ({} already enqueued)",
+                state.run_id, state.enqueued
+            );
+            state
+        }
+        Some(state) => anyhow::bail!(
+            "{} holds an unfinished backfill of '{}' ({} on {}); pass --restart to discard it",
+            args.state_file.display(),
+            state.glob,
+            state.job_type,
+            state.queue
+        ),
+        None => BackfillState::new(&args),
+    };
+
+    let pending = state.pending(&subjects);
+    println!(
+        "{} files match '{}', {} to enqueue",
+        subjects.len(),
+        args.glob,
+        pending.len()
+    );
+
+    if args.dry_run {
+        for subject in pending.iter().take(DRY_RUN_PREVIEW) {
+            println!("  {}", subject);
+        }
+        if pending.len() > DRY_RUN_PREVIEW {
+            println!("  ... and {} more", pending.len() - DRY_RUN_PREVIEW);
+        }
+        return Ok(());
+    }
+
+    for batch in pending.chunks(args.batch_size.max(1)) {
+        for subject in batch {
+            let payload = payload_template
+                .clone()
+                .unwrap_or_else(|| json!({ "path": subject }));
+            let params = json!({
+                "job_type": args.job_type,
+                "queue": args.queue,
+                "subject_key": subject,
+                "priority": args.priority,
+                "payload": payload,
+                "idempotency_key": state.idempotency_key(subject),
+            });
+            enqueue_with_backoff(rpc_url, params)
+                .await
+                .with_context(|| format!("Failed to enqueue {}", subject))?;
+        }
+
+        state.enqueued += batch.len();
+        state.last_subject = batch.last().cloned();
+        save_state(&args.state_file, &state)?;
+        println!("  {} enqueued", state.enqueued);
+    }
+
+    if args.state_file.exists() {
+        std::fs::remove_file(&args.state_file)
+            .with_context(|| format!("Failed to remove {}", args.state_file.display()))?;
+    }
+    println!(
+        "{}",
+        format!("✓ Backfill complete: {} jobs enqueued", state.enqueued)
+            .green()
+            .bold()
+    );
+    Ok(())
+}
+
+/// Enqueue one job, waiting out rate limiting
+async fn enqueue_with_backoff(rpc_url: &str, params: serde_json::Value) -> Result<()> {
+    loop {
+        match call_rpc(rpc_url, "dev.enqueue.v1", params.clone()).await {
+            Ok(_) => return Ok(()),
+            Err(e)
+                if e.downcast_ref::<JsonRpcError>()
+                    .is_some_and(|e| e.code == THROTTLED) =>
+            {
+                tokio::time::sleep(THROTTLE_BACKOFF).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Files matching `pattern` under `root`, as sorted `/`-separated relative paths
+fn collect_subjects(root: &Path, pattern: &str) -> Result<Vec<String>> {
+    let full_pattern = root.join(pattern);
+    let full_pattern = full_pattern
+        .to_str()
+        .context("Glob pattern is not valid UTF-8")?;
+
+    let mut subjects = Vec::new();
+    for entry in glob::glob(full_pattern).context("Invalid glob pattern")? {
+        let path = entry.context("Failed to read directory entry")?;
+        if !path.is_file() {
+            continue;
+        }
+        let relative = path.strip_prefix(root).unwrap_or(&path);
+        let subject = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        subjects.push(subject);
+    }
+
+    subjects.sort();
+    subjects.dedup();
+    Ok(subjects)
+}
+
+fn load_state(path: &Path) -> Result<Option<BackfillState>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let state = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid backfill state in {}", path.display()))?;
+    Ok(Some(state))
+}
+
+fn save_state(path: &Path, state: &BackfillState) -> Result<()> {
+    // Write then rename so an interrupted write never leaves a torn state file
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, serde_json::to_string_pretty(state)?)
+        .with_context(|| format!("Failed to write {}", tmp.display()))?;
+    std::fs::rename(&tmp, path).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+/// Stable 64-bit FNV-1a hash (idempotency keys must survive a CLI upgrade)
+fn fnv1a(value: &str) -> u64 {
+    value.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args() -> BackfillArgs {
+        BackfillArgs {
+            glob: "src/**/*.rs".to_string(),
+            root: PathBuf::from("."),
+            job_type: "INDEX_FILE".to_string(),
+            queue: "code_intel".to_string(),
+            priority: 0,
+            payload: None,
+            batch_size: 2,
+            state_file: PathBuf::from(".semantica-backfill.json"),
+            restart: false,
+            dry_run: false,
+        }
+    }
+
+    #[test]
+    fn test_pending_skips_enqueued_subjects() {
+        let subjects: Vec<String> = ["a.rs", "b.rs", "c.rs", "d.rs"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let mut state = BackfillState::new(&args());
+        assert_eq!(state.pending(&subjects).len(), 4);
+
+        state.last_subject = Some("b.rs".to_string());
+        assert_eq!(state.pending(&subjects), &subjects[2..]);
+    }
+
+    #[test]
+    fn test_idempotency_key_is_stable_per_run() {
+        let state = BackfillState::new(&args());
+
+        assert_eq!(
+            state.idempotency_key("src/main.rs"),
+            state.idempotency_key("src/main.rs")
+        );
+        assert_ne!(
+            state.idempotency_key("src/main.rs"),
+            state.idempotency_key("src/lib.rs")
+        );
+        assert!(state.idempotency_key(&"x".repeat(512)).len() < 64);
+    }
+
+    #[test]
+    fn test_collect_subjects_relative_and_sorted() {
+        let root = std::env::temp_dir().join(format!("semantica-backfill-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("src/nested")).unwrap();
+        for file in ["src/b.rs", "src/a.rs", "src/nested/c.rs", "src/notes.txt"] {
+            std::fs::write(root.join(file), "").unwrap();
+        }
+
+        let subjects = collect_subjects(&root, "src/**/*.rs").unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(subjects, vec!["src/a.rs", "src/b.rs", "src/nested/c.rs"]);
+    }
+}
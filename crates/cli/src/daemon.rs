@@ -0,0 +1,260 @@
+//! `semantica daemon start|stop|status` - Run the daemon in the background
+//!
+//! `start` launches `semantica-task-engine --daemon --pid-file ...` and waits
+//! until the detached process answers RPC; `stop` sends SIGTERM to the PID in
+//! the PID file (a graceful shutdown: running jobs get the grace period) and
+//! waits for the process to go away.
+
+use crate::call_rpc;
+use anyhow::{bail, Context, Result};
+use clap::Subcommand;
+use colored::Colorize;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+const DEFAULT_PID_FILE: &str = "~/.semantica/semantica.pid";
+const DEFAULT_DAEMON_LOG: &str = "~/.semantica/daemon.log";
+const DAEMON_BIN: &str = "semantica-task-engine";
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Daemon log lines shown when `start` fails
+const LOG_TAIL_LINES: usize = 20;
+
+#[derive(Subcommand)]
+pub enum DaemonAction {
+    /// Start the daemon in the background and wait until it serves RPC
+    Start {
+        #[arg(long, env = "SEMANTICA_PID_FILE", default_value = DEFAULT_PID_FILE)]
+        pid_file: PathBuf,
+
+        /// Daemon output (stdout and stderr)
+        #[arg(long, env = "SEMANTICA_DAEMON_LOG", default_value = DEFAULT_DAEMON_LOG)]
+        log_file: PathBuf,
+
+        /// Daemon executable (default: next to this CLI, else from PATH)
+        #[arg(long, env = "SEMANTICA_DAEMON_BIN")]
+        bin: Option<PathBuf>,
+
+        /// Give up waiting for the daemon after this many seconds
+        #[arg(short, long, default_value = "30")]
+        timeout: u64,
+    },
+
+    /// Stop the daemon (SIGTERM) and wait for it to exit
+    Stop {
+        #[arg(long, env = "SEMANTICA_PID_FILE", default_value = DEFAULT_PID_FILE)]
+        pid_file: PathBuf,
+
+        /// Give up waiting after this many seconds (running jobs get the shutdown grace period)
+        #[arg(short, long, default_value = "120")]
+        timeout: u64,
+    },
+
+    /// Show whether the daemon is running (fails if not)
+    Status {
+        #[arg(long, env = "SEMANTICA_PID_FILE", default_value = DEFAULT_PID_FILE)]
+        pid_file: PathBuf,
+    },
+}
+
+pub async fn run(rpc_url: &str, action: DaemonAction) -> Result<()> {
+    match action {
+        DaemonAction::Start {
+            pid_file,
+            log_file,
+            bin,
+            timeout,
+        } => {
+            start(
+                rpc_url,
+                &expand(&pid_file),
+                &expand(&log_file),
+                bin,
+                Duration::from_secs(timeout),
+            )
+            .await
+        }
+        DaemonAction::Stop { pid_file, timeout } => {
+            stop(&expand(&pid_file), Duration::from_secs(timeout)).await
+        }
+        DaemonAction::Status { pid_file } => status(rpc_url, &expand(&pid_file)).await,
+    }
+}
+
+async fn start(
+    rpc_url: &str,
+    pid_file: &Path,
+    log_file: &Path,
+    bin: Option<PathBuf>,
+    timeout: Duration,
+) -> Result<()> {
+    if let Some(pid) = running_pid(pid_file) {
+        bail!("Daemon already running (PID {})", pid);
+    }
+    let bin = bin.unwrap_or_else(default_bin);
+
+    // Returns once the daemon has detached; it fails here on bad arguments
+    let exit = Command::new(&bin)
+        .arg("--daemon")
+        .arg("--pid-file")
+        .arg(pid_file)
+        .arg("--log-file")
+        .arg(log_file)
+        .status()
+        .with_context(|| format!("Failed to run {}", bin.display()))?;
+    if !exit.success() {
+        bail!("{} exited with {}", bin.display(), exit);
+    }
+
+    let deadline = Instant::now() + timeout;
+    let mut seen = false;
+    loop {
+        match running_pid(pid_file) {
+            Some(pid) => {
+                if call_rpc(rpc_url, "admin.stats.v1", json!({})).await.is_ok() {
+                    println!(
+                        "{}",
+                        format!("✓ Daemon started (PID {})", pid).green().bold()
+                    );
+                    println!("  Log: {}", log_file.display());
+                    return Ok(());
+                }
+                seen = true;
+            }
+            // Alive before, gone now: it failed during startup
+            None if seen => break,
+            None => {}
+        }
+        if Instant::now() > deadline {
+            break;
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+
+    eprintln!("--- {} ---", log_file.display());
+    eprintln!("{}", log_tail(log_file));
+    bail!(
+        "Daemon did not answer on {} within {}s",
+        rpc_url,
+        timeout.as_secs()
+    )
+}
+
+async fn stop(pid_file: &Path, timeout: Duration) -> Result<()> {
+    let Some(pid) = running_pid(pid_file) else {
+        if std::fs::remove_file(pid_file).is_ok() {
+            println!("{}", "Daemon not running (stale PID file removed)".yellow());
+        } else {
+            println!("{}", "Daemon not running".yellow());
+        }
+        return Ok(());
+    };
+
+    terminate(pid)?;
+    let deadline = Instant::now() + timeout;
+    while is_alive(pid) {
+        if Instant::now() > deadline {
+            bail!(
+                "Daemon (PID {}) still running after {}s",
+                pid,
+                timeout.as_secs()
+            );
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+    println!(
+        "{}",
+        format!("✓ Daemon stopped (PID {})", pid).green().bold()
+    );
+    Ok(())
+}
+
+async fn status(rpc_url: &str, pid_file: &Path) -> Result<()> {
+    let Some(pid) = running_pid(pid_file) else {
+        bail!("Daemon not running (no live PID in {})", pid_file.display());
+    };
+    println!(
+        "{}",
+        format!("● Daemon running (PID {})", pid).green().bold()
+    );
+
+    match call_rpc(rpc_url, "admin.stats.v1", json!({})).await {
+        Ok(stats) => println!(
+            "  RPC: {} (v{}, up {}s, {} queued, {} running)",
+            rpc_url,
+            stats["engine_version"].as_str().unwrap_or("?"),
+            stats["uptime_seconds"].as_i64().unwrap_or(0),
+            stats["queued_jobs"].as_i64().unwrap_or(0),
+            stats["running_jobs"].as_i64().unwrap_or(0)
+        ),
+        Err(e) => println!(
+            "  RPC: {} {}",
+            rpc_url,
+            format!("unreachable ({})", e).red()
+        ),
+    }
+    Ok(())
+}
+
+fn expand(path: &Path) -> PathBuf {
+    PathBuf::from(shellexpand::tilde(&path.to_string_lossy()).into_owned())
+}
+
+/// `semantica-task-engine` installed next to this CLI, else from PATH
+fn default_bin() -> PathBuf {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| Some(exe.parent()?.join(DAEMON_BIN)))
+        .filter(|bin| bin.is_file())
+        .unwrap_or_else(|| PathBuf::from(DAEMON_BIN))
+}
+
+/// PID in the file if that process is alive
+fn running_pid(pid_file: &Path) -> Option<u32> {
+    let pid = std::fs::read_to_string(pid_file)
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    is_alive(pid).then_some(pid)
+}
+
+fn log_tail(log_file: &Path) -> String {
+    let log = std::fs::read_to_string(log_file).unwrap_or_default();
+    let lines: Vec<&str> = log.lines().collect();
+    lines[lines.len().saturating_sub(LOG_TAIL_LINES)..].join("\n")
+}
+
+#[cfg(unix)]
+fn is_alive(pid: u32) -> bool {
+    use nix::errno::Errno;
+    use nix::unistd::Pid;
+
+    match nix::sys::signal::kill(Pid::from_raw(pid as i32), None) {
+        Ok(()) => true,
+        Err(Errno::EPERM) => true,
+        Err(_) => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn is_alive(_pid: u32) -> bool {
+    false
+}
+
+#[cfg(unix)]
+fn terminate(pid: u32) -> Result<()> {
+    use nix::sys::signal::{kill, Signal};
+    use nix::unistd::Pid;
+
+    kill(Pid::from_raw(pid as i32), Signal::SIGTERM)
+        .with_context(|| format!("Failed to signal daemon (PID {})", pid))
+}
+
+#[cfg(not(unix))]
+fn terminate(_pid: u32) -> Result<()> {
+    bail!("`semantica daemon stop` is only supported on Unix")
+}
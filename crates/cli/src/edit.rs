@@ -0,0 +1,103 @@
+//! `semantica retry --edit` - Fix a job's payload in an editor
+//!
+//! The payload is written pretty-printed to a temporary file and opened in
+//! `$VISUAL`, `$EDITOR` or `vi`. Saving invalid JSON offers to edit again;
+//! saving an empty file aborts (like an empty git commit message).
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde_json::Value;
+use std::io::{BufRead, Write};
+use std::path::Path;
+use std::process::Command;
+
+/// Edit `payload` of `job_id`; None if it was left unchanged
+pub fn edit_payload(job_id: &str, payload: &Value) -> Result<Option<Value>> {
+    let path = std::env::temp_dir().join(format!(
+        "semantica-payload-{}-{}.json",
+        job_id,
+        std::process::id()
+    ));
+    std::fs::write(
+        &path,
+        format!("{}\n", serde_json::to_string_pretty(payload)?),
+    )
+    .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    let edited = loop {
+        open_editor(&path)?;
+        let text = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        match parse_edited(&text) {
+            Ok(Some(edited)) => break edited,
+            Ok(None) => {
+                let _ = std::fs::remove_file(&path);
+                anyhow::bail!("Empty payload, nothing enqueued");
+            }
+            Err(e) => {
+                eprintln!("{} {}", "Invalid JSON:".red().bold(), e);
+                if !confirm("Edit again? [Y/n] ")? {
+                    anyhow::bail!(
+                        "Nothing enqueued (edited payload kept in {})",
+                        path.display()
+                    );
+                }
+            }
+        }
+    };
+    let _ = std::fs::remove_file(&path);
+
+    Ok((edited != *payload).then_some(edited))
+}
+
+/// Edited file content as JSON (None if emptied)
+fn parse_edited(text: &str) -> serde_json::Result<Option<Value>> {
+    if text.trim().is_empty() {
+        return Ok(None);
+    }
+    serde_json::from_str(text).map(Some)
+}
+
+/// Run the user's editor on `path` (the command may carry arguments, e.g. `code --wait`)
+fn open_editor(path: &Path) -> Result<()> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{} \"$1\"", editor))
+        .arg("sh")
+        .arg(path)
+        .status()
+        .with_context(|| format!("Failed to start editor '{}'", editor))?;
+    if !status.success() {
+        anyhow::bail!("Editor '{}' exited with {}", editor, status);
+    }
+    Ok(())
+}
+
+/// Ask a yes/no question on the terminal (default yes)
+fn confirm(question: &str) -> Result<bool> {
+    eprint!("{}", question);
+    std::io::stderr().flush()?;
+    let mut answer = String::new();
+    if std::io::stdin().lock().read_line(&mut answer)? == 0 {
+        return Ok(false);
+    }
+    Ok(!matches!(answer.trim(), "n" | "N" | "no"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_edited() {
+        assert_eq!(
+            parse_edited("{\"command\": \"cargo\"}\n").unwrap(),
+            Some(serde_json::json!({"command": "cargo"}))
+        );
+        assert_eq!(parse_edited("  \n").unwrap(), None, "emptied aborts");
+        assert!(parse_edited("{\"command\": }").is_err());
+    }
+}
@@ -1,15 +1,33 @@
 //! Semantica CLI - Command-line interface for Semantica Task Engine
 //! Phase 4: User experience improvements
 
+mod backfill;
+mod daemon;
+mod edit;
+mod mcp;
+mod uds;
+#[cfg(feature = "watch")]
+mod watch;
+
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 use colored::Colorize;
+use semantica_task_sdk::{LogFollowEvent, SemanticaTaskClient};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use tabled::{Table, Tabled};
 
 const DEFAULT_RPC_URL: &str = "http://127.0.0.1:9527";
 
+/// Entries listed per category by `maintenance --dry-run`
+const DRY_RUN_SHOWN: usize = 20;
+
+/// Offending job IDs listed per queue by `status`
+const SLO_JOBS_SHOWN: usize = 5;
+
+/// Job types failing at least this often are highlighted by `status`
+const FAILING_JOB_TYPE_RATE: f64 = 0.2;
+
 #[derive(Parser)]
 #[command(name = "semantica")]
 #[command(about = "Semantica Task Engine CLI", long_about = None)]
@@ -18,7 +36,7 @@ struct Cli {
     #[command(subcommand)]
     command: Commands,
 
-    /// RPC server URL
+    /// RPC server URL (`unix:///path/to/semantica.sock` for the daemon's Unix socket)
     #[arg(long, env = "SEMANTICA_RPC_URL", default_value = DEFAULT_RPC_URL)]
     rpc_url: String,
 }
@@ -46,12 +64,252 @@ enum Commands {
         /// Payload as JSON string
         #[arg(long)]
         payload: String,
+
+        /// W3C traceparent to continue (e.g. from a calling script's OpenTelemetry span)
+        #[arg(long, env = "TRACEPARENT")]
+        traceparent: Option<String>,
+
+        /// Long-running service (watcher, sidecar): never expires, restarted when it exits
+        #[arg(long)]
+        service: bool,
+
+        /// Payload JSON of a retry (repeatable: the 2nd attempt runs the first, ...)
+        #[arg(long = "fallback-payload")]
+        fallback_payloads: Vec<String>,
+
+        /// Earliest start (epoch ms)
+        #[arg(long)]
+        schedule_at: Option<i64>,
+
+        /// Skip the job if not finished by then (epoch ms)
+        #[arg(long)]
+        deadline: Option<i64>,
+
+        /// Skip the job if still queued this long after enqueue (ms)
+        #[arg(long)]
+        ttl_ms: Option<i64>,
+
+        /// Total attempts including the first (default 3)
+        #[arg(long)]
+        max_attempts: Option<i32>,
+
+        /// Retry delay multiplier per attempt (default 2.0)
+        #[arg(long)]
+        backoff_factor: Option<f64>,
+
+        /// Run only while the system is idle
+        #[arg(long)]
+        wait_for_idle: bool,
+
+        /// Run only while on AC power
+        #[arg(long)]
+        require_charging: bool,
+
+        /// User-defined tag (the `tag` filter of dev.list.v1)
+        #[arg(long)]
+        tag: Option<String>,
+
+        /// IN_PROCESS or SUBPROCESS
+        #[arg(long)]
+        execution_mode: Option<String>,
+
+        /// Job this one was spawned by
+        #[arg(long)]
+        parent_job_id: Option<String>,
+
+        /// Chain/batch group identifier
+        #[arg(long)]
+        chain_group_id: Option<String>,
+    },
+
+    /// Enqueue one job per file matching a glob (resumable)
+    Backfill {
+        /// Files to enqueue, relative to --root (e.g. 'src/**/*.rs')
+        #[arg(long)]
+        glob: String,
+
+        /// Job type (e.g., INDEX_FILE)
+        #[arg(short, long)]
+        job_type: String,
+
+        /// Queue name (default: "default")
+        #[arg(short, long, default_value = "default")]
+        queue: String,
+
+        /// Directory the glob and subject keys are relative to
+        #[arg(long, default_value = ".")]
+        root: std::path::PathBuf,
+
+        /// Priority (higher = more urgent)
+        #[arg(short, long, default_value = "0")]
+        priority: i32,
+
+        /// Payload JSON for every job (default: {"path": <file>}); may use {{subject_key}}
+        #[arg(long)]
+        payload: Option<String>,
+
+        /// Jobs enqueued between progress saves
+        #[arg(long, default_value = "100")]
+        batch_size: usize,
+
+        /// Progress file used to resume an interrupted backfill
+        #[arg(long, default_value = ".semantica-backfill.json")]
+        state_file: std::path::PathBuf,
+
+        /// Discard an unfinished backfill and start over
+        #[arg(long)]
+        restart: bool,
+
+        /// List the files that would be enqueued
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Enqueue a job per changed file under a directory (debounced)
+    #[cfg(feature = "watch")]
+    Watch {
+        /// Job type (e.g., INDEX_FILE)
+        #[arg(short, long)]
+        job_type: String,
+
+        /// Queue name (default: "default")
+        #[arg(short, long, default_value = "default")]
+        queue: String,
+
+        /// Directory to watch; subject keys are relative to it
+        #[arg(long, default_value = ".")]
+        root: std::path::PathBuf,
+
+        /// Priority (higher = more urgent)
+        #[arg(short, long, default_value = "0")]
+        priority: i32,
+
+        /// Payload JSON for every job (default: {"path": <file>})
+        #[arg(long)]
+        payload: Option<String>,
+
+        /// Only files matching these globs (repeatable, e.g. '**/*.rs')
+        #[arg(long = "glob")]
+        include: Vec<String>,
+
+        /// Also ignore files matching these globs (repeatable)
+        #[arg(long)]
+        ignore: Vec<String>,
+
+        /// Do not ignore target/, node_modules/ and .git/
+        #[arg(long)]
+        no_default_ignores: bool,
+
+        /// Quiet time before a changed file is enqueued, in milliseconds
+        #[arg(long, default_value = "500")]
+        debounce_ms: u64,
+
+        /// Most jobs enqueued per flush
+        #[arg(long, default_value = "100")]
+        max_batch: usize,
+
+        /// Seconds between event / job counter reports
+        #[arg(long, default_value = "30")]
+        stats_interval: u64,
     },
 
     /// Cancel a job
     Cancel {
         /// Job ID
         job_id: String,
+
+        /// Why the job is cancelled (shown by `inspect`)
+        #[arg(short, long)]
+        reason: Option<String>,
+    },
+
+    /// Cancel every QUEUED job whose subject matches a glob (e.g. 'src/foo.rs::*')
+    CancelSubject {
+        /// Glob over subject_key (`*`, `?`, `[...]`)
+        pattern: String,
+
+        /// Only this queue (default: every queue)
+        #[arg(short, long)]
+        queue: Option<String>,
+
+        /// Why the jobs are cancelled (shown by `inspect`)
+        #[arg(short, long)]
+        reason: Option<String>,
+    },
+
+    /// Re-run a FAILED job (attempts reset, history kept)
+    Retry {
+        /// Job ID
+        job_id: String,
+
+        /// Why the job is retried (shown by `inspect`)
+        #[arg(short, long)]
+        reason: Option<String>,
+
+        /// Fix the payload in $EDITOR and enqueue a corrected copy of the
+        /// finished job (linked to it as parent) instead
+        #[arg(long)]
+        edit: bool,
+    },
+
+    /// Purge a finished job: row, event history, artifacts and log file
+    Delete {
+        /// Job ID
+        job_id: String,
+
+        /// Also delete a QUEUED job
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Report the progress of a RUNNING job (from inside a job script)
+    Progress {
+        /// Percent done (0-100)
+        #[arg(value_parser = clap::value_parser!(u8).range(0..=100))]
+        percent: u8,
+
+        /// Short status line (e.g. "parsed 400/1000 files")
+        #[arg(short, long)]
+        message: Option<String>,
+
+        /// Job ID (set in the environment of job subprocesses)
+        #[arg(long, env = "SEMANTICA_JOB_ID")]
+        job_id: String,
+    },
+
+    /// Show job details and its event history
+    Inspect {
+        /// Job ID
+        job_id: String,
+    },
+
+    /// List jobs, oldest first
+    List {
+        /// Only this queue
+        #[arg(short, long)]
+        queue: Option<String>,
+
+        /// Only this state (QUEUED, RUNNING, DONE, FAILED, ...)
+        #[arg(long)]
+        state: Option<String>,
+
+        /// Only this subject key
+        #[arg(short, long)]
+        subject: Option<String>,
+
+        /// Maximum number of jobs
+        #[arg(short = 'n', long, default_value = "50")]
+        limit: usize,
+    },
+
+    /// Wait until a job finishes (exit code 1 unless it ends DONE)
+    Wait {
+        /// Job ID
+        job_id: String,
+
+        /// Give up after this many seconds
+        #[arg(short, long, default_value = "300")]
+        timeout: u64,
     },
 
     /// Get job logs
@@ -62,19 +320,339 @@ enum Commands {
         /// Number of lines to tail
         #[arg(short = 'n', long, default_value = "100")]
         lines: usize,
+
+        /// Only show one stream (stdout | stderr)
+        #[arg(long)]
+        stream: Option<String>,
+
+        /// Keep printing new lines until the job finishes
+        #[arg(short = 'f', long)]
+        follow: bool,
     },
 
     /// Show system status
     Status,
 
+    /// Serve the daemon's job tools to coding agents (MCP over stdio)
+    Mcp,
+
+    /// Check database, worker loop and maintenance health (fails unless ready)
+    Health,
+
+    /// List queues with job counts and throughput
+    Queues,
+
+    /// Show or change runtime settings (reset on daemon restart)
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+
     /// Run maintenance operations
     Maintenance {
         /// Force VACUUM even if not needed
         #[arg(long)]
         force_vacuum: bool,
+
+        /// Show what would be deleted and how much space reclaimed, delete nothing
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Keep finished jobs and their artifacts this many days
+        #[arg(long, default_value = "30")]
+        retention_days: i64,
+
+        #[command(subcommand)]
+        action: Option<MaintenanceAction>,
+    },
+
+    /// List a job's artifacts, or download one with --get
+    Artifacts {
+        /// Job ID
+        job_id: String,
+
+        /// Artifact URI to download (as listed)
+        #[arg(long)]
+        get: Option<String>,
+
+        /// Write the downloaded artifact here (default: stdout)
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+    },
+
+    /// Show recent successful results of a subject (did its output change?)
+    SubjectHistory {
+        /// Subject key
+        subject_key: String,
+
+        /// Number of runs to show
+        #[arg(short = 'n', long, default_value = "10")]
+        limit: usize,
+    },
+
+    /// Show throughput over time (jobs completed / failed per minute, queue depth)
+    Metrics {
+        /// Number of snapshots to show (one per minute by default)
+        #[arg(short = 'n', long, default_value = "30")]
+        limit: usize,
+
+        /// Only snapshots of the last N minutes
+        #[arg(long)]
+        minutes: Option<i64>,
+    },
+
+    /// Show recorded mutating RPC calls (who enqueued / cancelled / changed what)
+    Audit {
+        /// Only calls targeting this job
+        #[arg(long)]
+        job_id: Option<String>,
+
+        /// Only this method (e.g. dev.cancel.v1)
+        #[arg(long)]
+        method: Option<String>,
+
+        /// Only calls made by this caller (e.g. cli, mcp, sdk:<client_id>)
+        #[arg(long)]
+        caller: Option<String>,
+
+        /// Number of entries to show
+        #[arg(short = 'n', long, default_value = "50")]
+        limit: usize,
+    },
+
+    /// Read jobs deleted by GC back from the daemon's job archive
+    Archive {
+        #[command(subcommand)]
+        action: ArchiveAction,
+    },
+
+    /// Enable or disable a job type
+    JobType {
+        #[command(subcommand)]
+        action: JobTypeAction,
+    },
+
+    /// Show or change how often failed jobs of a type are retried
+    RetryPolicy {
+        #[command(subcommand)]
+        action: RetryPolicyAction,
+    },
+
+    /// Pause or resume a queue (jobs stay QUEUED while paused)
+    Queue {
+        #[command(subcommand)]
+        action: QueueAction,
+    },
+
+    /// List, requeue or purge jobs that failed for good (dead-letter queue)
+    Dlq {
+        #[command(subcommand)]
+        action: DlqAction,
+    },
+
+    /// Refuse all subprocess executions (panic button) or lift the lockdown
+    Lockdown {
+        #[command(subcommand)]
+        action: LockdownAction,
+    },
+
+    /// Start, stop or check the background daemon
+    Daemon {
+        #[command(subcommand)]
+        action: daemon::DaemonAction,
+    },
+
+    /// Stop popping and wait for the jobs in flight (before restarting the daemon)
+    Drain {
+        /// End the drain and pop again
+        #[arg(long)]
+        resume: bool,
+
+        /// Give up waiting after this many seconds (the drain stays on)
+        #[arg(short, long, default_value = "300")]
+        timeout: u64,
+    },
+
+    /// Save jobs and subject generations to a snapshot file
+    Snapshot {
+        /// Snapshot file to write (JSON)
+        #[arg(short, long)]
+        out: std::path::PathBuf,
+
+        /// Only this queue (default: every queue)
+        #[arg(short, long)]
+        queue: Option<String>,
+
+        /// Replace payloads and env vars with hashes/sizes (safe to share)
+        #[arg(long)]
+        anonymize: bool,
+    },
+
+    /// Load a snapshot file into the daemon (job ids must not exist yet)
+    Restore {
+        /// Snapshot file written by `snapshot`
+        file: std::path::PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum MaintenanceAction {
+    /// Show reports of recent scheduled maintenance runs
+    History {
+        /// Number of reports to show
+        #[arg(short = 'n', long, default_value = "10")]
+        limit: usize,
+    },
+}
+
+#[derive(Subcommand)]
+enum ArchiveAction {
+    /// Show an archived job (row, payload and events)
+    Get {
+        /// Job ID
+        job_id: String,
+
+        /// Print the archived job row as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum JobTypeAction {
+    /// Stop popping jobs of this type (they stay QUEUED) and reject new enqueues
+    Disable {
+        /// Job type (e.g., INDEX_FILE)
+        job_type: String,
+    },
+
+    /// Re-enable a disabled job type
+    Enable {
+        /// Job type (e.g., INDEX_FILE)
+        job_type: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Print every runtime setting
+    Get,
+
+    /// Change one setting (e.g. `config set cpu_throttle_percent 75`)
+    Set {
+        /// Setting name
+        key: String,
+
+        /// New value (JSON, e.g. 75 or 0.05)
+        value: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum QueueAction {
+    /// Stop popping jobs of this queue (enqueues are still accepted)
+    Pause {
+        /// Queue name (e.g., code_intel)
+        queue: String,
+    },
+
+    /// Resume a paused queue
+    Resume {
+        /// Queue name (e.g., code_intel)
+        queue: String,
     },
 }
 
+#[derive(Subcommand)]
+enum RetryPolicyAction {
+    /// Show the retry rules of every job type
+    List,
+
+    /// Set the retry rule of a job type (replaces its previous rule)
+    Set {
+        /// Job type (e.g., FETCH)
+        job_type: String,
+
+        /// Retry budget (overrides the jobs' own max_attempts)
+        #[arg(short = 'n', long)]
+        max_attempts: Option<i32>,
+
+        /// Delay before the first retry (ms)
+        #[arg(long)]
+        base_delay_ms: Option<i64>,
+
+        /// Delay multiplier per attempt (1.0-10.0)
+        #[arg(long)]
+        backoff_factor: Option<f64>,
+    },
+
+    /// Back to the default retry policy
+    Reset {
+        /// Job type (e.g., FETCH)
+        job_type: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum DlqAction {
+    /// Show dead-lettered jobs with their last error (newest first)
+    List {
+        /// Only this queue
+        #[arg(short, long)]
+        queue: Option<String>,
+
+        /// Number of entries to show
+        #[arg(short = 'n', long, default_value = "50")]
+        limit: usize,
+    },
+
+    /// Re-run dead-lettered jobs with a fresh attempt budget
+    Requeue {
+        /// Jobs to requeue
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        job_ids: Vec<String>,
+
+        /// Requeue every dead-lettered job (of --queue)
+        #[arg(long)]
+        all: bool,
+
+        /// Only this queue (with --all)
+        #[arg(short, long, requires = "all")]
+        queue: Option<String>,
+
+        /// Reason recorded in the jobs' history
+        #[arg(short, long)]
+        reason: Option<String>,
+    },
+
+    /// Drop dead-letter entries (the FAILED jobs are kept)
+    Purge {
+        /// Only this queue
+        #[arg(short, long)]
+        queue: Option<String>,
+
+        /// Only entries older than N days
+        #[arg(long)]
+        older_than_days: Option<i64>,
+    },
+}
+
+#[derive(Subcommand)]
+enum LockdownAction {
+    /// Refuse pops, enqueues, retries and worker leases (running jobs continue)
+    On {
+        /// Why (shown in every refusal)
+        #[arg(short, long)]
+        reason: Option<String>,
+    },
+
+    /// Allow subprocess execution again
+    Off,
+
+    /// Show whether the lockdown is engaged
+    Status,
+}
+
 #[derive(Serialize)]
 struct JsonRpcRequest {
     jsonrpc: String,
@@ -93,10 +671,315 @@ struct JsonRpcResponse {
     error: Option<JsonRpcError>,
 }
 
-#[derive(Deserialize)]
+#[derive(Debug, Deserialize)]
 struct JsonRpcError {
     code: i32,
     message: String,
+    #[serde(default)]
+    data: serde_json::Value,
+}
+
+impl std::fmt::Display for JsonRpcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RPC error ({}): {}", self.code, self.message)?;
+        // Find the call in the daemon log
+        if let Some(request_id) = self.data["request_id"].as_str() {
+            write!(f, " (request {})", request_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for JsonRpcError {}
+
+#[derive(Deserialize)]
+struct MaintenanceReport {
+    started_at: i64,
+    duration_ms: i64,
+    jobs_deleted: i64,
+    artifacts_removed: usize,
+    #[serde(default)]
+    success_logs_dropped: usize,
+    mb_reclaimed: f64,
+    errors: Vec<String>,
+}
+
+#[derive(Tabled)]
+struct MaintenanceReportRow {
+    started: String,
+    duration_ms: i64,
+    jobs_deleted: i64,
+    artifacts: usize,
+    sampled_out: usize,
+    reclaimed_mb: String,
+    errors: usize,
+}
+
+impl From<&MaintenanceReport> for MaintenanceReportRow {
+    fn from(report: &MaintenanceReport) -> Self {
+        Self {
+            started: format_millis(report.started_at),
+            duration_ms: report.duration_ms,
+            jobs_deleted: report.jobs_deleted,
+            artifacts: report.artifacts_removed,
+            sampled_out: report.success_logs_dropped,
+            reclaimed_mb: format!("{:.2}", report.mb_reclaimed),
+            errors: report.errors.len(),
+        }
+    }
+}
+
+#[derive(Deserialize, Tabled)]
+struct AuditRow {
+    #[tabled(rename = "time (UTC)", display_with = "display_millis")]
+    timestamp: i64,
+    method: String,
+    caller: String,
+    #[tabled(display_with = "display_option")]
+    job_id: Option<String>,
+    #[tabled(rename = "outcome", display_with = "display_outcome")]
+    error_code: Option<i32>,
+    latency_ms: i64,
+    params: String,
+}
+
+#[derive(Deserialize, Tabled)]
+struct ArtifactRow {
+    uri: String,
+    #[tabled(rename = "size", display_with = "display_size")]
+    size_bytes: Option<u64>,
+    #[tabled(rename = "sha256", display_with = "display_option")]
+    checksum: Option<String>,
+    #[tabled(rename = "created (UTC)", display_with = "display_opt_millis")]
+    created_at: Option<i64>,
+}
+
+#[derive(Deserialize, Tabled)]
+struct SubjectHistoryRow {
+    generation: i64,
+    job_id: String,
+    #[tabled(rename = "result", display_with = "display_short_hash")]
+    result_hash: Option<String>,
+    #[tabled(display_with = "display_changed")]
+    changed: Option<bool>,
+    duration_ms: i64,
+    #[tabled(rename = "finished (UTC)", display_with = "display_millis")]
+    finished_at: i64,
+}
+
+#[derive(Deserialize, Tabled)]
+struct DeadLetterRow {
+    job_id: String,
+    queue: String,
+    job_type: String,
+    attempts: i32,
+    #[tabled(display_with = "display_first_line")]
+    last_error: String,
+    #[tabled(rename = "dead (UTC)", display_with = "display_millis")]
+    dead_at: i64,
+}
+
+#[derive(Deserialize, Tabled)]
+struct MetricsSnapshotRow {
+    #[tabled(rename = "taken (UTC)", display_with = "display_millis")]
+    taken_at: i64,
+    #[tabled(rename = "done/min", display_with = "display_rate")]
+    completed_per_minute: f64,
+    #[tabled(rename = "failed/min", display_with = "display_rate")]
+    failed_per_minute: f64,
+    queued: i64,
+    running: i64,
+}
+
+fn display_rate(rate: &f64) -> String {
+    format!("{:.1}", rate)
+}
+
+/// One block character per value, scaled to the largest one
+fn sparkline(values: impl IntoIterator<Item = f64>) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let values: Vec<f64> = values.into_iter().collect();
+    let max = values.iter().cloned().fold(0.0, f64::max);
+    values
+        .iter()
+        .map(|&value| {
+            if max > 0.0 {
+                BARS[((value / max) * (BARS.len() - 1) as f64).round() as usize]
+            } else {
+                BARS[0]
+            }
+        })
+        .collect()
+}
+
+fn display_short_hash(hash: &Option<String>) -> String {
+    hash.as_deref()
+        .map_or_else(|| "-".to_string(), |h| h.chars().take(12).collect())
+}
+
+fn display_first_line(text: &str) -> String {
+    let line = text.lines().next().unwrap_or_default();
+    if line.chars().count() > 60 {
+        format!("{}…", line.chars().take(60).collect::<String>())
+    } else {
+        line.to_string()
+    }
+}
+
+fn display_changed(changed: &Option<bool>) -> String {
+    match changed {
+        Some(true) => "yes".to_string(),
+        Some(false) => "no".to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn display_size(size_bytes: &Option<u64>) -> String {
+    size_bytes.map_or_else(|| "-".to_string(), |bytes| format!("{} B", bytes))
+}
+
+fn display_opt_millis(millis: &Option<i64>) -> String {
+    millis.map_or_else(|| "-".to_string(), format_millis)
+}
+
+fn display_option(value: &Option<String>) -> String {
+    value.clone().unwrap_or_else(|| "-".to_string())
+}
+
+fn display_outcome(error_code: &Option<i32>) -> String {
+    match error_code {
+        None => "ok".to_string(),
+        Some(code) => format!("error {}", code),
+    }
+}
+
+#[derive(Deserialize)]
+struct JobDetails {
+    job_id: String,
+    job_type: String,
+    queue: String,
+    subject_key: String,
+    generation: i64,
+    state: String,
+    priority: i32,
+    attempts: i32,
+    #[serde(default)]
+    panic_count: i32,
+    #[serde(default)]
+    next_attempt_at: Option<i64>,
+    created_at: i64,
+    started_at: Option<i64>,
+    finished_at: Option<i64>,
+    #[serde(default)]
+    job_class: String,
+    #[serde(default)]
+    fallback_payloads: usize,
+    #[serde(default)]
+    traceparent: Option<String>,
+    #[serde(default)]
+    progress: Option<JobProgress>,
+    events: Vec<JobEvent>,
+}
+
+#[derive(Deserialize)]
+struct JobProgress {
+    percent: u8,
+    message: Option<String>,
+    updated_at: i64,
+}
+
+impl std::fmt::Display for JobProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}%", self.percent)?;
+        if let Some(message) = &self.message {
+            write!(f, " {}", message)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+struct ArchivedJobDetails {
+    job_id: String,
+    archived_at: i64,
+    segment: String,
+    job: serde_json::Value,
+    events: Vec<JobEvent>,
+}
+
+#[derive(Deserialize)]
+struct JobEvent {
+    state: String,
+    actor: String,
+    reason: Option<String>,
+    created_at: i64,
+}
+
+#[derive(Tabled)]
+struct JobEventRow {
+    time: String,
+    state: String,
+    actor: String,
+    reason: String,
+}
+
+impl From<&JobEvent> for JobEventRow {
+    fn from(event: &JobEvent) -> Self {
+        Self {
+            time: format_millis(event.created_at),
+            state: event.state.clone(),
+            actor: event.actor.clone(),
+            reason: event.reason.clone().unwrap_or_else(|| "-".to_string()),
+        }
+    }
+}
+
+#[derive(Deserialize, Tabled)]
+struct QueueRow {
+    queue: String,
+    queued: i64,
+    running: i64,
+    done: i64,
+    failed: i64,
+    #[tabled(rename = "oldest wait", display_with = "display_age")]
+    oldest_queued_age_ms: Option<i64>,
+    #[tabled(rename = "done+failed/1h")]
+    throughput_last_hour: i64,
+    paused: bool,
+}
+
+#[derive(Deserialize, Tabled)]
+struct JobRow {
+    job_id: String,
+    job_type: String,
+    queue: String,
+    subject_key: String,
+    state: String,
+    #[serde(default)]
+    #[tabled(display_with = "display_percent")]
+    progress: Option<JobProgress>,
+    #[tabled(rename = "created (UTC)", display_with = "display_millis")]
+    created_at: i64,
+}
+
+fn display_percent(progress: &Option<JobProgress>) -> String {
+    progress
+        .as_ref()
+        .map_or_else(|| "-".to_string(), |p| format!("{}%", p.percent))
+}
+
+fn display_millis(millis: &i64) -> String {
+    format_millis(*millis)
+}
+
+fn display_age(age_ms: &Option<i64>) -> String {
+    age_ms.map_or_else(|| "-".to_string(), |ms| format!("{}s", ms / 1000))
+}
+
+fn format_millis(millis: i64) -> String {
+    chrono::DateTime::from_timestamp_millis(millis)
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| millis.to_string())
 }
 
 #[derive(Deserialize, Tabled)]
@@ -114,82 +997,503 @@ async fn call_rpc(url: &str, method: &str, params: serde_json::Value) -> Result<
         id: 1,
     };
 
-    let client = reqwest::Client::new();
-    let response: JsonRpcResponse = client
-        .post(url)
-        .json(&request)
-        .send()
-        .await
-        .context("Failed to connect to daemon")?
-        .json()
-        .await
-        .context("Failed to parse response")?;
+    let response: JsonRpcResponse = match url.strip_prefix("unix://") {
+        Some(socket) => {
+            let body = uds::post_json(std::path::Path::new(socket), &serde_json::to_vec(&request)?)
+                .await?;
+            serde_json::from_slice(&body).context("Failed to parse response")?
+        }
+        None => reqwest::Client::new()
+            .post(url)
+            .json(&request)
+            .send()
+            .await
+            .context("Failed to connect to daemon")?
+            .json()
+            .await
+            .context("Failed to parse response")?,
+    };
+
+    if let Some(error) = response.error {
+        return Err(error.into());
+    }
+
+    response
+        .result
+        .ok_or_else(|| anyhow::anyhow!("No result in response"))
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Commands::Enqueue {
+            job_type,
+            queue,
+            subject,
+            priority,
+            payload,
+            traceparent,
+            service,
+            fallback_payloads,
+            schedule_at,
+            deadline,
+            ttl_ms,
+            max_attempts,
+            backoff_factor,
+            wait_for_idle,
+            require_charging,
+            tag,
+            execution_mode,
+            parent_job_id,
+            chain_group_id,
+        } => {
+            let payload_json: serde_json::Value =
+                serde_json::from_str(&payload).context("Invalid JSON payload")?;
+            let fallback_payloads = fallback_payloads
+                .iter()
+                .map(|p| serde_json::from_str::<serde_json::Value>(p))
+                .collect::<Result<Vec<_>, _>>()
+                .context("Invalid JSON fallback payload")?;
+
+            let params = json!({
+                "job_type": job_type,
+                "queue": queue,
+                "subject_key": subject,
+                "priority": priority,
+                "payload": payload_json,
+                "traceparent": traceparent,
+                "job_class": if service { "SERVICE" } else { "TASK" },
+                "fallback_payloads": fallback_payloads,
+                "schedule_at": schedule_at,
+                "deadline": deadline,
+                "ttl_ms": ttl_ms,
+                "max_attempts": max_attempts,
+                "backoff_factor": backoff_factor,
+                "wait_for_idle": wait_for_idle,
+                "require_charging": require_charging,
+                "user_tag": tag,
+                "execution_mode": execution_mode,
+                "parent_job_id": parent_job_id,
+                "chain_group_id": chain_group_id,
+            });
+
+            let result = call_rpc(&cli.rpc_url, "dev.enqueue.v1", params).await?;
+            let enqueue_result: EnqueueResult = serde_json::from_value(result)?;
+
+            println!("{}", "✓ Job enqueued successfully".green().bold());
+            println!();
+
+            let table = Table::new(vec![enqueue_result]).to_string();
+            println!("{}", table);
+        }
+
+        Commands::Backfill {
+            glob,
+            job_type,
+            queue,
+            root,
+            priority,
+            payload,
+            batch_size,
+            state_file,
+            restart,
+            dry_run,
+        } => {
+            let args = backfill::BackfillArgs {
+                glob,
+                root,
+                job_type,
+                queue,
+                priority,
+                payload,
+                batch_size,
+                state_file,
+                restart,
+                dry_run,
+            };
+            backfill::run(&cli.rpc_url, args).await?;
+        }
+
+        #[cfg(feature = "watch")]
+        Commands::Watch {
+            job_type,
+            queue,
+            root,
+            priority,
+            payload,
+            include,
+            ignore,
+            no_default_ignores,
+            debounce_ms,
+            max_batch,
+            stats_interval,
+        } => {
+            let args = watch::WatchArgs {
+                root,
+                job_type,
+                queue,
+                priority,
+                payload,
+                include,
+                ignore,
+                no_default_ignores,
+                debounce: std::time::Duration::from_millis(debounce_ms),
+                max_batch,
+                stats_interval: std::time::Duration::from_secs(stats_interval.max(1)),
+            };
+            watch::run(&cli.rpc_url, args).await?;
+        }
+
+        Commands::Cancel { job_id, reason } => {
+            let params = json!({
+                "job_id": job_id,
+                "reason": reason,
+                "actor": "cli",
+            });
+
+            let result = call_rpc(&cli.rpc_url, "dev.cancel.v1", params).await?;
+
+            println!("{}", format!("✓ Job {} cancelled", job_id).green().bold());
+            let flag = |key: &str| result.get(key).and_then(|v| v.as_bool()) == Some(true);
+            if flag("was_running") {
+                let pid = result.get("pid").and_then(|v| v.as_u64());
+                match (flag("terminated"), pid) {
+                    (true, Some(pid)) => println!("  Process {} killed", pid),
+                    (true, None) => println!("  Execution stopped"),
+                    (false, Some(pid)) => println!(
+                        "{}",
+                        format!("  Process {} is still running after the kill", pid).yellow()
+                    ),
+                    (false, None) => println!("{}", "  Execution may still be running".yellow()),
+                }
+            }
+        }
+
+        Commands::CancelSubject {
+            pattern,
+            queue,
+            reason,
+        } => {
+            let params = json!({
+                "subject_pattern": pattern,
+                "queue": queue,
+                "reason": reason,
+                "actor": "cli",
+            });
+
+            let result = call_rpc(&cli.rpc_url, "dev.cancel_by_subject.v1", params).await?;
+            let cancelled = result
+                .get("cancelled")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0);
+
+            println!(
+                "{}",
+                format!("✓ {} job(s) matching '{}' cancelled", cancelled, pattern)
+                    .green()
+                    .bold()
+            );
+        }
+
+        Commands::Retry {
+            job_id,
+            reason,
+            edit: true,
+        } => {
+            let job = call_rpc(
+                &cli.rpc_url,
+                "dev.inspect.v1",
+                json!({ "job_id": job_id, "include_payload": true }),
+            )
+            .await?;
+            let Some(payload) = edit::edit_payload(&job_id, &job["payload"])? else {
+                println!("{}", "Payload unchanged, nothing enqueued".yellow());
+                return Ok(());
+            };
+
+            let params = json!({
+                "job_id": job_id,
+                "reason": reason,
+                "actor": "cli",
+                "payload": payload,
+            });
+            let result = call_rpc(&cli.rpc_url, "job.retry.v1", params).await?;
+
+            println!(
+                "{}",
+                format!(
+                    "✓ Corrected copy of {} enqueued: {}",
+                    job_id,
+                    result["job_id"].as_str().unwrap_or_default()
+                )
+                .green()
+                .bold()
+            );
+        }
+
+        Commands::Retry {
+            job_id,
+            reason,
+            edit: false,
+        } => {
+            let params = json!({
+                "job_id": job_id,
+                "reason": reason,
+                "actor": "cli",
+            });
+
+            call_rpc(&cli.rpc_url, "job.retry.v1", params).await?;
 
-    if let Some(error) = response.error {
-        anyhow::bail!("RPC error ({}): {}", error.code, error.message);
-    }
+            println!("{}", format!("✓ Job {} requeued", job_id).green().bold());
+        }
 
-    response
-        .result
-        .ok_or_else(|| anyhow::anyhow!("No result in response"))
-}
+        Commands::Delete { job_id, force } => {
+            let params = json!({
+                "job_id": job_id,
+                "force": force,
+                "actor": "cli",
+            });
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    let cli = Cli::parse();
+            let result = call_rpc(&cli.rpc_url, "job.delete.v1", params).await?;
 
-    match cli.command {
-        Commands::Enqueue {
-            job_type,
-            queue,
-            subject,
-            priority,
-            payload,
-        } => {
-            let payload_json: serde_json::Value =
-                serde_json::from_str(&payload).context("Invalid JSON payload")?;
+            println!("{}", format!("✓ Job {} deleted", job_id).green().bold());
+            let leftovers = result["leftovers"].as_array().cloned().unwrap_or_default();
+            for leftover in leftovers {
+                println!(
+                    "{} {}",
+                    "Could not remove:".yellow(),
+                    leftover.as_str().unwrap_or_default()
+                );
+            }
+        }
 
+        Commands::Progress {
+            percent,
+            message,
+            job_id,
+        } => {
             let params = json!({
-                "job_type": job_type,
-                "queue": queue,
-                "subject_key": subject,
-                "priority": priority,
-                "payload": payload_json,
+                "job_id": job_id,
+                "percent": percent,
+                "message": message,
             });
 
-            let result = call_rpc(&cli.rpc_url, "dev.enqueue.v1", params).await?;
-            let enqueue_result: EnqueueResult = serde_json::from_value(result)?;
+            call_rpc(&cli.rpc_url, "job.report_progress.v1", params).await?;
+        }
 
-            println!("{}", "✓ Job enqueued successfully".green().bold());
+        Commands::Inspect { job_id } => {
+            let params = json!({ "job_id": job_id });
+
+            let result = call_rpc(&cli.rpc_url, "dev.inspect.v1", params).await?;
+            let job: JobDetails = serde_json::from_value(result)?;
+
+            println!("{}", format!("Job {} (UTC)", job.job_id).cyan().bold());
             println!();
+            if job.job_class == "SERVICE" {
+                println!("  Type:       {} (service)", job.job_type);
+            } else {
+                println!("  Type:       {}", job.job_type);
+            }
+            println!("  Queue:      {}", job.queue);
+            println!(
+                "  Subject:    {} (generation {})",
+                job.subject_key, job.generation
+            );
+            println!("  State:      {}", job.state.bold());
+            println!("  Priority:   {}", job.priority);
+            if job.fallback_payloads > 0 {
+                println!(
+                    "  Attempts:   {} ({} fallback payloads)",
+                    job.attempts, job.fallback_payloads
+                );
+            } else {
+                println!("  Attempts:   {}", job.attempts);
+            }
+            if job.panic_count > 0 {
+                println!("  Panics:     {}", job.panic_count.to_string().red().bold());
+            }
+            if let Some(next_attempt_at) = job.next_attempt_at {
+                println!("  Next try:   {}", format_millis(next_attempt_at));
+            }
+            println!("  Created:    {}", format_millis(job.created_at));
+            if let Some(started_at) = job.started_at {
+                println!("  Started:    {}", format_millis(started_at));
+            }
+            if let Some(finished_at) = job.finished_at {
+                println!("  Finished:   {}", format_millis(finished_at));
+            }
+            if let Some(traceparent) = &job.traceparent {
+                println!("  Trace:      {}", traceparent);
+            }
+            if let Some(progress) = &job.progress {
+                println!(
+                    "  Progress:   {} (at {})",
+                    progress,
+                    format_millis(progress.updated_at)
+                );
+            }
 
-            let table = Table::new(vec![enqueue_result]).to_string();
-            println!("{}", table);
+            println!();
+            if job.events.is_empty() {
+                println!("{}", "No recorded events".yellow());
+            } else {
+                println!("{}", "Events".cyan().bold());
+                let rows: Vec<JobEventRow> = job.events.iter().map(Into::into).collect();
+                println!("{}", Table::new(rows));
+            }
         }
 
-        Commands::Cancel { job_id } => {
-            let params = json!({
-                "job_id": job_id,
-            });
+        Commands::Archive {
+            action: ArchiveAction::Get { job_id, json },
+        } => {
+            let result = call_rpc(
+                &cli.rpc_url,
+                "admin.archive.get.v1",
+                json!({ "job_id": job_id }),
+            )
+            .await?;
+            let archived: ArchivedJobDetails = serde_json::from_value(result)?;
 
-            call_rpc(&cli.rpc_url, "dev.cancel.v1", params).await?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&archived.job)?);
+                return Ok(());
+            }
 
-            println!("{}", format!("✓ Job {} cancelled", job_id).green().bold());
+            let job = &archived.job;
+            let text = |key: &str| job[key].as_str().unwrap_or("-").to_string();
+            println!(
+                "{}",
+                format!("Archived job {} (UTC)", archived.job_id)
+                    .cyan()
+                    .bold()
+            );
+            println!();
+            println!("  Type:       {}", text("job_type"));
+            println!("  Queue:      {}", text("queue"));
+            println!(
+                "  Subject:    {} (generation {})",
+                text("subject_key"),
+                job["generation"]
+            );
+            println!("  State:      {}", text("state").bold());
+            println!("  Attempts:   {}", job["attempts"]);
+            for (label, key) in [
+                ("Created:", "created_at"),
+                ("Started:", "started_at"),
+                ("Finished:", "finished_at"),
+            ] {
+                if let Some(millis) = job[key].as_i64() {
+                    println!("  {:<11} {}", label, format_millis(millis));
+                }
+            }
+            println!("  Payload:    {}", job["payload"]);
+            if let Some(summary) = job["result_summary"].as_str() {
+                println!("  Result:     {}", summary);
+            }
+            println!(
+                "  Archived:   {} ({})",
+                format_millis(archived.archived_at),
+                archived.segment
+            );
+
+            println!();
+            if archived.events.is_empty() {
+                println!("{}", "No recorded events".yellow());
+            } else {
+                println!("{}", "Events".cyan().bold());
+                let rows: Vec<JobEventRow> = archived.events.iter().map(Into::into).collect();
+                println!("{}", Table::new(rows));
+            }
+        }
+
+        Commands::Wait { job_id, timeout } => {
+            let params = json!({ "job_id": job_id, "timeout_ms": timeout * 1000 });
+
+            let result = call_rpc(&cli.rpc_url, "job.wait.v1", params).await?;
+            let state = result["state"].as_str().unwrap_or("UNKNOWN");
+
+            if !result["terminal"].as_bool().unwrap_or(false) {
+                anyhow::bail!("Timed out after {}s, job {} is {}", timeout, job_id, state);
+            }
+            if let Some(summary) = result["result_summary"].as_str() {
+                println!("{}", summary);
+            }
+            if state != "DONE" {
+                anyhow::bail!("Job {} finished as {}", job_id, state);
+            }
+            println!("{}", format!("✓ Job {} done", job_id).green().bold());
+        }
+
+        Commands::Logs {
+            job_id,
+            lines,
+            stream,
+            follow: true,
+        } => {
+            let client = SemanticaTaskClient::connect(&cli.rpc_url).await?;
+            let mut log = client.follow_logs(&job_id, lines, stream).await?;
+            while let Some(event) = log.next().await {
+                match event? {
+                    LogFollowEvent::Line { line, entry } => match entry {
+                        Some(entry) if entry.stream == "stderr" => println!("{}", line.red()),
+                        _ => println!("{}", line),
+                    },
+                    LogFollowEvent::End { state } => {
+                        let done = format!("Job {} finished as {}", job_id, state);
+                        if state == "DONE" {
+                            eprintln!("{}", done.green());
+                        } else {
+                            eprintln!("{}", done.yellow());
+                        }
+                    }
+                }
+            }
         }
 
-        Commands::Logs { job_id, lines } => {
+        Commands::Logs {
+            job_id,
+            lines,
+            stream,
+            follow: false,
+        } => {
             let params = json!({
                 "job_id": job_id,
                 "lines": lines,
+                "stream": stream,
             });
 
             let result = call_rpc(&cli.rpc_url, "logs.tail.v1", params).await?;
+            let entries = result
+                .get("entries")
+                .and_then(|v| v.as_array())
+                .cloned()
+                .unwrap_or_default();
+            let lines = result
+                .get("lines")
+                .and_then(|v| v.as_array())
+                .cloned()
+                .unwrap_or_default();
 
-            if let Some(logs) = result.get("logs").and_then(|v| v.as_str()) {
-                println!("{}", format!("Logs for job {}:", job_id).cyan().bold());
-                println!("{}", logs);
-            } else {
+            if lines.is_empty() {
                 println!("{}", "No logs available".yellow());
+            } else {
+                println!("{}", format!("Logs for job {}:", job_id).cyan().bold());
+                if entries.len() == lines.len() {
+                    // Tagged logs: mark stderr so errors stand out
+                    for entry in &entries {
+                        let line = entry.get("line").and_then(|v| v.as_str()).unwrap_or("");
+                        match entry.get("stream").and_then(|v| v.as_str()) {
+                            Some("stderr") => println!("{}", line.red()),
+                            _ => println!("{}", line),
+                        }
+                    }
+                } else {
+                    for line in &lines {
+                        println!("{}", line.as_str().unwrap_or(""));
+                    }
+                }
             }
         }
 
@@ -207,11 +1511,130 @@ async fn main() -> Result<()> {
                     println!("  {} {}", "Running:".bold(), stats["running_jobs"]);
                     println!("  {} {}", "Done:".bold(), stats["done_jobs"]);
                     println!("  {} {}", "Failed:".bold(), stats["failed_jobs"]);
+                    println!("  {} {}", "Expired:".bold(), stats["expired_jobs"]);
                     println!();
+                    if let Some(slots) = stats["slots"].as_array().filter(|s| !s.is_empty()) {
+                        println!("  {}", "Worker Slots:".bold());
+                        for slot in slots {
+                            match slot["job_id"].as_str() {
+                                Some(job_id) => println!(
+                                    "    #{} {} {} ({}) running {}s",
+                                    slot["slot"],
+                                    job_id,
+                                    slot["job_type"].as_str().unwrap_or("?"),
+                                    slot["subject_key"].as_str().unwrap_or("?"),
+                                    slot["running_ms"].as_i64().unwrap_or(0) / 1000
+                                ),
+                                None => println!("    #{} {}", slot["slot"], "idle".dimmed()),
+                            }
+                        }
+                        println!();
+                    }
+                    if let Some(restarts) = stats["worker_restarts"].as_u64().filter(|&n| n > 0) {
+                        println!(
+                            "  {} {}",
+                            "Worker Restarts:".bold(),
+                            restarts.to_string().red()
+                        );
+                        println!();
+                    }
+                    if let Some(services) = stats["services"].as_array().filter(|s| !s.is_empty()) {
+                        println!("  {}", "Services:".bold());
+                        for service in services {
+                            println!(
+                                "    {} {} ({}) {}, {} restarts",
+                                service["job_id"].as_str().unwrap_or("?"),
+                                service["job_type"].as_str().unwrap_or("?"),
+                                service["subject_key"].as_str().unwrap_or("?"),
+                                service["state"].as_str().unwrap_or("?"),
+                                service["restarts"]
+                            );
+                        }
+                        println!();
+                    }
+                    if let Some(slos) = stats["wait_slos"].as_array().filter(|s| !s.is_empty()) {
+                        println!("  {}", "Wait SLOs (24h):".bold());
+                        for slo in slos {
+                            let late = slo["violations"].as_i64().unwrap_or(0)
+                                + slo["waiting_over"].as_i64().unwrap_or(0);
+                            let summary = format!(
+                                "{:.1}% within {}ms ({} of {} started late, {} waiting over)",
+                                slo["met_ratio"].as_f64().unwrap_or(1.0) * 100.0,
+                                slo["target_ms"],
+                                slo["violations"],
+                                slo["started"],
+                                slo["waiting_over"]
+                            );
+                            let summary = if late > 0 {
+                                summary.yellow()
+                            } else {
+                                summary.green()
+                            };
+                            println!("    {}: {}", slo["queue"].as_str().unwrap_or("?"), summary);
+                            if let Some(job_ids) =
+                                slo["job_ids"].as_array().filter(|ids| !ids.is_empty())
+                            {
+                                let shown: Vec<&str> = job_ids
+                                    .iter()
+                                    .take(SLO_JOBS_SHOWN)
+                                    .filter_map(|id| id.as_str())
+                                    .collect();
+                                let more = job_ids.len().saturating_sub(SLO_JOBS_SHOWN);
+                                println!(
+                                    "      worst {}ms: {}{}",
+                                    slo["max_wait_ms"],
+                                    shown.join(", "),
+                                    if more > 0 {
+                                        format!(" (+{} more)", more)
+                                    } else {
+                                        String::new()
+                                    }
+                                );
+                            }
+                        }
+                        println!();
+                    }
+                    if let Some(job_types) = stats["job_types"].as_array().filter(|t| !t.is_empty())
+                    {
+                        println!("  {}", "Job Types:".bold());
+                        for job_type in job_types {
+                            let failure_rate = job_type["failure_rate"].as_f64().unwrap_or(0.0);
+                            let failures = format!(
+                                "{} done, {} failed ({:.1}% failing)",
+                                job_type["done"],
+                                job_type["failed"],
+                                failure_rate * 100.0
+                            );
+                            let failures = if failure_rate >= FAILING_JOB_TYPE_RATE {
+                                failures.red()
+                            } else {
+                                failures.green()
+                            };
+                            let avg = job_type["avg_duration_ms"]
+                                .as_f64()
+                                .map_or_else(|| "-".to_string(), |ms| format!("{:.0}ms", ms));
+                            println!(
+                                "    {}: {}, {} queued, {} running, {} expired, avg {}",
+                                job_type["job_type"].as_str().unwrap_or("?"),
+                                failures,
+                                job_type["queued"],
+                                job_type["running"],
+                                job_type["expired"],
+                                avg
+                            );
+                        }
+                        println!();
+                    }
                     let db_mb =
                         stats["db_size_bytes"].as_i64().unwrap_or(0) as f64 / (1024.0 * 1024.0);
                     println!("  {} {:.2} MB", "DB Size:".bold(), db_mb);
                     println!("  {} {} seconds", "Uptime:".bold(), stats["uptime_seconds"]);
+                    println!(
+                        "  {} {} (schema v{})",
+                        "Engine:".bold(),
+                        stats["engine_version"].as_str().unwrap_or("unknown"),
+                        stats["schema_version"]
+                    );
                 }
                 Err(e) => {
                     println!("  {} {}", "Status:".bold(), "ERROR".red());
@@ -220,7 +1643,371 @@ async fn main() -> Result<()> {
             }
         }
 
-        Commands::Maintenance { force_vacuum } => {
+        Commands::Health => {
+            let health = call_rpc(&cli.rpc_url, "health.check.v1", json!({})).await?;
+            let status = health["status"].as_str().unwrap_or("unknown");
+
+            let colored = match status {
+                "ok" => status.green(),
+                "degraded" => status.yellow(),
+                _ => status.red(),
+            };
+            println!("  {} {}", "Health:".bold(), colored.bold());
+
+            let db = &health["database"];
+            match db["reachable"].as_bool() {
+                Some(true) => println!(
+                    "  {} reachable ({} ms)",
+                    "Database:".bold(),
+                    db["latency_ms"]
+                ),
+                _ => println!(
+                    "  {} {} ({})",
+                    "Database:".bold(),
+                    "unreachable".red(),
+                    db["error"].as_str().unwrap_or("unknown error")
+                ),
+            }
+
+            let worker = &health["worker"];
+            if worker.is_null() {
+                println!("  {} {}", "Worker:".bold(), "not attached".dimmed());
+            } else {
+                let state = if worker["restarting"].as_bool() == Some(true) {
+                    "restarting".red()
+                } else if worker["busy"].as_bool() == Some(true) {
+                    "busy".green()
+                } else if worker["alive"].as_bool() == Some(true) {
+                    "idle".green()
+                } else {
+                    "wedged".red()
+                };
+                let tick = worker["last_tick_age_ms"]
+                    .as_i64()
+                    .map(|ms| format!("last tick {}s ago", ms / 1000))
+                    .unwrap_or_else(|| "never ticked".to_string());
+                println!("  {} {} ({})", "Worker:".bold(), state, tick);
+                if let Some(at) = worker["last_exit_at"].as_i64() {
+                    println!(
+                        "    {} restart(s), last exit {}: {}",
+                        worker["restarts"],
+                        format_millis(at),
+                        worker["last_exit_reason"].as_str().unwrap_or("?")
+                    );
+                }
+            }
+
+            let maintenance = &health["maintenance"];
+            match maintenance["last_run_at"].as_i64() {
+                Some(at) => println!(
+                    "  {} last run {} ({})",
+                    "Maintenance:".bold(),
+                    format_millis(at),
+                    if maintenance["last_run_ok"].as_bool() == Some(true) {
+                        "ok".green()
+                    } else {
+                        "errors".red()
+                    }
+                ),
+                None => println!("  {} {}", "Maintenance:".bold(), "no run recorded".dimmed()),
+            }
+
+            if health["ready"].as_bool() != Some(true) {
+                anyhow::bail!("Daemon is not ready ({})", status);
+            }
+        }
+
+        Commands::Config { action } => {
+            let settings = match action {
+                ConfigAction::Get => {
+                    call_rpc(&cli.rpc_url, "admin.config.get.v1", json!({})).await?
+                }
+                ConfigAction::Set { key, value } => {
+                    let value: serde_json::Value = serde_json::from_str(&value)
+                        .with_context(|| format!("Value '{}' is not valid JSON", value))?;
+                    let result = call_rpc(
+                        &cli.rpc_url,
+                        "admin.config.set.v1",
+                        json!({ "key": key, "value": value }),
+                    )
+                    .await?;
+                    println!("{}", format!("✓ {} updated", key).green().bold());
+                    result["settings"].clone()
+                }
+            };
+
+            if let Some(settings) = settings.as_object() {
+                for (key, value) in settings {
+                    println!("  {} {}", format!("{}:", key).bold(), value);
+                }
+            }
+        }
+
+        Commands::List {
+            queue,
+            state,
+            subject,
+            limit,
+        } => {
+            let params = json!({
+                "queue": queue,
+                "state": state,
+                "subject_key": subject,
+                "limit": limit,
+            });
+
+            let result = call_rpc(&cli.rpc_url, "dev.list.v1", params).await?;
+            let jobs: Vec<JobRow> = serde_json::from_value(result["jobs"].clone())?;
+
+            if jobs.is_empty() {
+                println!("{}", "No jobs".yellow());
+            } else {
+                println!("{}", Table::new(jobs));
+                if result["next_cursor"].is_string() {
+                    println!("{}", "More jobs match (raise --limit)".dimmed());
+                }
+            }
+        }
+
+        Commands::Mcp => {
+            mcp::serve(&cli.rpc_url).await?;
+        }
+
+        Commands::Queues => {
+            let result = call_rpc(&cli.rpc_url, "admin.queues.list.v1", json!({})).await?;
+            let queues: Vec<QueueRow> = serde_json::from_value(result["queues"].clone())?;
+
+            if queues.is_empty() {
+                println!("{}", "No queues".yellow());
+            } else {
+                println!("{}", Table::new(queues));
+            }
+        }
+
+        Commands::Artifacts {
+            job_id,
+            get: Some(uri),
+            output,
+        } => {
+            let client = SemanticaTaskClient::connect(&cli.rpc_url).await?;
+            let content = client.read_artifact(&job_id, &uri).await?;
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, &content)
+                        .with_context(|| format!("Failed to write {}", path.display()))?;
+                    eprintln!(
+                        "{}",
+                        format!("✓ {} bytes written to {}", content.len(), path.display()).green()
+                    );
+                }
+                None => {
+                    use std::io::Write;
+                    std::io::stdout().write_all(&content)?;
+                }
+            }
+        }
+
+        Commands::Artifacts {
+            job_id, get: None, ..
+        } => {
+            let result = call_rpc(
+                &cli.rpc_url,
+                "job.artifacts.list.v1",
+                json!({ "job_id": job_id }),
+            )
+            .await?;
+            let artifacts: Vec<ArtifactRow> = serde_json::from_value(result["artifacts"].clone())?;
+
+            if artifacts.is_empty() {
+                println!("{}", "No artifacts".yellow());
+                return Ok(());
+            }
+
+            println!("{}", format!("Artifacts of job {}:", job_id).cyan().bold());
+            println!("{}", Table::new(artifacts));
+        }
+
+        Commands::SubjectHistory { subject_key, limit } => {
+            let result = call_rpc(
+                &cli.rpc_url,
+                "subject.history.v1",
+                json!({ "subject_key": subject_key, "limit": limit }),
+            )
+            .await?;
+            let entries: Vec<SubjectHistoryRow> =
+                serde_json::from_value(result["entries"].clone())?;
+
+            if entries.is_empty() {
+                println!("{}", "No recorded results".yellow());
+                return Ok(());
+            }
+
+            println!(
+                "{}",
+                format!("Results of {} (newest first):", subject_key)
+                    .cyan()
+                    .bold()
+            );
+            println!("{}", Table::new(entries));
+        }
+
+        Commands::Metrics { limit, minutes } => {
+            let since =
+                minutes.map(|minutes| chrono::Utc::now().timestamp_millis() - minutes * 60_000);
+            let result = call_rpc(
+                &cli.rpc_url,
+                "admin.metrics.history.v1",
+                json!({ "since": since, "limit": limit }),
+            )
+            .await?;
+            let snapshots: Vec<MetricsSnapshotRow> =
+                serde_json::from_value(result["snapshots"].clone())?;
+
+            if snapshots.is_empty() {
+                println!("{}", "No metrics snapshots yet".yellow());
+                return Ok(());
+            }
+
+            println!("{}", "Throughput (oldest first)".cyan().bold());
+            println!(
+                "  Done/min:   {}",
+                sparkline(snapshots.iter().map(|s| s.completed_per_minute)).green()
+            );
+            println!(
+                "  Failed/min: {}",
+                sparkline(snapshots.iter().map(|s| s.failed_per_minute)).red()
+            );
+            println!(
+                "  Queued:     {}",
+                sparkline(snapshots.iter().map(|s| s.queued as f64)).yellow()
+            );
+            println!();
+            println!("{}", Table::new(snapshots));
+        }
+
+        Commands::Audit {
+            job_id,
+            method,
+            caller,
+            limit,
+        } => {
+            let result = call_rpc(
+                &cli.rpc_url,
+                "admin.audit.list.v1",
+                json!({ "job_id": job_id, "method": method, "caller": caller, "limit": limit }),
+            )
+            .await?;
+            let entries: Vec<AuditRow> = serde_json::from_value(result["entries"].clone())?;
+
+            if entries.is_empty() {
+                println!("{}", "No audited calls".yellow());
+                return Ok(());
+            }
+
+            println!("{}", "Audit Log (UTC, newest first)".cyan().bold());
+            println!();
+            println!("{}", Table::new(entries));
+        }
+
+        Commands::Maintenance {
+            action: Some(MaintenanceAction::History { limit }),
+            ..
+        } => {
+            let result = call_rpc(
+                &cli.rpc_url,
+                "admin.maintenance.history.v1",
+                json!({ "limit": limit }),
+            )
+            .await?;
+            let reports: Vec<MaintenanceReport> =
+                serde_json::from_value(result["reports"].clone())?;
+
+            if reports.is_empty() {
+                println!("{}", "No maintenance reports yet".yellow());
+                return Ok(());
+            }
+
+            println!("{}", "Maintenance History (UTC)".cyan().bold());
+            println!();
+            let rows: Vec<MaintenanceReportRow> = reports.iter().map(Into::into).collect();
+            println!("{}", Table::new(rows));
+
+            for report in reports.iter().filter(|r| !r.errors.is_empty()) {
+                println!();
+                println!(
+                    "  {} Errors in run started at {}:",
+                    "✗".red(),
+                    MaintenanceReportRow::from(report).started
+                );
+                for error in &report.errors {
+                    println!("    - {}", error);
+                }
+            }
+        }
+
+        Commands::Maintenance {
+            dry_run: true,
+            retention_days,
+            ..
+        } => {
+            let params = json!({ "dry_run": true, "retention_days": retention_days });
+            let result = call_rpc(&cli.rpc_url, "admin.maintenance.v1", params).await?;
+            let preview = &result["preview"];
+            let list = |key: &str| -> Vec<String> {
+                preview[key]
+                    .as_array()
+                    .map(|items| {
+                        items
+                            .iter()
+                            .filter_map(|v| v.as_str())
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            };
+
+            println!(
+                "{}",
+                format!(
+                    "Dry run (retention {} days), nothing deleted",
+                    retention_days
+                )
+                .cyan()
+                .bold()
+            );
+            for (label, items) in [
+                ("jobs", list("job_ids")),
+                ("log files", list("log_files")),
+                ("artifacts", list("artifacts")),
+            ] {
+                println!();
+                println!(
+                    "  {} {} would be deleted",
+                    items.len().to_string().bold(),
+                    label
+                );
+                for item in items.iter().take(DRY_RUN_SHOWN) {
+                    println!("    - {}", item);
+                }
+                if items.len() > DRY_RUN_SHOWN {
+                    println!("    ... and {} more", items.len() - DRY_RUN_SHOWN);
+                }
+            }
+            println!();
+            println!(
+                "  {} {:.2} MB (logs {:.2} MB, rows ~{:.2} MB after VACUUM)",
+                "Reclaimable:".bold(),
+                result["bytes_reclaimable"].as_u64().unwrap_or(0) as f64 / (1024.0 * 1024.0),
+                preview["log_bytes"].as_u64().unwrap_or(0) as f64 / (1024.0 * 1024.0),
+                preview["row_bytes"].as_u64().unwrap_or(0) as f64 / (1024.0 * 1024.0)
+            );
+        }
+
+        Commands::Maintenance {
+            force_vacuum,
+            retention_days,
+            ..
+        } => {
             println!("{}", "Running maintenance...".cyan().bold());
             println!();
 
@@ -228,7 +2015,7 @@ async fn main() -> Result<()> {
                 println!("  {} Force VACUUM enabled", "•".bold());
             }
 
-            let params = json!({ "force_vacuum": force_vacuum });
+            let params = json!({ "force_vacuum": force_vacuum, "retention_days": retention_days });
 
             match call_rpc(&cli.rpc_url, "admin.maintenance.v1", params).await {
                 Ok(result) => {
@@ -266,6 +2053,308 @@ async fn main() -> Result<()> {
                 }
             }
         }
+
+        Commands::JobType { action } => {
+            let (method, job_type) = match action {
+                JobTypeAction::Disable { job_type } => ("admin.job_type.disable.v1", job_type),
+                JobTypeAction::Enable { job_type } => ("admin.job_type.enable.v1", job_type),
+            };
+
+            let result = call_rpc(&cli.rpc_url, method, json!({ "job_type": job_type })).await?;
+
+            if result["disabled"].as_bool().unwrap_or(false) {
+                println!(
+                    "{}",
+                    format!("✓ Job type {} disabled", job_type).green().bold()
+                );
+            } else {
+                println!(
+                    "{}",
+                    format!("✓ Job type {} enabled", job_type).green().bold()
+                );
+            }
+        }
+
+        Commands::RetryPolicy { action } => {
+            let result = match action {
+                RetryPolicyAction::List => {
+                    call_rpc(&cli.rpc_url, "admin.retry_policy.list.v1", json!({})).await?
+                }
+                RetryPolicyAction::Set {
+                    job_type,
+                    max_attempts,
+                    base_delay_ms,
+                    backoff_factor,
+                } => {
+                    let params = json!({
+                        "job_type": job_type,
+                        "max_attempts": max_attempts,
+                        "base_delay_ms": base_delay_ms,
+                        "backoff_factor": backoff_factor,
+                    });
+                    let result =
+                        call_rpc(&cli.rpc_url, "admin.retry_policy.set.v1", params).await?;
+                    println!(
+                        "{}",
+                        format!("✓ Retry policy of {} set", job_type).green().bold()
+                    );
+                    result
+                }
+                RetryPolicyAction::Reset { job_type } => {
+                    let result = call_rpc(
+                        &cli.rpc_url,
+                        "admin.retry_policy.reset.v1",
+                        json!({ "job_type": job_type }),
+                    )
+                    .await?;
+                    println!(
+                        "{}",
+                        format!("✓ {} uses the default retry policy", job_type)
+                            .green()
+                            .bold()
+                    );
+                    result
+                }
+            };
+
+            println!(
+                "Default: job's own attempts, first retry after {} ms, quarantine after {} panics",
+                result["default_base_delay_ms"], result["max_panics"]
+            );
+            println!(
+                "Limits:  at most {} ms between retries, retry deadline {}",
+                result["max_delay_ms"],
+                result["retry_deadline_ms"]
+                    .as_i64()
+                    .map_or_else(|| "none".to_string(), |ms| format!("{} ms", ms))
+            );
+            let policies = result["policies"].as_object().cloned().unwrap_or_default();
+            if policies.is_empty() {
+                println!("{}", "No job type rules".yellow());
+            }
+            for (job_type, rule) in policies {
+                let value = |key: &str| {
+                    rule.get(key)
+                        .map_or_else(|| "-".to_string(), ToString::to_string)
+                };
+                println!(
+                    "  {:<20} attempts {:<4} base delay {:<8} backoff {}",
+                    job_type.bold(),
+                    value("max_attempts"),
+                    value("base_delay_ms"),
+                    value("backoff_factor")
+                );
+            }
+        }
+
+        Commands::Queue { action } => {
+            let (method, queue) = match action {
+                QueueAction::Pause { queue } => ("admin.queue.pause.v1", queue),
+                QueueAction::Resume { queue } => ("admin.queue.resume.v1", queue),
+            };
+
+            let result = call_rpc(&cli.rpc_url, method, json!({ "queue": queue })).await?;
+
+            if result["paused"].as_bool().unwrap_or(false) {
+                println!("{}", format!("✓ Queue {} paused", queue).green().bold());
+            } else {
+                println!("{}", format!("✓ Queue {} resumed", queue).green().bold());
+            }
+        }
+
+        Commands::Dlq {
+            action: DlqAction::List { queue, limit },
+        } => {
+            let result = call_rpc(
+                &cli.rpc_url,
+                "dlq.list.v1",
+                json!({ "queue": queue, "limit": limit }),
+            )
+            .await?;
+            let dead_letters: Vec<DeadLetterRow> =
+                serde_json::from_value(result["dead_letters"].clone())?;
+
+            if dead_letters.is_empty() {
+                println!("{}", "Dead-letter queue is empty".green());
+                return Ok(());
+            }
+
+            println!(
+                "{}",
+                format!("{} dead-lettered job(s):", dead_letters.len())
+                    .cyan()
+                    .bold()
+            );
+            println!("{}", Table::new(dead_letters));
+        }
+
+        Commands::Dlq {
+            action:
+                DlqAction::Requeue {
+                    job_ids,
+                    all,
+                    queue,
+                    reason,
+                },
+        } => {
+            let params = json!({
+                "job_ids": job_ids,
+                "all": all,
+                "queue": queue,
+                "reason": reason,
+                "actor": "cli",
+            });
+            let result = call_rpc(&cli.rpc_url, "dlq.requeue.v1", params).await?;
+
+            let requeued = result["requeued"].as_array().map_or(0, Vec::len);
+            println!(
+                "{}",
+                format!("✓ {} job(s) requeued", requeued).green().bold()
+            );
+            let errors = result["errors"].as_array().cloned().unwrap_or_default();
+            for error in errors {
+                println!(
+                    "{} {}: {}",
+                    "Skipped".yellow(),
+                    error["job_id"].as_str().unwrap_or_default(),
+                    error["error"].as_str().unwrap_or_default()
+                );
+            }
+        }
+
+        Commands::Dlq {
+            action:
+                DlqAction::Purge {
+                    queue,
+                    older_than_days,
+                },
+        } => {
+            let before = older_than_days
+                .map(|days| chrono::Utc::now().timestamp_millis() - days * 86_400_000);
+            let result = call_rpc(
+                &cli.rpc_url,
+                "dlq.purge.v1",
+                json!({ "queue": queue, "before": before }),
+            )
+            .await?;
+
+            println!(
+                "{}",
+                format!(
+                    "✓ {} dead-letter entr(ies) purged",
+                    result["purged"].as_u64().unwrap_or(0)
+                )
+                .green()
+                .bold()
+            );
+        }
+
+        Commands::Lockdown { action } => {
+            let params = match action {
+                LockdownAction::On { reason } => json!({ "enabled": true, "reason": reason }),
+                LockdownAction::Off => json!({ "enabled": false }),
+                LockdownAction::Status => json!({}),
+            };
+
+            let result = call_rpc(&cli.rpc_url, "admin.lockdown.v1", params).await?;
+
+            if result["locked_down"].as_bool().unwrap_or(false) {
+                let reason = result["reason"]
+                    .as_str()
+                    .map(|reason| format!(" ({})", reason))
+                    .unwrap_or_default();
+                println!(
+                    "{}",
+                    format!(
+                        "⛔ Lockdown engaged by {} since {}{}",
+                        result["actor"].as_str().unwrap_or("-"),
+                        result["since"]
+                            .as_i64()
+                            .map_or_else(|| "-".to_string(), format_millis),
+                        reason
+                    )
+                    .red()
+                    .bold()
+                );
+                println!("   Subprocess execution is refused (enqueue, retry, worker lease)");
+            } else {
+                println!("{}", "✓ Lockdown lifted".green().bold());
+            }
+        }
+
+        Commands::Daemon { action } => {
+            daemon::run(&cli.rpc_url, action).await?;
+        }
+
+        Commands::Drain { resume, timeout } => {
+            let params = json!({ "resume": resume, "timeout_ms": timeout * 1000 });
+            let result = call_rpc(&cli.rpc_url, "admin.drain.v1", params).await?;
+
+            if !result["draining"].as_bool().unwrap_or(false) {
+                println!("{}", "✓ Drain ended, popping again".green().bold());
+            } else if result["drained"].as_bool().unwrap_or(false) {
+                println!(
+                    "{}",
+                    "✓ Drained: no job in flight, the daemon can be stopped"
+                        .green()
+                        .bold()
+                );
+            } else {
+                anyhow::bail!(
+                    "Timed out after {}s, {} job(s) still in flight (the drain stays on)",
+                    timeout,
+                    result["in_flight"].as_u64().unwrap_or(0)
+                );
+            }
+        }
+
+        Commands::Snapshot {
+            out,
+            queue,
+            anonymize,
+        } => {
+            let snapshot = call_rpc(
+                &cli.rpc_url,
+                "admin.snapshot.v1",
+                json!({ "queue": queue, "anonymize": anonymize }),
+            )
+            .await?;
+            let jobs = snapshot["jobs"].as_array().map_or(0, Vec::len);
+
+            std::fs::write(&out, serde_json::to_vec_pretty(&snapshot)?)
+                .with_context(|| format!("Failed to write {}", out.display()))?;
+
+            println!(
+                "{}",
+                format!("✓ Snapshot of {} job(s) written to {}", jobs, out.display())
+                    .green()
+                    .bold()
+            );
+        }
+
+        Commands::Restore { file } => {
+            let content = std::fs::read(&file)
+                .with_context(|| format!("Failed to read {}", file.display()))?;
+            let snapshot: serde_json::Value = serde_json::from_slice(&content)
+                .with_context(|| format!("{} is not a snapshot file", file.display()))?;
+
+            let result = call_rpc(
+                &cli.rpc_url,
+                "admin.restore.v1",
+                json!({ "snapshot": snapshot }),
+            )
+            .await?;
+
+            println!(
+                "{}",
+                format!(
+                    "✓ Restored {} job(s), {} subject(s)",
+                    result["jobs_restored"], result["subjects_restored"]
+                )
+                .green()
+                .bold()
+            );
+        }
     }
 
     Ok(())
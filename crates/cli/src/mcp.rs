@@ -0,0 +1,281 @@
+//! `semantica mcp` - Model Context Protocol server over stdio
+//!
+//! Coding agents (Claude, Cursor, ...) launch `semantica mcp` as an MCP
+//! server and schedule / monitor background tasks through its tools. Each
+//! tool is one JSON-RPC call to the daemon at `--rpc-url`; the daemon must be
+//! running. Messages are newline-delimited JSON-RPC 2.0 on stdin / stdout.
+
+use crate::call_rpc;
+use anyhow::Result;
+use serde_json::{json, Map, Value};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+/// MCP revisions this server speaks (tools only), newest last
+const SUPPORTED_PROTOCOL_VERSIONS: &[&str] = &["2024-11-05", "2025-03-26", "2025-06-18"];
+
+const SERVER_NAME: &str = "semantica-task-engine";
+
+/// Actor recorded on cancellations made by an agent
+const MCP_ACTOR: &str = "mcp";
+
+// JSON-RPC error codes
+const PARSE_ERROR: i32 = -32700;
+const METHOD_NOT_FOUND: i32 = -32601;
+const INVALID_PARAMS: i32 = -32602;
+
+/// Serve MCP requests from stdin until it is closed
+pub async fn serve(rpc_url: &str) -> Result<()> {
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    let mut stdout = tokio::io::stdout();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match serde_json::from_str::<Value>(&line) {
+            Ok(message) => {
+                // Notifications (no id) get no response
+                let Some(id) = message.get("id").cloned() else {
+                    continue;
+                };
+                let method = message["method"].as_str().unwrap_or_default();
+                let params = message.get("params").cloned().unwrap_or(Value::Null);
+                match handle(rpc_url, method, params).await {
+                    Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                    Err((code, message)) => error_response(id, code, &message),
+                }
+            }
+            Err(e) => error_response(Value::Null, PARSE_ERROR, &e.to_string()),
+        };
+
+        let mut out = serde_json::to_vec(&response)?;
+        out.push(b'\n');
+        stdout.write_all(&out).await?;
+        stdout.flush().await?;
+    }
+
+    Ok(())
+}
+
+fn error_response(id: Value, code: i32, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+async fn handle(rpc_url: &str, method: &str, params: Value) -> Result<Value, (i32, String)> {
+    match method {
+        "initialize" => Ok(initialize_result(&params)),
+        "ping" => Ok(json!({})),
+        "tools/list" => Ok(json!({ "tools": tools() })),
+        "tools/call" => {
+            let name = params["name"].as_str().unwrap_or_default();
+            let arguments = params.get("arguments").cloned().unwrap_or(Value::Null);
+            let (rpc_method, rpc_params) = rpc_call(name, arguments)
+                .ok_or_else(|| (INVALID_PARAMS, format!("Unknown tool: {}", name)))?;
+
+            // Daemon errors are tool results the agent can read and react to
+            Ok(match call_rpc(rpc_url, rpc_method, rpc_params).await {
+                Ok(result) => tool_result(
+                    serde_json::to_string_pretty(&result).unwrap_or_default(),
+                    false,
+                ),
+                Err(e) => tool_result(e.to_string(), true),
+            })
+        }
+        _ => Err((METHOD_NOT_FOUND, format!("Method not found: {}", method))),
+    }
+}
+
+/// Echo the client's protocol version when supported, else offer the newest
+fn initialize_result(params: &Value) -> Value {
+    let requested = params["protocolVersion"].as_str().unwrap_or_default();
+    let version = SUPPORTED_PROTOCOL_VERSIONS
+        .iter()
+        .find(|&&v| v == requested)
+        .or(SUPPORTED_PROTOCOL_VERSIONS.last())
+        .copied()
+        .unwrap_or_default();
+
+    json!({
+        "protocolVersion": version,
+        "capabilities": { "tools": {} },
+        "serverInfo": { "name": SERVER_NAME, "version": env!("CARGO_PKG_VERSION") },
+    })
+}
+
+fn tool_result(text: String, is_error: bool) -> Value {
+    json!({ "content": [{ "type": "text", "text": text }], "isError": is_error })
+}
+
+/// Daemon method and params for a tool call (None = unknown tool)
+fn rpc_call(tool: &str, arguments: Value) -> Option<(&'static str, Value)> {
+    let mut params = match arguments {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    };
+    let method = match tool {
+        "enqueue_task" => {
+            params.entry("queue").or_insert_with(|| json!("default"));
+            "dev.enqueue.v1"
+        }
+        "list_tasks" => "dev.list.v1",
+        "get_task" => "dev.inspect.v1",
+        "cancel_task" => {
+            params.insert("actor".to_string(), json!(MCP_ACTOR));
+            "dev.cancel.v1"
+        }
+        "task_logs" => "logs.tail.v1",
+        _ => return None,
+    };
+    Some((method, Value::Object(params)))
+}
+
+/// Tool descriptions for tools/list
+fn tools() -> Value {
+    let job_id = json!({ "type": "string", "description": "Job ID returned by enqueue_task" });
+    json!([
+        {
+            "name": "enqueue_task",
+            "description": "Schedule a background task (build, test, indexing, ...) on the Semantica daemon. \
+                A newer task with the same subject_key supersedes queued older ones. \
+                Returns the job_id used by the other tools.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "job_type": { "type": "string", "description": "Kind of task, e.g. BUILD, TEST, INDEX_FILE" },
+                    "subject_key": { "type": "string", "description": "What the task is about, e.g. 'repo::src/main.rs'" },
+                    "payload": {
+                        "type": "object",
+                        "description": "Subprocess to run: {\"command\": \"cargo\", \"args\": [\"test\"]}",
+                    },
+                    "queue": { "type": "string", "description": "Queue name (default: default)" },
+                    "priority": { "type": "integer", "description": "Higher runs first (default 0)" },
+                },
+                "required": ["job_type", "subject_key", "payload"],
+            },
+        },
+        {
+            "name": "list_tasks",
+            "description": "List tasks, oldest first. Filter by queue, state (QUEUED, RUNNING, DONE, FAILED, \
+                CANCELLED, SUPERSEDED, ...) or subject_key; pass next_cursor as after for the next page.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "queue": { "type": "string" },
+                    "state": { "type": "string" },
+                    "subject_key": { "type": "string" },
+                    "after": { "type": "string", "description": "next_cursor of the previous page" },
+                    "limit": { "type": "integer", "description": "Page size (default 50)" },
+                },
+            },
+        },
+        {
+            "name": "get_task",
+            "description": "State, timestamps and event history of a task.",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "job_id": job_id },
+                "required": ["job_id"],
+            },
+        },
+        {
+            "name": "cancel_task",
+            "description": "Cancel a queued or running task.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "job_id": job_id,
+                    "reason": { "type": "string", "description": "Recorded in the task's history" },
+                },
+                "required": ["job_id"],
+            },
+        },
+        {
+            "name": "task_logs",
+            "description": "Last output lines of a task (stdout and stderr, tagged).",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "job_id": job_id,
+                    "lines": { "type": "integer", "description": "Number of lines (default 50)" },
+                    "stream": { "type": "string", "enum": ["stdout", "stderr"] },
+                },
+                "required": ["job_id"],
+            },
+        },
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handshake_and_tool_listing() {
+        // No daemon is needed until a tool is called
+        let url = "http://127.0.0.1:1";
+
+        let init = handle(
+            url,
+            "initialize",
+            json!({ "protocolVersion": "2024-11-05" }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(init["protocolVersion"], "2024-11-05");
+        assert!(init["capabilities"]["tools"].is_object());
+
+        let init = handle(
+            url,
+            "initialize",
+            json!({ "protocolVersion": "1999-01-01" }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            init["protocolVersion"],
+            *SUPPORTED_PROTOCOL_VERSIONS.last().unwrap()
+        );
+
+        let listed = handle(url, "tools/list", Value::Null).await.unwrap();
+        let names: Vec<&str> = listed["tools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|tool| tool["name"].as_str().unwrap())
+            .collect();
+        for name in names {
+            assert!(rpc_call(name, Value::Null).is_some(), "{} is routed", name);
+        }
+
+        let unknown = handle(url, "resources/list", Value::Null).await;
+        assert_eq!(unknown.unwrap_err().0, METHOD_NOT_FOUND);
+
+        // Daemon down: reported to the agent as a failed tool call
+        let result = handle(
+            url,
+            "tools/call",
+            json!({ "name": "get_task", "arguments": { "job_id": "j1" } }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result["isError"], true);
+    }
+
+    #[test]
+    fn test_tool_arguments_become_rpc_params() {
+        let (method, params) = rpc_call(
+            "enqueue_task",
+            json!({ "job_type": "TEST", "subject_key": "repo", "payload": {} }),
+        )
+        .unwrap();
+        assert_eq!(method, "dev.enqueue.v1");
+        assert_eq!(params["queue"], "default");
+
+        let (method, params) = rpc_call("cancel_task", json!({ "job_id": "j1" })).unwrap();
+        assert_eq!(method, "dev.cancel.v1");
+        assert_eq!(params["actor"], MCP_ACTOR);
+
+        assert!(rpc_call("rm_rf", json!({})).is_none());
+    }
+}
@@ -0,0 +1,106 @@
+//! JSON-RPC over the daemon's Unix socket (`--rpc-url unix:///path/to/semantica.sock`)
+//!
+//! reqwest cannot dial Unix sockets, so the CLI speaks the one HTTP/1.1
+//! exchange it needs itself: a single POST per connection (`Connection: close`).
+
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+
+/// POST `body` to the daemon listening on `socket` and return the response body
+#[cfg(unix)]
+pub async fn post_json(socket: &Path, body: &[u8]) -> Result<Vec<u8>> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let mut stream = tokio::net::UnixStream::connect(socket)
+        .await
+        .with_context(|| format!("Failed to connect to daemon at {}", socket.display()))?;
+
+    let head = format!(
+        "POST / HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(body).await?;
+
+    let mut response = Vec::new();
+    stream
+        .read_to_end(&mut response)
+        .await
+        .context("Failed to read daemon response")?;
+    parse_response(&response)
+}
+
+#[cfg(not(unix))]
+pub async fn post_json(socket: &Path, _body: &[u8]) -> Result<Vec<u8>> {
+    bail!(
+        "Unix socket {} is not supported on this platform",
+        socket.display()
+    )
+}
+
+/// Body of a complete HTTP/1.1 response (Content-Length or chunked)
+fn parse_response(response: &[u8]) -> Result<Vec<u8>> {
+    let split = response
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .context("Malformed HTTP response from daemon")?;
+    let head = String::from_utf8_lossy(&response[..split]);
+    let body = &response[split + 4..];
+
+    let status = head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .unwrap_or_default();
+    if status != "200" {
+        bail!("Daemon answered HTTP {}", status);
+    }
+
+    let chunked = head.lines().any(|line| {
+        line.to_ascii_lowercase()
+            .strip_prefix("transfer-encoding:")
+            .is_some_and(|value| value.contains("chunked"))
+    });
+    if !chunked {
+        return Ok(body.to_vec());
+    }
+
+    let mut decoded = Vec::new();
+    let mut rest = body;
+    loop {
+        let line_end = rest
+            .windows(2)
+            .position(|w| w == b"\r\n")
+            .context("Malformed chunked response")?;
+        let size_line = String::from_utf8_lossy(&rest[..line_end]);
+        let size = usize::from_str_radix(size_line.split(';').next().unwrap_or("").trim(), 16)
+            .context("Malformed chunk size")?;
+        rest = &rest[line_end + 2..];
+        if size == 0 {
+            return Ok(decoded);
+        }
+        if rest.len() < size {
+            bail!("Truncated chunked response");
+        }
+        decoded.extend_from_slice(&rest[..size]);
+        rest = rest.get(size + 2..).unwrap_or_default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_response_bodies() {
+        let plain = b"HTTP/1.1 200 OK\r\ncontent-length: 7\r\n\r\n{\"a\":1}";
+        assert_eq!(parse_response(plain).unwrap(), b"{\"a\":1}");
+
+        let chunked =
+            b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n{\"a\"\r\n3\r\n:1}\r\n0\r\n\r\n";
+        assert_eq!(parse_response(chunked).unwrap(), b"{\"a\":1}");
+
+        let error = b"HTTP/1.1 413 Payload Too Large\r\ncontent-length: 0\r\n\r\n";
+        assert!(parse_response(error).is_err());
+    }
+}
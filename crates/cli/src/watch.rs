@@ -0,0 +1,354 @@
+//! `semantica watch` - Enqueue a job per changed file under a directory
+//!
+//! File events are debounced per path: a job is enqueued once a path has been
+//! quiet for `--debounce-ms`, so an editor's save storm or a `git checkout`
+//! produces one job per file, not one per event. Quiet paths are flushed
+//! together, at most `--max-batch` per tick. When the daemon rate-limits the
+//! queue the unsent paths stay pending (further events coalesce into them)
+//! and flushing pauses, so a large monorepo never floods the queue.
+//!
+//! Paths matching an ignore glob (`target/`, `node_modules/`, `.git/` by
+//! default) are dropped before debouncing.
+
+use crate::backfill::THROTTLED;
+use crate::{call_rpc, JsonRpcError};
+use anyhow::{Context, Result};
+use colored::Colorize;
+use glob::Pattern;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+/// Build output, dependencies and VCS metadata
+const DEFAULT_IGNORES: &[&str] = &["**/target/**", "**/node_modules/**", "**/.git/**"];
+
+/// How often quiet paths are looked for
+const FLUSH_TICK: Duration = Duration::from_millis(100);
+
+/// Pause after the daemon rate-limited the queue
+const THROTTLE_BACKOFF: Duration = Duration::from_millis(500);
+
+pub struct WatchArgs {
+    pub root: PathBuf,
+    pub job_type: String,
+    pub queue: String,
+    pub priority: i32,
+    pub payload: Option<String>,
+    /// Only these files (relative globs), empty = every file
+    pub include: Vec<String>,
+    /// Extra ignore globs (added to DEFAULT_IGNORES unless no_default_ignores)
+    pub ignore: Vec<String>,
+    pub no_default_ignores: bool,
+    pub debounce: Duration,
+    pub max_batch: usize,
+    pub stats_interval: Duration,
+}
+
+/// Include / ignore rules applied to subjects (`/`-separated relative paths)
+#[derive(Debug)]
+struct PathRules {
+    include: Vec<Pattern>,
+    ignore: Vec<Pattern>,
+}
+
+impl PathRules {
+    fn new(args: &WatchArgs) -> Result<Self> {
+        let defaults = DEFAULT_IGNORES
+            .iter()
+            .filter(|_| !args.no_default_ignores)
+            .map(|p| p.to_string());
+        let compile = |patterns: Vec<String>| -> Result<Vec<Pattern>> {
+            patterns
+                .iter()
+                .map(|p| Pattern::new(p).with_context(|| format!("Invalid glob '{}'", p)))
+                .collect()
+        };
+        Ok(Self {
+            include: compile(args.include.clone())?,
+            ignore: compile(defaults.chain(args.ignore.iter().cloned()).collect())?,
+        })
+    }
+
+    fn accepts(&self, subject: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|p| p.matches(subject)))
+            && !self.ignore.iter().any(|p| p.matches(subject))
+    }
+}
+
+/// Per-path debouncer: a path is due once no event touched it for `quiet`
+#[derive(Debug)]
+struct Debouncer {
+    quiet: Duration,
+    /// Last event per pending path
+    pending: HashMap<String, Instant>,
+}
+
+impl Debouncer {
+    fn new(quiet: Duration) -> Self {
+        Self {
+            quiet,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Record an event; false when it coalesced into a pending path
+    fn record(&mut self, subject: String, now: Instant) -> bool {
+        self.pending.insert(subject, now).is_none()
+    }
+
+    /// Up to `max` quiet paths (oldest first), removed from the pending set
+    fn take_due(&mut self, now: Instant, max: usize) -> Vec<String> {
+        let mut due: Vec<(Instant, String)> = self
+            .pending
+            .iter()
+            .filter(|(_, &last)| now.duration_since(last) >= self.quiet)
+            .map(|(subject, &last)| (last, subject.clone()))
+            .collect();
+        due.sort();
+        due.truncate(max);
+
+        due.into_iter()
+            .map(|(_, subject)| {
+                self.pending.remove(&subject);
+                subject
+            })
+            .collect()
+    }
+
+    /// Put back a path that could not be enqueued (a newer event wins)
+    fn restore(&mut self, subject: String, last: Instant) {
+        self.pending.entry(subject).or_insert(last);
+    }
+
+    fn len(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+/// Events seen vs jobs enqueued
+#[derive(Debug, Default)]
+struct WatchStats {
+    events_seen: u64,
+    events_ignored: u64,
+    /// Events merged into an already pending path
+    events_coalesced: u64,
+    jobs_enqueued: u64,
+    throttled: u64,
+}
+
+impl WatchStats {
+    fn summary(&self, pending: usize) -> String {
+        format!(
+            "{} events ({} ignored, {} coalesced) -> {} jobs, {} pending, throttled {} times",
+            self.events_seen,
+            self.events_ignored,
+            self.events_coalesced,
+            self.jobs_enqueued,
+            pending,
+            self.throttled
+        )
+    }
+}
+
+pub async fn run(rpc_url: &str, args: WatchArgs) -> Result<()> {
+    let root = args
+        .root
+        .canonicalize()
+        .with_context(|| format!("Cannot watch {}", args.root.display()))?;
+    let rules = PathRules::new(&args)?;
+    let payload_template: Option<serde_json::Value> = args
+        .payload
+        .as_deref()
+        .map(serde_json::from_str)
+        .transpose()
+        .context("Invalid JSON payload")?;
+
+    let (tx, mut events) = mpsc::unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+        // Err = the watch loop ended, nothing left to notify
+        let _ = tx.send(event);
+    })
+    .context("Failed to start the file watcher")?;
+    watcher
+        .watch(&root, RecursiveMode::Recursive)
+        .with_context(|| format!("Failed to watch {}", root.display()))?;
+    println!(
+        "Watching {} ({} on queue '{}', debounce {}ms)",
+        root.display(),
+        args.job_type,
+        args.queue,
+        args.debounce.as_millis()
+    );
+
+    let mut debouncer = Debouncer::new(args.debounce);
+    let mut stats = WatchStats::default();
+    let mut flush = tokio::time::interval(FLUSH_TICK);
+    let mut report = tokio::time::interval(args.stats_interval);
+    report.tick().await;
+    let shutdown = tokio::signal::ctrl_c();
+    tokio::pin!(shutdown);
+
+    loop {
+        tokio::select! {
+            event = events.recv() => {
+                let Some(event) = event else { break };
+                match event {
+                    Ok(event) => {
+                        for subject in subjects(&root, &event) {
+                            stats.events_seen += 1;
+                            if !rules.accepts(&subject) {
+                                stats.events_ignored += 1;
+                            } else if !debouncer.record(subject, Instant::now()) {
+                                stats.events_coalesced += 1;
+                            }
+                        }
+                    }
+                    Err(e) => eprintln!("{} {}", "Watch error:".yellow(), e),
+                }
+            }
+            _ = flush.tick() => {
+                let due = debouncer.take_due(Instant::now(), args.max_batch.max(1));
+                let mut due = due.into_iter();
+                while let Some(subject) = due.next() {
+                    let params = json!({
+                        "job_type": args.job_type,
+                        "queue": args.queue,
+                        "subject_key": subject,
+                        "priority": args.priority,
+                        "payload": payload_template.clone().unwrap_or_else(|| json!({ "path": subject })),
+                    });
+                    match call_rpc(rpc_url, "dev.enqueue.v1", params).await {
+                        Ok(_) => stats.jobs_enqueued += 1,
+                        Err(e) if e.downcast_ref::<JsonRpcError>().is_some_and(|e| e.code == THROTTLED) => {
+                            // Backpressure: keep the rest pending and let the queue drain
+                            stats.throttled += 1;
+                            let now = Instant::now();
+                            for subject in std::iter::once(subject).chain(due.by_ref()) {
+                                debouncer.restore(subject, now);
+                            }
+                            tokio::time::sleep(THROTTLE_BACKOFF).await;
+                        }
+                        Err(e) => eprintln!("{} {}: {}", "Failed to enqueue".red(), subject, e),
+                    }
+                }
+            }
+            _ = report.tick() => println!("  {}", stats.summary(debouncer.len())),
+            _ = &mut shutdown => break,
+        }
+    }
+
+    println!(
+        "{}",
+        format!("✓ Watch stopped: {}", stats.summary(debouncer.len()))
+            .green()
+            .bold()
+    );
+    Ok(())
+}
+
+/// Changed files of an event as `/`-separated paths relative to `root`
+fn subjects(root: &Path, event: &Event) -> Vec<String> {
+    if matches!(event.kind, EventKind::Access(_) | EventKind::Other) {
+        return Vec::new();
+    }
+    event
+        .paths
+        .iter()
+        // Removed paths no longer exist; keep them so consumers can drop the file
+        .filter(|path| !path.is_dir())
+        .filter_map(|path| path.strip_prefix(root).ok())
+        .map(|relative| {
+            relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+        .filter(|subject| !subject.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args() -> WatchArgs {
+        WatchArgs {
+            root: PathBuf::from("."),
+            job_type: "INDEX_FILE".to_string(),
+            queue: "code_intel".to_string(),
+            priority: 0,
+            payload: None,
+            include: Vec::new(),
+            ignore: Vec::new(),
+            no_default_ignores: false,
+            debounce: Duration::from_millis(200),
+            max_batch: 100,
+            stats_interval: Duration::from_secs(30),
+        }
+    }
+
+    #[test]
+    fn test_default_and_custom_ignores() {
+        let mut args = args();
+        args.include = vec!["**/*.rs".to_string()];
+        args.ignore = vec!["generated/**".to_string()];
+        let rules = PathRules::new(&args).unwrap();
+
+        assert!(rules.accepts("src/main.rs"));
+        assert!(rules.accepts("main.rs"));
+        assert!(!rules.accepts("src/notes.txt"), "not included");
+        assert!(!rules.accepts("target/debug/build.rs"));
+        assert!(!rules.accepts("web/node_modules/pkg/index.rs"));
+        assert!(!rules.accepts(".git/hooks/x.rs"));
+        assert!(!rules.accepts("generated/api.rs"));
+
+        args.no_default_ignores = true;
+        let rules = PathRules::new(&args).unwrap();
+        assert!(rules.accepts("target/debug/build.rs"));
+    }
+
+    #[test]
+    fn test_burst_is_debounced_per_path() {
+        let quiet = Duration::from_millis(200);
+        let mut debouncer = Debouncer::new(quiet);
+        let start = Instant::now();
+
+        // Save storm on one file, single save on another
+        assert!(debouncer.record("a.rs".to_string(), start));
+        assert!(debouncer.record("b.rs".to_string(), start));
+        for ms in [50, 100, 150] {
+            assert!(!debouncer.record("a.rs".to_string(), start + Duration::from_millis(ms)));
+        }
+
+        let due = debouncer.take_due(start + quiet, 10);
+        assert_eq!(due, vec!["b.rs".to_string()]);
+        assert!(debouncer.take_due(start + quiet, 10).is_empty());
+
+        let due = debouncer.take_due(start + Duration::from_millis(350), 10);
+        assert_eq!(due, vec!["a.rs".to_string()]);
+        assert_eq!(debouncer.len(), 0);
+    }
+
+    #[test]
+    fn test_batches_are_capped_and_throttled_paths_kept() {
+        let mut debouncer = Debouncer::new(Duration::ZERO);
+        let start = Instant::now();
+        for (i, subject) in ["a.rs", "b.rs", "c.rs"].iter().enumerate() {
+            debouncer.record(subject.to_string(), start + Duration::from_millis(i as u64));
+        }
+
+        let now = start + Duration::from_secs(1);
+        let batch = debouncer.take_due(now, 2);
+        assert_eq!(batch, vec!["a.rs".to_string(), "b.rs".to_string()]);
+
+        // b.rs was throttled while a newer event arrived for it
+        debouncer.record("b.rs".to_string(), now);
+        debouncer.restore("b.rs".to_string(), start);
+        assert_eq!(debouncer.len(), 2);
+        assert_eq!(debouncer.pending["b.rs"], now);
+    }
+}
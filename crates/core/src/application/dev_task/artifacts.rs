@@ -0,0 +1,95 @@
+// Artifact listing and retrieval (job.artifacts.list.v1 / job.artifacts.read.v1)
+//
+// `job.artifacts` only holds URIs. Listing joins them with the metadata
+// recorded at registration; reading goes through the artifact storage in
+// bounded chunks so large bundles never travel in one response.
+
+use crate::domain::{Artifact, JobId};
+use crate::error::{AppError, Result};
+use crate::port::{ArtifactStorage, JobRepository};
+
+/// Bytes returned by a read without an explicit limit
+pub const DEFAULT_ARTIFACT_READ_BYTES: usize = 64 * 1024;
+
+/// Largest chunk a single read returns
+pub const MAX_ARTIFACT_READ_BYTES: usize = 1024 * 1024;
+
+/// Part of an artifact's content
+#[derive(Debug, Clone)]
+pub struct ArtifactChunk {
+    pub artifact: Artifact,
+    pub offset: u64,
+    pub data: Vec<u8>,
+    /// No content follows this chunk
+    pub eof: bool,
+}
+
+/// Artifacts registered for a job, in registration order
+///
+/// Artifacts registered before metadata was recorded only have their URI.
+pub async fn list(job_repo: &dyn JobRepository, job_id: &JobId) -> Result<Vec<Artifact>> {
+    let job = job_repo
+        .find_by_id(job_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Job {} not found", job_id)))?;
+    let mut recorded = job_repo.list_artifacts(job_id).await?;
+
+    Ok(job
+        .artifacts
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .filter(|uri| !uri.is_empty())
+        .map(|uri| match recorded.iter().position(|a| a.uri == uri) {
+            Some(index) => recorded.swap_remove(index),
+            None => Artifact::unknown(uri),
+        })
+        .collect())
+}
+
+/// Read up to `max_bytes` of a job's artifact starting at `offset`
+///
+/// Only artifacts registered for `job_id` can be read.
+pub async fn read(
+    job_repo: &dyn JobRepository,
+    storage: Option<&dyn ArtifactStorage>,
+    job_id: &JobId,
+    uri: &str,
+    offset: u64,
+    max_bytes: usize,
+) -> Result<ArtifactChunk> {
+    if max_bytes == 0 || max_bytes > MAX_ARTIFACT_READ_BYTES {
+        return Err(AppError::Validation(format!(
+            "max_bytes must be 1-{}, got {}",
+            MAX_ARTIFACT_READ_BYTES, max_bytes
+        )));
+    }
+
+    let artifact = list(job_repo, job_id)
+        .await?
+        .into_iter()
+        .find(|artifact| artifact.uri == uri)
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "Artifact {} is not registered for job {}",
+                uri, job_id
+            ))
+        })?;
+    let storage =
+        storage.ok_or_else(|| AppError::Config("No artifact storage configured".to_string()))?;
+
+    // One byte more than asked tells whether content follows
+    let mut data = storage
+        .get_range(uri, offset, max_bytes + 1)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Artifact {} no longer exists", uri)))?;
+    let eof = data.len() <= max_bytes;
+    data.truncate(max_bytes);
+
+    Ok(ArtifactChunk {
+        artifact,
+        offset,
+        data,
+        eof,
+    })
+}
@@ -0,0 +1,123 @@
+// Enqueue Coalescing - Burst absorption per subject
+//
+// Remembers the last job enqueued for each (queue, subject_key). A new enqueue
+// for the same subject within the window overwrites that job's payload and
+// options while it is still QUEUED, instead of inserting a new generation and
+// superseding the old one. Each coalesced enqueue restarts the window.
+
+use crate::domain::JobId;
+use crate::port::elapsed_ms;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+// Entries are pruned once the map grows past this size
+const MAX_PENDING_SUBJECTS: usize = 1024;
+
+struct PendingJob {
+    job_id: JobId,
+    job_type: String,
+    last_enqueued_at: i64,
+}
+
+/// In-memory coalescing buffer (window 0 = disabled)
+pub struct EnqueueCoalescer {
+    window_ms: i64,
+    pending: Mutex<HashMap<(String, String), PendingJob>>,
+}
+
+impl EnqueueCoalescer {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window_ms: window.as_millis() as i64,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Self::new(Duration::ZERO)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.window_ms > 0
+    }
+
+    /// Job an enqueue at `now` may be merged into (same queue, subject and job type)
+    pub fn pending(
+        &self,
+        queue: &str,
+        subject_key: &str,
+        job_type: &str,
+        now: i64,
+    ) -> Option<JobId> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        pending
+            .get(&(queue.to_string(), subject_key.to_string()))
+            .filter(|p| {
+                p.job_type == job_type && elapsed_ms(now, p.last_enqueued_at) < self.window_ms
+            })
+            .map(|p| p.job_id.clone())
+    }
+
+    /// Record the job that absorbed (or was created by) an enqueue at `now`
+    pub fn remember(
+        &self,
+        queue: &str,
+        subject_key: &str,
+        job_type: &str,
+        job_id: &JobId,
+        now: i64,
+    ) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        if pending.len() >= MAX_PENDING_SUBJECTS {
+            let window_ms = self.window_ms;
+            pending.retain(|_, p| elapsed_ms(now, p.last_enqueued_at) < window_ms);
+        }
+        pending.insert(
+            (queue.to_string(), subject_key.to_string()),
+            PendingJob {
+                job_id: job_id.clone(),
+                job_type: job_type.to_string(),
+                last_enqueued_at: now,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_is_per_subject_and_sliding() {
+        let coalescer = EnqueueCoalescer::new(Duration::from_millis(500));
+        coalescer.remember("q", "a.rs", "INDEX", &"job-1".to_string(), 1_000);
+
+        assert_eq!(
+            coalescer.pending("q", "a.rs", "INDEX", 1_400),
+            Some("job-1".to_string())
+        );
+        assert_eq!(coalescer.pending("q", "b.rs", "INDEX", 1_400), None);
+        assert_eq!(coalescer.pending("q", "a.rs", "BUILD", 1_400), None);
+        assert_eq!(coalescer.pending("q", "a.rs", "INDEX", 1_500), None);
+
+        // A coalesced enqueue restarts the window
+        coalescer.remember("q", "a.rs", "INDEX", &"job-1".to_string(), 1_400);
+        assert!(coalescer.pending("q", "a.rs", "INDEX", 1_800).is_some());
+    }
+
+    #[test]
+    fn test_disabled_never_coalesces() {
+        let coalescer = EnqueueCoalescer::disabled();
+        coalescer.remember("q", "a.rs", "INDEX", &"job-1".to_string(), 1_000);
+        assert_eq!(coalescer.pending("q", "a.rs", "INDEX", 1_000), None);
+    }
+}
@@ -0,0 +1,118 @@
+// Dead-letter queue (dlq.list.v1, dlq.requeue.v1, dlq.purge.v1)
+//
+// The worker and the lease service park every job that failed for good with
+// its last error. Requeueing goes through the manual retry (fresh attempt
+// budget, QUEUED event by the actor); purging only drops the entries, the
+// FAILED jobs stay until the finished-job GC removes them.
+
+use super::rerun;
+use crate::domain::{DeadLetter, Job, JobId};
+use crate::error::{AppError, Result};
+use crate::port::{JobRepository, TimeProvider};
+
+/// Entries returned without an explicit limit
+pub const DEFAULT_DLQ_LIMIT: usize = 50;
+
+/// Most entries a single list or bulk requeue handles
+pub const MAX_DLQ_LIMIT: usize = 1000;
+
+/// Newest `limit` dead letters (of `queue`), newest first
+pub async fn list(
+    job_repo: &dyn JobRepository,
+    queue: Option<&str>,
+    limit: usize,
+) -> Result<Vec<DeadLetter>> {
+    check_limit(limit)?;
+    job_repo.list_dead_letters(queue, limit).await
+}
+
+/// Which dead letters to requeue
+#[derive(Debug, Clone)]
+pub enum RequeueTarget {
+    Jobs(Vec<JobId>),
+    /// Every dead letter (of the queue), at most MAX_DLQ_LIMIT per call
+    All {
+        queue: Option<String>,
+    },
+}
+
+/// Outcome of a requeue: per job, requeued or why not
+#[derive(Debug, Default)]
+pub struct RequeueReport {
+    pub requeued: Vec<Job>,
+    pub errors: Vec<(JobId, AppError)>,
+}
+
+/// Requeue dead jobs with a fresh attempt budget
+///
+/// A job that is no longer FAILED (retried meanwhile), was superseded by a
+/// newer generation of its subject or was deleted is reported in `errors`;
+/// the others are requeued regardless.
+pub async fn requeue(
+    job_repo: &dyn JobRepository,
+    time_provider: &dyn TimeProvider,
+    target: RequeueTarget,
+    actor: &str,
+    reason: Option<String>,
+) -> Result<RequeueReport> {
+    let job_ids = match target {
+        RequeueTarget::Jobs(job_ids) if job_ids.is_empty() => {
+            return Err(AppError::Validation(
+                "Name the jobs to requeue or requeue all".into(),
+            ));
+        }
+        RequeueTarget::Jobs(job_ids) if job_ids.len() > MAX_DLQ_LIMIT => {
+            return Err(AppError::Validation(format!(
+                "At most {} jobs can be requeued at once",
+                MAX_DLQ_LIMIT
+            )));
+        }
+        RequeueTarget::Jobs(job_ids) => job_ids,
+        RequeueTarget::All { queue } => job_repo
+            .list_dead_letters(queue.as_deref(), MAX_DLQ_LIMIT)
+            .await?
+            .into_iter()
+            .map(|letter| letter.job_id)
+            .collect(),
+    };
+
+    let reason = reason.unwrap_or_else(|| "requeued from the dead-letter queue".to_string());
+    let mut report = RequeueReport::default();
+    for job_id in job_ids {
+        match rerun::execute(
+            job_repo,
+            time_provider,
+            &job_id,
+            actor,
+            Some(reason.clone()),
+        )
+        .await
+        {
+            Ok(job) => report.requeued.push(job),
+            Err(e @ (AppError::NotFound(_) | AppError::Conflict(_))) => {
+                report.errors.push((job_id, e))
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(report)
+}
+
+/// Drop dead letters (of `queue`) parked before `before`; returns how many
+pub async fn purge(
+    job_repo: &dyn JobRepository,
+    queue: Option<&str>,
+    before: Option<i64>,
+) -> Result<u64> {
+    job_repo.purge_dead_letters(queue, before).await
+}
+
+fn check_limit(limit: usize) -> Result<()> {
+    if limit == 0 || limit > MAX_DLQ_LIMIT {
+        return Err(AppError::Validation(format!(
+            "limit must be 1-{}, got {}",
+            MAX_DLQ_LIMIT, limit
+        )));
+    }
+    Ok(())
+}
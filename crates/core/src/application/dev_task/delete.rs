@@ -0,0 +1,102 @@
+// Purge of a single job (job.delete.v1)
+//
+// Removes a job on demand instead of waiting for the retention GC, e.g. when
+// its payload contains an accidentally pasted secret. The job row and all rows
+// keyed by it go in one transaction; stored artifacts are deleted afterwards.
+
+use crate::domain::{Job, JobId, JobState};
+use crate::error::{AppError, Result};
+use crate::port::{ArtifactStorage, JobRepository};
+use tracing::{info, warn};
+
+/// A deleted job and what happened to its artifacts
+#[derive(Debug, Clone)]
+pub struct DeletedJob {
+    /// The job as it was before deletion (its `log_path` is left to the caller)
+    pub job: Job,
+    /// Artifacts removed from storage
+    pub artifacts_deleted: usize,
+    /// Artifact URIs that could not be removed (or no storage was configured)
+    pub artifacts_left: Vec<String>,
+}
+
+/// Delete a finished job (or a QUEUED one with `force`)
+///
+/// RUNNING jobs are never deleted: cancel them first. Artifacts are deleted
+/// through `artifact_storage` once the rows are gone.
+pub async fn execute(
+    job_repo: &dyn JobRepository,
+    artifact_storage: Option<&dyn ArtifactStorage>,
+    job_id: &JobId,
+    force: bool,
+) -> Result<DeletedJob> {
+    let job = job_repo
+        .find_by_id(job_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Job {} not found", job_id)))?;
+
+    let deletable = deletable_states(force);
+    if !deletable.contains(&job.state) {
+        let hint = match job.state {
+            JobState::Queued | JobState::Requeued => "pass force to delete it",
+            _ => "cancel it first",
+        };
+        return Err(AppError::Conflict(format!(
+            "Job {} is {}, {}",
+            job_id, job.state, hint
+        )));
+    }
+
+    if !job_repo.delete_job(job_id, &deletable).await? {
+        return Err(AppError::Conflict(format!(
+            "Job {} changed state while being deleted",
+            job_id
+        )));
+    }
+    info!(job_id = %job_id, state = %job.state, "Job deleted");
+
+    let mut deleted = DeletedJob {
+        artifacts_deleted: 0,
+        artifacts_left: Vec::new(),
+        job,
+    };
+    let uris = deleted
+        .job
+        .artifacts
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .filter(|uri| !uri.is_empty());
+    for uri in uris {
+        let Some(storage) = artifact_storage else {
+            deleted.artifacts_left.push(uri.to_string());
+            continue;
+        };
+        match storage.delete(uri).await {
+            Ok(true) => deleted.artifacts_deleted += 1,
+            Ok(false) => {}
+            Err(e) => {
+                warn!(uri = %uri, error = %e, "Failed to delete artifact of deleted job");
+                deleted.artifacts_left.push(uri.to_string());
+            }
+        }
+    }
+
+    Ok(deleted)
+}
+
+/// Terminal states, plus the queued ones when forced
+fn deletable_states(force: bool) -> Vec<JobState> {
+    let mut states = vec![
+        JobState::Done,
+        JobState::Failed,
+        JobState::Superseded,
+        JobState::Cancelled,
+        JobState::SkippedTtl,
+        JobState::SkippedDeadline,
+    ];
+    if force {
+        states.extend([JobState::Queued, JobState::Requeued]);
+    }
+    states
+}
@@ -1,8 +1,11 @@
 // Enqueue Use Case
 
-use crate::domain::{Job, JobPayload, JobType};
+use super::coalesce::EnqueueCoalescer;
+use crate::application::interceptor::InterceptorChain;
+use crate::application::payload_validator::QueueValidators;
+use crate::domain::{ExecutionMode, Job, JobClass, JobId, JobPayload, JobType, TraceParent};
 use crate::error::Result;
-use crate::port::{IdProvider, TimeProvider, TransactionalJobRepository};
+use crate::port::{IdProvider, JobRepositoryTransaction, TimeProvider, TransactionalJobRepository};
 use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
@@ -19,6 +22,74 @@ pub struct EnqueueRequest {
 
     #[serde(default)]
     pub priority: i32,
+
+    /// Client-chosen key; re-sending the same key returns the original job ID
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
+
+    /// W3C traceparent of the caller; the job's execution span continues this trace
+    #[serde(default)]
+    pub traceparent: Option<String>,
+
+    /// TASK (default) or SERVICE (runs indefinitely, restarted on exit)
+    #[serde(default)]
+    pub job_class: Option<String>,
+
+    /// Payloads of the retries, in order (attempt 2 runs the first one, ...)
+    #[serde(default)]
+    pub fallback_payloads: Vec<serde_json::Value>,
+
+    /// Scheduling, retry and grouping fields (flat on the wire)
+    #[serde(flatten, default)]
+    pub options: EnqueueOptions,
+}
+
+/// Optional Phase 2/3/4 job fields (None/false = the Job defaults)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EnqueueOptions {
+    /// Earliest start (epoch ms)
+    #[serde(default)]
+    pub schedule_at: Option<i64>,
+
+    /// Skip (SKIPPED_DEADLINE) if not finished by then (epoch ms)
+    #[serde(default)]
+    pub deadline: Option<i64>,
+
+    /// Skip (SKIPPED_TTL) if still queued this long after enqueue
+    #[serde(default)]
+    pub ttl_ms: Option<i64>,
+
+    /// Total attempts including the first (default 3)
+    #[serde(default)]
+    pub max_attempts: Option<i32>,
+
+    /// Retry delay multiplier per attempt (default 2.0)
+    #[serde(default)]
+    pub backoff_factor: Option<f64>,
+
+    /// Run only while the system is idle
+    #[serde(default)]
+    pub wait_for_idle: bool,
+
+    /// Run only while on AC power
+    #[serde(default)]
+    pub require_charging: bool,
+
+    /// User-defined tag for filtering
+    #[serde(default)]
+    pub user_tag: Option<String>,
+
+    /// IN_PROCESS (default) or SUBPROCESS
+    #[serde(default)]
+    pub execution_mode: Option<String>,
+
+    /// Job this one was spawned by (chains)
+    #[serde(default)]
+    pub parent_job_id: Option<String>,
+
+    /// Chain/batch group identifier
+    #[serde(default)]
+    pub chain_group_id: Option<String>,
 }
 
 /// Execute enqueue use case (with transaction for atomicity)
@@ -28,26 +99,38 @@ pub struct EnqueueRequest {
 /// * `job_repo` - Transactional job repository
 /// * `id_provider` - ID generator (injected for determinism)
 /// * `time_provider` - Time provider (injected for determinism)
+/// * `interceptors` - Policy hooks run on the job before insert
+/// * `validators` - Payload rules of the request's queue
 /// * `req` - Enqueue request
 pub async fn execute(
     job_repo: &dyn TransactionalJobRepository,
     id_provider: &dyn IdProvider,
     time_provider: &dyn TimeProvider,
+    interceptors: &InterceptorChain,
+    validators: &QueueValidators,
     req: EnqueueRequest,
 ) -> Result<String> {
     // Input validation (Security: prevent DoS and resource exhaustion)
-    validate_request(&req)?;
+    validate_request(&req, validators)?;
+    let trace_id = normalized_traceparent(&req)?;
+    let job_class = requested_job_class(&req)?;
+    let execution_mode = requested_execution_mode(&req)?;
+    let created_at = time_provider.now_millis();
+    validate_deadline(&req, created_at)?;
 
     // Start transaction to prevent generation conflicts
     let mut tx = job_repo.begin_transaction().await?;
 
-    // Get latest generation for this subject (within transaction)
-    let latest_gen = tx.get_latest_generation(&req.subject_key).await?;
-    let new_gen = latest_gen + 1;
+    if let Some(existing_id) = precheck(&mut tx, &req).await? {
+        tx.rollback().await?;
+        return Ok(existing_id);
+    }
+
+    // Claim the next generation for this subject (within transaction)
+    let new_gen = tx.next_generation(&req.subject_key).await?;
 
     // Create new job (with injected ID and timestamp for determinism)
     let job_id = id_provider.generate_id();
-    let created_at = time_provider.now_millis();
 
     let mut job = Job::new(
         job_id.clone(),
@@ -58,15 +141,31 @@ pub async fn execute(
         new_gen,
         JobPayload::new(req.payload),
     );
+    job.queued_at = Some(created_at);
 
     // Set priority from request
     job.priority = req.priority;
+    job.trace_id = trace_id;
+    job.job_class = job_class;
+    job.fallback_payloads = req
+        .fallback_payloads
+        .into_iter()
+        .map(JobPayload::new)
+        .collect();
+    apply_options(&mut job, req.options, execution_mode);
 
-    // Insert job (within transaction)
-    tx.insert(&job).await?;
+    // Interceptors may rewrite the job or reject it (before any write)
+    if let Err(e) = interceptors.on_enqueue(&mut job).await {
+        tx.rollback().await?;
+        return Err(e);
+    }
+
+    // Insert job, superseding older generations (within transaction)
+    tx.insert_superseding(&job).await?;
 
-    // Mark older generations as superseded (within transaction)
-    tx.mark_superseded(&req.subject_key, new_gen).await?;
+    if let Some(key) = &req.idempotency_key {
+        tx.record_idempotency_key(key, &job_id).await?;
+    }
 
     // Commit transaction
     tx.commit().await?;
@@ -74,14 +173,169 @@ pub async fn execute(
     Ok(job_id)
 }
 
+/// Execute enqueue, merging bursts for the same subject (see [`EnqueueCoalescer`])
+///
+/// Within the coalescing window a new request overwrites the payload and
+/// options of the subject's pending job and returns its ID. Once that job was
+/// popped, a new job is enqueued as usual.
+pub async fn execute_coalescing(
+    job_repo: &dyn TransactionalJobRepository,
+    id_provider: &dyn IdProvider,
+    time_provider: &dyn TimeProvider,
+    interceptors: &InterceptorChain,
+    validators: &QueueValidators,
+    coalescer: &EnqueueCoalescer,
+    req: EnqueueRequest,
+) -> Result<String> {
+    if !coalescer.is_enabled() {
+        return execute(
+            job_repo,
+            id_provider,
+            time_provider,
+            interceptors,
+            validators,
+            req,
+        )
+        .await;
+    }
+
+    let now = time_provider.now_millis();
+    let (queue, subject_key, job_type) = (
+        req.queue.clone(),
+        req.subject_key.clone(),
+        req.job_type.clone(),
+    );
+
+    if let Some(pending_id) = coalescer.pending(&queue, &subject_key, &job_type, now) {
+        if let Some(job_id) = coalesce_into(
+            job_repo,
+            time_provider,
+            interceptors,
+            validators,
+            &pending_id,
+            &req,
+        )
+        .await?
+        {
+            coalescer.remember(&queue, &subject_key, &job_type, &job_id, now);
+            return Ok(job_id);
+        }
+    }
+
+    let job_id = execute(
+        job_repo,
+        id_provider,
+        time_provider,
+        interceptors,
+        validators,
+        req,
+    )
+    .await?;
+    coalescer.remember(&queue, &subject_key, &job_type, &job_id, now);
+    Ok(job_id)
+}
+
+/// Overwrite the pending job with the request (None if it is no longer QUEUED)
+async fn coalesce_into(
+    job_repo: &dyn TransactionalJobRepository,
+    time_provider: &dyn TimeProvider,
+    interceptors: &InterceptorChain,
+    validators: &QueueValidators,
+    pending_id: &JobId,
+    req: &EnqueueRequest,
+) -> Result<Option<String>> {
+    validate_request(req, validators)?;
+    let trace_id = normalized_traceparent(req)?;
+    let job_class = requested_job_class(req)?;
+    let execution_mode = requested_execution_mode(req)?;
+    validate_deadline(req, time_provider.now_millis())?;
+
+    let mut tx = job_repo.begin_transaction().await?;
+
+    if let Some(existing_id) = precheck(&mut tx, req).await? {
+        tx.rollback().await?;
+        return Ok(Some(existing_id));
+    }
+
+    // The latest request wins: payloads, priority and every option are applied
+    // (generation and creation time of the pending job stay the same)
+    let mut job = Job::new(
+        pending_id.clone(),
+        time_provider.now_millis(),
+        req.queue.clone(),
+        JobType::new(req.job_type.clone()),
+        req.subject_key.clone(),
+        0,
+        JobPayload::new(req.payload.clone()),
+    );
+    job.priority = req.priority;
+    job.trace_id = trace_id;
+    job.job_class = job_class;
+    job.fallback_payloads = req
+        .fallback_payloads
+        .iter()
+        .cloned()
+        .map(JobPayload::new)
+        .collect();
+    apply_options(&mut job, req.options.clone(), execution_mode);
+
+    if let Err(e) = interceptors.on_enqueue(&mut job).await {
+        tx.rollback().await?;
+        return Err(e);
+    }
+
+    if !tx.replace_queued(&job).await? {
+        tx.rollback().await?;
+        return Ok(None);
+    }
+
+    if let Some(key) = &req.idempotency_key {
+        tx.record_idempotency_key(key, pending_id).await?;
+    }
+
+    tx.commit().await?;
+    Ok(Some(pending_id.clone()))
+}
+
+/// Checks shared by every enqueue path (within the transaction)
+///
+/// Returns the existing job ID for a replayed idempotency key.
+async fn precheck(
+    tx: &mut Box<dyn JobRepositoryTransaction>,
+    req: &EnqueueRequest,
+) -> Result<Option<String>> {
+    // Reject job types turned off via admin.job_type.disable.v1
+    if tx.is_job_type_disabled(&req.job_type).await? {
+        return Err(crate::error::AppError::Conflict(format!(
+            "Job type '{}' is disabled",
+            req.job_type
+        )));
+    }
+
+    // Replayed request: resolve to the job created by the first attempt
+    if let Some(key) = &req.idempotency_key {
+        if let Some(existing_id) = tx.find_by_idempotency_key(key).await? {
+            return Ok(Some(existing_id));
+        }
+    }
+
+    Ok(None)
+}
+
 // Validation constants (ADR-040: No magic numbers)
 const MAX_QUEUE_NAME_LEN: usize = 64;
 const MAX_JOB_TYPE_LEN: usize = 128;
-const MAX_SUBJECT_KEY_LEN: usize = 512;
+pub const MAX_SUBJECT_KEY_LEN: usize = 512;
+const MAX_IDEMPOTENCY_KEY_LEN: usize = 256;
 const MIN_PRIORITY: i32 = -100;
 const MAX_PRIORITY: i32 = 100;
-const MAX_PAYLOAD_DEPTH: usize = 32;
-const MAX_PAYLOAD_SIZE_BYTES: usize = 10_000_000; // 10MB (ADR-040)
+pub const MAX_PAYLOAD_DEPTH: usize = 32;
+pub const MAX_PAYLOAD_SIZE_BYTES: usize = 10_000_000; // 10MB (ADR-040)
+const MAX_FALLBACK_PAYLOADS: usize = 8;
+pub const MAX_ATTEMPTS: i32 = 100;
+pub const MIN_BACKOFF_FACTOR: f64 = 1.0;
+pub const MAX_BACKOFF_FACTOR: f64 = 10.0;
+const MAX_TAG_LEN: usize = 128;
 
 /// Validate enqueue request (Security: ADR-040)
 ///
@@ -90,7 +344,10 @@ const MAX_PAYLOAD_SIZE_BYTES: usize = 10_000_000; // 10MB (ADR-040)
 /// - Invalid queue names
 /// - Priority abuse
 /// - Subject key overflow
-fn validate_request(req: &EnqueueRequest) -> Result<()> {
+///
+/// Then applies the per-queue rules in `validators` (stricter hygiene on
+/// selected queues, see [`QueueValidators`]).
+fn validate_request(req: &EnqueueRequest, validators: &QueueValidators) -> Result<()> {
     use crate::error::AppError;
 
     // Queue name validation
@@ -150,19 +407,28 @@ fn validate_request(req: &EnqueueRequest) -> Result<()> {
         ));
     }
 
+    // Idempotency key validation
+    if let Some(key) = &req.idempotency_key {
+        if key.is_empty() || key.len() > MAX_IDEMPOTENCY_KEY_LEN {
+            return Err(AppError::Validation(format!(
+                "Idempotency key must be 1-{} chars",
+                MAX_IDEMPOTENCY_KEY_LEN
+            )));
+        }
+    }
+
     // Payload validation (Defense in Depth - ADR-040)
-    // 1. Size check (even though RPC layer has max_request_body_size)
-    let payload_str = req.payload.to_string();
-    if payload_str.len() > MAX_PAYLOAD_SIZE_BYTES {
+    validate_payload(&req.payload, "Payload")?;
+    if req.fallback_payloads.len() > MAX_FALLBACK_PAYLOADS {
         return Err(AppError::Validation(format!(
-            "Payload too large (max {} bytes, got {} bytes)",
-            MAX_PAYLOAD_SIZE_BYTES,
-            payload_str.len()
+            "Too many fallback payloads (max {}, got {})",
+            MAX_FALLBACK_PAYLOADS,
+            req.fallback_payloads.len()
         )));
     }
-
-    // 2. Complexity check (nesting depth)
-    validate_payload_complexity(&req.payload)?;
+    for (i, fallback) in req.fallback_payloads.iter().enumerate() {
+        validate_payload(fallback, &format!("Fallback payload {}", i + 1))?;
+    }
 
     // Priority validation
     if req.priority < MIN_PRIORITY || req.priority > MAX_PRIORITY {
@@ -172,9 +438,177 @@ fn validate_request(req: &EnqueueRequest) -> Result<()> {
         )));
     }
 
+    validate_options(&req.options)?;
+
+    // Per-queue rules (after the global limits)
+    validators.validate(req)
+}
+
+/// Validate the optional job fields (ranges only, see `validate_deadline` for time)
+fn validate_options(options: &EnqueueOptions) -> Result<()> {
+    use crate::error::AppError;
+
+    if options.schedule_at.is_some_and(|at| at < 0) {
+        return Err(AppError::Validation(
+            "schedule_at must be an epoch ms timestamp".to_string(),
+        ));
+    }
+    if options.ttl_ms.is_some_and(|ttl| ttl <= 0) {
+        return Err(AppError::Validation("ttl_ms must be positive".to_string()));
+    }
+    if let Some(max_attempts) = options.max_attempts {
+        if !(1..=MAX_ATTEMPTS).contains(&max_attempts) {
+            return Err(AppError::Validation(format!(
+                "max_attempts out of range (must be between 1 and {}, got {})",
+                MAX_ATTEMPTS, max_attempts
+            )));
+        }
+    }
+    if let Some(factor) = options.backoff_factor {
+        if !(MIN_BACKOFF_FACTOR..=MAX_BACKOFF_FACTOR).contains(&factor) {
+            return Err(AppError::Validation(format!(
+                "backoff_factor out of range (must be between {} and {}, got {})",
+                MIN_BACKOFF_FACTOR, MAX_BACKOFF_FACTOR, factor
+            )));
+        }
+    }
+    for (name, value) in [
+        ("user_tag", &options.user_tag),
+        ("parent_job_id", &options.parent_job_id),
+        ("chain_group_id", &options.chain_group_id),
+    ] {
+        if let Some(value) = value {
+            if value.is_empty() || value.len() > MAX_TAG_LEN || value.contains('\0') {
+                return Err(AppError::Validation(format!(
+                    "{} must be 1-{} chars without null bytes",
+                    name, MAX_TAG_LEN
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Reject deadlines already passed at enqueue or before the scheduled start
+fn validate_deadline(req: &EnqueueRequest, now: i64) -> Result<()> {
+    use crate::error::AppError;
+
+    let Some(deadline) = req.options.deadline else {
+        return Ok(());
+    };
+    let earliest_start = req.options.schedule_at.unwrap_or(now).max(now);
+    if deadline <= earliest_start {
+        return Err(AppError::Validation(format!(
+            "deadline {} is not after the earliest start {}",
+            deadline, earliest_start
+        )));
+    }
+    Ok(())
+}
+
+/// Requested execution mode (None = the Job default)
+fn requested_execution_mode(req: &EnqueueRequest) -> Result<Option<ExecutionMode>> {
+    Ok(req
+        .options
+        .execution_mode
+        .as_deref()
+        .map(ExecutionMode::parse)
+        .transpose()?)
+}
+
+/// Copy the requested options onto a new job (unset fields keep the defaults)
+fn apply_options(job: &mut Job, options: EnqueueOptions, execution_mode: Option<ExecutionMode>) {
+    job.schedule_at = options.schedule_at;
+    job.deadline = options.deadline;
+    job.ttl_ms = options.ttl_ms;
+    if let Some(max_attempts) = options.max_attempts {
+        job.max_attempts = max_attempts;
+    }
+    if let Some(backoff_factor) = options.backoff_factor {
+        job.backoff_factor = backoff_factor;
+    }
+    job.wait_for_idle = options.wait_for_idle;
+    job.require_charging = options.require_charging;
+    job.user_tag = options.user_tag;
+    if execution_mode.is_some() {
+        job.execution_mode = execution_mode;
+    }
+    job.parent_job_id = options.parent_job_id;
+    job.chain_group_id = options.chain_group_id;
+}
+
+/// Validate one payload (`what` names it in error messages)
+fn validate_payload(payload: &serde_json::Value, what: &str) -> Result<()> {
+    use crate::error::AppError;
+
+    // 1. Size check (even though RPC layer has max_request_body_size)
+    let payload_len = serialized_len(payload);
+    if payload_len > MAX_PAYLOAD_SIZE_BYTES {
+        return Err(AppError::Validation(format!(
+            "{} too large (max {} bytes, got {} bytes)",
+            what, MAX_PAYLOAD_SIZE_BYTES, payload_len
+        )));
+    }
+
+    // 2. Complexity check (nesting depth)
+    validate_payload_complexity(payload)?;
+
+    // 3. Template references must resolve when the job runs
+    let unknown = crate::domain::template::unknown_variables(payload);
+    if !unknown.is_empty() {
+        return Err(AppError::Validation(format!(
+            "Unknown template variable(s) in {}: {}",
+            what.to_lowercase(),
+            unknown.join(", ")
+        )));
+    }
+
     Ok(())
 }
 
+/// Length of `value` serialized as compact JSON, without building the string
+///
+/// Enqueue sits on the editor save path: the payload is serialized once, by
+/// the repository insert.
+pub fn serialized_len(value: &serde_json::Value) -> usize {
+    struct ByteCounter(usize);
+
+    impl std::io::Write for ByteCounter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0 += buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut counter = ByteCounter(0);
+    // Neither the counter nor serializing a Value can fail
+    let _ = serde_json::to_writer(&mut counter, value);
+    counter.0
+}
+
+/// The request's traceparent in canonical form (None if not supplied)
+fn normalized_traceparent(req: &EnqueueRequest) -> Result<Option<String>> {
+    req.traceparent
+        .as_deref()
+        .map(|value| Ok(TraceParent::parse(value)?.to_string()))
+        .transpose()
+}
+
+/// The request's job class (TASK if not supplied)
+fn requested_job_class(req: &EnqueueRequest) -> Result<JobClass> {
+    Ok(req
+        .job_class
+        .as_deref()
+        .map(JobClass::parse)
+        .transpose()?
+        .unwrap_or_default())
+}
+
 /// Validate payload complexity (depth and structure)
 ///
 /// Prevents deeply nested JSON that could cause stack overflow
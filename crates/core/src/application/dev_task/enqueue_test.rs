@@ -3,6 +3,7 @@
 #[cfg(test)]
 mod tests {
     use super::super::*;
+    use crate::application::payload_validator::QueueValidators;
     use serde_json::json;
 
     #[test]
@@ -13,9 +14,14 @@ mod tests {
             subject_key: "key".to_string(),
             payload: json!({}),
             priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
         };
 
-        let result = validate_request(&req);
+        let result = validate_request(&req, &QueueValidators::new());
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("empty"));
     }
@@ -28,9 +34,14 @@ mod tests {
             subject_key: "key".to_string(),
             payload: json!({}),
             priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
         };
 
-        let result = validate_request(&req);
+        let result = validate_request(&req, &QueueValidators::new());
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("too long"));
     }
@@ -43,9 +54,14 @@ mod tests {
             subject_key: "key".to_string(),
             payload: json!({}),
             priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
         };
 
-        let result = validate_request(&req);
+        let result = validate_request(&req, &QueueValidators::new());
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("alphanumeric"));
     }
@@ -58,9 +74,14 @@ mod tests {
             subject_key: "key".to_string(),
             payload: json!({}),
             priority: 101, // Out of range
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
         };
 
-        let result = validate_request(&req);
+        let result = validate_request(&req, &QueueValidators::new());
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("out of range"));
     }
@@ -79,9 +100,14 @@ mod tests {
             subject_key: "key".to_string(),
             payload: deep,
             priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
         };
 
-        let result = validate_request(&req);
+        let result = validate_request(&req, &QueueValidators::new());
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("deeply nested"));
     }
@@ -94,9 +120,183 @@ mod tests {
             subject_key: "test_key".to_string(),
             payload: json!({"data": "value"}),
             priority: 50,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
         };
 
-        let result = validate_request(&req);
+        let result = validate_request(&req, &QueueValidators::new());
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_validate_idempotency_key() {
+        let mut req = EnqueueRequest {
+            queue: "test_queue".to_string(),
+            job_type: "test_job".to_string(),
+            subject_key: "test_key".to_string(),
+            payload: json!({}),
+            priority: 0,
+            idempotency_key: Some(String::new()),
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
+        };
+        assert!(validate_request(&req, &QueueValidators::new()).is_err());
+
+        req.idempotency_key = Some("k".repeat(257));
+        assert!(validate_request(&req, &QueueValidators::new()).is_err());
+
+        req.idempotency_key = Some("editor-1234".to_string());
+        assert!(validate_request(&req, &QueueValidators::new()).is_ok());
+    }
+
+    #[test]
+    fn test_normalized_traceparent() {
+        let mut req = EnqueueRequest {
+            queue: "test_queue".to_string(),
+            job_type: "test_job".to_string(),
+            subject_key: "test_key".to_string(),
+            payload: json!({}),
+            priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
+        };
+        assert_eq!(normalized_traceparent(&req).unwrap(), None);
+
+        req.traceparent = Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7".to_string());
+        assert!(normalized_traceparent(&req).is_err());
+
+        req.traceparent =
+            Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00F067AA0BA902B7-00".to_string());
+        assert_eq!(
+            normalized_traceparent(&req).unwrap().as_deref(),
+            Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00")
+        );
+    }
+
+    #[test]
+    fn test_validate_template_variables() {
+        let mut req = EnqueueRequest {
+            queue: "test_queue".to_string(),
+            job_type: "test_job".to_string(),
+            subject_key: "src/main.rs".to_string(),
+            payload: json!({"command": "indexer", "args": ["{{subject_key}}", "{{root}}"]}),
+            priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
+        };
+        let err = validate_request(&req, &QueueValidators::new()).unwrap_err();
+        assert!(err.to_string().contains("root"));
+
+        req.payload["vars"] = json!({"root": "/repo"});
+        assert!(validate_request(&req, &QueueValidators::new()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_applies_queue_rules() {
+        let validators = QueueValidators::from_spec("untrusted:forbid=env").unwrap();
+        let mut req = EnqueueRequest {
+            queue: "untrusted".to_string(),
+            job_type: "test_job".to_string(),
+            subject_key: "key".to_string(),
+            payload: json!({"command": "ls", "env": {"TOKEN": "x"}}),
+            priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
+        };
+        let err = validate_request(&req, &validators).unwrap_err();
+        assert!(err.to_string().contains("queue 'untrusted'"));
+
+        req.queue = "default".to_string();
+        assert!(validate_request(&req, &validators).is_ok());
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let mut req = EnqueueRequest {
+            queue: "default".to_string(),
+            job_type: "test_job".to_string(),
+            subject_key: "key".to_string(),
+            payload: json!({}),
+            priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: EnqueueOptions {
+                max_attempts: Some(5),
+                backoff_factor: Some(1.5),
+                user_tag: Some("nightly".to_string()),
+                ..Default::default()
+            },
+        };
+        assert!(validate_request(&req, &QueueValidators::new()).is_ok());
+
+        req.options.max_attempts = Some(0);
+        assert!(validate_request(&req, &QueueValidators::new()).is_err());
+        req.options.max_attempts = None;
+
+        req.options.backoff_factor = Some(f64::NAN);
+        assert!(validate_request(&req, &QueueValidators::new()).is_err());
+        req.options.backoff_factor = None;
+
+        req.options.ttl_ms = Some(0);
+        assert!(validate_request(&req, &QueueValidators::new()).is_err());
+        req.options.ttl_ms = None;
+
+        req.options.user_tag = Some(String::new());
+        assert!(validate_request(&req, &QueueValidators::new()).is_err());
+        req.options.user_tag = None;
+
+        req.options.execution_mode = Some("THREAD".to_string());
+        assert!(requested_execution_mode(&req).is_err());
+    }
+
+    #[test]
+    fn test_validate_deadline() {
+        let mut req = EnqueueRequest {
+            queue: "default".to_string(),
+            job_type: "test_job".to_string(),
+            subject_key: "key".to_string(),
+            payload: json!({}),
+            priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: EnqueueOptions {
+                deadline: Some(2_000),
+                ..Default::default()
+            },
+        };
+        assert!(validate_deadline(&req, 1_000).is_ok());
+        assert!(validate_deadline(&req, 2_000).is_err());
+
+        req.options.schedule_at = Some(5_000);
+        assert!(validate_deadline(&req, 1_000).is_err());
+    }
+
+    #[test]
+    fn test_serialized_len_matches_to_string() {
+        for value in [
+            json!({}),
+            json!({ "path": "/repo/src/main.rs", "mode": "incremental" }),
+            json!({ "args": ["-c", "echo \"quoted\" ü"], "n": 1.5, "ok": null }),
+        ] {
+            assert_eq!(serialized_len(&value), value.to_string().len());
+        }
+    }
 }
@@ -1,18 +1,34 @@
 // Dev Task Service - Core use cases for job management
 
+pub mod artifacts;
+pub mod coalesce;
+pub mod dead_letter;
+pub mod delete;
 pub mod enqueue;
+pub mod progress;
+pub mod rerun;
+pub mod subject_history;
 
-pub use enqueue::EnqueueRequest;
+pub use coalesce::EnqueueCoalescer;
+pub use enqueue::{EnqueueOptions, EnqueueRequest};
 
+use crate::application::dispatch_signal::DispatchSignal;
+use crate::application::interceptor::InterceptorChain;
+use crate::application::payload_validator::QueueValidators;
 use crate::error::Result;
 use crate::port::{IdProvider, TimeProvider, TransactionalJobRepository};
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Dev Task Service (Phase 1)
 pub struct DevTaskService {
     job_repo: Arc<dyn TransactionalJobRepository>,
     id_provider: Arc<dyn IdProvider>,
     time_provider: Arc<dyn TimeProvider>,
+    interceptors: Arc<InterceptorChain>,
+    validators: Arc<QueueValidators>,
+    coalescer: Arc<EnqueueCoalescer>,
+    dispatch: Option<Arc<DispatchSignal>>,
 }
 
 impl DevTaskService {
@@ -25,17 +41,52 @@ impl DevTaskService {
             job_repo,
             id_provider,
             time_provider,
+            interceptors: Arc::new(InterceptorChain::new()),
+            validators: Arc::new(QueueValidators::new()),
+            coalescer: Arc::new(EnqueueCoalescer::disabled()),
+            dispatch: None,
         }
     }
 
+    /// Attach an interceptor chain run around every enqueue
+    pub fn with_interceptors(mut self, interceptors: Arc<InterceptorChain>) -> Self {
+        self.interceptors = interceptors;
+        self
+    }
+
+    /// Attach per-queue payload rules checked on every enqueue
+    pub fn with_queue_validators(mut self, validators: Arc<QueueValidators>) -> Self {
+        self.validators = validators;
+        self
+    }
+
+    /// Merge enqueues for the same subject arriving within `window` (zero = disabled)
+    pub fn with_coalescing_window(mut self, window: Duration) -> Self {
+        self.coalescer = Arc::new(EnqueueCoalescer::new(window));
+        self
+    }
+
+    /// Wake the worker waiting on this signal after every enqueue
+    pub fn with_dispatch_signal(mut self, dispatch: Arc<DispatchSignal>) -> Self {
+        self.dispatch = Some(dispatch);
+        self
+    }
+
     /// Enqueue a new job
     pub async fn enqueue(&self, req: EnqueueRequest) -> Result<String> {
-        enqueue::execute(
+        let job_id = enqueue::execute_coalescing(
             self.job_repo.as_ref(),
             self.id_provider.as_ref(),
             self.time_provider.as_ref(),
+            &self.interceptors,
+            &self.validators,
+            &self.coalescer,
             req,
         )
-        .await
+        .await?;
+        if let Some(dispatch) = &self.dispatch {
+            dispatch.notify();
+        }
+        Ok(job_id)
     }
 }
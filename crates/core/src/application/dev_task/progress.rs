@@ -0,0 +1,59 @@
+// Progress reporting of a running job (job.report_progress.v1)
+//
+// Long-running index/build jobs report how far they got so clients can show
+// status. Only the latest report is kept; it is cleared when the job is
+// popped again.
+
+use crate::domain::{Job, JobId, JobProgress};
+use crate::error::{AppError, Result};
+use crate::port::{JobRepository, TimeProvider};
+
+/// Maximum length of a progress message
+pub const MAX_PROGRESS_MESSAGE_LEN: usize = 512;
+
+/// Store the progress of a RUNNING job
+///
+/// Returns the job with its new progress.
+pub async fn execute(
+    job_repo: &dyn JobRepository,
+    time_provider: &dyn TimeProvider,
+    job_id: &JobId,
+    percent: u8,
+    message: Option<String>,
+) -> Result<Job> {
+    if percent > 100 {
+        return Err(AppError::Validation(format!(
+            "Progress must be 0-100, got {}",
+            percent
+        )));
+    }
+    if message
+        .as_ref()
+        .is_some_and(|m| m.len() > MAX_PROGRESS_MESSAGE_LEN)
+    {
+        return Err(AppError::Validation(format!(
+            "Progress message must be at most {} chars",
+            MAX_PROGRESS_MESSAGE_LEN
+        )));
+    }
+
+    let progress = JobProgress {
+        percent,
+        message,
+        updated_at: time_provider.now_millis(),
+    };
+    let stored = job_repo.update_progress(job_id, &progress).await?;
+
+    let job = job_repo
+        .find_by_id(job_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Job {} not found", job_id)))?;
+    if !stored {
+        return Err(AppError::Conflict(format!(
+            "Job {} is {}, only RUNNING jobs report progress",
+            job_id, job.state
+        )));
+    }
+
+    Ok(job)
+}
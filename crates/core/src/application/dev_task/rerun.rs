@@ -0,0 +1,143 @@
+// Manual re-run of a failed job (job.retry.v1)
+//
+// The job is requeued in place with a fresh attempt budget, so its id, event
+// history, result summary and log stay attached to it. Its TTL restarts from
+// the retry. A job whose subject was enqueued again since is refused: pop only
+// serves the latest generation, so it would never run. A retry with an edited
+// payload instead enqueues a corrected copy linked to the original.
+
+use super::enqueue::{EnqueueOptions, EnqueueRequest};
+use crate::domain::{Job, JobEvent, JobId, JobState};
+use crate::error::{AppError, Result};
+use crate::port::{JobRepository, TimeProvider};
+
+/// Requeue a FAILED job with its attempts reset
+///
+/// Records a QUEUED event by `actor`. Returns the requeued job. Conflict if
+/// the job is not FAILED or a newer generation of its subject exists.
+pub async fn execute(
+    job_repo: &dyn JobRepository,
+    time_provider: &dyn TimeProvider,
+    job_id: &JobId,
+    actor: &str,
+    reason: Option<String>,
+) -> Result<Job> {
+    let mut job = job_repo
+        .find_by_id(job_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Job {} not found", job_id)))?;
+
+    if job.state != JobState::Failed {
+        return Err(AppError::Conflict(format!(
+            "Job {} is {}, only FAILED jobs can be retried",
+            job_id, job.state
+        )));
+    }
+
+    let latest_generation = job_repo.get_latest_generation(&job.subject_key).await?;
+    if job.generation < latest_generation {
+        return Err(AppError::Conflict(format!(
+            "Job {} is generation {} of {}, superseded by generation {}",
+            job_id, job.generation, job.subject_key, latest_generation
+        )));
+    }
+
+    let now = time_provider.now_millis();
+    job.state = JobState::Queued;
+    // Waits in the queue from now on: TTL counts from the retry
+    job.queued_at = Some(now);
+    job.attempts = 0;
+    job.panic_count = 0;
+    job.next_attempt_at = None;
+    job.started_at = None;
+    job.finished_at = None;
+    job.schedule_at = None;
+    job_repo.update(&job).await?;
+    // Running again: no longer dead
+    job_repo.remove_dead_letter(&job.id).await?;
+
+    job_repo
+        .record_event(&JobEvent::new(
+            job.id.clone(),
+            JobState::Queued,
+            actor,
+            Some(reason.unwrap_or_else(|| "manual retry".to_string())),
+            now,
+        ))
+        .await?;
+
+    Ok(job)
+}
+
+/// Enqueue request for a corrected copy of a finished job
+///
+/// The copy keeps the job's type, subject and options and is linked to it by
+/// `parent_job_id`. Start time and deadline are dropped: they were relative
+/// to the original run.
+pub fn corrected_request(job: &Job, payload: serde_json::Value) -> Result<EnqueueRequest> {
+    if !job.state.is_terminal() {
+        return Err(AppError::Conflict(format!(
+            "Job {} is {}, only finished jobs can be retried with a new payload",
+            job.id, job.state
+        )));
+    }
+
+    Ok(EnqueueRequest {
+        job_type: job.job_type.as_str().to_string(),
+        queue: job.queue.clone(),
+        subject_key: job.subject_key.clone(),
+        payload,
+        priority: job.priority,
+        idempotency_key: None,
+        traceparent: job.trace_id.clone(),
+        job_class: Some(job.job_class.to_string()),
+        fallback_payloads: job
+            .fallback_payloads
+            .iter()
+            .map(|p| p.as_value().clone())
+            .collect(),
+        options: EnqueueOptions {
+            schedule_at: None,
+            deadline: None,
+            ttl_ms: job.ttl_ms,
+            max_attempts: Some(job.max_attempts),
+            backoff_factor: Some(job.backoff_factor),
+            wait_for_idle: job.wait_for_idle,
+            require_charging: job.require_charging,
+            user_tag: job.user_tag.clone(),
+            execution_mode: job.execution_mode.as_ref().map(|m| m.to_string()),
+            parent_job_id: Some(job.id.clone()),
+            chain_group_id: job.chain_group_id.clone(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{JobPayload, JobType};
+
+    #[test]
+    fn test_corrected_request_links_to_the_original() {
+        let mut job = Job::new_test(
+            "default",
+            JobType::new("BUILD"),
+            "src/lib.rs",
+            3,
+            JobPayload::new(serde_json::json!({"command": "carg"})),
+        );
+        job.max_attempts = 5;
+        job.deadline = Some(1);
+
+        let err = corrected_request(&job, serde_json::json!({})).unwrap_err();
+        assert!(matches!(err, AppError::Conflict(_)), "QUEUED job");
+
+        job.state = JobState::Failed;
+        let req = corrected_request(&job, serde_json::json!({"command": "cargo"})).unwrap();
+        assert_eq!(req.payload["command"], "cargo");
+        assert_eq!(req.subject_key, "src/lib.rs");
+        assert_eq!(req.options.parent_job_id.as_deref(), Some(job.id.as_str()));
+        assert_eq!(req.options.max_attempts, Some(5));
+        assert_eq!(req.options.deadline, None);
+    }
+}
@@ -0,0 +1,60 @@
+// Subject result history (subject.history.v1)
+//
+// The worker records every successful completion of a subject with a hash of
+// its output. Comparing consecutive hashes answers "did the result for this
+// subject change between runs?" for incremental tooling.
+
+use crate::domain::SubjectResult;
+use crate::error::{AppError, Result};
+use crate::port::JobRepository;
+
+/// Entries returned without an explicit limit
+pub const DEFAULT_SUBJECT_HISTORY_LIMIT: usize = 10;
+
+/// Most entries a single query returns
+pub const MAX_SUBJECT_HISTORY_LIMIT: usize = 1000;
+
+/// One recorded result and how it compares to the run before it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubjectHistoryEntry {
+    pub result: SubjectResult,
+    /// Output differs from the previous recorded run (None if there is no
+    /// previous run or either output was not captured)
+    pub changed: Option<bool>,
+}
+
+/// Newest `limit` results of `subject_key`, newest first
+pub async fn list(
+    job_repo: &dyn JobRepository,
+    subject_key: &str,
+    limit: usize,
+) -> Result<Vec<SubjectHistoryEntry>> {
+    if subject_key.is_empty() {
+        return Err(AppError::Validation("subject_key must not be empty".into()));
+    }
+    if limit == 0 || limit > MAX_SUBJECT_HISTORY_LIMIT {
+        return Err(AppError::Validation(format!(
+            "limit must be 1-{}, got {}",
+            MAX_SUBJECT_HISTORY_LIMIT, limit
+        )));
+    }
+
+    // One extra entry so the oldest returned one can be compared as well
+    let results = job_repo
+        .list_subject_history(subject_key, limit + 1)
+        .await?;
+    Ok(results
+        .iter()
+        .enumerate()
+        .take(limit)
+        .map(|(index, result)| SubjectHistoryEntry {
+            result: result.clone(),
+            changed: results.get(index + 1).and_then(|previous| {
+                match (&result.result_hash, &previous.result_hash) {
+                    (Some(current), Some(previous)) => Some(current != previous),
+                    _ => None,
+                }
+            }),
+        })
+        .collect())
+}
@@ -0,0 +1,72 @@
+// Dispatch Signal - Wakes an idle worker as soon as work is enqueued
+//
+// Without it the worker finds new jobs by polling every 100ms. The enqueue,
+// retry and requeue paths signal here, and a worker waiting on the signal
+// starts the job right away, polling only rarely as a safety net for jobs that
+// become due without an enqueue (schedule_at, retry backoff, resumed queues).
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::Notify;
+
+/// In-process "new work may be available" signal
+#[derive(Debug, Default)]
+pub struct DispatchSignal {
+    notify: Notify,
+    /// Signals sent since start
+    sent: AtomicU64,
+}
+
+impl DispatchSignal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A job was enqueued (or requeued): wake the waiting worker
+    ///
+    /// Not lost when nobody waits: the next `notified()` returns at once.
+    pub fn notify(&self) {
+        self.sent.fetch_add(1, Ordering::Relaxed);
+        self.notify.notify_one();
+    }
+
+    /// Wait for the next signal (or one sent while nobody was waiting)
+    pub async fn notified(&self) {
+        self.notify.notified().await;
+    }
+
+    /// Signals sent since start
+    pub fn sent(&self) -> u64 {
+        self.sent.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_signal_wakes_waiter_and_is_kept_until_awaited() {
+        let signal = Arc::new(DispatchSignal::new());
+
+        // Sent before anyone waits: the next wait returns at once
+        signal.notify();
+        tokio::time::timeout(Duration::from_millis(100), signal.notified())
+            .await
+            .expect("signal sent while idle was lost");
+
+        let waiter = tokio::spawn({
+            let signal = Arc::clone(&signal);
+            async move { signal.notified().await }
+        });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(!waiter.is_finished());
+        signal.notify();
+        tokio::time::timeout(Duration::from_millis(100), waiter)
+            .await
+            .expect("waiter not woken")
+            .unwrap();
+        assert_eq!(signal.sent(), 2);
+    }
+}
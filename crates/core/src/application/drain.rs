@@ -0,0 +1,168 @@
+// Drain - Stop taking new work and let the jobs in flight finish
+//
+// Deploy scripts restart the daemon; stopping it while jobs execute fails
+// them mid-flight (or leaves them RUNNING until recovery). admin.drain.v1
+// starts a drain: the Worker and the LeaseService pop nothing anymore while
+// enqueues keep being accepted (their jobs wait QUEUED for the next daemon).
+// Every job the worker claims counts as in flight from its pop until its
+// result is recorded, so "drained" means the daemon can be stopped without
+// losing work. Jobs leased by external workers are theirs to finish; their
+// leases survive a restart. In-memory only: a restart ends the drain.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+#[derive(Debug, Default)]
+struct DrainState {
+    /// Draining since (epoch ms), None = popping normally
+    since: Option<i64>,
+    in_flight: usize,
+}
+
+/// Shared drain switch and count of the jobs in flight
+#[derive(Debug, Default)]
+pub struct Drain {
+    state: Mutex<DrainState>,
+    idle: Notify,
+}
+
+impl Drain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pop nothing from now on (false if already draining)
+    pub fn start(&self, now: i64) -> bool {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.since.is_some() {
+            return false;
+        }
+        state.since = Some(now);
+        true
+    }
+
+    /// Pop again (false if it was not draining)
+    pub fn stop(&self) -> bool {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .since
+            .take()
+            .is_some()
+    }
+
+    pub fn is_draining(&self) -> bool {
+        self.since().is_some()
+    }
+
+    /// When the drain started (None while popping normally)
+    pub fn since(&self) -> Option<i64> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).since
+    }
+
+    /// Jobs claimed and not finished yet
+    pub fn in_flight(&self) -> usize {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .in_flight
+    }
+
+    /// Count a claim in flight, None while draining (pop nothing then)
+    ///
+    /// The check and the count are one step, so a drain started afterwards
+    /// waits for this claim and the jobs it hands out (`InFlight::clone`).
+    pub fn enter(self: &Arc<Self>) -> Option<InFlight> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.since.is_some() {
+            return None;
+        }
+        state.in_flight += 1;
+        Some(InFlight {
+            drain: Arc::clone(self),
+        })
+    }
+
+    /// Wait until nothing is in flight, false if `timeout` elapsed first
+    pub async fn wait_idle(&self, timeout: Duration) -> bool {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            // Register before checking so a release in between is not missed
+            let released = self.idle.notified();
+            if self.in_flight() == 0 {
+                return true;
+            }
+            if tokio::time::timeout_at(deadline, released).await.is_err() {
+                return self.in_flight() == 0;
+            }
+        }
+    }
+
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.in_flight = state.in_flight.saturating_sub(1);
+        if state.in_flight == 0 {
+            self.idle.notify_waiters();
+        }
+    }
+}
+
+/// Work in flight, counted until dropped
+#[derive(Debug)]
+pub struct InFlight {
+    drain: Arc<Drain>,
+}
+
+impl Clone for InFlight {
+    /// One more unit of work in flight (e.g. a job handed out by a claim)
+    fn clone(&self) -> Self {
+        self.drain
+            .state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .in_flight += 1;
+        Self {
+            drain: Arc::clone(&self.drain),
+        }
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.drain.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_drain_waits_for_work_in_flight() {
+        let drain = Arc::new(Drain::new());
+        let claim = drain.enter().unwrap();
+        let job = claim.clone();
+        drop(claim);
+        assert_eq!(drain.in_flight(), 1);
+
+        assert!(drain.start(1000));
+        assert!(!drain.start(2000), "already draining");
+        assert!(drain.enter().is_none(), "nothing is claimed while draining");
+        assert!(!drain.wait_idle(Duration::from_millis(20)).await);
+
+        let waiter = tokio::spawn({
+            let drain = Arc::clone(&drain);
+            async move { drain.wait_idle(Duration::from_secs(5)).await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        drop(job);
+        assert!(waiter.await.unwrap());
+        assert_eq!(drain.in_flight(), 0);
+
+        assert_eq!(drain.since(), Some(1000));
+        assert!(drain.stop());
+        assert!(!drain.stop());
+        assert!(drain.enter().is_some());
+    }
+}
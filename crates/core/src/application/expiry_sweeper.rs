@@ -0,0 +1,106 @@
+// Expiry Sweeper - Expires QUEUED jobs whose TTL or deadline passed
+//
+// Workers only notice an expired job when they pop it, so a job stuck behind
+// a paused queue, a disabled job type or a long backlog stays QUEUED (and
+// counted as waiting) long after it stopped mattering. The sweeper marks such
+// jobs SKIPPED_TTL / SKIPPED_DEADLINE periodically, records an event for each
+// and reports them to the metrics backend.
+
+use crate::application::InterceptorChain;
+use crate::domain::job_event::actor;
+use crate::domain::ExpiredJob;
+use crate::error::Result;
+use crate::port::{JobRepository, NoopWorkerMetrics, TimeProvider, WorkerMetrics};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::interval;
+use tracing::{error, info, warn};
+
+/// How often the sweeper runs (30s)
+pub const DEFAULT_EXPIRY_SWEEP_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Periodically expires QUEUED jobs of every queue
+pub struct ExpirySweeper {
+    job_repo: Arc<dyn JobRepository>,
+    time_provider: Arc<dyn TimeProvider>,
+    metrics: Arc<dyn WorkerMetrics>,
+    interceptors: Arc<InterceptorChain>,
+    /// Jobs expired since start
+    expired_total: AtomicU64,
+}
+
+impl ExpirySweeper {
+    pub fn new(job_repo: Arc<dyn JobRepository>, time_provider: Arc<dyn TimeProvider>) -> Self {
+        Self {
+            job_repo,
+            time_provider,
+            metrics: Arc::new(NoopWorkerMetrics),
+            interceptors: Arc::new(InterceptorChain::new()),
+            expired_total: AtomicU64::new(0),
+        }
+    }
+
+    /// Report expired jobs to a metrics backend (default: discarded)
+    pub fn with_metrics(mut self, metrics: Arc<dyn WorkerMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Tell interceptors about expired jobs (`on_complete`), like the worker does
+    pub fn with_interceptors(mut self, interceptors: Arc<InterceptorChain>) -> Self {
+        self.interceptors = interceptors;
+        self
+    }
+
+    /// Jobs expired by this sweeper since start
+    pub fn expired_total(&self) -> u64 {
+        self.expired_total.load(Ordering::Relaxed)
+    }
+
+    /// Run one sweep, returns the jobs it expired
+    pub async fn sweep_once(&self) -> Result<Vec<ExpiredJob>> {
+        let now = self.time_provider.now_millis();
+        let expired = self
+            .job_repo
+            .expire_queued(now, actor::EXPIRY_SWEEPER)
+            .await?;
+        if expired.is_empty() {
+            return Ok(expired);
+        }
+
+        for job in &expired {
+            warn!(job_id = %job.job_id, queue = %job.queue, state = %job.state, "Queued job expired");
+            self.metrics
+                .record_expired(&job.queue, &job.job_type, &job.state);
+            if !self.interceptors.is_empty() {
+                if let Some(stored) = self.job_repo.find_by_id(&job.job_id).await? {
+                    self.interceptors.on_complete(&stored, &job.state).await;
+                }
+            }
+        }
+        self.expired_total
+            .fetch_add(expired.len() as u64, Ordering::Relaxed);
+        info!(expired = expired.len(), "Expired queued jobs swept");
+
+        Ok(expired)
+    }
+
+    /// Run the sweeper loop (background task)
+    ///
+    /// Should be spawned in tokio::spawn
+    pub async fn run(self: Arc<Self>, every: Duration) {
+        info!(
+            interval_ms = every.as_millis() as u64,
+            "Expiry sweeper started"
+        );
+
+        let mut tick = interval(every);
+        loop {
+            tick.tick().await;
+            if let Err(e) = self.sweep_once().await {
+                error!(error = %e, "Expiry sweep failed");
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+// Failure Bundle - Diagnostic archive collected on final failure
+//
+// When a job ends FAILED (retries exhausted or not retryable), the job row,
+// its attempt history, the log tail, system metrics and the engine version are
+// written into one archive registered as a job artifact.
+
+use crate::application::interceptor::JobInterceptor;
+use crate::domain::{Artifact, Job, JobId, JobState};
+use crate::error::{AppError, Result};
+use crate::port::{
+    ArtifactStorage, BundleWriter, FailureBundle, JobRepository, SystemProbe, TimeProvider,
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Interceptor writing a failure bundle for every FAILED job
+pub struct FailureBundler {
+    job_repo: Arc<dyn JobRepository>,
+    system_probe: Arc<dyn SystemProbe>,
+    bundle_writer: Arc<dyn BundleWriter>,
+    storage: Arc<dyn ArtifactStorage>,
+    time_provider: Arc<dyn TimeProvider>,
+    engine_version: String,
+}
+
+impl FailureBundler {
+    pub fn new(
+        job_repo: Arc<dyn JobRepository>,
+        system_probe: Arc<dyn SystemProbe>,
+        bundle_writer: Arc<dyn BundleWriter>,
+        storage: Arc<dyn ArtifactStorage>,
+        time_provider: Arc<dyn TimeProvider>,
+        engine_version: impl Into<String>,
+    ) -> Self {
+        Self {
+            job_repo,
+            system_probe,
+            bundle_writer,
+            storage,
+            time_provider,
+            engine_version: engine_version.into(),
+        }
+    }
+
+    /// Collect and write the bundle of `job_id`, then register it as an artifact
+    ///
+    /// Returns the artifact URI.
+    pub async fn collect(&self, job_id: &JobId) -> Result<String> {
+        let job = self
+            .job_repo
+            .find_by_id(job_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Job {} not found", job_id)))?;
+        let events = self.job_repo.list_events(job_id).await?;
+
+        let bundle = FailureBundle {
+            job,
+            events,
+            system_metrics: self.system_probe.get_metrics().await,
+            engine_version: self.engine_version.clone(),
+            collected_at: self.time_provider.now_millis(),
+        };
+
+        let key = format!(
+            "{}-failure-{}.{}",
+            job_id,
+            bundle.collected_at,
+            self.bundle_writer.extension()
+        );
+        let archive = self.bundle_writer.write(&bundle).await?;
+        let described = Artifact::new("", &archive, bundle.collected_at);
+        let uri = self.storage.put(&key, archive).await?;
+        let artifact = Artifact {
+            uri: uri.clone(),
+            ..described
+        };
+        self.job_repo.add_artifact(job_id, &artifact).await?;
+        Ok(uri)
+    }
+}
+
+#[async_trait]
+impl JobInterceptor for FailureBundler {
+    fn name(&self) -> &str {
+        "failure_bundle"
+    }
+
+    /// Best-effort: a bundle failure never changes the job outcome
+    async fn on_complete(&self, job: &Job, state: &JobState) {
+        if *state != JobState::Failed {
+            return;
+        }
+        match self.collect(&job.id).await {
+            Ok(uri) => info!(job_id = %job.id, uri = %uri, "Failure bundle written"),
+            Err(e) => warn!(job_id = %job.id, error = %e, "Failed to write failure bundle"),
+        }
+    }
+}
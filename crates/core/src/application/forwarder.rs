@@ -0,0 +1,215 @@
+// Forwarder - Relay a queue to a remote Semantica daemon
+//
+// Jobs of the forwarded queue are popped locally, enqueued on the remote
+// daemon and kept RUNNING here until the remote job finishes; its terminal
+// state is then mirrored onto the local job (e.g. offload BUILD jobs from a
+// laptop to a desktop).
+
+use crate::domain::job_event::actor;
+use crate::domain::{ForwardedJob, JobEvent, JobState};
+use crate::error::Result;
+use crate::port::{JobRepository, RemoteDaemon, RemoteSubmit, TimeProvider};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::interval;
+use tracing::{error, info, warn};
+
+/// How often the forwarder relays new jobs and polls remote states (2s)
+pub const DEFAULT_FORWARD_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Maximum jobs relayed / polled per round
+const FORWARD_BATCH_SIZE: usize = 32;
+
+/// Relays one local queue to a remote daemon
+pub struct Forwarder {
+    job_repo: Arc<dyn JobRepository>,
+    remote: Arc<dyn RemoteDaemon>,
+    queue: String,
+    time_provider: Arc<dyn TimeProvider>,
+}
+
+impl Forwarder {
+    /// Create a forwarder
+    ///
+    /// # Arguments
+    /// * `queue` - Local queue whose jobs are relayed (not processed locally)
+    pub fn new(
+        job_repo: Arc<dyn JobRepository>,
+        remote: Arc<dyn RemoteDaemon>,
+        queue: impl Into<String>,
+        time_provider: Arc<dyn TimeProvider>,
+    ) -> Self {
+        Self {
+            job_repo,
+            remote,
+            queue: queue.into(),
+            time_provider,
+        }
+    }
+
+    /// Run the forwarding loop (background task)
+    ///
+    /// Should be spawned in tokio::spawn
+    pub async fn run(self: Arc<Self>, every: Duration) {
+        info!(
+            queue = %self.queue,
+            endpoint = %self.remote.endpoint(),
+            "Forwarder started"
+        );
+
+        let mut tick = interval(every);
+        loop {
+            tick.tick().await;
+            if let Err(e) = self.forward_once().await {
+                error!(error = %e, "Forwarding failed");
+            }
+            if let Err(e) = self.sync_once().await {
+                error!(error = %e, "Forward state sync failed");
+            }
+        }
+    }
+
+    /// Relay queued jobs to the remote daemon
+    ///
+    /// Stops at the first transport error (the job is put back in the queue).
+    /// Returns the number of forwarded jobs.
+    pub async fn forward_once(&self) -> Result<usize> {
+        let mut forwarded = 0;
+
+        for _ in 0..FORWARD_BATCH_SIZE {
+            let Some(mut job) = self.job_repo.pop_next(&self.queue).await? else {
+                break;
+            };
+
+            // Stable key: a resubmit after a lost response maps to the same remote job
+            let idempotency_key = format!("fwd:{}", job.id);
+            let now = self.time_provider.now_millis();
+
+            match self.remote.submit(&job, &idempotency_key).await {
+                Ok(RemoteSubmit::Accepted { remote_job_id }) => {
+                    self.job_repo
+                        .record_forward(&ForwardedJob::new(
+                            job.id.clone(),
+                            self.remote.endpoint(),
+                            remote_job_id.clone(),
+                            now,
+                        ))
+                        .await?;
+                    self.job_repo
+                        .record_event(&JobEvent::new(
+                            job.id.clone(),
+                            JobState::Running,
+                            actor::FORWARDER,
+                            Some(format!(
+                                "forwarded to {} as {}",
+                                self.remote.endpoint(),
+                                remote_job_id
+                            )),
+                            now,
+                        ))
+                        .await?;
+                    info!(job_id = %job.id, remote_job_id = %remote_job_id, "Job forwarded");
+                    forwarded += 1;
+                }
+                Ok(RemoteSubmit::Rejected { reason }) => {
+                    warn!(job_id = %job.id, reason = %reason, "Remote daemon rejected job");
+                    self.job_repo
+                        .update_state(&job.id, JobState::Failed, Some(now))
+                        .await?;
+                    self.job_repo
+                        .record_event(&JobEvent::new(
+                            job.id.clone(),
+                            JobState::Failed,
+                            actor::FORWARDER,
+                            Some(format!(
+                                "rejected by {}: {}",
+                                self.remote.endpoint(),
+                                reason
+                            )),
+                            now,
+                        ))
+                        .await?;
+                }
+                Err(e) => {
+                    // Remote unreachable: keep the job queued locally and retry next round
+                    warn!(job_id = %job.id, error = %e, "Remote daemon unreachable");
+                    job.state = JobState::Queued;
+                    job.started_at = None;
+                    self.job_repo.update(&job).await?;
+                    break;
+                }
+            }
+        }
+
+        Ok(forwarded)
+    }
+
+    /// Mirror terminal remote states back onto local jobs
+    ///
+    /// Returns the number of forwards closed.
+    pub async fn sync_once(&self) -> Result<usize> {
+        let mut closed = 0;
+
+        for forward in self.job_repo.list_open_forwards(FORWARD_BATCH_SIZE).await? {
+            let remote_state = match self.remote.state(&forward.remote_job_id).await {
+                Ok(Some(state)) if !state.is_terminal() => continue,
+                Ok(state) => state,
+                Err(e) => {
+                    warn!(endpoint = %forward.endpoint, error = %e, "Remote daemon unreachable");
+                    break;
+                }
+            };
+
+            // Remote lost the job (e.g. GC'd or database reset): fail locally
+            let local_state = remote_state.clone().unwrap_or(JobState::Failed);
+            let now = self.time_provider.now_millis();
+
+            let local = self.job_repo.find_by_id(&forward.job_id).await?;
+            // A job cancelled locally meanwhile keeps its local state
+            if local.is_some_and(|job| job.state == JobState::Running) {
+                let summary = serde_json::json!({
+                    "status": local_state.to_string(),
+                    "forwarded": {
+                        "endpoint": forward.endpoint,
+                        "remote_job_id": forward.remote_job_id,
+                        "remote_state": remote_state.as_ref().map(|s| s.to_string()),
+                    },
+                });
+                if let Err(e) = self
+                    .job_repo
+                    .update_result_summary(&forward.job_id, &summary.to_string())
+                    .await
+                {
+                    warn!(job_id = %forward.job_id, error = %e, "Failed to record forward summary");
+                }
+                self.job_repo
+                    .update_state(&forward.job_id, local_state.clone(), Some(now))
+                    .await?;
+                self.job_repo
+                    .record_event(&JobEvent::new(
+                        forward.job_id.clone(),
+                        local_state.clone(),
+                        actor::FORWARDER,
+                        Some(match &remote_state {
+                            Some(_) => {
+                                format!("mirrored from remote job {}", forward.remote_job_id)
+                            }
+                            None => {
+                                format!("remote job {} no longer exists", forward.remote_job_id)
+                            }
+                        }),
+                        now,
+                    ))
+                    .await?;
+                info!(job_id = %forward.job_id, state = %local_state, "Forwarded job finished");
+            }
+
+            self.job_repo
+                .close_forward(&forward.job_id, remote_state, now)
+                .await?;
+            closed += 1;
+        }
+
+        Ok(closed)
+    }
+}
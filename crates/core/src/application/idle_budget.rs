@@ -0,0 +1,255 @@
+// Idle Budget Policy - Cap background work per rolling window
+//
+// Bulk jobs (wait_for_idle) only run while the CPU time they consumed in the
+// last window (default 1h) stays under a budget, e.g. 20 CPU-minutes per hour,
+// regardless of queue depth. Consumption is estimated from SystemProbe samples
+// taken while bulk jobs run: elapsed time x CPU usage.
+
+use crate::application::scheduling_policy::SchedulingPolicy;
+use crate::domain::Job;
+use crate::port::{SystemProbe, TimeProvider};
+use async_trait::async_trait;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::interval;
+use tracing::{debug, info};
+
+/// Rolling window the budget applies to (1h)
+pub const DEFAULT_BUDGET_WINDOW: Duration = Duration::from_secs(60 * 60);
+
+/// How often CPU usage is sampled while bulk jobs run (5s)
+pub const DEFAULT_SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Bulk jobs run only within a rolling CPU-time budget
+pub struct IdleBudgetPolicy {
+    system_probe: Arc<dyn SystemProbe>,
+    time_provider: Arc<dyn TimeProvider>,
+    budget_ms: i64,
+    window_ms: i64,
+    state: Mutex<BudgetState>,
+}
+
+struct BudgetState {
+    /// (accounted_at, cpu_ms) in chronological order
+    usage: VecDeque<(i64, i64)>,
+    running: usize,
+    last_accounted_at: i64,
+    /// Latest sampled CPU usage (assume a busy CPU until the first sample)
+    last_cpu_percent: f32,
+}
+
+impl IdleBudgetPolicy {
+    /// Create a policy granting `budget` of CPU time per DEFAULT_BUDGET_WINDOW
+    pub fn new(
+        system_probe: Arc<dyn SystemProbe>,
+        time_provider: Arc<dyn TimeProvider>,
+        budget: Duration,
+    ) -> Self {
+        let now = time_provider.now_millis();
+        Self {
+            system_probe,
+            time_provider,
+            budget_ms: budget.as_millis() as i64,
+            window_ms: DEFAULT_BUDGET_WINDOW.as_millis() as i64,
+            state: Mutex::new(BudgetState {
+                usage: VecDeque::new(),
+                running: 0,
+                last_accounted_at: now,
+                last_cpu_percent: 100.0,
+            }),
+        }
+    }
+
+    /// Override the rolling window
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window_ms = window.as_millis() as i64;
+        self
+    }
+
+    /// Jobs subject to the budget
+    fn is_bulk(job: &Job) -> bool {
+        job.wait_for_idle
+    }
+
+    /// CPU time consumed by bulk jobs within the current window (ms)
+    pub fn used_ms(&self) -> i64 {
+        let now = self.time_provider.now_millis();
+        let mut state = self.state.lock().unwrap();
+        state.prune(now - self.window_ms);
+        state.usage.iter().map(|(_, cpu_ms)| cpu_ms).sum()
+    }
+
+    /// Take one SystemProbe sample and account it to running bulk jobs
+    pub async fn sample(&self) {
+        let metrics = self.system_probe.get_metrics().await;
+        let now = self.time_provider.now_millis();
+        let mut state = self.state.lock().unwrap();
+        state.last_cpu_percent = metrics.cpu_usage_percent.clamp(0.0, 100.0);
+        state.account(now);
+    }
+
+    /// Run the sampling loop (background task)
+    ///
+    /// Should be spawned in tokio::spawn
+    pub async fn run(self: Arc<Self>, every: Duration) {
+        info!(
+            budget_ms = self.budget_ms,
+            window_ms = self.window_ms,
+            "Idle budget sampler started"
+        );
+
+        let mut tick = interval(every);
+        loop {
+            tick.tick().await;
+            self.sample().await;
+        }
+    }
+}
+
+impl BudgetState {
+    /// Charge the time since the last accounting to running bulk jobs
+    fn account(&mut self, now: i64) {
+        let elapsed = (now - self.last_accounted_at).max(0);
+        if self.running > 0 && elapsed > 0 {
+            let cpu_ms = (elapsed as f64 * self.last_cpu_percent as f64 / 100.0) as i64;
+            self.usage.push_back((now, cpu_ms));
+        }
+        self.last_accounted_at = now;
+    }
+
+    fn prune(&mut self, cutoff: i64) {
+        while self.usage.front().is_some_and(|(at, _)| *at <= cutoff) {
+            self.usage.pop_front();
+        }
+    }
+}
+
+#[async_trait]
+impl SchedulingPolicy for IdleBudgetPolicy {
+    fn name(&self) -> &str {
+        "idle_budget"
+    }
+
+    async fn is_ready(&self, job: &Job) -> bool {
+        if !Self::is_bulk(job) {
+            return true;
+        }
+        let used_ms = self.used_ms();
+        if used_ms >= self.budget_ms {
+            debug!(
+                job_id = %job.id,
+                used_ms,
+                budget_ms = self.budget_ms,
+                "Job not ready: idle budget exhausted"
+            );
+            return false;
+        }
+        true
+    }
+
+    /// Push the job back until the oldest usage leaves the window
+    fn on_deferred(&self, job: &mut Job) {
+        if !Self::is_bulk(job) {
+            return;
+        }
+        let now = self.time_provider.now_millis();
+        let state = self.state.lock().unwrap();
+        if let Some((oldest, _)) = state.usage.front() {
+            let retry_at = oldest + self.window_ms;
+            job.schedule_at = Some(job.schedule_at.map_or(retry_at, |at| at.max(retry_at)));
+            debug!(job_id = %job.id, retry_in_ms = retry_at - now, "Bulk job deferred");
+        }
+    }
+
+    fn on_started(&self, job: &Job) {
+        if Self::is_bulk(job) {
+            let now = self.time_provider.now_millis();
+            let mut state = self.state.lock().unwrap();
+            state.account(now);
+            state.running += 1;
+        }
+    }
+
+    fn on_finished(&self, job: &Job) {
+        if Self::is_bulk(job) {
+            let now = self.time_provider.now_millis();
+            let mut state = self.state.lock().unwrap();
+            state.account(now);
+            state.running = state.running.saturating_sub(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{JobPayload, JobType};
+    use crate::port::system_probe::mocks::MockSystemProbe;
+    use std::sync::atomic::{AtomicI64, Ordering};
+
+    struct StepTime(AtomicI64);
+
+    impl TimeProvider for StepTime {
+        fn now_millis(&self) -> i64 {
+            self.0.load(Ordering::SeqCst)
+        }
+    }
+
+    fn job(bulk: bool) -> Job {
+        let mut job = Job::new_test(
+            "test_queue",
+            JobType::new("INDEX"),
+            "src/lib.rs",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job.wait_for_idle = bulk;
+        job
+    }
+
+    #[tokio::test]
+    async fn test_budget_exhausted_then_recovers() {
+        let time = Arc::new(StepTime(AtomicI64::new(0)));
+        let probe = Arc::new(MockSystemProbe::new(50.0));
+        let policy = IdleBudgetPolicy::new(probe, time.clone(), Duration::from_secs(60))
+            .with_window(Duration::from_secs(600));
+
+        let bulk = job(true);
+        assert!(policy.is_ready(&bulk).await);
+
+        // 2 minutes at 50% CPU = 60s of CPU time
+        policy.on_started(&bulk);
+        policy.sample().await;
+        time.0.store(120_000, Ordering::SeqCst);
+        policy.sample().await;
+        policy.on_finished(&bulk);
+        assert_eq!(policy.used_ms(), 60_000);
+
+        assert!(!policy.is_ready(&bulk).await, "Budget exhausted");
+        assert!(
+            policy.is_ready(&job(false)).await,
+            "Non-bulk jobs unaffected"
+        );
+
+        let mut deferred = job(true);
+        policy.on_deferred(&mut deferred);
+        assert_eq!(deferred.schedule_at, Some(720_000));
+
+        // Usage leaves the window
+        time.0.store(720_000, Ordering::SeqCst);
+        assert_eq!(policy.used_ms(), 0);
+        assert!(policy.is_ready(&bulk).await);
+    }
+
+    #[tokio::test]
+    async fn test_idle_time_is_not_charged() {
+        let time = Arc::new(StepTime(AtomicI64::new(0)));
+        let probe = Arc::new(MockSystemProbe::new(80.0));
+        let policy = IdleBudgetPolicy::new(probe, time.clone(), Duration::from_secs(60));
+
+        time.0.store(300_000, Ordering::SeqCst);
+        policy.sample().await;
+        assert_eq!(policy.used_ms(), 0, "No bulk job running");
+    }
+}
@@ -0,0 +1,217 @@
+// Job Interceptors - Policy hooks around enqueue, pop and completion
+//
+// Embedders register interceptors to rewrite payloads, attach tags or reject
+// job types without forking DevTaskService or the Worker loop.
+
+use crate::domain::{Job, JobState};
+use crate::error::{AppError, Result};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::debug;
+
+/// Hook invoked at well-defined points of a job's lifecycle
+///
+/// All methods have no-op defaults so an interceptor only implements the hooks it needs.
+#[async_trait]
+pub trait JobInterceptor: Send + Sync {
+    /// Interceptor name (for logs)
+    fn name(&self) -> &str;
+
+    /// Called after validation and before the job is inserted
+    ///
+    /// May mutate the job (payload, tags, priority). Returning an error rejects the enqueue.
+    async fn on_enqueue(&self, _job: &mut Job) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called after a worker popped the job (already RUNNING)
+    async fn on_pop(&self, _job: &Job) {}
+
+    /// Called once the worker recorded the job outcome
+    async fn on_complete(&self, _job: &Job, _state: &JobState) {}
+}
+
+/// Ordered chain of interceptors (registration order = invocation order)
+#[derive(Clone, Default)]
+pub struct InterceptorChain {
+    interceptors: Vec<Arc<dyn JobInterceptor>>,
+}
+
+impl InterceptorChain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an interceptor at the end of the chain
+    pub fn register(&mut self, interceptor: Arc<dyn JobInterceptor>) {
+        self.interceptors.push(interceptor);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.interceptors.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.interceptors.len()
+    }
+
+    /// Build a chain from built-in interceptors declared in configuration
+    ///
+    /// Format: comma-separated `name:arg` entries, e.g.
+    /// `reject_job_type:DANGEROUS,default_tag:ci`
+    ///
+    /// Built-ins:
+    /// - `reject_job_type:<TYPE>` - reject enqueues of the given job type
+    /// - `default_tag:<TAG>` - set `user_tag` on jobs enqueued without one
+    pub fn from_spec(spec: &str) -> Result<Self> {
+        let mut chain = Self::new();
+
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (name, arg) = entry.split_once(':').ok_or_else(|| {
+                AppError::Config(format!("Invalid interceptor entry '{}'", entry))
+            })?;
+            let arg = arg.trim();
+            if arg.is_empty() {
+                return Err(AppError::Config(format!(
+                    "Interceptor '{}' requires an argument",
+                    name
+                )));
+            }
+
+            let interceptor: Arc<dyn JobInterceptor> = match name.trim() {
+                "reject_job_type" => Arc::new(RejectJobTypeInterceptor::new(arg)),
+                "default_tag" => Arc::new(DefaultTagInterceptor::new(arg)),
+                other => return Err(AppError::Config(format!("Unknown interceptor '{}'", other))),
+            };
+            chain.register(interceptor);
+        }
+
+        Ok(chain)
+    }
+
+    /// Run all `on_enqueue` hooks, stopping at the first rejection
+    pub async fn on_enqueue(&self, job: &mut Job) -> Result<()> {
+        for interceptor in &self.interceptors {
+            interceptor.on_enqueue(job).await.inspect_err(|e| {
+                debug!(interceptor = interceptor.name(), error = %e, "Enqueue rejected");
+            })?;
+        }
+        Ok(())
+    }
+
+    pub async fn on_pop(&self, job: &Job) {
+        for interceptor in &self.interceptors {
+            interceptor.on_pop(job).await;
+        }
+    }
+
+    pub async fn on_complete(&self, job: &Job, state: &JobState) {
+        for interceptor in &self.interceptors {
+            interceptor.on_complete(job, state).await;
+        }
+    }
+}
+
+/// Built-in: reject enqueues of a specific job type
+pub struct RejectJobTypeInterceptor {
+    job_type: String,
+}
+
+impl RejectJobTypeInterceptor {
+    pub fn new(job_type: impl Into<String>) -> Self {
+        Self {
+            job_type: job_type.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl JobInterceptor for RejectJobTypeInterceptor {
+    fn name(&self) -> &str {
+        "reject_job_type"
+    }
+
+    async fn on_enqueue(&self, job: &mut Job) -> Result<()> {
+        if job.job_type.as_str() == self.job_type {
+            return Err(AppError::Validation(format!(
+                "Job type '{}' is rejected by policy",
+                self.job_type
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Built-in: attach a default `user_tag` to untagged jobs
+pub struct DefaultTagInterceptor {
+    tag: String,
+}
+
+impl DefaultTagInterceptor {
+    pub fn new(tag: impl Into<String>) -> Self {
+        Self { tag: tag.into() }
+    }
+}
+
+#[async_trait]
+impl JobInterceptor for DefaultTagInterceptor {
+    fn name(&self) -> &str {
+        "default_tag"
+    }
+
+    async fn on_enqueue(&self, job: &mut Job) -> Result<()> {
+        if job.user_tag.is_none() {
+            job.user_tag = Some(self.tag.clone());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{JobPayload, JobType};
+
+    fn test_job(job_type: &str) -> Job {
+        Job::new_test(
+            "default",
+            JobType::new(job_type),
+            "subject",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_from_spec_builds_builtins() {
+        let chain =
+            InterceptorChain::from_spec("reject_job_type:DANGEROUS, default_tag:ci").unwrap();
+        assert_eq!(chain.len(), 2);
+
+        let mut job = test_job("INDEX");
+        chain.on_enqueue(&mut job).await.unwrap();
+        assert_eq!(job.user_tag.as_deref(), Some("ci"));
+
+        let mut job = test_job("DANGEROUS");
+        let err = chain.on_enqueue(&mut job).await.unwrap_err();
+        assert!(err.to_string().contains("rejected by policy"));
+    }
+
+    #[tokio::test]
+    async fn test_default_tag_keeps_existing_tag() {
+        let chain = InterceptorChain::from_spec("default_tag:ci").unwrap();
+
+        let mut job = test_job("INDEX");
+        job.user_tag = Some("feature-123".to_string());
+        chain.on_enqueue(&mut job).await.unwrap();
+        assert_eq!(job.user_tag.as_deref(), Some("feature-123"));
+    }
+
+    #[test]
+    fn test_from_spec_rejects_invalid_entries() {
+        assert!(InterceptorChain::from_spec("").unwrap().is_empty());
+        assert!(InterceptorChain::from_spec("unknown:x").is_err());
+        assert!(InterceptorChain::from_spec("default_tag").is_err());
+        assert!(InterceptorChain::from_spec("default_tag:").is_err());
+    }
+}
@@ -0,0 +1,405 @@
+// Lease Service - Job broker for external workers
+//
+// External processes (any language) lease RUNNING jobs over RPC, renew the
+// lease with heartbeats and report the outcome. Leases that are not renewed
+// in time are reclaimed by the reaper and go through the normal retry policy.
+
+use crate::application::drain::Drain;
+use crate::application::interceptor::InterceptorChain;
+use crate::application::lockdown::Lockdown;
+use crate::application::queue_pause::QueuePauseRegistry;
+use crate::application::retry::{RetryDecision, RetryPolicy};
+use crate::application::retry_policies::RetryPolicies;
+use crate::domain::job_event::actor;
+use crate::domain::{DeadLetter, Job, JobEvent, JobId, JobLease, JobState};
+use crate::error::{AppError, Result};
+use crate::port::{JobRepository, TimeProvider};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::interval;
+use tracing::{error, info, warn};
+
+/// Lease duration when the worker does not ask for one (30s)
+pub const DEFAULT_LEASE_MS: i64 = 30_000;
+
+/// Bounds for requested lease durations (1s .. 10min)
+pub const MIN_LEASE_MS: i64 = 1_000;
+pub const MAX_LEASE_MS: i64 = 10 * 60 * 1000;
+
+/// How often the reaper looks for expired leases (5s)
+pub const DEFAULT_REAPER_INTERVAL: Duration = Duration::from_secs(5);
+
+// Expired jobs popped while leasing are skipped, up to this many per call
+const MAX_EXPIRED_SKIPS: usize = 16;
+
+/// Outcome reported by an external worker
+#[derive(Debug, Clone, PartialEq)]
+pub enum LeaseOutcome {
+    Succeeded {
+        result: Option<serde_json::Value>,
+    },
+    Failed {
+        error: String,
+        /// false = do not retry even if attempts remain
+        retryable: bool,
+    },
+}
+
+/// Leasing use cases (lease, heartbeat, complete, reap)
+pub struct LeaseService {
+    job_repo: Arc<dyn JobRepository>,
+    retry_policies: Arc<RetryPolicies>,
+    time_provider: Arc<dyn TimeProvider>,
+    interceptors: Arc<InterceptorChain>,
+    pauses: Arc<QueuePauseRegistry>,
+    lockdown: Arc<Lockdown>,
+    drain: Arc<Drain>,
+}
+
+impl LeaseService {
+    pub fn new(
+        job_repo: Arc<dyn JobRepository>,
+        retry_policy: Arc<RetryPolicy>,
+        time_provider: Arc<dyn TimeProvider>,
+    ) -> Self {
+        Self {
+            job_repo,
+            retry_policies: Arc::new(RetryPolicies::new(retry_policy)),
+            time_provider,
+            interceptors: Arc::new(InterceptorChain::new()),
+            pauses: Arc::new(QueuePauseRegistry::new()),
+            lockdown: Arc::new(Lockdown::new()),
+            drain: Arc::new(Drain::new()),
+        }
+    }
+
+    /// Attach an interceptor chain notified on lease (pop) and completion
+    pub fn with_interceptors(mut self, interceptors: Arc<InterceptorChain>) -> Self {
+        self.interceptors = interceptors;
+        self
+    }
+
+    /// Lease nothing from queues paused in this registry
+    pub fn with_pause_registry(mut self, pauses: Arc<QueuePauseRegistry>) -> Self {
+        self.pauses = pauses;
+        self
+    }
+
+    /// Look up the retry policy of each failed job's type (default: the one given to `new`)
+    pub fn with_retry_policies(mut self, retry_policies: Arc<RetryPolicies>) -> Self {
+        self.retry_policies = retry_policies;
+        self
+    }
+
+    /// Refuse leases while this lockdown is engaged
+    pub fn with_lockdown(mut self, lockdown: Arc<Lockdown>) -> Self {
+        self.lockdown = lockdown;
+        self
+    }
+
+    /// Lease nothing while this drain is on
+    pub fn with_drain(mut self, drain: Arc<Drain>) -> Self {
+        self.drain = drain;
+        self
+    }
+
+    /// Lease the next job of `queue` to `worker_id` (None while the queue is paused
+    /// or the daemon drains, Conflict while locked down)
+    ///
+    /// Only due jobs are popped: a job whose `schedule_at` is ahead stays QUEUED.
+    ///
+    /// # Arguments
+    /// * `lease_ms` - Lease duration (None = DEFAULT_LEASE_MS), must be within MIN..=MAX_LEASE_MS
+    pub async fn lease(
+        &self,
+        queue: &str,
+        worker_id: &str,
+        lease_ms: Option<i64>,
+    ) -> Result<Option<(Job, JobLease)>> {
+        validate_worker_id(worker_id)?;
+        let lease_ms = validate_lease_ms(lease_ms)?;
+        if let Some(refusal) = self.lockdown.refusal("lease") {
+            return Err(refusal);
+        }
+        if self.pauses.is_paused(queue) || self.drain.is_draining() {
+            return Ok(None);
+        }
+
+        for _ in 0..MAX_EXPIRED_SKIPS {
+            let Some(job) = self.job_repo.pop_next(queue).await? else {
+                return Ok(None);
+            };
+
+            if let Some(state) = self.expired_state(&job) {
+                let now = self.time_provider.now_millis();
+                self.job_repo
+                    .update_state(&job.id, state.clone(), Some(now))
+                    .await?;
+                self.interceptors.on_complete(&job, &state).await;
+                continue;
+            }
+
+            let lease = JobLease::new(
+                job.id.clone(),
+                worker_id,
+                self.time_provider.now_millis(),
+                lease_ms,
+            );
+            self.job_repo.insert_lease(&lease).await?;
+            self.interceptors.on_pop(&job).await;
+
+            // The worker runs the attempt's payload (a fallback on retries)
+            let mut job = job;
+            job.use_attempt_payload();
+
+            info!(job_id = %job.id, worker_id, lease_ms, "Job leased");
+            return Ok(Some((job, lease)));
+        }
+
+        Ok(None)
+    }
+
+    /// Renew a lease and record the worker's progress message
+    pub async fn heartbeat(
+        &self,
+        job_id: &JobId,
+        worker_id: &str,
+        lease_ms: Option<i64>,
+        progress: Option<&str>,
+    ) -> Result<JobLease> {
+        let lease_ms = validate_lease_ms(lease_ms)?;
+        let expires_at = self.time_provider.now_millis() + lease_ms;
+
+        self.job_repo
+            .renew_lease(job_id, worker_id, expires_at, progress)
+            .await?
+            .ok_or_else(|| lease_lost(job_id, worker_id))
+    }
+
+    /// Record the worker's outcome and release the lease
+    ///
+    /// Returns the job's new state (QUEUED when a failure is retried).
+    pub async fn complete(
+        &self,
+        job_id: &JobId,
+        worker_id: &str,
+        outcome: LeaseOutcome,
+    ) -> Result<JobState> {
+        let lease = self
+            .job_repo
+            .find_lease(job_id)
+            .await?
+            .filter(|lease| lease.is_held_by(worker_id))
+            .ok_or_else(|| lease_lost(job_id, worker_id))?;
+
+        let job = self
+            .job_repo
+            .find_by_id(job_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Job {} not found", job_id)))?;
+
+        if job.state != JobState::Running {
+            // Cancelled or superseded while leased: the result is discarded
+            self.job_repo.delete_lease(job_id).await?;
+            return Err(AppError::Conflict(format!(
+                "Job {} is {}, result discarded",
+                job_id, job.state
+            )));
+        }
+
+        let summary = match &outcome {
+            LeaseOutcome::Succeeded { result } => serde_json::json!({
+                "attempt": job.attempts,
+                "payload_index": job.attempt_payload_index(),
+                "status": "Success",
+                "worker_id": lease.worker_id,
+                "result": result,
+            }),
+            LeaseOutcome::Failed { error, retryable } => serde_json::json!({
+                "attempt": job.attempts,
+                "payload_index": job.attempt_payload_index(),
+                "status": "Failed",
+                "worker_id": lease.worker_id,
+                "error": error,
+                "retryable": retryable,
+            }),
+        };
+        if let Err(e) = self
+            .job_repo
+            .update_result_summary(job_id, &summary.to_string())
+            .await
+        {
+            warn!(job_id = %job_id, error = %e, "Failed to record attempt summary");
+        }
+
+        let state = match outcome {
+            LeaseOutcome::Succeeded { .. } => {
+                let now = self.time_provider.now_millis();
+                self.job_repo
+                    .update_state(job_id, JobState::Done, Some(now))
+                    .await?;
+                JobState::Done
+            }
+            LeaseOutcome::Failed { error, retryable } => {
+                self.fail(job.clone(), &error, retryable).await?
+            }
+        };
+
+        self.job_repo.delete_lease(job_id).await?;
+        self.interceptors.on_complete(&job, &state).await;
+
+        info!(job_id = %job_id, worker_id, state = %state, "Leased job completed");
+        Ok(state)
+    }
+
+    /// Reclaim jobs whose lease expired (retry policy applies)
+    ///
+    /// Returns the number of reclaimed jobs.
+    pub async fn reap_expired(&self) -> Result<usize> {
+        let now = self.time_provider.now_millis();
+        let mut reaped = 0;
+
+        for lease in self.job_repo.find_expired_leases(now).await? {
+            // Re-check: the worker may have renewed since the scan
+            let current = self.job_repo.find_lease(&lease.job_id).await?;
+            if current.as_ref().is_some_and(|l| !l.is_expired(now)) {
+                continue;
+            }
+
+            let job = self.job_repo.find_by_id(&lease.job_id).await?;
+            if let Some(job) = job.filter(|job| job.state == JobState::Running) {
+                warn!(
+                    job_id = %job.id,
+                    worker_id = %lease.worker_id,
+                    expired_at = lease.expires_at,
+                    "Lease expired, reclaiming job"
+                );
+
+                let reason = format!("lease of worker '{}' expired", lease.worker_id);
+                let state = self.fail(job.clone(), &reason, true).await?;
+                self.job_repo
+                    .record_event(&JobEvent::new(
+                        job.id.clone(),
+                        state.clone(),
+                        actor::LEASE_REAPER,
+                        Some(reason),
+                        now,
+                    ))
+                    .await?;
+                self.interceptors.on_complete(&job, &state).await;
+                reaped += 1;
+            }
+
+            self.job_repo.delete_lease(&lease.job_id).await?;
+        }
+
+        Ok(reaped)
+    }
+
+    /// Run the lease reaper loop (background task)
+    ///
+    /// Should be spawned in tokio::spawn
+    pub async fn run_reaper(self: Arc<Self>, every: Duration) {
+        info!(
+            interval_ms = every.as_millis() as u64,
+            "Lease reaper started"
+        );
+
+        let mut tick = interval(every);
+        loop {
+            tick.tick().await;
+            match self.reap_expired().await {
+                Ok(0) => {}
+                Ok(count) => info!(reclaimed = count, "Expired leases reclaimed"),
+                Err(e) => error!(error = %e, "Lease reaper failed"),
+            }
+        }
+    }
+
+    /// Apply the retry policy to a failed leased job
+    async fn fail(&self, mut job: Job, error: &str, retryable: bool) -> Result<JobState> {
+        let now = self.time_provider.now_millis();
+        let retry_policy = self.retry_policies.for_job_type(job.job_type.as_str());
+
+        if retry_policy.is_deadline_exceeded(&job) {
+            self.job_repo
+                .update_state(&job.id, JobState::SkippedDeadline, Some(now))
+                .await?;
+            return Ok(JobState::SkippedDeadline);
+        }
+
+        let mut gave_up = None;
+        if retryable {
+            match retry_policy.should_retry(&job) {
+                RetryDecision::Retry(delay_ms) => {
+                    info!(job_id = %job.id, delay_ms, error, "Retrying leased job after failure");
+                    retry_policy.prepare_for_retry(&mut job, delay_ms);
+                    self.job_repo.update(&job).await?;
+                    if let Some(event) = retry_policy.fallback_event(&job) {
+                        self.job_repo.record_event(&event).await?;
+                    }
+                    return Ok(JobState::Queued);
+                }
+                RetryDecision::RetryDeadlineExceeded(reason) => gave_up = Some(reason),
+                RetryDecision::Failed => {}
+            }
+        }
+
+        error!(job_id = %job.id, error, "Leased job failed");
+        self.job_repo
+            .update_state(&job.id, JobState::Failed, Some(now))
+            .await?;
+        if let Some(reason) = gave_up {
+            self.job_repo
+                .record_event(&retry_policy.gave_up_event(&job, &reason))
+                .await?;
+        }
+        if let Err(e) = self
+            .job_repo
+            .record_dead_letter(&DeadLetter::new(&job, error, now))
+            .await
+        {
+            warn!(job_id = %job.id, error = %e, "Failed to record dead letter");
+        }
+        Ok(JobState::Failed)
+    }
+
+    /// Expired state for a job that must not be handed out (TTL first, then deadline)
+    fn expired_state(&self, job: &Job) -> Option<JobState> {
+        let retry_policy = self.retry_policies.default_policy();
+        if retry_policy.is_ttl_exceeded(job) {
+            Some(JobState::SkippedTtl)
+        } else if retry_policy.is_deadline_exceeded(job) {
+            Some(JobState::SkippedDeadline)
+        } else {
+            None
+        }
+    }
+}
+
+fn validate_worker_id(worker_id: &str) -> Result<()> {
+    if worker_id.trim().is_empty() || worker_id.len() > 128 {
+        return Err(AppError::Validation(
+            "worker_id must be 1-128 characters".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_lease_ms(lease_ms: Option<i64>) -> Result<i64> {
+    let lease_ms = lease_ms.unwrap_or(DEFAULT_LEASE_MS);
+    if !(MIN_LEASE_MS..=MAX_LEASE_MS).contains(&lease_ms) {
+        return Err(AppError::Validation(format!(
+            "lease_ms must be between {} and {}",
+            MIN_LEASE_MS, MAX_LEASE_MS
+        )));
+    }
+    Ok(lease_ms)
+}
+
+fn lease_lost(job_id: &JobId, worker_id: &str) -> AppError {
+    AppError::Conflict(format!(
+        "Job {} is not leased to worker '{}' (lease expired or reassigned)",
+        job_id, worker_id
+    ))
+}
@@ -0,0 +1,108 @@
+// Lockdown - Global kill switch for subprocess execution
+//
+// A panic button for when a bad template starts spawning harmful commands.
+// Every job execution goes through a TaskExecutor that spawns a process, so
+// while engaged nothing is popped (Worker, LeaseService) and submissions that
+// would lead to an execution (enqueue, retry) are refused with a clear error.
+// Read-only and in-process operations (inspect, cancel, pause, stats, ...)
+// keep working. Jobs already running are not killed, cancel them explicitly.
+// In-memory only: a daemon restart lifts it unless configured at startup.
+
+use crate::error::AppError;
+use std::sync::RwLock;
+
+/// Who engaged the lockdown, when and why
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockdownState {
+    pub since: i64,
+    pub reason: Option<String>,
+    pub actor: String,
+}
+
+/// Shared lockdown switch
+#[derive(Debug, Default)]
+pub struct Lockdown {
+    state: RwLock<Option<LockdownState>>,
+}
+
+impl Lockdown {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Refuse subprocess execution from now on (false if already engaged)
+    pub fn engage(&self, actor: impl Into<String>, reason: Option<String>, now: i64) -> bool {
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+        if state.is_some() {
+            return false;
+        }
+        *state = Some(LockdownState {
+            since: now,
+            reason,
+            actor: actor.into(),
+        });
+        true
+    }
+
+    /// Allow subprocess execution again (false if it was not engaged)
+    pub fn lift(&self) -> bool {
+        self.state
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+            .is_some()
+    }
+
+    pub fn is_engaged(&self) -> bool {
+        self.state
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_some()
+    }
+
+    /// Current lockdown (None while lifted)
+    pub fn status(&self) -> Option<LockdownState> {
+        self.state.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Error for an operation that would spawn a process (None while lifted)
+    pub fn refusal(&self, operation: &str) -> Option<AppError> {
+        self.status().map(|state| {
+            AppError::Conflict(format!(
+                "Lockdown engaged by {}{}: subprocess execution is refused, {} rejected \
+                 (lift with admin.lockdown.v1)",
+                state.actor,
+                state
+                    .reason
+                    .map(|reason| format!(" ({})", reason))
+                    .unwrap_or_default(),
+                operation
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_engage_and_lift() {
+        let lockdown = Lockdown::new();
+        assert!(lockdown.refusal("enqueue").is_none());
+
+        assert!(lockdown.engage("ops", Some("rogue template".to_string()), 1000));
+        assert!(!lockdown.engage("other", None, 2000), "already engaged");
+        assert!(lockdown.is_engaged());
+        let state = lockdown.status().unwrap();
+        assert_eq!((state.actor.as_str(), state.since), ("ops", 1000));
+
+        let refusal = lockdown.refusal("enqueue").unwrap().to_string();
+        assert!(refusal.contains("ops (rogue template)"));
+        assert!(refusal.contains("enqueue rejected"));
+
+        assert!(lockdown.lift());
+        assert!(!lockdown.lift());
+        assert!(lockdown.status().is_none());
+    }
+}
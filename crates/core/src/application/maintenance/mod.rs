@@ -1,12 +1,29 @@
 // Maintenance Service (Phase 4 - ADR-050)
 // Scheduled maintenance operations for DB and artifacts
 
+use crate::application::runtime_config::RuntimeConfig;
+use crate::application::worker::constants::IDLE_CPU_THRESHOLD;
 use crate::error::Result;
-use crate::port::{Maintenance, MaintenanceConfig};
+use crate::port::{
+    LogNotifier, Maintenance, MaintenanceConfig, MaintenanceReport, Notification, Notifier,
+    SystemProbe, TimeProvider,
+};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::time::interval;
-use tracing::{error, info};
+use tracing::{error, info, warn};
+
+/// Number of reports kept for `semantica maintenance history`
+pub const REPORT_HISTORY_SIZE: usize = 20;
+
+/// Notification topic for scheduled maintenance reports
+pub const MAINTENANCE_REPORT_TOPIC: &str = "maintenance.report";
+
+/// How long the machine must be idle before gated maintenance starts (seconds)
+pub const MAINTENANCE_IDLE_SECS: u64 = 60;
+
+/// How often a gated run re-checks for an idle window
+const IDLE_POLL_INTERVAL: Duration = Duration::from_secs(60);
 
 /// Maintenance scheduler
 ///
@@ -15,6 +32,11 @@ pub struct MaintenanceScheduler {
     maintenance: Arc<dyn Maintenance>,
     config: MaintenanceConfig,
     interval_hours: u64,
+    time_provider: Arc<dyn TimeProvider>,
+    notifier: Arc<dyn Notifier>,
+    runtime_config: Option<Arc<RuntimeConfig>>,
+    idle_gate: Option<Arc<dyn SystemProbe>>,
+    idle_poll: Duration,
 }
 
 impl MaintenanceScheduler {
@@ -24,18 +46,75 @@ impl MaintenanceScheduler {
     /// * `maintenance` - Maintenance implementation
     /// * `config` - Maintenance configuration
     /// * `interval_hours` - How often to run maintenance (hours)
+    /// * `time_provider` - Time provider (report timestamps)
     pub fn new(
         maintenance: Arc<dyn Maintenance>,
         config: MaintenanceConfig,
         interval_hours: u64,
+        time_provider: Arc<dyn TimeProvider>,
     ) -> Self {
         Self {
             maintenance,
             config,
             interval_hours,
+            time_provider,
+            notifier: Arc::new(LogNotifier),
+            runtime_config: None,
+            idle_gate: None,
+            idle_poll: IDLE_POLL_INTERVAL,
         }
     }
 
+    /// Only run scheduled maintenance (VACUUM / GC) while the machine is idle
+    ///
+    /// A run waits for an idle window and is skipped if none opens before the
+    /// next one is due. Manual runs are not gated.
+    pub fn with_idle_gate(mut self, system_probe: Arc<dyn SystemProbe>) -> Self {
+        self.idle_gate = Some(system_probe);
+        self
+    }
+
+    /// Wait until the idle gate opens (true) or `deadline` passes (false)
+    async fn wait_for_idle_window(&self, deadline: Duration) -> bool {
+        let Some(probe) = &self.idle_gate else {
+            return true;
+        };
+
+        let started = Instant::now();
+        loop {
+            if probe
+                .is_idle(IDLE_CPU_THRESHOLD, MAINTENANCE_IDLE_SECS)
+                .await
+            {
+                return true;
+            }
+            if started.elapsed() + self.idle_poll > deadline {
+                return false;
+            }
+            tokio::time::sleep(self.idle_poll).await;
+        }
+    }
+
+    /// Take retention and log sampling from shared runtime settings (read on every run)
+    pub fn with_runtime_config(mut self, runtime_config: Arc<RuntimeConfig>) -> Self {
+        self.runtime_config = Some(runtime_config);
+        self
+    }
+
+    /// Configuration for the next run
+    fn effective_config(&self) -> MaintenanceConfig {
+        match &self.runtime_config {
+            Some(runtime) => runtime.maintenance_config(&self.config),
+            None => self.config.clone(),
+        }
+    }
+
+    /// Publish reports through the given notification channel (default: log)
+    pub fn with_notifier(mut self, notifier: Arc<dyn Notifier>) -> Self {
+        self.notifier = notifier;
+        self
+    }
+
     /// Run maintenance loop (background task)
     ///
     /// Runs full maintenance every interval_hours
@@ -43,40 +122,147 @@ impl MaintenanceScheduler {
     pub async fn run(self) {
         info!(
             interval_hours = self.interval_hours,
-            retention_days = self.config.finished_job_retention_days,
+            retention_days = self.effective_config().finished_job_retention_days,
             "Maintenance scheduler started"
         );
 
-        let mut tick = interval(Duration::from_secs(self.interval_hours * 3600));
+        let every = Duration::from_secs(self.interval_hours * 3600);
+        let mut tick = interval(every);
 
         loop {
             tick.tick().await;
 
+            if !self.wait_for_idle_window(every).await {
+                warn!("No idle window before the next maintenance run, skipping this one");
+                continue;
+            }
+
             info!("Running scheduled maintenance...");
 
-            match self.maintenance.run_full_maintenance(&self.config).await {
-                Ok(stats) => {
-                    info!(
-                        db_size_mb = stats.db_size_mb,
-                        job_count = stats.job_count,
-                        finished_jobs = stats.finished_job_count,
-                        artifacts = stats.artifact_count,
-                        artifact_size_mb = stats.log_files_size_mb,
-                        "Scheduled maintenance completed successfully"
-                    );
-                }
-                Err(e) => {
-                    error!(error = ?e, "Scheduled maintenance failed");
+            let report = self.run_once().await;
+            if report.errors.is_empty() {
+                info!(
+                    jobs_deleted = report.jobs_deleted,
+                    artifacts_removed = report.artifacts_removed,
+                    mb_reclaimed = report.mb_reclaimed,
+                    duration_ms = report.duration_ms,
+                    "Scheduled maintenance completed successfully"
+                );
+            } else {
+                error!(errors = ?report.errors, "Scheduled maintenance finished with errors");
+            }
+        }
+    }
+
+    /// Run every maintenance step once, then persist and publish the report
+    ///
+    /// A failing step is recorded in the report and does not stop later steps.
+    pub async fn run_once(&self) -> MaintenanceReport {
+        let config = self.effective_config();
+        let started_at = self.time_provider.now_millis();
+        let started = Instant::now();
+        let mut errors = Vec::new();
+
+        let stats_before = self
+            .maintenance
+            .get_stats()
+            .await
+            .map_err(|e| errors.push(format!("stats before: {}", e)))
+            .ok();
+
+        let jobs_deleted = self
+            .maintenance
+            .gc_finished_jobs(config.finished_job_retention_days)
+            .await
+            .unwrap_or_else(|e| {
+                errors.push(format!("gc_finished_jobs: {}", e));
+                0
+            });
+
+        let artifacts_removed = self
+            .maintenance
+            .gc_artifacts(config.artifact_retention_days)
+            .await
+            .unwrap_or_else(|e| {
+                errors.push(format!("gc_artifacts: {}", e));
+                0
+            });
+
+        let success_logs_dropped = self
+            .maintenance
+            .sample_success_logs(
+                config.full_log_retention_hours,
+                config.success_log_sample_rate,
+            )
+            .await
+            .unwrap_or_else(|e| {
+                errors.push(format!("sample_success_logs: {}", e));
+                0
+            });
+
+        let mut vacuum_run = false;
+        if let Some(before) = &stats_before {
+            if before.db_size_mb > config.max_db_size_mb {
+                match self.maintenance.vacuum().await {
+                    Ok(_) => vacuum_run = true,
+                    Err(e) => errors.push(format!("vacuum: {}", e)),
                 }
             }
         }
+
+        let stats_after = self
+            .maintenance
+            .get_stats()
+            .await
+            .map_err(|e| errors.push(format!("stats after: {}", e)))
+            .ok();
+
+        let mb_reclaimed = match (&stats_before, &stats_after) {
+            (Some(before), Some(after)) => ((before.db_size_mb + before.log_files_size_mb)
+                - (after.db_size_mb + after.log_files_size_mb))
+                .max(0.0),
+            _ => 0.0,
+        };
+
+        let report = MaintenanceReport {
+            started_at,
+            duration_ms: started.elapsed().as_millis() as i64,
+            jobs_deleted,
+            artifacts_removed,
+            success_logs_dropped,
+            vacuum_run,
+            mb_reclaimed,
+            errors,
+        };
+
+        if let Err(e) = self
+            .maintenance
+            .record_report(&report, REPORT_HISTORY_SIZE)
+            .await
+        {
+            warn!(error = %e, "Failed to persist maintenance report");
+        }
+
+        let notification = Notification {
+            topic: MAINTENANCE_REPORT_TOPIC.to_string(),
+            timestamp: self.time_provider.now_millis(),
+            payload: serde_json::to_value(&report).unwrap_or_default(),
+        };
+        if let Err(e) = self.notifier.notify(&notification).await {
+            warn!(error = %e, "Failed to publish maintenance report");
+        }
+
+        report
     }
 
     /// Run maintenance immediately (for manual trigger)
     pub async fn run_now(&self) -> Result<()> {
         info!("Running manual maintenance...");
 
-        let stats = self.maintenance.run_full_maintenance(&self.config).await?;
+        let stats = self
+            .maintenance
+            .run_full_maintenance(&self.effective_config())
+            .await?;
 
         info!(
             db_size_mb = stats.db_size_mb,
@@ -87,3 +273,138 @@ impl MaintenanceScheduler {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AppError;
+    use crate::port::{GcPreview, MaintenanceStats};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct FixedTimeProvider;
+
+    impl TimeProvider for FixedTimeProvider {
+        fn now_millis(&self) -> i64 {
+            1_000
+        }
+    }
+
+    #[derive(Default)]
+    struct MockMaintenance {
+        reports: Mutex<Vec<MaintenanceReport>>,
+    }
+
+    #[async_trait]
+    impl Maintenance for MockMaintenance {
+        async fn vacuum(&self) -> Result<f64> {
+            Ok(0.0)
+        }
+
+        async fn gc_finished_jobs(&self, _retention_days: i64) -> Result<i64> {
+            Ok(3)
+        }
+
+        async fn gc_artifacts(&self, _retention_days: i64) -> Result<usize> {
+            Err(AppError::Internal("disk unavailable".to_string()))
+        }
+
+        async fn sample_success_logs(
+            &self,
+            _older_than_hours: i64,
+            _sample_rate: f64,
+        ) -> Result<usize> {
+            Ok(2)
+        }
+
+        async fn preview_gc(
+            &self,
+            _job_retention_days: i64,
+            _artifact_retention_days: i64,
+        ) -> Result<GcPreview> {
+            Ok(GcPreview::default())
+        }
+
+        async fn get_stats(&self) -> Result<MaintenanceStats> {
+            Ok(MaintenanceStats {
+                db_size_mb: 1.0,
+                db_size_bytes: 1024 * 1024,
+                job_count: 0,
+                finished_job_count: 0,
+                artifact_count: 0,
+                log_files_size_mb: 0.0,
+                fragmentation_percent: 0.0,
+                schema_version: 0,
+            })
+        }
+
+        async fn record_report(&self, report: &MaintenanceReport, _keep: usize) -> Result<()> {
+            self.reports.lock().unwrap().push(report.clone());
+            Ok(())
+        }
+
+        async fn list_reports(&self, _limit: usize) -> Result<Vec<MaintenanceReport>> {
+            Ok(self.reports.lock().unwrap().clone())
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingNotifier {
+        topics: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl Notifier for RecordingNotifier {
+        async fn notify(&self, notification: &Notification) -> Result<()> {
+            self.topics.lock().unwrap().push(notification.topic.clone());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_idle_gate_waits_for_idle_window() {
+        use crate::port::system_probe::mocks::MockSystemProbe;
+
+        let scheduler = MaintenanceScheduler::new(
+            Arc::new(MockMaintenance::default()),
+            MaintenanceConfig::default(),
+            24,
+            Arc::new(FixedTimeProvider),
+        );
+        assert!(scheduler.wait_for_idle_window(Duration::ZERO).await);
+
+        // The mock machine is never idle
+        let mut gated = scheduler.with_idle_gate(Arc::new(MockSystemProbe::new(0.0)));
+        gated.idle_poll = Duration::from_millis(5);
+        assert!(!gated.wait_for_idle_window(Duration::from_millis(20)).await);
+    }
+
+    #[tokio::test]
+    async fn test_run_once_records_and_publishes_report() {
+        let maintenance = Arc::new(MockMaintenance::default());
+        let notifier = Arc::new(RecordingNotifier::default());
+        let scheduler = MaintenanceScheduler::new(
+            maintenance.clone(),
+            MaintenanceConfig::default(),
+            24,
+            Arc::new(FixedTimeProvider),
+        )
+        .with_notifier(notifier.clone());
+
+        let report = scheduler.run_once().await;
+
+        // Artifact GC failure is reported, job GC still ran
+        assert_eq!(report.started_at, 1_000);
+        assert_eq!(report.jobs_deleted, 3);
+        assert_eq!(report.artifacts_removed, 0);
+        assert_eq!(report.success_logs_dropped, 2);
+        assert_eq!(report.errors.len(), 1);
+        assert!(report.errors[0].contains("gc_artifacts"));
+
+        assert_eq!(maintenance.list_reports(10).await.unwrap().len(), 1);
+        assert_eq!(
+            *notifier.topics.lock().unwrap(),
+            vec![MAINTENANCE_REPORT_TOPIC.to_string()]
+        );
+    }
+}
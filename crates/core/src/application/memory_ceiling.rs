@@ -0,0 +1,200 @@
+// Memory Ceiling Policy - Aggregate memory limit for job subprocesses
+//
+// Per-job limits do not stop several medium jobs from swapping the machine
+// together. A sampler sums the resident memory of every running job's process
+// tree (SystemProbe) and, while the total is above the ceiling, no new job
+// starts. Optionally the most recently started job is killed to get back under
+// it; it fails and goes through the normal retry policy.
+
+use crate::application::scheduling_policy::SchedulingPolicy;
+use crate::domain::Job;
+use crate::port::{SystemProbe, TaskExecutor};
+use async_trait::async_trait;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::interval;
+use tracing::{debug, error, info, warn};
+
+/// How often the memory of running jobs is sampled (2s)
+pub const DEFAULT_MEMORY_SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Jobs start only while their subprocesses stay under a memory ceiling
+pub struct MemoryCeilingPolicy {
+    system_probe: Arc<dyn SystemProbe>,
+    task_executor: Arc<dyn TaskExecutor>,
+    ceiling_mb: u64,
+    kill_newest: bool,
+    /// Latest sampled total (MB)
+    used_mb: AtomicU64,
+}
+
+impl MemoryCeilingPolicy {
+    /// Create a policy holding back new jobs above `ceiling_mb`
+    pub fn new(
+        system_probe: Arc<dyn SystemProbe>,
+        task_executor: Arc<dyn TaskExecutor>,
+        ceiling_mb: u64,
+    ) -> Self {
+        Self {
+            system_probe,
+            task_executor,
+            ceiling_mb,
+            kill_newest: false,
+            used_mb: AtomicU64::new(0),
+        }
+    }
+
+    /// Also kill the most recently started job while over the ceiling
+    pub fn with_kill_newest(mut self, kill_newest: bool) -> Self {
+        self.kill_newest = kill_newest;
+        self
+    }
+
+    /// Memory of running job subprocesses at the last sample (MB)
+    pub fn used_mb(&self) -> u64 {
+        self.used_mb.load(Ordering::Relaxed)
+    }
+
+    pub fn is_exceeded(&self) -> bool {
+        self.used_mb() > self.ceiling_mb
+    }
+
+    /// Sample the memory of running jobs, killing the newest one if configured
+    ///
+    /// Returns the sampled total (MB).
+    pub async fn sample(&self) -> u64 {
+        let running = self.task_executor.running();
+        let pids: Vec<u32> = running.iter().map(|process| process.pid).collect();
+        let used_mb = self.system_probe.process_tree_memory_mb(&pids).await;
+        self.used_mb.store(used_mb, Ordering::Relaxed);
+
+        if used_mb <= self.ceiling_mb {
+            return used_mb;
+        }
+        warn!(
+            used_mb,
+            ceiling_mb = self.ceiling_mb,
+            running = running.len(),
+            "Job memory above ceiling, holding back new jobs"
+        );
+
+        if self.kill_newest {
+            if let Some(newest) = running.iter().max_by_key(|process| process.started_at) {
+                warn!(job_id = %newest.job_id, pid = newest.pid, "Killing newest job to free memory");
+                if let Err(e) = self.task_executor.kill(newest.pid as i32).await {
+                    error!(job_id = %newest.job_id, error = %e, "Failed to kill job");
+                }
+            }
+        }
+        used_mb
+    }
+
+    /// Run the sampling loop (background task)
+    ///
+    /// Should be spawned in tokio::spawn
+    pub async fn run(self: Arc<Self>, every: Duration) {
+        info!(
+            ceiling_mb = self.ceiling_mb,
+            kill_newest = self.kill_newest,
+            "Memory ceiling sampler started"
+        );
+
+        let mut tick = interval(every);
+        loop {
+            tick.tick().await;
+            self.sample().await;
+        }
+    }
+}
+
+#[async_trait]
+impl SchedulingPolicy for MemoryCeilingPolicy {
+    fn name(&self) -> &str {
+        "memory_ceiling"
+    }
+
+    async fn is_ready(&self, job: &Job) -> bool {
+        if self.is_exceeded() {
+            debug!(
+                job_id = %job.id,
+                used_mb = self.used_mb(),
+                ceiling_mb = self.ceiling_mb,
+                "Job not ready: memory ceiling exceeded"
+            );
+            return false;
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{JobPayload, JobType};
+    use crate::port::system_probe::mocks::MockSystemProbe;
+    use crate::port::task_executor::mocks::MockTaskExecutor;
+    use crate::port::RunningProcess;
+
+    fn job() -> Job {
+        Job::new_test(
+            "test_queue",
+            JobType::new("BUILD"),
+            "crate",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        )
+    }
+
+    fn process(pid: u32, started_at: i64) -> RunningProcess {
+        RunningProcess {
+            job_id: format!("job-{}", pid),
+            pid,
+            started_at,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_holds_back_jobs_above_ceiling() {
+        let probe = Arc::new(MockSystemProbe::new(10.0));
+        let executor = Arc::new(MockTaskExecutor::new_success());
+        let policy = MemoryCeilingPolicy::new(probe.clone(), executor.clone(), 1_000);
+
+        executor.set_running(vec![process(100, 1), process(200, 2)]);
+        probe.set_process_memory_mb(1_500);
+        assert_eq!(policy.sample().await, 1_500);
+        assert!(!policy.is_ready(&job()).await);
+        assert!(executor.killed_pids().is_empty(), "Defer only by default");
+
+        probe.set_process_memory_mb(600);
+        policy.sample().await;
+        assert!(policy.is_ready(&job()).await);
+    }
+
+    #[tokio::test]
+    async fn test_kills_newest_job_above_ceiling() {
+        let probe = Arc::new(MockSystemProbe::new(10.0));
+        let executor = Arc::new(MockTaskExecutor::new_success());
+        let policy =
+            MemoryCeilingPolicy::new(probe.clone(), executor.clone(), 1_000).with_kill_newest(true);
+
+        executor.set_running(vec![process(100, 5), process(300, 9), process(200, 7)]);
+        probe.set_process_memory_mb(1_200);
+        policy.sample().await;
+
+        assert_eq!(executor.killed_pids(), vec![300]);
+    }
+
+    #[tokio::test]
+    async fn test_no_running_jobs_uses_no_memory() {
+        let probe = Arc::new(MockSystemProbe::new(10.0));
+        probe.set_process_memory_mb(5_000);
+        let executor = Arc::new(MockTaskExecutor::new_success());
+        let policy =
+            MemoryCeilingPolicy::new(probe, executor.clone(), 1_000).with_kill_newest(true);
+
+        assert_eq!(policy.sample().await, 0);
+        assert!(policy.is_ready(&job()).await);
+        assert!(executor.killed_pids().is_empty());
+    }
+}
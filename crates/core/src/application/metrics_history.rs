@@ -0,0 +1,95 @@
+// Metrics History - Periodic throughput snapshots
+//
+// admin.stats.v1 only reports instantaneous counters. The recorder samples
+// once per interval how many jobs finished DONE / FAILED since the previous
+// sample and how deep the queues are, and stores the result so
+// admin.metrics.history.v1 can render a trend. Only the newest snapshots are
+// kept (a week of one-minute samples by default).
+
+use crate::domain::MetricsSnapshot;
+use crate::error::Result;
+use crate::port::{JobRepository, TimeProvider};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::interval;
+use tracing::{info, warn};
+
+/// How often a snapshot is taken (1min)
+pub const DEFAULT_METRICS_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Snapshots kept (one week of one-minute samples)
+pub const DEFAULT_METRICS_HISTORY_LEN: usize = 7 * 24 * 60;
+
+/// Snapshots returned by default (one hour)
+pub const DEFAULT_METRICS_HISTORY_LIMIT: usize = 60;
+
+/// Most snapshots returned by one admin.metrics.history.v1 call
+pub const MAX_METRICS_HISTORY_LIMIT: usize = DEFAULT_METRICS_HISTORY_LEN;
+
+/// Takes and stores throughput snapshots
+pub struct MetricsRecorder {
+    job_repo: Arc<dyn JobRepository>,
+    time_provider: Arc<dyn TimeProvider>,
+    every: Duration,
+    keep: usize,
+    /// End of the previous window (None before the first sample)
+    last_taken_at: Mutex<Option<i64>>,
+}
+
+impl MetricsRecorder {
+    pub fn new(
+        job_repo: Arc<dyn JobRepository>,
+        time_provider: Arc<dyn TimeProvider>,
+        every: Duration,
+    ) -> Self {
+        Self {
+            job_repo,
+            time_provider,
+            every,
+            keep: DEFAULT_METRICS_HISTORY_LEN,
+            last_taken_at: Mutex::new(None),
+        }
+    }
+
+    /// Snapshots kept in the database (older ones are pruned)
+    pub fn with_history_len(mut self, keep: usize) -> Self {
+        self.keep = keep;
+        self
+    }
+
+    /// Take and store one snapshot of the window since the previous one
+    ///
+    /// The first window is the one interval before it.
+    pub async fn record_once(&self) -> Result<MetricsSnapshot> {
+        let now = self.time_provider.now_millis();
+        let since = self
+            .last_taken_at
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .unwrap_or(now - self.every.as_millis() as i64);
+
+        let snapshot = self.job_repo.take_metrics_snapshot(since, now).await?;
+        self.job_repo
+            .record_metrics_snapshot(&snapshot, self.keep)
+            .await?;
+        *self.last_taken_at.lock().unwrap_or_else(|e| e.into_inner()) = Some(now);
+        Ok(snapshot)
+    }
+
+    /// Sample every interval (should be spawned in tokio::spawn)
+    pub async fn run(self: Arc<Self>) {
+        info!(
+            every_secs = self.every.as_secs(),
+            keep = self.keep,
+            "Metrics recorder started"
+        );
+
+        let mut tick = interval(self.every);
+        loop {
+            tick.tick().await;
+            if let Err(e) = self.record_once().await {
+                warn!(error = %e, "Failed to record metrics snapshot");
+            }
+        }
+    }
+}
@@ -1,13 +1,58 @@
 // Application Layer - Use Cases and Business Logic
 
 pub mod dev_task;
+pub mod dispatch_signal;
+pub mod drain;
+pub mod expiry_sweeper;
+pub mod failure_bundle;
+pub mod forwarder;
+pub mod idle_budget;
+pub mod interceptor;
+pub mod lease;
+pub mod lockdown;
 pub mod maintenance;
+pub mod memory_ceiling;
+pub mod metrics_history;
+pub mod payload_validator;
+pub mod queue_pause;
 pub mod recovery; // Phase 2
 pub mod retry; // Phase 2
+pub mod retry_policies;
+pub mod runtime_config;
 pub mod scheduler; // Phase 3
+pub mod scheduling_policy;
+pub mod snapshot;
+pub mod stalled;
+pub mod starvation;
+pub mod state_feed;
+pub mod warmup;
 pub mod worker; // Phase 3 // Phase 4
 
 // Re-exports
 pub use dev_task::DevTaskService;
+pub use dispatch_signal::DispatchSignal;
+pub use drain::{Drain, InFlight};
+pub use expiry_sweeper::ExpirySweeper;
+pub use failure_bundle::FailureBundler;
+pub use forwarder::Forwarder;
+pub use idle_budget::IdleBudgetPolicy;
+pub use interceptor::{InterceptorChain, JobInterceptor};
+pub use lease::{LeaseOutcome, LeaseService};
+pub use lockdown::{Lockdown, LockdownState};
 pub use maintenance::MaintenanceScheduler;
-pub use worker::{shutdown_channel, ShutdownSender, ShutdownToken, Worker}; // Phase 4
+pub use memory_ceiling::MemoryCeilingPolicy;
+pub use metrics_history::MetricsRecorder;
+pub use payload_validator::{PayloadValidator, QueueValidators};
+pub use queue_pause::QueuePauseRegistry;
+pub use retry_policies::{RetryPolicies, RetryRule};
+pub use runtime_config::{RuntimeConfig, RuntimeSettings};
+pub use scheduling_policy::{PolicyChain, SchedulingPolicy};
+pub use snapshot::{RestoreSummary, SnapshotService};
+pub use stalled::StalledJobDetector;
+pub use starvation::StarvationDetector;
+pub use state_feed::{JobStateChange, StateFeed, SubscriptionFilter, UpdateKind};
+pub use warmup::Warmup;
+pub use worker::{
+    shutdown_channel, RunningJobs, ShutdownSender, ShutdownToken, SlotOccupant, Termination,
+    Worker, WorkerActivity, WorkerExit, WorkerSupervisor,
+}; // Phase 4
@@ -0,0 +1,303 @@
+// Payload Validators - Per-queue enqueue hygiene rules
+//
+// Queues shared with less trusted clients can enforce stricter payload rules
+// (smaller size limit, required fields, forbidden keys such as `env`) than
+// the global limits in enqueue validation.
+
+use super::dev_task::enqueue::serialized_len;
+use super::dev_task::EnqueueRequest;
+use crate::error::{AppError, Result};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Queue name matching every queue in a spec
+pub const ALL_QUEUES: &str = "*";
+
+/// Rule checked against each payload (primary and fallbacks) of an enqueue
+pub trait PayloadValidator: Send + Sync {
+    /// Rule name (for error messages)
+    fn name(&self) -> &str;
+
+    /// Check one payload; the error message is prefixed with queue and payload
+    fn validate(&self, payload: &Value) -> std::result::Result<(), String>;
+}
+
+/// Validators registered per queue (registration order = evaluation order)
+#[derive(Clone, Default)]
+pub struct QueueValidators {
+    by_queue: HashMap<String, Vec<Arc<dyn PayloadValidator>>>,
+}
+
+impl QueueValidators {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a validator for `queue` (`*` = every queue)
+    pub fn register(&mut self, queue: impl Into<String>, validator: Arc<dyn PayloadValidator>) {
+        self.by_queue
+            .entry(queue.into())
+            .or_default()
+            .push(validator);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_queue.is_empty()
+    }
+
+    /// Number of registered validators
+    pub fn len(&self) -> usize {
+        self.by_queue.values().map(Vec::len).sum()
+    }
+
+    /// Build validators from built-in rules declared in configuration
+    ///
+    /// Format: comma-separated `queue:rule=arg` entries (`*` = every queue), e.g.
+    /// `untrusted:max_payload_bytes=65536,untrusted:require=path,untrusted:forbid=env`
+    ///
+    /// Built-ins:
+    /// - `max_payload_bytes=<N>` - reject payloads larger than N bytes (serialized)
+    /// - `require=<FIELD>` - reject payloads without the top-level field
+    /// - `forbid=<KEY>` - reject payloads containing the key at any depth
+    pub fn from_spec(spec: &str) -> Result<Self> {
+        let mut validators = Self::new();
+
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let invalid = || {
+                AppError::Config(format!(
+                    "Invalid payload validator entry '{}' (expected queue:rule=arg)",
+                    entry
+                ))
+            };
+            let (queue, rule) = entry.split_once(':').ok_or_else(invalid)?;
+            let (name, arg) = rule.split_once('=').ok_or_else(invalid)?;
+            let (queue, name, arg) = (queue.trim(), name.trim(), arg.trim());
+            if queue.is_empty() || arg.is_empty() {
+                return Err(invalid());
+            }
+
+            let validator: Arc<dyn PayloadValidator> = match name {
+                "max_payload_bytes" => {
+                    let max_bytes = match arg.parse::<usize>() {
+                        Ok(n) if n > 0 => n,
+                        _ => {
+                            return Err(AppError::Config(format!(
+                                "max_payload_bytes must be a positive integer, got '{}'",
+                                arg
+                            )))
+                        }
+                    };
+                    Arc::new(MaxPayloadBytes::new(max_bytes))
+                }
+                "require" => Arc::new(RequiredField::new(arg)),
+                "forbid" => Arc::new(ForbiddenKey::new(arg)),
+                other => {
+                    return Err(AppError::Config(format!(
+                        "Unknown payload validator '{}'",
+                        other
+                    )))
+                }
+            };
+            validators.register(queue, validator);
+        }
+
+        Ok(validators)
+    }
+
+    /// Run the validators of the request's queue (and `*`) on every payload
+    pub fn validate(&self, req: &EnqueueRequest) -> Result<()> {
+        let rules = [ALL_QUEUES, req.queue.as_str()]
+            .into_iter()
+            .filter_map(|queue| self.by_queue.get(queue))
+            .flatten();
+
+        for validator in rules {
+            let payloads = std::iter::once(("Payload".to_string(), &req.payload)).chain(
+                req.fallback_payloads
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| (format!("Fallback payload {}", i + 1), p)),
+            );
+            for (what, payload) in payloads {
+                validator.validate(payload).map_err(|reason| {
+                    AppError::Validation(format!(
+                        "{} rejected by queue '{}' rule {}: {}",
+                        what,
+                        req.queue,
+                        validator.name(),
+                        reason
+                    ))
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Built-in: cap the serialized payload size
+pub struct MaxPayloadBytes {
+    max_bytes: usize,
+}
+
+impl MaxPayloadBytes {
+    pub fn new(max_bytes: usize) -> Self {
+        Self { max_bytes }
+    }
+}
+
+impl PayloadValidator for MaxPayloadBytes {
+    fn name(&self) -> &str {
+        "max_payload_bytes"
+    }
+
+    fn validate(&self, payload: &Value) -> std::result::Result<(), String> {
+        let size = serialized_len(payload);
+        if size > self.max_bytes {
+            return Err(format!(
+                "too large (max {} bytes, got {})",
+                self.max_bytes, size
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Built-in: require a top-level payload field
+pub struct RequiredField {
+    field: String,
+}
+
+impl RequiredField {
+    pub fn new(field: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+        }
+    }
+}
+
+impl PayloadValidator for RequiredField {
+    fn name(&self) -> &str {
+        "require"
+    }
+
+    fn validate(&self, payload: &Value) -> std::result::Result<(), String> {
+        match payload.get(&self.field) {
+            Some(value) if !value.is_null() => Ok(()),
+            _ => Err(format!("missing required field '{}'", self.field)),
+        }
+    }
+}
+
+/// Built-in: reject a key anywhere in the payload (e.g. `env` on untrusted queues)
+pub struct ForbiddenKey {
+    key: String,
+}
+
+impl ForbiddenKey {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into() }
+    }
+
+    fn contains_key(&self, value: &Value) -> bool {
+        match value {
+            Value::Object(fields) => fields
+                .iter()
+                .any(|(key, value)| *key == self.key || self.contains_key(value)),
+            Value::Array(items) => items.iter().any(|item| self.contains_key(item)),
+            _ => false,
+        }
+    }
+}
+
+impl PayloadValidator for ForbiddenKey {
+    fn name(&self) -> &str {
+        "forbid"
+    }
+
+    fn validate(&self, payload: &Value) -> std::result::Result<(), String> {
+        if self.contains_key(payload) {
+            return Err(format!("key '{}' is not allowed", self.key));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(queue: &str, payload: Value) -> EnqueueRequest {
+        EnqueueRequest {
+            job_type: "BUILD".to_string(),
+            queue: queue.to_string(),
+            subject_key: "repo".to_string(),
+            payload,
+            priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_from_spec() {
+        let validators = QueueValidators::from_spec(
+            "untrusted:max_payload_bytes=64, untrusted:require=path,*:forbid=secret",
+        )
+        .unwrap();
+        assert_eq!(validators.len(), 3);
+
+        assert!(QueueValidators::from_spec("").unwrap().is_empty());
+        assert!(QueueValidators::from_spec("untrusted").is_err());
+        assert!(QueueValidators::from_spec("untrusted:forbid").is_err());
+        assert!(QueueValidators::from_spec("untrusted:forbid=").is_err());
+        assert!(QueueValidators::from_spec("untrusted:max_payload_bytes=0").is_err());
+        assert!(QueueValidators::from_spec("untrusted:shout=yes").is_err());
+    }
+
+    #[test]
+    fn test_rules_apply_to_their_queue() {
+        let validators =
+            QueueValidators::from_spec("untrusted:require=path,untrusted:forbid=env").unwrap();
+
+        let ok = request("untrusted", json!({ "path": "/repo" }));
+        assert!(validators.validate(&ok).is_ok());
+
+        let missing = request("untrusted", json!({ "command": "ls" }));
+        let err = validators.validate(&missing).unwrap_err();
+        assert!(matches!(err, AppError::Validation(_)));
+        assert!(err.to_string().contains("missing required field 'path'"));
+
+        let nested_env = request(
+            "untrusted",
+            json!({ "path": "/repo", "steps": [{ "env": { "TOKEN": "x" } }] }),
+        );
+        assert!(validators
+            .validate(&nested_env)
+            .unwrap_err()
+            .to_string()
+            .contains("key 'env' is not allowed"));
+
+        // Other queues are unaffected
+        let trusted = request("default", json!({ "env": {} }));
+        assert!(validators.validate(&trusted).is_ok());
+    }
+
+    #[test]
+    fn test_wildcard_and_fallback_payloads() {
+        let validators = QueueValidators::from_spec("*:max_payload_bytes=32").unwrap();
+
+        let mut req = request("default", json!({ "a": 1 }));
+        assert!(validators.validate(&req).is_ok());
+
+        req.fallback_payloads = vec![json!({ "blob": "x".repeat(64) })];
+        let err = validators.validate(&req).unwrap_err().to_string();
+        assert!(err.contains("Fallback payload 1"));
+        assert!(err.contains("max 32 bytes"));
+    }
+}
@@ -0,0 +1,74 @@
+// Queue Pause Registry - Queues operators stopped without shutting down
+//
+// Shared by the RPC handler (admin.queue.pause/resume) and everything that
+// pops jobs (Worker, LeaseService). Paused queues keep accepting enqueues;
+// their jobs stay QUEUED until the queue is resumed. In-memory only: a daemon
+// restart resumes every queue.
+
+use std::collections::BTreeSet;
+use std::sync::RwLock;
+
+/// Set of paused queues
+#[derive(Debug, Default)]
+pub struct QueuePauseRegistry {
+    paused: RwLock<BTreeSet<String>>,
+}
+
+impl QueuePauseRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop handing out jobs of `queue` (false if it was already paused)
+    pub fn pause(&self, queue: &str) -> bool {
+        self.paused
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(queue.to_string())
+    }
+
+    /// Hand out jobs of `queue` again (false if it was not paused)
+    pub fn resume(&self, queue: &str) -> bool {
+        self.paused
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(queue)
+    }
+
+    pub fn is_paused(&self, queue: &str) -> bool {
+        self.paused
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .contains(queue)
+    }
+
+    /// Paused queues, sorted by name
+    pub fn paused(&self) -> Vec<String> {
+        self.paused
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_and_resume() {
+        let registry = QueuePauseRegistry::new();
+
+        assert!(registry.pause("code_intel"));
+        assert!(!registry.pause("code_intel"));
+        assert!(registry.is_paused("code_intel"));
+        assert!(!registry.is_paused("default"));
+        assert_eq!(registry.paused(), vec!["code_intel"]);
+
+        assert!(registry.resume("code_intel"));
+        assert!(!registry.resume("code_intel"));
+        assert!(registry.paused().is_empty());
+    }
+}
@@ -14,6 +14,8 @@ pub struct RecoveryService {
     task_executor: Arc<dyn TaskExecutor>,
     time_provider: Arc<dyn TimeProvider>,
     recovery_window_ms: i64,
+    /// Lease holder of this daemon's worker (its leases are recovered too)
+    worker_id: Option<String>,
 }
 
 impl RecoveryService {
@@ -46,9 +48,16 @@ impl RecoveryService {
             task_executor,
             time_provider,
             recovery_window_ms: recovery_window_ms.unwrap_or(DEFAULT_RECOVERY_WINDOW_MS),
+            worker_id: None,
         }
     }
 
+    /// Recover the jobs leased to this worker id like unleased ones
+    pub fn with_worker_id(mut self, worker_id: impl Into<String>) -> Self {
+        self.worker_id = Some(worker_id.into());
+        self
+    }
+
     /// Recover orphaned jobs on daemon startup
     ///
     /// Algorithm (ADR-002):
@@ -60,6 +69,10 @@ impl RecoveryService {
     /// 3. For jobs without PID (in-process):
     ///    - Mark as REQUEUED (can be retried)
     ///
+    /// Jobs with an unexpired lease held by another worker (an external worker
+    /// still heartbeating) are skipped: LeaseService::reap_expired reclaims
+    /// them if the lease runs out. Recovered jobs lose their lease.
+    ///
     /// # Returns
     /// Number of jobs recovered
     pub async fn recover_orphaned_jobs(&self) -> crate::error::Result<usize> {
@@ -77,9 +90,25 @@ impl RecoveryService {
         let mut recovered_count = 0;
 
         for mut job in running_jobs {
+            if self.leased_elsewhere(&job, now).await? {
+                info!(job_id = %job.id, "RUNNING job leased by another worker, not recovered");
+                continue;
+            }
+
             // Check if job is orphaned (started_at is too old)
             if let Some(started_at) = job.started_at {
-                if started_at < cutoff {
+                // Started "in the future": the clock went backwards since, so the
+                // real age is unknown. Nothing survives a daemon restart: recover.
+                let clock_skewed = started_at > now;
+                if clock_skewed {
+                    warn!(
+                        job_id = %job.id,
+                        started_at = %started_at,
+                        now = %now,
+                        "Clock anomaly: RUNNING job started in the future, recovering"
+                    );
+                }
+                if started_at < cutoff || clock_skewed {
                     info!(
                         job_id = %job.id,
                         started_at = %started_at,
@@ -101,6 +130,7 @@ impl RecoveryService {
                 job.state = JobState::Failed;
                 job.finished_at = Some(now);
                 self.job_repo.update(&job).await?;
+                self.job_repo.delete_lease(&job.id).await?;
                 recovered_count += 1;
             }
         }
@@ -155,9 +185,22 @@ impl RecoveryService {
         }
 
         self.job_repo.update(job).await?;
+        self.job_repo.delete_lease(&job.id).await?;
         Ok(())
     }
 
+    /// The job holds an unexpired lease of a worker other than this daemon's
+    async fn leased_elsewhere(&self, job: &Job, now: i64) -> crate::error::Result<bool> {
+        let Some(lease) = self.job_repo.find_lease(&job.id).await? else {
+            return Ok(false);
+        };
+        let own = self
+            .worker_id
+            .as_deref()
+            .is_some_and(|worker_id| lease.is_held_by(worker_id));
+        Ok(!own && !lease.is_expired(now))
+    }
+
     /// Cleanup zombie processes (processes that exist but job is not RUNNING)
     ///
     /// This is a defensive measure to kill any leaked processes
@@ -1,6 +1,12 @@
 // Retry logic (Phase 2, ADR-002)
-use crate::domain::{Job, JobState};
-use crate::port::TimeProvider;
+use crate::application::retry_policies::RetryRule;
+use crate::application::worker::constants::{
+    DEFAULT_MAX_PANICS, DEFAULT_RETRY_MAX_DELAY_MS, SERVICE_RESTART_BASE_DELAY_MS,
+    SERVICE_RESTART_MAX_DELAY_MS,
+};
+use crate::domain::job_event::actor;
+use crate::domain::{Job, JobEvent, JobState};
+use crate::port::{elapsed_ms, TimeProvider};
 use std::sync::Arc;
 use tracing::{info, warn};
 
@@ -11,6 +17,8 @@ pub enum RetryDecision {
     Retry(i64),
     /// Do not retry, job has failed permanently
     Failed,
+    /// Do not retry: the next attempt would start past the retry deadline (reason)
+    RetryDeadlineExceeded(String),
 }
 
 /// Retry policy based on ADR-002
@@ -18,10 +26,23 @@ pub enum RetryDecision {
 /// Determines if a job should be retried based on:
 /// - Current attempt count
 /// - Maximum attempts allowed
-/// - Backoff factor for exponential delay
+/// - Backoff factor for exponential delay, capped at `max_delay_ms`
+/// - Retry deadline: how long after its creation a job may still be retried
+///
+/// Panics are counted apart from attempts: a job that panicked `max_panics`
+/// times is poisoned and quarantined instead of retried.
+///
+/// A job type's rule (see RetryPolicies) overrides the job's own attempts
+/// and backoff factor.
+#[derive(Clone)]
 pub struct RetryPolicy {
     time_provider: Arc<dyn TimeProvider>,
     base_delay_ms: i64,
+    max_delay_ms: i64,
+    retry_deadline_ms: Option<i64>,
+    max_panics: i32,
+    max_attempts: Option<i32>,
+    backoff_factor: Option<f64>,
 }
 
 impl RetryPolicy {
@@ -39,17 +60,74 @@ impl RetryPolicy {
         Self {
             time_provider,
             base_delay_ms,
+            max_delay_ms: DEFAULT_RETRY_MAX_DELAY_MS,
+            retry_deadline_ms: None,
+            max_panics: DEFAULT_MAX_PANICS,
+            max_attempts: None,
+            backoff_factor: None,
+        }
+    }
+
+    /// This policy with a job type's rule applied
+    pub fn with_rule(&self, rule: &RetryRule) -> Self {
+        let base_delay_ms = rule.base_delay_ms.unwrap_or(self.base_delay_ms);
+        Self {
+            base_delay_ms,
+            max_delay_ms: self.max_delay_ms.max(base_delay_ms),
+            max_attempts: rule.max_attempts.or(self.max_attempts),
+            backoff_factor: rule.backoff_factor.or(self.backoff_factor),
+            ..self.clone()
         }
     }
 
+    /// Delay before the first retry (ms)
+    pub fn base_delay_ms(&self) -> i64 {
+        self.base_delay_ms
+    }
+
+    /// Never wait longer than `max_delay_ms` between two retries (at least the base delay)
+    pub fn with_max_delay_ms(mut self, max_delay_ms: i64) -> Self {
+        self.max_delay_ms = max_delay_ms.max(self.base_delay_ms);
+        self
+    }
+
+    /// Upper bound of the delay between two retries (ms)
+    pub fn max_delay_ms(&self) -> i64 {
+        self.max_delay_ms
+    }
+
+    /// Stop retrying jobs once `retry_deadline_ms` have passed since they were created
+    pub fn with_retry_deadline_ms(mut self, retry_deadline_ms: i64) -> Self {
+        self.retry_deadline_ms = Some(retry_deadline_ms.max(0));
+        self
+    }
+
+    /// Time after creation past which a job is not retried (ms, None = no limit)
+    pub fn retry_deadline_ms(&self) -> Option<i64> {
+        self.retry_deadline_ms
+    }
+
+    /// Quarantine jobs after this many panicked executions (at least 1)
+    pub fn with_max_panics(mut self, max_panics: i32) -> Self {
+        self.max_panics = max_panics.max(1);
+        self
+    }
+
+    /// Panicked executions after which a job is quarantined
+    pub fn max_panics(&self) -> i32 {
+        self.max_panics
+    }
+
     /// Determine if a job should be retried
     ///
     /// Returns:
     /// - `RetryDecision::Retry(delay_ms)` if job should be retried with calculated backoff
     /// - `RetryDecision::Failed` if max attempts reached
+    /// - `RetryDecision::RetryDeadlineExceeded(reason)` if the retry would
+    ///   start past the job's retry deadline
     ///
     /// Backoff formula (ADR-002):
-    /// delay = base_delay * (backoff_factor ^ attempt)
+    /// delay = min(base_delay * (backoff_factor ^ attempt), max_delay)
     ///
     /// # Example
     /// ```text
@@ -61,15 +139,19 @@ impl RetryPolicy {
     ///     RetryDecision::Failed => {
     ///         println!("Max retries exceeded");
     ///     }
+    ///     RetryDecision::RetryDeadlineExceeded(reason) => {
+    ///         println!("Gave up: {}", reason);
+    ///     }
     /// }
     /// ```
     pub fn should_retry(&self, job: &Job) -> RetryDecision {
         // Check if max attempts reached
-        if job.attempts >= job.max_attempts {
+        let max_attempts = self.max_attempts.unwrap_or(job.max_attempts);
+        if job.attempts >= max_attempts {
             warn!(
                 job_id = %job.id,
                 attempts = %job.attempts,
-                max_attempts = %job.max_attempts,
+                max_attempts = %max_attempts,
                 "Max retry attempts reached"
             );
             return RetryDecision::Failed;
@@ -77,19 +159,40 @@ impl RetryPolicy {
 
         // Calculate exponential backoff with jitter (ADR-002)
         // delay = base_delay * (backoff_factor ^ attempt) * (1.0 ± 0.1)
-        let base_delay_ms = self.base_delay_ms as f64 * job.backoff_factor.powi(job.attempts);
+        let backoff_factor = self.backoff_factor.unwrap_or(job.backoff_factor);
+        let base_delay_ms = self.base_delay_ms as f64 * backoff_factor.powi(job.attempts);
 
         // Apply ±10% jitter to prevent "Thundering Herd" problem
         // Use job.id as seed for deterministic jitter per job
         let jitter_seed = job.id.chars().map(|c| c as u32).sum::<u32>();
         let jitter_factor = 0.9 + ((jitter_seed % 21) as f64 / 100.0); // 0.9 to 1.1
 
-        let delay_ms = (base_delay_ms * jitter_factor) as i64;
+        let delay_ms = ((base_delay_ms * jitter_factor) as i64).min(self.max_delay_ms);
+
+        if let Some(retry_deadline_ms) = self.retry_deadline_ms {
+            let retry_at = self.time_provider.now_millis().saturating_add(delay_ms);
+            let deadline = job.created_at.saturating_add(retry_deadline_ms);
+            if retry_at > deadline {
+                warn!(
+                    job_id = %job.id,
+                    attempts = %job.attempts,
+                    delay_ms = %delay_ms,
+                    retry_deadline_ms = %retry_deadline_ms,
+                    "Retry deadline exceeded"
+                );
+                return RetryDecision::RetryDeadlineExceeded(format!(
+                    "retry deadline exceeded: attempt {} would start {} ms after the {} ms retry deadline",
+                    job.attempts + 2,
+                    retry_at - deadline,
+                    retry_deadline_ms
+                ));
+            }
+        }
 
         info!(
             job_id = %job.id,
             attempt = %job.attempts,
-            max_attempts = %job.max_attempts,
+            max_attempts = %max_attempts,
             delay_ms = %delay_ms,
             "Scheduling retry"
         );
@@ -99,27 +202,102 @@ impl RetryPolicy {
 
     /// Prepare a job for retry
     ///
-    /// Updates job state and increments attempt counter
+    /// Updates job state, increments attempt counter and holds the job back
+    /// until the backoff delay has passed (`next_attempt_at`, see pop_next)
     ///
     /// # Arguments
     /// * `job` - Job to prepare for retry
-    pub fn prepare_for_retry(&self, job: &mut Job) {
+    /// * `delay_ms` - Backoff delay from `RetryDecision::Retry`
+    pub fn prepare_for_retry(&self, job: &mut Job, delay_ms: i64) {
         job.attempts += 1;
         job.state = JobState::Queued;
         job.started_at = None;
         job.pid = None;
+        job.next_attempt_at = Some(self.time_provider.now_millis().saturating_add(delay_ms));
 
         info!(
             job_id = %job.id,
             attempt = %job.attempts,
+            next_attempt_at = ?job.next_attempt_at,
             "Job prepared for retry"
         );
     }
 
+    /// Job panicked too often to be retried (call after counting the panic)
+    pub fn is_poisoned(&self, job: &Job) -> bool {
+        job.panic_count >= self.max_panics
+    }
+
+    /// Requeue a job whose execution panicked
+    ///
+    /// The panic was counted in `panic_count`; `attempts` is left alone so
+    /// regular failures keep their full retry budget.
+    pub fn prepare_for_panic_retry(&self, job: &mut Job) {
+        job.state = JobState::Queued;
+        job.next_attempt_at = None;
+        job.started_at = None;
+        job.pid = None;
+
+        warn!(
+            job_id = %job.id,
+            panics = %job.panic_count,
+            max_panics = %self.max_panics,
+            "Job panicked, prepared for retry"
+        );
+    }
+
+    /// Event recording that a retry switches to the next fallback payload
+    ///
+    /// Call after `prepare_for_retry`. None when the attempt runs the same
+    /// payload as the previous one (no fallbacks, or the last one is reused).
+    pub fn fallback_event(&self, job: &Job) -> Option<JobEvent> {
+        let index = job.attempt_payload_index();
+        if index == 0 || job.attempts as usize > job.fallback_payloads.len() {
+            return None;
+        }
+        info!(job_id = %job.id, attempt = %job.attempts, fallback = index, "Retry runs fallback payload");
+        Some(JobEvent::new(
+            job.id.clone(),
+            JobState::Queued,
+            actor::RETRY,
+            Some(format!(
+                "attempt {} runs fallback payload {}",
+                job.attempts + 1,
+                index
+            )),
+            self.time_provider.now_millis(),
+        ))
+    }
+
+    /// Event recording why a failed job was not retried
+    pub fn gave_up_event(&self, job: &Job, reason: &str) -> JobEvent {
+        JobEvent::new(
+            job.id.clone(),
+            JobState::Failed,
+            actor::RETRY,
+            Some(reason.to_string()),
+            self.time_provider.now_millis(),
+        )
+    }
+
+    /// Delay before restarting a service job that exited
+    ///
+    /// `restarts` counts consecutive quick exits: the delay doubles with each
+    /// one, up to SERVICE_RESTART_MAX_DELAY_MS.
+    pub fn service_restart_delay_ms(&self, restarts: i32) -> i64 {
+        let factor = 2_i64.saturating_pow(restarts.clamp(0, 32) as u32);
+        SERVICE_RESTART_BASE_DELAY_MS
+            .saturating_mul(factor)
+            .min(SERVICE_RESTART_MAX_DELAY_MS)
+    }
+
     /// Check if a job has exceeded its deadline
     ///
-    /// Returns true if deadline is set and has passed
+    /// Returns true if deadline is set and has passed (never for services)
     pub fn is_deadline_exceeded(&self, job: &Job) -> bool {
+        if job.is_service() {
+            return false;
+        }
         if let Some(deadline) = job.deadline {
             let now = self.time_provider.now_millis();
             if now > deadline {
@@ -137,11 +315,14 @@ impl RetryPolicy {
 
     /// Check if a job has exceeded its TTL in queue
     ///
-    /// Returns true if ttl_ms is set and job has been queued too long
+    /// Returns true if ttl_ms is set and job has been queued too long (never for services)
     pub fn is_ttl_exceeded(&self, job: &Job) -> bool {
+        if job.is_service() {
+            return false;
+        }
         if let Some(ttl_ms) = job.ttl_ms {
             let now = self.time_provider.now_millis();
-            let age_ms = now - job.created_at;
+            let age_ms = elapsed_ms(now, job.queued_since());
 
             if age_ms > ttl_ms {
                 warn!(
@@ -188,6 +369,22 @@ mod tests {
         job
     }
 
+    #[test]
+    fn test_poisoned_after_max_panics() {
+        let policy =
+            RetryPolicy::new(Arc::new(MockTimeProvider { now_ms: 1000 }), 1000).with_max_panics(2);
+
+        let mut job = create_test_job("job-poison", 0, 3, 2.0);
+        job.panic_count = 1;
+        assert!(!policy.is_poisoned(&job));
+        policy.prepare_for_panic_retry(&mut job);
+        assert_eq!(job.state, JobState::Queued);
+        assert_eq!(job.attempts, 0, "panics do not use up attempts");
+
+        job.panic_count = 2;
+        assert!(policy.is_poisoned(&job));
+    }
+
     #[test]
     fn test_max_attempts_returns_failed() {
         let policy = RetryPolicy::new(Arc::new(MockTimeProvider { now_ms: 1000 }), 1000);
@@ -263,6 +460,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_backoff_is_capped_at_max_delay() {
+        let policy = RetryPolicy::new(Arc::new(MockTimeProvider { now_ms: 1000 }), 1000)
+            .with_max_delay_ms(5000);
+
+        // 1000 * 2^9 would wait over 8 minutes
+        let job = create_test_job("job-cap", 9, 10, 2.0);
+        assert_eq!(policy.should_retry(&job), RetryDecision::Retry(5000));
+
+        // Cap never drops below the base delay
+        let policy = policy.with_max_delay_ms(10);
+        assert_eq!(policy.max_delay_ms(), 1000);
+    }
+
+    #[test]
+    fn test_retry_deadline_fails_job() {
+        // Job created at 1000, now 9000: 8000 ms spent retrying
+        let policy = RetryPolicy::new(Arc::new(MockTimeProvider { now_ms: 9000 }), 1000)
+            .with_retry_deadline_ms(10_000);
+
+        // Next retry (~1000 ms) still starts before 11000
+        let job = create_test_job("job-deadline", 0, 5, 2.0);
+        assert!(matches!(policy.should_retry(&job), RetryDecision::Retry(_)));
+
+        // Next retry (~4000 ms) would start past it
+        let job = create_test_job("job-deadline", 2, 5, 2.0);
+        match policy.should_retry(&job) {
+            RetryDecision::RetryDeadlineExceeded(reason) => {
+                assert!(reason.starts_with("retry deadline exceeded"), "{}", reason);
+            }
+            other => panic!("Expected RetryDeadlineExceeded, got {:?}", other),
+        }
+
+        // Max attempts are still checked first
+        let job = create_test_job("job-deadline", 5, 5, 2.0);
+        assert_eq!(policy.should_retry(&job), RetryDecision::Failed);
+    }
+
     #[test]
     fn test_deadline_exceeded() {
         let policy = RetryPolicy::new(Arc::new(MockTimeProvider { now_ms: 5000 }), 1000);
@@ -298,6 +533,80 @@ mod tests {
         // TTL exceeded
         job.ttl_ms = Some(3000);
         assert!(policy.is_ttl_exceeded(&job));
+
+        // Created "in the future" (clock set backwards): age clamps to 0
+        job.created_at = 9000;
+        job.ttl_ms = Some(1);
+        assert!(!policy.is_ttl_exceeded(&job));
+    }
+
+    #[test]
+    fn test_services_never_expire() {
+        use crate::domain::JobClass;
+
+        let policy = RetryPolicy::new(Arc::new(MockTimeProvider { now_ms: 5000 }), 1000);
+
+        let mut job = create_test_job("job-service", 0, 5, 2.0);
+        job.job_class = JobClass::Service;
+        job.deadline = Some(4000);
+        job.ttl_ms = Some(1);
+
+        assert!(!policy.is_deadline_exceeded(&job));
+        assert!(!policy.is_ttl_exceeded(&job));
+    }
+
+    #[test]
+    fn test_service_restart_delay_is_capped() {
+        let policy = RetryPolicy::new(Arc::new(MockTimeProvider { now_ms: 0 }), 1000);
+
+        assert_eq!(
+            policy.service_restart_delay_ms(0),
+            SERVICE_RESTART_BASE_DELAY_MS
+        );
+        assert_eq!(
+            policy.service_restart_delay_ms(3),
+            SERVICE_RESTART_BASE_DELAY_MS * 8
+        );
+        assert_eq!(
+            policy.service_restart_delay_ms(1_000),
+            SERVICE_RESTART_MAX_DELAY_MS
+        );
+    }
+
+    #[test]
+    fn test_retries_walk_through_fallback_payloads() {
+        let policy = RetryPolicy::new(Arc::new(MockTimeProvider { now_ms: 1000 }), 1000);
+        let mut job = create_test_job("job-fallback", 0, 5, 2.0);
+        job.payload = JobPayload::new(serde_json::json!({"mode": "incremental"}));
+        job.fallback_payloads = vec![
+            JobPayload::new(serde_json::json!({"mode": "full"})),
+            JobPayload::new(serde_json::json!({"mode": "clean"})),
+        ];
+
+        // Attempt 1 runs the primary payload
+        assert_eq!(job.attempt_payload_index(), 0);
+        assert!(job.clone().use_attempt_payload().is_none());
+
+        let mut modes = Vec::new();
+        let mut events = Vec::new();
+        for _ in 0..3 {
+            policy.prepare_for_retry(&mut job, 0);
+            events.push(policy.fallback_event(&job).and_then(|e| e.reason));
+            let mut attempt = job.clone();
+            let primary = attempt.use_attempt_payload().expect("fallback swapped in");
+            assert_eq!(primary.as_value()["mode"], "incremental");
+            modes.push(attempt.payload.as_value()["mode"].clone());
+        }
+
+        assert_eq!(modes, vec!["full", "clean", "clean"]);
+        assert_eq!(
+            events,
+            vec![
+                Some("attempt 2 runs fallback payload 1".to_string()),
+                Some("attempt 3 runs fallback payload 2".to_string()),
+                None,
+            ]
+        );
     }
 
     #[test]
@@ -309,10 +618,11 @@ mod tests {
         job.started_at = Some(500);
         job.pid = Some(12345);
 
-        policy.prepare_for_retry(&mut job);
+        policy.prepare_for_retry(&mut job, 4000);
 
         assert_eq!(job.attempts, 2);
         assert_eq!(job.state, JobState::Queued);
+        assert_eq!(job.next_attempt_at, Some(5000), "now + delay");
         assert!(job.started_at.is_none());
         assert!(job.pid.is_none());
     }
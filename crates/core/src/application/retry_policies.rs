@@ -0,0 +1,246 @@
+// Retry Policies - Retry rules per job type
+//
+// The Worker and the LeaseService look up the policy of a failed job's type
+// here instead of applying one global RetryPolicy: a flaky network fetch can
+// retry 10 times while a compile job fails on the first error. Rules come
+// from SEMANTICA_RETRY_POLICIES at startup and admin.retry_policy.set.v1 /
+// reset.v1 at runtime. Like RuntimeConfig they are not persisted.
+
+use crate::application::dev_task::enqueue::{MAX_ATTEMPTS, MAX_BACKOFF_FACTOR, MIN_BACKOFF_FACTOR};
+use crate::application::retry::RetryPolicy;
+use crate::error::{AppError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+/// Longest base delay a rule may set (1 hour)
+pub const MAX_RETRY_BASE_DELAY_MS: i64 = 60 * 60 * 1000;
+
+/// Overrides of one job type (None = the job's own value / the default policy)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RetryRule {
+    /// Total attempts including the first, replacing the job's max_attempts
+    #[serde(default)]
+    pub max_attempts: Option<i32>,
+    /// Delay before the first retry (ms)
+    #[serde(default)]
+    pub base_delay_ms: Option<i64>,
+    /// Multiplier of the delay per attempt, replacing the job's backoff_factor
+    #[serde(default)]
+    pub backoff_factor: Option<f64>,
+}
+
+impl RetryRule {
+    fn validate(&self) -> Result<()> {
+        if self.max_attempts.is_none()
+            && self.base_delay_ms.is_none()
+            && self.backoff_factor.is_none()
+        {
+            return Err(AppError::Validation(
+                "Retry rule must set max_attempts, base_delay_ms or backoff_factor".to_string(),
+            ));
+        }
+        if let Some(max_attempts) = self.max_attempts {
+            if !(1..=MAX_ATTEMPTS).contains(&max_attempts) {
+                return Err(AppError::Validation(format!(
+                    "max_attempts must be between 1 and {}, got {}",
+                    MAX_ATTEMPTS, max_attempts
+                )));
+            }
+        }
+        if let Some(delay) = self.base_delay_ms {
+            if !(0..=MAX_RETRY_BASE_DELAY_MS).contains(&delay) {
+                return Err(AppError::Validation(format!(
+                    "base_delay_ms must be between 0 and {}, got {}",
+                    MAX_RETRY_BASE_DELAY_MS, delay
+                )));
+            }
+        }
+        if let Some(factor) = self.backoff_factor {
+            if !(MIN_BACKOFF_FACTOR..=MAX_BACKOFF_FACTOR).contains(&factor) {
+                return Err(AppError::Validation(format!(
+                    "backoff_factor must be between {} and {}, got {}",
+                    MIN_BACKOFF_FACTOR, MAX_BACKOFF_FACTOR, factor
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Default retry policy plus the rules of individual job types
+pub struct RetryPolicies {
+    default: Arc<RetryPolicy>,
+    rules: RwLock<BTreeMap<String, (RetryRule, Arc<RetryPolicy>)>>,
+}
+
+impl RetryPolicies {
+    /// Every job type uses `default`
+    pub fn new(default: Arc<RetryPolicy>) -> Self {
+        Self {
+            default,
+            rules: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    /// Parse comma-separated `job_type:max_attempts[/base_delay_ms[/backoff_factor]]` entries
+    ///
+    /// # Example
+    /// `FETCH:10/500,BUILD:1` - FETCH: 10 attempts, first retry after 500ms; BUILD: no retry
+    pub fn from_spec(default: Arc<RetryPolicy>, spec: &str) -> std::result::Result<Self, String> {
+        let policies = Self::new(default);
+
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let invalid = || {
+                format!(
+                    "Invalid retry policy '{}' (expected job_type:max_attempts[/base_delay_ms[/backoff_factor]])",
+                    entry
+                )
+            };
+            let (job_type, values) = entry.split_once(':').ok_or_else(invalid)?;
+            let mut values = values.split('/').map(str::trim);
+            let rule = RetryRule {
+                max_attempts: Some(
+                    values
+                        .next()
+                        .and_then(|v| v.parse().ok())
+                        .ok_or_else(invalid)?,
+                ),
+                base_delay_ms: values
+                    .next()
+                    .map(|v| v.parse().map_err(|_| invalid()))
+                    .transpose()?,
+                backoff_factor: values
+                    .next()
+                    .map(|v| v.parse().map_err(|_| invalid()))
+                    .transpose()?,
+            };
+            if values.next().is_some() {
+                return Err(invalid());
+            }
+            policies
+                .set(job_type.trim(), rule)
+                .map_err(|e| format!("{}: {}", invalid(), e))?;
+        }
+
+        Ok(policies)
+    }
+
+    /// Policy of job types without a rule
+    pub fn default_policy(&self) -> Arc<RetryPolicy> {
+        self.default.clone()
+    }
+
+    /// Policy applied to failed jobs of `job_type`
+    pub fn for_job_type(&self, job_type: &str) -> Arc<RetryPolicy> {
+        self.rules
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(job_type)
+            .map_or_else(|| self.default.clone(), |(_, policy)| policy.clone())
+    }
+
+    /// Set (or replace) the rule of `job_type`; invalid rules are rejected
+    pub fn set(&self, job_type: &str, rule: RetryRule) -> Result<()> {
+        if job_type.is_empty() {
+            return Err(AppError::Validation("Job type cannot be empty".to_string()));
+        }
+        rule.validate()?;
+
+        let policy = Arc::new(self.default.with_rule(&rule));
+        self.rules
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(job_type.to_string(), (rule, policy));
+        Ok(())
+    }
+
+    /// Back to the default policy (false if `job_type` had no rule)
+    pub fn reset(&self, job_type: &str) -> bool {
+        self.rules
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(job_type)
+            .is_some()
+    }
+
+    /// Rules by job type, sorted by name
+    pub fn rules(&self) -> BTreeMap<String, RetryRule> {
+        self.rules
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|(job_type, (rule, _))| (job_type.clone(), rule.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::retry::RetryDecision;
+    use crate::domain::{Job, JobPayload, JobType};
+    use crate::port::time_provider::SystemTimeProvider;
+
+    fn policies(spec: &str) -> std::result::Result<RetryPolicies, String> {
+        RetryPolicies::from_spec(
+            Arc::new(RetryPolicy::new(Arc::new(SystemTimeProvider), 1000)),
+            spec,
+        )
+    }
+
+    fn failed_job(job_type: &str, attempts: i32) -> Job {
+        let mut job = Job::new_test(
+            "default",
+            JobType::new(job_type),
+            "src/lib.rs",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job.attempts = attempts;
+        job
+    }
+
+    #[test]
+    fn test_rule_overrides_job_attempts() {
+        let policies = policies("FETCH:10/500, BUILD:1").unwrap();
+
+        // Job default is 3 attempts
+        let fetch = failed_job("FETCH", 5);
+        assert!(matches!(
+            policies.for_job_type("FETCH").should_retry(&fetch),
+            RetryDecision::Retry(_)
+        ));
+        assert_eq!(policies.for_job_type("FETCH").base_delay_ms(), 500);
+
+        let build = failed_job("BUILD", 1);
+        assert_eq!(
+            policies.for_job_type("BUILD").should_retry(&build),
+            RetryDecision::Failed
+        );
+
+        let test = failed_job("TEST", 1);
+        assert!(matches!(
+            policies.for_job_type("TEST").should_retry(&test),
+            RetryDecision::Retry(_)
+        ));
+        assert_eq!(policies.for_job_type("TEST").base_delay_ms(), 1000);
+
+        assert!(policies.reset("FETCH"));
+        assert!(!policies.reset("FETCH"));
+        assert_eq!(policies.rules().keys().collect::<Vec<_>>(), vec!["BUILD"]);
+    }
+
+    #[test]
+    fn test_invalid_rules_rejected() {
+        assert!(policies("FETCH").is_err());
+        assert!(policies("FETCH:0").is_err(), "at least one attempt");
+        assert!(policies("FETCH:3/100/0.5").is_err(), "backoff below 1.0");
+        assert!(policies("FETCH:3/100/2/9").is_err());
+
+        let policies = policies("").unwrap();
+        assert!(policies.set("FETCH", RetryRule::default()).is_err());
+        assert!(policies.rules().is_empty());
+    }
+}
@@ -0,0 +1,228 @@
+// Runtime Config - Settings tunable without a daemon restart
+//
+// Initialized from env vars at startup, then read on every use by the Worker
+// (CPU throttle), the MaintenanceScheduler (retention, log sampling) and the
+// RPC handler (rate limits). admin.config.set.v1 updates it in place; changes
+// are not persisted, so a restart goes back to the env/default values.
+// Consumers holding derived state (the RPC rate limiter) subscribe to changes,
+// so a wholesale reload (SIGHUP) reaches them without a restart.
+
+use crate::application::worker::constants::CPU_THROTTLE_THRESHOLD;
+use crate::error::{AppError, Result};
+use crate::port::MaintenanceConfig;
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+
+/// Current values of every runtime-tunable setting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuntimeSettings {
+    /// Worker stops popping above this CPU usage (percent)
+    pub cpu_throttle_percent: f32,
+    /// Scheduled maintenance deletes finished jobs older than this
+    pub finished_job_retention_days: i64,
+    /// Scheduled maintenance deletes artifacts older than this
+    pub artifact_retention_days: i64,
+    /// Share of successful runs whose logs are kept past the full-log window
+    pub success_log_sample_rate: f64,
+    /// Global RPC token bucket size
+    pub rate_limit_burst: u32,
+    /// Global RPC token refill (requests/sec)
+    pub rate_limit_per_sec: u32,
+}
+
+impl Default for RuntimeSettings {
+    fn default() -> Self {
+        let maintenance = MaintenanceConfig::default();
+        Self {
+            cpu_throttle_percent: CPU_THROTTLE_THRESHOLD,
+            finished_job_retention_days: maintenance.finished_job_retention_days,
+            artifact_retention_days: maintenance.artifact_retention_days,
+            success_log_sample_rate: maintenance.success_log_sample_rate,
+            rate_limit_burst: 200,
+            rate_limit_per_sec: 100,
+        }
+    }
+}
+
+impl RuntimeSettings {
+    fn validate(&self) -> Result<()> {
+        if !(self.cpu_throttle_percent > 0.0 && self.cpu_throttle_percent <= 100.0) {
+            return Err(AppError::Validation(
+                "cpu_throttle_percent must be in (0, 100]".to_string(),
+            ));
+        }
+        if self.finished_job_retention_days < 1 || self.artifact_retention_days < 1 {
+            return Err(AppError::Validation(
+                "retention days must be at least 1".to_string(),
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.success_log_sample_rate) {
+            return Err(AppError::Validation(
+                "success_log_sample_rate must be between 0.0 and 1.0".to_string(),
+            ));
+        }
+        if self.rate_limit_burst == 0 || self.rate_limit_per_sec == 0 {
+            return Err(AppError::Validation(
+                "rate limits must be at least 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Shared, mutable runtime settings
+#[derive(Debug)]
+pub struct RuntimeConfig {
+    settings: watch::Sender<RuntimeSettings>,
+}
+
+impl Default for RuntimeConfig {
+    fn default() -> Self {
+        Self::new(RuntimeSettings::default())
+    }
+}
+
+impl RuntimeConfig {
+    pub fn new(settings: RuntimeSettings) -> Self {
+        Self {
+            settings: watch::Sender::new(settings),
+        }
+    }
+
+    /// Snapshot of the current settings
+    pub fn get(&self) -> RuntimeSettings {
+        self.settings.borrow().clone()
+    }
+
+    /// Receiver notified whenever a setting changes
+    pub fn subscribe(&self) -> watch::Receiver<RuntimeSettings> {
+        self.settings.subscribe()
+    }
+
+    /// Replace every setting at once (e.g. a config reload)
+    ///
+    /// Invalid settings are rejected and leave the current ones unchanged.
+    /// Returns true if anything changed.
+    pub fn replace(&self, settings: RuntimeSettings) -> Result<bool> {
+        settings.validate()?;
+        Ok(self.settings.send_if_modified(|current| {
+            if *current == settings {
+                return false;
+            }
+            *current = settings;
+            true
+        }))
+    }
+
+    /// Set one setting by name (JSON value of the field's type)
+    ///
+    /// Unknown keys, wrong types and out-of-range values are rejected and
+    /// leave the settings unchanged. Returns the updated settings.
+    pub fn set(&self, key: &str, value: serde_json::Value) -> Result<RuntimeSettings> {
+        let mut outcome = Ok(());
+        self.settings.send_if_modified(|settings| {
+            outcome = Self::with_field(settings, key, value).map(|updated| *settings = updated);
+            outcome.is_ok()
+        });
+        outcome.map(|()| self.get())
+    }
+
+    /// `settings` with one field replaced, validated
+    fn with_field(
+        settings: &RuntimeSettings,
+        key: &str,
+        value: serde_json::Value,
+    ) -> Result<RuntimeSettings> {
+        let mut fields = serde_json::to_value(settings)?;
+        match fields.get_mut(key) {
+            Some(field) => *field = value,
+            None => {
+                return Err(AppError::Validation(format!(
+                    "Unknown runtime setting '{}'",
+                    key
+                )))
+            }
+        }
+        let updated: RuntimeSettings = serde_json::from_value(fields)
+            .map_err(|e| AppError::Validation(format!("Invalid value for '{}': {}", key, e)))?;
+        updated.validate()?;
+        Ok(updated)
+    }
+
+    pub fn cpu_throttle_percent(&self) -> f32 {
+        self.get().cpu_throttle_percent
+    }
+
+    /// `base` with the runtime-tunable retention and sampling values applied
+    pub fn maintenance_config(&self, base: &MaintenanceConfig) -> MaintenanceConfig {
+        let settings = self.get();
+        MaintenanceConfig {
+            finished_job_retention_days: settings.finished_job_retention_days,
+            artifact_retention_days: settings.artifact_retention_days,
+            success_log_sample_rate: settings.success_log_sample_rate,
+            ..base.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_updates_one_setting() {
+        let config = RuntimeConfig::default();
+
+        let settings = config
+            .set("cpu_throttle_percent", serde_json::json!(75.0))
+            .unwrap();
+
+        assert_eq!(settings.cpu_throttle_percent, 75.0);
+        assert_eq!(config.cpu_throttle_percent(), 75.0);
+        assert_eq!(
+            settings.finished_job_retention_days,
+            RuntimeSettings::default().finished_job_retention_days
+        );
+    }
+
+    #[test]
+    fn test_set_rejects_invalid_changes() {
+        let config = RuntimeConfig::default();
+
+        assert!(config.set("no_such_key", serde_json::json!(1)).is_err());
+        assert!(config
+            .set("finished_job_retention_days", serde_json::json!("7"))
+            .is_err());
+        assert!(config
+            .set("success_log_sample_rate", serde_json::json!(1.5))
+            .is_err());
+        assert!(config
+            .set("rate_limit_burst", serde_json::json!(0))
+            .is_err());
+        assert_eq!(config.get(), RuntimeSettings::default());
+    }
+
+    #[test]
+    fn test_replace_notifies_subscribers() {
+        let config = RuntimeConfig::default();
+        let mut changes = config.subscribe();
+
+        let reloaded = RuntimeSettings {
+            rate_limit_burst: 10,
+            ..Default::default()
+        };
+        assert!(config.replace(reloaded.clone()).unwrap());
+        assert!(changes.has_changed().unwrap());
+        assert_eq!(*changes.borrow_and_update(), reloaded);
+
+        assert!(!config.replace(reloaded).unwrap(), "nothing changed");
+        let invalid = RuntimeSettings {
+            cpu_throttle_percent: 0.0,
+            ..Default::default()
+        };
+        assert!(config.replace(invalid).is_err());
+        assert!(!changes.has_changed().unwrap());
+        assert_eq!(config.get().rate_limit_burst, 10);
+    }
+}
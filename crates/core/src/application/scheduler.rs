@@ -6,8 +6,10 @@
 //! - wait_for_event: Execute when specific event occurs
 //! - schedule_at: Execute at specific time
 
+use crate::application::scheduling_policy::SchedulingPolicy;
 use crate::domain::Job;
 use crate::port::SystemProbe;
+use async_trait::async_trait;
 use std::sync::Arc;
 use tracing::{debug, info};
 
@@ -81,6 +83,14 @@ impl Scheduler {
         true
     }
 
+    /// Take the first system sample ahead of time (cold start)
+    ///
+    /// CPU usage needs a previous sample, so the first readiness check
+    /// would otherwise pay for two.
+    pub async fn warm_up(&self) {
+        let _ = self.system_probe.get_metrics().await;
+    }
+
     /// Check if system is idle (low CPU usage)
     async fn is_system_idle(&self) -> bool {
         use crate::application::worker::constants::IDLE_CPU_THRESHOLD;
@@ -99,7 +109,7 @@ impl Scheduler {
     /// Uses spawn_blocking to avoid blocking the async executor
     async fn is_charging(&self) -> bool {
         // Use spawn_blocking to prevent blocking the tokio runtime
-        tokio::task::spawn_blocking(|| Self::is_charging_blocking())
+        tokio::task::spawn_blocking(Self::is_charging_blocking)
             .await
             .unwrap_or(false) // If spawn fails, assume not charging
     }
@@ -175,6 +185,18 @@ impl Scheduler {
     }
 }
 
+/// Default policy: the job's own scheduling conditions
+#[async_trait]
+impl SchedulingPolicy for Scheduler {
+    fn name(&self) -> &str {
+        "conditions"
+    }
+
+    async fn is_ready(&self, job: &Job) -> bool {
+        Scheduler::is_ready(self, job).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,147 @@
+// Scheduling Policies - Pluggable ready-checks for the Worker
+//
+// The Worker asks its policy whether a popped job may run now. The default is
+// the conditions-based Scheduler (schedule_at, wait_for_idle, require_charging);
+// embedders implement SchedulingPolicy to experiment with smarter scheduling
+// without patching core.
+
+use crate::domain::Job;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::debug;
+
+/// Decides when a job may run
+#[async_trait]
+pub trait SchedulingPolicy: Send + Sync {
+    /// Policy name (for logs)
+    fn name(&self) -> &str;
+
+    /// Whether `job` may run now (false = the job goes back to QUEUED)
+    async fn is_ready(&self, job: &Job) -> bool;
+
+    /// Ordering hint for a job that is not ready, applied before it is re-queued
+    ///
+    /// May lower the priority or push schedule_at so other jobs are picked
+    /// first. Changes are persisted. Default: leave the job unchanged.
+    fn on_deferred(&self, _job: &mut Job) {}
+
+    /// Called when a ready job starts executing
+    fn on_started(&self, _job: &Job) {}
+
+    /// Called when the execution of a started job ended (any outcome)
+    fn on_finished(&self, _job: &Job) {}
+}
+
+/// Policies combined: a job is ready only if every policy agrees
+#[derive(Clone, Default)]
+pub struct PolicyChain {
+    policies: Vec<Arc<dyn SchedulingPolicy>>,
+}
+
+impl PolicyChain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a policy at the end of the chain
+    pub fn register(&mut self, policy: Arc<dyn SchedulingPolicy>) {
+        self.policies.push(policy);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.policies.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.policies.len()
+    }
+}
+
+#[async_trait]
+impl SchedulingPolicy for PolicyChain {
+    fn name(&self) -> &str {
+        "chain"
+    }
+
+    async fn is_ready(&self, job: &Job) -> bool {
+        for policy in &self.policies {
+            if !policy.is_ready(job).await {
+                debug!(job_id = %job.id, policy = policy.name(), "Job deferred by policy");
+                return false;
+            }
+        }
+        true
+    }
+
+    fn on_deferred(&self, job: &mut Job) {
+        for policy in &self.policies {
+            policy.on_deferred(job);
+        }
+    }
+
+    fn on_started(&self, job: &Job) {
+        for policy in &self.policies {
+            policy.on_started(job);
+        }
+    }
+
+    fn on_finished(&self, job: &Job) {
+        for policy in &self.policies {
+            policy.on_finished(job);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{JobPayload, JobType};
+
+    struct Fixed {
+        ready: bool,
+    }
+
+    #[async_trait]
+    impl SchedulingPolicy for Fixed {
+        fn name(&self) -> &str {
+            "fixed"
+        }
+
+        async fn is_ready(&self, _job: &Job) -> bool {
+            self.ready
+        }
+
+        fn on_deferred(&self, job: &mut Job) {
+            job.priority -= 1;
+        }
+    }
+
+    fn job() -> Job {
+        Job::new_test(
+            "test_queue",
+            JobType::new("test"),
+            "test.rs",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_chain_requires_every_policy() {
+        let mut chain = PolicyChain::new();
+        assert!(
+            chain.is_ready(&job()).await,
+            "Empty chain allows everything"
+        );
+
+        chain.register(Arc::new(Fixed { ready: true }));
+        assert!(chain.is_ready(&job()).await);
+
+        chain.register(Arc::new(Fixed { ready: false }));
+        assert!(!chain.is_ready(&job()).await);
+
+        let mut deferred = job();
+        chain.on_deferred(&mut deferred);
+        assert_eq!(deferred.priority, -2, "Every policy sees the deferred job");
+    }
+}
@@ -0,0 +1,379 @@
+// Stalled Job Detector - Watchdog for hung executions
+//
+// A subprocess that hangs (deadlock, prompt on stdin, network call without
+// timeout) keeps its job RUNNING and its slot busy forever. The detector
+// compares the run time of every RUNNING job with what its job type is
+// expected to take, configured per type or derived from the p95 of recent
+// successful runs, and flags jobs far past it: warning log, job event,
+// notification and metric. Optionally it kills the job's process, so the job
+// fails and goes through the normal retry policy.
+
+use crate::domain::job_event::actor;
+use crate::domain::{Job, JobEvent, JobId, JobState};
+use crate::error::{AppError, Result};
+use crate::port::{
+    elapsed_ms, JobRepository, LogNotifier, NoopWorkerMetrics, Notification, Notifier,
+    TaskExecutor, TimeProvider, WorkerMetrics,
+};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::interval;
+use tracing::{error, info, warn};
+
+/// Notification topic for stalled jobs
+pub const STALLED_TOPIC: &str = "job.stalled";
+
+/// How often the detector checks (1min)
+pub const DEFAULT_STALL_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A job type without a configured expectation may run this many times its
+/// p95 before it counts as stalled
+pub const STALL_P95_FACTOR: i64 = 3;
+
+/// Lower bound of a p95-derived expectation (1min): quick job types jitter
+pub const MIN_DERIVED_EXPECTATION_MS: i64 = 60_000;
+
+/// Successful runs needed before a job type's p95 is trusted
+pub const MIN_DURATION_SAMPLES: usize = 20;
+
+/// Recent successful runs the p95 is taken over
+pub const DURATION_SAMPLE_SIZE: usize = 200;
+
+/// Configured maximum run time per job type
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExpectedDurations {
+    /// job type -> expected maximum run time (ms)
+    entries: HashMap<String, i64>,
+}
+
+impl ExpectedDurations {
+    /// Parse `"BUILD:1800,INDEX_FILE:60"` (seconds per job type)
+    pub fn from_spec(spec: &str) -> Result<Self> {
+        let mut entries = HashMap::new();
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (job_type, secs) = entry.split_once(':').ok_or_else(|| {
+                AppError::Validation(format!(
+                    "Invalid expected duration '{}' (want JOB_TYPE:SECONDS)",
+                    entry
+                ))
+            })?;
+            let secs = secs
+                .trim()
+                .parse::<i64>()
+                .ok()
+                .filter(|&secs| secs > 0)
+                .ok_or_else(|| {
+                    AppError::Validation(format!(
+                        "Invalid expected duration '{}' (want JOB_TYPE:SECONDS)",
+                        entry
+                    ))
+                })?;
+            entries.insert(job_type.trim().to_string(), secs * 1000);
+        }
+        Ok(Self { entries })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Expected maximum run time of a job type (ms), if configured
+    pub fn get(&self, job_type: &str) -> Option<i64> {
+        self.entries.get(job_type).copied()
+    }
+}
+
+/// Where a job type's expectation came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpectationBasis {
+    Configured,
+    /// STALL_P95_FACTOR x p95 of recent successful runs
+    P95,
+}
+
+impl ExpectationBasis {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Configured => "configured",
+            Self::P95 => "p95",
+        }
+    }
+}
+
+/// A RUNNING job flagged by the detector
+#[derive(Debug, Clone, PartialEq)]
+pub struct StalledJob {
+    pub job_id: JobId,
+    pub queue: String,
+    pub job_type: String,
+    pub running_ms: i64,
+    pub expected_ms: i64,
+    pub basis: ExpectationBasis,
+    /// The detector killed the job's process
+    pub killed: bool,
+}
+
+/// Flags RUNNING jobs that run far longer than their job type is expected to
+pub struct StalledJobDetector {
+    job_repo: Arc<dyn JobRepository>,
+    time_provider: Arc<dyn TimeProvider>,
+    notifier: Arc<dyn Notifier>,
+    metrics: Arc<dyn WorkerMetrics>,
+    expected: ExpectedDurations,
+    /// Set = kill stalled jobs through this executor
+    killer: Option<Arc<dyn TaskExecutor>>,
+    /// Jobs already flagged (flag once per job)
+    flagged: Mutex<HashSet<JobId>>,
+    /// Jobs flagged since start
+    stalled_total: AtomicU64,
+}
+
+impl StalledJobDetector {
+    pub fn new(job_repo: Arc<dyn JobRepository>, time_provider: Arc<dyn TimeProvider>) -> Self {
+        Self {
+            job_repo,
+            time_provider,
+            notifier: Arc::new(LogNotifier),
+            metrics: Arc::new(NoopWorkerMetrics),
+            expected: ExpectedDurations::default(),
+            killer: None,
+            flagged: Mutex::new(HashSet::new()),
+            stalled_total: AtomicU64::new(0),
+        }
+    }
+
+    /// Use these expectations instead of the p95 for the listed job types
+    pub fn with_expected_durations(mut self, expected: ExpectedDurations) -> Self {
+        self.expected = expected;
+        self
+    }
+
+    /// Publish alerts through the given notification channel (default: log)
+    pub fn with_notifier(mut self, notifier: Arc<dyn Notifier>) -> Self {
+        self.notifier = notifier;
+        self
+    }
+
+    /// Count stalled jobs in a metrics backend (default: discarded)
+    pub fn with_metrics(mut self, metrics: Arc<dyn WorkerMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Also kill the process of a stalled job (found in `running()`)
+    pub fn with_auto_kill(mut self, task_executor: Arc<dyn TaskExecutor>) -> Self {
+        self.killer = Some(task_executor);
+        self
+    }
+
+    /// Jobs flagged by this detector since start
+    pub fn stalled_total(&self) -> u64 {
+        self.stalled_total.load(Ordering::Relaxed)
+    }
+
+    /// Run one check, returns the jobs it newly flagged
+    pub async fn check_once(&self) -> Result<Vec<StalledJob>> {
+        let now = self.time_provider.now_millis();
+        let running = self.job_repo.find_by_state(JobState::Running).await?;
+
+        // Jobs that finished meanwhile are forgotten
+        let mut flagged: HashSet<JobId> = {
+            let flagged = self.flagged.lock().unwrap_or_else(|e| e.into_inner());
+            running
+                .iter()
+                .filter(|job| flagged.contains(&job.id))
+                .map(|job| job.id.clone())
+                .collect()
+        };
+
+        let mut expectations = HashMap::new();
+        let mut stalled = Vec::new();
+        for job in &running {
+            // Services run until stopped
+            if job.is_service() || flagged.contains(&job.id) {
+                continue;
+            }
+            let Some(started_at) = job.started_at else {
+                continue;
+            };
+            let job_type = job.job_type.as_str();
+            if !expectations.contains_key(job_type) {
+                let expectation = self.expectation(job_type).await?;
+                expectations.insert(job_type.to_string(), expectation);
+            }
+            let Some((expected_ms, basis)) = expectations[job_type] else {
+                continue;
+            };
+
+            let running_ms = elapsed_ms(now, started_at);
+            if running_ms <= expected_ms {
+                continue;
+            }
+
+            let killed = self.kill(job).await;
+            let found = StalledJob {
+                job_id: job.id.clone(),
+                queue: job.queue.clone(),
+                job_type: job_type.to_string(),
+                running_ms,
+                expected_ms,
+                basis,
+                killed,
+            };
+            self.report(&found, now).await?;
+            flagged.insert(job.id.clone());
+            stalled.push(found);
+        }
+
+        *self.flagged.lock().unwrap_or_else(|e| e.into_inner()) = flagged;
+        self.stalled_total
+            .fetch_add(stalled.len() as u64, Ordering::Relaxed);
+        Ok(stalled)
+    }
+
+    /// Expected maximum run time of a job type: configured, else derived from
+    /// its p95 (None while there are too few successful runs to tell)
+    async fn expectation(&self, job_type: &str) -> Result<Option<(i64, ExpectationBasis)>> {
+        if let Some(expected_ms) = self.expected.get(job_type) {
+            return Ok(Some((expected_ms, ExpectationBasis::Configured)));
+        }
+
+        let durations = self
+            .job_repo
+            .recent_durations(job_type, DURATION_SAMPLE_SIZE)
+            .await?;
+        if durations.len() < MIN_DURATION_SAMPLES {
+            return Ok(None);
+        }
+        let expected_ms = (p95(durations) * STALL_P95_FACTOR).max(MIN_DERIVED_EXPECTATION_MS);
+        Ok(Some((expected_ms, ExpectationBasis::P95)))
+    }
+
+    /// Kill a stalled job's process if configured (true = killed)
+    async fn kill(&self, job: &Job) -> bool {
+        let Some(killer) = &self.killer else {
+            return false;
+        };
+        let Some(process) = killer
+            .running()
+            .into_iter()
+            .find(|process| process.job_id == job.id)
+        else {
+            warn!(job_id = %job.id, "Stalled job has no known process to kill");
+            return false;
+        };
+
+        match killer.kill(process.pid as i32).await {
+            Ok(()) => true,
+            Err(e) => {
+                error!(job_id = %job.id, pid = process.pid, error = %e, "Failed to kill stalled job");
+                false
+            }
+        }
+    }
+
+    /// Log, record and publish a flagged job
+    async fn report(&self, stalled: &StalledJob, now: i64) -> Result<()> {
+        warn!(
+            job_id = %stalled.job_id,
+            queue = %stalled.queue,
+            job_type = %stalled.job_type,
+            running_ms = stalled.running_ms,
+            expected_ms = stalled.expected_ms,
+            basis = stalled.basis.as_str(),
+            killed = stalled.killed,
+            "Job stalled: running far longer than expected"
+        );
+
+        let reason = format!(
+            "running {}ms, expected at most {}ms ({}){}",
+            stalled.running_ms,
+            stalled.expected_ms,
+            stalled.basis.as_str(),
+            if stalled.killed { ", killed" } else { "" }
+        );
+        self.job_repo
+            .record_event(&JobEvent::new(
+                stalled.job_id.clone(),
+                JobState::Running,
+                actor::STALL_DETECTOR,
+                Some(reason),
+                now,
+            ))
+            .await?;
+
+        self.metrics
+            .record_stalled(&stalled.queue, &stalled.job_type, stalled.killed);
+
+        let notification = Notification {
+            topic: STALLED_TOPIC.to_string(),
+            timestamp: now,
+            payload: serde_json::json!({
+                "job_id": stalled.job_id,
+                "queue": stalled.queue,
+                "job_type": stalled.job_type,
+                "running_ms": stalled.running_ms,
+                "expected_ms": stalled.expected_ms,
+                "basis": stalled.basis.as_str(),
+                "killed": stalled.killed,
+            }),
+        };
+        if let Err(e) = self.notifier.notify(&notification).await {
+            warn!(error = %e, "Failed to publish stalled job alert");
+        }
+        Ok(())
+    }
+
+    /// Run the watchdog loop (background task)
+    ///
+    /// Should be spawned in tokio::spawn
+    pub async fn run(self: Arc<Self>, every: Duration) {
+        info!(
+            configured = self.expected.entries.len(),
+            auto_kill = self.killer.is_some(),
+            "Stalled job detector started"
+        );
+
+        let mut tick = interval(every);
+        loop {
+            tick.tick().await;
+            if let Err(e) = self.check_once().await {
+                error!(error = %e, "Stalled job check failed");
+            }
+        }
+    }
+}
+
+/// Nearest-rank 95th percentile (durations must not be empty)
+fn p95(mut durations: Vec<i64>) -> i64 {
+    durations.sort_unstable();
+    let rank = (durations.len() * 95).div_ceil(100);
+    durations[rank.saturating_sub(1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected_durations_from_spec() {
+        let expected = ExpectedDurations::from_spec("BUILD:1800, INDEX_FILE:60,").unwrap();
+        assert_eq!(expected.get("BUILD"), Some(1_800_000));
+        assert_eq!(expected.get("INDEX_FILE"), Some(60_000));
+        assert_eq!(expected.get("LINT"), None);
+        assert!(ExpectedDurations::from_spec("").unwrap().is_empty());
+
+        assert!(ExpectedDurations::from_spec("BUILD").is_err());
+        assert!(ExpectedDurations::from_spec("BUILD:0").is_err());
+        assert!(ExpectedDurations::from_spec("BUILD:soon").is_err());
+    }
+
+    #[test]
+    fn test_p95_nearest_rank() {
+        assert_eq!(p95((1..=100).collect()), 95);
+        assert_eq!(p95((1..=20).rev().collect()), 19);
+        assert_eq!(p95(vec![7]), 7);
+    }
+}
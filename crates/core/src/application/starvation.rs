@@ -0,0 +1,140 @@
+// Starvation Detector - Watchdog for stalled queues
+//
+// Alerts (error log + notification) when due QUEUED jobs have waited longer
+// than a threshold while the worker is neither busy, throttled nor paused by
+// an operator: nothing legitimate explains the wait, so something (e.g. a
+// scheduler requeue loop) is silently stalling processing.
+
+use crate::application::queue_pause::QueuePauseRegistry;
+use crate::application::worker::WorkerActivity;
+use crate::error::Result;
+use crate::port::{elapsed_ms, JobRepository, LogNotifier, Notification, Notifier, TimeProvider};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::interval;
+use tracing::{error, info, warn};
+
+/// Notification topic for starvation alerts
+pub const STARVATION_TOPIC: &str = "worker.starvation";
+
+/// How long a due job may wait on an idle worker before alerting (10min)
+pub const DEFAULT_MAX_QUEUE_WAIT: Duration = Duration::from_secs(10 * 60);
+
+/// How often the detector checks (1min)
+pub const DEFAULT_STARVATION_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Watches one queue served by one worker
+pub struct StarvationDetector {
+    job_repo: Arc<dyn JobRepository>,
+    activity: Arc<WorkerActivity>,
+    queue: String,
+    max_wait_ms: i64,
+    time_provider: Arc<dyn TimeProvider>,
+    notifier: Arc<dyn Notifier>,
+    pauses: Arc<QueuePauseRegistry>,
+    /// Alert already raised for the current episode (alert once, log recovery)
+    starving: AtomicBool,
+}
+
+impl StarvationDetector {
+    pub fn new(
+        job_repo: Arc<dyn JobRepository>,
+        activity: Arc<WorkerActivity>,
+        queue: impl Into<String>,
+        max_wait: Duration,
+        time_provider: Arc<dyn TimeProvider>,
+    ) -> Self {
+        Self {
+            job_repo,
+            activity,
+            queue: queue.into(),
+            max_wait_ms: max_wait.as_millis() as i64,
+            time_provider,
+            notifier: Arc::new(LogNotifier),
+            pauses: Arc::new(QueuePauseRegistry::new()),
+            starving: AtomicBool::new(false),
+        }
+    }
+
+    /// Publish alerts through the given notification channel (default: log)
+    pub fn with_notifier(mut self, notifier: Arc<dyn Notifier>) -> Self {
+        self.notifier = notifier;
+        self
+    }
+
+    /// Treat the queue as paused while paused in this registry (the worker
+    /// may serve other queues that are not)
+    pub fn with_pause_registry(mut self, pauses: Arc<QueuePauseRegistry>) -> Self {
+        self.pauses = pauses;
+        self
+    }
+
+    /// Run one check
+    ///
+    /// Returns the wait (ms) of the oldest starving job, None if the queue is healthy.
+    pub async fn check_once(&self) -> Result<Option<i64>> {
+        let now = self.time_provider.now_millis();
+        let waited_ms = self
+            .job_repo
+            .oldest_queued_at(&self.queue, now)
+            .await?
+            .map(|created_at| elapsed_ms(now, created_at));
+
+        let starving = waited_ms.filter(|&waited| {
+            waited > self.max_wait_ms
+                && !self.activity.is_busy()
+                && !self.activity.is_throttled()
+                && !self.activity.is_paused()
+                && !self.pauses.is_paused(&self.queue)
+        });
+
+        match starving {
+            Some(waited_ms) if !self.starving.swap(true, Ordering::Relaxed) => {
+                error!(
+                    queue = %self.queue,
+                    waited_ms,
+                    max_wait_ms = self.max_wait_ms,
+                    "Worker starvation: queued jobs are not being processed"
+                );
+                let notification = Notification {
+                    topic: STARVATION_TOPIC.to_string(),
+                    timestamp: now,
+                    payload: serde_json::json!({
+                        "queue": self.queue,
+                        "oldest_wait_ms": waited_ms,
+                        "max_wait_ms": self.max_wait_ms,
+                    }),
+                };
+                if let Err(e) = self.notifier.notify(&notification).await {
+                    warn!(error = %e, "Failed to publish starvation alert");
+                }
+            }
+            None if self.starving.swap(false, Ordering::Relaxed) => {
+                info!(queue = %self.queue, "Worker starvation resolved");
+            }
+            _ => {}
+        }
+
+        Ok(starving)
+    }
+
+    /// Run the watchdog loop (background task)
+    ///
+    /// Should be spawned in tokio::spawn
+    pub async fn run(self: Arc<Self>, every: Duration) {
+        info!(
+            queue = %self.queue,
+            max_wait_ms = self.max_wait_ms,
+            "Starvation detector started"
+        );
+
+        let mut tick = interval(every);
+        loop {
+            tick.tick().await;
+            if let Err(e) = self.check_once().await {
+                error!(error = %e, "Starvation check failed");
+            }
+        }
+    }
+}
@@ -0,0 +1,279 @@
+// State Feed - Live stream of job state transitions (jobs.subscribe.v1)
+//
+// Registered as an interceptor, it sees every pop and completion of the
+// worker and the lease service; the RPC layer adds cancellations and retries.
+// Slow subscribers lag and miss events rather than slowing the worker down.
+// Each subscription can narrow the stream with a SubscriptionFilter.
+
+use crate::application::interceptor::JobInterceptor;
+use crate::domain::{Job, JobId, JobProgress, JobState};
+use crate::port::TimeProvider;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// Events buffered per subscriber before it starts lagging
+pub const STATE_FEED_CAPACITY: usize = 1024;
+
+/// What a feed event reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateKind {
+    /// The job moved to another state
+    State,
+    /// A running job reported progress (`new_state` unchanged)
+    Progress,
+}
+
+/// One state transition of a job
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JobStateChange {
+    pub kind: UpdateKind,
+    pub job_id: JobId,
+    pub queue: String,
+    pub job_type: String,
+    pub subject_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain_group_id: Option<String>,
+    /// None for a newly enqueued job
+    pub old_state: Option<JobState>,
+    pub new_state: JobState,
+    /// Reported progress (progress events only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<JobProgress>,
+    pub timestamp: i64, // epoch ms
+}
+
+/// Server-side filter of one subscription (unset fields match everything)
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct SubscriptionFilter {
+    #[serde(default)]
+    pub queue: Option<String>,
+    #[serde(default)]
+    pub job_type: Option<String>,
+    /// Jobs whose subject_key starts with this (e.g. an editor's workspace root)
+    #[serde(default)]
+    pub subject_prefix: Option<String>,
+    /// Jobs with this user_tag
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Jobs of this chain (chain_group_id)
+    #[serde(default)]
+    pub chain: Option<String>,
+    /// Event kinds to deliver (None = all)
+    #[serde(default)]
+    pub kinds: Option<Vec<UpdateKind>>,
+}
+
+impl SubscriptionFilter {
+    pub fn matches(&self, change: &JobStateChange) -> bool {
+        fn eq(wanted: &Option<String>, actual: &str) -> bool {
+            wanted.as_deref().is_none_or(|wanted| wanted == actual)
+        }
+
+        eq(&self.queue, &change.queue)
+            && eq(&self.job_type, &change.job_type)
+            && self
+                .subject_prefix
+                .as_deref()
+                .is_none_or(|prefix| change.subject_key.starts_with(prefix))
+            && eq(&self.tag, change.user_tag.as_deref().unwrap_or_default())
+            && eq(
+                &self.chain,
+                change.chain_group_id.as_deref().unwrap_or_default(),
+            )
+            && self
+                .kinds
+                .as_ref()
+                .is_none_or(|kinds| kinds.contains(&change.kind))
+    }
+}
+
+/// Broadcasts job state transitions to subscribers
+pub struct StateFeed {
+    sender: broadcast::Sender<JobStateChange>,
+    time_provider: Arc<dyn TimeProvider>,
+}
+
+impl StateFeed {
+    pub fn new(time_provider: Arc<dyn TimeProvider>) -> Self {
+        let (sender, _) = broadcast::channel(STATE_FEED_CAPACITY);
+        Self {
+            sender,
+            time_provider,
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<JobStateChange> {
+        self.sender.subscribe()
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+
+    /// Publish a transition of `job` (no-op without subscribers)
+    pub fn publish(&self, job: &Job, old_state: Option<JobState>, new_state: JobState) {
+        self.send(UpdateKind::State, job, old_state, new_state);
+    }
+
+    /// Publish the progress just reported by a running `job`
+    pub fn publish_progress(&self, job: &Job) {
+        self.send(
+            UpdateKind::Progress,
+            job,
+            Some(job.state.clone()),
+            job.state.clone(),
+        );
+    }
+
+    fn send(&self, kind: UpdateKind, job: &Job, old_state: Option<JobState>, new_state: JobState) {
+        if self.subscriber_count() == 0 {
+            return;
+        }
+        // Err = the last subscriber just left, nothing to deliver
+        let _ = self.sender.send(JobStateChange {
+            kind,
+            job_id: job.id.clone(),
+            queue: job.queue.clone(),
+            job_type: job.job_type.as_str().to_string(),
+            subject_key: job.subject_key.clone(),
+            user_tag: job.user_tag.clone(),
+            chain_group_id: job.chain_group_id.clone(),
+            old_state,
+            new_state,
+            progress: match kind {
+                UpdateKind::Progress => job.progress.clone(),
+                UpdateKind::State => None,
+            },
+            timestamp: self.time_provider.now_millis(),
+        });
+    }
+}
+
+#[async_trait]
+impl JobInterceptor for StateFeed {
+    fn name(&self) -> &str {
+        "state_feed"
+    }
+
+    async fn on_pop(&self, job: &Job) {
+        self.publish(job, Some(JobState::Queued), JobState::Running);
+    }
+
+    async fn on_complete(&self, job: &Job, state: &JobState) {
+        self.publish(job, Some(job.state.clone()), state.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{JobPayload, JobType};
+    use crate::port::time_provider::SystemTimeProvider;
+
+    #[tokio::test]
+    async fn test_pop_and_complete_are_streamed() {
+        let feed = StateFeed::new(Arc::new(SystemTimeProvider));
+        let mut events = feed.subscribe();
+
+        let mut job = Job::new_test(
+            "default",
+            JobType::new("BUILD"),
+            "repo/app",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job.state = JobState::Running;
+        feed.on_pop(&job).await;
+        feed.on_complete(&job, &JobState::Done).await;
+
+        let popped = events.recv().await.unwrap();
+        assert_eq!(popped.old_state, Some(JobState::Queued));
+        assert_eq!(popped.new_state, JobState::Running);
+        let done = events.recv().await.unwrap();
+        assert_eq!(done.job_id, job.id);
+        assert_eq!(done.old_state, Some(JobState::Running));
+        assert_eq!(done.new_state, JobState::Done);
+        assert_eq!(done.progress, None);
+    }
+
+    #[tokio::test]
+    async fn test_progress_is_streamed() {
+        let feed = StateFeed::new(Arc::new(SystemTimeProvider));
+        let mut events = feed.subscribe();
+
+        let mut job = Job::new_test(
+            "default",
+            JobType::new("INDEX"),
+            "repo",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job.state = JobState::Running;
+        job.progress = Some(JobProgress {
+            percent: 40,
+            message: Some("parsed 400/1000 files".to_string()),
+            updated_at: 7,
+        });
+        feed.publish_progress(&job);
+
+        let change = events.recv().await.unwrap();
+        assert_eq!(change.kind, UpdateKind::Progress);
+        assert_eq!(change.new_state, JobState::Running);
+        assert_eq!(change.progress, job.progress);
+    }
+
+    #[test]
+    fn test_filter_matches_own_subjects_and_kinds() {
+        let mut job = Job::new_test(
+            "default",
+            JobType::new("INDEX_FILE"),
+            "/work/editor/src/main.rs",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job.user_tag = Some("editor".to_string());
+        let change = JobStateChange {
+            kind: UpdateKind::State,
+            job_id: job.id.clone(),
+            queue: job.queue.clone(),
+            job_type: "INDEX_FILE".to_string(),
+            subject_key: job.subject_key.clone(),
+            user_tag: job.user_tag.clone(),
+            chain_group_id: None,
+            old_state: Some(JobState::Queued),
+            new_state: JobState::Running,
+            progress: None,
+            timestamp: 0,
+        };
+
+        assert!(SubscriptionFilter::default().matches(&change));
+        let own = SubscriptionFilter {
+            subject_prefix: Some("/work/editor/".to_string()),
+            tag: Some("editor".to_string()),
+            kinds: Some(vec![UpdateKind::State]),
+            ..Default::default()
+        };
+        assert!(own.matches(&change));
+
+        let other_root = SubscriptionFilter {
+            subject_prefix: Some("/work/other/".to_string()),
+            ..Default::default()
+        };
+        assert!(!other_root.matches(&change));
+        let progress_only = SubscriptionFilter {
+            kinds: Some(vec![UpdateKind::Progress]),
+            ..Default::default()
+        };
+        assert!(!progress_only.matches(&change));
+        let chain = SubscriptionFilter {
+            chain: Some("chain-1".to_string()),
+            ..Default::default()
+        };
+        assert!(!chain.matches(&change), "Job is not part of a chain");
+    }
+}
@@ -0,0 +1,61 @@
+// Warmup - Prime the pop path after crash recovery
+//
+// Runs once before the daemon reports readiness (RPC server started), so the
+// first interactive request does not pay for opening connections, preparing
+// statements and reading cold index pages.
+
+use crate::application::scheduler::Scheduler;
+use crate::error::Result;
+use crate::port::JobRepository;
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::info;
+
+/// Outcome of a warmup run
+#[derive(Debug, Clone)]
+pub struct WarmupReport {
+    /// Queues whose pop path was primed
+    pub queues: Vec<String>,
+    pub duration_ms: u64,
+}
+
+/// Startup warmup (call before serving requests)
+pub struct Warmup {
+    job_repo: Arc<dyn JobRepository>,
+    scheduler: Arc<Scheduler>,
+}
+
+impl Warmup {
+    pub fn new(job_repo: Arc<dyn JobRepository>, scheduler: Arc<Scheduler>) -> Self {
+        Self {
+            job_repo,
+            scheduler,
+        }
+    }
+
+    /// Prime the configured queues plus every queue holding QUEUED jobs
+    pub async fn run(&self, queues: &[&str]) -> Result<WarmupReport> {
+        let started = Instant::now();
+
+        let mut all: Vec<String> = queues.iter().map(|q| q.to_string()).collect();
+        for queue in self.job_repo.list_active_queues().await? {
+            if !all.contains(&queue) {
+                all.push(queue);
+            }
+        }
+
+        self.job_repo.warm_up(&all).await?;
+        self.scheduler.warm_up().await;
+
+        let report = WarmupReport {
+            queues: all,
+            duration_ms: started.elapsed().as_millis() as u64,
+        };
+        info!(
+            queues = report.queues.len(),
+            duration_ms = report.duration_ms,
+            "Warmup completed"
+        );
+        Ok(report)
+    }
+}
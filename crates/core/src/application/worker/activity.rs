@@ -0,0 +1,173 @@
+// Worker Activity - What the worker loop is doing right now
+//
+// Written by the Worker, read by watchdogs (e.g. StarvationDetector) that need
+// to tell "idle because there is nothing to do" from "stalled", by
+// admin.stats.v1 to show which job occupies each execution slot, and by
+// health.check.v1 to tell a wedged worker loop from an idle one. The
+// WorkerSupervisor records here when the loop died and how often it restarted.
+
+use super::constants::WORKER_SLOTS;
+use crate::domain::JobId;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// Job occupying an execution slot
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlotOccupant {
+    pub job_id: JobId,
+    pub job_type: String,
+    pub subject_key: String,
+    /// When execution started in this slot (epoch ms)
+    pub since: i64,
+}
+
+/// Last unexpected termination of the worker loop
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkerExit {
+    /// When the loop terminated (epoch ms)
+    pub at: i64,
+    /// Panic message or error
+    pub reason: String,
+}
+
+/// Shared view of a worker's state (flags are lock-free)
+#[derive(Debug)]
+pub struct WorkerActivity {
+    busy: AtomicBool,
+    throttled: AtomicBool,
+    paused: AtomicBool,
+    last_tick: AtomicI64,
+    slots: Mutex<Vec<Option<SlotOccupant>>>,
+    down: AtomicBool,
+    restarts: AtomicU64,
+    last_exit: Mutex<Option<WorkerExit>>,
+}
+
+impl Default for WorkerActivity {
+    fn default() -> Self {
+        Self {
+            busy: AtomicBool::new(false),
+            throttled: AtomicBool::new(false),
+            paused: AtomicBool::new(false),
+            last_tick: AtomicI64::new(0),
+            slots: Mutex::new(vec![None; WORKER_SLOTS]),
+            down: AtomicBool::new(false),
+            restarts: AtomicU64::new(0),
+            last_exit: Mutex::new(None),
+        }
+    }
+}
+
+impl WorkerActivity {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Occupant of every slot, by slot index (None = idle slot)
+    pub fn slots(&self) -> Vec<Option<SlotOccupant>> {
+        self.slots.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// At least one job is executing
+    pub fn is_busy(&self) -> bool {
+        self.busy.load(Ordering::Relaxed)
+    }
+
+    /// The last poll skipped processing because the system is overloaded
+    pub fn is_throttled(&self) -> bool {
+        self.throttled.load(Ordering::Relaxed)
+    }
+
+    /// The queue is paused by an operator (admin.queue.pause.v1)
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    /// When the worker loop last started an iteration (epoch ms, None = never)
+    pub fn last_tick(&self) -> Option<i64> {
+        match self.last_tick.load(Ordering::Relaxed) {
+            0 => None,
+            at => Some(at),
+        }
+    }
+
+    /// The loop terminated and has not been restarted yet
+    pub fn is_down(&self) -> bool {
+        self.down.load(Ordering::Relaxed)
+    }
+
+    /// Times the supervisor restarted the loop
+    pub fn restarts(&self) -> u64 {
+        self.restarts.load(Ordering::Relaxed)
+    }
+
+    /// Latest unexpected termination (None = never)
+    pub fn last_exit(&self) -> Option<WorkerExit> {
+        self.last_exit
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// The loop died: nothing runs in its slots anymore
+    pub(crate) fn record_exit(&self, now: i64, reason: &str) {
+        self.down.store(true, Ordering::Relaxed);
+        self.busy.store(false, Ordering::Relaxed);
+        self.slots
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .fill(None);
+        *self.last_exit.lock().unwrap_or_else(|e| e.into_inner()) = Some(WorkerExit {
+            at: now,
+            reason: reason.to_string(),
+        });
+    }
+
+    pub(crate) fn record_restart(&self) {
+        self.restarts.fetch_add(1, Ordering::Relaxed);
+        self.down.store(false, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_tick(&self, now: i64) {
+        self.last_tick.store(now, Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_throttled(&self, throttled: bool) {
+        self.throttled.store(throttled, Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::Relaxed);
+    }
+
+    /// One slot per job the worker may execute at once (occupied slots are kept)
+    pub(crate) fn resize_slots(&self, count: usize) {
+        let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+        let occupied = slots.iter().rposition(Option::is_some).map_or(0, |i| i + 1);
+        slots.resize(count.max(occupied), None);
+    }
+
+    /// Place a job in the first idle slot (a new one if all are taken), returns its index
+    pub(crate) fn occupy_slot(&self, occupant: SlotOccupant) -> usize {
+        let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+        let slot = match slots.iter().position(Option::is_none) {
+            Some(slot) => slot,
+            None => {
+                slots.push(None);
+                slots.len() - 1
+            }
+        };
+        slots[slot] = Some(occupant);
+        self.busy.store(true, Ordering::Relaxed);
+        slot
+    }
+
+    pub(crate) fn free_slot(&self, slot: usize) {
+        let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(entry) = slots.get_mut(slot) {
+            *entry = None;
+        }
+        self.busy
+            .store(slots.iter().any(Option::is_some), Ordering::Relaxed);
+    }
+}
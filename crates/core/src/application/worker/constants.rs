@@ -1,12 +1,51 @@
 // Worker constants (ADR: No magic values)
 use std::time::Duration;
 
+/// Jobs a worker executes at the same time unless configured otherwise
+/// (one slot per job)
+pub const WORKER_SLOTS: usize = 1;
+
 /// Sleep duration when no jobs are available (100ms)
 pub const IDLE_SLEEP_DURATION: Duration = Duration::from_millis(100);
 
+/// Safety-net poll of an idle worker woken by a dispatch signal (1s): catches
+/// jobs that become due without an enqueue (schedule_at, retry backoff)
+pub const SIGNALLED_IDLE_POLL_DURATION: Duration = Duration::from_secs(1);
+
 /// Sleep duration after worker error before retry (1s)
 pub const ERROR_RECOVERY_SLEEP_DURATION: Duration = Duration::from_secs(1);
 
+/// A worker loop that is not executing a job and has not ticked for this
+/// long is reported as wedged by health.check.v1 (30s)
+pub const WORKER_STALE_TICK_MS: i64 = 30_000;
+
+/// On shutdown, running jobs get this long to finish before they are
+/// interrupted and requeued (10s)
+pub const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(10);
+
+/// Delay before the first restart of a terminated worker loop (1s)
+pub const WORKER_RESTART_BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// Upper bound of the worker restart backoff (1 minute)
+pub const WORKER_RESTART_MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// A worker loop that ran at least this long before terminating restarts
+/// after the base delay again (5 minutes)
+pub const WORKER_STABLE_RUN: Duration = Duration::from_secs(5 * 60);
+
+/// Delay before the first restart of a service job that exited (1s)
+pub const SERVICE_RESTART_BASE_DELAY_MS: i64 = 1000;
+
+/// Upper bound of the service restart backoff (5 minutes)
+pub const SERVICE_RESTART_MAX_DELAY_MS: i64 = 5 * 60 * 1000;
+
+/// A service that ran at least this long before exiting restarts without
+/// backoff (1 minute)
+pub const SERVICE_STABLE_RUN_MS: i64 = 60_000;
+
+/// Successful results kept per subject for subject.history.v1
+pub const SUBJECT_HISTORY_LEN: usize = 20;
+
 /// Mock execution duration for testing (10ms)
 /// Note: Real execution uses IN_PROCESS or SUBPROCESS mode (Phase 2)
 pub const MOCK_EXECUTION_DURATION: Duration = Duration::from_millis(10);
@@ -14,10 +53,16 @@ pub const MOCK_EXECUTION_DURATION: Duration = Duration::from_millis(10);
 /// Default retry base delay (1000ms = 1s)
 pub const DEFAULT_RETRY_BASE_DELAY_MS: i64 = 1000;
 
+/// Executions of a job that may panic before it is quarantined as poisoned
+pub const DEFAULT_MAX_PANICS: i32 = 3;
+
+/// Upper bound of the delay between two retries of a job (10 minutes)
+pub const DEFAULT_RETRY_MAX_DELAY_MS: i64 = 10 * 60 * 1000;
+
 /// Default recovery window for orphaned jobs (5 minutes)
 pub const DEFAULT_RECOVERY_WINDOW_MS: i64 = 5 * 60 * 1000;
 
-/// CPU usage threshold for throttling (percent)
+/// Default CPU usage threshold for throttling (percent, tunable at runtime)
 /// When CPU usage exceeds this, low-priority queues are paused (ADR-002)
 pub const CPU_THROTTLE_THRESHOLD: f32 = 90.0;
 
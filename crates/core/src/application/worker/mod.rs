@@ -1,33 +1,100 @@
 // Worker - Job execution loop
 
+mod activity;
 pub mod constants;
 mod panic_guard;
+mod running;
+mod service;
 mod shutdown; // Public for use in other modules
+mod supervisor;
 
+pub use activity::{SlotOccupant, WorkerActivity, WorkerExit};
 use constants::*;
 pub use panic_guard::{execute_guarded, execute_guarded_async, PanicGuardResult};
+pub use running::{RunningJobs, StopReason, Termination, KILL_CONFIRM_TIMEOUT};
+use service::ServiceSupervisor;
 pub use shutdown::{shutdown_channel, ShutdownSender, ShutdownToken};
+pub use supervisor::WorkerSupervisor;
 
 // Note: This helper is replaced by RetryPolicy in Phase 2
 // Removed as dead code
 
+use crate::application::dispatch_signal::DispatchSignal;
+use crate::application::drain::{Drain, InFlight};
+use crate::application::interceptor::InterceptorChain;
+use crate::application::lockdown::Lockdown;
+use crate::application::queue_pause::QueuePauseRegistry;
 use crate::application::retry::RetryPolicy;
-use crate::domain::Job;
+use crate::application::retry_policies::RetryPolicies;
+use crate::application::runtime_config::RuntimeConfig;
+use crate::application::scheduling_policy::SchedulingPolicy;
+use crate::domain::job_event::actor;
+use crate::domain::{
+    DeadLetter, Job, JobEvent, JobId, JobLease, JobState, QueueWeights, SubjectResult, TraceParent,
+};
 use crate::error::Result;
-use crate::port::{JobRepository, SystemProbe, TaskExecutor};
-use std::sync::Arc;
-use tokio::time::sleep;
-use tracing::{error, info, warn};
+use crate::port::{
+    ErrorClass, ExecutionError, ExecutionResult, ExecutionStatus, JobRepository, NoopWorkerMetrics,
+    SystemProbe, TaskExecutor, WorkerMetrics,
+};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use tokio::time::{interval_at, sleep};
+use tracing::{error, info, info_span, warn, Instrument};
 
 /// Worker processes jobs from a queue (Phase 1 + Phase 2 + Phase 3)
 pub struct Worker {
-    queue: String,
+    /// Queues polled in turn (at least one)
+    queues: Vec<String>,
+    /// Share of pops per queue (same order as `queues`, >= 1)
+    weights: Vec<u32>,
+    /// Smooth weighted round-robin state (same order as `queues`)
+    credits: Mutex<Vec<i64>>,
     job_repo: Arc<dyn JobRepository>,
     task_executor: Arc<dyn TaskExecutor>,
     system_probe: Arc<dyn SystemProbe>,
-    retry_policy: Arc<RetryPolicy>,
-    scheduler: Arc<crate::application::scheduler::Scheduler>, // Phase 3
-    time_provider: Arc<dyn crate::port::TimeProvider>,        // For deterministic testing
+    retry_policies: Arc<RetryPolicies>,
+    scheduler: Arc<dyn SchedulingPolicy>,              // Phase 3
+    time_provider: Arc<dyn crate::port::TimeProvider>, // For deterministic testing
+    interceptors: Arc<InterceptorChain>,
+    pauses: Arc<QueuePauseRegistry>,
+    lockdown: Arc<Lockdown>,
+    drain: Arc<Drain>,
+    runtime_config: Arc<RuntimeConfig>,
+    activity: Arc<WorkerActivity>,
+    running_jobs: Arc<RunningJobs>,
+    metrics: Arc<dyn WorkerMetrics>,
+    subject_history_len: usize,
+    concurrency: usize,
+    dispatch: Option<Arc<DispatchSignal>>,
+    /// Idle poll while a dispatch signal is attached
+    signalled_idle_poll: Duration,
+    /// How long running jobs may finish on shutdown before they are requeued
+    shutdown_grace: Duration,
+    lease: Option<WorkerLease>,
+    /// Service supervisors, interrupted and awaited on shutdown
+    services: Mutex<JoinSet<()>>,
+}
+
+/// Lease held on each executing job, renewed until the execution ends
+///
+/// A job whose worker stops renewing (died, wedged) is reclaimed by the
+/// lease reaper without waiting for a daemon restart.
+struct WorkerLease {
+    worker_id: String,
+    duration: Duration,
+}
+
+/// What popping the queue yielded
+enum Claim {
+    /// Nothing to run (empty, paused, throttled or deferred)
+    Idle,
+    /// Finalized without an execution slot (expired job, service)
+    Handled,
+    /// RUNNING job to execute in a slot, in flight until its result is recorded
+    Ready(Box<Job>, InFlight),
 }
 
 impl Worker {
@@ -38,18 +105,198 @@ impl Worker {
         task_executor: Arc<dyn TaskExecutor>,
         system_probe: Arc<dyn SystemProbe>,
         retry_policy: Arc<RetryPolicy>,
-        scheduler: Arc<crate::application::scheduler::Scheduler>, // Phase 3
+        scheduler: Arc<dyn SchedulingPolicy>, // Phase 3 (default: conditions Scheduler)
         time_provider: Arc<dyn crate::port::TimeProvider>,
     ) -> Self {
+        let running_jobs = Arc::new(RunningJobs::new(Arc::clone(&task_executor)));
         Self {
-            queue: queue.into(),
+            queues: vec![queue.into()],
+            weights: vec![1],
+            credits: Mutex::new(vec![0]),
             job_repo,
             task_executor,
             system_probe,
-            retry_policy,
+            retry_policies: Arc::new(RetryPolicies::new(retry_policy)),
             scheduler,
             time_provider,
+            interceptors: Arc::new(InterceptorChain::new()),
+            pauses: Arc::new(QueuePauseRegistry::new()),
+            lockdown: Arc::new(Lockdown::new()),
+            drain: Arc::new(Drain::new()),
+            runtime_config: Arc::new(RuntimeConfig::default()),
+            activity: Arc::new(WorkerActivity::new()),
+            running_jobs,
+            metrics: Arc::new(NoopWorkerMetrics),
+            subject_history_len: SUBJECT_HISTORY_LEN,
+            concurrency: WORKER_SLOTS,
+            dispatch: None,
+            signalled_idle_poll: SIGNALLED_IDLE_POLL_DURATION,
+            shutdown_grace: SHUTDOWN_GRACE_PERIOD,
+            lease: None,
+            services: Mutex::new(JoinSet::new()),
+        }
+    }
+
+    /// Shared activity state (busy / throttled) for watchdogs
+    pub fn activity(&self) -> Arc<WorkerActivity> {
+        self.activity.clone()
+    }
+
+    /// Report into a shared activity (e.g. one created before the RPC server)
+    pub fn with_activity(mut self, activity: Arc<WorkerActivity>) -> Self {
+        self.activity = activity;
+        self
+    }
+
+    /// Executions in flight, for cancelling them (kill / abort)
+    pub fn running_jobs(&self) -> Arc<RunningJobs> {
+        self.running_jobs.clone()
+    }
+
+    /// Register executions in a shared registry (e.g. one created before the
+    /// RPC server); it must kill through this worker's executor
+    pub fn with_running_jobs(mut self, running_jobs: Arc<RunningJobs>) -> Self {
+        self.running_jobs = running_jobs;
+        self
+    }
+
+    /// Attach an interceptor chain notified on pop and completion
+    pub fn with_interceptors(mut self, interceptors: Arc<InterceptorChain>) -> Self {
+        self.interceptors = interceptors;
+        self
+    }
+
+    /// Skip popping while the queue is paused in this registry
+    pub fn with_pause_registry(mut self, pauses: Arc<QueuePauseRegistry>) -> Self {
+        self.pauses = pauses;
+        self
+    }
+
+    /// Report cycle, pop and execution timings (e.g. to OpenTelemetry)
+    pub fn with_metrics(mut self, metrics: Arc<dyn WorkerMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Look up the retry policy of each failed job's type (default: the one given to `new`)
+    pub fn with_retry_policies(mut self, retry_policies: Arc<RetryPolicies>) -> Self {
+        self.retry_policies = retry_policies;
+        self
+    }
+
+    /// Pop nothing while this lockdown is engaged
+    pub fn with_lockdown(mut self, lockdown: Arc<Lockdown>) -> Self {
+        self.lockdown = lockdown;
+        self
+    }
+
+    /// Pop nothing while this drain is on, count claimed jobs in flight
+    pub fn with_drain(mut self, drain: Arc<Drain>) -> Self {
+        self.drain = drain;
+        self
+    }
+
+    /// Read the CPU throttle threshold from shared runtime settings
+    pub fn with_runtime_config(mut self, runtime_config: Arc<RuntimeConfig>) -> Self {
+        self.runtime_config = runtime_config;
+        self
+    }
+
+    /// Replace the scheduling policy (e.g. a PolicyChain wrapping the default Scheduler)
+    pub fn with_scheduling_policy(mut self, policy: Arc<dyn SchedulingPolicy>) -> Self {
+        self.scheduler = policy;
+        self
+    }
+
+    /// Successful results kept per subject for subject.history.v1 (0 = none recorded)
+    pub fn with_subject_history_len(mut self, len: usize) -> Self {
+        self.subject_history_len = len;
+        self
+    }
+
+    /// Serve several queues, polled round-robin (empty names are ignored)
+    ///
+    /// Replaces the queue given to `new` unless no name is left.
+    pub fn with_queues<I, S>(self, queues: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut names: Vec<String> = Vec::new();
+        for queue in queues {
+            let queue = queue.into().trim().to_string();
+            if !queue.is_empty() && !names.contains(&queue) {
+                names.push(queue);
+            }
         }
+        self.with_weighted_queues(names.into_iter().map(|queue| (queue, 1)).collect())
+    }
+
+    /// Serve several queues, each getting pops in proportion to its weight
+    /// while all have work (a flood in one queue cannot starve the others)
+    pub fn with_queue_weights(self, weights: &QueueWeights) -> Self {
+        let queues = weights.queues();
+        let entries = queues
+            .into_iter()
+            .map(|queue| {
+                let weight = weights.weight(&queue).unwrap_or(1);
+                (queue, weight)
+            })
+            .collect();
+        self.with_weighted_queues(entries)
+    }
+
+    fn with_weighted_queues(mut self, entries: Vec<(String, u32)>) -> Self {
+        if entries.is_empty() {
+            return self;
+        }
+        self.credits = Mutex::new(vec![0; entries.len()]);
+        (self.queues, self.weights) = entries
+            .into_iter()
+            .map(|(queue, weight)| (queue, weight.max(1)))
+            .unzip();
+        self
+    }
+
+    /// Queues this worker pops from
+    pub fn queues(&self) -> &[String] {
+        &self.queues
+    }
+
+    /// Wake up from idle when this signal fires instead of polling every
+    /// IDLE_SLEEP_DURATION (a slow poll still catches jobs that become due
+    /// without an enqueue)
+    pub fn with_dispatch_signal(mut self, dispatch: Arc<DispatchSignal>) -> Self {
+        self.dispatch = Some(dispatch);
+        self
+    }
+
+    /// Safety-net poll of an idle worker that has a dispatch signal (default 1s)
+    pub fn with_signalled_idle_poll(mut self, every: Duration) -> Self {
+        self.signalled_idle_poll = every;
+        self
+    }
+
+    /// Lease executing jobs to `worker_id` for `duration`, renewed every third
+    /// of it (the lease reaper reclaims jobs whose lease ran out)
+    pub fn with_lease(mut self, worker_id: impl Into<String>, duration: Duration) -> Self {
+        self.lease = Some(WorkerLease {
+            worker_id: worker_id.into(),
+            duration,
+        });
+        self
+    }
+
+    /// Jobs the run loop executes at the same time (at least 1)
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// On shutdown, let running jobs finish this long, then kill and requeue them
+    pub fn with_shutdown_grace(mut self, grace: Duration) -> Self {
+        self.shutdown_grace = grace;
+        self
     }
 
     /// Create a Phase 1 compatible worker (for backward compatibility in tests)
@@ -83,111 +330,461 @@ impl Worker {
         )
     }
     /// Run worker loop with graceful shutdown support
-    pub async fn run(&self, mut shutdown: ShutdownToken) -> Result<()> {
-        info!("Worker started for queue: {}", self.queue);
+    ///
+    /// Up to `concurrency` jobs execute at once, each in its own task. On
+    /// shutdown the loop stops popping and waits for running jobs to finish;
+    /// the ones still running after the grace period are killed and requeued.
+    pub async fn run(self: Arc<Self>, mut shutdown: ShutdownToken) -> Result<()> {
+        info!(
+            concurrency = self.concurrency,
+            "Worker started for queue: {}",
+            self.queue_label()
+        );
+        self.activity.resize_slots(self.concurrency);
+        let permits = Arc::new(Semaphore::new(self.concurrency));
+        let mut running = JoinSet::new();
+
         loop {
+            self.activity.record_tick(self.time_provider.now_millis());
+
             // Check for shutdown signal
             if shutdown.is_shutdown() {
-                info!("Worker shutting down for queue: {}", self.queue);
+                info!("Worker shutting down for queue: {}", self.queue_label());
                 break;
             }
-            match self.process_next_job().await {
-                Ok(processed) => {
-                    if !processed {
-                        // No job available, sleep briefly (or wait for shutdown)
-                        tokio::select! {
-                            _ = sleep(IDLE_SLEEP_DURATION) => {},
-                            _ = shutdown.wait() => {
-                                info!("Worker interrupted during idle");
-                                break;
+
+            // Wait for a free slot, then take every other free one for a batch
+            let permit = tokio::select! {
+                permit = Arc::clone(&permits).acquire_owned() => {
+                    permit.expect("worker semaphore is never closed")
+                }
+                _ = shutdown.wait() => {
+                    info!("Worker interrupted while all slots are busy");
+                    break;
+                }
+            };
+            let mut free = vec![permit];
+            while let Ok(permit) = Arc::clone(&permits).try_acquire_owned() {
+                free.push(permit);
+            }
+            while let Some(finished) = running.try_join_next() {
+                Self::reap(finished);
+            }
+            self.reap_services();
+
+            let cycle_started = Instant::now();
+            let mut processed = false;
+            let mut failed = false;
+            match self.claim(free.len()).await {
+                Ok(claims) => {
+                    for claim in claims {
+                        match claim {
+                            Ok(Claim::Ready(job, in_flight)) => {
+                                let permit = free.pop().expect("one slot per claimed job");
+                                let worker = Arc::clone(&self);
+                                running.spawn(async move {
+                                    let outcome = worker.execute_claimed(*job).await;
+                                    drop(in_flight);
+                                    worker.metrics.record_cycle(
+                                        &worker.queue_label(),
+                                        cycle_started.elapsed(),
+                                        true,
+                                    );
+                                    drop(permit);
+                                    outcome
+                                });
+                                processed = true;
+                            }
+                            Ok(Claim::Handled) => {
+                                self.metrics.record_cycle(
+                                    &self.queue_label(),
+                                    cycle_started.elapsed(),
+                                    true,
+                                );
+                                processed = true;
+                            }
+                            Ok(Claim::Idle) => {}
+                            Err(e) => {
+                                error!("Worker error: {}", e);
+                                failed = true;
                             }
                         }
                     }
                 }
                 Err(e) => {
                     error!("Worker error: {}", e);
-                    tokio::select! {
-                        _ = sleep(ERROR_RECOVERY_SLEEP_DURATION) => {},
-                        _ = shutdown.wait() => {
-                            info!("Worker interrupted during error recovery");
-                            break;
-                        }
+                    failed = true;
+                }
+            }
+            drop(free);
+            if processed {
+                continue;
+            }
+            self.metrics
+                .record_cycle(&self.queue_label(), cycle_started.elapsed(), false);
+            let idle = !failed;
+
+            if idle {
+                // No job available, sleep until new work is signalled (or shutdown)
+                tokio::select! {
+                    _ = self.wait_for_work() => {},
+                    _ = shutdown.wait() => {
+                        info!("Worker interrupted during idle");
+                        break;
+                    }
+                }
+            } else {
+                tokio::select! {
+                    _ = sleep(ERROR_RECOVERY_SLEEP_DURATION) => {},
+                    _ = shutdown.wait() => {
+                        info!("Worker interrupted during error recovery");
+                        break;
                     }
                 }
             }
         }
-        info!("Worker stopped for queue: {}", self.queue);
+
+        if !running.is_empty() {
+            info!(
+                running = running.len(),
+                grace = ?self.shutdown_grace,
+                "Waiting for running jobs before stopping queue: {}",
+                self.queue_label()
+            );
+        }
+        let drained = tokio::time::timeout(self.shutdown_grace, async {
+            while let Some(finished) = running.join_next().await {
+                Self::reap(finished);
+            }
+        })
+        .await;
+        // Services never finish on their own: they are interrupted either way
+        if drained.is_err() || !self.running_jobs.job_ids().is_empty() {
+            let interrupted = self.running_jobs.interrupt_all().await;
+            warn!(
+                interrupted = interrupted.len(),
+                "Running jobs interrupted by shutdown, requeued for the next start"
+            );
+            while let Some(finished) = running.join_next().await {
+                Self::reap(finished);
+            }
+        }
+        let mut services =
+            std::mem::take(&mut *self.services.lock().unwrap_or_else(|e| e.into_inner()));
+        while services.join_next().await.is_some() {}
+        info!("Worker stopped for queue: {}", self.queue_label());
         Ok(())
     }
+
+    /// Idle wait: the dispatch signal or the fallback poll, whichever comes first
+    async fn wait_for_work(&self) {
+        match &self.dispatch {
+            Some(dispatch) => {
+                tokio::select! {
+                    _ = dispatch.notified() => {},
+                    _ = sleep(self.signalled_idle_poll) => {},
+                }
+            }
+            None => sleep(IDLE_SLEEP_DURATION).await,
+        }
+    }
+
+    /// Drop the supervisors of services that stopped for good
+    fn reap_services(&self) {
+        let mut services = self.services.lock().unwrap_or_else(|e| e.into_inner());
+        while services.try_join_next().is_some() {}
+    }
+
+    /// Log a finished execution task's error, re-raise its panic in the loop
+    fn reap(finished: std::result::Result<Result<()>, tokio::task::JoinError>) {
+        match finished {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => error!("Worker error: {}", e),
+            Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+            Err(e) => error!("Worker execution task aborted: {}", e),
+        }
+    }
+
     /// Process next job from queue (returns true if job was processed)
+    ///
+    /// Runs the job to completion, outside the run loop's slot limit.
     pub async fn process_next_job(&self) -> Result<bool> {
+        let Some(claim) = self.claim(1).await?.pop() else {
+            return Ok(false);
+        };
+        match claim? {
+            Claim::Idle => Ok(false),
+            Claim::Handled => Ok(true),
+            Claim::Ready(job, _in_flight) => {
+                self.execute_claimed(*job).await?;
+                Ok(true)
+            }
+        }
+    }
+
+    /// Pop up to `max` jobs (one statement) and admit each of them
+    ///
+    /// Empty when nothing may or can be popped. A failure to admit one job
+    /// does not affect the others of the batch.
+    async fn claim(&self, max: usize) -> Result<Vec<Result<Claim>>> {
+        // Paused by an operator (or locked down, draining): jobs stay QUEUED until resumed
+        let claiming = self.drain.enter();
+        let open: Vec<&String> = if self.lockdown.is_engaged() || claiming.is_none() {
+            Vec::new()
+        } else {
+            self.queues
+                .iter()
+                .filter(|queue| !self.pauses.is_paused(queue))
+                .collect()
+        };
+        self.activity.set_paused(open.is_empty());
+        let Some(claiming) = claiming.filter(|_| !open.is_empty()) else {
+            return Ok(Vec::new());
+        };
+
         // Phase 2: Check system throttling before popping job (ADR-002)
         let metrics = self.system_probe.get_metrics().await;
-        if metrics.cpu_usage_percent > CPU_THROTTLE_THRESHOLD {
+        let threshold = self.runtime_config.cpu_throttle_percent();
+        let throttled = metrics.cpu_usage_percent > threshold;
+        self.activity.set_throttled(throttled);
+        if throttled {
             warn!(
                 cpu_usage = %metrics.cpu_usage_percent,
-                threshold = %CPU_THROTTLE_THRESHOLD,
+                threshold = %threshold,
                 "System throttling: CPU > threshold, skipping job processing"
             );
-            return Ok(false); // Don't process, system is overloaded
+            return Ok(Vec::new()); // Don't process, system is overloaded
         }
 
-        // Pop next job (already atomically set to RUNNING in DB)
-        let mut job = match self.job_repo.pop_next(&self.queue).await? {
-            Some(j) => j,
-            None => return Ok(false), // No job available
-        };
+        // Pop next jobs (already atomically set to RUNNING in DB)
+        let jobs = self.pop_any(&open, max).await?;
+        let mut claims = Vec::with_capacity(jobs.len());
+        for job in jobs {
+            claims.push(self.admit(job, &claiming).await);
+        }
+        Ok(claims)
+    }
+
+    /// Checks between pop and execution slot: expiry, scheduling conditions, services
+    async fn admit(&self, mut job: Job, claiming: &InFlight) -> Result<Claim> {
+        // Expired jobs are finalized without running (distinct from FAILED)
+        if let Some(expired_state) = self.expired_state(&job) {
+            let now = self.time_provider.now_millis();
+            warn!(job_id = %job.id, state = %expired_state, "Job expired before execution");
+            self.job_repo
+                .update_state(&job.id, expired_state.clone(), Some(now))
+                .await?;
+            self.interceptors.on_complete(&job, &expired_state).await;
+            return Ok(Claim::Handled);
+        }
 
         // Phase 3: Check if job is ready based on scheduling conditions (ADR-050)
         if !self.scheduler.is_ready(&job).await {
             info!(
                 job_id = %job.id,
+                policy = self.scheduler.name(),
                 "Job not ready due to scheduling conditions, re-queuing"
             );
             // Re-queue the job (set back to QUEUED state)
-            job.state = crate::domain::JobState::Queued;
+            job.state = JobState::Queued;
             job.started_at = None;
-            self.job_repo.update(&job).await?;
-            return Ok(false);
+            self.scheduler.on_deferred(&mut job);
+            // Cancelled right after the pop: nothing left to defer
+            if !self.job_repo.update_if_running(&job).await? {
+                return Ok(Claim::Handled);
+            }
+            return Ok(Claim::Idle);
         }
 
         info!("Processing job: {} ({})", job.id, job.job_type.as_str());
+        self.interceptors.on_pop(&job).await;
+
+        // Services run outside the execution slots, restarted whenever they exit
+        if job.is_service() {
+            let supervisor = ServiceSupervisor {
+                job_repo: Arc::clone(&self.job_repo),
+                task_executor: Arc::clone(&self.task_executor),
+                retry_policy: self.retry_policies.for_job_type(job.job_type.as_str()),
+                time_provider: Arc::clone(&self.time_provider),
+                interceptors: Arc::clone(&self.interceptors),
+                running_jobs: Arc::clone(&self.running_jobs),
+            };
+            supervisor.spawn(
+                job,
+                &mut self.services.lock().unwrap_or_else(|e| e.into_inner()),
+            );
+            return Ok(Claim::Handled);
+        }
+
+        self.scheduler.on_started(&job);
+        Ok(Claim::Ready(Box::new(job), claiming.clone()))
+    }
 
+    /// Execute a claimed job in a slot and record its outcome
+    async fn execute_claimed(&self, mut job: Job) -> Result<()> {
         // Execute job with panic isolation (ADR-002: Worker panic must not kill daemon)
         // Using tokio::task::spawn to isolate panics
         //
         // Optimization: Use Arc to avoid cloning large payloads
+        // Retries of a job with fallbacks run the attempt's alternate payload
+        let primary_payload = job.use_attempt_payload();
         let job_arc = Arc::new(job);
         let job_for_exec = Arc::clone(&job_arc);
         let task_executor = Arc::clone(&self.task_executor);
 
-        let handle = tokio::task::spawn(async move {
-            // Execute directly without creating new Worker
-            Self::execute_job_static(&task_executor, &job_for_exec).await
+        let slot = self.activity.occupy_slot(SlotOccupant {
+            job_id: job_arc.id.clone(),
+            job_type: job_arc.job_type.as_str().to_string(),
+            subject_key: job_arc.subject_key.clone(),
+            since: self.time_provider.now_millis(),
         });
+        let leased = self.acquire_lease(&job_arc.id).await;
+        let span = execution_span(&job_arc);
+        let execution_started = Instant::now();
+        let handle = tokio::task::spawn(
+            async move {
+                // Execute directly without creating new Worker
+                Self::execute_job_static(&task_executor, &job_for_exec).await
+            }
+            .instrument(span),
+        );
+        self.running_jobs
+            .register(&job_arc.id, handle.abort_handle());
 
         // Await the spawned task - panics will be caught by JoinHandle
-        let execution_result = handle.await;
+        let execution_result = match self.lease.as_ref().filter(|_| leased) {
+            Some(lease) => self.renew_while_running(lease, &job_arc.id, handle).await,
+            None => handle.await,
+        };
+        let execution_time = execution_started.elapsed();
+        let stopped = self.running_jobs.finish(&job_arc.id);
+        self.activity.free_slot(slot);
+        self.scheduler.on_finished(&job_arc);
+
+        // Cancelled (and killed) meanwhile: the job stays CANCELLED
+        if stopped == Some(StopReason::Cancelled) {
+            info!(job_id = %job_arc.id, "Job cancelled during execution");
+            if leased {
+                self.job_repo.delete_lease(&job_arc.id).await?;
+            }
+            self.metrics.record_execution(
+                &job_arc.queue,
+                job_arc.job_type.as_str(),
+                &JobState::Cancelled,
+                execution_time,
+            );
+            return Ok(());
+        }
+
+        // Interrupted by shutdown: back to the queue, the attempt does not count
+        if stopped == Some(StopReason::Shutdown) {
+            let mut job = Arc::try_unwrap(job_arc).unwrap_or_else(|arc| (*arc).clone());
+            if let Some(primary) = primary_payload {
+                job.payload = primary;
+            }
+            job.state = JobState::Queued;
+            job.started_at = None;
+            // Cancelled just before the interrupt: it stays CANCELLED
+            if self.job_repo.update_if_running(&job).await? {
+                self.job_repo
+                    .record_event(&JobEvent::new(
+                        job.id.clone(),
+                        JobState::Queued,
+                        actor::SHUTDOWN,
+                        Some("interrupted by daemon shutdown".to_string()),
+                        self.time_provider.now_millis(),
+                    ))
+                    .await?;
+                info!(job_id = %job.id, "Job interrupted by shutdown, requeued");
+            }
+            if leased {
+                self.job_repo.delete_lease(&job.id).await?;
+            }
+            return Ok(());
+        }
+
+        // Reclaimed by the lease reaper meanwhile: the job is someone else's now
+        if leased && !self.holds_lease(&job_arc.id).await? {
+            warn!(job_id = %job_arc.id, "Lease lost during execution, result discarded");
+            return Ok(());
+        }
+
+        // Record attempt provenance before deciding the outcome (success or not)
+        let mut log_path = None;
+        let mut result_summary = None;
+        let mut output = None;
+        if let Ok(Ok(result)) = &execution_result {
+            result_summary = Some(self.record_attempt(&job_arc, result).await);
+            log_path = result.log_path.clone();
+            output = Some((
+                result.stdout.as_deref().map(SubjectResult::hash_output),
+                result.duration_ms,
+            ));
+        }
+        let execution_result = execution_result.map(|r| r.and_then(Self::check_status));
 
         // Extract job from Arc for mutation (try_unwrap to avoid clone if possible)
         let mut job = Arc::try_unwrap(job_arc).unwrap_or_else(|arc| (*arc).clone()); // Fallback to clone if still referenced
 
+        // Keep the recorded log path when the job is fully updated (retry)
+        if log_path.is_some() {
+            job.log_path = log_path;
+        }
+        // Same for the attempt manifest, or a retry would write back the old one
+        if result_summary.is_some() {
+            job.result_summary = result_summary;
+        }
+        if let Some(primary) = primary_payload {
+            job.payload = primary;
+        }
+
         // Update job based on result (with retry logic - Phase 2, ADR-002)
         use crate::application::retry::RetryDecision;
+        let retry_policy = self.retry_policies.for_job_type(job.job_type.as_str());
 
-        match execution_result {
+        let final_state = match execution_result {
             Ok(Ok(_)) => {
                 // Task succeeded
                 let now = self.time_provider.now_millis();
                 info!("Job completed: {}", job.id);
                 // Optimization: Partial update (only state + finished_at)
                 self.job_repo
-                    .update_state(&job.id, crate::domain::JobState::Done, Some(now))
+                    .update_state(&job.id, JobState::Done, Some(now))
+                    .await?;
+                if let Some((result_hash, duration_ms)) = output {
+                    self.record_subject_result(&job, result_hash, duration_ms, now)
+                        .await;
+                }
+                JobState::Done
+            }
+            Ok(Err(e)) if retry_policy.is_deadline_exceeded(&job) => {
+                // Deadline hit during execution: retrying cannot succeed
+                warn!(job_id = %job.id, error = %e, "Job exceeded its deadline");
+                let now = self.time_provider.now_millis();
+                self.job_repo
+                    .update_state(&job.id, JobState::SkippedDeadline, Some(now))
+                    .await?;
+                JobState::SkippedDeadline
+            }
+            Ok(Err(e)) if Self::is_fatal(&e) => {
+                // Fatal errors fail the same way every time: skip the retry policy
+                error!(job_id = %job.id, error = %e, "Job failed with a fatal error, not retried");
+                let now = self.time_provider.now_millis();
+                self.job_repo
+                    .update_state(&job.id, JobState::Failed, Some(now))
+                    .await?;
+                self.job_repo
+                    .record_event(
+                        &retry_policy
+                            .gave_up_event(&job, &format!("fatal error, not retried: {}", e)),
+                    )
                     .await?;
+                self.record_dead_letter(&job, &e.to_string(), now).await;
+                JobState::Failed
             }
             Ok(Err(e)) => {
                 // Task failed gracefully - check if we should retry
-                match self.retry_policy.should_retry(&job) {
+                match retry_policy.should_retry(&job) {
                     RetryDecision::Retry(delay_ms) => {
                         info!(
                             job_id = %job.id,
@@ -197,36 +794,250 @@ impl Worker {
                             "Retrying job after failure"
                         );
 
-                        self.retry_policy.prepare_for_retry(&mut job);
-                        // Full update needed (state, attempts, schedule_at all change)
-                        self.job_repo.update(&job).await?;
+                        retry_policy.prepare_for_retry(&mut job, delay_ms);
+                        // Full update needed (state, attempts, next_attempt_at all change),
+                        // unless a cancel raced the execution's end
+                        if self.job_repo.update_if_running(&job).await? {
+                            if let Some(event) = retry_policy.fallback_event(&job) {
+                                self.job_repo.record_event(&event).await?;
+                            }
+                            JobState::Queued
+                        } else {
+                            info!(job_id = %job.id, "Job cancelled during execution, not retried");
+                            JobState::Cancelled
+                        }
                     }
                     RetryDecision::Failed => {
                         error!("Job failed {} after max retries: {}", job.id, e);
                         let now = self.time_provider.now_millis();
                         // Optimization: Partial update (only state + finished_at)
                         self.job_repo
-                            .update_state(&job.id, crate::domain::JobState::Failed, Some(now))
+                            .update_state(&job.id, JobState::Failed, Some(now))
                             .await?;
+                        self.record_dead_letter(&job, &e.to_string(), now).await;
+                        JobState::Failed
+                    }
+                    RetryDecision::RetryDeadlineExceeded(reason) => {
+                        error!(job_id = %job.id, error = %e, "Job failed, {}", reason);
+                        let now = self.time_provider.now_millis();
+                        self.job_repo
+                            .update_state(&job.id, JobState::Failed, Some(now))
+                            .await?;
+                        self.job_repo
+                            .record_event(&retry_policy.gave_up_event(&job, &reason))
+                            .await?;
+                        self.record_dead_letter(&job, &e.to_string(), now).await;
+                        JobState::Failed
                     }
                 }
             }
-            Err(join_err) => {
-                // Task panicked or was cancelled (non-retryable)
-                if join_err.is_panic() {
-                    error!("Job panicked {}: {:?}", job.id, join_err);
+            Err(join_err) if join_err.is_panic() => {
+                // Panics are retried apart from attempts until the job is poisoned
+                error!("Job panicked {}: {:?}", job.id, join_err);
+                job.panic_count += 1;
+                if retry_policy.is_poisoned(&job) {
+                    self.quarantine(&mut job, &join_err.to_string()).await?
                 } else {
-                    error!("Job cancelled {}: {:?}", job.id, join_err);
+                    retry_policy.prepare_for_panic_retry(&mut job);
+                    if self.job_repo.update_if_running(&job).await? {
+                        JobState::Queued
+                    } else {
+                        info!(job_id = %job.id, "Job cancelled during execution, not retried");
+                        JobState::Cancelled
+                    }
                 }
+            }
+            Err(join_err) => {
+                // Task was cancelled (non-retryable)
+                error!("Job cancelled {}: {:?}", job.id, join_err);
                 let now = self.time_provider.now_millis();
                 // Optimization: Partial update (only state + finished_at)
                 self.job_repo
-                    .update_state(&job.id, crate::domain::JobState::Failed, Some(now))
+                    .update_state(&job.id, JobState::Failed, Some(now))
                     .await?;
+                self.record_dead_letter(&job, &join_err.to_string(), now)
+                    .await;
+                JobState::Failed
+            }
+        };
+
+        if leased {
+            self.job_repo.delete_lease(&job.id).await?;
+        }
+        self.metrics.record_execution(
+            &job.queue,
+            job.job_type.as_str(),
+            &final_state,
+            execution_time,
+        );
+        self.interceptors.on_complete(&job, &final_state).await;
+        Ok(())
+    }
+
+    /// Store the lease of a job about to execute (false = run unleased)
+    async fn acquire_lease(&self, job_id: &JobId) -> bool {
+        let Some(lease) = &self.lease else {
+            return false;
+        };
+        let now = self.time_provider.now_millis();
+        let lease = JobLease::new(
+            job_id.clone(),
+            lease.worker_id.clone(),
+            now,
+            lease.duration.as_millis() as i64,
+        );
+        match self.job_repo.insert_lease(&lease).await {
+            Ok(()) => true,
+            Err(e) => {
+                // Crash recovery still finds the job at the next start
+                warn!(job_id = %job_id, error = %e, "Failed to lease job, running it unleased");
+                false
             }
         }
-        Ok(true)
     }
+
+    /// Await an execution, renewing its lease every third of the lease duration
+    ///
+    /// Renewals stop with the execution, or when this future is dropped
+    /// (the worker died), so the lease runs out and the reaper steps in.
+    async fn renew_while_running<T>(
+        &self,
+        lease: &WorkerLease,
+        job_id: &JobId,
+        handle: tokio::task::JoinHandle<T>,
+    ) -> std::result::Result<T, tokio::task::JoinError> {
+        let every = (lease.duration / 3).max(Duration::from_millis(1));
+        let mut renewals = interval_at(tokio::time::Instant::now() + every, every);
+        let mut renewing = true;
+        tokio::pin!(handle);
+        loop {
+            tokio::select! {
+                finished = &mut handle => return finished,
+                _ = renewals.tick(), if renewing => {
+                    let expires_at =
+                        self.time_provider.now_millis() + lease.duration.as_millis() as i64;
+                    match self
+                        .job_repo
+                        .renew_lease(job_id, &lease.worker_id, expires_at, None)
+                        .await
+                    {
+                        Ok(Some(_)) => {}
+                        Ok(None) => {
+                            warn!(job_id = %job_id, "Lease lost during execution");
+                            renewing = false;
+                        }
+                        Err(e) => warn!(job_id = %job_id, error = %e, "Failed to renew lease"),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Whether this worker still holds the lease of a job
+    async fn holds_lease(&self, job_id: &JobId) -> Result<bool> {
+        let Some(lease) = &self.lease else {
+            return Ok(false);
+        };
+        Ok(self
+            .job_repo
+            .find_lease(job_id)
+            .await?
+            .is_some_and(|held| held.is_held_by(&lease.worker_id)))
+    }
+    /// Pop up to `max` jobs from the open queues, preferring them by smooth
+    /// weighted round-robin
+    ///
+    /// Every open queue earns its weight in credits per popped job, the queue
+    /// that delivered pays the sum of the open weights. Queues are tried in
+    /// order of credits, so with work everywhere each gets its weighted share,
+    /// and an empty queue's turn goes to the next one. A batch (`max` > 1)
+    /// comes from a single queue.
+    async fn pop_any(&self, open: &[&String], max: usize) -> Result<Vec<Job>> {
+        let is_open = |index: usize| open.contains(&&self.queues[index]);
+        let weight = |index: usize| i64::from(self.weights[index]);
+        let credits = self
+            .credits
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        let mut order: Vec<usize> = (0..self.queues.len()).filter(|&i| is_open(i)).collect();
+        order.sort_by_key(|&index| std::cmp::Reverse(credits[index] + weight(index)));
+        let preferred: Vec<&str> = order.iter().map(|&i| self.queues[i].as_str()).collect();
+
+        let pop_started = Instant::now();
+        let jobs = if max <= 1 {
+            self.job_repo
+                .pop_next_multi(&preferred)
+                .await?
+                .into_iter()
+                .collect()
+        } else {
+            let mut jobs = Vec::new();
+            for queue in &preferred {
+                jobs = self.job_repo.pop_batch(queue, max).await?;
+                if !jobs.is_empty() {
+                    break;
+                }
+            }
+            jobs
+        };
+        let label = match jobs.first() {
+            Some(job) => job.queue.clone(),
+            None => self.queue_label(),
+        };
+        self.metrics
+            .record_pop(&label, pop_started.elapsed(), !jobs.is_empty());
+
+        if let Some(job) = jobs.first() {
+            if let Some(winner) = self.queues.iter().position(|queue| *queue == job.queue) {
+                let popped = jobs.len() as i64;
+                let total: i64 = order.iter().map(|&i| weight(i)).sum();
+                let mut credits = self.credits.lock().unwrap_or_else(|e| e.into_inner());
+                for &index in &order {
+                    credits[index] += weight(index) * popped;
+                }
+                credits[winner] -= total * popped;
+            }
+        }
+        Ok(jobs)
+    }
+
+    /// Queue names for logs and cycle metrics (e.g. "default,build")
+    fn queue_label(&self) -> String {
+        self.queues.join(",")
+    }
+
+    /// Fail a poisoned job with a warning event instead of retrying it
+    ///
+    /// Returns the job's final state: CANCELLED if a cancel came first.
+    async fn quarantine(&self, job: &mut Job, panic: &str) -> Result<JobState> {
+        let now = self.time_provider.now_millis();
+        let reason = format!(
+            "poisoned: panicked {} times, quarantined instead of retried",
+            job.panic_count
+        );
+        warn!(job_id = %job.id, panics = job.panic_count, "Job poisoned, quarantined");
+
+        job.state = JobState::Failed;
+        job.finished_at = Some(now);
+        if !self.job_repo.update_if_running(job).await? {
+            info!(job_id = %job.id, "Job cancelled during execution, not quarantined");
+            return Ok(JobState::Cancelled);
+        }
+        self.job_repo
+            .record_event(&JobEvent::new(
+                job.id.clone(),
+                JobState::Failed,
+                actor::QUARANTINE,
+                Some(reason.clone()),
+                now,
+            ))
+            .await?;
+        self.record_dead_letter(job, &format!("{} (last panic: {})", reason, panic), now)
+            .await;
+        Ok(JobState::Failed)
+    }
+
     /// Execute job with real TaskExecutor (Phase 2)
     /// Static method to avoid unnecessary Worker cloning in spawn
     ///
@@ -234,7 +1045,7 @@ impl Worker {
     async fn execute_job_static(
         task_executor: &Arc<dyn TaskExecutor>,
         job: &Arc<Job>,
-    ) -> Result<()> {
+    ) -> Result<ExecutionResult> {
         use crate::domain::ExecutionMode;
 
         // Check execution mode
@@ -245,16 +1056,125 @@ impl Worker {
 
                 let result = task_executor.execute(job).await?;
 
-                if result.status != crate::port::task_executor::ExecutionStatus::Success {
-                    return Err(crate::error::AppError::Internal(format!(
-                        "Job execution failed: {:?}",
-                        result.status
-                    )));
-                }
+                info!(job_id = %job.id, duration_ms = %result.duration_ms, status = ?result.status, "Job executed");
+                Ok(result)
+            }
+        }
+    }
+
+    /// Expired state for a job that must not run (TTL first, then deadline)
+    fn expired_state(&self, job: &Job) -> Option<JobState> {
+        let retry_policy = self.retry_policies.default_policy();
+        if retry_policy.is_ttl_exceeded(job) {
+            Some(JobState::SkippedTtl)
+        } else if retry_policy.is_deadline_exceeded(job) {
+            Some(JobState::SkippedDeadline)
+        } else {
+            None
+        }
+    }
+
+    /// Map a non-successful execution status to an error
+    fn check_status(result: ExecutionResult) -> Result<()> {
+        if result.status != ExecutionStatus::Success {
+            let message = format!("Job execution failed: {:?}", result.status);
+            if result.error_class == Some(ErrorClass::Fatal) {
+                let message = match result.exit_code {
+                    Some(code) => format!("{} (exit code {})", message, code),
+                    None => message,
+                };
+                return Err(ExecutionError::Fatal(message).into());
+            }
+            return Err(crate::error::AppError::Internal(message));
+        }
+        Ok(())
+    }
+
+    /// The executor said retrying this failure cannot help
+    fn is_fatal(error: &crate::error::AppError) -> bool {
+        matches!(error, crate::error::AppError::Execution(e) if e.class() == ErrorClass::Fatal)
+    }
+
+    /// Persist the attempt record (exit code, duration, environment manifest)
+    ///
+    /// Best-effort: a failure here must not change the job outcome. Returns
+    /// the recorded summary.
+    async fn record_attempt(&self, job: &Job, result: &ExecutionResult) -> String {
+        let summary = serde_json::json!({
+            "attempt": job.attempts,
+            "payload_index": job.attempt_payload_index(),
+            "status": format!("{:?}", result.status),
+            "exit_code": result.exit_code,
+            "duration_ms": result.duration_ms,
+            "environment": result.environment,
+        })
+        .to_string();
 
-                info!(job_id = %job.id, duration_ms = %result.duration_ms, "Job executed successfully");
-                Ok(())
+        if let Err(e) = self.job_repo.update_result_summary(&job.id, &summary).await {
+            warn!(job_id = %job.id, error = %e, "Failed to record attempt summary");
+        }
+
+        if let Some(log_path) = &result.log_path {
+            if let Err(e) = self.job_repo.update_log_path(&job.id, log_path).await {
+                warn!(job_id = %job.id, error = %e, "Failed to record log path");
             }
         }
+
+        summary
+    }
+
+    /// Park a job that failed for good in the dead-letter queue (best-effort)
+    async fn record_dead_letter(&self, job: &Job, error: &str, now: i64) {
+        let letter = DeadLetter::new(job, error, now);
+        if let Err(e) = self.job_repo.record_dead_letter(&letter).await {
+            warn!(job_id = %job.id, error = %e, "Failed to record dead letter");
+        }
+    }
+
+    /// Append a successful run to its subject's history (best effort)
+    async fn record_subject_result(
+        &self,
+        job: &Job,
+        result_hash: Option<String>,
+        duration_ms: i64,
+        finished_at: i64,
+    ) {
+        if self.subject_history_len == 0 {
+            return;
+        }
+        let result = SubjectResult {
+            subject_key: job.subject_key.clone(),
+            generation: job.generation,
+            job_id: job.id.clone(),
+            result_hash,
+            duration_ms,
+            finished_at,
+        };
+        if let Err(e) = self
+            .job_repo
+            .record_subject_result(&result, self.subject_history_len)
+            .await
+        {
+            warn!(job_id = %job.id, error = %e, "Failed to record subject history");
+        }
     }
 }
+
+/// Span around one execution, tagged with the trace context supplied at enqueue
+///
+/// `trace_id` / `parent_span_id` carry the caller's W3C traceparent so the
+/// execution (and the subprocess, via `TRACEPARENT`) joins the caller's trace.
+fn execution_span(job: &Job) -> tracing::Span {
+    let trace = job
+        .trace_id
+        .as_deref()
+        .and_then(|value| TraceParent::parse(value).ok());
+
+    info_span!(
+        "job.execute",
+        job_id = %job.id,
+        job_type = job.job_type.as_str(),
+        trace_id = trace.as_ref().map(|t| t.trace_id()),
+        parent_span_id = trace.as_ref().map(|t| t.parent_id()),
+    )
+}
@@ -0,0 +1,197 @@
+// Running Jobs - Registry of the executions in flight
+//
+// Cancelling a RUNNING job only flips its state in the database; the worker
+// keeps executing it. The worker registers every execution here (services
+// included), so a cancel can stop it: a subprocess (found in the executor's
+// `running()`) is killed and confirmed gone, an in-process execution is
+// aborted. The worker then leaves the job CANCELLED instead of recording the
+// failed execution. Shutdown stops the executions left after the grace
+// period the same way, services always, and the worker puts those jobs back
+// in the queue for the next daemon.
+
+use crate::domain::JobId;
+use crate::port::TaskExecutor;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::{AbortHandle, JoinSet};
+use tokio::time::sleep;
+use tracing::{info, warn};
+
+/// How long a killed subprocess may take to be reaped before the cancel
+/// reports it as still alive (2s)
+pub const KILL_CONFIRM_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// How a cancel stopped an execution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Termination {
+    /// Not executing in this worker (queued, leased by an external worker, finished)
+    NotRunning,
+    /// Subprocess killed and reaped
+    Killed { pid: u32 },
+    /// Subprocess signalled but still running after KILL_CONFIRM_TIMEOUT
+    StillAlive { pid: u32 },
+    /// Execution without a subprocess aborted
+    Aborted,
+}
+
+impl Termination {
+    /// The execution is known to be over
+    pub fn is_terminated(&self) -> bool {
+        matches!(self, Self::Killed { .. } | Self::Aborted)
+    }
+
+    pub fn pid(&self) -> Option<u32> {
+        match self {
+            Self::Killed { pid } | Self::StillAlive { pid } => Some(*pid),
+            Self::NotRunning | Self::Aborted => None,
+        }
+    }
+}
+
+/// Why an execution was stopped before it finished
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    /// The job was cancelled (it stays CANCELLED)
+    Cancelled,
+    /// The daemon shuts down (the job is requeued)
+    Shutdown,
+}
+
+struct Execution {
+    abort: AbortHandle,
+    stopped: Option<StopReason>,
+}
+
+/// Executions of a worker, by job
+pub struct RunningJobs {
+    task_executor: Arc<dyn TaskExecutor>,
+    executions: Mutex<HashMap<JobId, Execution>>,
+}
+
+impl RunningJobs {
+    /// Registry killing subprocesses through this executor (the worker's)
+    pub fn new(task_executor: Arc<dyn TaskExecutor>) -> Self {
+        Self {
+            task_executor,
+            executions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Track an execution until `finish`
+    pub fn register(&self, job_id: &JobId, abort: AbortHandle) {
+        self.executions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(
+                job_id.clone(),
+                Execution {
+                    abort,
+                    stopped: None,
+                },
+            );
+    }
+
+    /// Stop tracking an execution, returns why it was stopped (None = ran to the end)
+    pub fn finish(&self, job_id: &JobId) -> Option<StopReason> {
+        self.executions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(job_id)
+            .and_then(|execution| execution.stopped)
+    }
+
+    pub fn is_running(&self, job_id: &JobId) -> bool {
+        self.executions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .contains_key(job_id)
+    }
+
+    /// Jobs executing right now
+    pub fn job_ids(&self) -> Vec<JobId> {
+        self.executions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// Stop a cancelled job's execution (kill its subprocess, or abort it)
+    pub async fn cancel(&self, job_id: &JobId) -> Termination {
+        self.stop(job_id, StopReason::Cancelled).await
+    }
+
+    /// Stop every execution because the daemon shuts down (all at once)
+    pub async fn interrupt_all(self: &Arc<Self>) -> Vec<(JobId, Termination)> {
+        let mut stops = JoinSet::new();
+        for job_id in self.job_ids() {
+            let running = Arc::clone(self);
+            stops.spawn(async move {
+                let termination = running.stop(&job_id, StopReason::Shutdown).await;
+                (job_id, termination)
+            });
+        }
+        let mut stopped = Vec::new();
+        while let Some(result) = stops.join_next().await {
+            if let Ok(stop) = result {
+                stopped.push(stop);
+            }
+        }
+        stopped
+    }
+
+    async fn stop(&self, job_id: &JobId, reason: StopReason) -> Termination {
+        let abort = {
+            let mut executions = self.executions.lock().unwrap_or_else(|e| e.into_inner());
+            let Some(execution) = executions.get_mut(job_id) else {
+                return Termination::NotRunning;
+            };
+            execution.stopped.get_or_insert(reason);
+            execution.abort.clone()
+        };
+
+        let process = self
+            .task_executor
+            .running()
+            .into_iter()
+            .find(|process| process.job_id == *job_id);
+        let Some(process) = process else {
+            // No subprocess (in-process execution, or not spawned yet)
+            abort.abort();
+            info!(job_id = %job_id, reason = ?reason, "Execution aborted");
+            return Termination::Aborted;
+        };
+
+        if let Err(e) = self.task_executor.kill(process.pid as i32).await {
+            warn!(job_id = %job_id, pid = process.pid, error = %e, "Failed to kill job");
+        }
+        if self.wait_reaped(job_id).await {
+            info!(job_id = %job_id, pid = process.pid, reason = ?reason, "Job killed");
+            Termination::Killed { pid: process.pid }
+        } else {
+            warn!(job_id = %job_id, pid = process.pid, "Job still running after kill");
+            Termination::StillAlive { pid: process.pid }
+        }
+    }
+
+    /// Wait until the executor no longer lists a process of the job
+    async fn wait_reaped(&self, job_id: &JobId) -> bool {
+        let started = Instant::now();
+        loop {
+            let listed = self
+                .task_executor
+                .running()
+                .iter()
+                .any(|process| process.job_id == *job_id);
+            if !listed {
+                return true;
+            }
+            if started.elapsed() >= KILL_CONFIRM_TIMEOUT {
+                return false;
+            }
+            sleep(Duration::from_millis(20)).await;
+        }
+    }
+}
@@ -0,0 +1,210 @@
+// Service Supervision - Run a service job until its process exits
+//
+// Services never finish on their own, so they run outside the worker's
+// execution slots. Whenever the process exits (any status), the job goes back
+// to QUEUED with a backoff delay, unless it was cancelled or superseded
+// meanwhile. Consecutive quick exits double the delay; a run longer than
+// SERVICE_STABLE_RUN_MS resets it.
+//
+// The execution is registered in the worker's RunningJobs like any other, so
+// a cancel kills the service process and shutdown interrupts it. A service
+// stopped by shutdown goes back to QUEUED at once for the next daemon.
+
+use super::constants::SERVICE_STABLE_RUN_MS;
+use super::execution_span;
+use super::running::{RunningJobs, StopReason};
+use crate::application::interceptor::InterceptorChain;
+use crate::application::retry::RetryPolicy;
+use crate::domain::job_event::actor;
+use crate::domain::{Job, JobEvent, JobState};
+use crate::error::Result;
+use crate::port::{ExecutionError, ExecutionResult, JobRepository, TaskExecutor, TimeProvider};
+use std::sync::Arc;
+use tokio::task::{JoinHandle, JoinSet};
+use tracing::{error, info, warn, Instrument};
+
+type Execution = JoinHandle<std::result::Result<ExecutionResult, ExecutionError>>;
+
+/// Runs one service job to its exit and schedules the restart
+pub(super) struct ServiceSupervisor {
+    pub job_repo: Arc<dyn JobRepository>,
+    pub task_executor: Arc<dyn TaskExecutor>,
+    pub retry_policy: Arc<RetryPolicy>,
+    pub time_provider: Arc<dyn TimeProvider>,
+    pub interceptors: Arc<InterceptorChain>,
+    pub running_jobs: Arc<RunningJobs>,
+}
+
+impl ServiceSupervisor {
+    /// Start the service and supervise it in a task of `services`
+    ///
+    /// The execution is registered before this returns, so a shutdown right
+    /// after the pop still finds it.
+    pub(super) fn spawn(self, job: Job, services: &mut JoinSet<()>) {
+        let started_at = self.time_provider.now_millis();
+        info!(job_id = %job.id, job_type = job.job_type.as_str(), "Service started");
+
+        // Separate task: a panicking executor must not skip the restart
+        let task_executor = self.task_executor.clone();
+        let exec_job = job.clone();
+        let span = execution_span(&job);
+        let execution =
+            tokio::spawn(async move { task_executor.execute(&exec_job).await }.instrument(span));
+        self.running_jobs
+            .register(&job.id, execution.abort_handle());
+
+        services.spawn(self.supervise(job, execution, started_at));
+    }
+
+    /// Wait for the service to exit, then restart or stop it
+    async fn supervise(self, job: Job, execution: Execution, started_at: i64) {
+        let outcome = execution.await;
+        match self.running_jobs.finish(&job.id) {
+            Some(StopReason::Cancelled) => {
+                info!(job_id = %job.id, "Service cancelled, process stopped");
+                return;
+            }
+            Some(StopReason::Shutdown) => {
+                if let Err(e) = self.requeue_interrupted(job.clone()).await {
+                    error!(job_id = %job.id, error = %e, "Failed to requeue interrupted service");
+                }
+                return;
+            }
+            None => {}
+        }
+
+        let exit = match &outcome {
+            Ok(Ok(result)) => {
+                self.record_exit(&job, result).await;
+                match result.exit_code {
+                    Some(code) => format!("exit code {}", code),
+                    None => "killed by signal".to_string(),
+                }
+            }
+            Ok(Err(e)) => e.to_string(),
+            Err(e) if e.is_panic() => "executor panicked".to_string(),
+            Err(_) => "execution cancelled".to_string(),
+        };
+
+        if let Err(e) = self.restart(job.clone(), started_at, &exit).await {
+            error!(job_id = %job.id, error = %e, "Failed to schedule service restart");
+        }
+    }
+
+    /// Put the exited service back in the queue with a backoff delay
+    async fn restart(&self, mut job: Job, started_at: i64, exit: &str) -> Result<()> {
+        let now = self.time_provider.now_millis();
+
+        // Cancelled or superseded while running: stop supervising
+        let current = self.job_repo.find_by_id(&job.id).await?;
+        if !current.is_some_and(|current| current.state == JobState::Running) {
+            info!(job_id = %job.id, exit, "Service stopped");
+            return Ok(());
+        }
+
+        // A newer generation of the subject replaces this service
+        if self
+            .job_repo
+            .get_latest_generation(&job.subject_key)
+            .await?
+            > job.generation
+        {
+            self.job_repo
+                .update_state(&job.id, JobState::Superseded, Some(now))
+                .await?;
+            self.job_repo
+                .record_event(&JobEvent::new(
+                    job.id.clone(),
+                    JobState::Superseded,
+                    actor::SUPERSEDE,
+                    Some(format!(
+                        "service exited ({}), newer generation exists",
+                        exit
+                    )),
+                    now,
+                ))
+                .await?;
+            self.interceptors
+                .on_complete(&job, &JobState::Superseded)
+                .await;
+            info!(job_id = %job.id, exit, "Service superseded, not restarting");
+            return Ok(());
+        }
+
+        let restarts = if now - started_at >= SERVICE_STABLE_RUN_MS {
+            0
+        } else {
+            job.attempts
+        };
+        let delay_ms = self.retry_policy.service_restart_delay_ms(restarts);
+
+        job.attempts = restarts + 1;
+        job.state = JobState::Queued;
+        job.started_at = None;
+        job.pid = None;
+        job.schedule_at = Some(now + delay_ms);
+        // Cancelled since the check above: the cancel wins
+        if !self.job_repo.update_if_running(&job).await? {
+            info!(job_id = %job.id, exit, "Service stopped");
+            return Ok(());
+        }
+        self.job_repo
+            .record_event(&JobEvent::new(
+                job.id.clone(),
+                JobState::Queued,
+                actor::SERVICE,
+                Some(format!("exited ({}), restarting in {} ms", exit, delay_ms)),
+                now,
+            ))
+            .await?;
+        self.interceptors.on_complete(&job, &JobState::Queued).await;
+
+        warn!(job_id = %job.id, exit, delay_ms, restarts = job.attempts, "Service exited, restart scheduled");
+        Ok(())
+    }
+
+    /// Put a service stopped by shutdown back in the queue (no restart delay)
+    async fn requeue_interrupted(&self, mut job: Job) -> Result<()> {
+        job.state = JobState::Queued;
+        job.started_at = None;
+        job.pid = None;
+        if !self.job_repo.update_if_running(&job).await? {
+            return Ok(());
+        }
+        self.job_repo
+            .record_event(&JobEvent::new(
+                job.id.clone(),
+                JobState::Queued,
+                actor::SHUTDOWN,
+                Some("interrupted by daemon shutdown".to_string()),
+                self.time_provider.now_millis(),
+            ))
+            .await?;
+        info!(job_id = %job.id, "Service interrupted by shutdown, requeued");
+        Ok(())
+    }
+
+    /// Record the exit as the job's latest attempt (best-effort)
+    async fn record_exit(&self, job: &Job, result: &ExecutionResult) {
+        let summary = serde_json::json!({
+            "attempt": job.attempts,
+            "status": "Exited",
+            "exit_code": result.exit_code,
+            "duration_ms": result.duration_ms,
+            "environment": result.environment,
+        });
+        if let Err(e) = self
+            .job_repo
+            .update_result_summary(&job.id, &summary.to_string())
+            .await
+        {
+            warn!(job_id = %job.id, error = %e, "Failed to record service exit");
+        }
+
+        if let Some(log_path) = &result.log_path {
+            if let Err(e) = self.job_repo.update_log_path(&job.id, log_path).await {
+                warn!(job_id = %job.id, error = %e, "Failed to record log path");
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// Worker Supervisor - Restart a worker loop that died
+//
+// A worker task that panics (or returns) leaves the daemon up but processing
+// nothing. The supervisor runs the loop in its own task, records every exit
+// in WorkerActivity (restart count in health.check.v1 / admin.stats.v1) and
+// restarts it with backoff once the database answers again.
+
+use super::constants::{WORKER_RESTART_BASE_DELAY, WORKER_RESTART_MAX_DELAY, WORKER_STABLE_RUN};
+use super::{ShutdownToken, Worker};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::task::JoinError;
+use tokio::time::sleep;
+use tracing::{error, info, warn};
+
+/// Runs a worker loop and restarts it whenever it terminates unexpectedly
+pub struct WorkerSupervisor {
+    worker: Arc<Worker>,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl WorkerSupervisor {
+    pub fn new(worker: Arc<Worker>) -> Self {
+        Self {
+            worker,
+            base_delay: WORKER_RESTART_BASE_DELAY,
+            max_delay: WORKER_RESTART_MAX_DELAY,
+        }
+    }
+
+    /// Restart delay: `base` after the first exit, doubling up to `max`
+    pub fn with_backoff(mut self, base: Duration, max: Duration) -> Self {
+        self.base_delay = base;
+        self.max_delay = max.max(base);
+        self
+    }
+
+    /// Supervise until `shutdown` (a loop stopped by shutdown is not restarted)
+    pub async fn run(self, mut shutdown: ShutdownToken) {
+        let mut delay = self.base_delay;
+        loop {
+            let started = Instant::now();
+            let worker = Arc::clone(&self.worker);
+            let token = shutdown.clone();
+            let exit = tokio::spawn(async move { worker.run(token).await }).await;
+            if shutdown.is_shutdown() {
+                return;
+            }
+
+            let reason = exit_reason(exit);
+            let activity = &self.worker.activity;
+            let orphaned: Vec<String> = activity
+                .slots()
+                .into_iter()
+                .flatten()
+                .map(|occupant| occupant.job_id)
+                .collect();
+            activity.record_exit(self.worker.time_provider.now_millis(), &reason);
+            error!(
+                queue = %self.worker.queue_label(),
+                reason = %reason,
+                restarts = activity.restarts(),
+                orphaned_jobs = ?orphaned,
+                "Worker loop terminated unexpectedly, no jobs are processed until it restarts"
+            );
+
+            // A loop that ran for a while crashed for a new reason: start over
+            if started.elapsed() >= WORKER_STABLE_RUN {
+                delay = self.base_delay;
+            }
+            if !self.wait_until_healthy(delay, &mut shutdown).await {
+                return;
+            }
+            delay = (delay * 2).min(self.max_delay);
+
+            activity.record_restart();
+            info!(
+                queue = %self.worker.queue_label(),
+                restarts = activity.restarts(),
+                "Worker loop restarted"
+            );
+        }
+    }
+
+    /// Sleep `delay`, then keep backing off until the database answers
+    ///
+    /// Returns false if shutdown was requested meanwhile.
+    async fn wait_until_healthy(&self, delay: Duration, shutdown: &mut ShutdownToken) -> bool {
+        let mut delay = delay;
+        loop {
+            tokio::select! {
+                _ = sleep(delay) => {},
+                _ = shutdown.wait() => return false,
+            }
+            match self.worker.job_repo.ping().await {
+                Ok(()) => return true,
+                Err(e) => {
+                    warn!(error = %e, "Database unhealthy, worker restart postponed");
+                    delay = (delay * 2).min(self.max_delay);
+                }
+            }
+        }
+    }
+}
+
+/// Why the worker task ended (panic message, error, or a plain return)
+fn exit_reason(exit: Result<crate::error::Result<()>, JoinError>) -> String {
+    match exit {
+        Ok(Ok(())) => "worker loop returned".to_string(),
+        Ok(Err(e)) => format!("worker loop failed: {}", e),
+        Err(e) if e.is_panic() => {
+            let panic = e.into_panic();
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            format!("worker loop panicked: {}", message)
+        }
+        Err(e) => format!("worker task aborted: {}", e),
+    }
+}
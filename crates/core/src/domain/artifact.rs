@@ -0,0 +1,61 @@
+// Artifact Domain Model
+// A file registered for a job (e.g. a failure bundle) with what is known
+// about its content. The URI is the storage backend's address.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Artifact {
+    pub uri: String,
+    /// None for artifacts registered before metadata was recorded
+    pub size_bytes: Option<u64>,
+    /// SHA-256 of the content (hex)
+    pub checksum: Option<String>,
+    pub created_at: Option<i64>, // epoch ms
+}
+
+impl Artifact {
+    /// Describe `content` stored at `uri`
+    pub fn new(uri: impl Into<String>, content: &[u8], created_at: i64) -> Self {
+        Self {
+            uri: uri.into(),
+            size_bytes: Some(content.len() as u64),
+            checksum: Some(sha256_hex(content)),
+            created_at: Some(created_at),
+        }
+    }
+
+    /// An artifact known only by its URI (registered without metadata)
+    pub fn unknown(uri: impl Into<String>) -> Self {
+        Self {
+            uri: uri.into(),
+            size_bytes: None,
+            checksum: None,
+            created_at: None,
+        }
+    }
+}
+
+pub(crate) fn sha256_hex(content: &[u8]) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_records_size_and_checksum() {
+        let artifact = Artifact::new("/a/b.tar.gz", b"abc", 7);
+        assert_eq!(artifact.size_bytes, Some(3));
+        assert_eq!(
+            artifact.checksum.as_deref(),
+            Some("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+        assert_eq!(artifact.created_at, Some(7));
+    }
+}
@@ -0,0 +1,72 @@
+// Dead Letter Domain Model
+// A job that failed for good (retries exhausted, panicked or not retryable),
+// parked in the dead-letter queue with its last error until an operator
+// requeues or purges it (dlq.*.v1). The job itself stays FAILED.
+
+use super::job::{Job, JobId};
+use serde::{Deserialize, Serialize};
+
+/// Longest error text kept (the full output stays in the job log)
+pub const MAX_DEAD_LETTER_ERROR_LEN: usize = 2000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeadLetter {
+    pub job_id: JobId,
+    pub queue: String,
+    pub job_type: String,
+    pub subject_key: String,
+    /// Attempts made before the job was given up
+    pub attempts: i32,
+    pub last_error: String,
+    pub dead_at: i64, // epoch ms
+}
+
+impl DeadLetter {
+    pub fn new(job: &Job, last_error: &str, dead_at: i64) -> Self {
+        Self {
+            job_id: job.id.clone(),
+            queue: job.queue.clone(),
+            job_type: job.job_type.as_str().to_string(),
+            subject_key: job.subject_key.clone(),
+            attempts: job.attempts,
+            last_error: truncate(last_error, MAX_DEAD_LETTER_ERROR_LEN),
+            dead_at,
+        }
+    }
+}
+
+/// At most `max` bytes, cut at a character boundary
+fn truncate(text: &str, max: usize) -> String {
+    if text.len() <= max {
+        return text.to_string();
+    }
+    let mut end = max;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}…", &text[..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{JobPayload, JobType};
+
+    #[test]
+    fn test_dead_letter_keeps_a_bounded_error() {
+        let job = Job::new_test(
+            "build",
+            JobType::new("BUILD"),
+            "src/lib.rs",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        let letter = DeadLetter::new(&job, &"é".repeat(MAX_DEAD_LETTER_ERROR_LEN), 42);
+        assert_eq!(letter.queue, "build");
+        assert_eq!(letter.job_type, "BUILD");
+        assert!(letter.last_error.len() <= MAX_DEAD_LETTER_ERROR_LEN + '…'.len_utf8());
+        assert!(letter.last_error.ends_with('…'));
+
+        assert_eq!(DeadLetter::new(&job, "exit 1", 42).last_error, "exit 1");
+    }
+}
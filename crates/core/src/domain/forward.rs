@@ -0,0 +1,37 @@
+// Forwarded Job Domain Model
+// Local job relayed to a remote Semantica daemon; the local job stays RUNNING
+// until the remote job reaches a terminal state, which is then mirrored back.
+
+use super::job::{JobId, JobState};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForwardedJob {
+    pub job_id: JobId,
+    /// Remote daemon RPC endpoint
+    pub endpoint: String,
+    pub remote_job_id: String,
+    pub forwarded_at: i64, // epoch ms
+    /// Terminal state reported by the remote (None while in flight or if lost)
+    pub remote_state: Option<JobState>,
+    /// When the remote outcome was mirrored (None = still in flight)
+    pub closed_at: Option<i64>,
+}
+
+impl ForwardedJob {
+    pub fn new(
+        job_id: impl Into<JobId>,
+        endpoint: impl Into<String>,
+        remote_job_id: impl Into<String>,
+        forwarded_at: i64,
+    ) -> Self {
+        Self {
+            job_id: job_id.into(),
+            endpoint: endpoint.into(),
+            remote_job_id: remote_job_id.into(),
+            forwarded_at,
+            remote_state: None,
+            closed_at: None,
+        }
+    }
+}
@@ -19,6 +19,39 @@ pub enum JobState {
     Superseded,
     Cancelled,
     Requeued,
+    /// Expired: waited in the queue longer than its `ttl_ms`
+    SkippedTtl,
+    /// Expired: `deadline` passed before or during execution
+    SkippedDeadline,
+}
+
+impl JobState {
+    /// Parse a state name as displayed (e.g. "QUEUED", "SKIPPED_TTL")
+    pub fn parse(value: &str) -> Result<Self, crate::domain::DomainError> {
+        serde_json::from_value(serde_json::Value::String(value.to_string())).map_err(|_| {
+            crate::domain::DomainError::ValidationError(format!("Unknown job state '{}'", value))
+        })
+    }
+
+    /// TTL- or deadline-expired (terminal, counted separately from Failed)
+    pub fn is_expired(&self) -> bool {
+        matches!(self, JobState::SkippedTtl | JobState::SkippedDeadline)
+    }
+
+    /// Final states: the job will not run (again) without a new enqueue
+    pub const TERMINAL: [JobState; 6] = [
+        JobState::Done,
+        JobState::Failed,
+        JobState::Superseded,
+        JobState::Cancelled,
+        JobState::SkippedTtl,
+        JobState::SkippedDeadline,
+    ];
+
+    /// Final state (one of [`JobState::TERMINAL`])
+    pub fn is_terminal(&self) -> bool {
+        Self::TERMINAL.contains(self)
+    }
 }
 
 /// Execution Mode (Phase 2)
@@ -29,6 +62,20 @@ pub enum ExecutionMode {
     Subprocess,
 }
 
+impl ExecutionMode {
+    /// Parse a stored or requested mode ("IN_PROCESS" / "SUBPROCESS")
+    pub fn parse(value: &str) -> Result<Self, crate::domain::DomainError> {
+        match value {
+            "IN_PROCESS" => Ok(ExecutionMode::InProcess),
+            "SUBPROCESS" => Ok(ExecutionMode::Subprocess),
+            other => Err(crate::domain::DomainError::ValidationError(format!(
+                "Unknown execution mode '{}' (expected IN_PROCESS or SUBPROCESS)",
+                other
+            ))),
+        }
+    }
+}
+
 impl std::fmt::Display for ExecutionMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -38,6 +85,41 @@ impl std::fmt::Display for ExecutionMode {
     }
 }
 
+/// Job Class - what the job's process is expected to do
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum JobClass {
+    /// Runs to completion (retry policy, deadline and TTL apply)
+    #[default]
+    Task,
+    /// Runs indefinitely (watchers, LSP sidecars): exempt from deadline and
+    /// TTL, restarted with backoff whenever its process exits
+    Service,
+}
+
+impl JobClass {
+    /// Parse a stored or requested class ("TASK" / "SERVICE")
+    pub fn parse(value: &str) -> Result<Self, crate::domain::DomainError> {
+        match value {
+            "TASK" => Ok(JobClass::Task),
+            "SERVICE" => Ok(JobClass::Service),
+            other => Err(crate::domain::DomainError::ValidationError(format!(
+                "Unknown job class '{}' (expected TASK or SERVICE)",
+                other
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for JobClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JobClass::Task => write!(f, "TASK"),
+            JobClass::Service => write!(f, "SERVICE"),
+        }
+    }
+}
+
 impl std::fmt::Display for JobState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -48,6 +130,8 @@ impl std::fmt::Display for JobState {
             JobState::Superseded => write!(f, "SUPERSEDED"),
             JobState::Cancelled => write!(f, "CANCELLED"),
             JobState::Requeued => write!(f, "REQUEUED"),
+            JobState::SkippedTtl => write!(f, "SKIPPED_TTL"),
+            JobState::SkippedDeadline => write!(f, "SKIPPED_DEADLINE"),
         }
     }
 }
@@ -89,6 +173,15 @@ impl JobPayload {
     }
 }
 
+/// Last progress reported by a running job (job.report_progress.v1)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobProgress {
+    /// 0-100
+    pub percent: u8,
+    pub message: Option<String>,
+    pub updated_at: i64, // epoch ms
+}
+
 /// Job Entity (Phase 1 + Phase 2 + Phase 3 fields)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Job {
@@ -103,6 +196,10 @@ pub struct Job {
     pub state: JobState,
 
     pub created_at: i64, // epoch ms
+    /// Start of the current wait in the queue, the TTL anchor: the enqueue or
+    /// the last manual retry (None = `created_at`)
+    #[serde(default)]
+    pub queued_at: Option<i64>,
     pub started_at: Option<i64>,
     pub finished_at: Option<i64>,
 
@@ -111,20 +208,31 @@ pub struct Job {
 
     // Phase 2: Execution & Resilience
     pub execution_mode: Option<ExecutionMode>,
+    #[serde(default)]
+    pub job_class: JobClass,
     pub pid: Option<i32>,
     pub env_vars: Option<serde_json::Value>, // JSON
 
     // Phase 2: Retry Logic
     pub attempts: i32,
     pub max_attempts: i32,
+    /// Executions that panicked (not counted in `attempts`, see RetryPolicy::is_poisoned)
+    #[serde(default)]
+    pub panic_count: i32,
     pub backoff_factor: f64,
+    /// Not popped before this time while waiting out a retry backoff (epoch ms)
+    #[serde(default)]
+    pub next_attempt_at: Option<i64>,
+    /// Payloads of successive retries (attempt 2 runs the first, ...)
+    #[serde(default)]
+    pub fallback_payloads: Vec<JobPayload>,
 
     // Phase 2: Timeouts
     pub deadline: Option<i64>, // Epoch ms
     pub ttl_ms: Option<i64>,   // Milliseconds
 
     // Phase 2: Tracing
-    pub trace_id: Option<String>,
+    pub trace_id: Option<String>, // W3C traceparent supplied at enqueue
 
     // Phase 3: Scheduling & Conditions
     pub schedule_at: Option<i64>, // Unix timestamp (ms) when job should run
@@ -138,6 +246,9 @@ pub struct Job {
     pub chain_group_id: Option<String>, // Chain/batch group identifier
     pub result_summary: Option<String>, // JSON result summary
     pub artifacts: Option<String>,      // Comma-separated artifact paths
+    /// Written only by progress reports (cleared at the next pop)
+    #[serde(default)]
+    pub progress: Option<JobProgress>,
 }
 
 impl Job {
@@ -209,6 +320,7 @@ impl Job {
             priority: 0,
             state: JobState::Queued,
             created_at,
+            queued_at: None,
             started_at: None,
             finished_at: None,
             payload,
@@ -216,11 +328,15 @@ impl Job {
 
             // Phase 2 defaults
             execution_mode: Some(ExecutionMode::InProcess), // Default to in-process
+            job_class: JobClass::Task,
             pid: None,
             env_vars: None,
             attempts: 0,
             max_attempts: 3, // Default retry count
+            panic_count: 0,
+            next_attempt_at: None,
             backoff_factor: 2.0,
+            fallback_payloads: Vec::new(),
             deadline: None,
             ttl_ms: None,
             trace_id: None,
@@ -237,9 +353,40 @@ impl Job {
             chain_group_id: None,
             result_summary: None,
             artifacts: None,
+            progress: None,
         }
     }
 
+    /// Index of the payload the current attempt runs (0 = primary)
+    ///
+    /// Retry N runs fallback N; once they run out the last fallback is reused.
+    pub fn attempt_payload_index(&self) -> usize {
+        (self.attempts.max(0) as usize).min(self.fallback_payloads.len())
+    }
+
+    /// Swap in the payload of the current attempt, returning the primary one
+    ///
+    /// None when the attempt runs the primary payload (nothing swapped).
+    pub fn use_attempt_payload(&mut self) -> Option<JobPayload> {
+        match self.attempt_payload_index() {
+            0 => None,
+            i => Some(std::mem::replace(
+                &mut self.payload,
+                self.fallback_payloads[i - 1].clone(),
+            )),
+        }
+    }
+
+    /// Long-running service (see [`JobClass::Service`])
+    pub fn is_service(&self) -> bool {
+        self.job_class == JobClass::Service
+    }
+
+    /// When the job's current wait in the queue started (epoch ms)
+    pub fn queued_since(&self) -> i64 {
+        self.queued_at.unwrap_or(self.created_at)
+    }
+
     /// Transition to Running state with explicit timestamp
     pub fn start(&mut self, now_millis: i64) -> crate::domain::error::Result<()> {
         if self.state != JobState::Queued {
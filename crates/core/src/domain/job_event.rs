@@ -0,0 +1,68 @@
+// Job Event Domain Model
+// Audit trail of state changes made on behalf of someone (cancel, supersede, ...)
+
+use super::job::{JobId, JobState};
+use serde::{Deserialize, Serialize};
+
+/// Well-known actors (free-form strings are allowed, e.g. `sdk:<client_id>`)
+pub mod actor {
+    /// `semantica` CLI
+    pub const CLI: &str = "cli";
+    /// RPC caller that did not identify itself
+    pub const RPC: &str = "rpc";
+    /// Replaced by a newer generation of the same subject
+    pub const SUPERSEDE: &str = "supersede";
+    /// Daemon shutdown
+    pub const SHUTDOWN: &str = "shutdown";
+    /// Lease of an external worker expired without heartbeat
+    pub const LEASE_REAPER: &str = "lease_reaper";
+    /// Relayed to (or mirrored back from) a remote daemon
+    pub const FORWARDER: &str = "forwarder";
+    /// Supervisor of a service job whose process exited
+    pub const SERVICE: &str = "service";
+    /// Retry policy (e.g. switching a retry to a fallback payload)
+    pub const RETRY: &str = "retry";
+    /// Poison-job detection (the job panicked too often)
+    pub const QUARANTINE: &str = "quarantine";
+    /// TTL or deadline passed while the job sat in the queue
+    pub const EXPIRY_SWEEPER: &str = "expiry_sweeper";
+    /// Ran far longer than its job type usually takes
+    pub const STALL_DETECTOR: &str = "stall_detector";
+    /// Application running the engine in-process (semantica-engine)
+    pub const EMBEDDED: &str = "embedded";
+
+    /// Actor string for an SDK client
+    pub fn sdk(client_id: &str) -> String {
+        format!("sdk:{}", client_id)
+    }
+}
+
+/// One recorded state change
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobEvent {
+    pub job_id: JobId,
+    /// State the job moved to
+    pub state: JobState,
+    /// Who or what triggered the change
+    pub actor: String,
+    pub reason: Option<String>,
+    pub created_at: i64, // epoch ms
+}
+
+impl JobEvent {
+    pub fn new(
+        job_id: impl Into<JobId>,
+        state: JobState,
+        actor: impl Into<String>,
+        reason: Option<String>,
+        created_at: i64,
+    ) -> Self {
+        Self {
+            job_id: job_id.into(),
+            state,
+            actor: actor.into(),
+            reason,
+            created_at,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+// Job Lease Domain Model
+// A RUNNING job handed to an external worker; the worker must renew the lease
+// (heartbeat) before it expires, otherwise the lease reaper reclaims the job.
+
+use super::job::JobId;
+use serde::{Deserialize, Serialize};
+
+/// Lease held by an external worker on a RUNNING job
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobLease {
+    pub job_id: JobId,
+    /// Identifier chosen by the worker process (e.g. "py-indexer-1")
+    pub worker_id: String,
+    pub leased_at: i64,    // epoch ms
+    pub expires_at: i64,   // epoch ms
+    pub heartbeat_at: i64, // epoch ms
+    /// Last progress message reported by the worker
+    pub progress: Option<String>,
+}
+
+impl JobLease {
+    pub fn new(
+        job_id: impl Into<JobId>,
+        worker_id: impl Into<String>,
+        now: i64,
+        lease_ms: i64,
+    ) -> Self {
+        Self {
+            job_id: job_id.into(),
+            worker_id: worker_id.into(),
+            leased_at: now,
+            expires_at: now + lease_ms,
+            heartbeat_at: now,
+            progress: None,
+        }
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+
+    pub fn is_held_by(&self, worker_id: &str) -> bool {
+        self.worker_id == worker_id
+    }
+}
@@ -0,0 +1,76 @@
+// Log Line Domain Model
+// One captured output line of a job, tagged with its stream and capture time.
+// Log files store one JSON object per line so stdout and stderr stay separable.
+
+use serde::{Deserialize, Serialize};
+
+/// Output stream a line was captured from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogStream {
+    Stdout,
+    Stderr,
+}
+
+impl LogStream {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogStream::Stdout => "stdout",
+            LogStream::Stderr => "stderr",
+        }
+    }
+}
+
+impl std::fmt::Display for LogStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogLine {
+    pub ts: i64, // epoch ms
+    pub stream: LogStream,
+    pub line: String,
+}
+
+impl LogLine {
+    pub fn new(ts: i64, stream: LogStream, line: impl Into<String>) -> Self {
+        Self {
+            ts,
+            stream,
+            line: line.into(),
+        }
+    }
+
+    /// Serialize as one log file line (JSON, no trailing newline)
+    pub fn to_record(&self) -> String {
+        serde_json::to_string(self).expect("LogLine serialization cannot fail")
+    }
+
+    /// Parse a log file line (None for untagged lines written by older versions)
+    pub fn parse_record(record: &str) -> Option<Self> {
+        serde_json::from_str(record).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_roundtrip() {
+        let line = LogLine::new(42, LogStream::Stderr, "error: boom");
+        let record = line.to_record();
+        assert_eq!(
+            record,
+            r#"{"ts":42,"stream":"stderr","line":"error: boom"}"#
+        );
+        assert_eq!(LogLine::parse_record(&record), Some(line));
+    }
+
+    #[test]
+    fn test_untagged_record_is_not_parsed() {
+        assert_eq!(LogLine::parse_record("plain output"), None);
+    }
+}
@@ -1,12 +1,35 @@
 // Domain Layer - Pure business logic and entities
 
+pub mod artifact;
+pub mod dead_letter;
 pub mod error;
+pub mod forward;
 pub mod job;
+pub mod job_event;
+pub mod lease;
+pub mod log_line;
 pub mod queue;
+pub mod snapshot;
+pub mod subject_history;
+pub mod template;
+pub mod trace;
 
 // Re-exports
+pub use artifact::Artifact;
+pub use dead_letter::DeadLetter;
 pub use error::DomainError;
+pub use forward::ForwardedJob;
 pub use job::{
-    ExecutionMode, Generation, Job, JobId, JobPayload, JobState, JobType, Priority, SubjectKey,
+    ExecutionMode, Generation, Job, JobClass, JobId, JobPayload, JobProgress, JobState, JobType,
+    Priority, SubjectKey,
 };
-pub use queue::QueueId;
+pub use job_event::JobEvent;
+pub use lease::JobLease;
+pub use log_line::{LogLine, LogStream};
+pub use queue::{
+    ExpiredJob, JobTypeStats, MetricsSnapshot, QueueId, QueueStats, QueueWaitSlos, QueueWeights,
+    WaitSloReport,
+};
+pub use snapshot::{QueueSnapshot, SubjectGeneration, SNAPSHOT_FORMAT_VERSION};
+pub use subject_history::SubjectResult;
+pub use trace::TraceParent;
@@ -1,5 +1,8 @@
 // Queue Domain Model
 
+use super::JobId;
+use std::collections::BTreeMap;
+
 /// Queue identifier
 pub type QueueId = String;
 
@@ -18,3 +21,236 @@ impl QueueConfig {
         }
     }
 }
+
+/// Job counts of one queue (admin.queues.list.v1)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct QueueStats {
+    pub queue: QueueId,
+    pub queued: i64,
+    pub running: i64,
+    pub done: i64,
+    pub failed: i64,
+    /// created_at of the oldest due QUEUED job
+    pub oldest_queued_at: Option<i64>,
+    /// DONE + FAILED jobs finished since the requested time
+    pub finished_since: i64,
+}
+
+/// QUEUED job expired by the sweeper (TTL or deadline passed before it was popped)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpiredJob {
+    pub job_id: JobId,
+    pub queue: QueueId,
+    pub job_type: String,
+    /// SKIPPED_TTL or SKIPPED_DEADLINE
+    pub state: super::JobState,
+}
+
+/// Job counts and run time of one job type (admin.stats.v1)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct JobTypeStats {
+    pub job_type: String,
+    pub queued: i64,
+    pub running: i64,
+    pub done: i64,
+    pub failed: i64,
+    /// SKIPPED_TTL + SKIPPED_DEADLINE
+    pub expired: i64,
+    /// Mean started_at -> finished_at of DONE and FAILED jobs (None if none ran)
+    pub avg_duration_ms: Option<f64>,
+}
+
+impl JobTypeStats {
+    /// Share of finished (DONE + FAILED) jobs that failed, 0.0 if none finished
+    pub fn failure_rate(&self) -> f64 {
+        let finished = self.done + self.failed;
+        if finished > 0 {
+            self.failed as f64 / finished as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Throughput over one sampling window (admin.metrics.history.v1)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MetricsSnapshot {
+    /// End of the window (epoch ms)
+    pub taken_at: i64,
+    pub window_ms: i64,
+    /// Jobs that finished DONE / FAILED during the window
+    pub completed: i64,
+    pub failed: i64,
+    /// QUEUED / RUNNING jobs (all queues) at `taken_at`
+    pub queued: i64,
+    pub running: i64,
+}
+
+impl MetricsSnapshot {
+    /// Jobs completed per minute over the window
+    pub fn completed_per_minute(&self) -> f64 {
+        per_minute(self.completed, self.window_ms)
+    }
+
+    /// Jobs failed per minute over the window
+    pub fn failed_per_minute(&self) -> f64 {
+        per_minute(self.failed, self.window_ms)
+    }
+}
+
+fn per_minute(count: i64, window_ms: i64) -> f64 {
+    if window_ms > 0 {
+        count as f64 * 60_000.0 / window_ms as f64
+    } else {
+        0.0
+    }
+}
+
+/// How soon a due job of a queue should start (queue wait SLO targets)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct QueueWaitSlos {
+    targets: BTreeMap<QueueId, i64>,
+}
+
+impl QueueWaitSlos {
+    /// Parse comma-separated `queue:target_ms` entries
+    ///
+    /// # Example
+    /// `interactive:2000,build:600000` - interactive jobs should start within 2s
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let mut slos = Self::default();
+
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let parsed = entry.split_once(':').and_then(|(queue, target)| {
+                let target_ms: i64 = target.trim().parse().ok()?;
+                Some((queue.trim(), target_ms))
+            });
+            let Some((queue, target_ms)) = parsed.filter(|(q, t)| !q.is_empty() && *t > 0) else {
+                return Err(format!(
+                    "Invalid queue wait SLO '{}' (expected queue:target_ms)",
+                    entry
+                ));
+            };
+            slos.targets.insert(queue.to_string(), target_ms);
+        }
+
+        Ok(slos)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    /// Targets (ms) by queue
+    pub fn targets(&self) -> &BTreeMap<QueueId, i64> {
+        &self.targets
+    }
+}
+
+/// Queues one worker serves and their share of its pops
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct QueueWeights {
+    /// Configuration order, weight >= 1
+    entries: Vec<(QueueId, u32)>,
+}
+
+impl QueueWeights {
+    /// Parse comma-separated `queue[:weight]` entries (weight defaults to 1)
+    ///
+    /// # Example
+    /// `default:3,code_intel,build:1` - default gets 3 of every 5 pops while
+    /// all three queues have work
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let mut weights = Self::default();
+
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (queue, weight) = match entry.split_once(':') {
+                Some((queue, weight)) => (queue.trim(), weight.trim().parse().ok()),
+                None => (entry, Some(1)),
+            };
+            let Some(weight) = weight.filter(|&w: &u32| w > 0 && !queue.is_empty()) else {
+                return Err(format!(
+                    "Invalid queue '{}' (expected queue or queue:weight, weight >= 1)",
+                    entry
+                ));
+            };
+            if weights.weight(queue).is_some() {
+                return Err(format!("Queue '{}' is listed twice", queue));
+            }
+            weights.entries.push((queue.to_string(), weight));
+        }
+
+        Ok(weights)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Queue names in configuration order
+    pub fn queues(&self) -> Vec<QueueId> {
+        self.entries
+            .iter()
+            .map(|(queue, _)| queue.clone())
+            .collect()
+    }
+
+    /// Weight of a listed queue
+    pub fn weight(&self, queue: &str) -> Option<u32> {
+        self.entries
+            .iter()
+            .find(|(name, _)| name == queue)
+            .map(|(_, weight)| *weight)
+    }
+}
+
+/// Wait SLO compliance of one queue (admin.stats.v1)
+///
+/// A job's wait runs from when it became due (created, or its `schedule_at`)
+/// until it started, or until now while it is still QUEUED.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WaitSloReport {
+    pub queue: QueueId,
+    pub target_ms: i64,
+    /// Jobs started in the reporting window
+    pub started: i64,
+    /// Of those, jobs that started later than the target
+    pub violations: i64,
+    /// QUEUED jobs already waiting longer than the target
+    pub waiting_over: i64,
+    /// Longest wait among the above (started or still waiting)
+    pub max_wait_ms: Option<i64>,
+    /// Offending jobs, longest wait first (capped)
+    pub job_ids: Vec<JobId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wait_slo_spec() {
+        let slos = QueueWaitSlos::from_spec(" interactive:2000, build:600000 ,").unwrap();
+        assert_eq!(slos.targets()["interactive"], 2000);
+        assert_eq!(slos.targets()["build"], 600_000);
+        assert!(QueueWaitSlos::from_spec("").unwrap().is_empty());
+
+        for invalid in ["interactive", "interactive:2s", ":2000", "build:0"] {
+            assert!(QueueWaitSlos::from_spec(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_queue_weights_spec() {
+        let weights = QueueWeights::from_spec(" default:3, code_intel ,build:1,").unwrap();
+        assert_eq!(weights.queues(), ["default", "code_intel", "build"]);
+        assert_eq!(weights.weight("default"), Some(3));
+        assert_eq!(weights.weight("code_intel"), Some(1));
+        assert_eq!(weights.weight("other"), None);
+        assert!(QueueWeights::from_spec("").unwrap().is_empty());
+
+        for invalid in ["default:0", "default:x", ":2", "default,default:2"] {
+            assert!(QueueWeights::from_spec(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
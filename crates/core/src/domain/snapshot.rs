@@ -0,0 +1,186 @@
+// Queue Snapshot Domain Model
+// Full queue state (jobs + subject generations) captured for reproducing
+// scheduling bugs and seeding test scenarios. Serialized as JSON.
+
+use super::job::{Job, JobPayload, JobState};
+use crate::error::{AppError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+/// Snapshot format understood by this engine
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// Latest generation of a subject (supersede bookkeeping)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubjectGeneration {
+    pub subject_key: String,
+    pub latest_generation: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueueSnapshot {
+    pub format_version: u32,
+    pub taken_at: i64, // epoch ms
+    /// Captured queue (None = every queue)
+    pub queue: Option<String>,
+    pub jobs: Vec<Job>,
+    pub subjects: Vec<SubjectGeneration>,
+    /// Payloads and env_vars replaced by hashes (see `anonymize`)
+    #[serde(default)]
+    pub anonymized: bool,
+}
+
+impl QueueSnapshot {
+    pub fn new(
+        taken_at: i64,
+        queue: Option<String>,
+        jobs: Vec<Job>,
+        subjects: Vec<SubjectGeneration>,
+    ) -> Self {
+        Self {
+            format_version: SNAPSHOT_FORMAT_VERSION,
+            taken_at,
+            queue,
+            jobs,
+            subjects,
+            anonymized: false,
+        }
+    }
+
+    /// Strip payload and env_vars contents so the dump can be shared
+    ///
+    /// Every string becomes `anon:<hash>:<len>`; keys, arrays, numbers,
+    /// booleans and all timing fields are kept. Hashes are salted, so equal
+    /// values stay equal within this snapshot but cannot be guessed.
+    pub fn anonymize(&mut self, salt: &str) {
+        for job in &mut self.jobs {
+            job.payload = JobPayload::new(anonymize_value(job.payload.as_value(), salt));
+            job.env_vars = job.env_vars.as_ref().map(|env| anonymize_value(env, salt));
+        }
+        self.anonymized = true;
+    }
+
+    /// Reject snapshots written by a newer engine
+    pub fn check_version(&self) -> Result<()> {
+        if self.format_version == 0 || self.format_version > SNAPSHOT_FORMAT_VERSION {
+            return Err(AppError::Validation(format!(
+                "Unsupported snapshot format {} (expected <= {})",
+                self.format_version, SNAPSHOT_FORMAT_VERSION
+            )));
+        }
+        Ok(())
+    }
+
+    /// Jobs as they are restored
+    ///
+    /// RUNNING jobs have no worker after a restore, so they go back to QUEUED
+    /// (like crash recovery) instead of staying stuck.
+    pub fn jobs_for_restore(&self) -> Vec<Job> {
+        self.jobs
+            .iter()
+            .cloned()
+            .map(|mut job| {
+                if job.state == JobState::Running {
+                    job.state = JobState::Queued;
+                    job.started_at = None;
+                    job.pid = None;
+                }
+                job
+            })
+            .collect()
+    }
+}
+
+fn anonymize_value(value: &Value, salt: &str) -> Value {
+    match value {
+        Value::String(s) => {
+            let digest = Sha256::new()
+                .chain_update(salt.as_bytes())
+                .chain_update(s.as_bytes())
+                .finalize();
+            let hash: String = digest[..8].iter().map(|b| format!("{:02x}", b)).collect();
+            Value::String(format!("anon:{}:{}", hash, s.len()))
+        }
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| anonymize_value(item, salt))
+                .collect(),
+        ),
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(key, field)| (key.clone(), anonymize_value(field, salt)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{JobPayload, JobType};
+
+    #[test]
+    fn test_running_jobs_are_requeued_on_restore() {
+        let mut job = Job::new_test(
+            "default",
+            JobType::new("BUILD"),
+            "repo/app",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job.state = JobState::Running;
+        job.started_at = Some(10);
+
+        let snapshot = QueueSnapshot::new(20, None, vec![job], vec![]);
+        let jobs = snapshot.jobs_for_restore();
+        assert_eq!(jobs[0].state, JobState::Queued);
+        assert_eq!(jobs[0].started_at, None);
+    }
+
+    #[test]
+    fn test_newer_format_is_rejected() {
+        let mut snapshot = QueueSnapshot::new(0, None, vec![], vec![]);
+        assert!(snapshot.check_version().is_ok());
+        snapshot.format_version = SNAPSHOT_FORMAT_VERSION + 1;
+        assert!(snapshot.check_version().is_err());
+    }
+
+    #[test]
+    fn test_anonymize_keeps_structure_and_timing() {
+        let mut job = Job::new_test(
+            "default",
+            JobType::new("BUILD"),
+            "repo/app",
+            1,
+            JobPayload::new(serde_json::json!({
+                "command": "cargo",
+                "args": ["build", "--release"],
+                "jobs": 4,
+                "token": "cargo",
+            })),
+        );
+        job.env_vars = Some(serde_json::json!({"API_KEY": "s3cr3t"}));
+        let created_at = job.created_at;
+
+        let mut snapshot = QueueSnapshot::new(20, None, vec![job], vec![]);
+        snapshot.anonymize("salt");
+        let job = &snapshot.jobs[0];
+        let payload = job.payload.as_value();
+
+        assert!(snapshot.anonymized);
+        assert_eq!(job.created_at, created_at);
+        assert_eq!(payload["jobs"], 4);
+        assert_eq!(payload["args"].as_array().unwrap().len(), 2);
+        assert!(payload["command"].as_str().unwrap().starts_with("anon:"));
+        assert!(payload["command"].as_str().unwrap().ends_with(":5"));
+        // Same value, same hash within one snapshot
+        assert_eq!(payload["command"], payload["token"]);
+        let env = job.env_vars.as_ref().unwrap().to_string();
+        assert!(!env.contains("s3cr3t"));
+        assert!(env.contains("API_KEY"));
+    }
+}
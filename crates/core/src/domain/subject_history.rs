@@ -0,0 +1,25 @@
+// Subject History Domain Model
+// One successful completion of a subject (subject.history.v1). The result
+// hash identifies the job's output, so two runs of the same subject can be
+// compared without storing the output itself.
+
+use super::artifact::sha256_hex;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubjectResult {
+    pub subject_key: String,
+    pub generation: i64,
+    pub job_id: String,
+    /// SHA-256 of the job's stdout (hex), None if the executor captured none
+    pub result_hash: Option<String>,
+    pub duration_ms: i64,
+    pub finished_at: i64, // epoch ms
+}
+
+impl SubjectResult {
+    /// Hash identifying an output (stable across runs with the same output)
+    pub fn hash_output(output: &str) -> String {
+        sha256_hex(output.as_bytes())
+    }
+}
@@ -0,0 +1,206 @@
+// Payload Templates
+// String values in a job payload may reference `{{name}}` variables, expanded
+// by the executor right before the job runs, so one template such as
+// {"command":"indexer","args":["{{subject_key}}"]} serves thousands of files.
+//
+// Built-in variables: subject_key, job_id, job_type, queue, generation.
+// Extra variables come from the payload's "vars" object (string values).
+
+use super::error::{DomainError, Result};
+use super::job::Job;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Payload key holding enqueue-provided variables
+pub const VARS_KEY: &str = "vars";
+
+/// Variables every job provides (they take precedence over "vars")
+pub const BUILTIN_VARS: &[&str] = &["subject_key", "job_id", "job_type", "queue", "generation"];
+
+/// Payload of `job` with every `{{name}}` reference expanded
+///
+/// The "vars" object itself is left as is. Fails on unknown variables.
+pub fn expand_payload(job: &Job) -> Result<Value> {
+    let payload = job.payload.as_value();
+    let mut vars = user_vars(payload);
+    vars.insert("subject_key".to_string(), job.subject_key.clone());
+    vars.insert("job_id".to_string(), job.id.clone());
+    vars.insert("job_type".to_string(), job.job_type.as_str().to_string());
+    vars.insert("queue".to_string(), job.queue.clone());
+    vars.insert("generation".to_string(), job.generation.to_string());
+
+    match payload {
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, value)| {
+                let value = if key == VARS_KEY {
+                    value.clone()
+                } else {
+                    expand_value(value, &vars)?
+                };
+                Ok((key.clone(), value))
+            })
+            .collect::<Result<_>>()
+            .map(Value::Object),
+        other => expand_value(other, &vars),
+    }
+}
+
+/// Variables referenced by `payload` that neither "vars" nor the built-ins define
+pub fn unknown_variables(payload: &Value) -> Vec<String> {
+    let vars = user_vars(payload);
+    let mut names = Vec::new();
+    collect_references(payload, &mut names);
+
+    let mut unknown: Vec<String> = names
+        .into_iter()
+        .filter(|name| !vars.contains_key(name) && !BUILTIN_VARS.contains(&name.as_str()))
+        .collect();
+    unknown.sort();
+    unknown.dedup();
+    unknown
+}
+
+fn user_vars(payload: &Value) -> HashMap<String, String> {
+    payload
+        .get(VARS_KEY)
+        .and_then(|v| v.as_object())
+        .map(|obj| {
+            obj.iter()
+                .filter_map(|(k, v)| v.as_str().map(|s| (k.clone(), s.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn expand_value(value: &Value, vars: &HashMap<String, String>) -> Result<Value> {
+    Ok(match value {
+        Value::String(s) => Value::String(expand_str(s, vars)?),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| expand_value(item, vars))
+                .collect::<Result<_>>()?,
+        ),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| Ok((k.clone(), expand_value(v, vars)?)))
+                .collect::<Result<_>>()?,
+        ),
+        other => other.clone(),
+    })
+}
+
+fn expand_str(s: &str, vars: &HashMap<String, String>) -> Result<String> {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some((before, name, after)) = next_reference(rest) {
+        let value = vars.get(name).ok_or_else(|| {
+            DomainError::ValidationError(format!("Unknown template variable '{}'", name))
+        })?;
+        out.push_str(before);
+        out.push_str(value);
+        rest = after;
+    }
+
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn collect_references(value: &Value, names: &mut Vec<String>) {
+    match value {
+        Value::String(s) => {
+            let mut rest = s.as_str();
+            while let Some((_, name, after)) = next_reference(rest) {
+                names.push(name.to_string());
+                rest = after;
+            }
+        }
+        Value::Array(items) => items
+            .iter()
+            .for_each(|item| collect_references(item, names)),
+        Value::Object(map) => map
+            .iter()
+            .filter(|(key, _)| key.as_str() != VARS_KEY)
+            .for_each(|(_, v)| collect_references(v, names)),
+        _ => {}
+    }
+}
+
+/// Split at the next `{{name}}` reference: (text before, name, text after)
+///
+/// Braces around anything but a plain identifier are left as literal text.
+fn next_reference(s: &str) -> Option<(&str, &str, &str)> {
+    let mut offset = 0;
+    loop {
+        let start = offset + s[offset..].find("{{")?;
+        let end = start + 2 + s[start + 2..].find("}}")?;
+        let name = s[start + 2..end].trim();
+
+        if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Some((&s[..start], name, &s[end + 2..]));
+        }
+        offset = start + 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{JobPayload, JobType};
+    use serde_json::json;
+
+    fn job(payload: Value) -> Job {
+        Job::new(
+            "job-1".to_string(),
+            0,
+            "default".to_string(),
+            JobType::new("INDEX_FILE"),
+            "src/main.rs".to_string(),
+            3,
+            JobPayload::new(payload),
+        )
+    }
+
+    #[test]
+    fn test_expands_builtins_and_vars() {
+        let payload = job(json!({
+            "command": "indexer",
+            "args": ["--root", "{{ root }}", "{{subject_key}}", "gen={{generation}}"],
+            "env": {"JOB": "{{job_id}}"},
+            "vars": {"root": "/repo"}
+        }));
+
+        let expanded = expand_payload(&payload).unwrap();
+        assert_eq!(
+            expanded["args"],
+            json!(["--root", "/repo", "src/main.rs", "gen=3"])
+        );
+        assert_eq!(expanded["env"]["JOB"], "job-1");
+        assert_eq!(expanded["vars"], json!({"root": "/repo"}));
+    }
+
+    #[test]
+    fn test_builtins_win_over_vars() {
+        let payload = job(json!({"args": ["{{subject_key}}"], "vars": {"subject_key": "x"}}));
+        assert_eq!(expand_payload(&payload).unwrap()["args"][0], "src/main.rs");
+    }
+
+    #[test]
+    fn test_non_identifiers_stay_literal() {
+        let payload = job(json!({"args": ["{{ not a var }}", "{{", "a}}b", "{}"]}));
+        assert_eq!(
+            expand_payload(&payload).unwrap()["args"],
+            json!(["{{ not a var }}", "{{", "a}}b", "{}"])
+        );
+    }
+
+    #[test]
+    fn test_unknown_variables() {
+        let payload =
+            json!({"args": ["{{root}}", "{{subject_key}}", "{{missing}}"], "vars": {"root": "/"}});
+        assert_eq!(unknown_variables(&payload), vec!["missing".to_string()]);
+        assert!(expand_payload(&job(payload)).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+// Trace Context Domain Model
+// W3C traceparent supplied by the caller at enqueue time; the job's execution
+// span is linked to it so the caller's trace continues into the background task.
+
+use super::error::DomainError;
+use std::fmt;
+
+/// Parsed W3C `traceparent` header (`00-<trace-id>-<parent-id>-<flags>`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceParent {
+    trace_id: String,
+    parent_id: String,
+    flags: u8,
+}
+
+impl TraceParent {
+    /// Parse a version 00 traceparent (hex is normalized to lowercase)
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        let invalid = || DomainError::ValidationError(format!("Invalid traceparent '{}'", value));
+
+        let parts: Vec<&str> = value.trim().split('-').collect();
+        let [version, trace_id, parent_id, flags] = parts[..] else {
+            return Err(invalid());
+        };
+        if version != "00"
+            || !is_hex(trace_id, 32)
+            || !is_hex(parent_id, 16)
+            || !is_hex(flags, 2)
+            || trace_id.bytes().all(|b| b == b'0')
+            || parent_id.bytes().all(|b| b == b'0')
+        {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            trace_id: trace_id.to_ascii_lowercase(),
+            parent_id: parent_id.to_ascii_lowercase(),
+            flags: u8::from_str_radix(flags, 16).map_err(|_| invalid())?,
+        })
+    }
+
+    /// 32 hex chars shared by every span of the trace
+    pub fn trace_id(&self) -> &str {
+        &self.trace_id
+    }
+
+    /// 16 hex chars of the caller's span (parent of the job's spans)
+    pub fn parent_id(&self) -> &str {
+        &self.parent_id
+    }
+
+    pub fn is_sampled(&self) -> bool {
+        self.flags & 0x01 != 0
+    }
+}
+
+impl fmt::Display for TraceParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "00-{}-{}-{:02x}",
+            self.trace_id, self.parent_id, self.flags
+        )
+    }
+}
+
+fn is_hex(s: &str, len: usize) -> bool {
+    s.len() == len && s.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_traceparent() {
+        let tp =
+            TraceParent::parse("00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01").unwrap();
+
+        assert_eq!(tp.trace_id(), "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(tp.parent_id(), "00f067aa0ba902b7");
+        assert!(tp.is_sampled());
+        assert_eq!(
+            tp.to_string(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_traceparent() {
+        for value in [
+            "",
+            "01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e473-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-zz",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+        ] {
+            assert!(TraceParent::parse(value).is_err(), "{}", value);
+        }
+    }
+}
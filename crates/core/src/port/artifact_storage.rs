@@ -0,0 +1,41 @@
+// Artifact Storage Port - Where job artifacts (e.g. failure bundles) live
+// Backends: local filesystem, S3-compatible object stores.
+
+use crate::error::Result;
+use async_trait::async_trait;
+
+/// Stores artifact content and addresses it by URI
+///
+/// The URI returned by `put` is what gets registered in `job.artifacts`;
+/// `get` and `delete` only accept URIs produced by the same backend.
+#[async_trait]
+pub trait ArtifactStorage: Send + Sync {
+    /// Backend name for logs (e.g. "local", "s3")
+    fn name(&self) -> &str;
+
+    /// Store `content` under `key` (e.g. `<job_id>-failure-<ts>.tar.gz`)
+    ///
+    /// Returns the artifact URI.
+    async fn put(&self, key: &str, content: Vec<u8>) -> Result<String>;
+
+    /// Fetch an artifact (None if it no longer exists)
+    async fn get(&self, uri: &str) -> Result<Option<Vec<u8>>>;
+
+    /// Fetch at most `len` bytes starting at `offset` (empty past the end)
+    ///
+    /// Defaults to slicing `get`; backends that can read a range override it.
+    async fn get_range(&self, uri: &str, offset: u64, len: usize) -> Result<Option<Vec<u8>>> {
+        Ok(self.get(uri).await?.map(|content| {
+            let start = usize::try_from(offset)
+                .unwrap_or(usize::MAX)
+                .min(content.len());
+            let end = start.saturating_add(len).min(content.len());
+            content[start..end].to_vec()
+        }))
+    }
+
+    /// Delete an artifact
+    ///
+    /// Returns false if it was already gone.
+    async fn delete(&self, uri: &str) -> Result<bool>;
+}
@@ -0,0 +1,41 @@
+// Audit Log Port - Who changed what through the RPC API
+
+use crate::error::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// One mutating RPC call
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Epoch ms when the call arrived
+    pub timestamp: i64,
+    pub method: String,
+    /// `actor` / `worker_id` param of the call ("unknown" when absent)
+    pub caller: String,
+    /// Job the call targeted, when its params name one
+    pub job_id: Option<String>,
+    /// Params with payloads and other nested values elided
+    pub params: String,
+    /// None = succeeded
+    pub error_code: Option<i32>,
+    pub latency_ms: i64,
+}
+
+/// Audit log query (all filters optional, newest first)
+#[derive(Debug, Clone, Default)]
+pub struct AuditFilter {
+    pub method: Option<String>,
+    pub job_id: Option<String>,
+    pub caller: Option<String>,
+    /// Only entries at or after this time (epoch ms)
+    pub since: Option<i64>,
+}
+
+/// Audit log store
+#[async_trait]
+pub trait AuditLog: Send + Sync {
+    async fn record(&self, entry: &AuditEntry) -> Result<()>;
+
+    /// Newest matching entries first
+    async fn list(&self, filter: &AuditFilter, limit: usize) -> Result<Vec<AuditEntry>>;
+}
@@ -0,0 +1,36 @@
+// Bundle Writer Port - Diagnostic archives for failed jobs
+// "Attach the bundle" is the one-step bug-report flow.
+
+use crate::domain::{Job, JobEvent};
+use crate::error::Result;
+use crate::port::SystemMetrics;
+use async_trait::async_trait;
+use serde::Serialize;
+
+/// Log lines included in a bundle (tail of the job's log)
+pub const BUNDLE_LOG_LINES: usize = 500;
+
+/// Diagnostic data collected when a job failed for good
+#[derive(Debug, Clone, Serialize)]
+pub struct FailureBundle {
+    /// Job row as stored (state, attempts, result_summary, ...)
+    pub job: Job,
+    /// State transitions of every attempt
+    pub events: Vec<JobEvent>,
+    /// System metrics when the failure was recorded
+    pub system_metrics: SystemMetrics,
+    pub engine_version: String,
+    pub collected_at: i64, // epoch ms
+}
+
+/// Encodes failure bundles as a single archive
+#[async_trait]
+pub trait BundleWriter: Send + Sync {
+    /// File extension of the archive (e.g. "tar.gz")
+    fn extension(&self) -> &str;
+
+    /// Build the archive, including the last BUNDLE_LOG_LINES lines of `job.log_path`
+    ///
+    /// Returns the archive content (stored through an ArtifactStorage).
+    async fn write(&self, bundle: &FailureBundle) -> Result<Vec<u8>>;
+}
@@ -0,0 +1,39 @@
+// Job Archive Port - Finished jobs kept after GC deletes their rows
+//
+// Finished job GC hands every job it is about to delete to the archive, so an
+// old job can still be looked up (`semantica-cli archive get <job_id>`).
+
+use crate::domain::{Job, JobEvent};
+use crate::error::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// A job as archived: its final row and state transitions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedJob {
+    pub job: Job,
+    pub events: Vec<JobEvent>,
+    pub archived_at: i64, // epoch ms
+}
+
+/// Where an archived job is stored
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchiveLocation {
+    /// Segment file name (e.g. "segment-000003.jsonl.zst")
+    pub segment: String,
+    /// Byte range of the block holding the job within the segment
+    pub offset: u64,
+    pub len: u64,
+}
+
+/// Append-only store of GC'd jobs
+#[async_trait]
+pub trait JobArchive: Send + Sync {
+    /// Append a batch of jobs; durable once this returns
+    ///
+    /// GC deletes the rows only after a successful append.
+    async fn append(&self, jobs: &[ArchivedJob]) -> Result<()>;
+
+    /// Look up an archived job (the latest copy if archived twice)
+    async fn get(&self, job_id: &str) -> Result<Option<(ArchivedJob, ArchiveLocation)>>;
+}
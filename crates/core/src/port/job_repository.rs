@@ -1,7 +1,12 @@
 // Job Repository Port (Interface)
 
-use crate::domain::{Job, JobId, JobState};
+use crate::domain::{
+    Artifact, DeadLetter, ExpiredJob, ForwardedJob, Job, JobEvent, JobId, JobLease, JobProgress,
+    JobState, JobTypeStats, MetricsSnapshot, QueueStats, SubjectGeneration, SubjectResult,
+    WaitSloReport,
+};
 use crate::error::Result;
+use crate::port::pagination::{JobFilter, Page, PageRequest};
 use async_trait::async_trait;
 
 /// Repository interface for Job persistence
@@ -16,6 +21,12 @@ pub trait JobRepository: Send + Sync {
     /// Update job (full update - all fields)
     async fn update(&self, job: &Job) -> Result<()>;
 
+    /// Full update of a job that is still RUNNING
+    ///
+    /// Returns false, writing nothing, when the job left RUNNING meanwhile
+    /// (e.g. cancelled while the worker was executing it).
+    async fn update_if_running(&self, job: &Job) -> Result<bool>;
+
     /// Update only job state and finished_at (for completion)
     ///
     /// Optimization: Avoids updating all 19+ fields when only state changes
@@ -26,23 +37,237 @@ pub trait JobRepository: Send + Sync {
         finished_at: Option<i64>,
     ) -> Result<()>;
 
+    /// Store the latest attempt record (JSON) in `result_summary`
+    async fn update_result_summary(&self, id: &JobId, result_summary: &str) -> Result<()>;
+
+    /// Store where the job's output log is written
+    async fn update_log_path(&self, id: &JobId, log_path: &str) -> Result<()>;
+
+    /// Append an artifact URI to the job's comma-separated `artifacts` and
+    /// record its metadata
+    async fn add_artifact(&self, id: &JobId, artifact: &Artifact) -> Result<()>;
+
+    /// Recorded metadata of the job's artifacts (registration order)
+    ///
+    /// Artifacts registered without metadata are only in `job.artifacts`.
+    async fn list_artifacts(&self, id: &JobId) -> Result<Vec<Artifact>>;
+
     /// Increment attempts counter (for retry)
     ///
     /// Optimization: Avoids full update when only attempts changes
     async fn increment_attempts(&self, id: &JobId) -> Result<()>;
 
     /// Pop next job from queue (FIFO with priority)
+    ///
+    /// Ordering uses the effective priority: the max of the job's own priority and
+    /// the priorities of QUEUED jobs chained after it (via `parent_job_id`, transitively).
+    /// Jobs waiting out a retry backoff or a future `schedule_at` are skipped.
     async fn pop_next(&self, queue: &str) -> Result<Option<Job>>;
 
+    /// Pop the next job of the first listed queue that has one
+    ///
+    /// `queues` is in preference order: a job of a later queue is only popped
+    /// while every earlier queue has nothing due. Within a queue the order is
+    /// the same as `pop_next`.
+    async fn pop_next_multi(&self, queues: &[&str]) -> Result<Option<Job>>;
+
+    /// Claim up to `max` jobs of `queue` in one statement (same order and
+    /// filters as `pop_next`), all set to RUNNING
+    ///
+    /// For workers running many tiny jobs, where one round trip per pop dominates.
+    async fn pop_batch(&self, queue: &str, max: usize) -> Result<Vec<Job>>;
+
     /// Get latest generation for subject_key
     async fn get_latest_generation(&self, subject_key: &str) -> Result<i64>;
 
     /// Mark jobs as superseded
+    ///
+    /// Records a `supersede` event for every job it marks.
     async fn mark_superseded(&self, subject_key: &str, below_generation: i64) -> Result<u64>;
 
     /// Count jobs by state
     async fn count_by_state(&self, queue: &str, state: JobState) -> Result<i64>;
 
+    /// created_at of the oldest QUEUED job of `queue` that is due at `now`
+    /// (jobs scheduled for later are not waiting yet)
+    async fn oldest_queued_at(&self, queue: &str, now: i64) -> Result<Option<i64>>;
+
+    /// Counts of every queue present in the jobs table, sorted by name
+    ///
+    /// `now` decides which QUEUED jobs are due, `finished_since` starts the
+    /// throughput window.
+    async fn queue_stats(&self, now: i64, finished_since: i64) -> Result<Vec<QueueStats>>;
+
+    /// Jobs of `queue` that waited longer than `target_ms` to start
+    ///
+    /// Counts jobs started since `since` plus QUEUED jobs past the target at
+    /// `now`; lists at most `max_job_ids` offenders.
+    async fn wait_slo_report(
+        &self,
+        queue: &str,
+        target_ms: i64,
+        now: i64,
+        since: i64,
+        max_job_ids: usize,
+    ) -> Result<WaitSloReport>;
+
+    /// Counts and mean run time per job type over all retained jobs, by job type
+    async fn job_type_stats(&self) -> Result<Vec<JobTypeStats>>;
+
+    /// Run times (started_at -> finished_at, ms) of the latest `limit` DONE jobs
+    /// of a job type, newest first
+    async fn recent_durations(&self, job_type: &str, limit: usize) -> Result<Vec<i64>>;
+
+    /// Throughput of the window `since..now` and the queue depth at `now`
+    async fn take_metrics_snapshot(&self, since: i64, now: i64) -> Result<MetricsSnapshot>;
+
+    /// Store a snapshot, keeping only the newest `keep` snapshots
+    async fn record_metrics_snapshot(&self, snapshot: &MetricsSnapshot, keep: usize) -> Result<()>;
+
+    /// Newest `limit` snapshots taken after `since`, oldest first
+    async fn list_metrics_snapshots(
+        &self,
+        since: i64,
+        limit: usize,
+    ) -> Result<Vec<MetricsSnapshot>>;
+
+    /// Latest generation of every known subject (queue snapshots)
+    async fn list_subjects(&self) -> Result<Vec<SubjectGeneration>>;
+
+    /// Append a successful result to its subject's history, keeping the
+    /// newest `keep` entries of that subject
+    async fn record_subject_result(&self, result: &SubjectResult, keep: usize) -> Result<()>;
+
+    /// Newest `limit` results of a subject, newest first
+    async fn list_subject_history(
+        &self,
+        subject_key: &str,
+        limit: usize,
+    ) -> Result<Vec<SubjectResult>>;
+
+    /// Park a job that failed for good in the dead-letter queue (replaces an
+    /// earlier entry of the same job)
+    async fn record_dead_letter(&self, letter: &DeadLetter) -> Result<()>;
+
+    /// Newest `limit` dead letters (of `queue`), newest first
+    ///
+    /// Entries of jobs deleted meanwhile are skipped.
+    async fn list_dead_letters(&self, queue: Option<&str>, limit: usize)
+        -> Result<Vec<DeadLetter>>;
+
+    /// Drop a job's dead letter (true if there was one)
+    async fn remove_dead_letter(&self, job_id: &str) -> Result<bool>;
+
+    /// Drop the dead letters (of `queue`) parked before `before`; returns how many
+    async fn purge_dead_letters(&self, queue: Option<&str>, before: Option<i64>) -> Result<u64>;
+
     /// Find all jobs by state (Phase 2 - for recovery)
     async fn find_by_state(&self, state: JobState) -> Result<Vec<Job>>;
+
+    /// Service jobs that are running or waiting to be (re)started, oldest first
+    async fn list_active_services(&self) -> Result<Vec<Job>>;
+
+    /// List jobs matching `filter`, oldest first (keyset pagination)
+    ///
+    /// Ordered by `(created_at, id)`, so pages neither skip nor repeat rows when
+    /// other jobs are inserted or garbage-collected between calls.
+    async fn list_jobs(&self, filter: &JobFilter, page: &PageRequest) -> Result<Page<Job>>;
+
+    /// Disable or re-enable a job type
+    ///
+    /// Disabled job types are skipped by `pop_next` (they stay QUEUED)
+    /// and rejected at enqueue.
+    async fn set_job_type_disabled(&self, job_type: &str, disabled: bool) -> Result<()>;
+
+    /// List currently disabled job types
+    async fn list_disabled_job_types(&self) -> Result<Vec<String>>;
+
+    /// Append an entry to the job's event history
+    async fn record_event(&self, event: &JobEvent) -> Result<()>;
+
+    /// Expire QUEUED jobs whose TTL or deadline has passed at `now` (never services)
+    ///
+    /// TTL is checked first (SKIPPED_TTL), then the deadline (SKIPPED_DEADLINE),
+    /// like at pop time. Atomic: every expired job gets an event (`actor`) in
+    /// the same transaction.
+    async fn expire_queued(&self, now: i64, actor: &str) -> Result<Vec<ExpiredJob>>;
+
+    /// Cancel every QUEUED job whose subject_key matches a glob (`*`, `?`, `[...]`)
+    ///
+    /// Atomic: all matching jobs are cancelled and get a CANCELLED event
+    /// (`actor`, `reason`) in one transaction. `queue` None = every queue.
+    /// Returns the cancelled job ids.
+    async fn cancel_by_subject(
+        &self,
+        queue: Option<&str>,
+        subject_pattern: &str,
+        actor: &str,
+        reason: Option<&str>,
+    ) -> Result<Vec<JobId>>;
+
+    /// Store the progress reported by a RUNNING job
+    ///
+    /// Returns false (nothing stored) if the job is gone or not RUNNING.
+    async fn update_progress(&self, id: &JobId, progress: &JobProgress) -> Result<bool>;
+
+    /// Delete a job and everything keyed by it (events, lease, forward record,
+    /// idempotency keys) in one transaction, if its state is in `states`
+    ///
+    /// Returns false (nothing deleted) if the job is gone or in another state.
+    async fn delete_job(&self, id: &JobId, states: &[JobState]) -> Result<bool>;
+
+    /// Event history of a job (oldest first)
+    async fn list_events(&self, id: &JobId) -> Result<Vec<JobEvent>>;
+
+    /// Store a lease for a RUNNING job (replaces any previous lease of the job)
+    async fn insert_lease(&self, lease: &JobLease) -> Result<()>;
+
+    /// Current lease of a job, if any
+    async fn find_lease(&self, id: &JobId) -> Result<Option<JobLease>>;
+
+    /// Extend a lease held by `worker_id`
+    ///
+    /// Returns the renewed lease, or None if the job is not leased to `worker_id`.
+    async fn renew_lease(
+        &self,
+        id: &JobId,
+        worker_id: &str,
+        expires_at: i64,
+        progress: Option<&str>,
+    ) -> Result<Option<JobLease>>;
+
+    /// Drop the lease of a job (no-op if there is none)
+    async fn delete_lease(&self, id: &JobId) -> Result<()>;
+
+    /// Leases whose `expires_at` is at or before `now`
+    async fn find_expired_leases(&self, now: i64) -> Result<Vec<JobLease>>;
+
+    /// Remember that a job was forwarded to a remote daemon
+    async fn record_forward(&self, forward: &ForwardedJob) -> Result<()>;
+
+    /// Forward record of a job, if it was forwarded
+    async fn find_forward(&self, id: &JobId) -> Result<Option<ForwardedJob>>;
+
+    /// Forwards whose remote outcome has not been mirrored yet (oldest first)
+    async fn list_open_forwards(&self, limit: usize) -> Result<Vec<ForwardedJob>>;
+
+    /// Mark a forward as mirrored with the remote's terminal state (None = lost remotely)
+    async fn close_forward(
+        &self,
+        id: &JobId,
+        remote_state: Option<JobState>,
+        closed_at: i64,
+    ) -> Result<()>;
+
+    /// Queues that currently hold QUEUED jobs
+    async fn list_active_queues(&self) -> Result<Vec<String>>;
+
+    /// Prime the pop path before serving requests (cold start)
+    ///
+    /// Prepares the pop statement on pooled connections and loads the index
+    /// pages of `queues`. Must not change any job.
+    async fn warm_up(&self, queues: &[String]) -> Result<()>;
+
+    /// Round-trip to the database (health checks)
+    async fn ping(&self) -> Result<()>;
 }
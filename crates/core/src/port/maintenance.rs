@@ -1,6 +1,7 @@
 // DB Maintenance port (Phase 4 - ADR-050)
 use crate::error::Result;
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 
 /// Database maintenance statistics
 #[derive(Debug, Clone)]
@@ -12,6 +13,52 @@ pub struct MaintenanceStats {
     pub artifact_count: usize,
     pub log_files_size_mb: f64,
     pub fragmentation_percent: f64,
+    /// Latest applied migration (schema_version table)
+    pub schema_version: i64,
+}
+
+/// Outcome of one scheduled maintenance run
+///
+/// Steps that fail are recorded in `errors`; the remaining steps still run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaintenanceReport {
+    pub started_at: i64, // epoch ms
+    pub duration_ms: i64,
+    pub jobs_deleted: i64,
+    pub artifacts_removed: usize,
+    /// Success logs dropped by sampling (see MaintenanceConfig::success_log_sample_rate)
+    #[serde(default)]
+    pub success_logs_dropped: usize,
+    pub vacuum_run: bool,
+    /// DB + log file space freed (MB)
+    pub mb_reclaimed: f64,
+    pub errors: Vec<String>,
+}
+
+/// What a GC run would delete, computed without deleting anything
+///
+/// Mirrors `gc_finished_jobs` followed by `gc_artifacts`: jobs past the job
+/// retention are removed (with their registered artifacts), the remaining
+/// finished jobs past the artifact retention lose their log files and artifacts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GcPreview {
+    /// Finished jobs whose rows (and event history) would be deleted
+    pub job_ids: Vec<String>,
+    /// Log files that would be removed
+    pub log_files: Vec<String>,
+    /// Registered artifact URIs that would be deleted
+    pub artifacts: Vec<String>,
+    /// Size of those log files on disk
+    pub log_bytes: u64,
+    /// Approximate size of the deleted rows (payloads, results), freed by VACUUM
+    pub row_bytes: u64,
+}
+
+impl GcPreview {
+    /// Space a GC run (plus VACUUM) would reclaim
+    pub fn bytes_reclaimed(&self) -> u64 {
+        self.log_bytes + self.row_bytes
+    }
 }
 
 /// Maintenance configuration
@@ -25,6 +72,13 @@ pub struct MaintenanceConfig {
 
     /// Artifact retention period (days)
     pub artifact_retention_days: i64,
+
+    /// Share of successful runs whose logs are kept past `full_log_retention_hours`
+    /// (1.0 = keep all, 0.05 = keep 5%). Failure logs are always kept.
+    pub success_log_sample_rate: f64,
+
+    /// Every log is kept in full for this long after the job finished (hours)
+    pub full_log_retention_hours: i64,
 }
 
 impl Default for MaintenanceConfig {
@@ -33,10 +87,31 @@ impl Default for MaintenanceConfig {
             finished_job_retention_days: 7, // Keep finished jobs for 7 days
             max_db_size_mb: 1000.0,         // 1GB max
             artifact_retention_days: 3,     // Keep artifacts for 3 days
+            success_log_sample_rate: 1.0,   // No sampling
+            full_log_retention_hours: 24,
         }
     }
 }
 
+/// Whether a successful job's log survives sampling at `sample_rate`
+///
+/// Deterministic per job ID (FNV-1a), so repeated runs keep the same sample.
+pub fn is_success_log_sampled(job_id: &str, sample_rate: f64) -> bool {
+    if sample_rate >= 1.0 {
+        return true;
+    }
+    if sample_rate <= 0.0 {
+        return false;
+    }
+
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in job_id.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    (hash % 10_000) < (sample_rate * 10_000.0) as u64
+}
+
 /// Database maintenance operations
 #[async_trait]
 pub trait Maintenance: Send + Sync {
@@ -64,9 +139,32 @@ pub trait Maintenance: Send + Sync {
     /// Number of artifacts deleted
     async fn gc_artifacts(&self, retention_days: i64) -> Result<usize>;
 
+    /// Drop logs of successful jobs that fall outside the sample
+    ///
+    /// Only DONE jobs finished more than `older_than_hours` ago are considered;
+    /// failure logs are never touched.
+    ///
+    /// # Returns
+    /// Number of log files removed
+    async fn sample_success_logs(&self, older_than_hours: i64, sample_rate: f64) -> Result<usize>;
+
+    /// Report what `gc_finished_jobs(job_retention_days)` followed by
+    /// `gc_artifacts(artifact_retention_days)` would delete, without deleting
+    async fn preview_gc(
+        &self,
+        job_retention_days: i64,
+        artifact_retention_days: i64,
+    ) -> Result<GcPreview>;
+
     /// Get maintenance statistics
     async fn get_stats(&self) -> Result<MaintenanceStats>;
 
+    /// Persist a maintenance report, keeping only the newest `keep` reports
+    async fn record_report(&self, report: &MaintenanceReport, keep: usize) -> Result<()>;
+
+    /// List the most recent maintenance reports (newest first)
+    async fn list_reports(&self, limit: usize) -> Result<Vec<MaintenanceReport>>;
+
     /// Run full maintenance (VACUUM + GC)
     ///
     /// Runs all maintenance operations based on config
@@ -81,6 +179,12 @@ pub trait Maintenance: Send + Sync {
 
         // 3. GC artifacts
         let deleted_artifacts = self.gc_artifacts(config.artifact_retention_days).await?;
+        let dropped_logs = self
+            .sample_success_logs(
+                config.full_log_retention_hours,
+                config.success_log_sample_rate,
+            )
+            .await?;
 
         // 4. VACUUM if DB is large
         let reclaimed_mb = if stats_before.db_size_mb > config.max_db_size_mb {
@@ -96,6 +200,7 @@ pub trait Maintenance: Send + Sync {
         tracing::info!(
             deleted_jobs = deleted_jobs,
             deleted_artifacts = deleted_artifacts,
+            dropped_logs = dropped_logs,
             reclaimed_mb = reclaimed_mb,
             db_size_mb = stats_after.db_size_mb,
             "Maintenance completed"
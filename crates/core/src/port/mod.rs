@@ -1,18 +1,40 @@
 // Port Layer - Interfaces for external dependencies
 
+pub mod artifact_storage;
+pub mod audit_log;
+pub mod bundle_writer;
 pub mod id_provider; // For deterministic testing
+pub mod job_archive;
 pub mod job_repository;
 pub mod maintenance;
+pub mod notifier;
+pub mod pagination;
+pub mod remote_daemon;
 pub mod system_probe;
 pub mod task_executor; // Phase 2
 pub mod time_provider;
 pub mod transaction; // Phase 2 // Phase 4
+pub mod worker_metrics;
 
 // Re-exports
+pub use artifact_storage::ArtifactStorage;
+pub use audit_log::{AuditEntry, AuditFilter, AuditLog};
+pub use bundle_writer::{BundleWriter, FailureBundle, BUNDLE_LOG_LINES};
 pub use id_provider::IdProvider;
+pub use job_archive::{ArchiveLocation, ArchivedJob, JobArchive};
 pub use job_repository::JobRepository;
-pub use maintenance::{Maintenance, MaintenanceConfig, MaintenanceStats};
+pub use maintenance::{
+    is_success_log_sampled, GcPreview, Maintenance, MaintenanceConfig, MaintenanceReport,
+    MaintenanceStats,
+};
+pub use notifier::{LogNotifier, Notification, Notifier};
+pub use pagination::{JobFilter, Page, PageRequest, MAX_PAGE_SIZE};
+pub use remote_daemon::{RemoteDaemon, RemoteSubmit};
 pub use system_probe::{SystemMetrics, SystemProbe};
-pub use task_executor::{ExecutionError, ExecutionResult, ExecutionStatus, TaskExecutor};
-pub use time_provider::TimeProvider;
+pub use task_executor::{
+    EnvironmentManifest, ErrorClass, ExecutionError, ExecutionResult, ExecutionStatus,
+    RunningProcess, TaskExecutor,
+};
+pub use time_provider::{elapsed_ms, TimeProvider};
 pub use transaction::{JobRepositoryTransaction, Transaction, TransactionalJobRepository};
+pub use worker_metrics::{NoopWorkerMetrics, WorkerMetrics};
@@ -0,0 +1,39 @@
+// Notification Port - Structured events for operators and external tooling
+
+use crate::error::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+/// Structured notification (one event on the channel)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    /// Event topic (e.g. "maintenance.report")
+    pub topic: String,
+    /// Epoch ms when the event was produced
+    pub timestamp: i64,
+    pub payload: serde_json::Value,
+}
+
+/// Notification channel
+///
+/// Delivery is best-effort: callers log failures and carry on.
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn notify(&self, notification: &Notification) -> Result<()>;
+}
+
+/// Notifier that writes events to the tracing log (default)
+pub struct LogNotifier;
+
+#[async_trait]
+impl Notifier for LogNotifier {
+    async fn notify(&self, notification: &Notification) -> Result<()> {
+        info!(
+            topic = %notification.topic,
+            payload = %notification.payload,
+            "Notification"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+// Pagination types for list/search/history queries
+//
+// Cursors are opaque to callers: adapters encode their own keyset position
+// (e.g. `(created_at, id)`) so pages stay stable while rows are inserted or GC'd.
+
+use crate::domain::JobState;
+
+/// Default and maximum page sizes
+pub const DEFAULT_PAGE_SIZE: usize = 50;
+pub const MAX_PAGE_SIZE: usize = 500;
+
+/// Request for one page of results
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageRequest {
+    /// Cursor returned as `next_cursor` by the previous page (None = first page)
+    pub after: Option<String>,
+    /// Page size (0 = DEFAULT_PAGE_SIZE, capped at MAX_PAGE_SIZE)
+    pub limit: usize,
+}
+
+impl PageRequest {
+    pub fn first(limit: usize) -> Self {
+        Self { after: None, limit }
+    }
+
+    pub fn after(cursor: impl Into<String>, limit: usize) -> Self {
+        Self {
+            after: Some(cursor.into()),
+            limit,
+        }
+    }
+
+    /// Page size after applying the default and cap
+    pub fn effective_limit(&self) -> usize {
+        match self.limit {
+            0 => DEFAULT_PAGE_SIZE,
+            n => n.min(MAX_PAGE_SIZE),
+        }
+    }
+}
+
+/// One page of results
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Cursor for the next page (None = no more results)
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    pub fn empty() -> Self {
+        Self {
+            items: Vec::new(),
+            next_cursor: None,
+        }
+    }
+}
+
+/// Filter for job listings (all fields optional, combined with AND)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JobFilter {
+    pub queue: Option<String>,
+    pub state: Option<JobState>,
+    pub subject_key: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_limit() {
+        assert_eq!(PageRequest::first(0).effective_limit(), DEFAULT_PAGE_SIZE);
+        assert_eq!(PageRequest::first(10).effective_limit(), 10);
+        assert_eq!(PageRequest::first(10_000).effective_limit(), MAX_PAGE_SIZE);
+    }
+}
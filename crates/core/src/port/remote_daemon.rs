@@ -0,0 +1,33 @@
+// Remote Daemon Port (Interface)
+// Another Semantica daemon that jobs can be forwarded to
+
+use crate::domain::{Job, JobState};
+use crate::error::Result;
+use async_trait::async_trait;
+
+/// Result of submitting a job to the remote daemon
+#[derive(Debug, Clone, PartialEq)]
+pub enum RemoteSubmit {
+    Accepted {
+        remote_job_id: String,
+    },
+    /// The remote refused the job (validation, disabled job type, ...): do not retry
+    Rejected {
+        reason: String,
+    },
+}
+
+#[async_trait]
+pub trait RemoteDaemon: Send + Sync {
+    /// Endpoint recorded with forwarded jobs (e.g. RPC URL)
+    fn endpoint(&self) -> &str;
+
+    /// Enqueue `job` remotely
+    ///
+    /// `idempotency_key` makes a resubmit after a lost response resolve to the
+    /// same remote job. Returns Err if the remote is unreachable (retry later).
+    async fn submit(&self, job: &Job, idempotency_key: &str) -> Result<RemoteSubmit>;
+
+    /// Current state of a remote job (None = the remote no longer knows it)
+    async fn state(&self, remote_job_id: &str) -> Result<Option<JobState>>;
+}
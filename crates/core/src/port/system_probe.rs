@@ -1,9 +1,10 @@
 // System resource monitoring port (Phase 2)
 // reason: async-trait 필요 (ADR-001)
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 
 /// System resource metrics
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemMetrics {
     pub cpu_usage_percent: f32,
     pub memory_used_mb: u64,
@@ -42,6 +43,14 @@ pub trait SystemProbe: Send + Sync {
     /// # Returns
     /// true if system has been idle for the specified duration
     async fn is_idle(&self, cpu_threshold: f32, duration_secs: u64) -> bool;
+
+    /// Resident memory of the given processes and all their descendants (MB)
+    ///
+    /// Used to enforce the aggregate memory ceiling of job subprocesses.
+    /// Default: 0 (the probe cannot attribute memory to processes).
+    async fn process_tree_memory_mb(&self, _pids: &[u32]) -> u64 {
+        0
+    }
 }
 
 // ============================================================================
@@ -50,10 +59,12 @@ pub trait SystemProbe: Send + Sync {
 
 pub mod mocks {
     use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
     use std::sync::{Arc, Mutex};
     /// Mock SystemProbe for testing
     pub struct MockSystemProbe {
         metrics: Arc<Mutex<SystemMetrics>>,
+        process_memory_mb: AtomicU64,
     }
     impl MockSystemProbe {
         pub fn new(cpu_usage_percent: f32) -> Self {
@@ -67,11 +78,16 @@ pub mod mocks {
                     battery_percent: None,
                     is_charging: None,
                 })),
+                process_memory_mb: AtomicU64::new(0),
             }
         }
         pub fn set_cpu_usage(&self, cpu_usage_percent: f32) {
             self.metrics.lock().unwrap().cpu_usage_percent = cpu_usage_percent;
         }
+        /// Memory reported for any non-empty set of processes
+        pub fn set_process_memory_mb(&self, memory_mb: u64) {
+            self.process_memory_mb.store(memory_mb, Ordering::SeqCst);
+        }
     }
     #[async_trait]
     impl SystemProbe for MockSystemProbe {
@@ -82,5 +98,11 @@ pub mod mocks {
             // For testing, always return false (not idle)
             false
         }
+        async fn process_tree_memory_mb(&self, pids: &[u32]) -> u64 {
+            if pids.is_empty() {
+                return 0;
+            }
+            self.process_memory_mb.load(Ordering::SeqCst)
+        }
     }
 }
@@ -1,8 +1,10 @@
 // Task Executor Port (Phase 2, ADR-002)
 // Abstraction for executing external tasks (subprocess or in-process)
 
-use crate::domain::Job;
+use crate::domain::{Job, JobId};
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use thiserror::Error;
 
 /// Result of task execution
@@ -13,6 +15,47 @@ pub struct ExecutionResult {
     pub exit_code: Option<i32>,
     pub stdout: Option<String>,
     pub stderr: Option<String>,
+    /// Provenance of the attempt (None if the executor does not record it)
+    pub environment: Option<EnvironmentManifest>,
+    /// Tagged output log (JSON lines, see LogLine), None if not written
+    pub log_path: Option<String>,
+    /// Whether a failed run is worth retrying (None = retryable)
+    pub error_class: Option<ErrorClass>,
+}
+
+/// Whether retrying a failed execution can help
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ErrorClass {
+    /// Transient (timeout, I/O, crash): the retry policy applies
+    Retryable,
+    /// Fails the same way every time (invalid payload, command not found):
+    /// the job goes straight to FAILED and the DLQ
+    Fatal,
+}
+
+/// Resolved execution environment of a single attempt
+///
+/// Recorded so "works on machine A but not B" investigations have provenance.
+/// Environment values are stored as hashes only (no secrets in the DB).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvironmentManifest {
+    pub executor: String,
+    pub executor_version: String,
+    pub hostname: Option<String>,
+    pub os: String,
+    pub arch: String,
+    /// Allowlisted variable name -> hash of its resolved value
+    pub env_hashes: BTreeMap<String, String>,
+}
+
+/// Child process of a job that is executing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunningProcess {
+    pub job_id: JobId,
+    pub pid: u32,
+    /// When the process was spawned (epoch ms)
+    pub started_at: i64,
 }
 
 /// Execution status
@@ -41,6 +84,22 @@ pub enum ExecutionError {
 
     #[error("IO error: {0}")]
     IoError(String),
+
+    /// Retrying cannot help (e.g. the command does not exist)
+    #[error("Fatal: {0}")]
+    Fatal(String),
+}
+
+impl ExecutionError {
+    /// Invalid payloads and fatal errors are not retried
+    pub fn class(&self) -> ErrorClass {
+        match self {
+            Self::InvalidPayload(_) | Self::Fatal(_) => ErrorClass::Fatal,
+            Self::SpawnFailed(_) | Self::Timeout(_) | Self::Killed(_) | Self::IoError(_) => {
+                ErrorClass::Retryable
+            }
+        }
+    }
 }
 
 /// Task Executor trait
@@ -56,6 +115,9 @@ pub trait TaskExecutor: Send + Sync {
     /// - ExecutionError::SpawnFailed if process cannot be started
     /// - ExecutionError::Timeout if execution exceeds deadline
     /// - ExecutionError::InvalidPayload if job payload is malformed
+    /// - ExecutionError::Fatal if retrying cannot help
+    ///
+    /// A failed ExecutionResult may set `error_class` to Fatal as well.
     async fn execute(&self, job: &Job) -> Result<ExecutionResult, ExecutionError>;
 
     /// Kill a running process by PID
@@ -72,6 +134,13 @@ pub trait TaskExecutor: Send + Sync {
     /// # Arguments
     /// * `pid` - Process ID to check
     fn is_alive(&self, pid: i32) -> bool;
+
+    /// Processes of the jobs executing right now
+    ///
+    /// Default: none (the executor does not track its processes).
+    fn running(&self) -> Vec<RunningProcess> {
+        Vec::new()
+    }
 }
 
 // ============================================================================
@@ -88,6 +157,8 @@ pub mod mocks {
         Success,
         /// Always fail with message
         Fail(String),
+        /// Always fail with a fatal (not retried) error
+        FailFatal(String),
         /// Panic with message (for panic isolation testing)
         Panic(String),
         /// Timeout after N ms
@@ -97,12 +168,16 @@ pub mod mocks {
     pub struct MockTaskExecutor {
         behavior: Arc<Mutex<MockBehavior>>,
         call_count: Arc<Mutex<usize>>,
+        running: Arc<Mutex<Vec<RunningProcess>>>,
+        killed: Arc<Mutex<Vec<i32>>>,
     }
     impl MockTaskExecutor {
         pub fn new(behavior: MockBehavior) -> Self {
             Self {
                 behavior: Arc::new(Mutex::new(behavior)),
                 call_count: Arc::new(Mutex::new(0)),
+                running: Arc::new(Mutex::new(Vec::new())),
+                killed: Arc::new(Mutex::new(Vec::new())),
             }
         }
         pub fn new_panic_inducing(message: impl Into<String>) -> Self {
@@ -114,9 +189,20 @@ pub mod mocks {
         pub fn new_fail(message: impl Into<String>) -> Self {
             Self::new(MockBehavior::Fail(message.into()))
         }
+        pub fn new_fatal(message: impl Into<String>) -> Self {
+            Self::new(MockBehavior::FailFatal(message.into()))
+        }
         pub fn call_count(&self) -> usize {
             *self.call_count.lock().unwrap()
         }
+        /// Processes reported by `running()`
+        pub fn set_running(&self, running: Vec<RunningProcess>) {
+            *self.running.lock().unwrap() = running;
+        }
+        /// PIDs passed to `kill()`, in call order
+        pub fn killed_pids(&self) -> Vec<i32> {
+            self.killed.lock().unwrap().clone()
+        }
     }
     #[async_trait]
     impl TaskExecutor for MockTaskExecutor {
@@ -132,19 +218,27 @@ pub mod mocks {
                     exit_code: Some(0),
                     stdout: Some("mock output".to_string()),
                     stderr: None,
+                    environment: None,
+                    log_path: None,
+                    error_class: None,
                 }),
                 MockBehavior::Fail(msg) => Err(ExecutionError::SpawnFailed(msg)),
+                MockBehavior::FailFatal(msg) => Err(ExecutionError::Fatal(msg)),
                 MockBehavior::Panic(msg) => {
                     panic!("{}", msg); // Actually panic for panic isolation testing
                 }
                 MockBehavior::Timeout(ms) => Err(ExecutionError::Timeout(ms)),
             }
         }
-        async fn kill(&self, _pid: i32) -> Result<(), ExecutionError> {
+        async fn kill(&self, pid: i32) -> Result<(), ExecutionError> {
+            self.killed.lock().unwrap().push(pid);
             Ok(())
         }
         fn is_alive(&self, _pid: i32) -> bool {
             false
         }
+        fn running(&self) -> Vec<RunningProcess> {
+            self.running.lock().unwrap().clone()
+        }
     }
 }
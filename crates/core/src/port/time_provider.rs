@@ -1,5 +1,7 @@
 // Time Provider Port (for testability)
 
+use tracing::warn;
+
 /// Time provider interface (allows mocking in tests)
 pub trait TimeProvider: Send + Sync {
     /// Get current time in milliseconds since epoch
@@ -14,3 +16,33 @@ impl TimeProvider for SystemTimeProvider {
         chrono::Utc::now().timestamp_millis()
     }
 }
+
+/// Milliseconds elapsed from `since` to `now`, clamped to 0
+///
+/// Wall-clock time can go backwards (e.g. NTP sync after a laptop wakes up),
+/// leaving stored timestamps in the future. Such ages are logged as a clock
+/// anomaly instead of turning into negative, nonsense values.
+pub fn elapsed_ms(now: i64, since: i64) -> i64 {
+    let elapsed = now - since;
+    if elapsed < 0 {
+        warn!(
+            now,
+            since,
+            skew_ms = -elapsed,
+            "Clock anomaly: timestamp is in the future (clock set backwards?)"
+        );
+        return 0;
+    }
+    elapsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elapsed_ms_clamps_future_timestamps() {
+        assert_eq!(elapsed_ms(5_000, 1_000), 4_000);
+        assert_eq!(elapsed_ms(1_000, 5_000), 0);
+    }
+}
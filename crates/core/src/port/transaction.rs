@@ -29,6 +29,40 @@ pub trait JobRepositoryTransaction: Transaction {
     /// Insert job (within transaction)
     async fn insert(&mut self, job: &crate::domain::Job) -> Result<()>;
 
-    /// Mark superseded (within transaction)
+    /// Mark superseded (within transaction), recording a `supersede` event per job
     async fn mark_superseded(&mut self, subject_key: &str, below_generation: i64) -> Result<u64>;
+
+    /// Claim the subject's next generation (within transaction)
+    ///
+    /// Raises the subject's latest generation by one and returns it (1 for a
+    /// new subject). Enqueue fast path: one round trip instead of
+    /// `get_latest_generation` plus the generation update in `mark_superseded`.
+    async fn next_generation(&mut self, subject_key: &str) -> Result<i64>;
+
+    /// Insert a job claimed with `next_generation` and supersede the QUEUED
+    /// jobs of older generations of its subject (within transaction)
+    ///
+    /// Records a `supersede` event per superseded job and returns their count.
+    async fn insert_superseding(&mut self, job: &crate::domain::Job) -> Result<u64>;
+
+    /// Check whether a job type is disabled (within transaction)
+    async fn is_job_type_disabled(&mut self, job_type: &str) -> Result<bool>;
+
+    /// Find the job previously enqueued with this idempotency key (within transaction)
+    ///
+    /// None if the key is unknown or its job no longer exists.
+    async fn find_by_idempotency_key(&mut self, key: &str) -> Result<Option<crate::domain::JobId>>;
+
+    /// Remember the idempotency key for a newly inserted job (within transaction)
+    async fn record_idempotency_key(&mut self, key: &str, job_id: &str) -> Result<()>;
+
+    /// Overwrite payloads, priority, trace and options of a still-QUEUED job (enqueue coalescing)
+    ///
+    /// `job` carries the new values; its `id`, `job_type` and `job_class` must match the stored job.
+    /// Generation and creation time are kept.
+    /// Returns false if the job was already popped (or no longer matches).
+    async fn replace_queued(&mut self, job: &crate::domain::Job) -> Result<bool>;
+
+    /// Raise a subject's latest generation to at least `generation` (snapshot restore)
+    async fn raise_generation(&mut self, subject_key: &str, generation: i64) -> Result<()>;
 }
@@ -0,0 +1,43 @@
+// Worker Metrics Port - Timings of the worker loop for a metrics backend
+//
+// The daemon exports them over OpenTelemetry (`telemetry` feature); without a
+// backend the worker reports into NoopWorkerMetrics.
+
+use crate::domain::JobState;
+use std::time::Duration;
+
+/// Receives worker timings (called inline by the loop: keep it cheap, never block)
+pub trait WorkerMetrics: Send + Sync {
+    /// One pass of the worker loop; `processed` is false for idle, paused or throttled passes
+    fn record_cycle(&self, queue: &str, duration: Duration, processed: bool);
+
+    /// Time `pop_next` took; `found` is false when the queue was empty
+    fn record_pop(&self, queue: &str, duration: Duration, found: bool);
+
+    /// Wall time of one execution and the state it ended in
+    fn record_execution(&self, queue: &str, job_type: &str, state: &JobState, duration: Duration);
+
+    /// A QUEUED job expired (SKIPPED_TTL / SKIPPED_DEADLINE) before it was popped
+    fn record_expired(&self, _queue: &str, _job_type: &str, _state: &JobState) {}
+
+    /// A RUNNING job was flagged as stalled (`killed`: the detector killed it)
+    fn record_stalled(&self, _queue: &str, _job_type: &str, _killed: bool) {}
+}
+
+/// Discards all timings (default)
+pub struct NoopWorkerMetrics;
+
+impl WorkerMetrics for NoopWorkerMetrics {
+    fn record_cycle(&self, _queue: &str, _duration: Duration, _processed: bool) {}
+
+    fn record_pop(&self, _queue: &str, _duration: Duration, _found: bool) {}
+
+    fn record_execution(
+        &self,
+        _queue: &str,
+        _job_type: &str,
+        _state: &JobState,
+        _duration: Duration,
+    ) {
+    }
+}
@@ -0,0 +1,224 @@
+//! Config file - Typed daemon configuration
+//!
+//! `~/.semantica/config.toml` (or `SEMANTICA_CONFIG`) gathers what used to be
+//! spread over env vars: database, RPC endpoints, worker, scheduler and
+//! maintenance settings. Every key is optional and each env var still
+//! overrides its key (defaults < file < env), so existing setups keep working.
+//! Unknown keys are rejected instead of silently ignored.
+//!
+//! Most settings are read once at startup. Log level, RPC rate limits, CPU
+//! throttle and maintenance retention / log sampling also change without a
+//! restart: on SIGHUP the file is read again, the runtime settings are rebuilt
+//! and swapped in; consumers pick them up through the shared `RuntimeConfig`.
+//! A file that fails to parse or validate is rejected as a whole and the
+//! running settings stay. Changes made with admin.config.set.v1 are replaced
+//! by a reload.
+
+use anyhow::{Context, Result};
+use semantica_core::application::{RuntimeConfig, RuntimeSettings};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+use tracing_subscriber::{reload, EnvFilter, Registry};
+
+const DEFAULT_CONFIG_PATH: &str = "~/.semantica/config.toml";
+
+/// Log filter when neither RUST_LOG nor the file sets one
+const DEFAULT_LOG_FILTER: &str = "semantica=info";
+
+/// Swaps the log filter of the running subscriber
+pub type LogFilterHandle = reload::Handle<EnvFilter, Registry>;
+
+/// Contents of the config file (every key optional)
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFile {
+    #[serde(default)]
+    pub database: DatabaseSection,
+    #[serde(default)]
+    pub rpc: RpcSection,
+    #[serde(default)]
+    pub log: LogSection,
+    #[serde(default)]
+    pub rate_limit: RateLimitSection,
+    #[serde(default)]
+    pub worker: WorkerSection,
+    #[serde(default)]
+    pub scheduler: SchedulerSection,
+    #[serde(default)]
+    pub maintenance: MaintenanceSection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DatabaseSection {
+    /// SQLite file (SEMANTICA_DB_PATH), `~` is expanded
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RpcSection {
+    /// TCP port on 127.0.0.1 (SEMANTICA_RPC_PORT)
+    pub port: Option<u16>,
+    /// Unix socket path, "default" = ~/.semantica/semantica.sock (SEMANTICA_RPC_SOCKET)
+    pub socket: Option<String>,
+    /// Socket permissions, e.g. 0o660 (SEMANTICA_RPC_SOCKET_MODE, octal)
+    pub socket_mode: Option<u32>,
+    /// Serve TCP next to the socket (SEMANTICA_RPC_TCP)
+    pub tcp: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LogSection {
+    /// EnvFilter directives, e.g. "semantica=debug" (RUST_LOG wins)
+    pub level: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RateLimitSection {
+    pub burst: Option<u32>,
+    pub per_sec: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkerSection {
+    pub cpu_throttle_percent: Option<f32>,
+    /// SEMANTICA_WORKER_CONCURRENCY
+    pub concurrency: Option<usize>,
+    /// SEMANTICA_WORKER_LEASE_MS
+    pub lease_ms: Option<i64>,
+    /// SEMANTICA_IDLE_POLL_MS
+    pub idle_poll_ms: Option<u64>,
+    /// SEMANTICA_SHUTDOWN_GRACE_SECS
+    pub shutdown_grace_secs: Option<u64>,
+    /// SEMANTICA_SUBJECT_HISTORY_LEN
+    pub subject_history_len: Option<usize>,
+    /// SEMANTICA_MAX_PANICS
+    pub max_panics: Option<i32>,
+    /// SEMANTICA_RETRY_MAX_DELAY_MS
+    pub retry_max_delay_ms: Option<i64>,
+    /// SEMANTICA_RETRY_DEADLINE_MS
+    pub retry_deadline_ms: Option<i64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SchedulerSection {
+    /// Served queues and weights, e.g. "default:3,build" (SEMANTICA_QUEUES)
+    pub queues: Option<String>,
+    /// SEMANTICA_STARVATION_MINUTES
+    pub starvation_minutes: Option<u64>,
+    /// SEMANTICA_EXPIRY_SWEEP_SECS
+    pub expiry_sweep_secs: Option<u64>,
+    /// SEMANTICA_METRICS_INTERVAL_SECS
+    pub metrics_interval_secs: Option<u64>,
+    /// SEMANTICA_METRICS_HISTORY_LEN
+    pub metrics_history_len: Option<usize>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MaintenanceSection {
+    /// VACUUM / GC only while the machine is idle (SEMANTICA_MAINTENANCE_IDLE_ONLY)
+    pub idle_only: Option<bool>,
+    pub finished_job_retention_days: Option<i64>,
+    pub artifact_retention_days: Option<i64>,
+    pub success_log_sample_rate: Option<f64>,
+}
+
+/// SEMANTICA_CONFIG, else ~/.semantica/config.toml
+pub fn config_path() -> PathBuf {
+    let path = std::env::var("SEMANTICA_CONFIG").unwrap_or_else(|_| DEFAULT_CONFIG_PATH.into());
+    PathBuf::from(shellexpand::tilde(&path).into_owned())
+}
+
+impl ConfigFile {
+    /// Parse the file (a missing file is an empty config)
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        };
+        toml::from_str(&text).with_context(|| format!("Invalid config file {}", path.display()))
+    }
+
+    /// Runtime settings: defaults, then the file, then env vars
+    pub fn runtime_settings(&self) -> RuntimeSettings {
+        let mut settings = RuntimeSettings::default();
+
+        let rate_limit = &self.rate_limit;
+        settings.rate_limit_burst = env_or("SEMANTICA_RATE_LIMIT_BURST", rate_limit.burst)
+            .unwrap_or(settings.rate_limit_burst);
+        settings.rate_limit_per_sec = env_or("SEMANTICA_RATE_LIMIT_RATE", rate_limit.per_sec)
+            .unwrap_or(settings.rate_limit_per_sec);
+
+        if let Some(percent) = self.worker.cpu_throttle_percent {
+            settings.cpu_throttle_percent = percent;
+        }
+
+        let maintenance = &self.maintenance;
+        if let Some(days) = maintenance.finished_job_retention_days {
+            settings.finished_job_retention_days = days;
+        }
+        if let Some(days) = maintenance.artifact_retention_days {
+            settings.artifact_retention_days = days;
+        }
+        // Keep only a sample of success logs past 24h (e.g. 0.05); failure logs are always kept
+        if let Some(rate) = env_or(
+            "SEMANTICA_SUCCESS_LOG_SAMPLE_RATE",
+            maintenance.success_log_sample_rate,
+        ) {
+            settings.success_log_sample_rate = rate.clamp(0.0, 1.0);
+        }
+
+        settings
+    }
+
+    /// Log filter: RUST_LOG, then the file, then semantica=info
+    pub fn log_filter(&self) -> Result<EnvFilter> {
+        if let Ok(filter) = EnvFilter::try_from_default_env() {
+            return Ok(filter);
+        }
+        let directives = self.log.level.as_deref().unwrap_or(DEFAULT_LOG_FILTER);
+        EnvFilter::try_new(directives)
+            .with_context(|| format!("Invalid log level \"{}\"", directives))
+    }
+}
+
+/// Parsed env var if set, else the file's value
+pub fn env_or<T: std::str::FromStr>(var: &str, file: Option<T>) -> Option<T> {
+    std::env::var(var)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .or(file)
+}
+
+/// Re-read the config file and apply it (SIGHUP)
+///
+/// Nothing is applied unless the whole file is valid.
+pub fn reload(path: &Path, runtime_config: &RuntimeConfig, log_filter: &LogFilterHandle) {
+    let applied = ConfigFile::load(path).and_then(|file| {
+        let filter = file.log_filter()?;
+        let changed = runtime_config.replace(file.runtime_settings())?;
+        log_filter.reload(filter)?;
+        Ok(changed)
+    });
+    match applied {
+        Ok(changed) => info!(
+            path = %path.display(),
+            settings_changed = changed,
+            settings = ?runtime_config.get(),
+            "Configuration reloaded"
+        ),
+        Err(e) => warn!(
+            path = %path.display(),
+            error = %format!("{:#}", e),
+            "Configuration reload failed, keeping the current settings"
+        ),
+    }
+}
@@ -0,0 +1,129 @@
+//! Background mode (`--daemon`) and the PID file (`--pid-file`)
+//!
+//! `--daemon` detaches the classic way before the tokio runtime exists (a
+//! fork is only safe while the process has one thread): fork, `setsid`, fork
+//! again so the daemon can never reacquire a terminal, then stdin from
+//! `/dev/null` and stdout/stderr appended to the log file. The working
+//! directory is kept, so relative database and config paths mean the same as
+//! in the foreground.
+//!
+//! The PID file names the running daemon for `semantica daemon stop|status`.
+//! A file left by a crash is recognised by its dead PID and overwritten; one
+//! naming a live process refuses the start. It is removed on exit only while
+//! it still holds this process's PID.
+
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+
+/// PID file used by `--daemon` when `--pid-file` is not given
+pub const DEFAULT_PID_FILE: &str = "~/.semantica/semantica.pid";
+
+/// Output of a `--daemon` process
+pub const DEFAULT_DAEMON_LOG: &str = "~/.semantica/daemon.log";
+
+/// PID written in `path`, None if missing or unreadable
+pub fn read_pid(path: &Path) -> Option<u32> {
+    std::fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Whether a process with this PID exists (signal 0)
+#[cfg(unix)]
+pub fn is_alive(pid: u32) -> bool {
+    use nix::errno::Errno;
+    use nix::unistd::Pid;
+
+    match nix::sys::signal::kill(Pid::from_raw(pid as i32), None) {
+        Ok(()) => true,
+        // Alive, owned by another user
+        Err(Errno::EPERM) => true,
+        Err(_) => false,
+    }
+}
+
+#[cfg(not(unix))]
+pub fn is_alive(_pid: u32) -> bool {
+    false
+}
+
+/// Fail if the PID file names a live process (a stale file is fine)
+pub fn ensure_not_running(path: &Path) -> Result<()> {
+    match read_pid(path) {
+        Some(pid) if pid != std::process::id() && is_alive(pid) => bail!(
+            "Semantica daemon already running (PID {}, pid file {})",
+            pid,
+            path.display()
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// Detach from the terminal, output appended to `log_file`
+///
+/// Must run before any thread is started. Returns in the daemon process only;
+/// the intermediate processes exit.
+#[cfg(unix)]
+pub fn detach(log_file: &Path) -> Result<()> {
+    use nix::unistd::{dup2, fork, setsid, ForkResult};
+    use std::fs::{File, OpenOptions};
+    use std::os::fd::AsRawFd;
+
+    if let Some(dir) = log_file.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    // Opened before forking so a bad path fails in the foreground
+    let log = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_file)
+        .with_context(|| format!("Failed to open daemon log {}", log_file.display()))?;
+    let null = File::open("/dev/null").context("Failed to open /dev/null")?;
+
+    // SAFETY: single-threaded here (called before the runtime is built)
+    if let ForkResult::Parent { .. } = unsafe { fork() }.context("fork failed")? {
+        std::process::exit(0);
+    }
+    setsid().context("setsid failed")?;
+    // SAFETY: still single-threaded
+    if let ForkResult::Parent { .. } = unsafe { fork() }.context("fork failed")? {
+        std::process::exit(0);
+    }
+
+    dup2(null.as_raw_fd(), 0).context("Failed to redirect stdin")?;
+    dup2(log.as_raw_fd(), 1).context("Failed to redirect stdout")?;
+    dup2(log.as_raw_fd(), 2).context("Failed to redirect stderr")?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub fn detach(_log_file: &Path) -> Result<()> {
+    bail!("--daemon is only supported on Unix")
+}
+
+/// PID file of this process, removed on drop
+#[derive(Debug)]
+pub struct PidFile {
+    path: PathBuf,
+}
+
+impl PidFile {
+    /// Write this process's PID to `path`
+    pub fn create(path: PathBuf) -> Result<Self> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        std::fs::write(&path, format!("{}\n", std::process::id()))
+            .with_context(|| format!("Failed to write pid file {}", path.display()))?;
+        Ok(Self { path })
+    }
+}
+
+impl Drop for PidFile {
+    fn drop(&mut self) {
+        // A newer daemon may have taken the file over
+        if read_pid(&self.path) == Some(std::process::id()) {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
@@ -1,37 +1,137 @@
 //! Semantica Task Engine - Main Entry Point
 //! Phase 1: MVP with JSON-RPC Server + Worker
 
+mod config;
+mod daemonize;
+#[cfg(feature = "forward")]
+mod remote_daemon;
+mod systemd;
 mod telemetry;
 
 use anyhow::Result;
+use clap::Parser;
+use std::path::PathBuf;
 use std::sync::Arc;
 use tracing::info;
-use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+use tracing_subscriber::{fmt, layer::SubscriberExt, reload, util::SubscriberInitExt};
 
 // Import workspace crates
-use semantica_api_rpc::{server::RpcServerConfig, RpcServer};
-use semantica_core::application::recovery::RecoveryService;
+use config::ConfigFile;
+#[cfg(feature = "forward")]
+use remote_daemon::SdkRemoteDaemon;
+use semantica_api_rpc::{
+    server::{default_socket_path, RpcServerConfig, DEFAULT_SOCKET_MODE},
+    QueueRateLimits, RpcServer,
+};
+use semantica_core::application::expiry_sweeper::DEFAULT_EXPIRY_SWEEP_INTERVAL;
+#[cfg(feature = "forward")]
+use semantica_core::application::forwarder::DEFAULT_FORWARD_INTERVAL;
+use semantica_core::application::idle_budget::DEFAULT_SAMPLE_INTERVAL;
+use semantica_core::application::lease::{DEFAULT_LEASE_MS, MAX_LEASE_MS, MIN_LEASE_MS};
+use semantica_core::application::memory_ceiling::DEFAULT_MEMORY_SAMPLE_INTERVAL;
+use semantica_core::application::metrics_history::{
+    DEFAULT_METRICS_HISTORY_LEN, DEFAULT_METRICS_INTERVAL,
+};
 use semantica_core::application::retry::RetryPolicy;
-use semantica_core::application::worker::{shutdown_channel, Worker};
-use semantica_core::application::MaintenanceScheduler; // Phase 4
+use semantica_core::application::stalled::{ExpectedDurations, DEFAULT_STALL_CHECK_INTERVAL};
+use semantica_core::application::starvation::DEFAULT_MAX_QUEUE_WAIT;
+use semantica_core::application::worker::constants::{
+    DEFAULT_MAX_PANICS, SHUTDOWN_GRACE_PERIOD, SIGNALLED_IDLE_POLL_DURATION, SUBJECT_HISTORY_LEN,
+    WORKER_SLOTS,
+};
+use semantica_core::application::FailureBundler;
+#[cfg(feature = "forward")]
+use semantica_core::application::Forwarder;
+use semantica_core::application::IdleBudgetPolicy;
+use semantica_core::application::Lockdown;
+use semantica_core::application::MemoryCeilingPolicy;
+use semantica_core::application::{InterceptorChain, QueueValidators};
+use semantica_core::application::{
+    PolicyChain, RetryPolicies, RuntimeConfig, SchedulingPolicy, StalledJobDetector, StateFeed,
+};
+use semantica_core::domain::{QueueWaitSlos, QueueWeights};
 use semantica_core::port::id_provider::UuidProvider;
-use semantica_core::port::time_provider::SystemTimeProvider;
-use semantica_core::port::MaintenanceConfig; // Phase 4
-use semantica_infra_sqlite::{create_pool, run_migrations, SqliteJobRepository, SqliteMaintenance}; // Phase 4
-use semantica_infra_system::{SubprocessExecutor, SystemProbeImpl};
+use semantica_core::port::{ArtifactStorage, JobArchive, LogNotifier, Notifier};
+use semantica_engine::{EngineBuilder, EngineConfig};
+use semantica_infra_sqlite::{SqliteAuditLog, SqliteJobRepository}; // Phase 4
+use semantica_infra_system::job_archive::DEFAULT_SEGMENT_BYTES;
+use semantica_infra_system::{
+    lower_own_priority, ArchiveCompression, ExitCodeMaps, FileNotifier, LocalArtifactStorage,
+    ProbeConfig, ResourceHintConfig, ResourceHints, SegmentJobArchive, SubprocessExecutor,
+    TarBundleWriter,
+};
+#[cfg(feature = "s3")]
+use semantica_infra_system::{S3ArtifactStorage, S3Config};
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 const DEFAULT_DB_PATH: &str = "~/.semantica/meta.db";
-const DEFAULT_QUEUE: &str = "default";
 
-#[tokio::main]
-async fn main() -> Result<()> {
+#[cfg(feature = "forward")]
+use semantica_engine::config::DEFAULT_QUEUE;
+#[cfg(feature = "forward")]
+const DEFAULT_FORWARD_QUEUE: &str = "remote";
+
+#[derive(Parser)]
+#[command(
+    name = "semantica-task-engine",
+    version,
+    about = "Semantica Task Engine daemon"
+)]
+struct Args {
+    /// Detach into the background (output appended to --log-file)
+    #[arg(long)]
+    daemon: bool,
+
+    /// Write the daemon PID here (with --daemon: ~/.semantica/semantica.pid by default)
+    #[arg(long, env = "SEMANTICA_PID_FILE")]
+    pid_file: Option<PathBuf>,
+
+    /// Output of a --daemon process
+    #[arg(long, env = "SEMANTICA_DAEMON_LOG", default_value = daemonize::DEFAULT_DAEMON_LOG)]
+    log_file: PathBuf,
+}
+
+fn expand(path: &std::path::Path) -> PathBuf {
+    PathBuf::from(shellexpand::tilde(&path.to_string_lossy()).into_owned())
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let pid_file = args.pid_file.as_deref().map(expand).or_else(|| {
+        args.daemon
+            .then(|| expand(std::path::Path::new(daemonize::DEFAULT_PID_FILE)))
+    });
+
+    // Checked in the foreground so `--daemon` reports a running daemon
+    if let Some(path) = &pid_file {
+        daemonize::ensure_not_running(path)?;
+    }
+    // Before the runtime: forking is only safe with a single thread
+    if args.daemon {
+        daemonize::detach(&expand(&args.log_file))?;
+    }
+    let _pid_file = pid_file.map(daemonize::PidFile::create).transpose()?;
+    // Also before the runtime: it clears LISTEN_* from the environment
+    let activated = systemd::take_listen_fds()?;
+
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?
+        .block_on(run(activated))
+}
+
+async fn run(activated: systemd::ActivatedSockets) -> Result<()> {
     // 1. Initialize logging (JSON format for Phase 4 - ADR-050)
     let log_format = std::env::var("SEMANTICA_LOG_FORMAT").unwrap_or_else(|_| "pretty".to_string());
 
-    let env_filter = EnvFilter::try_from_default_env()
-        .or_else(|_| EnvFilter::try_new("semantica=info"))
-        .expect("Failed to create env filter");
+    // Settings file re-read on SIGHUP (log level, rate limits, throttle, maintenance)
+    let config_path = config::config_path();
+    let config_file = ConfigFile::load(&config_path)?;
+    let (env_filter, log_filter_handle) = reload::Layer::new(config_file.log_filter()?);
+
+    // OpenTelemetry (optional, Phase 4): built first so its trace layer joins the subscriber
+    let telemetry = telemetry::init_telemetry();
+    let otel = telemetry.as_ref().ok().and_then(Option::as_ref);
 
     match log_format.as_str() {
         "json" => {
@@ -39,6 +139,7 @@ async fn main() -> Result<()> {
             tracing_subscriber::registry()
                 .with(env_filter)
                 .with(fmt::layer().json())
+                .with(otel.map(|t| t.layer()))
                 .init();
         }
         _ => {
@@ -46,55 +147,264 @@ async fn main() -> Result<()> {
             tracing_subscriber::registry()
                 .with(env_filter)
                 .with(fmt::layer().pretty())
+                .with(otel.map(|t| t.layer()))
                 .init();
         }
     }
 
     info!("Semantica Task Engine v{} starting...", VERSION);
 
-    // 1.1. Initialize OpenTelemetry (optional, Phase 4)
-    if let Err(e) = telemetry::init_telemetry() {
-        tracing::warn!(error = ?e, "Failed to initialize OpenTelemetry (continuing without it)");
+    // 1.1. Report the OpenTelemetry setup now that logging works
+    let telemetry = match telemetry {
+        Ok(Some(telemetry)) => {
+            info!("OpenTelemetry traces and metrics exported to OTEL_EXPORTER_OTLP_ENDPOINT");
+            Some(telemetry)
+        }
+        Ok(None) => {
+            tracing::debug!("OpenTelemetry not configured (OTEL_EXPORTER_OTLP_ENDPOINT not set)");
+            None
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to initialize OpenTelemetry (continuing without it)");
+            None
+        }
+    };
+
+    // 1.2. Optional: run at reduced CPU / I/O priority (inherited by job subprocesses)
+    if let Some(nice) = std::env::var("SEMANTICA_DAEMON_NICE")
+        .ok()
+        .and_then(|s| s.parse::<i32>().ok())
+        .filter(|&nice| nice > 0)
+    {
+        if let Err(e) = lower_own_priority(nice) {
+            tracing::warn!(error = %e, "Failed to lower daemon priority (continuing at normal priority)");
+        }
     }
 
     // 2. Load configuration
-    let db_path = std::env::var("SEMANTICA_DB_PATH")
-        .unwrap_or_else(|_| shellexpand::tilde(DEFAULT_DB_PATH).into_owned());
+    let db_path = std::env::var("SEMANTICA_DB_PATH").unwrap_or_else(|_| {
+        let path = config_file
+            .database
+            .path
+            .as_deref()
+            .unwrap_or(DEFAULT_DB_PATH);
+        shellexpand::tilde(path).into_owned()
+    });
+
+    // Per-job output logs (tagged stdout/stderr lines) and artifacts, next to the DB by default
+    let data_dir = std::path::Path::new(&db_path)
+        .parent()
+        .unwrap_or_else(|| std::path::Path::new("."))
+        .to_path_buf();
+    let dir_from_env = |name: &str, default: &str| {
+        std::env::var(name)
+            .map(|dir| std::path::PathBuf::from(shellexpand::tilde(&dir).into_owned()))
+            .unwrap_or_else(|_| data_dir.join(default))
+    };
+    let log_dir = dir_from_env("SEMANTICA_LOG_DIR", "logs");
+    let artifact_dir = dir_from_env("SEMANTICA_ARTIFACT_DIR", "artifacts");
 
-    let rpc_port: u16 = std::env::var("SEMANTICA_RPC_PORT")
+    let rpc_port: u16 = config::env_or("SEMANTICA_RPC_PORT", config_file.rpc.port).unwrap_or(9527);
+
+    // Optional Unix socket ("default" = ~/.semantica/semantica.sock), owner-only unless
+    // SEMANTICA_RPC_SOCKET_MODE (octal) says otherwise; SEMANTICA_RPC_TCP=0 serves it alone
+    let rpc_socket = std::env::var("SEMANTICA_RPC_SOCKET")
+        .ok()
+        .or_else(|| config_file.rpc.socket.clone())
+        .filter(|path| !path.trim().is_empty())
+        .map(|path| match path.as_str() {
+            "default" => default_socket_path(),
+            _ => std::path::PathBuf::from(shellexpand::tilde(&path).into_owned()),
+        });
+    let rpc_socket_mode = std::env::var("SEMANTICA_RPC_SOCKET_MODE")
+        .ok()
+        .and_then(|s| u32::from_str_radix(s.trim(), 8).ok())
+        .or(config_file.rpc.socket_mode)
+        .filter(|&mode| mode <= 0o777)
+        .unwrap_or(DEFAULT_SOCKET_MODE);
+    let rpc_tcp = std::env::var("SEMANTICA_RPC_TCP")
+        .map(|v| !(v == "0" || v.eq_ignore_ascii_case("false")))
         .ok()
-        .and_then(|s| s.parse().ok())
-        .unwrap_or(9527);
+        .or(config_file.rpc.tcp)
+        .unwrap_or(true);
+
+    // Built-in interceptors declared in config (e.g. "reject_job_type:FOO,default_tag:ci")
+    let interceptor_spec = std::env::var("SEMANTICA_INTERCEPTORS").unwrap_or_default();
+    let mut interceptor_chain = InterceptorChain::from_spec(&interceptor_spec)
+        .map_err(|e| anyhow::anyhow!("Invalid SEMANTICA_INTERCEPTORS: {}", e))?;
+    if !interceptor_chain.is_empty() {
+        info!(count = interceptor_chain.len(), spec = %interceptor_spec, "Interceptors loaded");
+    }
+
+    // Per-queue payload rules (e.g. "untrusted:max_payload_bytes=65536,untrusted:forbid=env")
+    let validator_spec = std::env::var("SEMANTICA_QUEUE_VALIDATORS").unwrap_or_default();
+    let queue_validators = QueueValidators::from_spec(&validator_spec)
+        .map_err(|e| anyhow::anyhow!("Invalid SEMANTICA_QUEUE_VALIDATORS: {}", e))?;
+    if !queue_validators.is_empty() {
+        info!(count = queue_validators.len(), spec = %validator_spec, "Queue payload validators loaded");
+    }
+
+    // Per-queue enqueue limits (e.g. "code_intel:50/20" = burst 50, 20/sec)
+    let queue_rate_limits = QueueRateLimits::from_spec(
+        &std::env::var("SEMANTICA_QUEUE_RATE_LIMITS").unwrap_or_default(),
+    )
+    .map_err(|e| anyhow::anyhow!("Invalid SEMANTICA_QUEUE_RATE_LIMITS: {}", e))?;
+    if !queue_rate_limits.is_empty() {
+        info!(limits = ?queue_rate_limits.limits(), "Queue rate limits loaded");
+    }
+
+    // Per-queue wait targets reported in admin.stats.v1 (e.g. "interactive:2000")
+    let wait_slos =
+        QueueWaitSlos::from_spec(&std::env::var("SEMANTICA_QUEUE_WAIT_SLOS").unwrap_or_default())
+            .map_err(|e| anyhow::anyhow!("Invalid SEMANTICA_QUEUE_WAIT_SLOS: {}", e))?;
+    if !wait_slos.is_empty() {
+        info!(targets = ?wait_slos.targets(), "Queue wait SLOs loaded");
+    }
+
+    // Settings tunable at runtime (admin.config.set.v1, SIGHUP), seeded from
+    // the config file and env vars
+    let runtime_settings = config_file.runtime_settings();
+    if runtime_settings.success_log_sample_rate < 1.0 {
+        info!(
+            sample_rate = runtime_settings.success_log_sample_rate,
+            "Success log sampling enabled"
+        );
+    }
+    let runtime_config = Arc::new(RuntimeConfig::new(runtime_settings));
+
+    // Notification channel (maintenance reports); default: log only
+    let notifier: Arc<dyn Notifier> = match std::env::var("SEMANTICA_NOTIFY_FILE") {
+        Ok(path) => {
+            let path = shellexpand::tilde(&path).into_owned();
+            info!(path = %path, "Notifications written to file");
+            Arc::new(FileNotifier::new(path))
+        }
+        Err(_) => Arc::new(LogNotifier),
+    };
 
     info!(db_path = %db_path, "Initializing database...");
 
-    // 3. Initialize database
-    let pool = create_pool(&db_path)
-        .await
-        .map_err(|e| anyhow::anyhow!("DB pool creation failed: {}", e))?;
-    run_migrations(&pool)
-        .await
-        .map_err(|e| anyhow::anyhow!("Migration failed: {}", e))?;
+    // 3. Initialize database (the engine's wiring, shared with embedded use)
+    let engine_builder = EngineBuilder::open(&db_path).await?;
 
     // 4. Setup dependencies (DI wiring)
-    let time_provider = Arc::new(SystemTimeProvider);
+    let pool = engine_builder.pool().clone();
+    let time_provider = engine_builder.time_provider();
     let id_provider = Arc::new(UuidProvider);
-    let job_repo = Arc::new(SqliteJobRepository::new(
-        pool.clone(),
-        time_provider.clone(),
-    ));
+    let job_repo = engine_builder.job_repo();
     let tx_job_repo = Arc::new(SqliteJobRepository::new(
         pool.clone(),
         time_provider.clone(),
     ));
 
-    let task_executor = Arc::new(SubprocessExecutor::new(
+    let probe_config = ProbeConfig::from_env().map_err(|e| anyhow::anyhow!(e))?;
+    info!(
+        backend = ?probe_config.backend,
+        disk_metrics = ?probe_config.disk_metrics,
+        sample_interval = ?probe_config.sample_interval,
+        "System probe configured"
+    );
+    let system_probe = probe_config.build().map_err(|e| anyhow::anyhow!(e))?;
+
+    // Artifact backend: S3-compatible bucket when configured, else the local artifact dir
+    #[cfg(feature = "s3")]
+    let artifact_storage: Arc<dyn ArtifactStorage> = match S3Config::from_env()
+        .map_err(|e| anyhow::anyhow!(e))?
+    {
+        Some(config) => {
+            info!(endpoint = %config.endpoint, bucket = %config.bucket, "Artifacts stored in S3");
+            Arc::new(
+                S3ArtifactStorage::new(config, time_provider.clone())
+                    .map_err(|e| anyhow::anyhow!(e))?,
+            )
+        }
+        None => Arc::new(LocalArtifactStorage::new(artifact_dir)),
+    };
+    #[cfg(not(feature = "s3"))]
+    let artifact_storage: Arc<dyn ArtifactStorage> = {
+        if std::env::var("SEMANTICA_ARTIFACT_S3_BUCKET").is_ok_and(|b| !b.trim().is_empty()) {
+            anyhow::bail!(
+                "SEMANTICA_ARTIFACT_S3_BUCKET is set but this daemon was built without the \"s3\" feature"
+            );
+        }
+        Arc::new(LocalArtifactStorage::new(artifact_dir))
+    };
+
+    // Diagnostic bundle (job row, attempts, log tail, metrics) for every FAILED job
+    interceptor_chain.register(Arc::new(FailureBundler::new(
+        job_repo.clone(),
+        system_probe.clone(),
+        Arc::new(TarBundleWriter::new()),
+        artifact_storage.clone(),
         time_provider.clone(),
-        vec!["PATH".to_string(), "HOME".to_string(), "USER".to_string()],
-    ));
+        VERSION,
+    )));
+
+    // Pops and completions streamed to jobs.subscribe.v1 subscribers
+    let state_feed = Arc::new(StateFeed::new(time_provider.clone()));
+    interceptor_chain.register(state_feed.clone());
+    let interceptors = Arc::new(interceptor_chain);
+
+    // Build parallelism hints scale down while the user is active
+    let resource_hint_config = ResourceHintConfig::from_env().map_err(|e| anyhow::anyhow!(e))?;
+    info!(
+        max_jobs = resource_hint_config.max_jobs,
+        min_jobs = resource_hint_config.min_jobs,
+        nice = ?resource_hint_config.nice,
+        cpu_affinity = ?resource_hint_config.cpu_affinity,
+        "Resource hints configured"
+    );
 
-    let system_probe = Arc::new(SystemProbeImpl::new());
-    let retry_policy = Arc::new(RetryPolicy::new(time_provider.clone(), 1000));
+    let mut task_executor = SubprocessExecutor::new(
+        time_provider.clone(),
+        vec!["PATH".to_string(), "HOME".to_string(), "USER".to_string()],
+    )
+    .with_resource_hints(ResourceHints::new(
+        system_probe.clone(),
+        resource_hint_config,
+    ))
+    .with_log_dir(&log_dir);
+    // Tool-specific exit codes (e.g. "LINT:2=success/3=retry"); a job's `exit_codes` payload wins
+    if let Ok(spec) = std::env::var("SEMANTICA_EXIT_CODES") {
+        let exit_codes = ExitCodeMaps::from_spec(&spec)
+            .map_err(|e| anyhow::anyhow!("Invalid SEMANTICA_EXIT_CODES: {}", e))?;
+        task_executor = task_executor.with_exit_codes(exit_codes);
+    }
+    if rpc_tcp {
+        // Lets job scripts report progress with `semantica-cli progress`
+        task_executor = task_executor.with_rpc_url(format!("http://127.0.0.1:{}", rpc_port));
+    }
+    let task_executor = Arc::new(task_executor);
+    // Poison-job detection: panicked executions before a job is quarantined
+    let max_panics = config::env_or("SEMANTICA_MAX_PANICS", config_file.worker.max_panics)
+        .unwrap_or(DEFAULT_MAX_PANICS);
+    let mut retry_policy =
+        RetryPolicy::new(time_provider.clone(), 1000).with_max_panics(max_panics);
+    // Backoff cap and overall retry deadline (from job creation)
+    if let Some(max_delay_ms) = config::env_or(
+        "SEMANTICA_RETRY_MAX_DELAY_MS",
+        config_file.worker.retry_max_delay_ms,
+    ) {
+        retry_policy = retry_policy.with_max_delay_ms(max_delay_ms);
+    }
+    if let Some(retry_deadline_ms) = config::env_or(
+        "SEMANTICA_RETRY_DEADLINE_MS",
+        config_file.worker.retry_deadline_ms,
+    ) {
+        retry_policy = retry_policy.with_retry_deadline_ms(retry_deadline_ms);
+    }
+    let retry_policy = Arc::new(retry_policy);
+    // Per-job-type retry rules (e.g. "FETCH:10/500,BUILD:1"), editable via admin.retry_policy.*
+    let retry_policies = Arc::new(
+        RetryPolicies::from_spec(
+            retry_policy.clone(),
+            &std::env::var("SEMANTICA_RETRY_POLICIES").unwrap_or_default(),
+        )
+        .map_err(|e| anyhow::anyhow!("Invalid SEMANTICA_RETRY_POLICIES: {}", e))?,
+    );
+    if !retry_policies.rules().is_empty() {
+        info!(policies = ?retry_policies.rules(), "Retry policies loaded");
+    }
 
     // Phase 3: Create Scheduler
     let scheduler = Arc::new(semantica_core::application::scheduler::Scheduler::new(
@@ -102,91 +412,378 @@ async fn main() -> Result<()> {
         time_provider.clone(),
     ));
 
-    // 5. Run crash recovery (Phase 2)
-    info!("Running crash recovery...");
-    let recovery_service = RecoveryService::new(
-        job_repo.clone(),
-        task_executor.clone(),
-        time_provider.clone(),
-        None, // Use default recovery window
-    );
+    // Queues the worker serves and their share of its pops
+    // (e.g. "default:3,code_intel,build" = queue[:weight], weight defaults to 1)
+    let queue_spec = std::env::var("SEMANTICA_QUEUES")
+        .ok()
+        .or_else(|| config_file.scheduler.queues.clone())
+        .unwrap_or_default();
+    let queue_weights = QueueWeights::from_spec(&queue_spec)
+        .map_err(|e| anyhow::anyhow!("Invalid SEMANTICA_QUEUES: {}", e))?;
+
+    // Optional job archive: GC appends jobs here before deleting their rows
+    // (SEMANTICA_ARCHIVE_COMPRESSION = none | zstd | zstd:<level>)
+    let job_archive: Option<Arc<dyn JobArchive>> = match std::env::var("SEMANTICA_ARCHIVE_DIR") {
+        Ok(dir) if !dir.trim().is_empty() => {
+            let compression = ArchiveCompression::parse(
+                &std::env::var("SEMANTICA_ARCHIVE_COMPRESSION").unwrap_or_else(|_| "none".into()),
+            )
+            .map_err(|e| anyhow::anyhow!("Invalid SEMANTICA_ARCHIVE_COMPRESSION: {}", e))?;
+            let segment_mb: u64 = std::env::var("SEMANTICA_ARCHIVE_SEGMENT_MB")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(DEFAULT_SEGMENT_BYTES / (1024 * 1024));
+            let dir = shellexpand::tilde(&dir).into_owned();
+            info!(dir = %dir, ?compression, segment_mb, "Job archive enabled");
+            Some(Arc::new(
+                SegmentJobArchive::new(dir)
+                    .with_compression(compression)
+                    .with_max_segment_bytes(segment_mb * 1024 * 1024),
+            ))
+        }
+        _ => None,
+    };
 
-    match recovery_service.recover_orphaned_jobs().await {
-        Ok(count) => info!(recovered_jobs = count, "Crash recovery completed"),
-        Err(e) => tracing::error!(error = ?e, "Crash recovery failed"),
+    // Subprocess kill switch (admin.lockdown.v1), optionally engaged from startup
+    let lockdown = Arc::new(Lockdown::new());
+    if std::env::var("SEMANTICA_LOCKDOWN")
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+    {
+        lockdown.engage(
+            "config",
+            Some("SEMANTICA_LOCKDOWN".to_string()),
+            time_provider.now_millis(),
+        );
+        tracing::warn!("Lockdown engaged at startup, subprocess execution refused");
     }
 
-    // 6. Initialize maintenance service (needed for RPC server)
-    let maintenance = Arc::new(SqliteMaintenance::new(pool.clone(), time_provider.clone()));
+    let mut chain = PolicyChain::new();
+    chain.register(scheduler.clone());
 
-    // 7. Start JSON-RPC server
+    // Optional: cap bulk (wait_for_idle) jobs to N CPU-minutes per rolling hour
+    if let Some(minutes) = std::env::var("SEMANTICA_IDLE_BUDGET_CPU_MINUTES")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .filter(|&minutes| minutes > 0)
+    {
+        let budget = Arc::new(IdleBudgetPolicy::new(
+            system_probe.clone(),
+            time_provider.clone(),
+            std::time::Duration::from_secs(minutes * 60),
+        ));
+        tokio::spawn(budget.clone().run(DEFAULT_SAMPLE_INTERVAL));
+        chain.register(budget);
+        info!(cpu_minutes_per_hour = minutes, "Idle budget enabled");
+    }
+
+    // Optional: hold back new jobs while job subprocesses use more than N MB in total
+    if let Some(ceiling_mb) = std::env::var("SEMANTICA_MEMORY_CEILING_MB")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .filter(|&mb| mb > 0)
+    {
+        let kill_newest = std::env::var("SEMANTICA_MEMORY_CEILING_KILL")
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        let ceiling = Arc::new(
+            MemoryCeilingPolicy::new(system_probe.clone(), task_executor.clone(), ceiling_mb)
+                .with_kill_newest(kill_newest),
+        );
+        tokio::spawn(ceiling.clone().run(DEFAULT_MEMORY_SAMPLE_INTERVAL));
+        chain.register(ceiling);
+        info!(ceiling_mb, kill_newest, "Memory ceiling enabled");
+    }
+
+    let scheduling_policy: Arc<dyn SchedulingPolicy> = if chain.len() > 1 {
+        Arc::new(chain)
+    } else {
+        scheduler.clone()
+    };
+
+    let engine_config = EngineConfig {
+        queues: queue_weights,
+        // Successful results kept per subject for subject.history.v1 (0 = disabled)
+        subject_history_len: config::env_or(
+            "SEMANTICA_SUBJECT_HISTORY_LEN",
+            config_file.worker.subject_history_len,
+        )
+        .unwrap_or(SUBJECT_HISTORY_LEN),
+        // Safety-net poll of the idle worker between dispatch signals (catches
+        // jobs that become due without an enqueue, e.g. schedule_at, retry backoff)
+        idle_poll: config::env_or("SEMANTICA_IDLE_POLL_MS", config_file.worker.idle_poll_ms)
+            .filter(|&ms| ms > 0)
+            .map(std::time::Duration::from_millis)
+            .unwrap_or(SIGNALLED_IDLE_POLL_DURATION),
+        // Jobs executed at the same time (a slow BUILD no longer blocks quick jobs)
+        concurrency: config::env_or(
+            "SEMANTICA_WORKER_CONCURRENCY",
+            config_file.worker.concurrency,
+        )
+        .filter(|&n| n > 0)
+        .unwrap_or(WORKER_SLOTS),
+        // Executing jobs are leased and renewed: if the worker dies mid-job, the
+        // lease reaper reclaims the job once the lease runs out
+        lease: std::time::Duration::from_millis(
+            config::env_or("SEMANTICA_WORKER_LEASE_MS", config_file.worker.lease_ms)
+                .filter(|ms| (MIN_LEASE_MS..=MAX_LEASE_MS).contains(ms))
+                .unwrap_or(DEFAULT_LEASE_MS) as u64,
+        ),
+        // On shutdown, running jobs finish within this grace period or are killed and requeued
+        shutdown_grace: config::env_or(
+            "SEMANTICA_SHUTDOWN_GRACE_SECS",
+            config_file.worker.shutdown_grace_secs,
+        )
+        .map(std::time::Duration::from_secs)
+        .unwrap_or(SHUTDOWN_GRACE_PERIOD),
+        // Watchdog: alert when due jobs wait while the worker sits idle and unthrottled
+        max_queue_wait: config::env_or(
+            "SEMANTICA_STARVATION_MINUTES",
+            config_file.scheduler.starvation_minutes,
+        )
+        .map(|minutes| std::time::Duration::from_secs(minutes * 60))
+        .unwrap_or(DEFAULT_MAX_QUEUE_WAIT),
+        // Throughput snapshots for admin.metrics.history.v1 (trends instead of counters)
+        metrics_interval: config::env_or(
+            "SEMANTICA_METRICS_INTERVAL_SECS",
+            config_file.scheduler.metrics_interval_secs,
+        )
+        .filter(|&secs| secs > 0)
+        .map(std::time::Duration::from_secs)
+        .unwrap_or(DEFAULT_METRICS_INTERVAL),
+        metrics_history_len: config::env_or(
+            "SEMANTICA_METRICS_HISTORY_LEN",
+            config_file.scheduler.metrics_history_len,
+        )
+        .unwrap_or(DEFAULT_METRICS_HISTORY_LEN),
+        // Expire jobs whose TTL/deadline passes while they wait (paused queue, backlog)
+        expiry_sweep_interval: config::env_or(
+            "SEMANTICA_EXPIRY_SWEEP_SECS",
+            config_file.scheduler.expiry_sweep_secs,
+        )
+        .filter(|&secs| secs > 0)
+        .map(std::time::Duration::from_secs)
+        .unwrap_or(DEFAULT_EXPIRY_SWEEP_INTERVAL),
+        // Optional: VACUUM / GC only while the machine is idle
+        maintenance_idle_only: std::env::var("SEMANTICA_MAINTENANCE_IDLE_ONLY")
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .ok()
+            .or(config_file.maintenance.idle_only)
+            .unwrap_or(false),
+        ..EngineConfig::default()
+    };
+
+    // 5. Crash recovery, then the worker, maintenance and background loops
+    let stalled_kill_executor = task_executor.clone();
+    let mut engine_builder = engine_builder
+        .with_config(engine_config)
+        .with_task_executor(task_executor)
+        .with_system_probe(system_probe)
+        .with_scheduler(scheduler) // Phase 3
+        .with_scheduling_policy(scheduling_policy)
+        .with_retry_policies(retry_policies)
+        .with_interceptors(interceptors)
+        .with_queue_validators(Arc::new(queue_validators))
+        .with_notifier(notifier.clone())
+        .with_runtime_config(runtime_config.clone())
+        .with_lockdown(lockdown.clone())
+        .with_artifact_storage(artifact_storage.clone());
+    if let Some(job_archive) = &job_archive {
+        engine_builder = engine_builder.with_job_archive(job_archive.clone());
+    }
+    if let Some(telemetry) = &telemetry {
+        engine_builder = engine_builder.with_worker_metrics(telemetry.worker_metrics());
+    }
+    let engine = engine_builder.start().await?;
+
+    // Watchdog: flag jobs running far longer than their job type usually takes
+    // (SEMANTICA_STALL_EXPECTED="BUILD:1800,..." in seconds, else 3 x p95)
+    let expected_durations = match std::env::var("SEMANTICA_STALL_EXPECTED") {
+        Ok(spec) => ExpectedDurations::from_spec(&spec)
+            .map_err(|e| anyhow::anyhow!("Invalid SEMANTICA_STALL_EXPECTED: {}", e))?,
+        Err(_) => ExpectedDurations::default(),
+    };
+    let mut stalled_detector = StalledJobDetector::new(job_repo.clone(), time_provider.clone())
+        .with_expected_durations(expected_durations)
+        .with_notifier(notifier);
+    if std::env::var("SEMANTICA_STALL_KILL")
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+    {
+        stalled_detector = stalled_detector.with_auto_kill(stalled_kill_executor);
+    }
+    if let Some(telemetry) = &telemetry {
+        stalled_detector = stalled_detector.with_metrics(telemetry.worker_metrics());
+    }
+    tokio::spawn(Arc::new(stalled_detector).run(DEFAULT_STALL_CHECK_INTERVAL));
+
+    // 6. Start JSON-RPC server (readiness: the worker is already running)
     info!("Starting JSON-RPC server...");
-    let rpc_config = RpcServerConfig {
+    let mut rpc_config = RpcServerConfig {
+        socket_path: rpc_socket,
+        socket_mode: rpc_socket_mode,
+        tcp: rpc_tcp,
         port: rpc_port,
         ..Default::default()
     };
-    let rpc_server = RpcServer::new(
+    // Socket activation: systemd's sockets replace the configured ones
+    if !activated.is_empty() {
+        info!("Serving RPC on the sockets passed by systemd");
+        rpc_config.socket_path = None;
+        rpc_config.tcp = activated.tcp.is_some();
+        rpc_config.tcp_listener = activated.tcp;
+        #[cfg(unix)]
+        {
+            rpc_config.unix_listener = activated.unix;
+        }
+    }
+    let mut rpc_server = RpcServer::new(
         rpc_config,
         tx_job_repo,
         job_repo.clone(),
         id_provider.clone(),
         time_provider.clone(),
-        maintenance.clone(),
-    );
+        engine.maintenance(),
+    )
+    .with_interceptors(engine.interceptors())
+    .with_queue_validators(engine.queue_validators())
+    .with_lease_service(engine.lease_service())
+    .with_retry_policies(engine.retry_policies())
+    .with_pause_registry(engine.queue_pauses())
+    .with_lockdown(lockdown.clone())
+    .with_drain(engine.drain())
+    .with_worker_activity(engine.worker_activity())
+    .with_running_jobs(engine.running_jobs())
+    .with_runtime_config(runtime_config.clone())
+    .with_queue_rate_limits(queue_rate_limits)
+    .with_wait_slos(wait_slos)
+    .with_state_feed(state_feed)
+    .with_dispatch_signal(engine.dispatch_signal())
+    .with_artifact_storage(artifact_storage.clone())
+    .with_log_dir(log_dir)
+    .with_audit_log(Arc::new(SqliteAuditLog::new(pool.clone())));
+    if let Some(job_archive) = job_archive {
+        rpc_server = rpc_server.with_job_archive(job_archive);
+    }
     let rpc_handle = rpc_server
         .start()
         .await
         .map_err(|e| anyhow::anyhow!("RPC server start failed: {}", e))?;
 
-    // 7. Start Worker (job processing loop)
-    info!("Starting worker...");
-    let (shutdown_tx, shutdown_rx) = shutdown_channel();
-
-    let worker = Worker::new(
-        DEFAULT_QUEUE,
-        job_repo.clone(),
-        task_executor,
-        system_probe,
-        retry_policy,
-        scheduler, // Phase 3
-        time_provider.clone(),
-    );
-
-    let worker_handle = tokio::spawn(async move {
-        if let Err(e) = worker.run(shutdown_rx).await {
-            tracing::error!(error = ?e, "Worker failed");
+    // Optional: relay a queue to another daemon (e.g. heavy builds to a desktop)
+    if let Ok(forward_url) = std::env::var("SEMANTICA_FORWARD_URL") {
+        #[cfg(not(feature = "forward"))]
+        anyhow::bail!(
+            "SEMANTICA_FORWARD_URL ({}) is set but this daemon was built without the \"forward\" feature",
+            forward_url
+        );
+        #[cfg(feature = "forward")]
+        {
+            let forward_queue = std::env::var("SEMANTICA_FORWARD_QUEUE")
+                .unwrap_or_else(|_| DEFAULT_FORWARD_QUEUE.to_string());
+            let remote_queue = std::env::var("SEMANTICA_FORWARD_REMOTE_QUEUE")
+                .unwrap_or_else(|_| DEFAULT_QUEUE.to_string());
+
+            let remote = SdkRemoteDaemon::connect(&forward_url, remote_queue.clone())
+                .await
+                .map_err(|e| anyhow::anyhow!("Forwarder setup failed: {}", e))?;
+            info!(
+                url = %forward_url,
+                queue = %forward_queue,
+                remote_queue = %remote_queue,
+                "Queue forwarding enabled"
+            );
+
+            let forwarder = Arc::new(Forwarder::new(
+                job_repo.clone(),
+                Arc::new(remote),
+                forward_queue,
+                time_provider.clone(),
+            ));
+            tokio::spawn(forwarder.run(DEFAULT_FORWARD_INTERVAL));
         }
-    });
+    }
 
-    // 8. Start Maintenance Scheduler (Phase 4)
-    info!("Starting maintenance scheduler...");
-    let maintenance_config = MaintenanceConfig::default(); // 7 days retention
-    let maintenance_scheduler = MaintenanceScheduler::new(
-        maintenance,
-        maintenance_config,
-        24, // Run every 24 hours
-    );
+    // Panic button without RPC access: SIGUSR1 engages the lockdown, SIGUSR2 lifts it
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        let mut engage = signal(SignalKind::user_defined1())?;
+        let mut lift = signal(SignalKind::user_defined2())?;
+        let lockdown = lockdown.clone();
+        let time_provider = time_provider.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = engage.recv() => {
+                        if lockdown.engage("signal", Some("SIGUSR1".to_string()), time_provider.now_millis()) {
+                            tracing::warn!("Lockdown engaged by SIGUSR1, subprocess execution refused");
+                        }
+                    }
+                    _ = lift.recv() => {
+                        if lockdown.lift() {
+                            tracing::warn!("Lockdown lifted by SIGUSR2");
+                        }
+                    }
+                }
+            }
+        });
+    }
 
-    tokio::spawn(async move {
-        maintenance_scheduler.run().await;
-    });
+    // SIGHUP re-reads the config file (log level, rate limits, throttle, maintenance)
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        let mut hangup = signal(SignalKind::hangup())?;
+        let runtime_config = runtime_config.clone();
+        tokio::spawn(async move {
+            while hangup.recv().await.is_some() {
+                config::reload(&config_path, &runtime_config, &log_filter_handle);
+            }
+        });
+    }
 
     info!("✅ System ready. Waiting for tasks...");
     info!("Press Ctrl+C to shutdown");
+    systemd::notify("READY=1\nSTATUS=Waiting for tasks");
+    if let Some(interval) = systemd::watchdog_interval() {
+        info!(?interval, "Pinging the systemd watchdog");
+        systemd::spawn_watchdog(interval, engine.worker_activity(), time_provider.clone());
+    }
 
-    // 9. Wait for shutdown signal
-    tokio::signal::ctrl_c().await?;
+    // 7. Wait for shutdown signal (Ctrl+C, or SIGTERM from `semantica daemon stop`)
+    shutdown_signal().await?;
 
     info!("Shutdown signal received. Exiting gracefully...");
+    systemd::notify("STOPPING=1");
 
-    // 10. Graceful shutdown
-    shutdown_tx.shutdown();
+    // 8. Graceful shutdown
     rpc_handle
         .stop()
         .map_err(|e| anyhow::anyhow!("RPC server stop failed: {}", e))?;
-    let _ = tokio::time::timeout(std::time::Duration::from_secs(5), worker_handle).await;
+    // Grace period, then SIGTERM / SIGKILL of the jobs left and their requeue
+    engine.shutdown().await;
+    if let Some(telemetry) = telemetry {
+        telemetry.shutdown();
+    }
 
     info!("Shutdown complete.");
 
     Ok(())
 }
+
+/// Ctrl+C, or SIGTERM on Unix
+async fn shutdown_signal() -> Result<()> {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        let mut terminate = signal(SignalKind::terminate())?;
+        tokio::select! {
+            result = tokio::signal::ctrl_c() => result?,
+            _ = terminate.recv() => {}
+        }
+    }
+    #[cfg(not(unix))]
+    tokio::signal::ctrl_c().await?;
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! Remote Daemon Adapter
+//!
+//! Implements the `RemoteDaemon` port with the Rust SDK, for forwarding a
+//! local queue to another Semantica daemon.
+
+use async_trait::async_trait;
+use semantica_core::domain::{Job, JobState};
+use semantica_core::error::{AppError, Result};
+use semantica_core::port::{RemoteDaemon, RemoteSubmit};
+use semantica_task_sdk::{EnqueueRequest, SdkError, SemanticaTaskClient};
+
+// JSON-RPC NOT_FOUND (ADR-020)
+const RPC_NOT_FOUND: i32 = 4001;
+
+/// Remote Semantica daemon reached over JSON-RPC
+pub struct SdkRemoteDaemon {
+    client: SemanticaTaskClient,
+    endpoint: String,
+    remote_queue: String,
+}
+
+impl SdkRemoteDaemon {
+    /// Connect to the remote daemon
+    ///
+    /// # Arguments
+    /// * `url` - Remote RPC URL (e.g. `http://desktop.local:9527`)
+    /// * `remote_queue` - Queue the jobs are enqueued into on the remote side
+    pub async fn connect(url: &str, remote_queue: impl Into<String>) -> Result<Self> {
+        let client = SemanticaTaskClient::connect(url)
+            .await
+            .map_err(|e| AppError::Config(format!("Invalid forward URL '{}': {}", url, e)))?
+            .with_client_id("forwarder");
+
+        Ok(Self {
+            client,
+            endpoint: url.to_string(),
+            remote_queue: remote_queue.into(),
+        })
+    }
+}
+
+#[async_trait]
+impl RemoteDaemon for SdkRemoteDaemon {
+    fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    async fn submit(&self, job: &Job, idempotency_key: &str) -> Result<RemoteSubmit> {
+        let request = EnqueueRequest {
+            job_type: job.job_type.as_str().to_string(),
+            queue: self.remote_queue.clone(),
+            subject_key: job.subject_key.clone(),
+            payload: job.payload.as_value().clone(),
+            priority: job.priority,
+            idempotency_key: Some(idempotency_key.to_string()),
+            // The remote execution joins the same trace as the local job
+            traceparent: job.trace_id.clone(),
+            job_class: Some(job.job_class.to_string()),
+            fallback_payloads: job
+                .fallback_payloads
+                .iter()
+                .map(|p| p.as_value().clone())
+                .collect(),
+            options: Default::default(),
+        };
+
+        match self.client.enqueue(request).await {
+            Ok(response) => Ok(RemoteSubmit::Accepted {
+                remote_job_id: response.job_id,
+            }),
+            Err(e) if e.is_unreachable() => Err(remote_error(e)),
+            Err(SdkError::Rpc { message, .. }) => Ok(RemoteSubmit::Rejected { reason: message }),
+            Err(e) => Err(remote_error(e)),
+        }
+    }
+
+    async fn state(&self, remote_job_id: &str) -> Result<Option<JobState>> {
+        match self.client.inspect(remote_job_id).await {
+            Ok(job) => serde_json::from_value(serde_json::Value::String(job.state.clone()))
+                .map(Some)
+                .map_err(|_| {
+                    AppError::Internal(format!("Unknown remote job state '{}'", job.state))
+                }),
+            Err(SdkError::Rpc { code, .. }) if code == RPC_NOT_FOUND => Ok(None),
+            Err(e) => Err(remote_error(e)),
+        }
+    }
+}
+
+fn remote_error(e: SdkError) -> AppError {
+    AppError::Internal(format!("Remote daemon error: {}", e))
+}
@@ -0,0 +1,188 @@
+//! systemd integration: `Type=notify` readiness, watchdog and socket activation
+//!
+//! Everything keys off the environment systemd sets for a unit, so outside
+//! one it is a no-op:
+//! - `NOTIFY_SOCKET`: READY=1 once the RPC server serves, STOPPING=1 on
+//!   shutdown and, with `WatchdogSec=` (`WATCHDOG_USEC`), WATCHDOG=1 at half
+//!   the interval while the worker loop ticks, so a wedged loop gets the
+//!   service restarted
+//! - `LISTEN_FDS` / `LISTEN_PID`: the sockets of a `.socket` unit (at most one
+//!   TCP and one Unix stream socket) are served instead of the `[rpc]` ones;
+//!   connections made while the daemon starts wait in their backlog
+
+use anyhow::{bail, Context, Result};
+use semantica_core::application::worker::constants::WORKER_STALE_TICK_MS;
+use semantica_core::application::worker::WorkerActivity;
+use semantica_core::port::{elapsed_ms, TimeProvider};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// First descriptor passed by systemd (SD_LISTEN_FDS_START)
+#[cfg(unix)]
+const LISTEN_FDS_START: i32 = 3;
+
+/// RPC sockets passed by systemd
+#[derive(Debug, Default)]
+pub struct ActivatedSockets {
+    pub tcp: Option<std::net::TcpListener>,
+    #[cfg(unix)]
+    pub unix: Option<std::os::unix::net::UnixListener>,
+}
+
+impl ActivatedSockets {
+    pub fn is_empty(&self) -> bool {
+        #[cfg(unix)]
+        if self.unix.is_some() {
+            return false;
+        }
+        self.tcp.is_none()
+    }
+}
+
+/// Take over the sockets systemd passed to this process (`sd_listen_fds`)
+///
+/// Clears `LISTEN_*` so job subprocesses do not see them; call it before any
+/// thread starts.
+#[cfg(unix)]
+pub fn take_listen_fds() -> Result<ActivatedSockets> {
+    use nix::fcntl::{fcntl, FcntlArg, FdFlag};
+    use socket2::{Socket, Type};
+    use std::os::fd::{FromRawFd, OwnedFd};
+
+    let pid = std::env::var("LISTEN_PID").ok();
+    let count = std::env::var("LISTEN_FDS").ok();
+    for var in ["LISTEN_PID", "LISTEN_FDS", "LISTEN_FDNAMES"] {
+        std::env::remove_var(var);
+    }
+    let mut sockets = ActivatedSockets::default();
+    let (Some(pid), Some(count)) = (pid, count) else {
+        return Ok(sockets);
+    };
+    // Inherited from a parent they were meant for
+    if pid.parse::<u32>().ok() != Some(std::process::id()) {
+        return Ok(sockets);
+    }
+    let count: i32 = count
+        .parse()
+        .with_context(|| format!("Invalid LISTEN_FDS: {}", count))?;
+
+    for fd in LISTEN_FDS_START..LISTEN_FDS_START + count {
+        fcntl(fd, FcntlArg::F_SETFD(FdFlag::FD_CLOEXEC))
+            .with_context(|| format!("Socket passed by systemd (fd {}) is not open", fd))?;
+        // SAFETY: systemd handed this descriptor to this process (LISTEN_PID) to own
+        let socket = Socket::from(unsafe { OwnedFd::from_raw_fd(fd) });
+        let addr = socket.local_addr()?;
+        if socket.r#type()? != Type::STREAM {
+            bail!(
+                "Socket passed by systemd (fd {}) is not a stream socket",
+                fd
+            );
+        }
+        if addr.as_socket().is_some() && sockets.tcp.is_none() {
+            sockets.tcp = Some(socket.into());
+        } else if addr.is_unix() && sockets.unix.is_none() {
+            sockets.unix = Some(OwnedFd::from(socket).into());
+        } else {
+            bail!(
+                "Unexpected socket passed by systemd (fd {}): expected at most one TCP and one Unix socket",
+                fd
+            );
+        }
+    }
+    Ok(sockets)
+}
+
+#[cfg(not(unix))]
+pub fn take_listen_fds() -> Result<ActivatedSockets> {
+    Ok(ActivatedSockets::default())
+}
+
+/// Send a state change to systemd (`sd_notify`), ignored outside a notify unit
+pub fn notify(state: &str) {
+    let Some(socket) = std::env::var_os("NOTIFY_SOCKET") else {
+        return;
+    };
+    if let Err(e) = send(&socket, state) {
+        tracing::debug!(error = %e, state, "sd_notify failed");
+    }
+}
+
+#[cfg(unix)]
+fn send(socket: &std::ffi::OsStr, state: &str) -> std::io::Result<()> {
+    use std::os::unix::ffi::OsStrExt;
+    use std::os::unix::net::UnixDatagram;
+
+    let datagram = UnixDatagram::unbound()?;
+    match socket.as_bytes().strip_prefix(b"@") {
+        #[cfg(target_os = "linux")]
+        Some(name) => {
+            use std::os::linux::net::SocketAddrExt;
+            let addr = std::os::unix::net::SocketAddr::from_abstract_name(name)?;
+            datagram.send_to_addr(state.as_bytes(), &addr)?;
+        }
+        #[cfg(not(target_os = "linux"))]
+        Some(_) => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "abstract NOTIFY_SOCKET",
+            ))
+        }
+        None => {
+            datagram.send_to(state.as_bytes(), socket)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn send(_socket: &std::ffi::OsStr, _state: &str) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// `WatchdogSec=` of the unit (None = no watchdog for this process)
+pub fn watchdog_interval() -> Option<Duration> {
+    if let Ok(pid) = std::env::var("WATCHDOG_PID") {
+        if pid.parse::<u32>().ok() != Some(std::process::id()) {
+            return None;
+        }
+    }
+    std::env::var("WATCHDOG_USEC")
+        .ok()?
+        .parse::<u64>()
+        .ok()
+        .filter(|&usec| usec > 0)
+        .map(Duration::from_micros)
+}
+
+/// Ping the watchdog at half `interval` while the worker loop is alive
+///
+/// A loop without a tick for WORKER_STALE_TICK_MS that runs no job is wedged:
+/// the pings stop and systemd restarts the service. A loop the supervisor is
+/// restarting still pings, that is recovery already under way.
+pub fn spawn_watchdog(
+    interval: Duration,
+    activity: Arc<WorkerActivity>,
+    time_provider: Arc<dyn TimeProvider>,
+) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval / 2);
+        let mut withheld = false;
+        loop {
+            ticker.tick().await;
+            let stale = !activity.is_busy()
+                && activity.last_tick().is_some_and(|at| {
+                    elapsed_ms(time_provider.now_millis(), at) > WORKER_STALE_TICK_MS
+                });
+            if stale {
+                if !withheld {
+                    tracing::error!(
+                        "Worker loop stopped ticking, withholding systemd watchdog pings"
+                    );
+                }
+            } else {
+                notify("WATCHDOG=1");
+            }
+            withheld = stale;
+        }
+    });
+}
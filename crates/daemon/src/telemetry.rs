@@ -1,14 +1,31 @@
 //! Telemetry setup for OpenTelemetry integration
 //! Phase 4: Production observability
+//!
+//! Traces (tracing spans) and worker metrics are exported to the same OTLP
+//! endpoint. The pipelines are built before logging is set up, so the trace
+//! layer can join the subscriber; failures are returned and logged by main.
 
 use anyhow::Result;
+use semantica_core::port::WorkerMetrics;
+use std::sync::Arc;
 
-/// Initialize OpenTelemetry if enabled
+/// OpenTelemetry pipelines (flushed by `shutdown`)
+#[cfg_attr(not(feature = "telemetry"), allow(dead_code))]
+pub struct Telemetry {
+    #[cfg(feature = "telemetry")]
+    tracer_provider: opentelemetry_sdk::trace::TracerProvider,
+    #[cfg(feature = "telemetry")]
+    meter_provider: opentelemetry_sdk::metrics::SdkMeterProvider,
+    worker_metrics: Arc<dyn WorkerMetrics>,
+}
+
+/// Initialize OpenTelemetry if enabled (None when not configured)
 ///
 /// # Environment Variables
 ///
 /// - `OTEL_EXPORTER_OTLP_ENDPOINT`: OTLP endpoint (e.g., http://localhost:4317)
 /// - `OTEL_SERVICE_NAME`: Service name (default: semantica-task-engine)
+/// - `OTEL_METRIC_EXPORT_INTERVAL`: Metric export interval in ms (default: 60000)
 ///
 /// # Example
 ///
@@ -17,61 +34,225 @@ use anyhow::Result;
 /// OTEL_SERVICE_NAME=semantica-dev \
 ///     ./semantica
 /// ```
-pub fn init_telemetry() -> Result<()> {
+pub fn init_telemetry() -> Result<Option<Telemetry>> {
     // Check if OpenTelemetry is configured
-    if std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT").is_err() {
-        tracing::debug!("OpenTelemetry not configured (OTEL_EXPORTER_OTLP_ENDPOINT not set)");
-        return Ok(());
-    }
+    let Ok(endpoint) = std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT") else {
+        return Ok(None);
+    };
 
     #[cfg(feature = "telemetry")]
     {
-        init_telemetry_impl()?;
+        init_telemetry_impl(&endpoint).map(Some)
     }
 
     #[cfg(not(feature = "telemetry"))]
     {
-        tracing::warn!("OpenTelemetry endpoint set but feature 'telemetry' not enabled");
-        tracing::warn!("Rebuild with: cargo build --features telemetry");
+        anyhow::bail!(
+            "OpenTelemetry endpoint {} set but feature 'telemetry' not enabled (rebuild with: cargo build --features telemetry)",
+            endpoint
+        )
     }
+}
 
-    Ok(())
+impl Telemetry {
+    /// Layer exporting tracing spans
+    #[cfg(feature = "telemetry")]
+    pub fn layer<S>(
+        &self,
+    ) -> tracing_opentelemetry::OpenTelemetryLayer<S, opentelemetry_sdk::trace::Tracer>
+    where
+        S: tracing::Subscriber + for<'span> tracing_subscriber::registry::LookupSpan<'span>,
+    {
+        use opentelemetry::trace::TracerProvider;
+
+        tracing_opentelemetry::layer().with_tracer(self.tracer_provider.tracer(SCOPE))
+    }
+
+    /// Layer exporting tracing spans (nothing without the `telemetry` feature)
+    #[cfg(not(feature = "telemetry"))]
+    pub fn layer(&self) -> tracing_subscriber::layer::Identity {
+        tracing_subscriber::layer::Identity::new()
+    }
+
+    /// Worker cycle, pop and execution timings
+    pub fn worker_metrics(&self) -> Arc<dyn WorkerMetrics> {
+        self.worker_metrics.clone()
+    }
+
+    /// Export what is still buffered
+    pub fn shutdown(self) {
+        #[cfg(feature = "telemetry")]
+        {
+            if let Err(e) = self.meter_provider.shutdown() {
+                tracing::warn!(error = %e, "Failed to flush OpenTelemetry metrics");
+            }
+            if let Err(e) = self.tracer_provider.shutdown() {
+                tracing::warn!(error = %e, "Failed to flush OpenTelemetry traces");
+            }
+        }
+    }
 }
 
+/// Instrumentation scope of the daemon's spans and instruments
 #[cfg(feature = "telemetry")]
-fn init_telemetry_impl() -> Result<()> {
-    use opentelemetry::trace::TracerProvider;
-    use opentelemetry_otlp::WithExportConfig;
-    use opentelemetry_sdk::trace::Tracer;
+const SCOPE: &str = "semantica-task-engine";
+
+#[cfg(feature = "telemetry")]
+fn init_telemetry_impl(endpoint: &str) -> Result<Telemetry> {
+    use opentelemetry::metrics::MeterProvider;
+    use opentelemetry::KeyValue;
+    use opentelemetry_otlp::{MetricExporter, SpanExporter, WithExportConfig};
+    use opentelemetry_sdk::metrics::{PeriodicReader, SdkMeterProvider};
+    use opentelemetry_sdk::trace::TracerProvider;
+    use opentelemetry_sdk::{runtime, Resource};
 
     let service_name =
         std::env::var("OTEL_SERVICE_NAME").unwrap_or_else(|_| "semantica-task-engine".to_string());
+    let resource = Resource::new([KeyValue::new("service.name", service_name)]);
 
-    let endpoint = std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT")?;
+    let span_exporter = SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()?;
+    let tracer_provider = TracerProvider::builder()
+        .with_batch_exporter(span_exporter, runtime::Tokio)
+        .with_resource(resource.clone())
+        .build();
 
-    tracing::info!(
-        service_name = %service_name,
-        endpoint = %endpoint,
-        "Initializing OpenTelemetry"
-    );
+    // PeriodicReader honours OTEL_METRIC_EXPORT_INTERVAL
+    let metric_exporter = MetricExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()?;
+    let meter_provider = SdkMeterProvider::builder()
+        .with_reader(PeriodicReader::builder(metric_exporter, runtime::Tokio).build())
+        .with_resource(resource)
+        .build();
+    opentelemetry::global::set_meter_provider(meter_provider.clone());
 
-    let tracer: Tracer = opentelemetry_otlp::new_pipeline()
-        .tracing()
-        .with_exporter(
-            opentelemetry_otlp::new_exporter()
-                .tonic()
-                .with_endpoint(&endpoint),
-        )
-        .install_batch(opentelemetry_sdk::runtime::Tokio)?
-        .tracer(service_name);
+    Ok(Telemetry {
+        worker_metrics: Arc::new(OtelWorkerMetrics::new(&meter_provider.meter(SCOPE))),
+        tracer_provider,
+        meter_provider,
+    })
+}
+
+/// Worker timings as OpenTelemetry histograms (seconds)
+#[cfg(feature = "telemetry")]
+struct OtelWorkerMetrics {
+    cycle: opentelemetry::metrics::Histogram<f64>,
+    pop: opentelemetry::metrics::Histogram<f64>,
+    execution: opentelemetry::metrics::Histogram<f64>,
+    expired: opentelemetry::metrics::Counter<u64>,
+    stalled: opentelemetry::metrics::Counter<u64>,
+}
+
+#[cfg(feature = "telemetry")]
+impl OtelWorkerMetrics {
+    fn new(meter: &opentelemetry::metrics::Meter) -> Self {
+        Self {
+            cycle: meter
+                .f64_histogram("semantica.worker.cycle.duration")
+                .with_unit("s")
+                .with_description("One pass of the worker loop")
+                .build(),
+            pop: meter
+                .f64_histogram("semantica.worker.pop.duration")
+                .with_unit("s")
+                .with_description("Time to pop the next job from the queue")
+                .build(),
+            execution: meter
+                .f64_histogram("semantica.job.execution.duration")
+                .with_unit("s")
+                .with_description("Job execution wall time")
+                .build(),
+            expired: meter
+                .u64_counter("semantica.job.expired")
+                .with_description("QUEUED jobs expired (TTL or deadline) before they were popped")
+                .build(),
+            stalled: meter
+                .u64_counter("semantica.job.stalled")
+                .with_description(
+                    "RUNNING jobs flagged as stalled (far past their expected run time)",
+                )
+                .build(),
+        }
+    }
+}
+
+#[cfg(feature = "telemetry")]
+impl WorkerMetrics for OtelWorkerMetrics {
+    fn record_cycle(&self, queue: &str, duration: std::time::Duration, processed: bool) {
+        use opentelemetry::KeyValue;
 
-    // Register with tracing-subscriber
-    let telemetry_layer = tracing_opentelemetry::layer().with_tracer(tracer);
+        self.cycle.record(
+            duration.as_secs_f64(),
+            &[
+                KeyValue::new("queue", queue.to_string()),
+                KeyValue::new("processed", processed),
+            ],
+        );
+    }
+
+    fn record_pop(&self, queue: &str, duration: std::time::Duration, found: bool) {
+        use opentelemetry::KeyValue;
+
+        self.pop.record(
+            duration.as_secs_f64(),
+            &[
+                KeyValue::new("queue", queue.to_string()),
+                KeyValue::new("found", found),
+            ],
+        );
+    }
+
+    fn record_execution(
+        &self,
+        queue: &str,
+        job_type: &str,
+        state: &semantica_core::domain::JobState,
+        duration: std::time::Duration,
+    ) {
+        use opentelemetry::KeyValue;
 
-    use tracing_subscriber::layer::SubscriberExt;
-    tracing::subscriber::set_global_default(tracing_subscriber::registry().with(telemetry_layer))?;
+        self.execution.record(
+            duration.as_secs_f64(),
+            &[
+                KeyValue::new("queue", queue.to_string()),
+                KeyValue::new("job_type", job_type.to_string()),
+                KeyValue::new("state", state.to_string()),
+            ],
+        );
+    }
+
+    fn record_expired(
+        &self,
+        queue: &str,
+        job_type: &str,
+        state: &semantica_core::domain::JobState,
+    ) {
+        use opentelemetry::KeyValue;
 
-    tracing::info!("OpenTelemetry initialized successfully");
+        self.expired.add(
+            1,
+            &[
+                KeyValue::new("queue", queue.to_string()),
+                KeyValue::new("job_type", job_type.to_string()),
+                KeyValue::new("state", state.to_string()),
+            ],
+        );
+    }
 
-    Ok(())
+    fn record_stalled(&self, queue: &str, job_type: &str, killed: bool) {
+        use opentelemetry::KeyValue;
+
+        self.stalled.add(
+            1,
+            &[
+                KeyValue::new("queue", queue.to_string()),
+                KeyValue::new("job_type", job_type.to_string()),
+                KeyValue::new("killed", killed),
+            ],
+        );
+    }
 }
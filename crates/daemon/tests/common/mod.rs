@@ -0,0 +1,171 @@
+//! End-to-end harness: runs the real daemon binary
+//!
+//! Each harness gets its own temp directory (DB + daemon log) and a free
+//! localhost port. The daemon is killed when the harness is dropped.
+
+#![allow(dead_code)] // Not every test binary uses every helper
+
+use semantica_task_sdk::{InspectResponse, SemanticaTaskClient};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+const DAEMON_BIN: &str = env!("CARGO_BIN_EXE_semantica-task-engine");
+
+/// How long the daemon may take to start serving RPC
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(30);
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A running daemon process with its temp directory
+pub struct DaemonHarness {
+    child: Child,
+    dir: PathBuf,
+    url: String,
+}
+
+impl DaemonHarness {
+    /// Launch the daemon with a temp DB on a random port and wait until it serves RPC
+    pub async fn start() -> Self {
+        Self::start_with_env(&[]).await
+    }
+
+    /// Same as [`start`](Self::start) with extra environment variables
+    pub async fn start_with_env(env: &[(&str, &str)]) -> Self {
+        Self::start_with_config("", env).await
+    }
+
+    /// Same as [`start_with_env`](Self::start_with_env) with a config file in place
+    pub async fn start_with_config(config: &str, env: &[(&str, &str)]) -> Self {
+        let dir = std::env::temp_dir().join(format!(
+            "semantica-e2e-{}-{}",
+            std::process::id(),
+            NEXT_ID.fetch_add(1, Ordering::SeqCst)
+        ));
+        std::fs::create_dir_all(&dir).expect("create harness dir");
+        std::fs::write(dir.join("config.toml"), config).expect("write config file");
+
+        let port = free_port();
+        let log = std::fs::File::create(dir.join("daemon.log")).expect("create daemon log");
+
+        let child = Command::new(DAEMON_BIN)
+            .env("SEMANTICA_DB_PATH", dir.join("meta.db"))
+            .env("SEMANTICA_RPC_PORT", port.to_string())
+            .env("RUST_LOG", "semantica=debug")
+            .env("SEMANTICA_LOG_FORMAT", "json")
+            // Never the developer's ~/.semantica/config.toml
+            .env("SEMANTICA_CONFIG", dir.join("config.toml"))
+            .envs(env.iter().copied())
+            .stdin(Stdio::null())
+            .stdout(log.try_clone().expect("clone daemon log"))
+            .stderr(log)
+            .spawn()
+            .expect("spawn daemon");
+
+        let mut harness = Self {
+            child,
+            dir,
+            url: format!("http://127.0.0.1:{}", port),
+        };
+        harness.wait_ready().await;
+        harness
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn dir(&self) -> &PathBuf {
+        &self.dir
+    }
+
+    /// Write the config file and make the daemon re-read it (SIGHUP)
+    pub fn reload_config(&self, contents: &str) {
+        std::fs::write(self.dir.join("config.toml"), contents).expect("write config file");
+        let status = Command::new("kill")
+            .args(["-HUP", &self.child.id().to_string()])
+            .status()
+            .expect("run kill");
+        assert!(status.success(), "kill -HUP failed");
+    }
+
+    pub async fn client(&self) -> SemanticaTaskClient {
+        SemanticaTaskClient::connect(&self.url)
+            .await
+            .expect("connect to daemon")
+    }
+
+    /// Poll `dev.inspect.v1` until the job reaches one of `states`
+    pub async fn wait_for_state(
+        &mut self,
+        job_id: &str,
+        states: &[&str],
+        timeout: Duration,
+    ) -> InspectResponse {
+        let client = self.client().await;
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let job = client.inspect(job_id).await.expect("inspect job");
+            if states.contains(&job.state.as_str()) {
+                return job;
+            }
+            if Instant::now() > deadline {
+                self.fail(&format!(
+                    "job {} still {} after {:?} (expected one of {:?})",
+                    job_id, job.state, timeout, states
+                ));
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Daemon output so far (attached to failures)
+    pub fn daemon_log(&self) -> String {
+        std::fs::read_to_string(self.dir.join("daemon.log")).unwrap_or_default()
+    }
+
+    async fn wait_ready(&mut self) {
+        let deadline = Instant::now() + STARTUP_TIMEOUT;
+
+        loop {
+            if let Ok(Some(status)) = self.child.try_wait() {
+                self.fail(&format!("daemon exited during startup ({})", status));
+            }
+
+            if let Ok(client) = SemanticaTaskClient::connect(&self.url).await {
+                if client.stats().await.is_ok() {
+                    return;
+                }
+            }
+
+            if Instant::now() > deadline {
+                self.fail("daemon did not become ready in time");
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    fn fail(&self, message: &str) -> ! {
+        panic!("{}\n--- daemon log ---\n{}", message, self.daemon_log());
+    }
+}
+
+impl Drop for DaemonHarness {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+pub fn free_port() -> u16 {
+    // The OS picks a free port; it is released right before the daemon binds it
+    TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .map(|addr| addr.port())
+        .expect("find free port")
+}
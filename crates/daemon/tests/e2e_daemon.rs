@@ -0,0 +1,669 @@
+//! End-to-end tests against the real daemon binary (RPC server + worker)
+//!
+//! Unlike crates/integration-tests, nothing is wired by hand: requests go
+//! through the SDK, the JSON-RPC server and the daemon's own composition root.
+
+mod common;
+
+use common::DaemonHarness;
+use semantica_task_sdk::{EnqueueRequest, SubscriptionFilter};
+use serde_json::json;
+use std::time::Duration;
+
+const JOB_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[tokio::test]
+async fn test_enqueue_execute_logs_maintenance() {
+    let mut daemon = DaemonHarness::start().await;
+    let client = daemon.client().await;
+
+    // Fresh daemon: migrations applied, nothing queued
+    let stats = client.stats().await.unwrap();
+    assert!(stats.schema_version > 0);
+    assert_eq!(stats.total_jobs, 0);
+
+    let enqueued = client
+        .enqueue(EnqueueRequest {
+            job_type: "E2E_ECHO".to_string(),
+            queue: "default".to_string(),
+            subject_key: "e2e/echo".to_string(),
+            priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            payload: json!({"command": "sh", "args": ["-c", "echo hello; echo oops >&2"]}),
+            options: Default::default(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(enqueued.state, "QUEUED");
+
+    let job = daemon
+        .wait_for_state(&enqueued.job_id, &["DONE", "FAILED"], JOB_TIMEOUT)
+        .await;
+    assert_eq!(job.state, "DONE", "{}", daemon.daemon_log());
+    assert!(job.started_at.is_some());
+    assert!(job.finished_at.is_some());
+
+    let logs = client.tail_logs(&enqueued.job_id, Some(10)).await.unwrap();
+    assert_eq!(logs.job_id, enqueued.job_id);
+    assert!(logs.log_path.is_some());
+    assert_eq!(logs.entries.len(), 2);
+
+    // Stream filter: only stderr comes back, tagged
+    let errors = client
+        .tail_logs_stream(&enqueued.job_id, "stderr", Some(10))
+        .await
+        .unwrap();
+    assert_eq!(errors.lines, vec!["oops".to_string()]);
+    assert_eq!(errors.entries[0].stream, "stderr");
+
+    let stats = client.stats().await.unwrap();
+    assert_eq!(stats.done_jobs, 1);
+
+    // Nothing is old enough to collect, but the full maintenance path runs
+    let report = client.run_maintenance(true).await.unwrap();
+    assert!(report.vacuum_run);
+    assert_eq!(report.jobs_deleted, 0);
+    assert!(client.inspect(&enqueued.job_id).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_cancel_through_rpc_is_recorded() {
+    let mut daemon = DaemonHarness::start().await;
+    let client = daemon.client().await;
+
+    // No worker serves this queue, so the job stays QUEUED
+    let enqueued = client
+        .enqueue(EnqueueRequest {
+            job_type: "E2E_SLEEP".to_string(),
+            queue: "e2e-idle".to_string(),
+            subject_key: "e2e/cancel".to_string(),
+            priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            payload: json!({"command": "sleep", "args": ["30"]}),
+            options: Default::default(),
+        })
+        .await
+        .unwrap();
+
+    let cancelled = client
+        .cancel_with_reason(&enqueued.job_id, "e2e cleanup")
+        .await
+        .unwrap();
+    assert!(cancelled.cancelled);
+
+    let job = daemon
+        .wait_for_state(&enqueued.job_id, &["CANCELLED"], JOB_TIMEOUT)
+        .await;
+    let event = job.events.last().expect("cancel event");
+    assert_eq!(event.reason.as_deref(), Some("e2e cleanup"));
+    assert!(event.actor.starts_with("sdk:"));
+}
+
+#[tokio::test]
+async fn test_subscription_streams_worker_transitions() {
+    let daemon = DaemonHarness::start().await;
+    let client = daemon.client().await;
+    let mut changes = client.subscribe_state_changes().await.unwrap();
+
+    let enqueued = client
+        .enqueue(EnqueueRequest {
+            job_type: "E2E_ECHO".to_string(),
+            queue: "default".to_string(),
+            subject_key: "e2e/subscribe".to_string(),
+            priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            payload: json!({"command": "true"}),
+            options: Default::default(),
+        })
+        .await
+        .unwrap();
+
+    let mut seen = Vec::new();
+    while seen.len() < 2 {
+        let change = tokio::time::timeout(JOB_TIMEOUT, changes.next())
+            .await
+            .expect("state change pushed")
+            .expect("stream open")
+            .unwrap();
+        if change.job_id == enqueued.job_id {
+            seen.push((change.old_state, change.new_state));
+        }
+    }
+
+    assert_eq!(
+        seen,
+        vec![
+            (Some("QUEUED".to_string()), "RUNNING".to_string()),
+            (Some("RUNNING".to_string()), "DONE".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_subscription_filter_is_applied_by_daemon() {
+    let daemon = DaemonHarness::start().await;
+    let client = daemon.client().await;
+    let mut changes = client
+        .subscribe_state_changes_filtered(SubscriptionFilter {
+            subject_prefix: Some("e2e/filtered/".to_string()),
+            kinds: Some(vec!["state".to_string()]),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let mut job_ids = Vec::new();
+    // The first job runs first but is outside the filter
+    for subject_key in ["e2e/unfiltered", "e2e/filtered/a"] {
+        let enqueued = client
+            .enqueue(EnqueueRequest {
+                job_type: "E2E_ECHO".to_string(),
+                queue: "default".to_string(),
+                subject_key: subject_key.to_string(),
+                priority: 0,
+                idempotency_key: None,
+                traceparent: None,
+                job_class: None,
+                fallback_payloads: Vec::new(),
+                payload: json!({"command": "true"}),
+                options: Default::default(),
+            })
+            .await
+            .unwrap();
+        job_ids.push(enqueued.job_id);
+    }
+
+    for _ in 0..2 {
+        let change = tokio::time::timeout(JOB_TIMEOUT, changes.next())
+            .await
+            .expect("state change pushed")
+            .expect("stream open")
+            .unwrap();
+        assert_eq!(change.job_id, job_ids[1]);
+        assert_eq!(change.subject_key, "e2e/filtered/a");
+        assert_eq!(change.kind, "state");
+    }
+}
+
+#[tokio::test]
+async fn test_wait_returns_final_state_and_summary() {
+    let daemon = DaemonHarness::start().await;
+    let client = daemon.client().await;
+
+    let enqueued = client
+        .enqueue(EnqueueRequest {
+            job_type: "E2E_ECHO".to_string(),
+            queue: "default".to_string(),
+            subject_key: "e2e/wait".to_string(),
+            priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            payload: json!({"command": "true"}),
+            options: Default::default(),
+        })
+        .await
+        .unwrap();
+
+    let done = client.wait(&enqueued.job_id, JOB_TIMEOUT).await.unwrap();
+
+    assert!(done.terminal);
+    assert_eq!(done.state, "DONE");
+    assert!(done.result_summary.is_some());
+    assert!(done.finished_at.is_some());
+}
+
+#[tokio::test]
+async fn test_stats_show_job_occupying_worker_slot() {
+    let mut daemon = DaemonHarness::start().await;
+    let client = daemon.client().await;
+
+    let enqueued = client
+        .enqueue(EnqueueRequest {
+            job_type: "E2E_SLEEP".to_string(),
+            queue: "default".to_string(),
+            subject_key: "e2e/slot".to_string(),
+            priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            payload: json!({"command": "sleep", "args": ["2"]}),
+            options: Default::default(),
+        })
+        .await
+        .unwrap();
+
+    daemon
+        .wait_for_state(&enqueued.job_id, &["RUNNING"], JOB_TIMEOUT)
+        .await;
+    let stats = client.stats().await.unwrap();
+    let slot = &stats.slots[0];
+    assert_eq!(slot.job_id.as_deref(), Some(enqueued.job_id.as_str()));
+    assert_eq!(slot.job_type.as_deref(), Some("E2E_SLEEP"));
+    assert_eq!(slot.subject_key.as_deref(), Some("e2e/slot"));
+    assert!(slot.running_ms.is_some());
+
+    daemon
+        .wait_for_state(&enqueued.job_id, &["DONE"], JOB_TIMEOUT)
+        .await;
+    let stats = client.stats().await.unwrap();
+    assert!(stats.slots.iter().all(|slot| slot.job_id.is_none()));
+}
+
+#[tokio::test]
+async fn test_health_check_reports_ready_daemon() {
+    let daemon = DaemonHarness::start().await;
+    let client = daemon.client().await;
+
+    // The worker loop may not have ticked yet when the RPC server comes up
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    let health = loop {
+        let health = client.health().await.unwrap();
+        if health
+            .worker
+            .as_ref()
+            .is_some_and(|w| w.last_tick_at.is_some())
+            || std::time::Instant::now() > deadline
+        {
+            break health;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    };
+
+    assert!(health.ready);
+    assert_eq!(health.status, "ok");
+    assert!(health.database.reachable);
+    let worker = health.worker.expect("daemon runs a worker");
+    assert!(worker.alive);
+    assert!(worker.last_tick_at.is_some());
+}
+
+#[tokio::test]
+async fn test_rpc_discover_returns_openrpc_document() {
+    let daemon = DaemonHarness::start().await;
+    let client = daemon.client().await;
+
+    let doc = client.discover().await.unwrap();
+
+    assert!(doc["openrpc"].is_string());
+    let methods = doc["methods"].as_array().unwrap();
+    assert!(methods.iter().any(|m| m["name"] == "dev.enqueue.v1"));
+    assert!(doc["components"]["schemas"]["EnqueueResponse"].is_object());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_unix_socket_serves_rpc_to_owner() {
+    use semantica_task_sdk::SemanticaTaskClient;
+    use std::os::unix::fs::PermissionsExt;
+
+    let socket = std::env::temp_dir().join(format!("semantica-e2e-{}.sock", std::process::id()));
+    let daemon =
+        DaemonHarness::start_with_env(&[("SEMANTICA_RPC_SOCKET", socket.to_str().unwrap())]).await;
+
+    let mode = std::fs::metadata(&socket).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600, "Owner-only socket");
+
+    let client = SemanticaTaskClient::connect(format!("unix://{}", socket.display()))
+        .await
+        .unwrap();
+    let stats = client.stats().await.unwrap();
+    assert_eq!(stats.capabilities["features"]["uds"], true);
+
+    let mut changes = client.subscribe_state_changes().await.unwrap();
+    let enqueued = client
+        .enqueue(EnqueueRequest {
+            job_type: "E2E_UDS".to_string(),
+            queue: "default".to_string(),
+            subject_key: "e2e/uds".to_string(),
+            priority: 0,
+            payload: json!({ "command": "true" }),
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
+        })
+        .await
+        .unwrap();
+    let change = tokio::time::timeout(JOB_TIMEOUT, changes.next())
+        .await
+        .expect("state change over the socket")
+        .unwrap()
+        .unwrap();
+    assert_eq!(change.job_id, enqueued.job_id);
+
+    // TCP keeps serving alongside the socket
+    assert!(daemon.client().await.health().await.is_ok());
+
+    // Killed, not stopped: the socket file is left behind
+    drop(daemon);
+    let _ = std::fs::remove_file(&socket);
+}
+
+#[cfg(feature = "rest")]
+#[tokio::test]
+async fn test_rest_gateway_maps_job_routes() {
+    let daemon = DaemonHarness::start().await;
+
+    let (status, enqueued) = rest_request(
+        daemon.url(),
+        "POST",
+        "/v1/jobs",
+        Some(json!({
+            "job_type": "E2E_REST",
+            "queue": "default",
+            "subject_key": "e2e/rest",
+            "payload": { "command": "sleep", "args": ["30"] }
+        })),
+    )
+    .await;
+    assert_eq!(status, 201, "{}", enqueued);
+    let job_id = enqueued["job_id"].as_str().unwrap().to_string();
+
+    let (status, job) =
+        rest_request(daemon.url(), "GET", &format!("/v1/jobs/{}", job_id), None).await;
+    assert_eq!(status, 200);
+    assert_eq!(job["job_type"], "E2E_REST");
+
+    let (status, cancelled) = rest_request(
+        daemon.url(),
+        "DELETE",
+        &format!("/v1/jobs/{}", job_id),
+        None,
+    )
+    .await;
+    assert_eq!(status, 200, "{}", cancelled);
+
+    let (status, missing) = rest_request(daemon.url(), "GET", "/v1/jobs/no-such-job", None).await;
+    assert_eq!(status, 404);
+    assert_eq!(missing["error"]["code"], 4001);
+
+    let (status, _) = rest_request(daemon.url(), "POST", "/v1/jobs", Some(json!({}))).await;
+    assert_eq!(status, 400, "Body does not match EnqueueRequest");
+
+    // JSON-RPC keeps working next to the gateway
+    assert!(daemon.client().await.health().await.is_ok());
+}
+
+#[cfg(feature = "rest")]
+/// One HTTP/1.1 request without an HTTP client dependency: (status, JSON body)
+async fn rest_request(
+    url: &str,
+    method: &str,
+    path: &str,
+    body: Option<serde_json::Value>,
+) -> (u16, serde_json::Value) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let addr = url.trim_start_matches("http://");
+    let body = body.map(|b| b.to_string()).unwrap_or_default();
+    let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+    let request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        method,
+        path,
+        addr,
+        body.len(),
+        body
+    );
+    stream.write_all(request.as_bytes()).await.unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+    (status, serde_json::from_str(body).unwrap_or_default())
+}
+
+#[tokio::test]
+async fn test_sighup_reloads_runtime_settings() {
+    let daemon = DaemonHarness::start().await;
+    let client = daemon.client().await;
+    let defaults = client.config().await.unwrap();
+
+    daemon.reload_config(
+        r#"
+[rate_limit]
+burst = 7
+
+[worker]
+cpu_throttle_percent = 55.5
+"#,
+    );
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    let reloaded = loop {
+        let settings = client.config().await.unwrap();
+        if settings["rate_limit_burst"] == 7 || std::time::Instant::now() > deadline {
+            break settings;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    };
+    assert_eq!(reloaded["rate_limit_burst"], 7, "{}", daemon.daemon_log());
+    assert_eq!(reloaded["cpu_throttle_percent"], 55.5);
+    assert_eq!(
+        reloaded["finished_job_retention_days"],
+        defaults["finished_job_retention_days"]
+    );
+
+    // A broken file is rejected as a whole
+    daemon.reload_config("[rate_limit]\nburst = 9\nno_such_key = 1\n");
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    while !daemon.daemon_log().contains("Configuration reload failed") {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "{}",
+            daemon.daemon_log()
+        );
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert_eq!(client.config().await.unwrap()["rate_limit_burst"], 7);
+}
+
+#[tokio::test]
+async fn test_config_file_settings_with_env_overrides() {
+    let mut daemon = DaemonHarness::start_with_config(
+        r#"
+[rate_limit]
+burst = 7
+per_sec = 40
+
+[worker]
+concurrency = 2
+
+[scheduler]
+queues = "default,build:2"
+"#,
+        &[("SEMANTICA_RATE_LIMIT_BURST", "3")],
+    )
+    .await;
+    let client = daemon.client().await;
+
+    // The env var wins over the file, the rest comes from the file
+    let settings = client.config().await.unwrap();
+    assert_eq!(settings["rate_limit_burst"], 3);
+    assert_eq!(settings["rate_limit_per_sec"], 40);
+
+    // Only served because the file lists the queue
+    let enqueued = client
+        .enqueue(EnqueueRequest {
+            job_type: "E2E_ECHO".to_string(),
+            queue: "build".to_string(),
+            subject_key: "e2e/config".to_string(),
+            priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            payload: json!({"command": "true"}),
+            options: Default::default(),
+        })
+        .await
+        .unwrap();
+    let job = daemon
+        .wait_for_state(&enqueued.job_id, &["DONE", "FAILED"], JOB_TIMEOUT)
+        .await;
+    assert_eq!(job.state, "DONE", "{}", daemon.daemon_log());
+}
+
+#[tokio::test]
+async fn test_second_daemon_on_same_database_fails_fast() {
+    let daemon = DaemonHarness::start().await;
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_semantica-task-engine"))
+        .env("SEMANTICA_DB_PATH", daemon.dir().join("meta.db"))
+        .env("SEMANTICA_RPC_PORT", "0")
+        .env("SEMANTICA_CONFIG", daemon.dir().join("config.toml"))
+        .output()
+        .expect("run second daemon");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("already in use"), "{}", stderr);
+    assert!(stderr.contains("PID "), "{}", stderr);
+
+    // The first one keeps serving
+    assert!(daemon.client().await.stats().await.is_ok());
+}
+
+#[tokio::test]
+async fn test_daemon_mode_detaches_and_stops_on_sigterm() {
+    let dir = std::env::temp_dir().join(format!("semantica-e2e-{}-detach", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("config.toml"), "").unwrap();
+    let pid_file = dir.join("semantica.pid");
+    let port = common::free_port();
+    let daemon = || {
+        std::process::Command::new(env!("CARGO_BIN_EXE_semantica-task-engine"))
+            .args(["--daemon", "--pid-file"])
+            .arg(&pid_file)
+            .arg("--log-file")
+            .arg(dir.join("daemon.log"))
+            .env("SEMANTICA_DB_PATH", dir.join("meta.db"))
+            .env("SEMANTICA_RPC_PORT", port.to_string())
+            .env("SEMANTICA_CONFIG", dir.join("config.toml"))
+            .output()
+            .expect("run daemon")
+    };
+
+    // The launching process returns as soon as the daemon has detached
+    assert!(daemon().status.success());
+    let deadline = std::time::Instant::now() + JOB_TIMEOUT;
+    let pid = loop {
+        let pid = std::fs::read_to_string(&pid_file).unwrap_or_default();
+        if let Ok(client) =
+            semantica_task_sdk::SemanticaTaskClient::connect(&format!("http://127.0.0.1:{}", port))
+                .await
+        {
+            if client.stats().await.is_ok() && !pid.trim().is_empty() {
+                break pid.trim().to_string();
+            }
+        }
+        assert!(
+            std::time::Instant::now() < deadline,
+            "daemon not ready:\n{}",
+            std::fs::read_to_string(dir.join("daemon.log")).unwrap_or_default()
+        );
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    };
+
+    // A second start is refused in the foreground, naming the running one
+    let second = daemon();
+    let stderr = String::from_utf8_lossy(&second.stderr);
+    assert!(!second.status.success());
+    assert!(
+        stderr.contains(&format!("already running (PID {}", pid)),
+        "{}",
+        stderr
+    );
+
+    // SIGTERM is a graceful shutdown that removes the PID file
+    assert!(std::process::Command::new("kill")
+        .args(["-TERM", &pid])
+        .status()
+        .unwrap()
+        .success());
+    while pid_file.exists() {
+        assert!(std::time::Instant::now() < deadline, "PID file left behind");
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    let log = std::fs::read_to_string(dir.join("daemon.log")).unwrap();
+    assert!(log.contains("Shutdown complete"), "{}", log);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_systemd_socket_activation_and_notify() {
+    use std::os::fd::AsRawFd;
+    use std::os::unix::net::UnixDatagram;
+    use std::os::unix::process::CommandExt;
+
+    let dir = std::env::temp_dir().join(format!("semantica-e2e-{}-systemd", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("config.toml"), "").unwrap();
+    let notify = UnixDatagram::bind(dir.join("notify.sock")).unwrap();
+    notify.set_read_timeout(Some(JOB_TIMEOUT)).unwrap();
+    let next_state = || {
+        let mut buf = [0u8; 256];
+        let len = notify.recv(&mut buf).expect("sd_notify message");
+        String::from_utf8_lossy(&buf[..len]).into_owned()
+    };
+
+    // The socket of a .socket unit: bound here, served by the daemon (fd 3)
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let fd = listener.as_raw_fd();
+    let mut command = std::process::Command::new("sh");
+    // systemd sets LISTEN_PID after forking; `exec` keeps the shell's PID
+    command
+        .args(["-c", "LISTEN_PID=$$ exec \"$0\""])
+        .arg(env!("CARGO_BIN_EXE_semantica-task-engine"))
+        .env("LISTEN_FDS", "1")
+        .env("NOTIFY_SOCKET", dir.join("notify.sock"))
+        .env("WATCHDOG_USEC", "400000")
+        .env("SEMANTICA_DB_PATH", dir.join("meta.db"))
+        .env("SEMANTICA_RPC_PORT", common::free_port().to_string())
+        .env("SEMANTICA_CONFIG", dir.join("config.toml"))
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null());
+    // SAFETY: only async-signal-safe calls between fork and exec
+    unsafe {
+        command.pre_exec(move || {
+            use nix::fcntl::{fcntl, FcntlArg, FdFlag};
+            if fd != 3 {
+                nix::unistd::dup2(fd, 3)?;
+            }
+            fcntl(3, FcntlArg::F_SETFD(FdFlag::empty()))?;
+            Ok(())
+        });
+    }
+    let mut child = command.spawn().expect("spawn daemon");
+    drop(listener);
+
+    let ready = next_state();
+    assert!(ready.starts_with("READY=1"), "{}", ready);
+    let client = semantica_task_sdk::SemanticaTaskClient::connect(&format!("http://{}", addr))
+        .await
+        .unwrap();
+    assert!(client.stats().await.is_ok());
+    assert_eq!(next_state(), "WATCHDOG=1");
+
+    std::process::Command::new("kill")
+        .args(["-TERM", &child.id().to_string()])
+        .status()
+        .unwrap();
+    while next_state() != "STOPPING=1" {}
+    assert!(child.wait().unwrap().success());
+    let _ = std::fs::remove_dir_all(&dir);
+}
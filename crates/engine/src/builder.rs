@@ -0,0 +1,419 @@
+// Engine Builder - Opens the database and wires the engine
+//
+// Two steps: `open` creates the pool, runs the migrations and hands out the
+// repository (collaborators such as the failure bundler need it before the
+// engine exists); `start` recovers orphaned jobs, wires the worker and the
+// background loops and runs them. Every collaborator has a default, so
+// `EngineBuilder::open(path).await?.start().await?` is a working engine; the
+// daemon replaces most of them with what its config asks for.
+
+use crate::config::EngineConfig;
+use crate::engine::SemanticaEngine;
+use crate::instance_lock::InstanceLock;
+use semantica_core::application::lease::DEFAULT_REAPER_INTERVAL;
+use semantica_core::application::recovery::RecoveryService;
+use semantica_core::application::retry::RetryPolicy;
+use semantica_core::application::scheduler::Scheduler;
+use semantica_core::application::starvation::DEFAULT_STARVATION_CHECK_INTERVAL;
+use semantica_core::application::worker::constants::DEFAULT_RETRY_BASE_DELAY_MS;
+use semantica_core::application::{
+    shutdown_channel, DevTaskService, DispatchSignal, Drain, ExpirySweeper, InterceptorChain,
+    LeaseService, Lockdown, MaintenanceScheduler, MetricsRecorder, QueuePauseRegistry,
+    QueueValidators, RetryPolicies, RunningJobs, RuntimeConfig, SchedulingPolicy,
+    StarvationDetector, Warmup, Worker, WorkerActivity, WorkerSupervisor,
+};
+use semantica_core::error::{AppError, Result};
+use semantica_core::port::id_provider::UuidProvider;
+use semantica_core::port::time_provider::SystemTimeProvider;
+use semantica_core::port::{
+    ArtifactStorage, JobArchive, LogNotifier, MaintenanceConfig, Notifier, SystemProbe,
+    TaskExecutor, TimeProvider, WorkerMetrics,
+};
+use semantica_infra_sqlite::{create_pool, run_migrations, SqliteJobRepository, SqliteMaintenance};
+use semantica_infra_system::{ProbeConfig, SubprocessExecutor};
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Env vars job subprocesses inherit from the default executor
+const DEFAULT_ENV_ALLOWLIST: [&str; 3] = ["PATH", "HOME", "USER"];
+
+/// An opened database and the collaborators of the engine to start on it
+pub struct EngineBuilder {
+    instance_lock: Option<InstanceLock>,
+    pool: SqlitePool,
+    time_provider: Arc<dyn TimeProvider>,
+    job_repo: Arc<SqliteJobRepository>,
+    log_dir: PathBuf,
+    config: EngineConfig,
+    task_executor: Option<Arc<dyn TaskExecutor>>,
+    system_probe: Option<Arc<dyn SystemProbe>>,
+    scheduler: Option<Arc<Scheduler>>,
+    scheduling_policy: Option<Arc<dyn SchedulingPolicy>>,
+    retry_policies: Option<Arc<RetryPolicies>>,
+    interceptors: Arc<InterceptorChain>,
+    queue_validators: Arc<QueueValidators>,
+    notifier: Arc<dyn Notifier>,
+    runtime_config: Arc<RuntimeConfig>,
+    lockdown: Arc<Lockdown>,
+    artifact_storage: Option<Arc<dyn ArtifactStorage>>,
+    job_archive: Option<Arc<dyn JobArchive>>,
+    worker_metrics: Option<Arc<dyn WorkerMetrics>>,
+}
+
+impl EngineBuilder {
+    /// Open (or create) the SQLite file and bring its schema up to date
+    ///
+    /// Fails with Conflict while another engine (daemon or embedded) has the
+    /// file open; the lock is held until the engine is dropped.
+    pub async fn open(db_path: impl AsRef<Path>) -> Result<Self> {
+        let db_path = db_path.as_ref();
+        let instance_lock = if db_path.to_string_lossy().contains(":memory:") {
+            None
+        } else {
+            Some(InstanceLock::acquire(db_path)?)
+        };
+        let pool = create_pool(&db_path.to_string_lossy())
+            .await
+            .map_err(|e| AppError::Database(format!("DB pool creation failed: {}", e)))?;
+        run_migrations(&pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Migration failed: {}", e)))?;
+
+        let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+        let job_repo = Arc::new(SqliteJobRepository::new(
+            pool.clone(),
+            time_provider.clone(),
+        ));
+        // Job output logs next to the database unless an executor brings its own
+        let log_dir = db_path
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join("logs");
+
+        Ok(Self {
+            instance_lock,
+            pool,
+            time_provider,
+            job_repo,
+            log_dir,
+            config: EngineConfig::default(),
+            task_executor: None,
+            system_probe: None,
+            scheduler: None,
+            scheduling_policy: None,
+            retry_policies: None,
+            interceptors: Arc::new(InterceptorChain::new()),
+            queue_validators: Arc::new(QueueValidators::new()),
+            notifier: Arc::new(LogNotifier),
+            runtime_config: Arc::new(RuntimeConfig::default()),
+            lockdown: Arc::new(Lockdown::new()),
+            artifact_storage: None,
+            job_archive: None,
+            worker_metrics: None,
+        })
+    }
+
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+
+    pub fn time_provider(&self) -> Arc<dyn TimeProvider> {
+        self.time_provider.clone()
+    }
+
+    pub fn job_repo(&self) -> Arc<SqliteJobRepository> {
+        self.job_repo.clone()
+    }
+
+    pub fn with_config(mut self, config: EngineConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Executor of the jobs (default: subprocesses, output logs next to the database)
+    pub fn with_task_executor(mut self, task_executor: Arc<dyn TaskExecutor>) -> Self {
+        self.task_executor = Some(task_executor);
+        self
+    }
+
+    /// System metrics for throttling and conditions (default: sysinfo)
+    pub fn with_system_probe(mut self, system_probe: Arc<dyn SystemProbe>) -> Self {
+        self.system_probe = Some(system_probe);
+        self
+    }
+
+    /// Conditions scheduler, also used to warm up the pop path
+    pub fn with_scheduler(mut self, scheduler: Arc<Scheduler>) -> Self {
+        self.scheduler = Some(scheduler);
+        self
+    }
+
+    /// Policy deciding whether a popped job runs now (default: the scheduler alone)
+    pub fn with_scheduling_policy(mut self, policy: Arc<dyn SchedulingPolicy>) -> Self {
+        self.scheduling_policy = Some(policy);
+        self
+    }
+
+    pub fn with_retry_policies(mut self, retry_policies: Arc<RetryPolicies>) -> Self {
+        self.retry_policies = Some(retry_policies);
+        self
+    }
+
+    /// Interceptor chain run around enqueues, pops and completions
+    pub fn with_interceptors(mut self, interceptors: Arc<InterceptorChain>) -> Self {
+        self.interceptors = interceptors;
+        self
+    }
+
+    pub fn with_queue_validators(mut self, validators: Arc<QueueValidators>) -> Self {
+        self.queue_validators = validators;
+        self
+    }
+
+    /// Channel of maintenance reports and watchdog alerts (default: log only)
+    pub fn with_notifier(mut self, notifier: Arc<dyn Notifier>) -> Self {
+        self.notifier = notifier;
+        self
+    }
+
+    pub fn with_runtime_config(mut self, runtime_config: Arc<RuntimeConfig>) -> Self {
+        self.runtime_config = runtime_config;
+        self
+    }
+
+    /// Subprocess kill switch (engage it before `start` to refuse from the first pop)
+    pub fn with_lockdown(mut self, lockdown: Arc<Lockdown>) -> Self {
+        self.lockdown = lockdown;
+        self
+    }
+
+    /// Artifacts deleted with their jobs by maintenance
+    pub fn with_artifact_storage(mut self, storage: Arc<dyn ArtifactStorage>) -> Self {
+        self.artifact_storage = Some(storage);
+        self
+    }
+
+    /// Archive the GC appends jobs to before deleting their rows
+    pub fn with_job_archive(mut self, archive: Arc<dyn JobArchive>) -> Self {
+        self.job_archive = Some(archive);
+        self
+    }
+
+    pub fn with_worker_metrics(mut self, metrics: Arc<dyn WorkerMetrics>) -> Self {
+        self.worker_metrics = Some(metrics);
+        self
+    }
+
+    /// Recover orphaned jobs, then run the worker and the background loops
+    pub async fn start(self) -> Result<SemanticaEngine> {
+        let time_provider = self.time_provider;
+        let job_repo = self.job_repo;
+        let config = self.config;
+
+        let system_probe = match self.system_probe {
+            Some(system_probe) => system_probe,
+            None => ProbeConfig::default().build().map_err(AppError::Config)?,
+        };
+        let task_executor = self.task_executor.unwrap_or_else(|| {
+            Arc::new(
+                SubprocessExecutor::new(
+                    time_provider.clone(),
+                    DEFAULT_ENV_ALLOWLIST.map(String::from).to_vec(),
+                )
+                .with_log_dir(self.log_dir),
+            )
+        });
+        let scheduler = self.scheduler.unwrap_or_else(|| {
+            Arc::new(Scheduler::new(system_probe.clone(), time_provider.clone()))
+        });
+        let scheduling_policy = self.scheduling_policy.unwrap_or_else(|| scheduler.clone());
+        let retry_policies = self.retry_policies.unwrap_or_else(|| {
+            Arc::new(RetryPolicies::new(Arc::new(RetryPolicy::new(
+                time_provider.clone(),
+                DEFAULT_RETRY_BASE_DELAY_MS,
+            ))))
+        });
+
+        info!("Running crash recovery...");
+        let recovery_service = RecoveryService::new(
+            job_repo.clone(),
+            task_executor.clone(),
+            time_provider.clone(),
+            None, // Use default recovery window
+        )
+        .with_worker_id(config.worker_id.clone());
+        match recovery_service.recover_orphaned_jobs().await {
+            Ok(count) => info!(recovered_jobs = count, "Crash recovery completed"),
+            Err(e) => tracing::error!(error = ?e, "Crash recovery failed"),
+        }
+
+        // Prime the pop path before the first job arrives
+        let queue_weights = config.queue_weights();
+        let queues = queue_weights.queues();
+        let warmup = Warmup::new(job_repo.clone(), scheduler.clone());
+        let warmup_queues: Vec<&str> = queues.iter().map(String::as_str).collect();
+        if let Err(e) = warmup.run(&warmup_queues).await {
+            warn!(error = ?e, "Warmup failed (continuing cold)");
+        }
+
+        let mut maintenance = SqliteMaintenance::new(self.pool.clone(), time_provider.clone());
+        if let Some(storage) = self.artifact_storage {
+            maintenance = maintenance.with_artifact_storage(storage);
+        }
+        if let Some(archive) = self.job_archive {
+            maintenance = maintenance.with_job_archive(archive);
+        }
+        let maintenance = Arc::new(maintenance);
+
+        // Shared with whoever drives the engine (the daemon's RPC handler)
+        let dispatch = Arc::new(DispatchSignal::new());
+        let worker_activity = Arc::new(WorkerActivity::new());
+        let running_jobs = Arc::new(RunningJobs::new(task_executor.clone()));
+        let queue_pauses = Arc::new(QueuePauseRegistry::new());
+        let drain = Arc::new(Drain::new());
+
+        // Job broker for external workers; the reaper reclaims expired leases
+        let lease_service = Arc::new(
+            LeaseService::new(
+                job_repo.clone(),
+                retry_policies.default_policy(),
+                time_provider.clone(),
+            )
+            .with_retry_policies(retry_policies.clone())
+            .with_interceptors(self.interceptors.clone())
+            .with_pause_registry(queue_pauses.clone())
+            .with_lockdown(self.lockdown.clone())
+            .with_drain(drain.clone()),
+        );
+
+        info!(queues = ?queues, "Starting worker...");
+        let (shutdown_tx, shutdown_rx) = shutdown_channel();
+        let mut worker = Worker::new(
+            queues[0].clone(),
+            job_repo.clone(),
+            task_executor,
+            system_probe.clone(),
+            retry_policies.default_policy(),
+            scheduler,
+            time_provider.clone(),
+        )
+        .with_retry_policies(retry_policies.clone())
+        .with_interceptors(self.interceptors.clone())
+        .with_scheduling_policy(scheduling_policy)
+        .with_pause_registry(queue_pauses.clone())
+        .with_lockdown(self.lockdown.clone())
+        .with_drain(drain.clone())
+        .with_activity(worker_activity.clone())
+        .with_running_jobs(running_jobs.clone())
+        .with_runtime_config(self.runtime_config.clone())
+        .with_subject_history_len(config.subject_history_len)
+        .with_queue_weights(&queue_weights)
+        .with_dispatch_signal(dispatch.clone())
+        .with_signalled_idle_poll(config.idle_poll)
+        .with_concurrency(config.concurrency.max(1))
+        .with_lease(config.worker_id.clone(), config.lease)
+        .with_shutdown_grace(config.shutdown_grace);
+        if let Some(metrics) = &self.worker_metrics {
+            worker = worker.with_metrics(metrics.clone());
+        }
+
+        let mut background = Vec::new();
+
+        // Watchdog: alert when due jobs wait while the worker sits idle and unthrottled
+        for queue in &queues {
+            let starvation_detector = Arc::new(
+                StarvationDetector::new(
+                    job_repo.clone(),
+                    worker_activity.clone(),
+                    queue.as_str(),
+                    config.max_queue_wait,
+                    time_provider.clone(),
+                )
+                .with_notifier(self.notifier.clone())
+                .with_pause_registry(queue_pauses.clone()),
+            );
+            background.push(tokio::spawn(
+                starvation_detector.run(DEFAULT_STARVATION_CHECK_INTERVAL),
+            ));
+        }
+
+        // Throughput snapshots (trends instead of counters)
+        let metrics_recorder = Arc::new(
+            MetricsRecorder::new(
+                job_repo.clone(),
+                time_provider.clone(),
+                config.metrics_interval,
+            )
+            .with_history_len(config.metrics_history_len),
+        );
+        background.push(tokio::spawn(metrics_recorder.run()));
+
+        // Restarted with backoff (once the database answers) if the loop dies
+        let worker_handle = tokio::spawn(WorkerSupervisor::new(Arc::new(worker)).run(shutdown_rx));
+
+        info!("Starting maintenance scheduler...");
+        let mut maintenance_scheduler = MaintenanceScheduler::new(
+            maintenance.clone(),
+            MaintenanceConfig::default(), // 7 days retention
+            config.maintenance_interval_hours,
+            time_provider.clone(),
+        )
+        .with_notifier(self.notifier)
+        .with_runtime_config(self.runtime_config.clone());
+        if config.maintenance_idle_only {
+            maintenance_scheduler = maintenance_scheduler.with_idle_gate(system_probe);
+            info!("Scheduled maintenance gated on idle windows");
+        }
+        background.push(tokio::spawn(maintenance_scheduler.run()));
+
+        background.push(tokio::spawn(
+            lease_service.clone().run_reaper(DEFAULT_REAPER_INTERVAL),
+        ));
+
+        // Expire jobs whose TTL/deadline passes while they wait (paused queue, backlog)
+        let mut expiry_sweeper = ExpirySweeper::new(job_repo.clone(), time_provider.clone())
+            .with_interceptors(self.interceptors.clone());
+        if let Some(metrics) = self.worker_metrics {
+            expiry_sweeper = expiry_sweeper.with_metrics(metrics);
+        }
+        background.push(tokio::spawn(
+            Arc::new(expiry_sweeper).run(config.expiry_sweep_interval),
+        ));
+
+        let tasks = DevTaskService::new(
+            job_repo.clone(),
+            Arc::new(UuidProvider),
+            time_provider.clone(),
+        )
+        .with_interceptors(self.interceptors.clone())
+        .with_queue_validators(self.queue_validators.clone())
+        .with_dispatch_signal(dispatch.clone());
+
+        Ok(SemanticaEngine {
+            _instance_lock: self.instance_lock,
+            pool: self.pool,
+            time_provider,
+            job_repo,
+            tasks,
+            maintenance,
+            lease_service,
+            retry_policies,
+            interceptors: self.interceptors,
+            queue_validators: self.queue_validators,
+            runtime_config: self.runtime_config,
+            lockdown: self.lockdown,
+            dispatch,
+            worker_activity,
+            running_jobs,
+            queue_pauses,
+            drain,
+            queues,
+            shutdown_grace: config.shutdown_grace,
+            shutdown_tx,
+            worker_handle: Some(worker_handle),
+            background,
+        })
+    }
+}
@@ -0,0 +1,80 @@
+// Engine Config - Tunables of the embedded engine
+
+use semantica_core::application::expiry_sweeper::DEFAULT_EXPIRY_SWEEP_INTERVAL;
+use semantica_core::application::lease::DEFAULT_LEASE_MS;
+use semantica_core::application::metrics_history::{
+    DEFAULT_METRICS_HISTORY_LEN, DEFAULT_METRICS_INTERVAL,
+};
+use semantica_core::application::starvation::DEFAULT_MAX_QUEUE_WAIT;
+use semantica_core::application::worker::constants::{
+    SHUTDOWN_GRACE_PERIOD, SIGNALLED_IDLE_POLL_DURATION, SUBJECT_HISTORY_LEN, WORKER_SLOTS,
+};
+use semantica_core::domain::QueueWeights;
+use std::time::Duration;
+
+/// Queue served when none is configured
+pub const DEFAULT_QUEUE: &str = "default";
+
+/// Hours between scheduled maintenance runs
+pub const DEFAULT_MAINTENANCE_INTERVAL_HOURS: u64 = 24;
+
+/// Worker, watchdog and maintenance settings (the daemon fills them from its
+/// config file and env vars; the defaults match an unconfigured daemon)
+#[derive(Debug, Clone)]
+pub struct EngineConfig {
+    /// Queues the worker serves and their share of its pops (empty = "default")
+    pub queues: QueueWeights,
+    /// Jobs executed at the same time
+    pub concurrency: usize,
+    /// Lease of an executing job, renewed while it runs (reclaimed if the worker dies)
+    pub lease: Duration,
+    /// Lease holder name of the worker
+    pub worker_id: String,
+    /// Safety-net poll of the idle worker between enqueue signals
+    pub idle_poll: Duration,
+    /// On shutdown, running jobs finish within this or are killed and requeued
+    pub shutdown_grace: Duration,
+    /// Successful results kept per subject (0 = disabled)
+    pub subject_history_len: usize,
+    /// Starvation alert when due jobs wait this long while the worker is idle
+    pub max_queue_wait: Duration,
+    /// Throughput snapshot cadence and how many snapshots are kept
+    pub metrics_interval: Duration,
+    pub metrics_history_len: usize,
+    /// Sweep of queued jobs past their TTL / deadline
+    pub expiry_sweep_interval: Duration,
+    pub maintenance_interval_hours: u64,
+    /// VACUUM / GC only while the machine is idle
+    pub maintenance_idle_only: bool,
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        Self {
+            queues: QueueWeights::default(),
+            concurrency: WORKER_SLOTS,
+            lease: Duration::from_millis(DEFAULT_LEASE_MS as u64),
+            worker_id: format!("semantica-worker-{}", std::process::id()),
+            idle_poll: SIGNALLED_IDLE_POLL_DURATION,
+            shutdown_grace: SHUTDOWN_GRACE_PERIOD,
+            subject_history_len: SUBJECT_HISTORY_LEN,
+            max_queue_wait: DEFAULT_MAX_QUEUE_WAIT,
+            metrics_interval: DEFAULT_METRICS_INTERVAL,
+            metrics_history_len: DEFAULT_METRICS_HISTORY_LEN,
+            expiry_sweep_interval: DEFAULT_EXPIRY_SWEEP_INTERVAL,
+            maintenance_interval_hours: DEFAULT_MAINTENANCE_INTERVAL_HOURS,
+            maintenance_idle_only: false,
+        }
+    }
+}
+
+impl EngineConfig {
+    /// Configured queues, "default" when none is
+    pub fn queue_weights(&self) -> QueueWeights {
+        if self.queues.is_empty() {
+            QueueWeights::from_spec(DEFAULT_QUEUE).expect("default queue name is valid")
+        } else {
+            self.queues.clone()
+        }
+    }
+}
@@ -0,0 +1,202 @@
+// Semantica Engine - A running engine and its in-process API
+//
+// What the daemon exposes over RPC, an embedding application calls here
+// directly: enqueue, inspect, cancel, wait. The shared state (pauses, drain,
+// lockdown, runtime settings, ...) is handed out so a front end - the
+// daemon's RPC server - acts on the same worker.
+
+use crate::instance_lock::InstanceLock;
+use semantica_core::application::dev_task::EnqueueRequest;
+use semantica_core::application::{
+    DevTaskService, DispatchSignal, Drain, InterceptorChain, LeaseService, Lockdown,
+    QueuePauseRegistry, QueueValidators, RetryPolicies, RunningJobs, RuntimeConfig, ShutdownSender,
+    Termination, WorkerActivity,
+};
+use semantica_core::domain::job_event::actor;
+use semantica_core::domain::{Job, JobEvent, JobState};
+use semantica_core::error::{AppError, Result};
+use semantica_core::port::{JobRepository, TimeProvider};
+use semantica_infra_sqlite::{SqliteJobRepository, SqliteMaintenance};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// Shutdown time on top of the grace period: SIGTERM, SIGKILL after 5s,
+/// reap confirmation and the requeue of the interrupted jobs
+pub const SHUTDOWN_INTERRUPT_ALLOWANCE: Duration = Duration::from_secs(10);
+
+/// Poll interval of `wait_for`
+const WAIT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The queue, worker and background loops running in this process
+///
+/// Built by [`EngineBuilder`](crate::EngineBuilder). Dropping the engine
+/// stops it without waiting; [`shutdown`](Self::shutdown) lets running jobs
+/// finish first.
+pub struct SemanticaEngine {
+    /// Held while the engine lives (one engine per database)
+    pub(crate) _instance_lock: Option<InstanceLock>,
+    pub(crate) pool: SqlitePool,
+    pub(crate) time_provider: Arc<dyn TimeProvider>,
+    pub(crate) job_repo: Arc<SqliteJobRepository>,
+    pub(crate) tasks: DevTaskService,
+    pub(crate) maintenance: Arc<SqliteMaintenance>,
+    pub(crate) lease_service: Arc<LeaseService>,
+    pub(crate) retry_policies: Arc<RetryPolicies>,
+    pub(crate) interceptors: Arc<InterceptorChain>,
+    pub(crate) queue_validators: Arc<QueueValidators>,
+    pub(crate) runtime_config: Arc<RuntimeConfig>,
+    pub(crate) lockdown: Arc<Lockdown>,
+    pub(crate) dispatch: Arc<DispatchSignal>,
+    pub(crate) worker_activity: Arc<WorkerActivity>,
+    pub(crate) running_jobs: Arc<RunningJobs>,
+    pub(crate) queue_pauses: Arc<QueuePauseRegistry>,
+    pub(crate) drain: Arc<Drain>,
+    pub(crate) queues: Vec<String>,
+    pub(crate) shutdown_grace: Duration,
+    pub(crate) shutdown_tx: ShutdownSender,
+    pub(crate) worker_handle: Option<JoinHandle<()>>,
+    pub(crate) background: Vec<JoinHandle<()>>,
+}
+
+impl SemanticaEngine {
+    /// Enqueue a job (validated, intercepted and deduplicated like an RPC enqueue)
+    pub async fn enqueue(&self, req: EnqueueRequest) -> Result<String> {
+        self.tasks.enqueue(req).await
+    }
+
+    pub async fn job(&self, job_id: &str) -> Result<Option<Job>> {
+        self.job_repo.find_by_id(&job_id.to_string()).await
+    }
+
+    /// Cancel a job, killing its subprocess if it is running
+    pub async fn cancel(&self, job_id: &str) -> Result<Termination> {
+        let job_id = job_id.to_string();
+        let now = self.time_provider.now_millis();
+        self.job_repo
+            .update_state(&job_id, JobState::Cancelled, Some(now))
+            .await?;
+        self.job_repo
+            .record_event(&JobEvent::new(
+                job_id.clone(),
+                JobState::Cancelled,
+                actor::EMBEDDED.to_string(),
+                None,
+                now,
+            ))
+            .await?;
+
+        // Popped since it was read or not: the registry knows (NotRunning otherwise)
+        Ok(self.running_jobs.cancel(&job_id).await)
+    }
+
+    /// Wait until the job is finished, returns it as it is when `timeout` elapses first
+    pub async fn wait_for(&self, job_id: &str, timeout: Duration) -> Result<Job> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let job = self
+                .job(job_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("Job {} not found", job_id)))?;
+            if job.state.is_terminal() || tokio::time::Instant::now() >= deadline {
+                return Ok(job);
+            }
+            tokio::time::sleep(WAIT_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Stop the worker (running jobs get the grace period, then are killed and
+    /// requeued) and the background loops
+    pub async fn shutdown(mut self) {
+        self.shutdown_tx.shutdown();
+        if let Some(worker_handle) = self.worker_handle.take() {
+            let timeout = self.shutdown_grace + SHUTDOWN_INTERRUPT_ALLOWANCE;
+            if tokio::time::timeout(timeout, worker_handle).await.is_err() {
+                warn!(
+                    timeout = ?timeout,
+                    "Worker did not stop in time, running jobs are recovered on the next start"
+                );
+            }
+        }
+        info!("Engine stopped");
+        // Background loops are aborted on drop
+    }
+
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+
+    pub fn time_provider(&self) -> Arc<dyn TimeProvider> {
+        self.time_provider.clone()
+    }
+
+    pub fn job_repo(&self) -> Arc<SqliteJobRepository> {
+        self.job_repo.clone()
+    }
+
+    pub fn maintenance(&self) -> Arc<SqliteMaintenance> {
+        self.maintenance.clone()
+    }
+
+    /// Job broker for external workers
+    pub fn lease_service(&self) -> Arc<LeaseService> {
+        self.lease_service.clone()
+    }
+
+    pub fn retry_policies(&self) -> Arc<RetryPolicies> {
+        self.retry_policies.clone()
+    }
+
+    pub fn interceptors(&self) -> Arc<InterceptorChain> {
+        self.interceptors.clone()
+    }
+
+    pub fn queue_validators(&self) -> Arc<QueueValidators> {
+        self.queue_validators.clone()
+    }
+
+    pub fn runtime_config(&self) -> Arc<RuntimeConfig> {
+        self.runtime_config.clone()
+    }
+
+    pub fn lockdown(&self) -> Arc<Lockdown> {
+        self.lockdown.clone()
+    }
+
+    /// Wakes the idle worker (enqueues made outside `enqueue` should notify it)
+    pub fn dispatch_signal(&self) -> Arc<DispatchSignal> {
+        self.dispatch.clone()
+    }
+
+    pub fn worker_activity(&self) -> Arc<WorkerActivity> {
+        self.worker_activity.clone()
+    }
+
+    pub fn running_jobs(&self) -> Arc<RunningJobs> {
+        self.running_jobs.clone()
+    }
+
+    pub fn queue_pauses(&self) -> Arc<QueuePauseRegistry> {
+        self.queue_pauses.clone()
+    }
+
+    pub fn drain(&self) -> Arc<Drain> {
+        self.drain.clone()
+    }
+
+    /// Queues the worker serves
+    pub fn queues(&self) -> &[String] {
+        &self.queues
+    }
+}
+
+impl Drop for SemanticaEngine {
+    fn drop(&mut self) {
+        self.shutdown_tx.shutdown();
+        for task in &self.background {
+            task.abort();
+        }
+    }
+}
@@ -0,0 +1,74 @@
+// Instance Lock - One engine per database
+//
+// Two daemons (or a daemon and an embedded engine) on the same SQLite file
+// both pop, recover each other's RUNNING jobs as orphans and reap each
+// other's leases. Opening the engine takes an exclusive lock on
+// `<db>.lock` next to the database and keeps it for the life of the engine;
+// the OS releases it when the process exits, even on a crash, so a stale
+// file never blocks a restart. The holder's PID is written into the file to
+// name it in the error of the second instance.
+
+use semantica_core::error::{AppError, Result};
+use std::fs::{File, OpenOptions, TryLockError};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+/// Exclusive hold on a database, released on drop
+#[derive(Debug)]
+pub struct InstanceLock {
+    _file: File,
+    path: PathBuf,
+}
+
+impl InstanceLock {
+    /// Lock file of a database (`meta.db` -> `meta.db.lock`)
+    pub fn path_for(db_path: &Path) -> PathBuf {
+        let mut path = db_path.as_os_str().to_owned();
+        path.push(".lock");
+        PathBuf::from(path)
+    }
+
+    /// Take the lock of `db_path`, Conflict (with the holder's PID) if another
+    /// process has it
+    pub fn acquire(db_path: &Path) -> Result<Self> {
+        let path = Self::path_for(db_path);
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)?;
+
+        match file.try_lock() {
+            Ok(()) => {}
+            Err(TryLockError::WouldBlock) => {
+                let mut holder = String::new();
+                let _ = file.read_to_string(&mut holder);
+                let holder = match holder.trim() {
+                    "" => "unknown PID".to_string(),
+                    pid => format!("PID {}", pid),
+                };
+                return Err(AppError::Conflict(format!(
+                    "{} is already in use by another Semantica instance ({}); stop it or point this one at another database (lock file: {})",
+                    db_path.display(),
+                    holder,
+                    path.display()
+                )));
+            }
+            Err(TryLockError::Error(e)) => return Err(e.into()),
+        }
+
+        file.set_len(0)?;
+        file.seek(SeekFrom::Start(0))?;
+        writeln!(file, "{}", std::process::id())?;
+        file.flush()?;
+        Ok(Self { _file: file, path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
@@ -0,0 +1,50 @@
+//! Semantica Engine - The task engine embedded in an application
+//!
+//! The daemon is one way to run the engine: queue, worker, scheduler and the
+//! background loops (maintenance, lease reaper, expiry sweeper, watchdogs)
+//! behind a JSON-RPC server. An application that wants background jobs
+//! without a second process runs the same wiring in-process against its own
+//! SQLite file and calls the engine directly:
+//!
+//! ```no_run
+//! # async fn example() -> semantica_engine::Result<()> {
+//! use semantica_engine::{EngineBuilder, EnqueueRequest};
+//!
+//! let engine = EngineBuilder::open("./jobs.db").await?.start().await?;
+//! let job_id = engine
+//!     .enqueue(EnqueueRequest {
+//!         job_type: "INDEX".to_string(),
+//!         queue: "default".to_string(),
+//!         subject_key: "repo/main".to_string(),
+//!         payload: serde_json::json!({"command": "make", "args": ["index"]}),
+//!         priority: 0,
+//!         idempotency_key: None,
+//!         traceparent: None,
+//!         job_class: None,
+//!         fallback_payloads: Vec::new(),
+//!         options: Default::default(),
+//!     })
+//!     .await?;
+//! engine.shutdown().await;
+//! # let _ = job_id;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The daemon's composition root builds on `EngineBuilder` and adds what is
+//! daemon-only: env/config file parsing, the RPC server, telemetry, signals.
+
+mod builder;
+pub mod config;
+mod engine;
+mod instance_lock;
+
+pub use builder::EngineBuilder;
+pub use config::EngineConfig;
+pub use engine::SemanticaEngine;
+pub use instance_lock::InstanceLock;
+
+pub use semantica_core::application::dev_task::{EnqueueOptions, EnqueueRequest};
+pub use semantica_core::application::Termination;
+pub use semantica_core::domain::{Job, JobState};
+pub use semantica_core::error::{AppError, Result};
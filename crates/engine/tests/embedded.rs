@@ -0,0 +1,102 @@
+//! Embedded engine: queue, worker and scheduler in the test process, no daemon
+
+use semantica_engine::{EngineBuilder, EnqueueRequest, JobState};
+use serde_json::json;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const JOB_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn temp_db(name: &str) -> PathBuf {
+    let dir =
+        std::env::temp_dir().join(format!("semantica-engine-{}-{}", std::process::id(), name));
+    std::fs::create_dir_all(&dir).expect("create temp dir");
+    dir.join("jobs.db")
+}
+
+fn command(subject_key: &str, payload: serde_json::Value) -> EnqueueRequest {
+    EnqueueRequest {
+        job_type: "EMBEDDED".to_string(),
+        queue: "default".to_string(),
+        subject_key: subject_key.to_string(),
+        payload,
+        priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn test_embedded_engine_runs_and_cancels_jobs() {
+    let db_path = temp_db("run");
+    let engine = EngineBuilder::open(&db_path)
+        .await
+        .unwrap()
+        .start()
+        .await
+        .unwrap();
+    assert_eq!(engine.queues(), ["default".to_string()]);
+
+    // One engine per database: a second one fails fast and names the holder
+    let second = EngineBuilder::open(&db_path).await.map(|_| ()).unwrap_err();
+    assert!(
+        second
+            .to_string()
+            .contains(&format!("PID {}", std::process::id())),
+        "{}",
+        second
+    );
+
+    let done_id = engine
+        .enqueue(command(
+            "embedded/echo",
+            json!({"command": "echo", "args": ["hi"]}),
+        ))
+        .await
+        .unwrap();
+    let done = engine.wait_for(&done_id, JOB_TIMEOUT).await.unwrap();
+    assert_eq!(done.state, JobState::Done);
+
+    // A running job is killed, not left executing
+    let sleep_id = engine
+        .enqueue(command(
+            "embedded/sleep",
+            json!({"command": "sleep", "args": ["30"]}),
+        ))
+        .await
+        .unwrap();
+    let deadline = tokio::time::Instant::now() + JOB_TIMEOUT;
+    while !engine.running_jobs().is_running(&sleep_id) {
+        assert!(tokio::time::Instant::now() < deadline, "job never started");
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    let termination = engine.cancel(&sleep_id).await.unwrap();
+    assert!(termination.is_terminated(), "{:?}", termination);
+    let cancelled = engine.wait_for(&sleep_id, JOB_TIMEOUT).await.unwrap();
+    assert_eq!(cancelled.state, JobState::Cancelled);
+    assert_eq!(
+        engine
+            .cancel("no-such-job")
+            .await
+            .map(|_| ())
+            .unwrap_err()
+            .to_string(),
+        "Not found: Job no-such-job not found"
+    );
+
+    engine.shutdown().await;
+
+    // The same file opens again with the jobs in it
+    let engine = EngineBuilder::open(&db_path)
+        .await
+        .unwrap()
+        .start()
+        .await
+        .unwrap();
+    assert!(engine.job(&done_id).await.unwrap().is_some());
+    engine.shutdown().await;
+    let _ = std::fs::remove_dir_all(db_path.parent().unwrap());
+}
@@ -0,0 +1,166 @@
+//! pop_next on a large jobs table: the subjects join (migration 024) against
+//! the correlated `MAX(generation)` subquery it replaced
+//!
+//! Run with `cargo bench -p semantica-infra-sqlite --bench pop_next`.
+//! `POP_BENCH_SUBJECTS` (default 200000) subjects with `POP_BENCH_GENERATIONS`
+//! (default 5) generations each: the newest QUEUED, the rest SUPERSEDED.
+
+use semantica_core::port::time_provider::SystemTimeProvider;
+use semantica_core::port::JobRepository;
+use semantica_infra_sqlite::{create_pool, run_migrations, SqliteJobRepository};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const POPS: u32 = 20;
+
+/// pop_next before migration 024
+const LEGACY_POP_SQL: &str = r#"
+    WITH RECURSIVE inherited(job_id, priority) AS (
+        SELECT parent_job_id, priority FROM jobs
+        WHERE state = ? AND parent_job_id IS NOT NULL
+        UNION
+        SELECT p.parent_job_id, i.priority
+        FROM inherited i
+        JOIN jobs p ON p.id = i.job_id
+        WHERE p.parent_job_id IS NOT NULL
+    )
+    UPDATE jobs
+    SET state = ?, started_at = ?,
+        progress_percent = NULL, progress_message = NULL, progress_updated_at = NULL
+    WHERE id = (
+        SELECT j.id FROM jobs j
+        WHERE j.queue = ? AND j.state = ?
+          AND (j.next_attempt_at IS NULL OR j.next_attempt_at <= ?)
+          AND j.job_type NOT IN (SELECT job_type FROM disabled_job_types)
+          AND j.generation = (
+              SELECT MAX(generation)
+              FROM jobs
+              WHERE subject_key = j.subject_key
+          )
+        ORDER BY MAX(
+            j.priority,
+            COALESCE(
+                (SELECT MAX(i.priority) FROM inherited i WHERE i.job_id = j.id),
+                j.priority
+            )
+        ) DESC, j.created_at ASC, j.id ASC
+        LIMIT 1
+    )
+    RETURNING id
+    "#;
+
+/// Indexes as of migration 023
+const LEGACY_INDEXES_SQL: [&str; 3] = [
+    "DROP INDEX idx_jobs_pop_covering",
+    "DROP INDEX idx_subjects_latest_generation",
+    "CREATE INDEX idx_jobs_queue_state_priority
+       ON jobs (queue, state, priority DESC, created_at ASC)",
+];
+
+const CURRENT_INDEXES_SQL: [&str; 3] = [
+    "DROP INDEX idx_jobs_queue_state_priority",
+    "CREATE INDEX idx_jobs_pop_covering
+       ON jobs (queue, state, priority DESC, created_at ASC, id,
+                subject_key, generation, job_type, next_attempt_at, schedule_at)",
+    "CREATE INDEX idx_subjects_latest_generation
+       ON subjects (subject_key, latest_generation)",
+];
+
+fn env_count(name: &str, default: i64) -> i64 {
+    std::env::var(name)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .filter(|n| *n > 0)
+        .unwrap_or(default)
+}
+
+async fn seed(pool: &SqlitePool, subjects: i64, generations: i64) {
+    sqlx::query(
+        r#"
+        WITH RECURSIVE seq(n) AS (SELECT 0 UNION ALL SELECT n + 1 FROM seq WHERE n + 1 < ?)
+        INSERT INTO jobs (id, queue, job_type, subject_key, generation, priority, state,
+                          created_at, payload)
+        SELECT printf('job-%d', n), 'default', 'BENCH', printf('subject-%d', n / ?),
+               n % ? + 1, n % 7,
+               CASE WHEN n % ? + 1 = ? THEN 'QUEUED' ELSE 'SUPERSEDED' END,
+               n, '{}'
+        FROM seq
+        "#,
+    )
+    .bind(subjects * generations)
+    .bind(generations)
+    .bind(generations)
+    .bind(generations)
+    .bind(generations)
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        "INSERT INTO subjects (subject_key, latest_generation)
+         SELECT subject_key, MAX(generation) FROM jobs GROUP BY subject_key",
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query("ANALYZE").execute(pool).await.unwrap();
+}
+
+async fn execute_all(pool: &SqlitePool, statements: &[&str]) {
+    for statement in statements {
+        sqlx::query(statement).execute(pool).await.unwrap();
+    }
+    sqlx::query("ANALYZE").execute(pool).await.unwrap();
+}
+
+fn report(name: &str, elapsed: Duration) {
+    println!(
+        "{:<24} {:>10.2} ms/pop",
+        name,
+        elapsed.as_secs_f64() * 1000.0 / f64::from(POPS)
+    );
+}
+
+#[tokio::main]
+async fn main() {
+    let subjects = env_count("POP_BENCH_SUBJECTS", 200_000);
+    let generations = env_count("POP_BENCH_GENERATIONS", 5);
+
+    let pool = create_pool("sqlite::memory:").await.unwrap();
+    run_migrations(&pool).await.unwrap();
+
+    let started = Instant::now();
+    seed(&pool, subjects, generations).await;
+    println!(
+        "seeded {} jobs ({} QUEUED) in {:?}",
+        subjects * generations,
+        subjects,
+        started.elapsed()
+    );
+
+    execute_all(&pool, &LEGACY_INDEXES_SQL).await;
+    let started = Instant::now();
+    for _ in 0..POPS {
+        let popped: Option<String> = sqlx::query_scalar(LEGACY_POP_SQL)
+            .bind("QUEUED")
+            .bind("RUNNING")
+            .bind(0_i64)
+            .bind("default")
+            .bind("QUEUED")
+            .bind(i64::MAX)
+            .fetch_optional(&pool)
+            .await
+            .unwrap();
+        assert!(popped.is_some());
+    }
+    report("legacy MAX(generation)", started.elapsed());
+
+    execute_all(&pool, &CURRENT_INDEXES_SQL).await;
+    let repo = SqliteJobRepository::new(pool, Arc::new(SystemTimeProvider));
+    let started = Instant::now();
+    for _ in 0..POPS {
+        assert!(repo.pop_next("default").await.unwrap().is_some());
+    }
+    report("pop_next (subjects)", started.elapsed());
+}
@@ -0,0 +1,11 @@
+-- Job-type enable/disable switch (admin.job_type.disable.v1)
+-- Disabled job types stay QUEUED (pop skips them) and new enqueues are rejected
+
+CREATE TABLE IF NOT EXISTS disabled_job_types (
+  job_type TEXT PRIMARY KEY,
+  disabled_at INTEGER NOT NULL
+);
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (5, strftime('%s', 'now') * 1000);
@@ -0,0 +1,18 @@
+-- Composite indexes for hot paths (pop, supersede, GC)
+-- Slow pops and GCs degrade as the jobs table grows without these
+
+-- pop_next: WHERE queue = ? AND state = 'QUEUED' ORDER BY priority DESC, created_at ASC
+CREATE INDEX IF NOT EXISTS idx_jobs_queue_state_priority
+  ON jobs (queue, state, priority DESC, created_at ASC);
+
+-- mark_superseded: WHERE subject_key = ? AND generation < ? AND state = 'QUEUED'
+CREATE INDEX IF NOT EXISTS idx_jobs_subject_generation_state
+  ON jobs (subject_key, generation, state);
+
+-- gc_finished_jobs / gc_artifacts: WHERE finished_at < ? AND state IN (...)
+CREATE INDEX IF NOT EXISTS idx_jobs_finished_state
+  ON jobs (finished_at, state);
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (6, strftime('%s', 'now') * 1000);
@@ -0,0 +1,12 @@
+-- Idempotency keys for enqueue
+-- Replayed requests (e.g. from the SDK offline journal) resolve to the original job
+
+CREATE TABLE IF NOT EXISTS idempotency_keys (
+  key TEXT PRIMARY KEY,
+  job_id TEXT NOT NULL,
+  created_at INTEGER NOT NULL
+);
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (7, strftime('%s', 'now') * 1000);
@@ -0,0 +1,12 @@
+-- Maintenance report history (semantica maintenance history)
+-- Each row is one scheduled run, stored as JSON (only the newest N rows are kept)
+
+CREATE TABLE IF NOT EXISTS maintenance_reports (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  started_at INTEGER NOT NULL,
+  report TEXT NOT NULL
+);
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (8, strftime('%s', 'now') * 1000);
@@ -0,0 +1,18 @@
+-- Job event history (cancellation reasons and actors)
+-- actor: cli, rpc, sdk:<client_id>, supersede, shutdown
+
+CREATE TABLE IF NOT EXISTS job_events (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  job_id TEXT NOT NULL,
+  state TEXT NOT NULL,
+  actor TEXT NOT NULL,
+  reason TEXT,
+  created_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_job_events_job
+  ON job_events (job_id, id);
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (9, strftime('%s', 'now') * 1000);
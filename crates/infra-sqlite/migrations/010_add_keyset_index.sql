@@ -0,0 +1,9 @@
+-- Keyset pagination index
+-- List endpoints page through jobs ordered by (created_at, id)
+
+CREATE INDEX IF NOT EXISTS idx_jobs_created_id
+  ON jobs (created_at, id);
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (10, strftime('%s', 'now') * 1000);
@@ -0,0 +1,18 @@
+-- Job leases for external workers (worker.lease.v1)
+-- One row per leased RUNNING job, removed on completion or by the lease reaper
+
+CREATE TABLE IF NOT EXISTS job_leases (
+  job_id TEXT PRIMARY KEY,
+  worker_id TEXT NOT NULL,
+  leased_at INTEGER NOT NULL,
+  expires_at INTEGER NOT NULL,
+  heartbeat_at INTEGER NOT NULL,
+  progress TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_job_leases_expires
+  ON job_leases (expires_at);
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (11, strftime('%s', 'now') * 1000);
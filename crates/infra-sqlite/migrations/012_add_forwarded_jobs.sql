@@ -0,0 +1,18 @@
+-- Jobs forwarded to a remote daemon (queue forwarding)
+-- closed_at is set once the remote terminal state was mirrored locally
+
+CREATE TABLE IF NOT EXISTS forwarded_jobs (
+  job_id TEXT PRIMARY KEY,
+  endpoint TEXT NOT NULL,
+  remote_job_id TEXT NOT NULL,
+  forwarded_at INTEGER NOT NULL,
+  remote_state TEXT,
+  closed_at INTEGER
+);
+
+CREATE INDEX IF NOT EXISTS idx_forwarded_jobs_open
+  ON forwarded_jobs (forwarded_at) WHERE closed_at IS NULL;
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (12, strftime('%s', 'now') * 1000);
@@ -0,0 +1,11 @@
+-- Job class: TASK (runs to completion) or SERVICE (runs indefinitely, restarted on exit)
+
+ALTER TABLE jobs ADD COLUMN job_class TEXT NOT NULL DEFAULT 'TASK';
+
+-- Active services listed by admin.stats.v1
+CREATE INDEX IF NOT EXISTS idx_jobs_services
+  ON jobs (state) WHERE job_class = 'SERVICE';
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (13, strftime('%s', 'now') * 1000);
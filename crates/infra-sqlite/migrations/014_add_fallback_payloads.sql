@@ -0,0 +1,7 @@
+-- Fallback payloads: JSON array of payloads run by successive retries (NULL = none)
+
+ALTER TABLE jobs ADD COLUMN fallback_payloads TEXT;
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (14, strftime('%s', 'now') * 1000);
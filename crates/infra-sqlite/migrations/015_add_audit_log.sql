@@ -0,0 +1,20 @@
+-- RPC audit log: one row per mutating call (method, caller, outcome, latency)
+-- params holds a summary with payloads elided, error_code is NULL on success
+
+CREATE TABLE IF NOT EXISTS audit_log (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  timestamp INTEGER NOT NULL,
+  method TEXT NOT NULL,
+  caller TEXT NOT NULL,
+  job_id TEXT,
+  params TEXT NOT NULL,
+  error_code INTEGER,
+  latency_ms INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_job
+  ON audit_log (job_id, id);
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (15, strftime('%s', 'now') * 1000);
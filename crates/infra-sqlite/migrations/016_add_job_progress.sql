@@ -0,0 +1,10 @@
+-- Job progress: last percent (0-100) and message reported while RUNNING
+-- Cleared when the job is popped for a new attempt
+
+ALTER TABLE jobs ADD COLUMN progress_percent INTEGER;
+ALTER TABLE jobs ADD COLUMN progress_message TEXT;
+ALTER TABLE jobs ADD COLUMN progress_updated_at INTEGER;
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (16, strftime('%s', 'now') * 1000);
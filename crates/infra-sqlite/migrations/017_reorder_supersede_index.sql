@@ -0,0 +1,13 @@
+-- Supersede index with the equality columns first
+-- (subject_key, generation, state) made enqueue scan every older generation
+-- of the subject, so editor saves of the same file slowed down as history grew.
+-- (subject_key, state, generation) only visits the subject's QUEUED jobs
+
+DROP INDEX IF EXISTS idx_jobs_subject_generation_state;
+
+CREATE INDEX IF NOT EXISTS idx_jobs_subject_state_generation
+  ON jobs (subject_key, state, generation);
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (17, strftime('%s', 'now') * 1000);
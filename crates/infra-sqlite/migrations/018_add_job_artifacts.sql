@@ -0,0 +1,16 @@
+-- Metadata of registered job artifacts (size, SHA-256 checksum, registration time)
+-- jobs.artifacts stays the list of URIs. Artifacts registered before this
+-- table existed have no row here
+
+CREATE TABLE IF NOT EXISTS job_artifacts (
+  job_id TEXT NOT NULL,
+  uri TEXT NOT NULL,
+  size_bytes INTEGER NOT NULL,
+  checksum TEXT NOT NULL,
+  created_at INTEGER NOT NULL,
+  PRIMARY KEY (job_id, uri)
+);
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (18, strftime('%s', 'now') * 1000);
@@ -0,0 +1,20 @@
+-- History of successful results per subject (subject.history.v1)
+-- Keeps the last N completions of each subject_key with a hash of the job's
+-- output, so clients can tell whether a rerun produced a different result.
+-- Rows outlive the jobs they describe (GC and job.delete leave them)
+
+CREATE TABLE IF NOT EXISTS subject_history (
+  subject_key TEXT NOT NULL,
+  generation INTEGER NOT NULL,
+  job_id TEXT NOT NULL,
+  result_hash TEXT,
+  duration_ms INTEGER NOT NULL,
+  finished_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_subject_history_subject
+  ON subject_history (subject_key, finished_at DESC);
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (19, strftime('%s', 'now') * 1000);
@@ -0,0 +1,20 @@
+-- Throughput history (admin.metrics.history.v1)
+-- One row per sampling interval: jobs that finished DONE / FAILED during the
+-- window ending at taken_at, plus the queue depth at that moment.
+-- Only the newest N rows are kept
+
+CREATE TABLE IF NOT EXISTS metrics_snapshots (
+  taken_at INTEGER NOT NULL,
+  window_ms INTEGER NOT NULL,
+  completed INTEGER NOT NULL,
+  failed INTEGER NOT NULL,
+  queued INTEGER NOT NULL,
+  running INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_metrics_snapshots_taken_at
+  ON metrics_snapshots (taken_at);
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (20, strftime('%s', 'now') * 1000);
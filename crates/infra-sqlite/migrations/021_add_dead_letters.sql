@@ -0,0 +1,20 @@
+-- Dead-letter queue (dlq.list.v1, dlq.requeue.v1, dlq.purge.v1)
+-- One row per job that failed for good, with its last error.
+-- The job row stays FAILED, the entry goes away when the job is requeued
+
+CREATE TABLE IF NOT EXISTS dead_letters (
+  job_id TEXT PRIMARY KEY,
+  queue TEXT NOT NULL,
+  job_type TEXT NOT NULL,
+  subject_key TEXT NOT NULL,
+  attempts INTEGER NOT NULL,
+  last_error TEXT NOT NULL,
+  dead_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_dead_letters_queue_dead_at
+  ON dead_letters (queue, dead_at);
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (21, strftime('%s', 'now') * 1000);
@@ -0,0 +1,8 @@
+-- Poison-job detection: executions that panicked, counted apart from attempts
+-- A job reaching the panic limit is quarantined (FAILED) instead of retried
+
+ALTER TABLE jobs ADD COLUMN panic_count INTEGER NOT NULL DEFAULT 0;
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (22, strftime('%s', 'now') * 1000);
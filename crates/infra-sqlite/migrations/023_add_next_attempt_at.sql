@@ -0,0 +1,8 @@
+-- Retry backoff: a retried job is not popped before next_attempt_at (epoch ms)
+-- NULL for jobs that never failed (and for panic requeues, retried at once)
+
+ALTER TABLE jobs ADD COLUMN next_attempt_at INTEGER;
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (23, strftime('%s', 'now') * 1000);
@@ -0,0 +1,26 @@
+-- pop_next reads the latest generation from subjects instead of a
+-- correlated MAX(generation) subquery per candidate, which scanned the
+-- subject's whole history and degraded with millions of rows
+
+-- Backfill subjects for jobs inserted without one (latest never goes down)
+INSERT INTO subjects (subject_key, latest_generation)
+SELECT subject_key, MAX(generation) FROM jobs WHERE true GROUP BY subject_key
+ON CONFLICT(subject_key) DO UPDATE
+SET latest_generation = MAX(latest_generation, excluded.latest_generation);
+
+-- Covering index for the pop candidate scan: the queue/state range in pop
+-- order, plus every column the filters and the subjects join read, so
+-- candidates are rejected without touching the table
+DROP INDEX IF EXISTS idx_jobs_queue_state_priority;
+
+CREATE INDEX IF NOT EXISTS idx_jobs_pop_covering
+  ON jobs (queue, state, priority DESC, created_at ASC, id,
+           subject_key, generation, job_type, next_attempt_at);
+
+-- Covering index for the join (the primary key alone needs a row lookup)
+CREATE INDEX IF NOT EXISTS idx_subjects_latest_generation
+  ON subjects (subject_key, latest_generation);
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (24, strftime('%s', 'now') * 1000);
@@ -0,0 +1,13 @@
+-- pop_next skips jobs whose schedule_at is still ahead, so external workers
+-- leasing straight from pop never receive a job before its start time
+
+-- Covering index for the pop candidate scan, now including schedule_at
+DROP INDEX IF EXISTS idx_jobs_pop_covering;
+
+CREATE INDEX IF NOT EXISTS idx_jobs_pop_covering
+  ON jobs (queue, state, priority DESC, created_at ASC, id,
+           subject_key, generation, job_type, next_attempt_at, schedule_at);
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (25, strftime('%s', 'now') * 1000);
@@ -0,0 +1,9 @@
+-- TTL anchor: when the job's current wait in the queue started (epoch ms),
+-- set on enqueue and on a manual retry. created_at stays the creation time
+-- (list order and pagination key). NULL (older rows) = created_at
+
+ALTER TABLE jobs ADD COLUMN queued_at INTEGER;
+
+-- Update schema version
+INSERT INTO schema_version (version, applied_at)
+VALUES (26, strftime('%s', 'now') * 1000);
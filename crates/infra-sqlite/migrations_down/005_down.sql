@@ -0,0 +1,5 @@
+-- Rollback job-type enable/disable switch
+
+DROP TABLE IF EXISTS disabled_job_types;
+
+DELETE FROM schema_version WHERE version = 5;
@@ -0,0 +1,7 @@
+-- Rollback composite indexes
+
+DROP INDEX IF EXISTS idx_jobs_finished_state;
+DROP INDEX IF EXISTS idx_jobs_subject_generation_state;
+DROP INDEX IF EXISTS idx_jobs_queue_state_priority;
+
+DELETE FROM schema_version WHERE version = 6;
@@ -0,0 +1,5 @@
+-- Rollback idempotency keys
+
+DROP TABLE IF EXISTS idempotency_keys;
+
+DELETE FROM schema_version WHERE version = 7;
@@ -0,0 +1,5 @@
+-- Rollback maintenance report history
+
+DROP TABLE IF EXISTS maintenance_reports;
+
+DELETE FROM schema_version WHERE version = 8;
@@ -0,0 +1,6 @@
+-- Rollback job event history
+
+DROP INDEX IF EXISTS idx_job_events_job;
+DROP TABLE IF EXISTS job_events;
+
+DELETE FROM schema_version WHERE version = 9;
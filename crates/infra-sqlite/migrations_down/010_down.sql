@@ -0,0 +1,5 @@
+-- Rollback keyset pagination index
+
+DROP INDEX IF EXISTS idx_jobs_created_id;
+
+DELETE FROM schema_version WHERE version = 10;
@@ -0,0 +1,6 @@
+-- Rollback job leases
+
+DROP INDEX IF EXISTS idx_job_leases_expires;
+DROP TABLE IF EXISTS job_leases;
+
+DELETE FROM schema_version WHERE version = 11;
@@ -0,0 +1,6 @@
+-- Rollback forwarded jobs
+
+DROP INDEX IF EXISTS idx_forwarded_jobs_open;
+DROP TABLE IF EXISTS forwarded_jobs;
+
+DELETE FROM schema_version WHERE version = 12;
@@ -0,0 +1,6 @@
+-- Rollback job class
+
+DROP INDEX IF EXISTS idx_jobs_services;
+ALTER TABLE jobs DROP COLUMN job_class;
+
+DELETE FROM schema_version WHERE version = 13;
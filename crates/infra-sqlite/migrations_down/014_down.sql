@@ -0,0 +1,5 @@
+-- Rollback fallback payloads
+
+ALTER TABLE jobs DROP COLUMN fallback_payloads;
+
+DELETE FROM schema_version WHERE version = 14;
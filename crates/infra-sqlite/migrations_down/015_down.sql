@@ -0,0 +1,5 @@
+-- Rollback RPC audit log
+
+DROP TABLE IF EXISTS audit_log;
+
+DELETE FROM schema_version WHERE version = 15;
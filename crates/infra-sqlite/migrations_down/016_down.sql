@@ -0,0 +1,7 @@
+-- Rollback job progress
+
+ALTER TABLE jobs DROP COLUMN progress_updated_at;
+ALTER TABLE jobs DROP COLUMN progress_message;
+ALTER TABLE jobs DROP COLUMN progress_percent;
+
+DELETE FROM schema_version WHERE version = 16;
@@ -0,0 +1,8 @@
+-- Rollback supersede index reorder
+
+DROP INDEX IF EXISTS idx_jobs_subject_state_generation;
+
+CREATE INDEX IF NOT EXISTS idx_jobs_subject_generation_state
+  ON jobs (subject_key, generation, state);
+
+DELETE FROM schema_version WHERE version = 17;
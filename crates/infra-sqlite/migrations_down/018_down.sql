@@ -0,0 +1,5 @@
+-- Rollback job artifact metadata
+
+DROP TABLE IF EXISTS job_artifacts;
+
+DELETE FROM schema_version WHERE version = 18;
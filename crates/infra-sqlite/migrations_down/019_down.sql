@@ -0,0 +1,6 @@
+-- Rollback subject result history
+
+DROP INDEX IF EXISTS idx_subject_history_subject;
+DROP TABLE IF EXISTS subject_history;
+
+DELETE FROM schema_version WHERE version = 19;
@@ -0,0 +1,6 @@
+-- Rollback throughput history
+
+DROP INDEX IF EXISTS idx_metrics_snapshots_taken_at;
+DROP TABLE IF EXISTS metrics_snapshots;
+
+DELETE FROM schema_version WHERE version = 20;
@@ -0,0 +1,6 @@
+-- Rollback dead-letter queue
+
+DROP INDEX IF EXISTS idx_dead_letters_queue_dead_at;
+DROP TABLE IF EXISTS dead_letters;
+
+DELETE FROM schema_version WHERE version = 21;
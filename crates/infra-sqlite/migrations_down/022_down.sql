@@ -0,0 +1,5 @@
+-- Rollback panic count
+
+ALTER TABLE jobs DROP COLUMN panic_count;
+
+DELETE FROM schema_version WHERE version = 22;
@@ -0,0 +1,5 @@
+-- Rollback retry backoff column
+
+ALTER TABLE jobs DROP COLUMN next_attempt_at;
+
+DELETE FROM schema_version WHERE version = 23;
@@ -0,0 +1,10 @@
+-- Rollback pop subjects join indexes (backfilled subjects rows are kept)
+
+DROP INDEX IF EXISTS idx_subjects_latest_generation;
+
+DROP INDEX IF EXISTS idx_jobs_pop_covering;
+
+CREATE INDEX IF NOT EXISTS idx_jobs_queue_state_priority
+  ON jobs (queue, state, priority DESC, created_at ASC);
+
+DELETE FROM schema_version WHERE version = 24;
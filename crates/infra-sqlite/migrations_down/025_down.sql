@@ -0,0 +1,9 @@
+-- Rollback schedule_at in the pop covering index
+
+DROP INDEX IF EXISTS idx_jobs_pop_covering;
+
+CREATE INDEX IF NOT EXISTS idx_jobs_pop_covering
+  ON jobs (queue, state, priority DESC, created_at ASC, id,
+           subject_key, generation, job_type, next_attempt_at);
+
+DELETE FROM schema_version WHERE version = 25;
@@ -0,0 +1,5 @@
+-- Rollback TTL anchor column
+
+ALTER TABLE jobs DROP COLUMN queued_at;
+
+DELETE FROM schema_version WHERE version = 26;
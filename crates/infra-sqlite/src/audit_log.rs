@@ -0,0 +1,178 @@
+// SQLite Audit Log
+// Rows of the audit_log table (migration 015), trimmed to the newest N
+
+use async_trait::async_trait;
+use semantica_core::error::{AppError, Result};
+use semantica_core::port::{AuditEntry, AuditFilter, AuditLog};
+use sqlx::SqlitePool;
+
+/// Rows kept by default (oldest are dropped on insert)
+const DEFAULT_MAX_ROWS: i64 = 100_000;
+
+const LIST_SQL: &str = r#"
+    SELECT timestamp, method, caller, job_id, params, error_code, latency_ms
+    FROM audit_log
+    WHERE (? IS NULL OR method = ?)
+    AND (? IS NULL OR job_id = ?)
+    AND (? IS NULL OR caller = ?)
+    AND (? IS NULL OR timestamp >= ?)
+    ORDER BY id DESC
+    LIMIT ?
+    "#;
+
+type AuditRow = (
+    i64,
+    String,
+    String,
+    Option<String>,
+    String,
+    Option<i32>,
+    i64,
+);
+
+/// SQLite audit log store
+pub struct SqliteAuditLog {
+    pool: SqlitePool,
+    max_rows: i64,
+}
+
+impl SqliteAuditLog {
+    /// Keep `SEMANTICA_AUDIT_LOG_MAX_ROWS` rows (default 100000)
+    pub fn new(pool: SqlitePool) -> Self {
+        let max_rows = std::env::var("SEMANTICA_AUDIT_LOG_MAX_ROWS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_MAX_ROWS);
+        Self { pool, max_rows }
+    }
+
+    pub fn with_max_rows(mut self, max_rows: i64) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+}
+
+#[async_trait]
+impl AuditLog for SqliteAuditLog {
+    async fn record(&self, entry: &AuditEntry) -> Result<()> {
+        let id = sqlx::query(
+            r#"
+            INSERT INTO audit_log (timestamp, method, caller, job_id, params, error_code, latency_ms)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(entry.timestamp)
+        .bind(&entry.method)
+        .bind(&entry.caller)
+        .bind(&entry.job_id)
+        .bind(&entry.params)
+        .bind(entry.error_code)
+        .bind(entry.latency_ms)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to record audit entry: {}", e)))?
+        .last_insert_rowid();
+
+        // Ids are sequential: everything this far behind the newest row goes
+        sqlx::query("DELETE FROM audit_log WHERE id <= ?")
+            .bind(id - self.max_rows.max(1))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to trim audit log: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn list(&self, filter: &AuditFilter, limit: usize) -> Result<Vec<AuditEntry>> {
+        let rows: Vec<AuditRow> = sqlx::query_as(LIST_SQL)
+            .bind(&filter.method)
+            .bind(&filter.method)
+            .bind(&filter.job_id)
+            .bind(&filter.job_id)
+            .bind(&filter.caller)
+            .bind(&filter.caller)
+            .bind(filter.since)
+            .bind(filter.since)
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to list audit log: {}", e)))?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(timestamp, method, caller, job_id, params, error_code, latency_ms)| AuditEntry {
+                    timestamp,
+                    method,
+                    caller,
+                    job_id,
+                    params,
+                    error_code,
+                    latency_ms,
+                },
+            )
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_pool, run_migrations};
+
+    fn entry(timestamp: i64, method: &str, job_id: Option<&str>) -> AuditEntry {
+        AuditEntry {
+            timestamp,
+            method: method.to_string(),
+            caller: "cli".to_string(),
+            job_id: job_id.map(str::to_string),
+            params: "{}".to_string(),
+            error_code: None,
+            latency_ms: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_filters_and_trimming() {
+        let pool = create_pool(":memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        let audit = SqliteAuditLog::new(pool).with_max_rows(3);
+
+        audit
+            .record(&entry(1, "dev.enqueue.v1", None))
+            .await
+            .unwrap();
+        audit
+            .record(&entry(2, "dev.cancel.v1", Some("job-1")))
+            .await
+            .unwrap();
+        audit
+            .record(&entry(3, "dev.cancel.v1", Some("job-2")))
+            .await
+            .unwrap();
+        audit
+            .record(&entry(4, "job.retry.v1", Some("job-1")))
+            .await
+            .unwrap();
+
+        // Oldest row trimmed
+        let all = audit.list(&AuditFilter::default(), 10).await.unwrap();
+        let times: Vec<i64> = all.iter().map(|e| e.timestamp).collect();
+        assert_eq!(times, vec![4, 3, 2]);
+
+        let filter = AuditFilter {
+            method: Some("dev.cancel.v1".to_string()),
+            job_id: Some("job-1".to_string()),
+            ..Default::default()
+        };
+        let cancels = audit.list(&filter, 10).await.unwrap();
+        assert_eq!(cancels.len(), 1);
+        assert_eq!(cancels[0].caller, "cli");
+
+        let since = AuditFilter {
+            since: Some(4),
+            ..Default::default()
+        };
+        assert_eq!(audit.list(&since, 10).await.unwrap().len(), 1);
+    }
+}
@@ -1,14 +1,23 @@
 // SQLite JobRepository Implementation
 
+use crate::pagination::{self, KeysetCursor, KEYSET_AFTER, KEYSET_ORDER_BY};
+use crate::slow_query::SlowQueryLog;
 use crate::SqliteJobTransaction;
 use async_trait::async_trait;
-use semantica_core::domain::{Job, JobId, JobState};
+use semantica_core::domain::job_event::actor;
+use semantica_core::domain::{
+    Artifact, DeadLetter, ExpiredJob, ForwardedJob, Job, JobEvent, JobId, JobLease, JobProgress,
+    JobState, JobTypeStats, MetricsSnapshot, QueueStats, SubjectGeneration, SubjectResult,
+    WaitSloReport,
+};
 use semantica_core::error::{AppError, Result};
 use semantica_core::port::{
-    JobRepository, JobRepositoryTransaction, TimeProvider, TransactionalJobRepository,
+    JobFilter, JobRepository, JobRepositoryTransaction, Page, PageRequest, TimeProvider,
+    TransactionalJobRepository,
 };
 use sqlx::SqlitePool;
 use std::sync::Arc;
+use std::time::Instant;
 
 // Helper to convert sqlx::Error to AppError with structured information
 fn map_sqlx_error(err: sqlx::Error) -> AppError {
@@ -71,18 +80,319 @@ fn map_sqlx_error(err: sqlx::Error) -> AppError {
     }
 }
 
+// Hot-path queries (constants so slow-query logging can EXPLAIN them)
+const POP_NEXT_SQL: &str = r#"
+    WITH RECURSIVE inherited(job_id, priority) AS (
+        SELECT parent_job_id, priority FROM jobs
+        WHERE state = ? AND parent_job_id IS NOT NULL
+        UNION
+        SELECT p.parent_job_id, i.priority
+        FROM inherited i
+        JOIN jobs p ON p.id = i.job_id
+        WHERE p.parent_job_id IS NOT NULL
+    )
+    UPDATE jobs
+    SET state = ?, started_at = ?,
+        progress_percent = NULL, progress_message = NULL, progress_updated_at = NULL
+    WHERE id = (
+        SELECT j.id FROM jobs j
+        -- Pop-time supersede: Only pop if this job has the latest generation
+        -- (subjects tracks it, one primary key lookup instead of a MAX scan)
+        JOIN subjects s
+          ON s.subject_key = j.subject_key AND s.latest_generation = j.generation
+        WHERE j.queue = ? AND j.state = ?
+          -- Retries wait out their backoff
+          AND (j.next_attempt_at IS NULL OR j.next_attempt_at <= ?)
+          -- Scheduled jobs wait for their start time
+          AND (j.schedule_at IS NULL OR j.schedule_at <= ?)
+          -- Disabled job types stay QUEUED until re-enabled
+          AND j.job_type NOT IN (SELECT job_type FROM disabled_job_types)
+        ORDER BY MAX(
+            j.priority,
+            COALESCE(
+                (SELECT MAX(i.priority) FROM inherited i WHERE i.job_id = j.id),
+                j.priority
+            )
+        ) DESC, j.created_at ASC, j.id ASC
+        LIMIT 1
+    )
+    RETURNING *
+    "#;
+
+/// POP_NEXT_SQL over `queues` queues, preferring them in bind order.
+/// Binds: as POP_NEXT_SQL with one queue bind per queue, then the queues again
+/// for the ORDER BY preference
+fn pop_next_multi_sql(queues: usize) -> String {
+    let placeholders = vec!["?"; queues].join(", ");
+    let preference: String = (0..queues)
+        .map(|rank| format!(" WHEN ? THEN {}", rank))
+        .collect();
+    POP_NEXT_SQL
+        .replace(
+            "WHERE j.queue = ? AND",
+            &format!("WHERE j.queue IN ({}) AND", placeholders),
+        )
+        .replace(
+            "ORDER BY MAX(",
+            &format!("ORDER BY CASE j.queue{} END, MAX(", preference),
+        )
+}
+
+/// POP_NEXT_SQL claiming up to N jobs. Binds: as POP_NEXT_SQL, then the limit
+fn pop_batch_sql() -> String {
+    POP_NEXT_SQL
+        .replace("WHERE id = (", "WHERE id IN (")
+        .replace("LIMIT 1", "LIMIT ?")
+}
+
+/// Records `generation` as the subject's latest unless a newer one is known.
+/// Binds: subject_key, generation
+pub(crate) const RAISE_GENERATION_SQL: &str = r#"
+    INSERT INTO subjects (subject_key, latest_generation) VALUES (?, ?)
+    ON CONFLICT(subject_key) DO UPDATE
+    SET latest_generation = MAX(latest_generation, excluded.latest_generation)
+    "#;
+
+const MARK_SUPERSEDED_SQL: &str = r#"
+    UPDATE jobs
+    SET state = ?, finished_at = ?
+    WHERE subject_key = ? AND generation < ? AND state = ?
+    "#;
+
+/// Records a `supersede` event for each job MARK_SUPERSEDED_SQL is about to mark
+/// (same filter, must run first). Binds: state, actor, generation, now, subject_key,
+/// generation, state_queued
+pub(crate) const RECORD_SUPERSEDE_EVENTS_SQL: &str = r#"
+    INSERT INTO job_events (job_id, state, actor, reason, created_at)
+    SELECT id, ?, ?, 'superseded by generation ' || ?, ?
+    FROM jobs
+    WHERE subject_key = ? AND generation < ? AND state = ?
+    "#;
+
+const LEASE_COLUMNS: &str = "job_id, worker_id, leased_at, expires_at, heartbeat_at, progress";
+
+type LeaseRow = (String, String, i64, i64, i64, Option<String>);
+
+fn lease_from_row(row: LeaseRow) -> JobLease {
+    let (job_id, worker_id, leased_at, expires_at, heartbeat_at, progress) = row;
+    JobLease {
+        job_id,
+        worker_id,
+        leased_at,
+        expires_at,
+        heartbeat_at,
+        progress,
+    }
+}
+
+const FORWARD_COLUMNS: &str =
+    "job_id, endpoint, remote_job_id, forwarded_at, remote_state, closed_at";
+
+type ForwardRow = (String, String, String, i64, Option<String>, Option<i64>);
+
+fn forward_from_row(row: ForwardRow) -> ForwardedJob {
+    let (job_id, endpoint, remote_job_id, forwarded_at, remote_state, closed_at) = row;
+    ForwardedJob {
+        job_id,
+        endpoint,
+        remote_job_id,
+        forwarded_at,
+        remote_state: remote_state.as_deref().map(parse_state),
+        closed_at,
+    }
+}
+
+const COUNT_BY_STATE_SQL: &str = "SELECT COUNT(*) FROM jobs WHERE queue = ? AND state = ?";
+
+const LIST_ACTIVE_SERVICES_SQL: &str = r#"
+    SELECT * FROM jobs
+    WHERE job_class = 'SERVICE' AND state IN ('QUEUED', 'RUNNING')
+    ORDER BY created_at ASC
+    "#;
+
+const OLDEST_QUEUED_AT_SQL: &str = r#"
+    SELECT MIN(created_at) FROM jobs
+    WHERE queue = ? AND state = ?
+    AND (schedule_at IS NULL OR schedule_at <= ?)
+    "#;
+
+const QUEUE_STATS_SQL: &str = r#"
+    SELECT queue,
+        SUM(state = ?) AS queued,
+        SUM(state = ?) AS running,
+        SUM(state = ?) AS done,
+        SUM(state = ?) AS failed,
+        MIN(CASE WHEN state = ? AND (schedule_at IS NULL OR schedule_at <= ?)
+            THEN created_at END) AS oldest_queued_at,
+        SUM(state IN (?, ?) AND finished_at >= ?) AS finished_since
+    FROM jobs
+    GROUP BY queue
+    ORDER BY queue
+    "#;
+
+const JOB_TYPE_STATS_SQL: &str = r#"
+    SELECT job_type,
+        SUM(state = ?) AS queued,
+        SUM(state = ?) AS running,
+        SUM(state = ?) AS done,
+        SUM(state = ?) AS failed,
+        SUM(state IN (?, ?)) AS expired,
+        AVG(CASE WHEN state IN (?, ?) THEN finished_at - started_at END) AS avg_duration_ms
+    FROM jobs
+    GROUP BY job_type
+    ORDER BY job_type
+    "#;
+
+const RECENT_DURATIONS_SQL: &str = r#"
+    SELECT finished_at - started_at FROM jobs
+    WHERE job_type = ? AND state = ?
+      AND started_at IS NOT NULL AND finished_at IS NOT NULL
+    ORDER BY finished_at DESC
+    LIMIT ?
+    "#;
+
+// Finished counts use idx_jobs_finished_state, depths idx_jobs_state_queue
+const METRICS_SNAPSHOT_SQL: &str = r#"
+    SELECT
+        (SELECT COUNT(*) FROM jobs WHERE finished_at > ? AND finished_at <= ? AND state = ?),
+        (SELECT COUNT(*) FROM jobs WHERE finished_at > ? AND finished_at <= ? AND state = ?),
+        (SELECT COUNT(*) FROM jobs WHERE state = ?),
+        (SELECT COUNT(*) FROM jobs WHERE state = ?)
+    "#;
+
+// Wait of a job: from due (created_at, or schedule_at) to started_at, or to
+// now while QUEUED. Covers jobs started since the window start and due QUEUED jobs.
+const WAIT_SLO_SQL: &str = r#"
+    WITH waits AS (
+        SELECT state = ? AS queued,
+            CASE WHEN state = ? THEN ? ELSE started_at END
+                - MAX(created_at, COALESCE(schedule_at, created_at)) AS wait_ms
+        FROM jobs
+        WHERE queue = ?
+        AND CASE WHEN state = ? THEN COALESCE(schedule_at, created_at) <= ?
+            ELSE started_at >= ? END
+    )
+    SELECT COALESCE(SUM(NOT queued), 0),
+        COALESCE(SUM(NOT queued AND wait_ms > ?), 0),
+        COALESCE(SUM(queued AND wait_ms > ?), 0),
+        MAX(CASE WHEN wait_ms > ? THEN wait_ms END)
+    FROM waits
+    "#;
+
+const WAIT_SLO_OFFENDERS_SQL: &str = r#"
+    WITH waits AS (
+        SELECT id,
+            CASE WHEN state = ? THEN ? ELSE started_at END
+                - MAX(created_at, COALESCE(schedule_at, created_at)) AS wait_ms
+        FROM jobs
+        WHERE queue = ?
+        AND CASE WHEN state = ? THEN COALESCE(schedule_at, created_at) <= ?
+            ELSE started_at >= ? END
+    )
+    SELECT id FROM waits
+    WHERE wait_ms > ?
+    ORDER BY wait_ms DESC
+    LIMIT ?
+    "#;
+
+// TTL before deadline, as in Worker::expired_state. Binds: now, SKIPPED_TTL,
+// SKIPPED_DEADLINE, now, QUEUED, now, now
+const EXPIRE_QUEUED_SQL: &str = r#"
+    UPDATE jobs
+    SET state = CASE WHEN ttl_ms IS NOT NULL AND ? - COALESCE(queued_at, created_at) > ttl_ms
+            THEN ? ELSE ? END,
+        finished_at = ?
+    WHERE state = ? AND job_class != 'SERVICE'
+    AND ((ttl_ms IS NOT NULL AND ? - COALESCE(queued_at, created_at) > ttl_ms)
+        OR (deadline IS NOT NULL AND ? > deadline))
+    RETURNING id, queue, job_type, state
+    "#;
+
+const CANCEL_BY_SUBJECT_SQL: &str = r#"
+    UPDATE jobs
+    SET state = ?, finished_at = ?
+    WHERE state = ?
+    AND subject_key GLOB ?
+    AND (? IS NULL OR queue = ?)
+    RETURNING id
+    "#;
+
+// Full update of the mutable columns. Binds: the columns in order, then id
+const UPDATE_JOB_SQL: &str = r#"
+    UPDATE jobs
+    SET state = ?, started_at = ?, finished_at = ?, log_path = ?,
+        execution_mode = ?, pid = ?, env_vars = ?,
+        attempts = ?, panic_count = ?, next_attempt_at = ?, deadline = ?, trace_id = ?,
+        schedule_at = ?, wait_for_idle = ?, require_charging = ?, wait_for_event = ?,
+        user_tag = ?, parent_job_id = ?, chain_group_id = ?, result_summary = ?, artifacts = ?,
+        queued_at = ?
+    WHERE id = ?
+    "#;
+
+const FIND_BY_STATE_SQL: &str = r#"
+    SELECT * FROM jobs
+    WHERE state = ?
+    ORDER BY created_at ASC
+    "#;
+
 pub struct SqliteJobRepository {
     pool: SqlitePool,
     time_provider: Arc<dyn TimeProvider>,
+    slow_log: SlowQueryLog,
 }
 
 impl SqliteJobRepository {
     pub fn new(pool: SqlitePool, time_provider: Arc<dyn TimeProvider>) -> Self {
         Self {
+            slow_log: SlowQueryLog::from_env(pool.clone()),
             pool,
             time_provider,
         }
     }
+
+    /// Full update of a job, only while it is in `only_in` if given (rows written)
+    async fn write_job(&self, job: &Job, only_in: Option<JobState>) -> Result<u64> {
+        let execution_mode_str = job.execution_mode.as_ref().map(|m| m.to_string());
+        let env_vars_str = job.env_vars.as_ref().map(|v| v.to_string());
+
+        let sql = match only_in {
+            Some(_) => format!("{} AND state = ?", UPDATE_JOB_SQL),
+            None => UPDATE_JOB_SQL.to_string(),
+        };
+        let mut query = sqlx::query(&sql)
+            .bind(job.state.to_string())
+            .bind(job.started_at)
+            .bind(job.finished_at)
+            .bind(&job.log_path)
+            // Phase 2 fields
+            .bind(&execution_mode_str)
+            .bind(job.pid)
+            .bind(&env_vars_str)
+            .bind(job.attempts)
+            .bind(job.panic_count)
+            .bind(job.next_attempt_at)
+            .bind(job.deadline)
+            .bind(&job.trace_id)
+            // Phase 3 fields
+            .bind(job.schedule_at)
+            .bind(job.wait_for_idle)
+            .bind(job.require_charging)
+            .bind(&job.wait_for_event)
+            // Phase 4 fields
+            .bind(&job.user_tag)
+            .bind(&job.parent_job_id)
+            .bind(&job.chain_group_id)
+            .bind(&job.result_summary)
+            .bind(&job.artifacts)
+            .bind(job.queued_at)
+            .bind(&job.id);
+        if let Some(state) = only_in {
+            query = query.bind(state.to_string());
+        }
+        let result = query.execute(&self.pool).await.map_err(map_sqlx_error)?;
+
+        Ok(result.rows_affected())
+    }
 }
 
 #[async_trait]
@@ -90,6 +400,7 @@ impl JobRepository for SqliteJobRepository {
     async fn insert(&self, job: &Job) -> Result<()> {
         let execution_mode_str = job.execution_mode.as_ref().map(|m| m.to_string());
         let env_vars_str = job.env_vars.as_ref().map(|v| v.to_string());
+        let fallback_payloads_str = fallback_payloads_json(job);
 
         sqlx::query(
             r#"
@@ -97,12 +408,13 @@ impl JobRepository for SqliteJobRepository {
                 id, queue, job_type, subject_key, generation,
                 priority, state, created_at, started_at, finished_at,
                 payload, log_path,
-                execution_mode, pid, env_vars,
-                attempts, max_attempts, backoff_factor,
+                execution_mode, job_class, pid, env_vars,
+                attempts, max_attempts, backoff_factor, fallback_payloads,
                 deadline, ttl_ms, trace_id,
                 schedule_at, wait_for_idle, require_charging, wait_for_event,
-                user_tag, parent_job_id, chain_group_id, result_summary, artifacts
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                user_tag, parent_job_id, chain_group_id, result_summary, artifacts,
+                queued_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&job.id)
@@ -119,11 +431,13 @@ impl JobRepository for SqliteJobRepository {
         .bind(&job.log_path)
         // Phase 2 fields
         .bind(&execution_mode_str)
+        .bind(job.job_class.to_string())
         .bind(job.pid)
         .bind(&env_vars_str)
         .bind(job.attempts)
         .bind(job.max_attempts)
         .bind(job.backoff_factor)
+        .bind(&fallback_payloads_str)
         .bind(job.deadline)
         .bind(job.ttl_ms)
         .bind(&job.trace_id)
@@ -138,10 +452,19 @@ impl JobRepository for SqliteJobRepository {
         .bind(&job.chain_group_id)
         .bind(&job.result_summary)
         .bind(&job.artifacts)
+        .bind(job.queued_at)
         .execute(&self.pool)
         .await
         .map_err(map_sqlx_error)?;
 
+        // pop_next only sees the generation recorded in subjects
+        sqlx::query(RAISE_GENERATION_SQL)
+            .bind(&job.subject_key)
+            .bind(job.generation)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
         Ok(())
     }
 
@@ -156,48 +479,11 @@ impl JobRepository for SqliteJobRepository {
     }
 
     async fn update(&self, job: &Job) -> Result<()> {
-        let execution_mode_str = job.execution_mode.as_ref().map(|m| m.to_string());
-        let env_vars_str = job.env_vars.as_ref().map(|v| v.to_string());
-
-        sqlx::query(
-            r#"
-            UPDATE jobs
-            SET state = ?, started_at = ?, finished_at = ?, log_path = ?,
-                execution_mode = ?, pid = ?, env_vars = ?,
-                attempts = ?, deadline = ?, trace_id = ?,
-                schedule_at = ?, wait_for_idle = ?, require_charging = ?, wait_for_event = ?,
-                user_tag = ?, parent_job_id = ?, chain_group_id = ?, result_summary = ?, artifacts = ?
-            WHERE id = ?
-            "#,
-        )
-        .bind(job.state.to_string())
-        .bind(job.started_at)
-        .bind(job.finished_at)
-        .bind(&job.log_path)
-        // Phase 2 fields
-        .bind(&execution_mode_str)
-        .bind(job.pid)
-        .bind(&env_vars_str)
-        .bind(job.attempts)
-        .bind(job.deadline)
-        .bind(&job.trace_id)
-        // Phase 3 fields
-        .bind(job.schedule_at)
-        .bind(job.wait_for_idle)
-        .bind(job.require_charging)
-        .bind(&job.wait_for_event)
-        // Phase 4 fields
-        .bind(&job.user_tag)
-        .bind(&job.parent_job_id)
-        .bind(&job.chain_group_id)
-        .bind(&job.result_summary)
-        .bind(&job.artifacts)
-        .bind(&job.id)
-        .execute(&self.pool)
-        .await
-        .map_err(map_sqlx_error)?;
+        self.write_job(job, None).await.map(|_| ())
+    }
 
-        Ok(())
+    async fn update_if_running(&self, job: &Job) -> Result<bool> {
+        Ok(self.write_job(job, Some(JobState::Running)).await? > 0)
     }
 
     async fn update_state(
@@ -208,20 +494,19 @@ impl JobRepository for SqliteJobRepository {
     ) -> Result<()> {
         // Optimization: Update only state and finished_at (reduces WAL writes)
         // Security: Conditional update to prevent race conditions (e.g., cancel after completion)
-        let result = sqlx::query(
-            r#"
-            UPDATE jobs
-            SET state = ?, finished_at = ?
-            WHERE id = ?
-              AND state NOT IN ('COMPLETED', 'FAILED', 'CANCELLED', 'SUPERSEDED')
-            "#,
-        )
-        .bind(state.to_string())
-        .bind(finished_at)
-        .bind(id)
-        .execute(&self.pool)
-        .await
-        .map_err(map_sqlx_error)?;
+        let terminal = vec!["?"; JobState::TERMINAL.len()].join(", ");
+        let sql = format!(
+            "UPDATE jobs SET state = ?, finished_at = ? WHERE id = ? AND state NOT IN ({})",
+            terminal
+        );
+        let mut query = sqlx::query(&sql)
+            .bind(state.to_string())
+            .bind(finished_at)
+            .bind(id);
+        for terminal_state in JobState::TERMINAL {
+            query = query.bind(terminal_state.to_string());
+        }
+        let result = query.execute(&self.pool).await.map_err(map_sqlx_error)?;
 
         // Check if row was actually updated
         if result.rows_affected() == 0 {
@@ -245,6 +530,88 @@ impl JobRepository for SqliteJobRepository {
         }
     }
 
+    async fn update_result_summary(&self, id: &JobId, result_summary: &str) -> Result<()> {
+        sqlx::query("UPDATE jobs SET result_summary = ? WHERE id = ?")
+            .bind(result_summary)
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn update_log_path(&self, id: &JobId, log_path: &str) -> Result<()> {
+        sqlx::query("UPDATE jobs SET log_path = ? WHERE id = ?")
+            .bind(log_path)
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn add_artifact(&self, id: &JobId, artifact: &Artifact) -> Result<()> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+        sqlx::query(
+            r#"
+            UPDATE jobs
+            SET artifacts = CASE
+                WHEN artifacts IS NULL OR artifacts = '' THEN ?
+                ELSE artifacts || ',' || ?
+            END
+            WHERE id = ?
+            "#,
+        )
+        .bind(&artifact.uri)
+        .bind(&artifact.uri)
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        // Metadata is optional: an artifact without it is registered by URI only
+        if let (Some(size_bytes), Some(checksum), Some(created_at)) =
+            (artifact.size_bytes, &artifact.checksum, artifact.created_at)
+        {
+            sqlx::query(
+                "INSERT OR REPLACE INTO job_artifacts (job_id, uri, size_bytes, checksum, created_at) VALUES (?, ?, ?, ?, ?)",
+            )
+            .bind(id)
+            .bind(&artifact.uri)
+            .bind(size_bytes as i64)
+            .bind(checksum)
+            .bind(created_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+        Ok(())
+    }
+
+    async fn list_artifacts(&self, id: &JobId) -> Result<Vec<Artifact>> {
+        let rows: Vec<(String, i64, String, i64)> = sqlx::query_as(
+            "SELECT uri, size_bytes, checksum, created_at FROM job_artifacts WHERE job_id = ? ORDER BY created_at ASC, rowid ASC",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(uri, size_bytes, checksum, created_at)| Artifact {
+                uri,
+                size_bytes: Some(size_bytes as u64),
+                checksum: Some(checksum),
+                created_at: Some(created_at),
+            })
+            .collect())
+    }
+
     async fn increment_attempts(&self, id: &JobId) -> Result<()> {
         // Optimization: Atomic increment without reading
         sqlx::query(
@@ -266,41 +633,104 @@ impl JobRepository for SqliteJobRepository {
         // Phase 3: Pop-time supersede
         // Strategy: Only pop jobs with latest generation for their subject_key
         // This prevents popping obsolete jobs that were enqueued before a newer version
+        //
+        // Priority inheritance: A QUEUED job chained after an ancestor (parent_job_id)
+        // lends its priority to every ancestor up the chain, so an urgent job is not
+        // blocked behind low-priority bulk work it depends on. The boost is computed
+        // here at pop time and never written back to the stored priority.
 
         let now = self.time_provider.now_millis();
         let state_running = JobState::Running.to_string();
         let state_queued = JobState::Queued.to_string();
 
-        let row = sqlx::query_as::<_, JobRow>(
-            r#"
-            UPDATE jobs
-            SET state = ?, started_at = ?
-            WHERE id = (
-                SELECT j.id FROM jobs j
-                WHERE j.queue = ? AND j.state = ?
-                  -- Pop-time supersede: Only pop if this job has the latest generation
-                  AND j.generation = (
-                      SELECT MAX(generation) 
-                      FROM jobs 
-                      WHERE subject_key = j.subject_key
-                  )
-                ORDER BY j.priority DESC, j.created_at ASC, j.id ASC
-                LIMIT 1
-            )
-            RETURNING *
-            "#,
-        )
-        .bind(&state_running)
-        .bind(now)
-        .bind(queue)
-        .bind(&state_queued)
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(map_sqlx_error)?;
+        let started = Instant::now();
+        let row = sqlx::query_as::<_, JobRow>(POP_NEXT_SQL)
+            .bind(&state_queued)
+            .bind(&state_running)
+            .bind(now)
+            .bind(queue)
+            .bind(&state_queued)
+            .bind(now)
+            .bind(now)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+        self.slow_log
+            .observe("pop_next", POP_NEXT_SQL, started)
+            .await;
+
+        Ok(row.map(|r| r.into_job()))
+    }
+
+    async fn pop_next_multi(&self, queues: &[&str]) -> Result<Option<Job>> {
+        match queues {
+            [] => return Ok(None),
+            [queue] => return self.pop_next(queue).await,
+            _ => {}
+        }
+
+        let now = self.time_provider.now_millis();
+        let state_running = JobState::Running.to_string();
+        let state_queued = JobState::Queued.to_string();
+
+        let sql = pop_next_multi_sql(queues.len());
+        let started = Instant::now();
+        let mut query = sqlx::query_as::<_, JobRow>(&sql)
+            .bind(&state_queued)
+            .bind(&state_running)
+            .bind(now);
+        for queue in queues {
+            query = query.bind(*queue);
+        }
+        query = query.bind(&state_queued).bind(now).bind(now);
+        for queue in queues {
+            query = query.bind(*queue);
+        }
+        let row = query
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+        self.slow_log.observe("pop_next_multi", &sql, started).await;
 
         Ok(row.map(|r| r.into_job()))
     }
 
+    async fn pop_batch(&self, queue: &str, max: usize) -> Result<Vec<Job>> {
+        if max == 0 {
+            return Ok(Vec::new());
+        }
+
+        let now = self.time_provider.now_millis();
+        let state_running = JobState::Running.to_string();
+        let state_queued = JobState::Queued.to_string();
+
+        let sql = pop_batch_sql();
+        let started = Instant::now();
+        let rows = sqlx::query_as::<_, JobRow>(&sql)
+            .bind(&state_queued)
+            .bind(&state_running)
+            .bind(now)
+            .bind(queue)
+            .bind(&state_queued)
+            .bind(now)
+            .bind(now)
+            .bind(max as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+        self.slow_log.observe("pop_batch", &sql, started).await;
+
+        // RETURNING order is unspecified: hand jobs out by priority, then age
+        let mut jobs: Vec<Job> = rows.into_iter().map(|r| r.into_job()).collect();
+        jobs.sort_by(|a, b| {
+            b.priority
+                .cmp(&a.priority)
+                .then(a.created_at.cmp(&b.created_at))
+                .then(a.id.cmp(&b.id))
+        });
+        Ok(jobs)
+    }
+
     async fn get_latest_generation(&self, subject_key: &str) -> Result<i64> {
         let gen: Option<i64> =
             sqlx::query_scalar("SELECT latest_generation FROM subjects WHERE subject_key = ?")
@@ -328,26 +758,36 @@ impl JobRepository for SqliteJobRepository {
         let state_superseded = JobState::Superseded.to_string();
         let state_queued = JobState::Queued.to_string();
 
-        let result = sqlx::query(
-            r#"
-            UPDATE jobs
-            SET state = ?, finished_at = ?
-            WHERE subject_key = ? AND generation < ? AND state = ?
-            "#,
-        )
-        .bind(&state_superseded)
-        .bind(now)
-        .bind(subject_key)
-        .bind(below_generation)
-        .bind(&state_queued)
-        .execute(&self.pool)
-        .await
-        .map_err(map_sqlx_error)?;
+        sqlx::query(RECORD_SUPERSEDE_EVENTS_SQL)
+            .bind(&state_superseded)
+            .bind(actor::SUPERSEDE)
+            .bind(below_generation)
+            .bind(now)
+            .bind(subject_key)
+            .bind(below_generation)
+            .bind(&state_queued)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
 
-        // Update subjects table
-        sqlx::query("UPDATE subjects SET latest_generation = ? WHERE subject_key = ?")
+        let started = Instant::now();
+        let result = sqlx::query(MARK_SUPERSEDED_SQL)
+            .bind(&state_superseded)
+            .bind(now)
+            .bind(subject_key)
             .bind(below_generation)
+            .bind(&state_queued)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+        self.slow_log
+            .observe("mark_superseded", MARK_SUPERSEDED_SQL, started)
+            .await;
+
+        // Update subjects table
+        sqlx::query(RAISE_GENERATION_SQL)
             .bind(subject_key)
+            .bind(below_generation)
             .execute(&self.pool)
             .await
             .map_err(map_sqlx_error)?;
@@ -356,254 +796,2171 @@ impl JobRepository for SqliteJobRepository {
     }
 
     async fn count_by_state(&self, queue: &str, state: JobState) -> Result<i64> {
-        let count: i64 =
-            sqlx::query_scalar("SELECT COUNT(*) FROM jobs WHERE queue = ? AND state = ?")
-                .bind(queue)
-                .bind(state.to_string())
-                .fetch_one(&self.pool)
-                .await
-                .map_err(map_sqlx_error)?;
+        let started = Instant::now();
+        let count: i64 = sqlx::query_scalar(COUNT_BY_STATE_SQL)
+            .bind(queue)
+            .bind(state.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+        self.slow_log
+            .observe("count_by_state", COUNT_BY_STATE_SQL, started)
+            .await;
 
         Ok(count)
     }
 
+    async fn oldest_queued_at(&self, queue: &str, now: i64) -> Result<Option<i64>> {
+        let started = Instant::now();
+        let oldest: Option<i64> = sqlx::query_scalar(OLDEST_QUEUED_AT_SQL)
+            .bind(queue)
+            .bind(JobState::Queued.to_string())
+            .bind(now)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+        self.slow_log
+            .observe("oldest_queued_at", OLDEST_QUEUED_AT_SQL, started)
+            .await;
+
+        Ok(oldest)
+    }
+
     async fn find_by_state(&self, state: JobState) -> Result<Vec<Job>> {
-        let rows: Vec<JobRow> = sqlx::query_as(
-            r#"
-            SELECT * FROM jobs
-            WHERE state = ?
-            ORDER BY created_at ASC
-            "#,
-        )
-        .bind(state.to_string())
-        .fetch_all(&self.pool)
-        .await
-        .map_err(map_sqlx_error)?;
+        let started = Instant::now();
+        let rows: Vec<JobRow> = sqlx::query_as(FIND_BY_STATE_SQL)
+            .bind(state.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+        self.slow_log
+            .observe("find_by_state", FIND_BY_STATE_SQL, started)
+            .await;
 
         Ok(rows.into_iter().map(|row| row.into_job()).collect())
     }
-}
 
-#[async_trait]
-impl TransactionalJobRepository for SqliteJobRepository {
-    async fn begin_transaction(&self) -> Result<Box<dyn JobRepositoryTransaction>> {
-        let tx = self.pool.begin().await.map_err(map_sqlx_error)?;
-        Ok(Box::new(SqliteJobTransaction::new(
-            tx,
-            Arc::clone(&self.time_provider),
-        )))
+    async fn list_active_services(&self) -> Result<Vec<Job>> {
+        let started = Instant::now();
+        let rows: Vec<JobRow> = sqlx::query_as(LIST_ACTIVE_SERVICES_SQL)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+        self.slow_log
+            .observe("list_active_services", LIST_ACTIVE_SERVICES_SQL, started)
+            .await;
+
+        Ok(rows.into_iter().map(|row| row.into_job()).collect())
     }
-}
 
-/// SQLite row representation (Phase 1 + Phase 2 + Phase 3)
-#[derive(Debug, sqlx::FromRow)]
-struct JobRow {
-    // Phase 1
-    id: String,
-    queue: String,
-    job_type: String,
-    subject_key: String,
-    generation: i64, // Matches Generation type
-    state: String,
-    priority: i32,
-    payload: String,
-    log_path: Option<String>,
-    created_at: i64,
-    started_at: Option<i64>,
-    finished_at: Option<i64>,
+    async fn queue_stats(&self, now: i64, finished_since: i64) -> Result<Vec<QueueStats>> {
+        let started = Instant::now();
+        let rows: Vec<(String, i64, i64, i64, i64, Option<i64>, i64)> =
+            sqlx::query_as(QUEUE_STATS_SQL)
+                .bind(JobState::Queued.to_string())
+                .bind(JobState::Running.to_string())
+                .bind(JobState::Done.to_string())
+                .bind(JobState::Failed.to_string())
+                .bind(JobState::Queued.to_string())
+                .bind(now)
+                .bind(JobState::Done.to_string())
+                .bind(JobState::Failed.to_string())
+                .bind(finished_since)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(map_sqlx_error)?;
+        self.slow_log
+            .observe("queue_stats", QUEUE_STATS_SQL, started)
+            .await;
 
-    // Phase 2
-    execution_mode: Option<String>,
+        Ok(rows
+            .into_iter()
+            .map(
+                |(queue, queued, running, done, failed, oldest_queued_at, finished_since)| {
+                    QueueStats {
+                        queue,
+                        queued,
+                        running,
+                        done,
+                        failed,
+                        oldest_queued_at,
+                        finished_since,
+                    }
+                },
+            )
+            .collect())
+    }
+
+    async fn job_type_stats(&self) -> Result<Vec<JobTypeStats>> {
+        let started = Instant::now();
+        let rows: Vec<(String, i64, i64, i64, i64, i64, Option<f64>)> =
+            sqlx::query_as(JOB_TYPE_STATS_SQL)
+                .bind(JobState::Queued.to_string())
+                .bind(JobState::Running.to_string())
+                .bind(JobState::Done.to_string())
+                .bind(JobState::Failed.to_string())
+                .bind(JobState::SkippedTtl.to_string())
+                .bind(JobState::SkippedDeadline.to_string())
+                .bind(JobState::Done.to_string())
+                .bind(JobState::Failed.to_string())
+                .fetch_all(&self.pool)
+                .await
+                .map_err(map_sqlx_error)?;
+        self.slow_log
+            .observe("job_type_stats", JOB_TYPE_STATS_SQL, started)
+            .await;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(job_type, queued, running, done, failed, expired, avg_duration_ms)| {
+                    JobTypeStats {
+                        job_type,
+                        queued,
+                        running,
+                        done,
+                        failed,
+                        expired,
+                        avg_duration_ms,
+                    }
+                },
+            )
+            .collect())
+    }
+
+    async fn recent_durations(&self, job_type: &str, limit: usize) -> Result<Vec<i64>> {
+        let started = Instant::now();
+        let durations: Vec<i64> = sqlx::query_scalar(RECENT_DURATIONS_SQL)
+            .bind(job_type)
+            .bind(JobState::Done.to_string())
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+        self.slow_log
+            .observe("recent_durations", RECENT_DURATIONS_SQL, started)
+            .await;
+
+        Ok(durations)
+    }
+
+    async fn take_metrics_snapshot(&self, since: i64, now: i64) -> Result<MetricsSnapshot> {
+        let started = Instant::now();
+        let (completed, failed, queued, running): (i64, i64, i64, i64) =
+            sqlx::query_as(METRICS_SNAPSHOT_SQL)
+                .bind(since)
+                .bind(now)
+                .bind(JobState::Done.to_string())
+                .bind(since)
+                .bind(now)
+                .bind(JobState::Failed.to_string())
+                .bind(JobState::Queued.to_string())
+                .bind(JobState::Running.to_string())
+                .fetch_one(&self.pool)
+                .await
+                .map_err(map_sqlx_error)?;
+        self.slow_log
+            .observe("take_metrics_snapshot", METRICS_SNAPSHOT_SQL, started)
+            .await;
+
+        Ok(MetricsSnapshot {
+            taken_at: now,
+            window_ms: now - since,
+            completed,
+            failed,
+            queued,
+            running,
+        })
+    }
+
+    async fn record_metrics_snapshot(&self, snapshot: &MetricsSnapshot, keep: usize) -> Result<()> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+        sqlx::query(
+            "INSERT INTO metrics_snapshots (taken_at, window_ms, completed, failed, queued, running) VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(snapshot.taken_at)
+        .bind(snapshot.window_ms)
+        .bind(snapshot.completed)
+        .bind(snapshot.failed)
+        .bind(snapshot.queued)
+        .bind(snapshot.running)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        sqlx::query(
+            r#"
+            DELETE FROM metrics_snapshots
+            WHERE rowid NOT IN (
+                SELECT rowid FROM metrics_snapshots
+                ORDER BY taken_at DESC, rowid DESC
+                LIMIT ?
+            )
+            "#,
+        )
+        .bind(keep as i64)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+        Ok(())
+    }
+
+    async fn list_metrics_snapshots(
+        &self,
+        since: i64,
+        limit: usize,
+    ) -> Result<Vec<MetricsSnapshot>> {
+        let rows: Vec<(i64, i64, i64, i64, i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT taken_at, window_ms, completed, failed, queued, running
+            FROM metrics_snapshots
+            WHERE taken_at > ?
+            ORDER BY taken_at DESC, rowid DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(since)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(rows
+            .into_iter()
+            .rev()
+            .map(
+                |(taken_at, window_ms, completed, failed, queued, running)| MetricsSnapshot {
+                    taken_at,
+                    window_ms,
+                    completed,
+                    failed,
+                    queued,
+                    running,
+                },
+            )
+            .collect())
+    }
+
+    async fn wait_slo_report(
+        &self,
+        queue: &str,
+        target_ms: i64,
+        now: i64,
+        since: i64,
+        max_job_ids: usize,
+    ) -> Result<WaitSloReport> {
+        let started = Instant::now();
+        let queued = JobState::Queued.to_string();
+        let (started_count, violations, waiting_over, max_wait_ms): (i64, i64, i64, Option<i64>) =
+            sqlx::query_as(WAIT_SLO_SQL)
+                .bind(&queued)
+                .bind(&queued)
+                .bind(now)
+                .bind(queue)
+                .bind(&queued)
+                .bind(now)
+                .bind(since)
+                .bind(target_ms)
+                .bind(target_ms)
+                .bind(target_ms)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(map_sqlx_error)?;
+
+        let job_ids: Vec<JobId> = if violations + waiting_over > 0 {
+            sqlx::query_scalar(WAIT_SLO_OFFENDERS_SQL)
+                .bind(&queued)
+                .bind(now)
+                .bind(queue)
+                .bind(&queued)
+                .bind(now)
+                .bind(since)
+                .bind(target_ms)
+                .bind(max_job_ids as i64)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(map_sqlx_error)?
+        } else {
+            Vec::new()
+        };
+        self.slow_log
+            .observe("wait_slo_report", WAIT_SLO_SQL, started)
+            .await;
+
+        Ok(WaitSloReport {
+            queue: queue.to_string(),
+            target_ms,
+            started: started_count,
+            violations,
+            waiting_over,
+            max_wait_ms,
+            job_ids,
+        })
+    }
+
+    async fn list_subjects(&self) -> Result<Vec<SubjectGeneration>> {
+        let rows: Vec<(String, i64)> = sqlx::query_as(
+            "SELECT subject_key, latest_generation FROM subjects ORDER BY subject_key",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(subject_key, latest_generation)| SubjectGeneration {
+                subject_key,
+                latest_generation,
+            })
+            .collect())
+    }
+
+    async fn record_subject_result(&self, result: &SubjectResult, keep: usize) -> Result<()> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+        sqlx::query(
+            "INSERT INTO subject_history (subject_key, generation, job_id, result_hash, duration_ms, finished_at) VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&result.subject_key)
+        .bind(result.generation)
+        .bind(&result.job_id)
+        .bind(&result.result_hash)
+        .bind(result.duration_ms)
+        .bind(result.finished_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        // Prune in the same transaction so the history never exceeds `keep`
+        sqlx::query(
+            r#"
+            DELETE FROM subject_history
+            WHERE subject_key = ?
+              AND rowid NOT IN (
+                SELECT rowid FROM subject_history
+                WHERE subject_key = ?
+                ORDER BY finished_at DESC, rowid DESC
+                LIMIT ?
+              )
+            "#,
+        )
+        .bind(&result.subject_key)
+        .bind(&result.subject_key)
+        .bind(keep as i64)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+        Ok(())
+    }
+
+    async fn list_subject_history(
+        &self,
+        subject_key: &str,
+        limit: usize,
+    ) -> Result<Vec<SubjectResult>> {
+        let rows: Vec<(i64, String, Option<String>, i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT generation, job_id, result_hash, duration_ms, finished_at
+            FROM subject_history
+            WHERE subject_key = ?
+            ORDER BY finished_at DESC, rowid DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(subject_key)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(generation, job_id, result_hash, duration_ms, finished_at)| SubjectResult {
+                    subject_key: subject_key.to_string(),
+                    generation,
+                    job_id,
+                    result_hash,
+                    duration_ms,
+                    finished_at,
+                },
+            )
+            .collect())
+    }
+
+    async fn record_dead_letter(&self, letter: &DeadLetter) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO dead_letters
+                (job_id, queue, job_type, subject_key, attempts, last_error, dead_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&letter.job_id)
+        .bind(&letter.queue)
+        .bind(&letter.job_type)
+        .bind(&letter.subject_key)
+        .bind(letter.attempts)
+        .bind(&letter.last_error)
+        .bind(letter.dead_at)
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(())
+    }
+
+    async fn list_dead_letters(
+        &self,
+        queue: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<DeadLetter>> {
+        let rows: Vec<(String, String, String, String, i32, String, i64)> = sqlx::query_as(
+            r#"
+            SELECT d.job_id, d.queue, d.job_type, d.subject_key, d.attempts, d.last_error, d.dead_at
+            FROM dead_letters d
+            JOIN jobs j ON j.id = d.job_id
+            WHERE (? IS NULL OR d.queue = ?)
+            ORDER BY d.dead_at DESC, d.job_id
+            LIMIT ?
+            "#,
+        )
+        .bind(queue)
+        .bind(queue)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(job_id, queue, job_type, subject_key, attempts, last_error, dead_at)| {
+                    DeadLetter {
+                        job_id,
+                        queue,
+                        job_type,
+                        subject_key,
+                        attempts,
+                        last_error,
+                        dead_at,
+                    }
+                },
+            )
+            .collect())
+    }
+
+    async fn remove_dead_letter(&self, job_id: &str) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM dead_letters WHERE job_id = ?")
+            .bind(job_id)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn purge_dead_letters(&self, queue: Option<&str>, before: Option<i64>) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM dead_letters
+            WHERE (? IS NULL OR queue = ?)
+              AND (? IS NULL OR dead_at < ?)
+            "#,
+        )
+        .bind(queue)
+        .bind(queue)
+        .bind(before)
+        .bind(before)
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(result.rows_affected())
+    }
+
+    async fn list_jobs(&self, filter: &JobFilter, page: &PageRequest) -> Result<Page<Job>> {
+        let after = pagination::decode_after(page)?;
+
+        let mut conditions = Vec::new();
+        if filter.queue.is_some() {
+            conditions.push("queue = ?");
+        }
+        if filter.state.is_some() {
+            conditions.push("state = ?");
+        }
+        if filter.subject_key.is_some() {
+            conditions.push("subject_key = ?");
+        }
+        if after.is_some() {
+            conditions.push(KEYSET_AFTER);
+        }
+        let where_clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+        let sql = format!(
+            "SELECT * FROM jobs {} {} LIMIT ?",
+            where_clause, KEYSET_ORDER_BY
+        );
+
+        let mut query = sqlx::query_as::<_, JobRow>(&sql);
+        if let Some(queue) = &filter.queue {
+            query = query.bind(queue);
+        }
+        if let Some(state) = &filter.state {
+            query = query.bind(state.to_string());
+        }
+        if let Some(subject_key) = &filter.subject_key {
+            query = query.bind(subject_key);
+        }
+        if let Some(cursor) = &after {
+            let (created_at, created_at_eq, id) = cursor.bind_values();
+            query = query.bind(created_at).bind(created_at_eq).bind(id);
+        }
+
+        let started = Instant::now();
+        let rows = query
+            .bind(pagination::fetch_limit(page))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+        self.slow_log.observe("list_jobs", &sql, started).await;
+
+        let jobs = rows.into_iter().map(|row| row.into_job()).collect();
+        Ok(pagination::into_page(jobs, page, |job: &Job| {
+            KeysetCursor::new(job.created_at, job.id.clone())
+        }))
+    }
+
+    async fn set_job_type_disabled(&self, job_type: &str, disabled: bool) -> Result<()> {
+        if disabled {
+            let now = self.time_provider.now_millis();
+            sqlx::query(
+                "INSERT INTO disabled_job_types (job_type, disabled_at) VALUES (?, ?)
+                 ON CONFLICT(job_type) DO NOTHING",
+            )
+            .bind(job_type)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+        } else {
+            sqlx::query("DELETE FROM disabled_job_types WHERE job_type = ?")
+                .bind(job_type)
+                .execute(&self.pool)
+                .await
+                .map_err(map_sqlx_error)?;
+        }
+
+        Ok(())
+    }
+
+    async fn list_disabled_job_types(&self) -> Result<Vec<String>> {
+        let job_types: Vec<String> =
+            sqlx::query_scalar("SELECT job_type FROM disabled_job_types ORDER BY job_type ASC")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(map_sqlx_error)?;
+
+        Ok(job_types)
+    }
+
+    async fn record_event(&self, event: &JobEvent) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO job_events (job_id, state, actor, reason, created_at) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&event.job_id)
+        .bind(event.state.to_string())
+        .bind(&event.actor)
+        .bind(&event.reason)
+        .bind(event.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn expire_queued(&self, now: i64, actor: &str) -> Result<Vec<ExpiredJob>> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+        let started = Instant::now();
+        let rows: Vec<(String, String, String, String)> = sqlx::query_as(EXPIRE_QUEUED_SQL)
+            .bind(now)
+            .bind(JobState::SkippedTtl.to_string())
+            .bind(JobState::SkippedDeadline.to_string())
+            .bind(now)
+            .bind(JobState::Queued.to_string())
+            .bind(now)
+            .bind(now)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+        self.slow_log
+            .observe("expire_queued", EXPIRE_QUEUED_SQL, started)
+            .await;
+
+        let mut expired = Vec::with_capacity(rows.len());
+        for (job_id, queue, job_type, state) in rows {
+            let state = parse_state(&state);
+            let reason = if state == JobState::SkippedTtl {
+                "TTL passed while queued"
+            } else {
+                "deadline passed while queued"
+            };
+            sqlx::query(
+                "INSERT INTO job_events (job_id, state, actor, reason, created_at) VALUES (?, ?, ?, ?, ?)",
+            )
+            .bind(&job_id)
+            .bind(state.to_string())
+            .bind(actor)
+            .bind(reason)
+            .bind(now)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+            expired.push(ExpiredJob {
+                job_id,
+                queue,
+                job_type,
+                state,
+            });
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+        Ok(expired)
+    }
+
+    async fn cancel_by_subject(
+        &self,
+        queue: Option<&str>,
+        subject_pattern: &str,
+        actor: &str,
+        reason: Option<&str>,
+    ) -> Result<Vec<JobId>> {
+        let now = self.time_provider.now_millis();
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+        let started = Instant::now();
+        let job_ids: Vec<JobId> = sqlx::query_scalar(CANCEL_BY_SUBJECT_SQL)
+            .bind(JobState::Cancelled.to_string())
+            .bind(now)
+            .bind(JobState::Queued.to_string())
+            .bind(subject_pattern)
+            .bind(queue)
+            .bind(queue)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+        self.slow_log
+            .observe("cancel_by_subject", CANCEL_BY_SUBJECT_SQL, started)
+            .await;
+
+        for job_id in &job_ids {
+            sqlx::query(
+                "INSERT INTO job_events (job_id, state, actor, reason, created_at) VALUES (?, ?, ?, ?, ?)",
+            )
+            .bind(job_id)
+            .bind(JobState::Cancelled.to_string())
+            .bind(actor)
+            .bind(reason)
+            .bind(now)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+        Ok(job_ids)
+    }
+
+    async fn update_progress(&self, id: &JobId, progress: &JobProgress) -> Result<bool> {
+        let result = sqlx::query(
+            "UPDATE jobs SET progress_percent = ?, progress_message = ?, progress_updated_at = ?
+             WHERE id = ? AND state = ?",
+        )
+        .bind(progress.percent as i64)
+        .bind(&progress.message)
+        .bind(progress.updated_at)
+        .bind(id)
+        .bind(JobState::Running.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn delete_job(&self, id: &JobId, states: &[JobState]) -> Result<bool> {
+        let mut tx = self
+            .pool
+            .begin_with("BEGIN IMMEDIATE")
+            .await
+            .map_err(map_sqlx_error)?;
+
+        let state: Option<String> = sqlx::query_scalar("SELECT state FROM jobs WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+        let deletable = state.is_some_and(|state| states.iter().any(|s| s.to_string() == state));
+        if !deletable {
+            tx.rollback().await.map_err(map_sqlx_error)?;
+            return Ok(false);
+        }
+
+        for sql in [
+            "DELETE FROM job_events WHERE job_id = ?",
+            "DELETE FROM job_leases WHERE job_id = ?",
+            "DELETE FROM forwarded_jobs WHERE job_id = ?",
+            "DELETE FROM idempotency_keys WHERE job_id = ?",
+            "DELETE FROM job_artifacts WHERE job_id = ?",
+            "DELETE FROM dead_letters WHERE job_id = ?",
+            "DELETE FROM jobs WHERE id = ?",
+        ] {
+            sqlx::query(sql)
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(map_sqlx_error)?;
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+        Ok(true)
+    }
+
+    async fn list_events(&self, id: &JobId) -> Result<Vec<JobEvent>> {
+        load_events(&self.pool, id).await
+    }
+
+    async fn insert_lease(&self, lease: &JobLease) -> Result<()> {
+        sqlx::query(&format!(
+            "INSERT OR REPLACE INTO job_leases ({}) VALUES (?, ?, ?, ?, ?, ?)",
+            LEASE_COLUMNS
+        ))
+        .bind(&lease.job_id)
+        .bind(&lease.worker_id)
+        .bind(lease.leased_at)
+        .bind(lease.expires_at)
+        .bind(lease.heartbeat_at)
+        .bind(&lease.progress)
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn find_lease(&self, id: &JobId) -> Result<Option<JobLease>> {
+        let row: Option<LeaseRow> = sqlx::query_as(&format!(
+            "SELECT {} FROM job_leases WHERE job_id = ?",
+            LEASE_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(row.map(lease_from_row))
+    }
+
+    async fn renew_lease(
+        &self,
+        id: &JobId,
+        worker_id: &str,
+        expires_at: i64,
+        progress: Option<&str>,
+    ) -> Result<Option<JobLease>> {
+        let now = self.time_provider.now_millis();
+        // Only RUNNING jobs can be renewed: a cancelled job tells the worker to stop
+        let row: Option<LeaseRow> = sqlx::query_as(&format!(
+            "UPDATE job_leases
+             SET expires_at = ?, heartbeat_at = ?, progress = COALESCE(?, progress)
+             WHERE job_id = ? AND worker_id = ?
+               AND job_id IN (SELECT id FROM jobs WHERE state = ?)
+             RETURNING {}",
+            LEASE_COLUMNS
+        ))
+        .bind(expires_at)
+        .bind(now)
+        .bind(progress)
+        .bind(id)
+        .bind(worker_id)
+        .bind(JobState::Running.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(row.map(lease_from_row))
+    }
+
+    async fn delete_lease(&self, id: &JobId) -> Result<()> {
+        sqlx::query("DELETE FROM job_leases WHERE job_id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn find_expired_leases(&self, now: i64) -> Result<Vec<JobLease>> {
+        let rows: Vec<LeaseRow> = sqlx::query_as(&format!(
+            "SELECT {} FROM job_leases WHERE expires_at <= ? ORDER BY expires_at ASC",
+            LEASE_COLUMNS
+        ))
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(rows.into_iter().map(lease_from_row).collect())
+    }
+
+    async fn record_forward(&self, forward: &ForwardedJob) -> Result<()> {
+        sqlx::query(&format!(
+            "INSERT OR REPLACE INTO forwarded_jobs ({}) VALUES (?, ?, ?, ?, ?, ?)",
+            FORWARD_COLUMNS
+        ))
+        .bind(&forward.job_id)
+        .bind(&forward.endpoint)
+        .bind(&forward.remote_job_id)
+        .bind(forward.forwarded_at)
+        .bind(forward.remote_state.as_ref().map(|s| s.to_string()))
+        .bind(forward.closed_at)
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn find_forward(&self, id: &JobId) -> Result<Option<ForwardedJob>> {
+        let row: Option<ForwardRow> = sqlx::query_as(&format!(
+            "SELECT {} FROM forwarded_jobs WHERE job_id = ?",
+            FORWARD_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(row.map(forward_from_row))
+    }
+
+    async fn list_open_forwards(&self, limit: usize) -> Result<Vec<ForwardedJob>> {
+        let rows: Vec<ForwardRow> = sqlx::query_as(&format!(
+            "SELECT {} FROM forwarded_jobs WHERE closed_at IS NULL ORDER BY forwarded_at ASC LIMIT ?",
+            FORWARD_COLUMNS
+        ))
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(rows.into_iter().map(forward_from_row).collect())
+    }
+
+    async fn close_forward(
+        &self,
+        id: &JobId,
+        remote_state: Option<JobState>,
+        closed_at: i64,
+    ) -> Result<()> {
+        sqlx::query("UPDATE forwarded_jobs SET remote_state = ?, closed_at = ? WHERE job_id = ?")
+            .bind(remote_state.map(|s| s.to_string()))
+            .bind(closed_at)
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn list_active_queues(&self) -> Result<Vec<String>> {
+        sqlx::query_scalar("SELECT DISTINCT queue FROM jobs WHERE state = ? ORDER BY queue")
+            .bind(JobState::Queued.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+    }
+
+    async fn warm_up(&self, queues: &[String]) -> Result<()> {
+        let state_running = JobState::Running.to_string();
+        let state_queued = JobState::Queued.to_string();
+
+        // Hold every connection so each one gets opened and caches the statement.
+        // The empty queue name (rejected at enqueue) matches no job.
+        let connections = self.pool.options().get_max_connections() as usize;
+        let mut held = Vec::with_capacity(connections);
+        for _ in 0..connections {
+            let mut conn = self.pool.acquire().await.map_err(map_sqlx_error)?;
+            sqlx::query(POP_NEXT_SQL)
+                .bind(&state_queued)
+                .bind(&state_running)
+                .bind(0_i64)
+                .bind("")
+                .bind(&state_queued)
+                .bind(0_i64)
+                .bind(0_i64)
+                .execute(&mut *conn)
+                .await
+                .map_err(map_sqlx_error)?;
+            held.push(conn);
+        }
+        drop(held);
+
+        for queue in queues {
+            sqlx::query_scalar::<_, i64>(COUNT_BY_STATE_SQL)
+                .bind(queue)
+                .bind(&state_queued)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(map_sqlx_error)?;
+        }
+
+        Ok(())
+    }
+
+    async fn ping(&self) -> Result<()> {
+        sqlx::query_scalar::<_, i64>("SELECT 1")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TransactionalJobRepository for SqliteJobRepository {
+    async fn begin_transaction(&self) -> Result<Box<dyn JobRepositoryTransaction>> {
+        // IMMEDIATE: take the write lock up front. A deferred transaction that
+        // reads first fails with SQLITE_BUSY (no busy wait) when another writer,
+        // e.g. the audit log, commits before its first write.
+        let tx = self
+            .pool
+            .begin_with("BEGIN IMMEDIATE")
+            .await
+            .map_err(map_sqlx_error)?;
+        Ok(Box::new(SqliteJobTransaction::new(
+            tx,
+            Arc::clone(&self.time_provider),
+        )))
+    }
+}
+
+/// State transitions of a job, oldest first
+pub(crate) async fn load_events(pool: &SqlitePool, id: &str) -> Result<Vec<JobEvent>> {
+    let rows: Vec<(String, String, String, Option<String>, i64)> = sqlx::query_as(
+        "SELECT job_id, state, actor, reason, created_at FROM job_events WHERE job_id = ? ORDER BY id ASC",
+    )
+    .bind(id)
+    .fetch_all(pool)
+    .await
+    .map_err(map_sqlx_error)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(job_id, state, actor, reason, created_at)| JobEvent {
+            job_id,
+            state: parse_state(&state),
+            actor,
+            reason,
+            created_at,
+        })
+        .collect())
+}
+
+/// SQLite row representation (Phase 1 + Phase 2 + Phase 3)
+#[derive(Debug, sqlx::FromRow)]
+pub(crate) struct JobRow {
+    // Phase 1
+    id: String,
+    queue: String,
+    job_type: String,
+    subject_key: String,
+    generation: i64, // Matches Generation type
+    state: String,
+    priority: i32,
+    payload: String,
+    log_path: Option<String>,
+    created_at: i64,
+    started_at: Option<i64>,
+    finished_at: Option<i64>,
+
+    // Phase 2
+    execution_mode: Option<String>,
+    job_class: String,
     pid: Option<i32>,
     env_vars: Option<String>,
     attempts: i32,
     max_attempts: i32,
     backoff_factor: f64,
+    fallback_payloads: Option<String>,
     deadline: Option<i64>,
     ttl_ms: Option<i64>,
     trace_id: Option<String>,
 
-    // Phase 3
-    schedule_at: Option<i64>,
-    wait_for_idle: i32,    // SQLite boolean as integer
-    require_charging: i32, // SQLite boolean as integer
-    wait_for_event: Option<String>,
+    // Phase 3
+    schedule_at: Option<i64>,
+    wait_for_idle: i32,    // SQLite boolean as integer
+    require_charging: i32, // SQLite boolean as integer
+    wait_for_event: Option<String>,
+
+    // Phase 4
+    user_tag: Option<String>,
+    parent_job_id: Option<String>,
+    chain_group_id: Option<String>,
+    result_summary: Option<String>,
+    artifacts: Option<String>,
+
+    // Progress (migration 016)
+    progress_percent: Option<i64>,
+    progress_message: Option<String>,
+    progress_updated_at: Option<i64>,
+
+    // Poison-job detection (migration 022)
+    panic_count: i32,
+
+    // Retry backoff (migration 023)
+    next_attempt_at: Option<i64>,
+
+    // TTL anchor (migration 026)
+    queued_at: Option<i64>,
+}
+
+/// Stored form of a job's fallback payloads (JSON array, None when empty)
+pub(crate) fn fallback_payloads_json(job: &Job) -> Option<String> {
+    if job.fallback_payloads.is_empty() {
+        return None;
+    }
+    serde_json::to_string(&job.fallback_payloads).ok()
+}
+
+/// Parse a stored state string
+fn parse_state(state: &str) -> JobState {
+    match state {
+        "QUEUED" => JobState::Queued,
+        "RUNNING" => JobState::Running,
+        "DONE" => JobState::Done,
+        "FAILED" => JobState::Failed,
+        "SUPERSEDED" => JobState::Superseded,
+        "CANCELLED" => JobState::Cancelled,
+        "REQUEUED" => JobState::Requeued,
+        "SKIPPED_TTL" => JobState::SkippedTtl,
+        "SKIPPED_DEADLINE" => JobState::SkippedDeadline,
+        _ => JobState::Failed, // Default fallback
+    }
+}
+
+impl JobRow {
+    pub(crate) fn into_job(self) -> Job {
+        use semantica_core::domain::{ExecutionMode, JobClass, JobPayload, JobProgress, JobType};
+
+        let state = parse_state(&self.state);
+
+        let execution_mode = self.execution_mode.as_deref().and_then(|mode| match mode {
+            "IN_PROCESS" => Some(ExecutionMode::InProcess),
+            "SUBPROCESS" => Some(ExecutionMode::Subprocess),
+            _ => None,
+        });
+
+        let job_class = JobClass::parse(&self.job_class).unwrap_or_default();
+
+        let payload: serde_json::Value =
+            serde_json::from_str(&self.payload).unwrap_or(serde_json::json!({}));
+
+        let env_vars = self.env_vars.and_then(|s| serde_json::from_str(&s).ok());
+
+        let fallback_payloads = self
+            .fallback_payloads
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        Job {
+            // Phase 1 fields
+            id: self.id,
+            queue: self.queue,
+            job_type: JobType::new(self.job_type),
+            subject_key: self.subject_key,
+            generation: self.generation,
+            priority: self.priority,
+            state,
+            created_at: self.created_at,
+            queued_at: self.queued_at,
+            started_at: self.started_at,
+            finished_at: self.finished_at,
+            payload: JobPayload::new(payload),
+            log_path: self.log_path,
+
+            // Phase 2 fields
+            execution_mode,
+            job_class,
+            pid: self.pid,
+            env_vars,
+            attempts: self.attempts,
+            max_attempts: self.max_attempts,
+            panic_count: self.panic_count,
+            next_attempt_at: self.next_attempt_at,
+            backoff_factor: self.backoff_factor,
+            fallback_payloads,
+            deadline: self.deadline,
+            ttl_ms: self.ttl_ms,
+            trace_id: self.trace_id,
+
+            // Phase 3 fields
+            schedule_at: self.schedule_at,
+            wait_for_idle: self.wait_for_idle != 0,
+            require_charging: self.require_charging != 0,
+            wait_for_event: self.wait_for_event,
+
+            // Phase 4 fields
+            user_tag: self.user_tag,
+            parent_job_id: self.parent_job_id,
+            chain_group_id: self.chain_group_id,
+            result_summary: self.result_summary,
+            artifacts: self.artifacts,
+
+            progress: self.progress_percent.zip(self.progress_updated_at).map(
+                |(percent, updated_at)| JobProgress {
+                    percent: percent.clamp(0, 100) as u8,
+                    message: self.progress_message,
+                    updated_at,
+                },
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_pool, run_migrations};
+    use semantica_core::domain::{JobPayload, JobType};
+    use semantica_core::port::time_provider::SystemTimeProvider;
+
+    async fn setup_test_db() -> (SqlitePool, Arc<dyn TimeProvider>) {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        let time_provider = Arc::new(SystemTimeProvider);
+        (pool, time_provider)
+    }
+
+    #[tokio::test]
+    async fn test_insert_and_find() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        let job = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "test::subject",
+            1,
+            JobPayload::new(serde_json::json!({"key": "value"})),
+        );
+
+        repo.insert(&job).await.unwrap();
+
+        let found = repo.find_by_id(&job.id).await.unwrap();
+        assert!(found.is_some());
+        assert_eq!(found.unwrap().id, job.id);
+    }
+
+    #[tokio::test]
+    async fn test_pop_next() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        // Insert jobs with different priorities
+        let mut job1 = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "subject1",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job1.priority = 0;
+
+        let mut job2 = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "subject2",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job2.priority = 10;
+
+        repo.insert(&job1).await.unwrap();
+        repo.insert(&job2).await.unwrap();
+
+        // Should pop job2 first (higher priority)
+        let popped = repo.pop_next("test_queue").await.unwrap();
+        assert!(popped.is_some());
+        assert_eq!(popped.unwrap().id, job2.id);
+    }
+
+    #[tokio::test]
+    async fn test_update_state_keeps_terminal_states() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        for terminal in JobState::TERMINAL {
+            let mut job = Job::new_test(
+                "test_queue",
+                JobType::new("TEST"),
+                format!("subject-{}", terminal),
+                1,
+                JobPayload::new(serde_json::json!({})),
+            );
+            job.state = terminal.clone();
+            repo.insert(&job).await.unwrap();
+
+            // A late cancel or completion does not overwrite the final state
+            let err = repo
+                .update_state(&job.id, JobState::Cancelled, Some(1))
+                .await
+                .unwrap_err();
+            assert!(matches!(err, AppError::InvalidState(_)), "{}", terminal);
+            let stored = repo.find_by_id(&job.id).await.unwrap().unwrap();
+            assert_eq!(stored.state, terminal);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_if_running_skips_cancelled_job() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        let job = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "subject",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        repo.insert(&job).await.unwrap();
+        let mut popped = repo.pop_next("test_queue").await.unwrap().unwrap();
+
+        // Requeue for a retry while still RUNNING
+        popped.state = JobState::Queued;
+        popped.attempts = 1;
+        assert!(repo.update_if_running(&popped).await.unwrap());
+        assert_eq!(
+            repo.find_by_id(&job.id).await.unwrap().unwrap().state,
+            JobState::Queued
+        );
+
+        // Cancelled during the next execution: the retry write is dropped
+        let mut popped = repo.pop_next("test_queue").await.unwrap().unwrap();
+        repo.update_state(&job.id, JobState::Cancelled, Some(1))
+            .await
+            .unwrap();
+        popped.state = JobState::Queued;
+        popped.attempts = 2;
+        assert!(!repo.update_if_running(&popped).await.unwrap());
+        let stored = repo.find_by_id(&job.id).await.unwrap().unwrap();
+        assert_eq!(stored.state, JobState::Cancelled);
+        assert_eq!(stored.attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_pop_next_multi_prefers_earlier_queues() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        let job = |queue: &str, subject: &str, priority: i32| {
+            let mut job = Job::new_test(
+                queue,
+                JobType::new("TEST"),
+                subject,
+                1,
+                JobPayload::new(serde_json::json!({})),
+            );
+            job.priority = priority;
+            job
+        };
+        // Priority only orders jobs within a queue
+        let urgent_bulk = job("bulk", "a", 10);
+        let fast_low = job("fast", "b", 0);
+        let fast_high = job("fast", "c", 5);
+        let other = job("other", "d", 99);
+        for job in [&urgent_bulk, &fast_low, &fast_high, &other] {
+            repo.insert(job).await.unwrap();
+        }
+
+        let pop = |queues: &'static [&'static str]| {
+            let repo = &repo;
+            async move { repo.pop_next_multi(queues).await.unwrap().map(|j| j.id) }
+        };
+        assert_eq!(pop(&["fast", "bulk"]).await, Some(fast_high.id.clone()));
+        assert_eq!(pop(&["fast", "bulk"]).await, Some(fast_low.id.clone()));
+        assert_eq!(pop(&["fast", "bulk"]).await, Some(urgent_bulk.id.clone()));
+        assert_eq!(pop(&["fast", "bulk"]).await, None, "other is not listed");
+        assert_eq!(pop(&[]).await, None);
+        assert_eq!(pop(&["other"]).await, Some(other.id.clone()));
+    }
+
+    #[tokio::test]
+    async fn test_pop_batch_claims_up_to_max() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        let mut ids = Vec::new();
+        for i in 0..5 {
+            let mut job = Job::new_test(
+                "test_queue",
+                JobType::new("TEST"),
+                format!("subject-{}", i),
+                1,
+                JobPayload::new(serde_json::json!({})),
+            );
+            job.priority = i;
+            repo.insert(&job).await.unwrap();
+            ids.push(job.id);
+        }
+        // A superseded generation of subject-0 is never claimed
+        let stale = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "subject-0",
+            0,
+            JobPayload::new(serde_json::json!({})),
+        );
+        repo.insert(&stale).await.unwrap();
+
+        let batch = repo.pop_batch("test_queue", 3).await.unwrap();
+        let popped: Vec<_> = batch.iter().map(|j| j.id.clone()).collect();
+        assert_eq!(popped, vec![ids[4].clone(), ids[3].clone(), ids[2].clone()]);
+        assert!(batch.iter().all(|j| j.state == JobState::Running));
+
+        let rest = repo.pop_batch("test_queue", 10).await.unwrap();
+        assert_eq!(rest.len(), 2);
+        assert!(repo.pop_batch("test_queue", 10).await.unwrap().is_empty());
+        assert!(repo.pop_batch("test_queue", 0).await.unwrap().is_empty());
+        let stale = repo.find_by_id(&stale.id).await.unwrap().unwrap();
+        assert_eq!(stale.state, JobState::Queued);
+    }
+
+    #[tokio::test]
+    async fn test_pop_next_plan_reads_generation_from_subjects() {
+        let (pool, _) = setup_test_db().await;
+
+        let plan: Vec<String> = sqlx::query(&format!("EXPLAIN QUERY PLAN {}", POP_NEXT_SQL))
+            .fetch_all(&pool)
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| sqlx::Row::get::<String, _>(row, "detail"))
+            .collect();
+
+        assert!(
+            plan.iter()
+                .any(|line| line.contains("idx_jobs_pop_covering")),
+            "unexpected plan: {:?}",
+            plan
+        );
+        assert!(
+            plan.iter().any(|line| line.contains("subjects")),
+            "unexpected plan: {:?}",
+            plan
+        );
+        // No MAX(generation) search over the subject's history per candidate
+        assert!(
+            !plan
+                .iter()
+                .any(|line| line.contains("idx_jobs_subject_generation")),
+            "unexpected plan: {:?}",
+            plan
+        );
+    }
+
+    #[tokio::test]
+    async fn test_warm_up_leaves_jobs_queued() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        let job = Job::new_test(
+            "warm_queue",
+            JobType::new("TEST"),
+            "warm::subject",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        repo.insert(&job).await.unwrap();
+
+        let queues = repo.list_active_queues().await.unwrap();
+        assert_eq!(queues, vec!["warm_queue".to_string()]);
+
+        repo.warm_up(&queues).await.unwrap();
+
+        let found = repo.find_by_id(&job.id).await.unwrap().unwrap();
+        assert_eq!(found.state, JobState::Queued);
+        assert_eq!(
+            repo.pop_next("warm_queue").await.unwrap().unwrap().id,
+            job.id
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pop_next_priority_inheritance() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        // Low-priority bulk job that an urgent job is chained after
+        let mut bulk = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "bulk",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        bulk.priority = -10;
+
+        // Unrelated medium-priority job
+        let mut medium = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "medium",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        medium.priority = 5;
+
+        // Urgent job waiting on the bulk job (lives in another queue)
+        let mut urgent = Job::new_test(
+            "other_queue",
+            JobType::new("TEST"),
+            "urgent",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        urgent.priority = 50;
+        urgent.parent_job_id = Some(bulk.id.clone());
+
+        repo.insert(&bulk).await.unwrap();
+        repo.insert(&medium).await.unwrap();
+        repo.insert(&urgent).await.unwrap();
+
+        // Bulk job inherits priority 50 and jumps ahead of the medium job
+        let popped = repo.pop_next("test_queue").await.unwrap().unwrap();
+        assert_eq!(popped.id, bulk.id);
+        // Stored priority is untouched
+        assert_eq!(popped.priority, -10);
+
+        let popped = repo.pop_next("test_queue").await.unwrap().unwrap();
+        assert_eq!(popped.id, medium.id);
+    }
+
+    #[tokio::test]
+    async fn test_pop_next_skips_disabled_job_type() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        let job = Job::new_test(
+            "test_queue",
+            JobType::new("FLAKY"),
+            "subject",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        repo.insert(&job).await.unwrap();
+
+        repo.set_job_type_disabled("FLAKY", true).await.unwrap();
+        assert_eq!(repo.list_disabled_job_types().await.unwrap(), vec!["FLAKY"]);
+        assert!(repo.pop_next("test_queue").await.unwrap().is_none());
+
+        // Job stays QUEUED and is popped once re-enabled
+        repo.set_job_type_disabled("FLAKY", false).await.unwrap();
+        let popped = repo.pop_next("test_queue").await.unwrap().unwrap();
+        assert_eq!(popped.id, job.id);
+    }
+
+    #[tokio::test]
+    async fn test_pop_next_waits_for_schedule_at() {
+        let (pool, time_provider) = setup_test_db().await;
+        let now = time_provider.now_millis();
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        let mut later = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "later",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        later.priority = 10;
+        later.schedule_at = Some(now + 60 * 60 * 1000);
+        repo.insert(&later).await.unwrap();
+        let mut due = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "due",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        due.schedule_at = Some(now - 1);
+        repo.insert(&due).await.unwrap();
+
+        // The higher-priority job is not due yet: the due one is popped
+        let popped = repo.pop_next("test_queue").await.unwrap().unwrap();
+        assert_eq!(popped.id, due.id);
+        assert!(repo.pop_next("test_queue").await.unwrap().is_none());
+        assert!(repo.pop_batch("test_queue", 5).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_queue_stats_groups_by_queue() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        let mut jobs = Vec::new();
+        for (queue, subject) in [("build", "a"), ("build", "b"), ("code_intel", "c")] {
+            let mut job = Job::new_test(
+                queue,
+                JobType::new("TEST"),
+                subject,
+                1,
+                JobPayload::new(serde_json::json!({})),
+            );
+            job.created_at = 1_000;
+            repo.insert(&job).await.unwrap();
+            jobs.push(job);
+        }
+        repo.update_state(&jobs[0].id, JobState::Done, Some(5_000))
+            .await
+            .unwrap();
+        // Finished before the throughput window
+        repo.update_state(&jobs[2].id, JobState::Failed, Some(1_500))
+            .await
+            .unwrap();
+
+        let stats = repo.queue_stats(10_000, 2_000).await.unwrap();
+
+        assert_eq!(
+            stats,
+            vec![
+                QueueStats {
+                    queue: "build".to_string(),
+                    queued: 1,
+                    done: 1,
+                    oldest_queued_at: Some(1_000),
+                    finished_since: 1,
+                    ..Default::default()
+                },
+                QueueStats {
+                    queue: "code_intel".to_string(),
+                    failed: 1,
+                    ..Default::default()
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_job_type_stats_groups_by_job_type() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        let finished = [
+            ("BUILD", JobState::Done, 1_000),
+            ("BUILD", JobState::Failed, 3_000),
+            ("BUILD", JobState::SkippedTtl, 0),
+            ("INDEX_FILE", JobState::Done, 200),
+        ];
+        for (i, (job_type, state, duration_ms)) in finished.into_iter().enumerate() {
+            let mut job = Job::new_test(
+                "default",
+                JobType::new(job_type),
+                format!("subject-{}", i),
+                1,
+                JobPayload::new(serde_json::json!({})),
+            );
+            if state != JobState::SkippedTtl {
+                job.started_at = Some(10_000);
+            }
+            job.finished_at = Some(10_000 + duration_ms);
+            job.state = state;
+            repo.insert(&job).await.unwrap();
+        }
+        repo.insert(&Job::new_test(
+            "default",
+            JobType::new("INDEX_FILE"),
+            "queued",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        ))
+        .await
+        .unwrap();
+
+        let stats = repo.job_type_stats().await.unwrap();
+
+        assert_eq!(
+            stats,
+            vec![
+                JobTypeStats {
+                    job_type: "BUILD".to_string(),
+                    done: 1,
+                    failed: 1,
+                    expired: 1,
+                    avg_duration_ms: Some(2_000.0),
+                    ..Default::default()
+                },
+                JobTypeStats {
+                    job_type: "INDEX_FILE".to_string(),
+                    queued: 1,
+                    done: 1,
+                    avg_duration_ms: Some(200.0),
+                    ..Default::default()
+                },
+            ]
+        );
+        assert_eq!(stats[0].failure_rate(), 0.5);
+        assert_eq!(stats[1].failure_rate(), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_wait_slo_report() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        // (queue, state, created_at, schedule_at, started_at)
+        let rows = [
+            ("interactive", JobState::Done, 1_000, None, Some(2_000)), // waited 1s
+            ("interactive", JobState::Done, 1_000, None, Some(5_000)), // waited 4s
+            (
+                "interactive",
+                JobState::Running,
+                1_000,
+                Some(8_000),
+                Some(8_500),
+            ), // due at 8s
+            ("interactive", JobState::Queued, 5_000, None, None),      // waiting 5s
+            ("interactive", JobState::Queued, 5_000, Some(20_000), None), // not due
+            ("interactive", JobState::Done, 0, None, Some(500)),       // before the window
+            ("build", JobState::Done, 1_000, None, Some(9_000)),
+        ];
+        let mut ids = Vec::new();
+        for (i, (queue, state, created_at, schedule_at, started_at)) in rows.into_iter().enumerate()
+        {
+            let mut job = Job::new_test(
+                queue,
+                JobType::new("TEST"),
+                format!("subject-{}", i),
+                1,
+                JobPayload::new(serde_json::json!({})),
+            );
+            job.state = state;
+            job.created_at = created_at;
+            job.schedule_at = schedule_at;
+            job.started_at = started_at;
+            repo.insert(&job).await.unwrap();
+            ids.push(job.id);
+        }
+
+        let report = repo
+            .wait_slo_report("interactive", 2_000, 10_000, 1_000, 10)
+            .await
+            .unwrap();
+        assert_eq!(report.started, 3);
+        assert_eq!(report.violations, 1);
+        assert_eq!(report.waiting_over, 1);
+        assert_eq!(report.max_wait_ms, Some(5_000));
+        assert_eq!(report.job_ids, vec![ids[3].clone(), ids[1].clone()]);
+
+        let capped = repo
+            .wait_slo_report("interactive", 2_000, 10_000, 1_000, 1)
+            .await
+            .unwrap();
+        assert_eq!(capped.job_ids, vec![ids[3].clone()]);
+
+        let met = repo
+            .wait_slo_report("interactive", 60_000, 10_000, 1_000, 10)
+            .await
+            .unwrap();
+        assert_eq!((met.violations, met.waiting_over), (0, 0));
+        assert_eq!(met.max_wait_ms, None);
+        assert!(met.job_ids.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_supersede() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        // Insert 3 jobs with same subject_key, different generations
+        for gen in 1..=3 {
+            let job = Job::new_test(
+                "test_queue",
+                JobType::new("TEST"),
+                "same::subject",
+                gen,
+                JobPayload::new(serde_json::json!({})),
+            );
+            repo.insert(&job).await.unwrap();
+        }
+
+        // Supersede generations < 3
+        let count = repo.mark_superseded("same::subject", 3).await.unwrap();
+        assert_eq!(count, 2); // 2 jobs superseded
 
-    // Phase 4
-    user_tag: Option<String>,
-    parent_job_id: Option<String>,
-    chain_group_id: Option<String>,
-    result_summary: Option<String>,
-    artifacts: Option<String>,
-}
+        // Check that only generation 3 is QUEUED
+        let queued = repo
+            .count_by_state("test_queue", JobState::Queued)
+            .await
+            .unwrap();
+        assert_eq!(queued, 1);
 
-impl JobRow {
-    fn into_job(self) -> Job {
-        use semantica_core::domain::{ExecutionMode, JobPayload, JobType};
-
-        let state = match self.state.as_str() {
-            "QUEUED" => JobState::Queued,
-            "RUNNING" => JobState::Running,
-            "DONE" => JobState::Done,
-            "FAILED" => JobState::Failed,
-            "SUPERSEDED" => JobState::Superseded,
-            "CANCELLED" => JobState::Cancelled,
-            "REQUEUED" => JobState::Requeued,
-            _ => JobState::Failed, // Default fallback
-        };
+        let superseded = repo
+            .count_by_state("test_queue", JobState::Superseded)
+            .await
+            .unwrap();
+        assert_eq!(superseded, 2);
+    }
 
-        let execution_mode = self.execution_mode.as_deref().and_then(|mode| match mode {
-            "IN_PROCESS" => Some(ExecutionMode::InProcess),
-            "SUBPROCESS" => Some(ExecutionMode::Subprocess),
-            _ => None,
-        });
+    #[tokio::test]
+    async fn test_job_events_record_actor_and_reason() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
 
-        let payload: serde_json::Value =
-            serde_json::from_str(&self.payload).unwrap_or(serde_json::json!({}));
+        let mut ids = Vec::new();
+        for gen in 1..=2 {
+            let job = Job::new_test(
+                "test_queue",
+                JobType::new("TEST"),
+                "same::subject",
+                gen,
+                JobPayload::new(serde_json::json!({})),
+            );
+            repo.insert(&job).await.unwrap();
+            ids.push(job.id);
+        }
 
-        let env_vars = self.env_vars.and_then(|s| serde_json::from_str(&s).ok());
+        repo.mark_superseded("same::subject", 2).await.unwrap();
+        let events = repo.list_events(&ids[0]).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].state, JobState::Superseded);
+        assert_eq!(events[0].actor, actor::SUPERSEDE);
+        assert_eq!(
+            events[0].reason.as_deref(),
+            Some("superseded by generation 2")
+        );
 
-        Job {
-            // Phase 1 fields
-            id: self.id,
-            queue: self.queue,
-            job_type: JobType::new(self.job_type),
-            subject_key: self.subject_key,
-            generation: self.generation,
-            priority: self.priority,
-            state,
-            created_at: self.created_at,
-            started_at: self.started_at,
-            finished_at: self.finished_at,
-            payload: JobPayload::new(payload),
-            log_path: self.log_path,
+        let cancel = JobEvent::new(
+            ids[1].clone(),
+            JobState::Cancelled,
+            actor::sdk("editor-1"),
+            Some("file closed".to_string()),
+            42,
+        );
+        repo.record_event(&cancel).await.unwrap();
+        assert_eq!(repo.list_events(&ids[1]).await.unwrap(), vec![cancel]);
+    }
 
-            // Phase 2 fields
-            execution_mode,
-            pid: self.pid,
-            env_vars,
-            attempts: self.attempts,
-            max_attempts: self.max_attempts,
-            backoff_factor: self.backoff_factor,
-            deadline: self.deadline,
-            ttl_ms: self.ttl_ms,
-            trace_id: self.trace_id,
+    #[tokio::test]
+    async fn test_next_generation_and_insert_superseding() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
 
-            // Phase 3 fields
-            schedule_at: self.schedule_at,
-            wait_for_idle: self.wait_for_idle != 0,
-            require_charging: self.require_charging != 0,
-            wait_for_event: self.wait_for_event,
+        let mut ids = Vec::new();
+        for expected_gen in 1..=3 {
+            let mut tx = repo.begin_transaction().await.unwrap();
+            let gen = tx.next_generation("same::subject").await.unwrap();
+            assert_eq!(gen, expected_gen);
 
-            // Phase 4 fields
-            user_tag: self.user_tag,
-            parent_job_id: self.parent_job_id,
-            chain_group_id: self.chain_group_id,
-            result_summary: self.result_summary,
-            artifacts: self.artifacts,
+            let job = Job::new_test(
+                "test_queue",
+                JobType::new("TEST"),
+                "same::subject",
+                gen,
+                JobPayload::new(serde_json::json!({})),
+            );
+            let superseded = tx.insert_superseding(&job).await.unwrap();
+            assert_eq!(superseded, if gen == 1 { 0 } else { 1 });
+            tx.commit().await.unwrap();
+            ids.push(job.id);
         }
-    }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{create_pool, run_migrations};
-    use semantica_core::domain::{JobPayload, JobType};
-    use semantica_core::port::time_provider::SystemTimeProvider;
+        assert_eq!(
+            repo.get_latest_generation("same::subject").await.unwrap(),
+            3
+        );
+        let mut states = Vec::new();
+        for id in &ids {
+            states.push(repo.find_by_id(id).await.unwrap().unwrap().state);
+        }
+        assert_eq!(
+            states,
+            vec![JobState::Superseded, JobState::Superseded, JobState::Queued]
+        );
 
-    async fn setup_test_db() -> (SqlitePool, Arc<dyn TimeProvider>) {
-        let pool = create_pool("sqlite::memory:").await.unwrap();
-        run_migrations(&pool).await.unwrap();
-        let time_provider = Arc::new(SystemTimeProvider);
-        (pool, time_provider)
+        let events = repo.list_events(&ids[1]).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].actor, actor::SUPERSEDE);
+        assert_eq!(
+            events[0].reason.as_deref(),
+            Some("superseded by generation 3")
+        );
+
+        // A rolled back claim leaves the generation untouched
+        let mut tx = repo.begin_transaction().await.unwrap();
+        assert_eq!(tx.next_generation("same::subject").await.unwrap(), 4);
+        tx.rollback().await.unwrap();
+        assert_eq!(
+            repo.get_latest_generation("same::subject").await.unwrap(),
+            3
+        );
     }
 
     #[tokio::test]
-    async fn test_insert_and_find() {
+    async fn test_progress_only_while_running_and_reset_on_pop() {
         let (pool, time_provider) = setup_test_db().await;
         let repo = SqliteJobRepository::new(pool, time_provider);
 
         let job = Job::new_test(
-            "test_queue",
-            JobType::new("TEST"),
-            "test::subject",
+            "progress_queue",
+            JobType::new("INDEX"),
+            "progress::subject",
             1,
-            JobPayload::new(serde_json::json!({"key": "value"})),
+            JobPayload::new(serde_json::json!({})),
         );
-
         repo.insert(&job).await.unwrap();
 
-        let found = repo.find_by_id(&job.id).await.unwrap();
-        assert!(found.is_some());
-        assert_eq!(found.unwrap().id, job.id);
+        let progress = JobProgress {
+            percent: 60,
+            message: Some("60/100 files".to_string()),
+            updated_at: 5_000,
+        };
+        // Not running yet
+        assert!(!repo.update_progress(&job.id, &progress).await.unwrap());
+
+        let mut popped = repo.pop_next("progress_queue").await.unwrap().unwrap();
+        assert!(repo.update_progress(&job.id, &progress).await.unwrap());
+        let stored = repo.find_by_id(&job.id).await.unwrap().unwrap();
+        assert_eq!(stored.progress, Some(progress));
+
+        // Completion updates (full row) keep the reported progress
+        popped.result_summary = Some("ok".to_string());
+        repo.update(&popped).await.unwrap();
+        assert!(repo
+            .find_by_id(&job.id)
+            .await
+            .unwrap()
+            .unwrap()
+            .progress
+            .is_some());
+
+        // A new attempt starts without the previous attempt's progress
+        repo.update_state(&job.id, JobState::Queued, None)
+            .await
+            .unwrap();
+        let retried = repo.pop_next("progress_queue").await.unwrap().unwrap();
+        assert_eq!(retried.progress, None);
     }
 
     #[tokio::test]
-    async fn test_pop_next() {
+    async fn test_add_and_list_artifacts() {
         let (pool, time_provider) = setup_test_db().await;
         let repo = SqliteJobRepository::new(pool, time_provider);
 
-        // Insert jobs with different priorities
-        let mut job1 = Job::new_test(
+        let job = Job::new_test(
             "test_queue",
             JobType::new("TEST"),
-            "subject1",
+            "artifact::subject",
             1,
             JobPayload::new(serde_json::json!({})),
         );
-        job1.priority = 0;
+        repo.insert(&job).await.unwrap();
 
-        let mut job2 = Job::new_test(
-            "test_queue",
-            JobType::new("TEST"),
-            "subject2",
-            1,
-            JobPayload::new(serde_json::json!({})),
+        let bundle = Artifact::new("/a/bundle.tar.gz", b"bundle", 10);
+        repo.add_artifact(&job.id, &bundle).await.unwrap();
+        repo.add_artifact(&job.id, &Artifact::unknown("/a/legacy.txt"))
+            .await
+            .unwrap();
+
+        let stored = repo.find_by_id(&job.id).await.unwrap().unwrap();
+        assert_eq!(
+            stored.artifacts.as_deref(),
+            Some("/a/bundle.tar.gz,/a/legacy.txt")
         );
-        job2.priority = 10;
+        // Only artifacts with metadata have a row
+        assert_eq!(repo.list_artifacts(&job.id).await.unwrap(), vec![bundle]);
 
-        repo.insert(&job1).await.unwrap();
-        repo.insert(&job2).await.unwrap();
+        assert!(repo.delete_job(&job.id, &[JobState::Queued]).await.unwrap());
+        assert!(repo.list_artifacts(&job.id).await.unwrap().is_empty());
+    }
 
-        // Should pop job2 first (higher priority)
-        let popped = repo.pop_next("test_queue").await.unwrap();
-        assert!(popped.is_some());
-        assert_eq!(popped.unwrap().id, job2.id);
+    #[tokio::test]
+    async fn test_subject_history_keeps_newest_entries() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        for generation in 1..=4 {
+            let result = SubjectResult {
+                subject_key: "index::a.rs".to_string(),
+                generation,
+                job_id: format!("job-{}", generation),
+                result_hash: Some(SubjectResult::hash_output("out")),
+                duration_ms: 10 * generation,
+                finished_at: 1000 + generation,
+            };
+            repo.record_subject_result(&result, 3).await.unwrap();
+        }
+
+        let history = repo.list_subject_history("index::a.rs", 10).await.unwrap();
+        let generations: Vec<i64> = history.iter().map(|r| r.generation).collect();
+        assert_eq!(generations, vec![4, 3, 2], "newest first, oldest pruned");
+        assert_eq!(history[0].duration_ms, 40);
+
+        assert_eq!(
+            repo.list_subject_history("index::a.rs", 1)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(repo
+            .list_subject_history("index::other.rs", 10)
+            .await
+            .unwrap()
+            .is_empty());
     }
 
     #[tokio::test]
-    async fn test_supersede() {
+    async fn test_metrics_snapshots_count_window_and_keep_newest() {
         let (pool, time_provider) = setup_test_db().await;
         let repo = SqliteJobRepository::new(pool, time_provider);
 
-        // Insert 3 jobs with same subject_key, different generations
-        for gen in 1..=3 {
+        // Finished before / inside the window, and one still queued
+        for (i, (state, finished_at)) in [
+            (JobState::Done, Some(500)),
+            (JobState::Done, Some(1500)),
+            (JobState::Failed, Some(1800)),
+            (JobState::Queued, None),
+        ]
+        .into_iter()
+        .enumerate()
+        {
             let job = Job::new_test(
                 "test_queue",
                 JobType::new("TEST"),
-                "same::subject",
-                gen,
+                format!("subject-{}", i),
+                1,
                 JobPayload::new(serde_json::json!({})),
             );
             repo.insert(&job).await.unwrap();
+            if state != JobState::Queued {
+                repo.update_state(&job.id, state, finished_at)
+                    .await
+                    .unwrap();
+            }
         }
 
-        // Supersede generations < 3
-        let count = repo.mark_superseded("same::subject", 3).await.unwrap();
-        assert_eq!(count, 2); // 2 jobs superseded
+        let snapshot = repo.take_metrics_snapshot(1000, 2000).await.unwrap();
+        assert_eq!(
+            snapshot,
+            MetricsSnapshot {
+                taken_at: 2000,
+                window_ms: 1000,
+                completed: 1,
+                failed: 1,
+                queued: 1,
+                running: 0,
+            }
+        );
+        assert_eq!(snapshot.completed_per_minute(), 60.0);
 
-        // Check that only generation 3 is QUEUED
-        let queued = repo
-            .count_by_state("test_queue", JobState::Queued)
+        for taken_at in 1..=4 {
+            let snapshot = MetricsSnapshot {
+                taken_at: taken_at * 60_000,
+                window_ms: 60_000,
+                completed: taken_at,
+                ..Default::default()
+            };
+            repo.record_metrics_snapshot(&snapshot, 3).await.unwrap();
+        }
+
+        let history = repo.list_metrics_snapshots(0, 10).await.unwrap();
+        let completed: Vec<i64> = history.iter().map(|s| s.completed).collect();
+        assert_eq!(completed, vec![2, 3, 4], "oldest first, oldest pruned");
+
+        let recent = repo.list_metrics_snapshots(0, 2).await.unwrap();
+        assert_eq!(
+            recent.iter().map(|s| s.completed).collect::<Vec<_>>(),
+            vec![3, 4]
+        );
+        assert_eq!(
+            repo.list_metrics_snapshots(180_000, 10)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dead_letters_list_newest_first_and_purge() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool, time_provider);
+
+        let mut jobs = Vec::new();
+        for (i, queue) in ["build", "build", "index"].into_iter().enumerate() {
+            let job = Job::new_test(
+                queue,
+                JobType::new("TEST"),
+                format!("subject-{}", i),
+                1,
+                JobPayload::new(serde_json::json!({})),
+            );
+            repo.insert(&job).await.unwrap();
+            repo.record_dead_letter(&DeadLetter::new(&job, "exit 1", 1000 * (i as i64 + 1)))
+                .await
+                .unwrap();
+            jobs.push(job);
+        }
+        // Parked again: the entry is replaced, not duplicated
+        repo.record_dead_letter(&DeadLetter::new(&jobs[0], "exit 2", 1500))
             .await
             .unwrap();
-        assert_eq!(queued, 1);
 
-        let superseded = repo
-            .count_by_state("test_queue", JobState::Superseded)
+        let all = repo.list_dead_letters(None, 10).await.unwrap();
+        let ids: Vec<&str> = all.iter().map(|l| l.job_id.as_str()).collect();
+        assert_eq!(ids, vec![&jobs[2].id, &jobs[1].id, &jobs[0].id]);
+        assert_eq!(all[2].last_error, "exit 2");
+        assert_eq!(
+            repo.list_dead_letters(Some("build"), 1).await.unwrap()[0].job_id,
+            jobs[1].id
+        );
+
+        assert!(repo.remove_dead_letter(&jobs[1].id).await.unwrap());
+        assert!(!repo.remove_dead_letter(&jobs[1].id).await.unwrap());
+
+        assert_eq!(
+            repo.purge_dead_letters(Some("build"), Some(1000))
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            repo.purge_dead_letters(Some("build"), None).await.unwrap(),
+            1
+        );
+        assert_eq!(repo.purge_dead_letters(None, None).await.unwrap(), 1);
+        assert!(repo.list_dead_letters(None, 10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_delete_job_removes_related_rows() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool.clone(), time_provider);
+
+        let mut job = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "secret::subject",
+            1,
+            JobPayload::new(serde_json::json!({"token": "s3cr3t"})),
+        );
+        repo.insert(&job).await.unwrap();
+        repo.record_event(&JobEvent::new(
+            job.id.clone(),
+            JobState::Done,
+            actor::RPC,
+            None,
+            42,
+        ))
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO idempotency_keys (key, job_id, created_at) VALUES (?, ?, 0)")
+            .bind("key-1")
+            .bind(&job.id)
+            .execute(&pool)
             .await
             .unwrap();
-        assert_eq!(superseded, 2);
+
+        // Wrong state: nothing is deleted
+        assert!(!repo.delete_job(&job.id, &[JobState::Done]).await.unwrap());
+        assert!(repo.find_by_id(&job.id).await.unwrap().is_some());
+
+        job.state = JobState::Done;
+        repo.update(&job).await.unwrap();
+        assert!(repo.delete_job(&job.id, &[JobState::Done]).await.unwrap());
+
+        assert!(repo.find_by_id(&job.id).await.unwrap().is_none());
+        assert!(repo.list_events(&job.id).await.unwrap().is_empty());
+        let keys: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM idempotency_keys")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(keys, 0);
+        assert!(!repo.delete_job(&job.id, &[JobState::Done]).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_list_jobs_keyset_pages_survive_deletes() {
+        let (pool, time_provider) = setup_test_db().await;
+        let repo = SqliteJobRepository::new(pool.clone(), time_provider);
+
+        // Same created_at for most rows so ordering relies on the id tie-break
+        let mut expected = Vec::new();
+        for i in 0..5 {
+            let mut job = Job::new_test(
+                "list_queue",
+                JobType::new("TEST"),
+                format!("subject::{}", i),
+                1,
+                JobPayload::new(serde_json::json!({})),
+            );
+            job.id = format!("job-{}", i);
+            job.created_at = if i == 4 { 2_000 } else { 1_000 };
+            repo.insert(&job).await.unwrap();
+            expected.push(job.id);
+        }
+        let other = Job::new_test(
+            "other_queue",
+            JobType::new("TEST"),
+            "other",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        repo.insert(&other).await.unwrap();
+
+        let filter = JobFilter {
+            queue: Some("list_queue".to_string()),
+            ..Default::default()
+        };
+
+        let first = repo
+            .list_jobs(&filter, &PageRequest::first(2))
+            .await
+            .unwrap();
+        let ids: Vec<_> = first.items.iter().map(|j| j.id.as_str()).collect();
+        assert_eq!(ids, vec!["job-0", "job-1"]);
+
+        // GC between pages must not shift rows onto the page already served
+        sqlx::query("DELETE FROM jobs WHERE id IN ('job-0', 'job-1')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let mut seen: Vec<String> = first.items.into_iter().map(|j| j.id).collect();
+        let mut cursor = first.next_cursor;
+        while let Some(after) = cursor {
+            let page = repo
+                .list_jobs(&filter, &PageRequest::after(after, 2))
+                .await
+                .unwrap();
+            seen.extend(page.items.into_iter().map(|j| j.id));
+            cursor = page.next_cursor;
+        }
+        assert_eq!(seen, expected);
+
+        let err = repo
+            .list_jobs(&filter, &PageRequest::after("not-a-cursor", 2))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Validation(_)));
     }
 }
@@ -1,12 +1,16 @@
 // Semantica Infrastructure - SQLite Adapter
-// Implements: JobRepository, TransactionalJobRepository (ADR-010), Maintenance (Phase 4)
+// Implements: JobRepository, TransactionalJobRepository (ADR-010), Maintenance (Phase 4), AuditLog
 
+mod audit_log;
 mod connection;
 mod job_repository;
 mod maintenance_impl;
 mod migration;
+mod pagination;
+mod slow_query;
 mod transaction; // Phase 4
 
+pub use audit_log::SqliteAuditLog;
 pub use connection::create_pool;
 pub use job_repository::SqliteJobRepository;
 pub use maintenance_impl::SqliteMaintenance;
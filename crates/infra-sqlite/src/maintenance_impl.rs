@@ -1,24 +1,200 @@
 // SQLite Maintenance Implementation (Phase 4)
+use crate::job_repository::{load_events, JobRow};
+use crate::slow_query::SlowQueryLog;
 use async_trait::async_trait;
-use semantica_core::domain::JobState;
+use semantica_core::domain::{Job, JobState};
 use semantica_core::error::{AppError, Result};
-use semantica_core::port::{Maintenance, MaintenanceStats, TimeProvider};
+use semantica_core::port::{
+    is_success_log_sampled, ArchivedJob, ArtifactStorage, GcPreview, JobArchive, Maintenance,
+    MaintenanceReport, MaintenanceStats, TimeProvider,
+};
 use sqlx::SqlitePool;
 use std::sync::Arc;
+use std::time::Instant;
 use tracing::{info, warn};
 
+const GC_FINISHED_JOBS_SQL: &str = r#"
+    DELETE FROM jobs
+    WHERE state IN (?, ?, ?, ?, ?)
+    AND finished_at IS NOT NULL
+    AND finished_at < ?
+    "#;
+
+// Jobs the GC will delete, one keyset page at a time
+const GC_ARCHIVE_SQL: &str = r#"
+    SELECT * FROM jobs
+    WHERE state IN (?, ?, ?, ?, ?)
+    AND finished_at IS NOT NULL
+    AND finished_at < ?
+    AND (finished_at, id) > (?, ?)
+    ORDER BY finished_at, id
+    LIMIT ?
+    "#;
+
+/// Jobs per archive append
+const ARCHIVE_BATCH: i64 = 500;
+
+const FUTURE_FINISHED_SQL: &str = "SELECT COUNT(*) FROM jobs WHERE finished_at > ?";
+
+const GC_LOG_PATHS_SQL: &str = r#"
+    SELECT log_path FROM jobs
+    WHERE state IN (?, ?, ?, ?, ?)
+    AND finished_at IS NOT NULL
+    AND finished_at < ?
+    AND log_path IS NOT NULL
+    "#;
+
+const GC_ARTIFACTS_SQL: &str = r#"
+    SELECT id, artifacts FROM jobs
+    WHERE state IN (?, ?, ?, ?, ?)
+    AND finished_at IS NOT NULL
+    AND finished_at < ?
+    AND artifacts IS NOT NULL AND artifacts != ''
+    "#;
+
+// Everything the GC would touch, with the size of the row's bulky columns
+const GC_PREVIEW_SQL: &str = r#"
+    SELECT id, finished_at, log_path, artifacts,
+        LENGTH(payload) + COALESCE(LENGTH(fallback_payloads), 0)
+            + COALESCE(LENGTH(result_summary), 0) + COALESCE(LENGTH(env_vars), 0)
+    FROM jobs
+    WHERE state IN (?, ?, ?, ?, ?)
+    AND finished_at IS NOT NULL
+    AND finished_at < ?
+    ORDER BY finished_at, id
+    "#;
+
+const SUCCESS_LOGS_SQL: &str = r#"
+    SELECT id, log_path FROM jobs
+    WHERE state = ?
+    AND finished_at IS NOT NULL
+    AND finished_at < ?
+    AND log_path IS NOT NULL
+    "#;
+
 /// SQLite maintenance implementation
 pub struct SqliteMaintenance {
     pool: SqlitePool,
     time_provider: Arc<dyn TimeProvider>,
+    slow_log: SlowQueryLog,
+    artifact_storage: Option<Arc<dyn ArtifactStorage>>,
+    job_archive: Option<Arc<dyn JobArchive>>,
 }
 
 impl SqliteMaintenance {
     pub fn new(pool: SqlitePool, time_provider: Arc<dyn TimeProvider>) -> Self {
         Self {
+            slow_log: SlowQueryLog::from_env(pool.clone()),
             pool,
             time_provider,
+            artifact_storage: None,
+            job_archive: None,
+        }
+    }
+
+    /// Delete registered job artifacts through this backend during GC
+    ///
+    /// Without a backend only log files are collected.
+    pub fn with_artifact_storage(mut self, storage: Arc<dyn ArtifactStorage>) -> Self {
+        self.artifact_storage = Some(storage);
+        self
+    }
+
+    /// Archive every job before GC deletes it
+    ///
+    /// A failed append aborts the GC run, so no job is deleted unarchived.
+    pub fn with_job_archive(mut self, archive: Arc<dyn JobArchive>) -> Self {
+        self.job_archive = Some(archive);
+        self
+    }
+
+    /// Append every job finished before `cutoff_time` (and its events) to the archive
+    ///
+    /// Returns the number of jobs archived.
+    async fn archive_finished_jobs(
+        &self,
+        archive: &dyn JobArchive,
+        cutoff_time: i64,
+    ) -> Result<usize> {
+        let now = self.time_provider.now_millis();
+        let mut after = (i64::MIN, String::new());
+        let mut archived = 0;
+
+        loop {
+            let rows: Vec<JobRow> = sqlx::query_as(GC_ARCHIVE_SQL)
+                .bind(JobState::Done.to_string())
+                .bind(JobState::Failed.to_string())
+                .bind(JobState::Superseded.to_string())
+                .bind(JobState::SkippedTtl.to_string())
+                .bind(JobState::SkippedDeadline.to_string())
+                .bind(cutoff_time)
+                .bind(after.0)
+                .bind(&after.1)
+                .bind(ARCHIVE_BATCH)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| {
+                    AppError::Internal(format!("Failed to query jobs to archive: {}", e))
+                })?;
+            let jobs: Vec<Job> = rows.into_iter().map(JobRow::into_job).collect();
+            let Some(last) = jobs.last() else { break };
+            after = (last.finished_at.unwrap_or_default(), last.id.clone());
+
+            let mut batch = Vec::with_capacity(jobs.len());
+            for job in jobs {
+                batch.push(ArchivedJob {
+                    events: load_events(&self.pool, &job.id).await?,
+                    job,
+                    archived_at: now,
+                });
+            }
+            archive.append(&batch).await?;
+            archived += batch.len();
+        }
+
+        Ok(archived)
+    }
+
+    /// (job id, comma-separated artifacts) of jobs finished before `cutoff_time`
+    async fn finished_artifacts(&self, cutoff_time: i64) -> Result<Vec<(String, String)>> {
+        let started = Instant::now();
+        let rows = sqlx::query_as(GC_ARTIFACTS_SQL)
+            .bind(JobState::Done.to_string())
+            .bind(JobState::Failed.to_string())
+            .bind(JobState::Superseded.to_string())
+            .bind(JobState::SkippedTtl.to_string())
+            .bind(JobState::SkippedDeadline.to_string())
+            .bind(cutoff_time)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to query artifacts: {}", e)))?;
+        self.slow_log
+            .observe("finished_artifacts", GC_ARTIFACTS_SQL, started)
+            .await;
+        Ok(rows)
+    }
+
+    /// Delete every artifact of a comma-separated list (best-effort)
+    ///
+    /// Returns the number of artifacts deleted.
+    async fn delete_artifacts(&self, storage: &dyn ArtifactStorage, artifacts: &str) -> usize {
+        let mut deleted = 0;
+        for uri in artifacts.split(',').filter(|uri| !uri.is_empty()) {
+            match storage.delete(uri).await {
+                Ok(true) => {
+                    deleted += 1;
+                    info!(uri = %uri, backend = storage.name(), "Deleted artifact");
+                }
+                Ok(false) => {}
+                Err(e) => warn!(uri = %uri, error = %e, "Failed to delete artifact"),
+            }
         }
+        deleted
+    }
+
+    /// GC cutoff for a retention period (never after now)
+    fn cutoff(&self, retention_days: i64) -> i64 {
+        self.time_provider.now_millis() - retention_days.max(0) * 24 * 60 * 60 * 1000
     }
 
     /// Get DB file size in MB
@@ -71,34 +247,83 @@ impl Maintenance for SqliteMaintenance {
 
     async fn gc_finished_jobs(&self, retention_days: i64) -> Result<i64> {
         let now = self.time_provider.now_millis();
-        let retention_ms = retention_days * 24 * 60 * 60 * 1000;
+        // cutoff <= now: jobs "from the future" (clock set backwards) are never collected
+        let retention_ms = retention_days.max(0) * 24 * 60 * 60 * 1000;
         let cutoff_time = now - retention_ms;
 
+        let future_jobs: i64 = sqlx::query_scalar(FUTURE_FINISHED_SQL)
+            .bind(now)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Job GC failed: {}", e)))?;
+        if future_jobs > 0 {
+            warn!(
+                jobs = future_jobs,
+                "Clock anomaly: jobs finished in the future (clock set backwards?), kept"
+            );
+        }
+
         info!(
             retention_days = retention_days,
             cutoff_time = cutoff_time,
             "Running finished job GC"
         );
 
-        // Delete jobs that are DONE/FAILED/SUPERSEDED and finished before cutoff
-        let result = sqlx::query(
-            r#"
-            DELETE FROM jobs
-            WHERE state IN (?, ?, ?)
-            AND finished_at IS NOT NULL
-            AND finished_at < ?
-            "#,
-        )
-        .bind(JobState::Done.to_string())
-        .bind(JobState::Failed.to_string())
-        .bind(JobState::Superseded.to_string())
-        .bind(cutoff_time)
-        .execute(&self.pool)
-        .await
-        .map_err(|e| AppError::Internal(format!("Job GC failed: {}", e)))?;
+        if let Some(archive) = &self.job_archive {
+            let archived = self
+                .archive_finished_jobs(archive.as_ref(), cutoff_time)
+                .await?;
+            info!(archived_jobs = archived, "Archived jobs before GC");
+        }
+
+        // Artifacts of the collected jobs would be unreachable afterwards
+        if let Some(storage) = &self.artifact_storage {
+            for (_, artifacts) in self.finished_artifacts(cutoff_time).await? {
+                self.delete_artifacts(storage.as_ref(), &artifacts).await;
+            }
+        }
+
+        // Delete jobs that are DONE/FAILED/SUPERSEDED/expired and finished before cutoff
+        let started = Instant::now();
+        let result = sqlx::query(GC_FINISHED_JOBS_SQL)
+            .bind(JobState::Done.to_string())
+            .bind(JobState::Failed.to_string())
+            .bind(JobState::Superseded.to_string())
+            .bind(JobState::SkippedTtl.to_string())
+            .bind(JobState::SkippedDeadline.to_string())
+            .bind(cutoff_time)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Job GC failed: {}", e)))?;
+        self.slow_log
+            .observe("gc_finished_jobs", GC_FINISHED_JOBS_SQL, started)
+            .await;
 
         let deleted = result.rows_affected() as i64;
 
+        // Drop event history of jobs that no longer exist
+        sqlx::query("DELETE FROM job_events WHERE job_id NOT IN (SELECT id FROM jobs)")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Job event GC failed: {}", e)))?;
+        sqlx::query("DELETE FROM forwarded_jobs WHERE job_id NOT IN (SELECT id FROM jobs)")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Forward record GC failed: {}", e)))?;
+        sqlx::query("DELETE FROM job_artifacts WHERE job_id NOT IN (SELECT id FROM jobs)")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Artifact metadata GC failed: {}", e)))?;
+        sqlx::query("DELETE FROM dead_letters WHERE job_id NOT IN (SELECT id FROM jobs)")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Dead letter GC failed: {}", e)))?;
+        // A replayed key must not resolve to a collected job
+        sqlx::query("DELETE FROM idempotency_keys WHERE job_id NOT IN (SELECT id FROM jobs)")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Idempotency key GC failed: {}", e)))?;
+
         info!(deleted_jobs = deleted, "Finished job GC completed");
 
         Ok(deleted)
@@ -106,7 +331,7 @@ impl Maintenance for SqliteMaintenance {
 
     async fn gc_artifacts(&self, retention_days: i64) -> Result<usize> {
         let now = self.time_provider.now_millis();
-        let retention_ms = retention_days * 24 * 60 * 60 * 1000;
+        let retention_ms = retention_days.max(0) * 24 * 60 * 60 * 1000;
         let cutoff_time = now - retention_ms;
 
         info!(
@@ -116,22 +341,20 @@ impl Maintenance for SqliteMaintenance {
         );
 
         // Find log files for old finished jobs
-        let log_paths: Vec<String> = sqlx::query_scalar(
-            r#"
-            SELECT log_path FROM jobs
-            WHERE state IN (?, ?, ?)
-            AND finished_at IS NOT NULL
-            AND finished_at < ?
-            AND log_path IS NOT NULL
-            "#,
-        )
-        .bind(JobState::Done.to_string())
-        .bind(JobState::Failed.to_string())
-        .bind(JobState::Superseded.to_string())
-        .bind(cutoff_time)
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| AppError::Internal(format!("Failed to query log paths: {}", e)))?;
+        let started = Instant::now();
+        let log_paths: Vec<String> = sqlx::query_scalar(GC_LOG_PATHS_SQL)
+            .bind(JobState::Done.to_string())
+            .bind(JobState::Failed.to_string())
+            .bind(JobState::Superseded.to_string())
+            .bind(JobState::SkippedTtl.to_string())
+            .bind(JobState::SkippedDeadline.to_string())
+            .bind(cutoff_time)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to query log paths: {}", e)))?;
+        self.slow_log
+            .observe("gc_artifacts", GC_LOG_PATHS_SQL, started)
+            .await;
 
         let mut deleted_count = 0;
 
@@ -149,11 +372,147 @@ impl Maintenance for SqliteMaintenance {
             }
         }
 
+        // Registered artifacts (e.g. failure bundles), wherever the backend keeps them
+        if let Some(storage) = &self.artifact_storage {
+            for (job_id, artifacts) in self.finished_artifacts(cutoff_time).await? {
+                deleted_count += self.delete_artifacts(storage.as_ref(), &artifacts).await;
+
+                for sql in [
+                    "UPDATE jobs SET artifacts = NULL WHERE id = ?",
+                    "DELETE FROM job_artifacts WHERE job_id = ?",
+                ] {
+                    sqlx::query(sql)
+                        .bind(&job_id)
+                        .execute(&self.pool)
+                        .await
+                        .map_err(|e| {
+                            AppError::Internal(format!("Failed to clear artifacts: {}", e))
+                        })?;
+                }
+            }
+        }
+
         info!(deleted_artifacts = deleted_count, "Artifact GC completed");
 
         Ok(deleted_count)
     }
 
+    async fn sample_success_logs(&self, older_than_hours: i64, sample_rate: f64) -> Result<usize> {
+        if sample_rate >= 1.0 {
+            return Ok(0);
+        }
+
+        let cutoff_time =
+            self.time_provider.now_millis() - older_than_hours.max(0) * 60 * 60 * 1000;
+
+        let started = Instant::now();
+        let rows: Vec<(String, String)> = sqlx::query_as(SUCCESS_LOGS_SQL)
+            .bind(JobState::Done.to_string())
+            .bind(cutoff_time)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to query success logs: {}", e)))?;
+        self.slow_log
+            .observe("sample_success_logs", SUCCESS_LOGS_SQL, started)
+            .await;
+
+        let mut dropped = 0;
+        for (job_id, log_path) in rows {
+            if is_success_log_sampled(&job_id, sample_rate) {
+                continue;
+            }
+
+            match tokio::fs::remove_file(&log_path).await {
+                Ok(_) => dropped += 1,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => {
+                    warn!(path = %log_path, error = %e, "Failed to delete log file");
+                    continue;
+                }
+            }
+
+            // Forget the path so the job is not reconsidered (logs.tail reports no logs)
+            sqlx::query("UPDATE jobs SET log_path = NULL WHERE id = ?")
+                .bind(&job_id)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| AppError::Internal(format!("Failed to clear log path: {}", e)))?;
+        }
+
+        info!(
+            dropped_logs = dropped,
+            sample_rate = sample_rate,
+            "Success log sampling completed"
+        );
+
+        Ok(dropped)
+    }
+
+    async fn preview_gc(
+        &self,
+        job_retention_days: i64,
+        artifact_retention_days: i64,
+    ) -> Result<GcPreview> {
+        let job_cutoff = self.cutoff(job_retention_days);
+        let artifact_cutoff = self.cutoff(artifact_retention_days);
+
+        let started = Instant::now();
+        let rows: Vec<(String, i64, Option<String>, Option<String>, i64)> =
+            sqlx::query_as(GC_PREVIEW_SQL)
+                .bind(JobState::Done.to_string())
+                .bind(JobState::Failed.to_string())
+                .bind(JobState::Superseded.to_string())
+                .bind(JobState::SkippedTtl.to_string())
+                .bind(JobState::SkippedDeadline.to_string())
+                .bind(job_cutoff.max(artifact_cutoff))
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| AppError::Internal(format!("GC preview failed: {}", e)))?;
+        self.slow_log
+            .observe("preview_gc", GC_PREVIEW_SQL, started)
+            .await;
+
+        let mut preview = GcPreview::default();
+        for (job_id, finished_at, log_path, artifacts, row_bytes) in rows {
+            if finished_at < job_cutoff {
+                // Row deleted first, so gc_artifacts never sees its log file
+                preview.job_ids.push(job_id);
+                preview.row_bytes += row_bytes.max(0) as u64;
+            } else if finished_at < artifact_cutoff {
+                if let Some(log_path) = log_path {
+                    // Missing files are skipped by the GC as well
+                    if let Ok(metadata) = tokio::fs::metadata(&log_path).await {
+                        preview.log_bytes += metadata.len();
+                        preview.log_files.push(log_path);
+                    }
+                }
+            } else {
+                continue;
+            }
+
+            // Registered artifacts are only deleted through a storage backend
+            if self.artifact_storage.is_some() {
+                preview.artifacts.extend(
+                    artifacts
+                        .iter()
+                        .flat_map(|list| list.split(','))
+                        .filter(|uri| !uri.is_empty())
+                        .map(str::to_string),
+                );
+            }
+        }
+
+        info!(
+            jobs = preview.job_ids.len(),
+            log_files = preview.log_files.len(),
+            artifacts = preview.artifacts.len(),
+            bytes = preview.bytes_reclaimed(),
+            "GC preview computed"
+        );
+
+        Ok(preview)
+    }
+
     async fn get_stats(&self) -> Result<MaintenanceStats> {
         // Get DB size
         let db_size_mb = self.get_db_size().await?;
@@ -167,12 +526,14 @@ impl Maintenance for SqliteMaintenance {
         let finished_job_count: i64 = sqlx::query_scalar(
             r#"
             SELECT COUNT(*) FROM jobs
-            WHERE state IN (?, ?, ?)
+            WHERE state IN (?, ?, ?, ?, ?)
             "#,
         )
         .bind(JobState::Done.to_string())
         .bind(JobState::Failed.to_string())
         .bind(JobState::Superseded.to_string())
+        .bind(JobState::SkippedTtl.to_string())
+        .bind(JobState::SkippedDeadline.to_string())
         .fetch_one(&self.pool)
         .await
         .map_err(|e| AppError::Internal(format!("Failed to count finished jobs: {}", e)))?;
@@ -193,6 +554,12 @@ impl Maintenance for SqliteMaintenance {
             }
         }
 
+        let schema_version: i64 =
+            sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) FROM schema_version")
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| AppError::Internal(format!("Failed to read schema version: {}", e)))?;
+
         // Calculate DB size in bytes
         let db_size_bytes = (db_size_mb * 1024.0 * 1024.0) as i64;
 
@@ -211,8 +578,51 @@ impl Maintenance for SqliteMaintenance {
             artifact_count,
             log_files_size_mb,
             fragmentation_percent,
+            schema_version,
         })
     }
+
+    async fn record_report(&self, report: &MaintenanceReport, keep: usize) -> Result<()> {
+        let json = serde_json::to_string(report)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize report: {}", e)))?;
+
+        sqlx::query("INSERT INTO maintenance_reports (started_at, report) VALUES (?, ?)")
+            .bind(report.started_at)
+            .bind(json)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to record report: {}", e)))?;
+
+        // Trim history to the newest `keep` reports
+        sqlx::query(
+            r#"
+            DELETE FROM maintenance_reports
+            WHERE id NOT IN (SELECT id FROM maintenance_reports ORDER BY id DESC LIMIT ?)
+            "#,
+        )
+        .bind(keep as i64)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to trim report history: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn list_reports(&self, limit: usize) -> Result<Vec<MaintenanceReport>> {
+        let rows: Vec<String> =
+            sqlx::query_scalar("SELECT report FROM maintenance_reports ORDER BY id DESC LIMIT ?")
+                .bind(limit as i64)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| AppError::Database(format!("Failed to list reports: {}", e)))?;
+
+        rows.iter()
+            .map(|json| {
+                serde_json::from_str(json)
+                    .map_err(|e| AppError::Internal(format!("Corrupt maintenance report: {}", e)))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -236,6 +646,8 @@ mod tests {
         assert!(stats.db_size_mb > 0.0);
         assert_eq!(stats.job_count, 0);
         assert_eq!(stats.finished_job_count, 0);
+        // All bundled migrations applied
+        assert!(stats.schema_version >= 12);
     }
 
     #[tokio::test]
@@ -275,13 +687,316 @@ mod tests {
         job.finished_at = Some(ten_days_ago);
 
         job_repo.insert(&job).await.unwrap();
+        sqlx::query("INSERT INTO idempotency_keys (key, job_id, created_at) VALUES (?, ?, 0)")
+            .bind("save-1")
+            .bind(&job.id)
+            .execute(&maintenance.pool)
+            .await
+            .unwrap();
 
         // GC with 7 day retention should delete it
         let deleted = maintenance.gc_finished_jobs(7).await.unwrap();
         assert_eq!(deleted, 1);
 
-        // Verify job is deleted
+        // Verify job is deleted, with its idempotency key
         let found = job_repo.find_by_id(&job.id).await.unwrap();
         assert!(found.is_none());
+        let keys: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM idempotency_keys")
+            .fetch_one(&maintenance.pool)
+            .await
+            .unwrap();
+        assert_eq!(keys, 0);
+
+        // Finished "in the future" (clock set backwards): never collected
+        let mut future_job = Job::new_test(
+            "test",
+            JobType::new("TEST"),
+            "subject-2",
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        future_job.state = JobState::Done;
+        future_job.finished_at = Some(now_ms + 60 * 60 * 1000);
+        job_repo.insert(&future_job).await.unwrap();
+
+        assert_eq!(maintenance.gc_finished_jobs(0).await.unwrap(), 0);
+        assert!(job_repo.find_by_id(&future_job.id).await.unwrap().is_some());
+    }
+
+    /// In-memory archive, optionally refusing every append
+    #[derive(Default)]
+    struct MemoryArchive {
+        jobs: tokio::sync::Mutex<Vec<ArchivedJob>>,
+        broken: bool,
+    }
+
+    #[async_trait]
+    impl JobArchive for MemoryArchive {
+        async fn append(&self, jobs: &[ArchivedJob]) -> Result<()> {
+            if self.broken {
+                return Err(AppError::Internal("disk full".to_string()));
+            }
+            self.jobs.lock().await.extend_from_slice(jobs);
+            Ok(())
+        }
+
+        async fn get(
+            &self,
+            _job_id: &str,
+        ) -> Result<Option<(ArchivedJob, semantica_core::port::ArchiveLocation)>> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_gc_archives_before_deleting() {
+        let pool = create_pool(":memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+
+        let time_provider = Arc::new(SystemTimeProvider);
+        let job_repo = SqliteJobRepository::new(pool.clone(), time_provider.clone());
+        let ten_days_ago = time_provider.now_millis() - 10 * 24 * 60 * 60 * 1000;
+
+        let mut job_ids = Vec::new();
+        for subject in ["a", "b", "c"] {
+            let mut job = Job::new_test(
+                "test",
+                JobType::new("TEST"),
+                subject,
+                1,
+                JobPayload::new(serde_json::json!({})),
+            );
+            job.state = JobState::Done;
+            job.finished_at = Some(ten_days_ago);
+            job_repo.insert(&job).await.unwrap();
+            job_ids.push(job.id);
+        }
+
+        // A failing archive keeps every row
+        let broken = SqliteMaintenance::new(pool.clone(), time_provider.clone()).with_job_archive(
+            Arc::new(MemoryArchive {
+                broken: true,
+                ..Default::default()
+            }),
+        );
+        assert!(broken.gc_finished_jobs(7).await.is_err());
+        assert!(job_repo.find_by_id(&job_ids[0]).await.unwrap().is_some());
+
+        let archive = Arc::new(MemoryArchive::default());
+        let maintenance =
+            SqliteMaintenance::new(pool, time_provider).with_job_archive(archive.clone());
+        assert_eq!(maintenance.gc_finished_jobs(7).await.unwrap(), 3);
+
+        let mut archived: Vec<String> = archive
+            .jobs
+            .lock()
+            .await
+            .iter()
+            .map(|archived| archived.job.id.clone())
+            .collect();
+        archived.sort();
+        job_ids.sort();
+        assert_eq!(archived, job_ids);
+    }
+
+    #[tokio::test]
+    async fn test_preview_gc_deletes_nothing() {
+        let pool = create_pool(":memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+
+        let time_provider = Arc::new(SystemTimeProvider);
+        let job_repo = SqliteJobRepository::new(pool.clone(), time_provider.clone());
+        let maintenance = SqliteMaintenance::new(pool, time_provider.clone());
+
+        let day = 24 * 60 * 60 * 1000;
+        let now = time_provider.now_millis();
+        let dir = std::env::temp_dir().join(format!("semantica-gc-preview-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        // 10 days old (row goes), 5 days old (log goes), fresh (kept)
+        let mut jobs = Vec::new();
+        for (subject, age_days) in [("old", 10), ("middle", 5), ("fresh", 0)] {
+            let log_path = dir.join(format!("{}.log", subject));
+            std::fs::write(&log_path, "0123456789").unwrap();
+
+            let mut job = Job::new_test(
+                "test",
+                JobType::new("TEST"),
+                subject,
+                1,
+                JobPayload::new(serde_json::json!({ "subject": subject })),
+            );
+            job.state = JobState::Done;
+            job.finished_at = Some(now - age_days * day);
+            job.log_path = Some(log_path.to_string_lossy().into_owned());
+            job_repo.insert(&job).await.unwrap();
+            jobs.push((job, log_path));
+        }
+
+        let preview = maintenance.preview_gc(7, 3).await.unwrap();
+        assert_eq!(preview.job_ids, vec![jobs[0].0.id.clone()]);
+        assert_eq!(
+            preview.log_files,
+            vec![jobs[1].1.to_string_lossy().into_owned()]
+        );
+        assert!(preview.artifacts.is_empty(), "no artifact storage");
+        assert_eq!(preview.log_bytes, 10);
+        assert!(preview.row_bytes > 0);
+
+        // Nothing was touched
+        for (job, log_path) in &jobs {
+            assert!(log_path.exists());
+            assert!(job_repo.find_by_id(&job.id).await.unwrap().is_some());
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sample_success_logs_keeps_failures() {
+        let pool = create_pool(":memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+
+        let time_provider = Arc::new(SystemTimeProvider);
+        let job_repo = SqliteJobRepository::new(pool.clone(), time_provider.clone());
+        let maintenance = SqliteMaintenance::new(pool, time_provider.clone());
+
+        let two_days_ago = time_provider.now_millis() - 2 * 24 * 60 * 60 * 1000;
+        let dir = std::env::temp_dir().join(format!("semantica-sampling-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let mut jobs = Vec::new();
+        for (subject, state) in [("ok", JobState::Done), ("broken", JobState::Failed)] {
+            let log_path = dir.join(format!("{}.log", subject));
+            std::fs::write(&log_path, "output").unwrap();
+
+            let mut job = Job::new_test(
+                "test",
+                JobType::new("TEST"),
+                subject,
+                1,
+                JobPayload::new(serde_json::json!({})),
+            );
+            job.state = state;
+            job.finished_at = Some(two_days_ago);
+            job.log_path = Some(log_path.to_string_lossy().into_owned());
+            job_repo.insert(&job).await.unwrap();
+            jobs.push((job, log_path));
+        }
+
+        // Sample rate 0: every success log past 24h goes, failure logs stay
+        let dropped = maintenance.sample_success_logs(24, 0.0).await.unwrap();
+        assert_eq!(dropped, 1);
+
+        let (done, done_log) = &jobs[0];
+        assert!(!done_log.exists());
+        let found = job_repo.find_by_id(&done.id).await.unwrap().unwrap();
+        assert!(found.log_path.is_none());
+
+        let (_, failed_log) = &jobs[1];
+        assert!(failed_log.exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_success_log_sampling_rate() {
+        let kept = (0..10_000)
+            .filter(|i| is_success_log_sampled(&format!("job-{}", i), 0.05))
+            .count();
+        assert!((300..700).contains(&kept), "kept {} of 10000", kept);
+        assert!(is_success_log_sampled("job-1", 1.0));
+        assert!(!is_success_log_sampled("job-1", 0.0));
+    }
+
+    #[tokio::test]
+    async fn test_gc_artifacts_deletes_through_storage() {
+        use async_trait::async_trait;
+        use std::sync::Mutex;
+
+        #[derive(Default)]
+        struct RecordingStorage(Mutex<Vec<String>>);
+        #[async_trait]
+        impl ArtifactStorage for RecordingStorage {
+            fn name(&self) -> &str {
+                "recording"
+            }
+            async fn put(&self, key: &str, _content: Vec<u8>) -> Result<String> {
+                Ok(key.to_string())
+            }
+            async fn get(&self, _uri: &str) -> Result<Option<Vec<u8>>> {
+                Ok(None)
+            }
+            async fn delete(&self, uri: &str) -> Result<bool> {
+                self.0.lock().unwrap().push(uri.to_string());
+                Ok(true)
+            }
+        }
+
+        let pool = create_pool(":memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+
+        let time_provider = Arc::new(SystemTimeProvider);
+        let storage = Arc::new(RecordingStorage::default());
+        let maintenance = SqliteMaintenance::new(pool.clone(), time_provider.clone())
+            .with_artifact_storage(storage.clone());
+        let now = time_provider.now_millis();
+        let job_repo = SqliteJobRepository::new(pool, time_provider);
+
+        let mut ids = Vec::new();
+        for finished_at in [now - 10 * 24 * 60 * 60 * 1000, now] {
+            let mut job = Job::new_test(
+                "test_queue",
+                JobType::new("BUILD"),
+                "src/lib.rs",
+                1,
+                JobPayload::new(serde_json::json!({})),
+            );
+            job.state = JobState::Failed;
+            job.finished_at = Some(finished_at);
+            job.artifacts = Some(format!("s3://b/{}-a,s3://b/{}-b", job.id, job.id));
+            job_repo.insert(&job).await.unwrap();
+            ids.push(job.id);
+        }
+
+        let removed = maintenance.gc_artifacts(3).await.unwrap();
+        assert_eq!(removed, 2);
+        assert_eq!(
+            *storage.0.lock().unwrap(),
+            vec![
+                format!("s3://b/{}-a", ids[0]),
+                format!("s3://b/{}-b", ids[0])
+            ]
+        );
+        let old = job_repo.find_by_id(&ids[0]).await.unwrap().unwrap();
+        assert!(old.artifacts.is_none());
+        let recent = job_repo.find_by_id(&ids[1]).await.unwrap().unwrap();
+        assert!(recent.artifacts.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_report_history_keeps_newest() {
+        let pool = create_pool(":memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+
+        let maintenance = SqliteMaintenance::new(pool, Arc::new(SystemTimeProvider));
+
+        for started_at in 1..=5 {
+            let report = MaintenanceReport {
+                started_at,
+                duration_ms: 10,
+                jobs_deleted: started_at,
+                artifacts_removed: 0,
+                success_logs_dropped: 0,
+                vacuum_run: false,
+                mb_reclaimed: 0.0,
+                errors: vec![],
+            };
+            maintenance.record_report(&report, 3).await.unwrap();
+        }
+
+        let reports = maintenance.list_reports(10).await.unwrap();
+        let started: Vec<i64> = reports.iter().map(|r| r.started_at).collect();
+        assert_eq!(started, vec![5, 4, 3]);
     }
 }
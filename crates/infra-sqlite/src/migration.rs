@@ -50,6 +50,160 @@ pub async fn run_migrations(pool: &SqlitePool) -> Result<(), Box<dyn std::error:
         apply_migration(pool, include_str!("../migrations/004_add_dx_fields.sql")).await?;
     }
 
+    if current_version < 5 {
+        info!("Applying migration 005: Job type switch");
+        apply_migration(
+            pool,
+            include_str!("../migrations/005_add_job_type_switch.sql"),
+        )
+        .await?;
+    }
+
+    if current_version < 6 {
+        info!("Applying migration 006: Composite indexes");
+        apply_migration(
+            pool,
+            include_str!("../migrations/006_add_composite_indexes.sql"),
+        )
+        .await?;
+    }
+
+    if current_version < 7 {
+        info!("Applying migration 007: Idempotency keys");
+        apply_migration(
+            pool,
+            include_str!("../migrations/007_add_idempotency_keys.sql"),
+        )
+        .await?;
+    }
+
+    if current_version < 8 {
+        info!("Applying migration 008: Maintenance reports");
+        apply_migration(
+            pool,
+            include_str!("../migrations/008_add_maintenance_reports.sql"),
+        )
+        .await?;
+    }
+
+    if current_version < 9 {
+        info!("Applying migration 009: Job events");
+        apply_migration(pool, include_str!("../migrations/009_add_job_events.sql")).await?;
+    }
+
+    if current_version < 10 {
+        info!("Applying migration 010: Keyset pagination index");
+        apply_migration(pool, include_str!("../migrations/010_add_keyset_index.sql")).await?;
+    }
+
+    if current_version < 11 {
+        info!("Applying migration 011: Job leases");
+        apply_migration(pool, include_str!("../migrations/011_add_job_leases.sql")).await?;
+    }
+
+    if current_version < 12 {
+        info!("Applying migration 012: Forwarded jobs");
+        apply_migration(
+            pool,
+            include_str!("../migrations/012_add_forwarded_jobs.sql"),
+        )
+        .await?;
+    }
+
+    if current_version < 13 {
+        info!("Applying migration 013: Job class");
+        apply_migration(pool, include_str!("../migrations/013_add_job_class.sql")).await?;
+    }
+
+    if current_version < 14 {
+        info!("Applying migration 014: Fallback payloads");
+        apply_migration(
+            pool,
+            include_str!("../migrations/014_add_fallback_payloads.sql"),
+        )
+        .await?;
+    }
+
+    if current_version < 15 {
+        info!("Applying migration 015: Audit log");
+        apply_migration(pool, include_str!("../migrations/015_add_audit_log.sql")).await?;
+    }
+
+    if current_version < 16 {
+        info!("Applying migration 016: Job progress");
+        apply_migration(pool, include_str!("../migrations/016_add_job_progress.sql")).await?;
+    }
+
+    if current_version < 17 {
+        info!("Applying migration 017: Supersede index reorder");
+        apply_migration(
+            pool,
+            include_str!("../migrations/017_reorder_supersede_index.sql"),
+        )
+        .await?;
+    }
+
+    if current_version < 18 {
+        info!("Applying migration 018: Job artifact metadata");
+        apply_migration(
+            pool,
+            include_str!("../migrations/018_add_job_artifacts.sql"),
+        )
+        .await?;
+    }
+
+    if current_version < 19 {
+        info!("Applying migration 019: Subject result history");
+        apply_migration(
+            pool,
+            include_str!("../migrations/019_add_subject_history.sql"),
+        )
+        .await?;
+    }
+
+    if current_version < 20 {
+        info!("Applying migration 020: Throughput metrics history");
+        apply_migration(
+            pool,
+            include_str!("../migrations/020_add_metrics_snapshots.sql"),
+        )
+        .await?;
+    }
+
+    if current_version < 21 {
+        info!("Applying migration 021: Dead-letter queue");
+        apply_migration(pool, include_str!("../migrations/021_add_dead_letters.sql")).await?;
+    }
+
+    if current_version < 22 {
+        info!("Applying migration 022: Panic count");
+        apply_migration(pool, include_str!("../migrations/022_add_panic_count.sql")).await?;
+    }
+
+    if current_version < 23 {
+        info!("Applying migration 023: Retry backoff (next_attempt_at)");
+        apply_migration(
+            pool,
+            include_str!("../migrations/023_add_next_attempt_at.sql"),
+        )
+        .await?;
+    }
+
+    if current_version < 24 {
+        info!("Applying migration 024: Pop via subjects join");
+        apply_migration(pool, include_str!("../migrations/024_pop_subject_join.sql")).await?;
+    }
+
+    if current_version < 25 {
+        info!("Applying migration 025: Pop skips future schedule_at");
+        apply_migration(pool, include_str!("../migrations/025_pop_schedule_at.sql")).await?;
+    }
+
+    if current_version < 26 {
+        info!("Applying migration 026: TTL anchor queued_at");
+        apply_migration(pool, include_str!("../migrations/026_add_queued_at.sql")).await?;
+    }
+
     info!("All migrations applied successfully");
     Ok(())
 }
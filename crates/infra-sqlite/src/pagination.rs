@@ -0,0 +1,139 @@
+// Keyset Pagination Helpers
+//
+// Shared by list/search/history queries. Rows are ordered by (created_at, id)
+// and each page continues strictly after the last row of the previous one,
+// so unlike OFFSET the cost does not grow with the page number and deleting
+// rows (GC) between calls never shifts a row onto an already-served page.
+
+use semantica_core::error::{AppError, Result};
+use semantica_core::port::{Page, PageRequest};
+
+// Bump when the encoding changes so stale cursors are rejected, not misread
+const CURSOR_VERSION: &str = "k1";
+
+/// ORDER BY clause matching the keyset (append to the query)
+pub(crate) const KEYSET_ORDER_BY: &str = "ORDER BY created_at ASC, id ASC";
+
+/// WHERE fragment selecting rows after the cursor
+///
+/// Binds: created_at, created_at, id (see [`KeysetCursor::bind_values`])
+pub(crate) const KEYSET_AFTER: &str = "(created_at > ? OR (created_at = ? AND id > ?))";
+
+/// Position of the last row of a page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct KeysetCursor {
+    pub created_at: i64,
+    pub id: String,
+}
+
+impl KeysetCursor {
+    pub(crate) fn new(created_at: i64, id: impl Into<String>) -> Self {
+        Self {
+            created_at,
+            id: id.into(),
+        }
+    }
+
+    /// Opaque cursor string: `k1.<created_at>.<hex(id)>`
+    pub(crate) fn encode(&self) -> String {
+        let id_hex: String = self.id.bytes().map(|b| format!("{:02x}", b)).collect();
+        format!("{}.{}.{}", CURSOR_VERSION, self.created_at, id_hex)
+    }
+
+    pub(crate) fn decode(cursor: &str) -> Result<Self> {
+        let invalid = || AppError::Validation(format!("Invalid page cursor '{}'", cursor));
+
+        let mut parts = cursor.splitn(3, '.');
+        let (Some(version), Some(created_at), Some(id_hex)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        if version != CURSOR_VERSION || id_hex.is_empty() || id_hex.len() % 2 != 0 {
+            return Err(invalid());
+        }
+
+        let created_at: i64 = created_at.parse().map_err(|_| invalid())?;
+        let bytes = (0..id_hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&id_hex[i..i + 2], 16))
+            .collect::<std::result::Result<Vec<u8>, _>>()
+            .map_err(|_| invalid())?;
+        let id = String::from_utf8(bytes).map_err(|_| invalid())?;
+
+        Ok(Self { created_at, id })
+    }
+
+    /// Values for the `?` placeholders of [`KEYSET_AFTER`]
+    pub(crate) fn bind_values(&self) -> (i64, i64, &str) {
+        (self.created_at, self.created_at, &self.id)
+    }
+}
+
+/// Decode the request cursor (None on the first page)
+pub(crate) fn decode_after(page: &PageRequest) -> Result<Option<KeysetCursor>> {
+    page.after.as_deref().map(KeysetCursor::decode).transpose()
+}
+
+/// Number of rows to fetch: one extra to learn whether another page exists
+pub(crate) fn fetch_limit(page: &PageRequest) -> i64 {
+    page.effective_limit() as i64 + 1
+}
+
+/// Turn `fetch_limit` rows into a page, deriving `next_cursor` from the last kept row
+pub(crate) fn into_page<T>(
+    mut rows: Vec<T>,
+    page: &PageRequest,
+    key: impl Fn(&T) -> KeysetCursor,
+) -> Page<T> {
+    let limit = page.effective_limit();
+    let has_more = rows.len() > limit;
+    rows.truncate(limit);
+
+    let next_cursor = if has_more {
+        rows.last().map(|row| key(row).encode())
+    } else {
+        None
+    };
+
+    Page {
+        items: rows,
+        next_cursor,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let cursor = KeysetCursor::new(1_700_000_000_000, "job.with:odd-chars");
+        let encoded = cursor.encode();
+        assert!(encoded.starts_with("k1.1700000000000."));
+        assert_eq!(KeysetCursor::decode(&encoded).unwrap(), cursor);
+    }
+
+    #[test]
+    fn test_cursor_rejects_garbage() {
+        for bad in [
+            "", "k1", "k1.12", "k2.12.6a", "k1.x.6a", "k1.12.6", "k1.12.zz",
+        ] {
+            assert!(KeysetCursor::decode(bad).is_err(), "accepted '{}'", bad);
+        }
+    }
+
+    #[test]
+    fn test_into_page_sets_cursor_only_when_more_rows() {
+        let page = PageRequest::first(2);
+        let key = |n: &i64| KeysetCursor::new(*n, n.to_string());
+
+        let full = into_page(vec![1, 2, 3], &page, key);
+        assert_eq!(full.items, vec![1, 2]);
+        assert_eq!(full.next_cursor, Some(KeysetCursor::new(2, "2").encode()));
+
+        let last = into_page(vec![3], &page, key);
+        assert_eq!(last.items, vec![3]);
+        assert_eq!(last.next_cursor, None);
+    }
+}
@@ -0,0 +1,107 @@
+// Slow Query Logging
+//
+// Logs repository queries that exceed a configurable duration together with
+// their EXPLAIN QUERY PLAN, so pops and GCs that degrade as the table grows
+// show up in the logs instead of silently slowing down.
+
+use sqlx::{Row, SqlitePool};
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+// Slow query threshold default (configurable via env var)
+const DEFAULT_SLOW_QUERY_MS: u64 = 200;
+
+/// Slow query logger shared by SQLite adapters
+#[derive(Clone)]
+pub(crate) struct SlowQueryLog {
+    pool: SqlitePool,
+    threshold: Duration,
+}
+
+impl SlowQueryLog {
+    /// Create a logger with threshold from environment
+    ///
+    /// # Configuration
+    /// - `SEMANTICA_SLOW_QUERY_MS`: Threshold in milliseconds (default: 200)
+    pub(crate) fn from_env(pool: SqlitePool) -> Self {
+        let threshold_ms: u64 = std::env::var("SEMANTICA_SLOW_QUERY_MS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_SLOW_QUERY_MS);
+
+        Self::new(pool, Duration::from_millis(threshold_ms))
+    }
+
+    pub(crate) fn new(pool: SqlitePool, threshold: Duration) -> Self {
+        Self { pool, threshold }
+    }
+
+    /// Log the query with its plan if it ran longer than the threshold
+    ///
+    /// # Arguments
+    /// * `label` - Repository operation name (e.g. "pop_next")
+    /// * `sql` - SQL text that was executed (bind parameters are left unbound)
+    /// * `started` - When the query started
+    pub(crate) async fn observe(&self, label: &str, sql: &str, started: Instant) {
+        let elapsed = started.elapsed();
+        if elapsed < self.threshold {
+            return;
+        }
+
+        let plan = match self.explain(sql).await {
+            Ok(plan) => plan.join(" | "),
+            Err(e) => {
+                debug!(query = label, error = %e, "EXPLAIN QUERY PLAN failed");
+                "<unavailable>".to_string()
+            }
+        };
+
+        warn!(
+            query = label,
+            elapsed_ms = elapsed.as_millis() as u64,
+            threshold_ms = self.threshold.as_millis() as u64,
+            plan = %plan,
+            "Slow query"
+        );
+    }
+
+    /// Run EXPLAIN QUERY PLAN and return the plan detail lines
+    pub(crate) async fn explain(&self, sql: &str) -> Result<Vec<String>, sqlx::Error> {
+        let rows = sqlx::query(&format!("EXPLAIN QUERY PLAN {}", sql))
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows
+            .iter()
+            .filter_map(|row| row.try_get::<String, _>("detail").ok())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_pool, run_migrations};
+
+    #[tokio::test]
+    async fn test_explain_uses_composite_index() {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        let log = SlowQueryLog::new(pool, Duration::ZERO);
+
+        let plan = log
+            .explain(
+                "SELECT id FROM jobs WHERE queue = ? AND state = ? \
+                 ORDER BY priority DESC, created_at ASC LIMIT 1",
+            )
+            .await
+            .unwrap();
+
+        assert!(
+            plan.iter()
+                .any(|line| line.contains("idx_jobs_pop_covering")),
+            "unexpected plan: {:?}",
+            plan
+        );
+    }
+}
@@ -1,12 +1,39 @@
 // SQLite Transaction Implementation
 
+use crate::job_repository::{
+    fallback_payloads_json, RAISE_GENERATION_SQL, RECORD_SUPERSEDE_EVENTS_SQL,
+};
 use async_trait::async_trait;
+use semantica_core::domain::job_event::actor;
 use semantica_core::domain::{Job, JobState};
 use semantica_core::error::{AppError, Result};
 use semantica_core::port::{JobRepositoryTransaction, TimeProvider, Transaction};
 use sqlx::{Sqlite, Transaction as SqlxTransaction};
 use std::sync::Arc;
 
+// Enqueue fast path statements (static SQL, so sqlx's per-connection
+// statement cache prepares each of them once)
+const NEXT_GENERATION_SQL: &str = r#"
+    INSERT INTO subjects (subject_key, latest_generation) VALUES (?, 1)
+    ON CONFLICT(subject_key) DO UPDATE SET latest_generation = latest_generation + 1
+    RETURNING latest_generation
+    "#;
+
+const SUPERSEDE_OLDER_SQL: &str = r#"
+    UPDATE jobs
+    SET state = ?, finished_at = ?
+    WHERE subject_key = ? AND generation < ? AND state = ?
+    RETURNING id
+    "#;
+
+/// One `supersede` event per id of the JSON array bound last.
+/// Binds: state, actor, generation, now, ids
+const RECORD_SUPERSEDED_IDS_SQL: &str = r#"
+    INSERT INTO job_events (job_id, state, actor, reason, created_at)
+    SELECT value, ?, ?, 'superseded by generation ' || ?, ?
+    FROM json_each(?)
+    "#;
+
 pub struct SqliteJobTransaction<'a> {
     tx: SqlxTransaction<'a, Sqlite>,
     time_provider: Arc<dyn TimeProvider>,
@@ -96,6 +123,7 @@ impl JobRepositoryTransaction for SqliteJobTransaction<'_> {
     async fn insert(&mut self, job: &Job) -> Result<()> {
         let execution_mode_str = job.execution_mode.as_ref().map(|m| m.to_string());
         let env_vars_str = job.env_vars.as_ref().map(|v| v.to_string());
+        let fallback_payloads_str = fallback_payloads_json(job);
 
         sqlx::query(
             r#"
@@ -103,10 +131,13 @@ impl JobRepositoryTransaction for SqliteJobTransaction<'_> {
                 id, queue, job_type, subject_key, generation,
                 priority, state, created_at, started_at, finished_at,
                 payload, log_path,
-                execution_mode, pid, env_vars,
-                attempts, max_attempts, backoff_factor,
-                deadline, ttl_ms, trace_id
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                execution_mode, job_class, pid, env_vars,
+                attempts, max_attempts, backoff_factor, fallback_payloads,
+                deadline, ttl_ms, trace_id,
+                schedule_at, wait_for_idle, require_charging, wait_for_event,
+                user_tag, parent_job_id, chain_group_id, result_summary, artifacts,
+                queued_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&job.id)
@@ -123,14 +154,28 @@ impl JobRepositoryTransaction for SqliteJobTransaction<'_> {
         .bind(&job.log_path)
         // Phase 2 fields
         .bind(&execution_mode_str)
+        .bind(job.job_class.to_string())
         .bind(job.pid)
         .bind(&env_vars_str)
         .bind(job.attempts)
         .bind(job.max_attempts)
         .bind(job.backoff_factor)
+        .bind(&fallback_payloads_str)
         .bind(job.deadline)
         .bind(job.ttl_ms)
         .bind(&job.trace_id)
+        // Phase 3 fields
+        .bind(job.schedule_at)
+        .bind(if job.wait_for_idle { 1 } else { 0 })
+        .bind(if job.require_charging { 1 } else { 0 })
+        .bind(&job.wait_for_event)
+        // Phase 4 fields
+        .bind(&job.user_tag)
+        .bind(&job.parent_job_id)
+        .bind(&job.chain_group_id)
+        .bind(&job.result_summary)
+        .bind(&job.artifacts)
+        .bind(job.queued_at)
         .execute(&mut *self.tx)
         .await
         .map_err(|e| semantica_core::error::AppError::Database(e.to_string()))?;
@@ -143,6 +188,18 @@ impl JobRepositoryTransaction for SqliteJobTransaction<'_> {
         let state_superseded = JobState::Superseded.to_string();
         let state_queued = JobState::Queued.to_string();
 
+        sqlx::query(RECORD_SUPERSEDE_EVENTS_SQL)
+            .bind(&state_superseded)
+            .bind(actor::SUPERSEDE)
+            .bind(below_generation)
+            .bind(now)
+            .bind(subject_key)
+            .bind(below_generation)
+            .bind(&state_queued)
+            .execute(&mut *self.tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to record supersede events: {}", e)))?;
+
         let result = sqlx::query(
             r#"
             UPDATE jobs
@@ -173,4 +230,135 @@ impl JobRepositoryTransaction for SqliteJobTransaction<'_> {
 
         Ok(result.rows_affected())
     }
+
+    async fn next_generation(&mut self, subject_key: &str) -> Result<i64> {
+        sqlx::query_scalar(NEXT_GENERATION_SQL)
+            .bind(subject_key)
+            .fetch_one(&mut *self.tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to claim generation: {}", e)))
+    }
+
+    async fn insert_superseding(&mut self, job: &Job) -> Result<u64> {
+        let now = self.time_provider.now_millis();
+
+        // Generation 1 has nothing to supersede
+        let superseded: Vec<String> = if job.generation > 1 {
+            sqlx::query_scalar(SUPERSEDE_OLDER_SQL)
+                .bind(JobState::Superseded.to_string())
+                .bind(now)
+                .bind(&job.subject_key)
+                .bind(job.generation)
+                .bind(JobState::Queued.to_string())
+                .fetch_all(&mut *self.tx)
+                .await
+                .map_err(|e| AppError::Database(format!("Failed to mark superseded: {}", e)))?
+        } else {
+            Vec::new()
+        };
+
+        if !superseded.is_empty() {
+            let ids = serde_json::to_string(&superseded)
+                .map_err(|e| AppError::Internal(format!("Failed to encode job ids: {}", e)))?;
+            sqlx::query(RECORD_SUPERSEDED_IDS_SQL)
+                .bind(JobState::Superseded.to_string())
+                .bind(actor::SUPERSEDE)
+                .bind(job.generation)
+                .bind(now)
+                .bind(ids)
+                .execute(&mut *self.tx)
+                .await
+                .map_err(|e| {
+                    AppError::Database(format!("Failed to record supersede events: {}", e))
+                })?;
+        }
+
+        self.insert(job).await?;
+
+        Ok(superseded.len() as u64)
+    }
+
+    async fn is_job_type_disabled(&mut self, job_type: &str) -> Result<bool> {
+        let disabled: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM disabled_job_types WHERE job_type = ?")
+                .bind(job_type)
+                .fetch_one(&mut *self.tx)
+                .await
+                .map_err(|e| AppError::Database(format!("Failed to check job type: {}", e)))?;
+
+        Ok(disabled > 0)
+    }
+
+    async fn find_by_idempotency_key(&mut self, key: &str) -> Result<Option<String>> {
+        // A key whose job is gone (deleted, collected) is forgotten: the request runs again
+        sqlx::query(
+            "DELETE FROM idempotency_keys WHERE key = ? AND job_id NOT IN (SELECT id FROM jobs)",
+        )
+        .bind(key)
+        .execute(&mut *self.tx)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to drop stale idempotency key: {}", e)))?;
+
+        sqlx::query_scalar("SELECT job_id FROM idempotency_keys WHERE key = ?")
+            .bind(key)
+            .fetch_optional(&mut *self.tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to look up idempotency key: {}", e)))
+    }
+
+    async fn record_idempotency_key(&mut self, key: &str, job_id: &str) -> Result<()> {
+        sqlx::query("INSERT INTO idempotency_keys (key, job_id, created_at) VALUES (?, ?, ?)")
+            .bind(key)
+            .bind(job_id)
+            .bind(self.time_provider.now_millis())
+            .execute(&mut *self.tx)
+            .await
+            .map_err(|e| map_transaction_error("record idempotency key", e))?;
+        Ok(())
+    }
+
+    async fn replace_queued(&mut self, job: &Job) -> Result<bool> {
+        let result = sqlx::query(
+            "UPDATE jobs SET payload = ?, fallback_payloads = ?, priority = ?, trace_id = ?,
+                 schedule_at = ?, deadline = ?, ttl_ms = ?, max_attempts = ?, backoff_factor = ?,
+                 wait_for_idle = ?, require_charging = ?, execution_mode = ?,
+                 user_tag = ?, parent_job_id = ?, chain_group_id = ?
+             WHERE id = ? AND job_type = ? AND job_class = ? AND state = ?",
+        )
+        .bind(job.payload.as_value().to_string())
+        .bind(fallback_payloads_json(job))
+        .bind(job.priority)
+        .bind(&job.trace_id)
+        .bind(job.schedule_at)
+        .bind(job.deadline)
+        .bind(job.ttl_ms)
+        .bind(job.max_attempts)
+        .bind(job.backoff_factor)
+        .bind(job.wait_for_idle)
+        .bind(job.require_charging)
+        .bind(job.execution_mode.as_ref().map(|m| m.to_string()))
+        .bind(&job.user_tag)
+        .bind(&job.parent_job_id)
+        .bind(&job.chain_group_id)
+        .bind(&job.id)
+        .bind(job.job_type.as_str())
+        .bind(job.job_class.to_string())
+        .bind(JobState::Queued.to_string())
+        .execute(&mut *self.tx)
+        .await
+        .map_err(|e| map_transaction_error("replace queued job", e))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn raise_generation(&mut self, subject_key: &str, generation: i64) -> Result<()> {
+        sqlx::query(RAISE_GENERATION_SQL)
+            .bind(subject_key)
+            .bind(generation)
+            .execute(&mut *self.tx)
+            .await
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,141 @@
+// Local Artifact Storage - Artifacts as files in one directory
+//
+// URIs are the absolute file paths, so artifacts registered before storage
+// backends existed (plain paths under the artifact dir) stay readable.
+
+use async_trait::async_trait;
+use semantica_core::error::{AppError, Result};
+use semantica_core::port::ArtifactStorage;
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+/// Stores artifacts as `<dir>/<key>`
+pub struct LocalArtifactStorage {
+    dir: PathBuf,
+}
+
+impl LocalArtifactStorage {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Resolve a URI, refusing paths outside the artifact dir
+    fn resolve<'a>(&self, uri: &'a str) -> Result<&'a Path> {
+        let path = Path::new(uri);
+        if path.parent() != Some(self.dir.as_path()) {
+            return Err(AppError::Validation(format!(
+                "Artifact {} is not stored in {}",
+                uri,
+                self.dir.display()
+            )));
+        }
+        Ok(path)
+    }
+}
+
+#[async_trait]
+impl ArtifactStorage for LocalArtifactStorage {
+    fn name(&self) -> &str {
+        "local"
+    }
+
+    async fn put(&self, key: &str, content: Vec<u8>) -> Result<String> {
+        // Flat layout: a key is a single file name
+        if key.is_empty() || key.contains(['/', '\\']) || key == "." || key == ".." {
+            return Err(AppError::Validation(format!(
+                "Invalid artifact key: '{}'",
+                key
+            )));
+        }
+
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to create artifact dir: {}", e)))?;
+
+        let path = self.dir.join(key);
+        tokio::fs::write(&path, content).await.map_err(|e| {
+            AppError::Internal(format!(
+                "Failed to write artifact {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        Ok(path.to_string_lossy().into_owned())
+    }
+
+    async fn get(&self, uri: &str) -> Result<Option<Vec<u8>>> {
+        match tokio::fs::read(self.resolve(uri)?).await {
+            Ok(content) => Ok(Some(content)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(AppError::Internal(format!(
+                "Failed to read artifact {}: {}",
+                uri, e
+            ))),
+        }
+    }
+
+    async fn get_range(&self, uri: &str, offset: u64, len: usize) -> Result<Option<Vec<u8>>> {
+        let path = self.resolve(uri)?;
+        let read = async {
+            let mut file = tokio::fs::File::open(path).await?;
+            file.seek(SeekFrom::Start(offset)).await?;
+            let mut content = Vec::new();
+            file.take(len as u64).read_to_end(&mut content).await?;
+            Ok::<_, std::io::Error>(content)
+        };
+        match read.await {
+            Ok(content) => Ok(Some(content)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(AppError::Internal(format!(
+                "Failed to read artifact {}: {}",
+                uri, e
+            ))),
+        }
+    }
+
+    async fn delete(&self, uri: &str) -> Result<bool> {
+        match tokio::fs::remove_file(self.resolve(uri)?).await {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(AppError::Internal(format!(
+                "Failed to delete artifact {}: {}",
+                uri, e
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_put_get_delete_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("semantica-artifacts-{}", std::process::id()));
+        let storage = LocalArtifactStorage::new(&dir);
+
+        let uri = storage
+            .put("job-1.tar.gz", b"bundle".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(uri, dir.join("job-1.tar.gz").to_string_lossy());
+        assert_eq!(storage.get(&uri).await.unwrap(), Some(b"bundle".to_vec()));
+        assert_eq!(
+            storage.get_range(&uri, 2, 3).await.unwrap(),
+            Some(b"ndl".to_vec())
+        );
+        assert_eq!(storage.get_range(&uri, 10, 3).await.unwrap(), Some(vec![]));
+
+        assert!(storage.delete(&uri).await.unwrap());
+        assert!(!storage.delete(&uri).await.unwrap());
+        assert_eq!(storage.get(&uri).await.unwrap(), None);
+
+        // Nothing outside the artifact dir is reachable
+        assert!(storage.put("../escape", vec![]).await.is_err());
+        assert!(storage.get("/etc/passwd").await.is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
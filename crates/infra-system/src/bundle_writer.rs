@@ -0,0 +1,152 @@
+// Tar Bundle Writer - Failure bundles as .tar.gz archives
+//
+// Layout: manifest.json (engine version, collection time, system metrics),
+// job.json, events.json and log_tail.jsonl (last BUNDLE_LOG_LINES log lines).
+
+use async_trait::async_trait;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use semantica_core::error::{AppError, Result};
+use semantica_core::port::{BundleWriter, FailureBundle, BUNDLE_LOG_LINES};
+use std::path::Path;
+
+/// Encodes failure bundles as .tar.gz archives
+#[derive(Debug, Default)]
+pub struct TarBundleWriter;
+
+impl TarBundleWriter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl BundleWriter for TarBundleWriter {
+    fn extension(&self) -> &str {
+        "tar.gz"
+    }
+
+    async fn write(&self, bundle: &FailureBundle) -> Result<Vec<u8>> {
+        let log_tail = match &bundle.job.log_path {
+            Some(log_path) => read_tail(Path::new(log_path), BUNDLE_LOG_LINES).await,
+            None => String::new(),
+        };
+        let files = vec![
+            (
+                "manifest.json",
+                to_json(&serde_json::json!({
+                    "job_id": bundle.job.id,
+                    "engine_version": bundle.engine_version,
+                    "collected_at": bundle.collected_at,
+                    "system_metrics": bundle.system_metrics,
+                }))?,
+            ),
+            ("job.json", to_json(&bundle.job)?),
+            ("events.json", to_json(&bundle.events)?),
+            ("log_tail.jsonl", log_tail.into_bytes()),
+        ];
+
+        tokio::task::spawn_blocking(move || write_archive(&files))
+            .await
+            .map_err(|e| AppError::Internal(format!("Bundle writer panicked: {}", e)))?
+            .map_err(|e| AppError::Internal(format!("Failed to write bundle: {}", e)))
+    }
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<Vec<u8>> {
+    serde_json::to_vec_pretty(value)
+        .map_err(|e| AppError::Internal(format!("Failed to serialize bundle: {}", e)))
+}
+
+/// Last `lines` lines of a log file (empty if it cannot be read)
+async fn read_tail(path: &Path, lines: usize) -> String {
+    let content = tokio::fs::read_to_string(path).await.unwrap_or_default();
+    let all: Vec<&str> = content.lines().collect();
+    let start = all.len().saturating_sub(lines);
+    all[start..]
+        .iter()
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+fn write_archive(files: &[(&str, Vec<u8>)]) -> std::io::Result<Vec<u8>> {
+    let mut archive = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+
+    for (name, content) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        archive.append_data(&mut header, name, content.as_slice())?;
+    }
+
+    archive.into_inner()?.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::port::SystemMetrics;
+    use std::io::Read;
+
+    #[tokio::test]
+    async fn test_bundle_contains_job_and_log_tail() {
+        let dir = std::env::temp_dir().join(format!("semantica-bundle-{}", std::process::id()));
+        let log_path = dir.join("job.log");
+        std::fs::create_dir_all(&dir).unwrap();
+        let log: String = (0..600).map(|i| format!("line {}\n", i)).collect();
+        std::fs::write(&log_path, log).unwrap();
+
+        let mut job = Job::new_test(
+            "test_queue",
+            JobType::new("BUILD"),
+            "src/lib.rs",
+            1,
+            JobPayload::new(serde_json::json!({"command": "false"})),
+        );
+        job.log_path = Some(log_path.to_string_lossy().into_owned());
+
+        let bundle = FailureBundle {
+            job,
+            events: vec![],
+            system_metrics: SystemMetrics {
+                cpu_usage_percent: 12.5,
+                memory_used_mb: 1024,
+                memory_total_mb: 2048,
+                disk_used_gb: 10,
+                disk_total_gb: 100,
+                battery_percent: None,
+                is_charging: None,
+            },
+            engine_version: "0.1.0".to_string(),
+            collected_at: 42,
+        };
+
+        let content = TarBundleWriter::new().write(&bundle).await.unwrap();
+
+        let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(content.as_slice()));
+        let mut names = Vec::new();
+        for entry in archive.entries().unwrap() {
+            let mut entry = entry.unwrap();
+            let name = entry.path().unwrap().to_string_lossy().into_owned();
+            let mut content = String::new();
+            entry.read_to_string(&mut content).unwrap();
+            match name.as_str() {
+                "manifest.json" => assert!(content.contains("\"engine_version\": \"0.1.0\"")),
+                "log_tail.jsonl" => {
+                    assert_eq!(content.lines().count(), BUNDLE_LOG_LINES);
+                    assert_eq!(content.lines().next(), Some("line 100"));
+                }
+                _ => {}
+            }
+            names.push(name);
+        }
+        assert_eq!(
+            names,
+            ["manifest.json", "job.json", "events.json", "log_tail.jsonl"]
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
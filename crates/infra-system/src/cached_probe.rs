@@ -0,0 +1,147 @@
+// Cached SystemProbe
+// A background sampler refreshes metrics at a fixed cadence and callers are
+// served the last sample, so the worker loop and scheduler checks no longer
+// each pay for a full CPU/memory/disk refresh
+
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::time::interval;
+use tracing::info;
+
+use crate::idle_tracker::IdleTracker;
+use semantica_core::application::worker::constants::IDLE_TRACKER_MAX_SAMPLES;
+use semantica_core::port::system_probe::{SystemMetrics, SystemProbe};
+
+/// SystemProbe serving cached samples of another probe
+///
+/// Samples older than `max_age` (sampler not running or stalled) are
+/// refreshed inline on the next call. Process memory is always read live.
+pub struct CachedSystemProbe {
+    inner: Arc<dyn SystemProbe>,
+    max_age: Duration,
+    last_sample: Mutex<Option<(Instant, SystemMetrics)>>,
+    idle_tracker: Mutex<IdleTracker>,
+}
+
+impl CachedSystemProbe {
+    /// Cache `inner`, treating samples older than `max_age` as stale
+    pub fn new(inner: Arc<dyn SystemProbe>, max_age: Duration) -> Self {
+        Self {
+            inner,
+            max_age,
+            last_sample: Mutex::new(None),
+            idle_tracker: Mutex::new(IdleTracker::new(IDLE_TRACKER_MAX_SAMPLES)),
+        }
+    }
+
+    /// Take one sample from the inner probe and cache it
+    pub async fn sample(&self) -> SystemMetrics {
+        let metrics = self.inner.get_metrics().await;
+        self.idle_tracker
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .record(metrics.cpu_usage_percent);
+        *self.last_sample.lock().unwrap_or_else(|e| e.into_inner()) =
+            Some((Instant::now(), metrics.clone()));
+        metrics
+    }
+
+    fn fresh_sample(&self) -> Option<SystemMetrics> {
+        self.last_sample
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .filter(|(at, _)| at.elapsed() <= self.max_age)
+            .map(|(_, metrics)| metrics.clone())
+    }
+
+    /// Run the sampling loop (background task)
+    ///
+    /// Should be spawned in tokio::spawn
+    pub async fn run(self: Arc<Self>, every: Duration) {
+        info!(
+            every_ms = every.as_millis() as u64,
+            "System probe sampler started"
+        );
+
+        let mut tick = interval(every);
+        loop {
+            tick.tick().await;
+            self.sample().await;
+        }
+    }
+}
+
+#[async_trait]
+impl SystemProbe for CachedSystemProbe {
+    async fn get_metrics(&self) -> SystemMetrics {
+        match self.fresh_sample() {
+            Some(metrics) => metrics,
+            None => self.sample().await,
+        }
+    }
+
+    async fn is_idle(&self, cpu_threshold: f32, duration_secs: u64) -> bool {
+        if self.fresh_sample().is_none() {
+            self.sample().await;
+        }
+
+        self.idle_tracker
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_idle(cpu_threshold, Duration::from_secs(duration_secs))
+    }
+
+    async fn process_tree_memory_mb(&self, pids: &[u32]) -> u64 {
+        self.inner.process_tree_memory_mb(pids).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingProbe {
+        polls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl SystemProbe for CountingProbe {
+        async fn get_metrics(&self) -> SystemMetrics {
+            let polls = self.polls.fetch_add(1, Ordering::SeqCst) + 1;
+            SystemMetrics {
+                cpu_usage_percent: polls as f32,
+                memory_used_mb: 0,
+                memory_total_mb: 0,
+                disk_used_gb: 0,
+                disk_total_gb: 0,
+                battery_percent: None,
+                is_charging: None,
+            }
+        }
+
+        async fn is_idle(&self, _cpu_threshold: f32, _duration_secs: u64) -> bool {
+            unreachable!("Idle state comes from the cached samples")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_callers_share_cached_sample_until_stale() {
+        let inner = Arc::new(CountingProbe {
+            polls: AtomicUsize::new(0),
+        });
+        let probe = CachedSystemProbe::new(inner.clone(), Duration::from_millis(50));
+
+        for _ in 0..10 {
+            assert_eq!(probe.get_metrics().await.cpu_usage_percent, 1.0);
+        }
+        assert!(probe.is_idle(10.0, 60).await);
+        assert_eq!(inner.polls.load(Ordering::SeqCst), 1);
+
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        assert_eq!(probe.get_metrics().await.cpu_usage_percent, 2.0);
+        assert_eq!(inner.polls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,80 @@
+// Environment manifest builder
+// Captures the resolved allowlisted environment, executor version and host info
+
+use std::collections::{BTreeMap, HashMap};
+
+use semantica_core::port::EnvironmentManifest;
+use sysinfo::System;
+
+const EXECUTOR_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// FNV-1a 64-bit parameters (stable across Rust versions, unlike DefaultHasher)
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Build the manifest for one execution attempt
+///
+/// # Arguments
+/// * `executor` - Executor name (e.g. "subprocess")
+/// * `allowlist` - Environment variables the child may see (ADR-040)
+/// * `overrides` - Variables provided by the job payload (take precedence over the daemon env)
+pub fn build_manifest(
+    executor: &str,
+    allowlist: &[String],
+    overrides: &HashMap<String, String>,
+) -> EnvironmentManifest {
+    let env_hashes: BTreeMap<String, String> = allowlist
+        .iter()
+        .filter_map(|name| {
+            let value = overrides
+                .get(name)
+                .cloned()
+                .or_else(|| std::env::var(name).ok())?;
+            Some((name.clone(), hash_value(&value)))
+        })
+        .collect();
+
+    EnvironmentManifest {
+        executor: executor.to_string(),
+        executor_version: EXECUTOR_VERSION.to_string(),
+        hostname: System::host_name(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        env_hashes,
+    }
+}
+
+/// Hash an environment value (values themselves are never recorded)
+fn hash_value(value: &str) -> String {
+    let hash = value.bytes().fold(FNV_OFFSET_BASIS, |acc, byte| {
+        (acc ^ byte as u64).wrapping_mul(FNV_PRIME)
+    });
+    format!("fnv1a:{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_hashes_only_allowlisted_values() {
+        let mut overrides = HashMap::new();
+        overrides.insert("ALLOWED_VAR".to_string(), "secret".to_string());
+        overrides.insert("BLOCKED_VAR".to_string(), "value".to_string());
+
+        let manifest = build_manifest("subprocess", &["ALLOWED_VAR".to_string()], &overrides);
+
+        assert_eq!(manifest.executor, "subprocess");
+        assert_eq!(manifest.env_hashes.len(), 1);
+        let hash = &manifest.env_hashes["ALLOWED_VAR"];
+        assert_eq!(hash, &hash_value("secret"));
+        assert!(!hash.contains("secret"));
+    }
+
+    #[test]
+    fn test_hash_is_stable() {
+        // FNV-1a reference value for "a"
+        assert_eq!(hash_value("a"), "fnv1a:af63dc4c8601ec8c");
+        assert_ne!(hash_value("a"), hash_value("b"));
+    }
+}
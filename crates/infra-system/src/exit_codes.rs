@@ -0,0 +1,188 @@
+// Exit code mapping - What a subprocess's exit code means for the job
+//
+// By default 0 succeeds, 126/127 (not executable / command not found) fail
+// for good and anything else is a retryable failure. Tools with their own
+// conventions (2 = "nothing to do", 3 = "retry later") declare them per job
+// (`exit_codes` in the payload) or per job type (SEMANTICA_EXIT_CODES).
+
+use semantica_core::port::{ErrorClass, ExecutionStatus};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// Outcome an exit code stands for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExitOutcome {
+    /// Job is DONE
+    Success,
+    /// Job fails without retries (FAILED, DLQ)
+    Failed,
+    /// Job fails and the retry policy applies
+    Retry,
+}
+
+impl ExitOutcome {
+    /// Status and error class reported for this outcome
+    pub(crate) fn status(self) -> (ExecutionStatus, Option<ErrorClass>) {
+        match self {
+            Self::Success => (ExecutionStatus::Success, None),
+            Self::Failed => (ExecutionStatus::Failed, Some(ErrorClass::Fatal)),
+            Self::Retry => (ExecutionStatus::Failed, Some(ErrorClass::Retryable)),
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "success" => Some(Self::Success),
+            "failed" => Some(Self::Failed),
+            "retry" => Some(Self::Retry),
+            _ => None,
+        }
+    }
+}
+
+/// Exit codes with a non-default outcome
+pub type ExitCodeMap = BTreeMap<i32, ExitOutcome>;
+
+/// Default outcome of an exit code (None when the process was killed by a signal)
+pub(crate) fn default_outcome(code: Option<i32>) -> ExitOutcome {
+    match code {
+        Some(0) => ExitOutcome::Success,
+        // Shell convention: 126 not executable, 127 command not found
+        Some(126 | 127) => ExitOutcome::Failed,
+        _ => ExitOutcome::Retry,
+    }
+}
+
+/// Exit code mapping of a job's payload (`"exit_codes": {"2": "success"}`), None if absent
+pub(crate) fn from_payload(payload: &serde_json::Value) -> Result<Option<ExitCodeMap>, String> {
+    let Some(value) = payload.get("exit_codes") else {
+        return Ok(None);
+    };
+    let raw: BTreeMap<String, ExitOutcome> =
+        serde_json::from_value(value.clone()).map_err(|e| {
+            format!(
+                "Invalid 'exit_codes' (expected {{\"<code>\": \"success|failed|retry\"}}): {}",
+                e
+            )
+        })?;
+    raw.into_iter()
+        .map(|(code, outcome)| {
+            code.trim()
+                .parse()
+                .map(|code| (code, outcome))
+                .map_err(|_| format!("Invalid exit code '{}' in 'exit_codes'", code))
+        })
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
+/// Exit code mappings by job type
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExitCodeMaps {
+    by_job_type: HashMap<String, ExitCodeMap>,
+}
+
+impl ExitCodeMaps {
+    /// Parse `JOB_TYPE:code=outcome/code=outcome,...`
+    /// (e.g. `LINT:2=success/3=retry,BUILD:4=failed`)
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let mut maps = Self::default();
+
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let invalid = || {
+                format!(
+                    "Invalid exit code mapping '{}' (expected job_type:code=success|failed|retry[/...])",
+                    entry
+                )
+            };
+            let (job_type, rules) = entry.split_once(':').ok_or_else(invalid)?;
+            let job_type = job_type.trim();
+            if job_type.is_empty() {
+                return Err(invalid());
+            }
+
+            let map = maps.by_job_type.entry(job_type.to_string()).or_default();
+            for rule in rules.split('/') {
+                let (code, outcome) = rule.split_once('=').ok_or_else(invalid)?;
+                let code = code.trim().parse().map_err(|_| invalid())?;
+                let outcome = ExitOutcome::parse(outcome).ok_or_else(invalid)?;
+                map.insert(code, outcome);
+            }
+        }
+
+        Ok(maps)
+    }
+
+    /// Mapping of a job type (None = defaults only)
+    pub fn for_job_type(&self, job_type: &str) -> Option<&ExitCodeMap> {
+        self.by_job_type.get(job_type)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_job_type.is_empty()
+    }
+}
+
+/// Outcome of `code`: the job's mapping first, then its type's, then the default
+pub(crate) fn resolve(
+    code: Option<i32>,
+    job_map: Option<&ExitCodeMap>,
+    type_map: Option<&ExitCodeMap>,
+) -> ExitOutcome {
+    code.and_then(|code| {
+        job_map
+            .and_then(|map| map.get(&code))
+            .or_else(|| type_map.and_then(|map| map.get(&code)))
+            .copied()
+    })
+    .unwrap_or_else(|| default_outcome(code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_spec() {
+        let maps = ExitCodeMaps::from_spec("LINT:2=success/3=retry, BUILD:4=Failed").unwrap();
+        let lint = maps.for_job_type("LINT").unwrap();
+        assert_eq!(lint.get(&2), Some(&ExitOutcome::Success));
+        assert_eq!(lint.get(&3), Some(&ExitOutcome::Retry));
+        assert_eq!(
+            maps.for_job_type("BUILD").unwrap().get(&4),
+            Some(&ExitOutcome::Failed)
+        );
+        assert!(maps.for_job_type("INDEX").is_none());
+
+        assert!(ExitCodeMaps::from_spec("").unwrap().is_empty());
+        assert!(ExitCodeMaps::from_spec("LINT").is_err());
+        assert!(ExitCodeMaps::from_spec("LINT:x=success").is_err());
+        assert!(ExitCodeMaps::from_spec("LINT:2=maybe").is_err());
+    }
+
+    #[test]
+    fn test_job_mapping_wins_over_type_mapping() {
+        let job_map = from_payload(&serde_json::json!({"exit_codes": {"2": "retry"}}))
+            .unwrap()
+            .unwrap();
+        let type_map = ExitCodeMap::from([(2, ExitOutcome::Success), (3, ExitOutcome::Failed)]);
+
+        assert_eq!(
+            resolve(Some(2), Some(&job_map), Some(&type_map)),
+            ExitOutcome::Retry
+        );
+        assert_eq!(
+            resolve(Some(3), Some(&job_map), Some(&type_map)),
+            ExitOutcome::Failed
+        );
+        assert_eq!(resolve(Some(0), None, None), ExitOutcome::Success);
+        assert_eq!(resolve(Some(127), None, None), ExitOutcome::Failed);
+        assert_eq!(resolve(Some(1), None, None), ExitOutcome::Retry);
+        assert_eq!(resolve(None, None, None), ExitOutcome::Retry, "signal");
+
+        assert_eq!(from_payload(&serde_json::json!({})).unwrap(), None);
+        assert!(from_payload(&serde_json::json!({"exit_codes": {"two": "retry"}})).is_err());
+        assert!(from_payload(&serde_json::json!({"exit_codes": {"2": "maybe"}})).is_err());
+    }
+}
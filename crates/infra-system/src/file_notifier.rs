@@ -0,0 +1,85 @@
+// File Notifier - Appends notifications to a JSON Lines file
+// External tooling (log shippers, editor plugins) tails the file
+
+use async_trait::async_trait;
+use semantica_core::error::{AppError, Result};
+use semantica_core::port::{Notification, Notifier};
+use std::path::PathBuf;
+use tokio::io::AsyncWriteExt;
+
+/// Notifier writing one JSON object per line
+pub struct FileNotifier {
+    path: PathBuf,
+}
+
+impl FileNotifier {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl Notifier for FileNotifier {
+    async fn notify(&self, notification: &Notification) -> Result<()> {
+        let mut line = serde_json::to_string(notification)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize notification: {}", e)))?;
+        line.push('\n');
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| {
+                AppError::Internal(format!(
+                    "Failed to open notification file {}: {}",
+                    self.path.display(),
+                    e
+                ))
+            })?;
+
+        file.write_all(line.as_bytes())
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to write notification: {}", e)))?;
+        // tokio::fs::File completes writes in the background; flush before dropping
+        file.flush()
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to flush notification: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_appends_json_lines() {
+        let path =
+            std::env::temp_dir().join(format!("semantica-notify-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let notifier = FileNotifier::new(&path);
+
+        for i in 0..2 {
+            notifier
+                .notify(&Notification {
+                    topic: "maintenance.report".to_string(),
+                    timestamp: i,
+                    payload: serde_json::json!({"jobs_deleted": i}),
+                })
+                .await
+                .unwrap();
+        }
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<Notification> = contents
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1].payload["jobs_deleted"], 1);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
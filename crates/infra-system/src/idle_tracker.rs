@@ -0,0 +1,75 @@
+// Idle tracker shared by the SystemProbe backends
+// Keeps recent CPU samples so "idle for N seconds" can be answered without
+// sampling N seconds at once
+
+use std::time::{Duration, Instant};
+
+/// Tracks CPU usage history for idle detection
+pub(crate) struct IdleTracker {
+    samples: Vec<(Instant, f32)>,
+    max_samples: usize,
+}
+
+impl IdleTracker {
+    pub(crate) fn new(max_samples: usize) -> Self {
+        Self {
+            samples: Vec::new(),
+            max_samples,
+        }
+    }
+
+    /// Record a CPU usage sample
+    pub(crate) fn record(&mut self, cpu_usage: f32) {
+        let now = Instant::now();
+        self.samples.push((now, cpu_usage));
+
+        // Keep only recent samples
+        if self.samples.len() > self.max_samples {
+            self.samples.remove(0);
+        }
+    }
+
+    /// Check if CPU has been below threshold for given duration
+    pub(crate) fn is_idle(&self, threshold: f32, duration: Duration) -> bool {
+        let cutoff = Instant::now() - duration;
+
+        // Check all samples within the time window
+        let recent_samples: Vec<&(Instant, f32)> = self
+            .samples
+            .iter()
+            .filter(|(time, _)| *time >= cutoff)
+            .collect();
+
+        if recent_samples.is_empty() {
+            return false;
+        }
+
+        // All samples must be below threshold
+        recent_samples.iter().all(|(_, cpu)| *cpu < threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idle_tracker() {
+        let mut tracker = IdleTracker::new(10);
+
+        // Record low CPU usage samples
+        for _ in 0..5 {
+            tracker.record(5.0);
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        // Should be idle with threshold 10.0 and duration 1s
+        assert!(tracker.is_idle(10.0, Duration::from_secs(1)));
+
+        // Add high CPU sample
+        tracker.record(95.0);
+
+        // Should NOT be idle anymore
+        assert!(!tracker.is_idle(10.0, Duration::from_secs(1)));
+    }
+}
@@ -0,0 +1,423 @@
+// Segment Job Archive - GC'd jobs appended to rotating segment files
+//
+// Layout of the archive dir:
+// - segment-NNNNNN.jsonl[.zst]: blocks of JSON lines (one ArchivedJob per
+//   line), one block per append. With zstd each block is its own frame, so a
+//   segment stays a valid .zst file (`zstd -dc`) and one block can be read
+//   without decompressing the rest of the segment.
+// - index.jsonl: one {"job_id", "segment", "offset", "len"} line per job.
+//
+// A new segment is started once the current one reached the size limit, or
+// when the compression setting changed since it was written.
+
+use async_trait::async_trait;
+use semantica_core::error::{AppError, Result};
+use semantica_core::port::{ArchiveLocation, ArchivedJob, JobArchive};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+/// Segment size that triggers rotation (64 MiB)
+pub const DEFAULT_SEGMENT_BYTES: u64 = 64 * 1024 * 1024;
+
+/// zstd level used by "zstd" without an explicit level
+pub const DEFAULT_ZSTD_LEVEL: i32 = 3;
+
+const INDEX_FILE: &str = "index.jsonl";
+const SEGMENT_PREFIX: &str = "segment-";
+
+/// How segment blocks are encoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveCompression {
+    None,
+    Zstd { level: i32 },
+}
+
+impl ArchiveCompression {
+    /// Parse "none", "zstd" or "zstd:<level>" (1-22)
+    pub fn parse(value: &str) -> std::result::Result<Self, String> {
+        match value.trim().split_once(':') {
+            None if value.trim() == "none" => Ok(Self::None),
+            None if value.trim() == "zstd" => Ok(Self::Zstd {
+                level: DEFAULT_ZSTD_LEVEL,
+            }),
+            Some(("zstd", level)) => match level.trim().parse() {
+                Ok(level) if (1..=22).contains(&level) => Ok(Self::Zstd { level }),
+                _ => Err(format!("Invalid zstd level '{}' (expected 1-22)", level)),
+            },
+            _ => Err(format!(
+                "Invalid archive compression '{}' (expected none, zstd or zstd:<level>)",
+                value
+            )),
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::None => "jsonl",
+            Self::Zstd { .. } => "jsonl.zst",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexEntry {
+    job_id: String,
+    #[serde(flatten)]
+    location: ArchiveLocation,
+}
+
+/// Archives jobs as JSON lines in `<dir>/segment-NNNNNN.jsonl[.zst]`
+pub struct SegmentJobArchive {
+    dir: PathBuf,
+    compression: ArchiveCompression,
+    max_segment_bytes: u64,
+    /// Job ID -> location, loaded from index.jsonl on first use
+    /// (the lock also serializes appends)
+    index: Mutex<Option<HashMap<String, ArchiveLocation>>>,
+}
+
+impl SegmentJobArchive {
+    /// Uncompressed segments rotated at DEFAULT_SEGMENT_BYTES
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            compression: ArchiveCompression::None,
+            max_segment_bytes: DEFAULT_SEGMENT_BYTES,
+            index: Mutex::new(None),
+        }
+    }
+
+    pub fn with_compression(mut self, compression: ArchiveCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Start a new segment once the current one reaches this size
+    pub fn with_max_segment_bytes(mut self, max_segment_bytes: u64) -> Self {
+        self.max_segment_bytes = max_segment_bytes.max(1);
+        self
+    }
+
+    async fn load_index(&self, index: &mut Option<HashMap<String, ArchiveLocation>>) -> Result<()> {
+        if index.is_none() {
+            let path = self.dir.join(INDEX_FILE);
+            *index = Some(
+                tokio::task::spawn_blocking(move || read_index(&path))
+                    .await
+                    .map_err(|e| AppError::Internal(format!("Archive index panicked: {}", e)))??,
+            );
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl JobArchive for SegmentJobArchive {
+    async fn append(&self, jobs: &[ArchivedJob]) -> Result<()> {
+        if jobs.is_empty() {
+            return Ok(());
+        }
+
+        let mut block = Vec::new();
+        for job in jobs {
+            serde_json::to_writer(&mut block, job)
+                .map_err(|e| AppError::Internal(format!("Failed to serialize job: {}", e)))?;
+            block.push(b'\n');
+        }
+        let job_ids: Vec<String> = jobs
+            .iter()
+            .map(|archived| archived.job.id.clone())
+            .collect();
+
+        let mut index = self.index.lock().await;
+        self.load_index(&mut index).await?;
+
+        let dir = self.dir.clone();
+        let compression = self.compression;
+        let max_segment_bytes = self.max_segment_bytes;
+        let location = tokio::task::spawn_blocking(move || {
+            write_block(&dir, compression, max_segment_bytes, &block, &job_ids)
+                .map(|location| (location, job_ids))
+        })
+        .await
+        .map_err(|e| AppError::Internal(format!("Archive writer panicked: {}", e)))?
+        .map_err(|e| AppError::Internal(format!("Failed to archive jobs: {}", e)))?;
+
+        let (location, job_ids) = location;
+        info!(
+            jobs = job_ids.len(),
+            segment = %location.segment,
+            bytes = location.len,
+            "Archived jobs"
+        );
+        if let Some(index) = index.as_mut() {
+            for job_id in job_ids {
+                index.insert(job_id, location.clone());
+            }
+        }
+        Ok(())
+    }
+
+    async fn get(&self, job_id: &str) -> Result<Option<(ArchivedJob, ArchiveLocation)>> {
+        let location = {
+            let mut index = self.index.lock().await;
+            self.load_index(&mut index).await?;
+            index.as_ref().and_then(|index| index.get(job_id).cloned())
+        };
+        let Some(location) = location else {
+            return Ok(None);
+        };
+
+        let path = self.dir.join(&location.segment);
+        let (offset, len) = (location.offset, location.len);
+        let block = tokio::task::spawn_blocking(move || read_block(&path, offset, len))
+            .await
+            .map_err(|e| AppError::Internal(format!("Archive reader panicked: {}", e)))?
+            .map_err(|e| {
+                AppError::Internal(format!(
+                    "Failed to read archive segment {}: {}",
+                    location.segment, e
+                ))
+            })?;
+
+        let archived = block
+            .rsplit(|&b| b == b'\n')
+            .filter_map(|line| serde_json::from_slice::<ArchivedJob>(line).ok())
+            .find(|archived| archived.job.id == job_id);
+        Ok(archived.map(|archived| (archived, location)))
+    }
+}
+
+/// Job ID -> location (later lines win; torn lines from a crash are skipped)
+fn read_index(path: &Path) -> Result<HashMap<String, ArchiveLocation>> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(e) => {
+            return Err(AppError::Internal(format!(
+                "Failed to read archive index {}: {}",
+                path.display(),
+                e
+            )))
+        }
+    };
+
+    let mut index = HashMap::new();
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+        match serde_json::from_str::<IndexEntry>(line) {
+            Ok(entry) => {
+                index.insert(entry.job_id, entry.location);
+            }
+            Err(e) => warn!(error = %e, "Skipping malformed archive index line"),
+        }
+    }
+    Ok(index)
+}
+
+/// Append one encoded block to the current segment, then index its jobs
+fn write_block(
+    dir: &Path,
+    compression: ArchiveCompression,
+    max_segment_bytes: u64,
+    block: &[u8],
+    job_ids: &[String],
+) -> std::io::Result<ArchiveLocation> {
+    std::fs::create_dir_all(dir)?;
+    let encoded = match compression {
+        ArchiveCompression::None => block.to_vec(),
+        ArchiveCompression::Zstd { level } => zstd::bulk::compress(block, level)?,
+    };
+
+    let segment = current_segment(dir, compression, max_segment_bytes)?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join(&segment))?;
+    let offset = file.metadata()?.len();
+    file.write_all(&encoded)?;
+    file.sync_data()?;
+
+    let location = ArchiveLocation {
+        segment,
+        offset,
+        len: encoded.len() as u64,
+    };
+    let mut lines = Vec::new();
+    for job_id in job_ids {
+        let entry = IndexEntry {
+            job_id: job_id.clone(),
+            location: location.clone(),
+        };
+        serde_json::to_writer(&mut lines, &entry)?;
+        lines.push(b'\n');
+    }
+    let mut index = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join(INDEX_FILE))?;
+    index.write_all(&lines)?;
+    index.sync_data()?;
+
+    Ok(location)
+}
+
+/// Segment to append to: the latest one, or the next number when it is full
+/// or written with another compression
+fn current_segment(
+    dir: &Path,
+    compression: ArchiveCompression,
+    max_segment_bytes: u64,
+) -> std::io::Result<String> {
+    let mut latest: Option<(u32, String, u64)> = None;
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Some(number) = name
+            .strip_prefix(SEGMENT_PREFIX)
+            .and_then(|rest| rest.split_once('.'))
+            .and_then(|(number, _)| number.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        if latest.as_ref().is_none_or(|(n, _, _)| number > *n) {
+            latest = Some((number, name, entry.metadata()?.len()));
+        }
+    }
+
+    let next = match latest {
+        Some((number, name, size)) => {
+            let same_encoding =
+                name.split_once('.').map(|(_, ext)| ext) == Some(compression.extension());
+            if same_encoding && size < max_segment_bytes {
+                return Ok(name);
+            }
+            number + 1
+        }
+        None => 1,
+    };
+    Ok(format!(
+        "{}{:06}.{}",
+        SEGMENT_PREFIX,
+        next,
+        compression.extension()
+    ))
+}
+
+/// Decoded JSON lines of one block
+fn read_block(path: &Path, offset: u64, len: u64) -> std::io::Result<Vec<u8>> {
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(offset))?;
+    let mut encoded = vec![0; len as usize];
+    file.read_exact(&mut encoded)?;
+
+    if path.extension().is_some_and(|ext| ext == "zst") {
+        zstd::stream::decode_all(encoded.as_slice())
+    } else {
+        Ok(encoded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+
+    fn archived(id: &str) -> ArchivedJob {
+        let mut job = Job::new_test(
+            "default",
+            JobType::new("TEST"),
+            format!("subject/{}", id),
+            1,
+            JobPayload::new(serde_json::json!({ "path": "src/lib.rs" })),
+        );
+        job.id = id.to_string();
+        ArchivedJob {
+            job,
+            events: Vec::new(),
+            archived_at: 1_000,
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("semantica-archive-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_compression_spec() {
+        assert_eq!(
+            ArchiveCompression::parse("none"),
+            Ok(ArchiveCompression::None)
+        );
+        assert_eq!(
+            ArchiveCompression::parse("zstd"),
+            Ok(ArchiveCompression::Zstd {
+                level: DEFAULT_ZSTD_LEVEL
+            })
+        );
+        assert_eq!(
+            ArchiveCompression::parse("zstd:19"),
+            Ok(ArchiveCompression::Zstd { level: 19 })
+        );
+        assert!(ArchiveCompression::parse("zstd:0").is_err());
+        assert!(ArchiveCompression::parse("gzip").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_compressed_segments_rotate_and_stay_indexed() {
+        let dir = temp_dir("rotate");
+        let archive = SegmentJobArchive::new(&dir)
+            .with_compression(ArchiveCompression::Zstd { level: 3 })
+            .with_max_segment_bytes(1);
+
+        archive
+            .append(&[archived("job-1"), archived("job-2")])
+            .await
+            .unwrap();
+        archive.append(&[archived("job-3")]).await.unwrap();
+
+        let (job, location) = archive.get("job-2").await.unwrap().unwrap();
+        assert_eq!(job.job.id, "job-2");
+        assert_eq!(location.segment, "segment-000001.jsonl.zst");
+        assert_eq!(location.offset, 0);
+
+        // Full first segment: the second block starts a new one
+        let (_, location) = archive.get("job-3").await.unwrap().unwrap();
+        assert_eq!(location.segment, "segment-000002.jsonl.zst");
+        assert!(archive.get("job-4").await.unwrap().is_none());
+
+        // A segment is a plain zstd stream
+        let raw = std::fs::read(dir.join("segment-000001.jsonl.zst")).unwrap();
+        let decoded = String::from_utf8(zstd::stream::decode_all(raw.as_slice()).unwrap()).unwrap();
+        assert_eq!(decoded.lines().count(), 2);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_index_survives_restart_and_compression_change() {
+        let dir = temp_dir("reopen");
+        SegmentJobArchive::new(&dir)
+            .append(&[archived("job-1")])
+            .await
+            .unwrap();
+
+        let reopened =
+            SegmentJobArchive::new(&dir).with_compression(ArchiveCompression::Zstd { level: 1 });
+        reopened.append(&[archived("job-2")]).await.unwrap();
+
+        let (_, plain) = reopened.get("job-1").await.unwrap().unwrap();
+        assert_eq!(plain.segment, "segment-000001.jsonl");
+        let (_, compressed) = reopened.get("job-2").await.unwrap().unwrap();
+        assert_eq!(compressed.segment, "segment-000002.jsonl.zst");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,8 +1,39 @@
 // Semantica Infrastructure - System Adapters
-// Implements: SystemProbe, TaskExecutor (ADR-002)
+// Implements: SystemProbe, TaskExecutor, Notifier, BundleWriter, ArtifactStorage, JobArchive (ADR-002)
 
+pub mod artifact_storage;
+pub mod bundle_writer;
+pub mod cached_probe;
+mod env_manifest;
+pub mod exit_codes;
+pub mod file_notifier;
+mod idle_tracker;
+pub mod job_archive;
+mod output_capture;
+pub mod probe_config;
+pub mod process_priority;
+pub mod procfs_probe;
+#[cfg(feature = "remote-probe")]
+pub mod remote_probe;
+pub mod resource_hints;
+#[cfg(feature = "s3")]
+pub mod s3_storage;
 pub mod subprocess_executor;
 pub mod system_probe_impl;
 
+pub use artifact_storage::LocalArtifactStorage;
+pub use bundle_writer::TarBundleWriter;
+pub use cached_probe::CachedSystemProbe;
+pub use exit_codes::{ExitCodeMaps, ExitOutcome};
+pub use file_notifier::FileNotifier;
+pub use job_archive::{ArchiveCompression, SegmentJobArchive};
+pub use probe_config::{DiskMetrics, ProbeBackend, ProbeConfig};
+pub use process_priority::lower_own_priority;
+pub use procfs_probe::ProcfsSystemProbe;
+#[cfg(feature = "remote-probe")]
+pub use remote_probe::RemoteSystemProbe;
+pub use resource_hints::{ResourceHintConfig, ResourceHints};
+#[cfg(feature = "s3")]
+pub use s3_storage::{S3ArtifactStorage, S3Config};
 pub use subprocess_executor::SubprocessExecutor;
 pub use system_probe_impl::SystemProbeImpl;
@@ -0,0 +1,105 @@
+// Output capture - Stream child stdout/stderr line by line
+//
+// Each line is tagged with its stream and capture time (LogLine) and, when a
+// log file is given, appended to it as a JSON record while the process runs,
+// so stdout and stderr stay separable for agents extracting errors.
+
+use semantica_core::domain::{LogLine, LogStream};
+use semantica_core::port::TimeProvider;
+use std::process::ExitStatus;
+use std::sync::Arc;
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWriteExt, BufReader};
+use tokio::process::Child;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Exit status and the full text of both streams
+pub(crate) struct CapturedOutput {
+    pub status: ExitStatus,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Drain the child's piped stdout/stderr, then wait for it to exit
+///
+/// Log file write errors are logged and stop further writes (best-effort).
+pub(crate) async fn capture(
+    mut child: Child,
+    time_provider: Arc<dyn TimeProvider>,
+    mut log_file: Option<File>,
+) -> std::io::Result<CapturedOutput> {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    if let Some(stdout) = child.stdout.take() {
+        spawn_reader(stdout, LogStream::Stdout, tx.clone(), time_provider.clone());
+    }
+    if let Some(stderr) = child.stderr.take() {
+        spawn_reader(stderr, LogStream::Stderr, tx.clone(), time_provider);
+    }
+    drop(tx);
+
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+    while let Some(line) = rx.recv().await {
+        let text = match line.stream {
+            LogStream::Stdout => &mut stdout,
+            LogStream::Stderr => &mut stderr,
+        };
+        text.push_str(&line.line);
+        text.push('\n');
+
+        if let Some(file) = log_file.as_mut() {
+            let record = format!("{}\n", line.to_record());
+            if let Err(e) = file.write_all(record.as_bytes()).await {
+                warn!(error = %e, "Failed to write job log, disabling log capture");
+                log_file = None;
+            }
+        }
+    }
+    if let Some(file) = log_file.as_mut() {
+        if let Err(e) = file.flush().await {
+            warn!(error = %e, "Failed to flush job log");
+        }
+    }
+
+    let status = child.wait().await?;
+    Ok(CapturedOutput {
+        status,
+        stdout,
+        stderr,
+    })
+}
+
+/// Forward lines of one stream until EOF
+fn spawn_reader<R>(
+    reader: R,
+    stream: LogStream,
+    tx: mpsc::UnboundedSender<LogLine>,
+    time_provider: Arc<dyn TimeProvider>,
+) where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut reader = BufReader::new(reader);
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            match reader.read_until(b'\n', &mut buf).await {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    while buf.last().is_some_and(|b| *b == b'\n' || *b == b'\r') {
+                        buf.pop();
+                    }
+                    let line = LogLine::new(
+                        time_provider.now_millis(),
+                        stream,
+                        String::from_utf8_lossy(&buf),
+                    );
+                    if tx.send(line).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,168 @@
+// SystemProbe backend selection
+// Picks the probe implementation and how much it collects per poll, from env
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use semantica_core::port::SystemProbe;
+
+use crate::cached_probe::CachedSystemProbe;
+use crate::procfs_probe::ProcfsSystemProbe;
+#[cfg(feature = "remote-probe")]
+use crate::remote_probe::RemoteSystemProbe;
+use crate::system_probe_impl::SystemProbeImpl;
+
+/// Default cadence of the cached sampler (one idle-tracker sample per second)
+pub const DEFAULT_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// SystemProbe implementation
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProbeBackend {
+    /// sysinfo (cross-platform, default)
+    Sysinfo,
+    /// Direct /proc reads (Linux, lightweight)
+    Procfs,
+    /// Metrics agent serving SystemMetrics JSON at this URL
+    Remote(String),
+}
+
+/// When the disk scan runs on a poll
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiskMetrics {
+    Always,
+    /// Skipped while running on battery
+    OnAcPower,
+    /// Disk usage is always reported as 0
+    Never,
+}
+
+impl DiskMetrics {
+    pub(crate) fn should_collect(&self, is_charging: Option<bool>) -> bool {
+        match self {
+            DiskMetrics::Always => true,
+            DiskMetrics::OnAcPower => is_charging != Some(false),
+            DiskMetrics::Never => false,
+        }
+    }
+}
+
+/// Probe configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProbeConfig {
+    pub backend: ProbeBackend,
+    pub disk_metrics: DiskMetrics,
+    /// Cadence of the background sampler serving cached metrics (None = poll on every call)
+    pub sample_interval: Option<Duration>,
+}
+
+impl Default for ProbeConfig {
+    fn default() -> Self {
+        Self {
+            backend: ProbeBackend::Sysinfo,
+            disk_metrics: DiskMetrics::Always,
+            sample_interval: Some(DEFAULT_SAMPLE_INTERVAL),
+        }
+    }
+}
+
+impl ProbeConfig {
+    /// Load configuration from environment
+    ///
+    /// # Configuration
+    /// - `SEMANTICA_SYSTEM_PROBE`: `sysinfo` (default), `procfs` or `remote`
+    /// - `SEMANTICA_PROBE_REMOTE_URL`: Agent URL (required for `remote`)
+    /// - `SEMANTICA_PROBE_DISK_METRICS`: `always` (default), `ac` (skip on battery) or `never`
+    /// - `SEMANTICA_PROBE_SAMPLE_INTERVAL_MS`: Sampler cadence (default 1000, 0 = no caching)
+    pub fn from_env() -> Result<Self, String> {
+        let mut config = Self::default();
+
+        if let Some(value) = env_var("SEMANTICA_SYSTEM_PROBE") {
+            config.backend = match value.trim().to_ascii_lowercase().as_str() {
+                "sysinfo" => ProbeBackend::Sysinfo,
+                "procfs" => ProbeBackend::Procfs,
+                "remote" => {
+                    let url = env_var("SEMANTICA_PROBE_REMOTE_URL").ok_or(
+                        "SEMANTICA_PROBE_REMOTE_URL is required for the remote system probe",
+                    )?;
+                    ProbeBackend::Remote(url)
+                }
+                _ => return Err(format!("Invalid SEMANTICA_SYSTEM_PROBE: '{}'", value)),
+            };
+        }
+        if let Some(value) = env_var("SEMANTICA_PROBE_DISK_METRICS") {
+            config.disk_metrics = match value.trim().to_ascii_lowercase().as_str() {
+                "always" => DiskMetrics::Always,
+                "ac" => DiskMetrics::OnAcPower,
+                "never" => DiskMetrics::Never,
+                _ => return Err(format!("Invalid SEMANTICA_PROBE_DISK_METRICS: '{}'", value)),
+            };
+        }
+        if let Some(value) = env_var("SEMANTICA_PROBE_SAMPLE_INTERVAL_MS") {
+            let ms: u64 = value
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid SEMANTICA_PROBE_SAMPLE_INTERVAL_MS: '{}'", value))?;
+            config.sample_interval = (ms > 0).then(|| Duration::from_millis(ms));
+        }
+
+        Ok(config)
+    }
+
+    /// Build the configured probe, wrapped in the cached sampler when enabled
+    ///
+    /// Must be called within a tokio runtime: the sampler is spawned here.
+    pub fn build(&self) -> Result<Arc<dyn SystemProbe>, String> {
+        let probe = self.build_backend()?;
+        let Some(every) = self.sample_interval else {
+            return Ok(probe);
+        };
+
+        // Stale after a few missed ticks: callers then sample inline
+        let cached = Arc::new(CachedSystemProbe::new(probe, every * 3));
+        tokio::spawn(cached.clone().run(every));
+        Ok(cached)
+    }
+
+    fn build_backend(&self) -> Result<Arc<dyn SystemProbe>, String> {
+        Ok(match &self.backend {
+            ProbeBackend::Sysinfo => {
+                Arc::new(SystemProbeImpl::new().with_disk_metrics(self.disk_metrics))
+            }
+            ProbeBackend::Procfs => {
+                if !cfg!(target_os = "linux") {
+                    return Err("The procfs system probe is only available on Linux".to_string());
+                }
+                Arc::new(ProcfsSystemProbe::new().with_disk_metrics(self.disk_metrics))
+            }
+            #[cfg(feature = "remote-probe")]
+            ProbeBackend::Remote(url) => {
+                Arc::new(RemoteSystemProbe::new(url.clone()).map_err(|e| e.to_string())?)
+            }
+            #[cfg(not(feature = "remote-probe"))]
+            ProbeBackend::Remote(_) => {
+                return Err(
+                    "The remote system probe needs a build with the \"remote-probe\" feature"
+                        .to_string(),
+                );
+            }
+        })
+    }
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disk_metrics_on_ac_power() {
+        assert!(DiskMetrics::OnAcPower.should_collect(Some(true)));
+        assert!(DiskMetrics::OnAcPower.should_collect(None), "No battery");
+        assert!(!DiskMetrics::OnAcPower.should_collect(Some(false)));
+        assert!(DiskMetrics::Always.should_collect(Some(false)));
+        assert!(!DiskMetrics::Never.should_collect(None));
+    }
+}
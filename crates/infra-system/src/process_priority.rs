@@ -0,0 +1,81 @@
+// Daemon self-throttling
+// Lowers the daemon's own CPU (and on Linux I/O) priority so its bookkeeping
+// never competes with foreground work. Job subprocesses inherit it.
+
+use std::process::Command;
+use tracing::info;
+
+/// Lowest best-effort I/O priority (ionice class 2, level 7)
+#[cfg(target_os = "linux")]
+const IO_PRIORITY_LEVEL: &str = "7";
+
+/// Run the daemon at niceness `nice` (0..=19) from now on
+///
+/// On Linux niceness is per thread, so every thread of the process is
+/// reniced (threads spawned later inherit it) and its I/O priority lowered.
+pub fn lower_own_priority(nice: i32) -> Result<(), String> {
+    if !(0..=19).contains(&nice) {
+        return Err(format!("Niceness must be between 0 and 19, got {}", nice));
+    }
+
+    let threads = own_thread_ids();
+    run("renice", &[nice.to_string(), "-p".to_string()], &threads)?;
+
+    #[cfg(target_os = "linux")]
+    run(
+        "ionice",
+        &[
+            "-c".to_string(),
+            "2".to_string(),
+            "-n".to_string(),
+            IO_PRIORITY_LEVEL.to_string(),
+            "-p".to_string(),
+        ],
+        &threads,
+    )?;
+
+    info!(nice, threads = threads.len(), "Daemon priority lowered");
+    Ok(())
+}
+
+/// Thread ids of this process (just the pid outside Linux, where priority is per process)
+fn own_thread_ids() -> Vec<String> {
+    #[cfg(target_os = "linux")]
+    if let Ok(entries) = std::fs::read_dir("/proc/self/task") {
+        let tids: Vec<String> = entries
+            .flatten()
+            .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+            .collect();
+        if !tids.is_empty() {
+            return tids;
+        }
+    }
+    vec![std::process::id().to_string()]
+}
+
+fn run(program: &str, args: &[String], ids: &[String]) -> Result<(), String> {
+    let output = Command::new(program)
+        .args(args)
+        .args(ids)
+        .output()
+        .map_err(|e| format!("Failed to run {}: {}", program, e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} failed: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_out_of_range_niceness() {
+        assert!(lower_own_priority(-5).is_err());
+        assert!(lower_own_priority(20).is_err());
+    }
+}
@@ -0,0 +1,312 @@
+// Lightweight system probe reading /proc directly (Linux)
+// A handful of small file reads per poll instead of sysinfo's full refresh,
+// for machines where the probe itself should cost next to nothing
+
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::debug;
+
+use crate::idle_tracker::IdleTracker;
+use crate::probe_config::DiskMetrics;
+use crate::system_probe_impl::disk_usage_gb;
+use semantica_core::application::worker::constants::IDLE_TRACKER_MAX_SAMPLES;
+use semantica_core::port::system_probe::{SystemMetrics, SystemProbe};
+
+const POWER_SUPPLY_DIR: &str = "/sys/class/power_supply";
+
+/// Ancestors walked when attributing a process to a job (guards against cycles)
+const MAX_PROCESS_DEPTH: usize = 64;
+
+/// System probe backed by /proc (Linux only)
+///
+/// CPU usage is the busy share of the jiffies elapsed since the previous poll.
+pub struct ProcfsSystemProbe {
+    proc_root: std::path::PathBuf,
+    /// (total, idle) jiffies at the previous poll
+    last_cpu: Mutex<Option<(u64, u64)>>,
+    idle_tracker: Mutex<IdleTracker>,
+    disk_metrics: DiskMetrics,
+}
+
+impl ProcfsSystemProbe {
+    pub fn new() -> Self {
+        Self {
+            proc_root: "/proc".into(),
+            last_cpu: Mutex::new(None),
+            idle_tracker: Mutex::new(IdleTracker::new(IDLE_TRACKER_MAX_SAMPLES)),
+            disk_metrics: DiskMetrics::Always,
+        }
+    }
+
+    /// When to scan disks on each poll (the scan is the most expensive metric)
+    pub fn with_disk_metrics(mut self, disk_metrics: DiskMetrics) -> Self {
+        self.disk_metrics = disk_metrics;
+        self
+    }
+
+    /// CPU usage since the previous call (since boot on the first one)
+    fn cpu_usage_percent(&self) -> f32 {
+        let Some((total, idle)) = std::fs::read_to_string(self.proc_root.join("stat"))
+            .ok()
+            .and_then(|stat| parse_cpu_jiffies(&stat))
+        else {
+            return 0.0;
+        };
+
+        let mut last = self.last_cpu.lock().unwrap_or_else(|e| e.into_inner());
+        let (prev_total, prev_idle) = last.replace((total, idle)).unwrap_or((0, 0));
+        let elapsed = total.saturating_sub(prev_total);
+        if elapsed == 0 {
+            return 0.0;
+        }
+        let busy = elapsed.saturating_sub(idle.saturating_sub(prev_idle));
+        (busy as f64 * 100.0 / elapsed as f64) as f32
+    }
+}
+
+impl Default for ProcfsSystemProbe {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SystemProbe for ProcfsSystemProbe {
+    async fn get_metrics(&self) -> SystemMetrics {
+        let cpu_usage_percent = self.cpu_usage_percent();
+
+        let (memory_used_mb, memory_total_mb) =
+            std::fs::read_to_string(self.proc_root.join("meminfo"))
+                .ok()
+                .and_then(|meminfo| parse_meminfo_mb(&meminfo))
+                .unwrap_or((0, 0));
+
+        let (battery_percent, is_charging) = battery_status();
+
+        let (disk_used_gb, disk_total_gb) = if self.disk_metrics.should_collect(is_charging) {
+            disk_usage_gb()
+        } else {
+            (0, 0)
+        };
+
+        self.idle_tracker
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .record(cpu_usage_percent);
+
+        debug!(
+            cpu = %cpu_usage_percent,
+            mem_used_mb = %memory_used_mb,
+            mem_total_mb = %memory_total_mb,
+            "System metrics collected from /proc"
+        );
+
+        SystemMetrics {
+            cpu_usage_percent,
+            memory_used_mb,
+            memory_total_mb,
+            disk_used_gb,
+            disk_total_gb,
+            battery_percent,
+            is_charging,
+        }
+    }
+
+    async fn is_idle(&self, cpu_threshold: f32, duration_secs: u64) -> bool {
+        self.get_metrics().await;
+
+        self.idle_tracker
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_idle(cpu_threshold, Duration::from_secs(duration_secs))
+    }
+
+    async fn process_tree_memory_mb(&self, pids: &[u32]) -> u64 {
+        if pids.is_empty() {
+            return 0;
+        }
+        let roots: HashSet<u32> = pids.iter().copied().collect();
+
+        // pid -> (parent pid, resident KB); threads live under /proc/<pid>/task
+        let mut processes: HashMap<u32, (u32, u64)> = HashMap::new();
+        let Ok(entries) = std::fs::read_dir(&self.proc_root) else {
+            return 0;
+        };
+        for entry in entries.flatten() {
+            let Some(pid) = entry.file_name().to_str().and_then(|n| n.parse().ok()) else {
+                continue;
+            };
+            if let Some(status) = std::fs::read_to_string(entry.path().join("status"))
+                .ok()
+                .and_then(|status| parse_status(&status))
+            {
+                processes.insert(pid, status);
+            }
+        }
+
+        let kb: u64 = processes
+            .iter()
+            .filter(|(&pid, _)| {
+                let mut current = pid;
+                for _ in 0..MAX_PROCESS_DEPTH {
+                    if roots.contains(&current) {
+                        return true;
+                    }
+                    match processes.get(&current) {
+                        Some(&(parent, _)) if parent != 0 => current = parent,
+                        _ => return false,
+                    }
+                }
+                false
+            })
+            .map(|(_, &(_, rss_kb))| rss_kb)
+            .sum();
+
+        kb / 1024
+    }
+}
+
+/// Battery charge and charging state from /sys/class/power_supply
+///
+/// (None, None) without a battery (desktop) or outside Linux. Plugged into
+/// mains counts as charging even when the battery reports "Not charging".
+pub(crate) fn battery_status() -> (Option<f32>, Option<bool>) {
+    read_power_supply(Path::new(POWER_SUPPLY_DIR))
+}
+
+fn read_power_supply(dir: &Path) -> (Option<f32>, Option<bool>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return (None, None);
+    };
+    let read = |path: &Path, name: &str| {
+        std::fs::read_to_string(path.join(name))
+            .map(|value| value.trim().to_string())
+            .unwrap_or_default()
+    };
+
+    let mut battery_percent = None;
+    let mut battery_charging = None;
+    let mut on_mains = false;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        match read(&path, "type").as_str() {
+            "Battery" if battery_percent.is_none() => {
+                battery_percent = read(&path, "capacity").parse::<f32>().ok();
+                battery_charging = Some(matches!(
+                    read(&path, "status").as_str(),
+                    "Charging" | "Full"
+                ));
+            }
+            "Mains" => on_mains |= read(&path, "online") == "1",
+            _ => {}
+        }
+    }
+
+    match battery_charging {
+        Some(charging) => (battery_percent, Some(charging || on_mains)),
+        None => (None, None),
+    }
+}
+
+/// (total, idle + iowait) jiffies of the aggregate `cpu` line of /proc/stat
+fn parse_cpu_jiffies(stat: &str) -> Option<(u64, u64)> {
+    let line = stat.lines().find(|line| line.starts_with("cpu "))?;
+    let values: Vec<u64> = line
+        .split_whitespace()
+        .skip(1)
+        .filter_map(|v| v.parse().ok())
+        .collect();
+    if values.len() < 4 {
+        return None;
+    }
+    // guest / guest_nice are already included in user / nice
+    let total = values.iter().take(8).sum();
+    let idle = values[3] + values.get(4).copied().unwrap_or(0);
+    Some((total, idle))
+}
+
+/// (used, total) MB from /proc/meminfo (used = total - available)
+fn parse_meminfo_mb(meminfo: &str) -> Option<(u64, u64)> {
+    let field = |name: &str| {
+        meminfo
+            .lines()
+            .find_map(|line| line.strip_prefix(name))
+            .and_then(|rest| rest.trim_start_matches(':').split_whitespace().next())
+            .and_then(|kb| kb.parse::<u64>().ok())
+    };
+    let total_kb = field("MemTotal")?;
+    let available_kb = field("MemAvailable")?;
+    Some((
+        total_kb.saturating_sub(available_kb) / 1024,
+        total_kb / 1024,
+    ))
+}
+
+/// (parent pid, resident KB) from /proc/<pid>/status (kernel threads have no VmRSS)
+fn parse_status(status: &str) -> Option<(u32, u64)> {
+    let field = |name: &str| {
+        status
+            .lines()
+            .find_map(|line| line.strip_prefix(name))
+            .and_then(|rest| rest.split_whitespace().next())
+    };
+    let parent = field("PPid:")?.parse().ok()?;
+    let rss_kb = field("VmRSS:").and_then(|kb| kb.parse().ok()).unwrap_or(0);
+    Some((parent, rss_kb))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_proc_files() {
+        let stat = "cpu  100 5 50 800 20 3 2 0 0 0\ncpu0 50 2 25 400 10 1 1 0 0 0\n";
+        assert_eq!(parse_cpu_jiffies(stat), Some((980, 820)));
+
+        let meminfo = "MemTotal:       16384000 kB\nMemFree:         1024000 kB\nMemAvailable:    4096000 kB\n";
+        assert_eq!(parse_meminfo_mb(meminfo), Some((12000, 16000)));
+
+        let status = "Name:\tcargo\nPid:\t4242\nPPid:\t4200\nVmRSS:\t  204800 kB\n";
+        assert_eq!(parse_status(status), Some((4200, 204800)));
+        assert_eq!(parse_status("Name:\tkthreadd\nPPid:\t0\n"), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_power_supply_mains_counts_as_charging() {
+        let dir = std::env::temp_dir().join(format!("semantica-power-{}", std::process::id()));
+        let write = |name: &str, file: &str, value: &str| {
+            std::fs::create_dir_all(dir.join(name)).unwrap();
+            std::fs::write(dir.join(name).join(file), value).unwrap();
+        };
+        write("BAT0", "type", "Battery\n");
+        write("BAT0", "capacity", "42\n");
+        write("BAT0", "status", "Discharging\n");
+        write("AC", "type", "Mains\n");
+        write("AC", "online", "0\n");
+        assert_eq!(read_power_supply(&dir), (Some(42.0), Some(false)));
+
+        write("AC", "online", "1\n");
+        assert_eq!(read_power_supply(&dir), (Some(42.0), Some(true)));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(read_power_supply(&dir), (None, None));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_procfs_probe_reads_live_system() {
+        let probe = ProcfsSystemProbe::new().with_disk_metrics(DiskMetrics::Never);
+
+        let metrics = probe.get_metrics().await;
+        assert!(metrics.memory_total_mb > 0);
+        assert!((0.0..=100.0).contains(&metrics.cpu_usage_percent));
+        assert_eq!(metrics.disk_total_gb, 0, "Disk scan disabled");
+
+        assert!(probe.process_tree_memory_mb(&[std::process::id()]).await > 0);
+        assert_eq!(probe.process_tree_memory_mb(&[]).await, 0);
+    }
+}
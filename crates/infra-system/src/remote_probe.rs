@@ -0,0 +1,116 @@
+// Remote-agent system probe
+// Metrics come from an agent on another host (e.g. the VM host of a daemon
+// running in a guest) serving SystemMetrics as JSON over HTTP
+
+use async_trait::async_trait;
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::{debug, warn};
+
+use crate::idle_tracker::IdleTracker;
+use semantica_core::application::worker::constants::IDLE_TRACKER_MAX_SAMPLES;
+use semantica_core::error::{AppError, Result};
+use semantica_core::port::system_probe::{SystemMetrics, SystemProbe};
+
+/// Per-request timeout for the agent (polled on every scheduling decision)
+const REMOTE_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// System probe polling a remote metrics agent
+///
+/// While the agent is unreachable the last received metrics are reported and
+/// no idle samples are recorded, so the machine never looks idle by accident.
+/// Process memory cannot be attributed remotely (the memory ceiling sees 0).
+pub struct RemoteSystemProbe {
+    url: String,
+    client: reqwest::Client,
+    last_metrics: Mutex<Option<SystemMetrics>>,
+    idle_tracker: Mutex<IdleTracker>,
+}
+
+impl RemoteSystemProbe {
+    /// Create a probe for the agent at `url` (GET returns SystemMetrics JSON)
+    pub fn new(url: impl Into<String>) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(REMOTE_PROBE_TIMEOUT)
+            .build()
+            .map_err(|e| AppError::Config(format!("Failed to build probe client: {}", e)))?;
+        Ok(Self {
+            url: url.into(),
+            client,
+            last_metrics: Mutex::new(None),
+            idle_tracker: Mutex::new(IdleTracker::new(IDLE_TRACKER_MAX_SAMPLES)),
+        })
+    }
+
+    async fn fetch(&self) -> std::result::Result<SystemMetrics, String> {
+        let body = self
+            .client
+            .get(&self.url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| e.to_string())?
+            .bytes()
+            .await
+            .map_err(|e| e.to_string())?;
+        serde_json::from_slice(&body).map_err(|e| format!("Invalid metrics: {}", e))
+    }
+}
+
+#[async_trait]
+impl SystemProbe for RemoteSystemProbe {
+    async fn get_metrics(&self) -> SystemMetrics {
+        match self.fetch().await {
+            Ok(metrics) => {
+                debug!(url = %self.url, cpu = %metrics.cpu_usage_percent, "Remote metrics received");
+                self.idle_tracker
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .record(metrics.cpu_usage_percent);
+                *self.last_metrics.lock().unwrap_or_else(|e| e.into_inner()) =
+                    Some(metrics.clone());
+                metrics
+            }
+            Err(e) => {
+                warn!(url = %self.url, error = %e, "Remote metrics agent unreachable");
+                self.last_metrics
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .clone()
+                    .unwrap_or(SystemMetrics {
+                        cpu_usage_percent: 0.0,
+                        memory_used_mb: 0,
+                        memory_total_mb: 0,
+                        disk_used_gb: 0,
+                        disk_total_gb: 0,
+                        battery_percent: None,
+                        is_charging: None,
+                    })
+            }
+        }
+    }
+
+    async fn is_idle(&self, cpu_threshold: f32, duration_secs: u64) -> bool {
+        self.get_metrics().await;
+
+        self.idle_tracker
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_idle(cpu_threshold, Duration::from_secs(duration_secs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_unreachable_agent_is_never_idle() {
+        // Nothing listens on port 1
+        let probe = RemoteSystemProbe::new("http://127.0.0.1:1/metrics").unwrap();
+
+        let metrics = probe.get_metrics().await;
+        assert_eq!(metrics.memory_total_mb, 0);
+        assert!(!probe.is_idle(99.0, 60).await);
+    }
+}
@@ -0,0 +1,205 @@
+// Resource hints for subprocess jobs
+// Scales build parallelism (CARGO_BUILD_JOBS, MAKEFLAGS) down while the user is
+// active, and carries optional niceness / CPU affinity settings
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use semantica_core::port::SystemProbe;
+
+// Above this CPU usage the user is considered active (configurable via env var)
+const DEFAULT_BUSY_CPU_PERCENT: f32 = 30.0;
+const DEFAULT_MIN_JOBS: usize = 1;
+
+/// Resource hint configuration
+#[derive(Debug, Clone)]
+pub struct ResourceHintConfig {
+    /// Parallelism when the machine is otherwise idle
+    pub max_jobs: usize,
+    /// Parallelism floor under full load
+    pub min_jobs: usize,
+    /// CPU usage (%) above which parallelism is scaled down
+    pub busy_cpu_percent: f32,
+    /// Niceness added for spawned jobs (unix, like `nice -n`)
+    pub nice: Option<i32>,
+    /// CPUs the job may run on (Linux)
+    pub cpu_affinity: Option<Vec<usize>>,
+}
+
+impl Default for ResourceHintConfig {
+    fn default() -> Self {
+        Self {
+            max_jobs: std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1),
+            min_jobs: DEFAULT_MIN_JOBS,
+            busy_cpu_percent: DEFAULT_BUSY_CPU_PERCENT,
+            nice: None,
+            cpu_affinity: None,
+        }
+    }
+}
+
+impl ResourceHintConfig {
+    /// Load configuration from environment
+    ///
+    /// # Configuration
+    /// - `SEMANTICA_MAX_BUILD_JOBS`: Parallelism when idle (default: CPU count)
+    /// - `SEMANTICA_MIN_BUILD_JOBS`: Parallelism floor (default: 1)
+    /// - `SEMANTICA_BUSY_CPU_PERCENT`: Scale down above this CPU usage (default: 30)
+    /// - `SEMANTICA_JOB_NICE`: Niceness for jobs, e.g. `10` (default: unset)
+    /// - `SEMANTICA_CPU_AFFINITY`: CPU list, e.g. `0-3,6` (default: unset)
+    pub fn from_env() -> Result<Self, String> {
+        let mut config = Self::default();
+
+        if let Some(value) = env_var("SEMANTICA_MAX_BUILD_JOBS") {
+            config.max_jobs = parse_env("SEMANTICA_MAX_BUILD_JOBS", &value)?;
+        }
+        if let Some(value) = env_var("SEMANTICA_MIN_BUILD_JOBS") {
+            config.min_jobs = parse_env("SEMANTICA_MIN_BUILD_JOBS", &value)?;
+        }
+        if let Some(value) = env_var("SEMANTICA_BUSY_CPU_PERCENT") {
+            config.busy_cpu_percent = parse_env("SEMANTICA_BUSY_CPU_PERCENT", &value)?;
+        }
+        if let Some(value) = env_var("SEMANTICA_JOB_NICE") {
+            config.nice = Some(parse_env("SEMANTICA_JOB_NICE", &value)?);
+        }
+        if let Some(value) = env_var("SEMANTICA_CPU_AFFINITY") {
+            config.cpu_affinity = Some(parse_cpu_list(&value)?);
+        }
+
+        config.max_jobs = config.max_jobs.max(1);
+        config.min_jobs = config.min_jobs.clamp(1, config.max_jobs);
+
+        Ok(config)
+    }
+}
+
+/// Computes per-job resource hints from current system load
+pub struct ResourceHints {
+    probe: Arc<dyn SystemProbe>,
+    config: ResourceHintConfig,
+}
+
+impl ResourceHints {
+    pub fn new(probe: Arc<dyn SystemProbe>, config: ResourceHintConfig) -> Self {
+        Self { probe, config }
+    }
+
+    pub fn config(&self) -> &ResourceHintConfig {
+        &self.config
+    }
+
+    /// Environment variables to inject into the child (payload env wins on conflict)
+    pub async fn env(&self) -> HashMap<String, String> {
+        let cpu_usage = self.probe.get_metrics().await.cpu_usage_percent;
+        let jobs = self.parallelism(cpu_usage);
+
+        HashMap::from([
+            ("CARGO_BUILD_JOBS".to_string(), jobs.to_string()),
+            ("MAKEFLAGS".to_string(), format!("-j{}", jobs)),
+            ("CMAKE_BUILD_PARALLEL_LEVEL".to_string(), jobs.to_string()),
+        ])
+    }
+
+    /// Parallelism for the given CPU usage
+    ///
+    /// Full `max_jobs` below `busy_cpu_percent`, then scaled linearly down to
+    /// `min_jobs` at 100% CPU.
+    pub fn parallelism(&self, cpu_usage_percent: f32) -> usize {
+        let ResourceHintConfig {
+            max_jobs,
+            min_jobs,
+            busy_cpu_percent,
+            ..
+        } = self.config;
+
+        if cpu_usage_percent <= busy_cpu_percent {
+            return max_jobs;
+        }
+
+        let headroom = ((100.0 - cpu_usage_percent) / (100.0 - busy_cpu_percent)).clamp(0.0, 1.0);
+        let scaled = (max_jobs as f32 * headroom).floor() as usize;
+        scaled.clamp(min_jobs, max_jobs)
+    }
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.trim().is_empty())
+}
+
+fn parse_env<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid {}: '{}'", name, value))
+}
+
+/// Parse a CPU list such as `0-3,6`
+fn parse_cpu_list(value: &str) -> Result<Vec<usize>, String> {
+    let invalid = || format!("Invalid SEMANTICA_CPU_AFFINITY: '{}'", value);
+    let mut cpus = Vec::new();
+
+    for part in value.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let start: usize = start.trim().parse().map_err(|_| invalid())?;
+                let end: usize = end.trim().parse().map_err(|_| invalid())?;
+                if start > end {
+                    return Err(invalid());
+                }
+                cpus.extend(start..=end);
+            }
+            None => cpus.push(part.parse().map_err(|_| invalid())?),
+        }
+    }
+
+    if cpus.is_empty() {
+        return Err(invalid());
+    }
+    cpus.sort_unstable();
+    cpus.dedup();
+    Ok(cpus)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use semantica_core::port::system_probe::mocks::MockSystemProbe;
+
+    fn hints(cpu_usage: f32) -> ResourceHints {
+        ResourceHints::new(
+            Arc::new(MockSystemProbe::new(cpu_usage)),
+            ResourceHintConfig {
+                max_jobs: 8,
+                min_jobs: 1,
+                busy_cpu_percent: 20.0,
+                nice: None,
+                cpu_affinity: None,
+            },
+        )
+    }
+
+    #[test]
+    fn test_parallelism_scales_with_load() {
+        let hints = hints(0.0);
+        assert_eq!(hints.parallelism(10.0), 8);
+        assert_eq!(hints.parallelism(60.0), 4);
+        assert_eq!(hints.parallelism(100.0), 1);
+    }
+
+    #[tokio::test]
+    async fn test_env_hints_follow_probe() {
+        let env = hints(60.0).env().await;
+        assert_eq!(env["CARGO_BUILD_JOBS"], "4");
+        assert_eq!(env["MAKEFLAGS"], "-j4");
+    }
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(parse_cpu_list("0-2,6, 1").unwrap(), vec![0, 1, 2, 6]);
+        assert!(parse_cpu_list("3-1").is_err());
+        assert!(parse_cpu_list("a").is_err());
+        assert!(parse_cpu_list("").is_err());
+    }
+}
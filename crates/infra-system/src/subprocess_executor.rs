@@ -2,19 +2,38 @@
 // reason: async-trait, tokio for async process management (ADR-001)
 use async_trait::async_trait;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::process::Stdio;
+use std::sync::Mutex;
 use std::time::Duration;
 use tokio::process::Command;
 use tokio::time::timeout;
 use tracing::{info, warn};
 
-use semantica_core::domain::Job;
+use semantica_core::domain::{template, Job, JobId};
 use semantica_core::port::task_executor::{
-    ExecutionError, ExecutionResult, ExecutionStatus, TaskExecutor,
+    ExecutionError, ExecutionResult, RunningProcess, TaskExecutor,
 };
 use semantica_core::port::TimeProvider;
 use std::sync::Arc;
 
+use crate::env_manifest::build_manifest;
+use crate::exit_codes::{self, ExitCodeMaps, ExitOutcome};
+use crate::output_capture::{capture, CapturedOutput};
+use crate::resource_hints::ResourceHints;
+
+/// Executor name recorded in environment manifests
+const EXECUTOR_NAME: &str = "subprocess";
+
+/// W3C trace context variable passed to the child (OpenTelemetry SDKs read it)
+const TRACEPARENT_ENV: &str = "TRACEPARENT";
+
+/// Id of the job being run, for `semantica-cli progress` in job scripts
+pub const JOB_ID_ENV: &str = "SEMANTICA_JOB_ID";
+
+/// Daemon RPC endpoint passed to the child (see [`SubprocessExecutor::with_rpc_url`])
+pub const RPC_URL_ENV: &str = "SEMANTICA_RPC_URL";
+
 // Type alias to simplify complex return types (Clippy warning fix)
 type ParseResult = Result<
     (
@@ -32,6 +51,29 @@ type ParseResult = Result<
 pub struct SubprocessExecutor {
     time_provider: Arc<dyn TimeProvider>,
     env_allowlist: Vec<String>,
+    resource_hints: Option<ResourceHints>,
+    log_dir: Option<PathBuf>,
+    rpc_url: Option<String>,
+    exit_codes: ExitCodeMaps,
+    running: Arc<Mutex<HashMap<u32, RunningProcess>>>,
+}
+
+/// Entry in the running-process table, removed when the child is waited for
+/// (or the wait is abandoned on timeout)
+struct RunningEntry {
+    running: Arc<Mutex<HashMap<u32, RunningProcess>>>,
+    pid: Option<u32>,
+}
+
+impl Drop for RunningEntry {
+    fn drop(&mut self) {
+        if let Some(pid) = self.pid {
+            self.running
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .remove(&pid);
+        }
+    }
 }
 
 impl SubprocessExecutor {
@@ -52,9 +94,60 @@ impl SubprocessExecutor {
         Self {
             time_provider,
             env_allowlist,
+            resource_hints: None,
+            log_dir: None,
+            rpc_url: None,
+            exit_codes: ExitCodeMaps::default(),
+            running: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
+    /// Write each job's tagged output to `<log_dir>/<job_id>.log` while it runs
+    pub fn with_log_dir(mut self, log_dir: impl Into<PathBuf>) -> Self {
+        self.log_dir = Some(log_dir.into());
+        self
+    }
+
+    /// Tell children where the daemon listens so they can report progress
+    pub fn with_rpc_url(mut self, rpc_url: impl Into<String>) -> Self {
+        self.rpc_url = Some(rpc_url.into());
+        self
+    }
+
+    /// Exit codes with a non-default outcome, by job type (a job's own
+    /// `exit_codes` payload field takes precedence)
+    pub fn with_exit_codes(mut self, exit_codes: ExitCodeMaps) -> Self {
+        self.exit_codes = exit_codes;
+        self
+    }
+
+    /// Open (append) the job's log file, None if logging is off or fails
+    async fn open_log(&self, job: &Job) -> Option<(String, tokio::fs::File)> {
+        let log_dir = self.log_dir.as_ref()?;
+        let path = log_dir.join(format!("{}.log", job.id));
+        let opened = async {
+            tokio::fs::create_dir_all(log_dir).await?;
+            tokio::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .await
+        };
+        match opened.await {
+            Ok(file) => Some((path.to_string_lossy().into_owned(), file)),
+            Err(e) => {
+                warn!(path = %path.display(), error = %e, "Failed to open job log");
+                None
+            }
+        }
+    }
+
+    /// Inject load-based parallelism hints and apply niceness / CPU affinity
+    pub fn with_resource_hints(mut self, resource_hints: ResourceHints) -> Self {
+        self.resource_hints = Some(resource_hints);
+        self
+    }
+
     /// Filter environment variables to allowlist only (ADR-040)
     fn filter_env(&self, env: &HashMap<String, String>) -> HashMap<String, String> {
         env.iter()
@@ -65,7 +158,9 @@ impl SubprocessExecutor {
 
     /// Parse job payload to extract execution parameters
     fn parse_payload(&self, job: &Job) -> ParseResult {
-        let payload = job.payload.as_value();
+        // Expand `{{subject_key}}` etc. before reading any field
+        let payload = &template::expand_payload(job)
+            .map_err(|e| ExecutionError::InvalidPayload(e.to_string()))?;
 
         let command = payload
             .get("command")
@@ -100,7 +195,8 @@ impl SubprocessExecutor {
             .unwrap_or(".")
             .to_string();
 
-        let timeout_ms = job.deadline.map(|d| {
+        // Services run indefinitely: no deadline-derived timeout
+        let timeout_ms = job.deadline.filter(|_| !job.is_service()).map(|d| {
             let now = self.time_provider.now_millis();
             (d - now).max(1000) // At least 1s
         });
@@ -108,72 +204,160 @@ impl SubprocessExecutor {
         Ok((command.to_string(), args, env, working_dir, timeout_ms))
     }
 
+    /// Build the command, with niceness and CPU affinity applied to the child
+    fn build_command(&self, command: &str, args: &[String]) -> Command {
+        let mut cmd = Command::new(command);
+        cmd.args(args);
+        #[cfg(unix)]
+        self.apply_resource_hints(&mut cmd);
+        cmd
+    }
+
+    /// Lower the child's priority and pin it to the configured CPUs (Linux)
+    ///
+    /// Both are set in the child between fork and exec, so a spawn failure
+    /// still surfaces as such and every process it forks inherits them.
+    /// Best-effort: a failing call leaves the child as it is.
+    #[cfg(unix)]
+    fn apply_resource_hints(&self, cmd: &mut Command) {
+        use nix::errno::Errno;
+        use nix::libc;
+
+        let Some(config) = self.resource_hints.as_ref().map(|h| h.config()) else {
+            return;
+        };
+        let nice = config.nice;
+
+        #[cfg(target_os = "linux")]
+        let cpu_set = config.cpu_affinity.as_ref().map(|cpus| {
+            let mut cpu_set = nix::sched::CpuSet::new();
+            for &cpu in cpus {
+                if let Err(e) = cpu_set.set(cpu) {
+                    warn!(cpu = cpu, error = %e, "Invalid CPU in affinity list");
+                }
+            }
+            cpu_set
+        });
+        #[cfg(not(target_os = "linux"))]
+        let cpu_set: Option<()> = None;
+
+        if nice.is_none() && cpu_set.is_none() {
+            return;
+        }
+
+        // SAFETY: the closure runs in the forked child and only makes
+        // async-signal-safe system calls (no allocation, no locks)
+        unsafe {
+            cmd.pre_exec(move || {
+                if let Some(nice) = nice {
+                    // Relative to the inherited niceness, as `nice -n` is
+                    Errno::clear();
+                    let current = libc::getpriority(libc::PRIO_PROCESS, 0);
+                    if current != -1 || Errno::last_raw() == 0 {
+                        libc::setpriority(libc::PRIO_PROCESS, 0, (current + nice).min(19));
+                    }
+                }
+                #[cfg(target_os = "linux")]
+                if let Some(cpu_set) = &cpu_set {
+                    let _ = nix::sched::sched_setaffinity(nix::unistd::Pid::from_raw(0), cpu_set);
+                }
+                Ok(())
+            });
+        }
+    }
+
     /// Spawn child process and wait for output
+    ///
+    /// `env` is passed as-is (already filtered and merged with resource hints).
+    /// Output lines are streamed to `log_file` as they are produced. The child
+    /// is listed by `running()` until it exits.
     async fn spawn_and_wait(
         &self,
+        job_id: &JobId,
         command: &str,
         args: &[String],
         env: &HashMap<String, String>,
         working_dir: &str,
-        timeout_ms: Option<i64>,
-    ) -> Result<std::process::Output, ExecutionError> {
-        let filtered_env = self.filter_env(env);
-
-        let child = Command::new(command)
-            .args(args)
-            .envs(&filtered_env)
+        log_file: Option<tokio::fs::File>,
+    ) -> Result<CapturedOutput, ExecutionError> {
+        let child = self
+            .build_command(command, args)
+            .envs(env)
             .current_dir(working_dir)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()
-            .map_err(|e| ExecutionError::SpawnFailed(e.to_string()))?;
+            .map_err(|e| match e.kind() {
+                // Same result on every attempt: not worth retrying
+                std::io::ErrorKind::NotFound | std::io::ErrorKind::PermissionDenied => {
+                    ExecutionError::Fatal(format!("Cannot run '{}': {}", command, e))
+                }
+                _ => ExecutionError::SpawnFailed(e.to_string()),
+            })?;
 
-        if let Some(timeout_ms_val) = timeout_ms {
-            match timeout(
-                Duration::from_millis(timeout_ms_val as u64),
-                child.wait_with_output(),
-            )
+        let _entry = self.track(job_id, child.id());
+
+        capture(child, self.time_provider.clone(), log_file)
             .await
-            {
-                Ok(Ok(output)) => Ok(output),
-                Ok(Err(e)) => Err(ExecutionError::IoError(e.to_string())),
-                Err(_) => Err(ExecutionError::Timeout(timeout_ms_val)),
-            }
-        } else {
-            child
-                .wait_with_output()
-                .await
-                .map_err(|e| ExecutionError::IoError(e.to_string()))
+            .map_err(|e| ExecutionError::IoError(e.to_string()))
+    }
+
+    /// List a spawned child in the running-process table
+    fn track(&self, job_id: &JobId, pid: Option<u32>) -> RunningEntry {
+        if let Some(pid) = pid {
+            self.running
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(
+                    pid,
+                    RunningProcess {
+                        job_id: job_id.clone(),
+                        pid,
+                        started_at: self.time_provider.now_millis(),
+                    },
+                );
+        }
+        RunningEntry {
+            running: self.running.clone(),
+            pid,
         }
     }
 
     /// Build execution result from process output
-    fn build_result(&self, output: std::process::Output, duration_ms: i64) -> ExecutionResult {
-        let status = if output.status.success() {
-            ExecutionStatus::Success
-        } else {
-            ExecutionStatus::Failed
-        };
+    fn build_result(
+        &self,
+        output: CapturedOutput,
+        duration_ms: i64,
+        outcome: ExitOutcome,
+    ) -> ExecutionResult {
+        let (status, error_class) = outcome.status();
 
         ExecutionResult {
             status,
             exit_code: output.status.code(),
             duration_ms,
-            stdout: Some(String::from_utf8_lossy(&output.stdout).to_string()),
-            stderr: Some(String::from_utf8_lossy(&output.stderr).to_string()),
+            stdout: Some(output.stdout),
+            stderr: Some(output.stderr),
+            environment: None,
+            log_path: None,
+            error_class,
         }
     }
 
     /// Internal execute method (extracted for function length compliance)
     async fn execute_internal(
         &self,
+        job: &Job,
         command: &str,
         args: &[String],
-        env: &HashMap<String, String>,
+        mut child_env: HashMap<String, String>,
         working_dir: &str,
         timeout_ms: Option<i64>,
     ) -> Result<ExecutionResult, ExecutionError> {
         let start_time = self.time_provider.now_millis();
+        // Rejected before spawning: a bad mapping fails every attempt
+        let job_exit_codes = exit_codes::from_payload(job.payload.as_value())
+            .map_err(ExecutionError::InvalidPayload)?;
 
         info!(
             command = %command,
@@ -183,14 +367,35 @@ impl SubprocessExecutor {
             "Starting subprocess execution"
         );
 
-        let output = self
-            .spawn_and_wait(command, args, env, working_dir, timeout_ms)
-            .await?;
+        // Resource hints the payload did not set
+        let mut manifest_vars = self.env_allowlist.clone();
+        if let Some(hints) = &self.resource_hints {
+            for (key, value) in hints.env().await {
+                manifest_vars.push(key.clone());
+                child_env.entry(key).or_insert(value);
+            }
+        }
+
+        let (log_path, log_file) = self.open_log(job).await.unzip();
+        let output = self.spawn_and_wait(&job.id, command, args, &child_env, working_dir, log_file);
+        let output = match timeout_ms {
+            Some(timeout_ms) => timeout(Duration::from_millis(timeout_ms as u64), output)
+                .await
+                .map_err(|_| ExecutionError::Timeout(timeout_ms))??,
+            None => output.await?,
+        };
 
         let end_time = self.time_provider.now_millis();
         let duration_ms = end_time - start_time;
 
-        let result = self.build_result(output, duration_ms);
+        let outcome = exit_codes::resolve(
+            output.status.code(),
+            job_exit_codes.as_ref(),
+            self.exit_codes.for_job_type(job.job_type.as_str()),
+        );
+        let mut result = self.build_result(output, duration_ms, outcome);
+        result.environment = Some(build_manifest(EXECUTOR_NAME, &manifest_vars, &child_env));
+        result.log_path = log_path;
 
         info!(
             command = %command,
@@ -268,7 +473,20 @@ impl SubprocessExecutor {
 impl TaskExecutor for SubprocessExecutor {
     async fn execute(&self, job: &Job) -> Result<ExecutionResult, ExecutionError> {
         let (command, args, env, working_dir, timeout_ms) = self.parse_payload(job)?;
-        self.execute_internal(&command, &args, &env, &working_dir, timeout_ms)
+
+        // Allowlisted payload env, plus the caller's trace context so the
+        // child's own spans join the trace the job was enqueued under
+        let mut child_env = self.filter_env(&env);
+        if let Some(traceparent) = &job.trace_id {
+            child_env.insert(TRACEPARENT_ENV.to_string(), traceparent.clone());
+        }
+        // Enough for the child to call job.report_progress.v1 on itself
+        child_env.insert(JOB_ID_ENV.to_string(), job.id.clone());
+        if let Some(rpc_url) = &self.rpc_url {
+            child_env.insert(RPC_URL_ENV.to_string(), rpc_url.clone());
+        }
+
+        self.execute_internal(job, &command, &args, child_env, &working_dir, timeout_ms)
             .await
     }
 
@@ -302,6 +520,15 @@ impl TaskExecutor for SubprocessExecutor {
             }
         }
     }
+
+    fn running(&self) -> Vec<RunningProcess> {
+        self.running
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .cloned()
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -309,6 +536,54 @@ mod tests {
     use super::*;
     use semantica_core::domain::{ExecutionMode, Job, JobPayload, JobType};
     use semantica_core::port::time_provider::SystemTimeProvider;
+    use semantica_core::port::{ErrorClass, ExecutionStatus};
+
+    #[tokio::test]
+    async fn test_output_lines_are_tagged_in_log() {
+        use semantica_core::domain::{LogLine, LogStream};
+
+        let log_dir = std::env::temp_dir().join(format!("semantica_log_test_{}", unique_suffix()));
+        let executor =
+            SubprocessExecutor::new(Arc::new(SystemTimeProvider), vec![]).with_log_dir(&log_dir);
+
+        let job = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "test::subject",
+            1,
+            JobPayload::new(serde_json::json!({
+                "command": "sh",
+                "args": ["-c", "echo out; echo err >&2"]
+            })),
+        );
+
+        let result = executor.execute(&job).await.unwrap();
+        assert_eq!(result.stdout.as_deref(), Some("out\n"));
+        assert_eq!(result.stderr.as_deref(), Some("err\n"));
+
+        let log_path = result.log_path.expect("log written");
+        let content = std::fs::read_to_string(&log_path).unwrap();
+        let mut lines: Vec<LogLine> = content
+            .lines()
+            .map(|record| LogLine::parse_record(record).expect("tagged record"))
+            .collect();
+        lines.sort_by_key(|line| line.stream.as_str());
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].stream, LogStream::Stderr);
+        assert_eq!(lines[0].line, "err");
+        assert_eq!(lines[1].stream, LogStream::Stdout);
+        assert_eq!(lines[1].line, "out");
+        assert!(lines.iter().all(|line| line.ts > 0));
+
+        std::fs::remove_dir_all(&log_dir).unwrap();
+    }
+
+    fn unique_suffix() -> u128 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    }
 
     #[tokio::test]
     async fn test_execute_success() {
@@ -333,6 +608,113 @@ mod tests {
 
         assert_eq!(result.status, ExecutionStatus::Success);
         assert!(result.stdout.unwrap_or_default().contains("hello"));
+
+        let manifest = result.environment.expect("manifest recorded");
+        assert_eq!(manifest.executor, "subprocess");
+        assert!(manifest
+            .env_hashes
+            .keys()
+            .all(|k| k == "PATH" || k == "HOME"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_expands_payload_template() {
+        let executor = SubprocessExecutor::new(Arc::new(SystemTimeProvider), vec![]);
+
+        let job = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "src/lib.rs",
+            1,
+            JobPayload::new(serde_json::json!({
+                "command": "echo",
+                "args": ["{{mode}}:{{subject_key}}"],
+                "vars": {"mode": "index"}
+            })),
+        );
+
+        let result = executor.execute(&job).await.unwrap();
+
+        assert_eq!(result.status, ExecutionStatus::Success);
+        assert!(result
+            .stdout
+            .unwrap_or_default()
+            .contains("index:src/lib.rs"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_command_is_fatal() {
+        let executor = SubprocessExecutor::new(Arc::new(SystemTimeProvider), vec![]);
+
+        let job = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "src/lib.rs",
+            1,
+            JobPayload::new(serde_json::json!({"command": "semantica-no-such-command"})),
+        );
+        let err = executor.execute(&job).await.unwrap_err();
+        assert!(matches!(err, ExecutionError::Fatal(_)), "{}", err);
+        assert_eq!(err.class(), ErrorClass::Fatal);
+
+        // Shell reports a missing command with exit code 127
+        let job = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "src/lib.rs",
+            1,
+            JobPayload::new(serde_json::json!({
+                "command": "sh",
+                "args": ["-c", "exit 127"]
+            })),
+        );
+        let result = executor.execute(&job).await.unwrap();
+        assert_eq!(result.status, ExecutionStatus::Failed);
+        assert_eq!(result.error_class, Some(ErrorClass::Fatal));
+    }
+
+    #[tokio::test]
+    async fn test_exit_codes_map_to_outcomes() {
+        let executor = SubprocessExecutor::new(Arc::new(SystemTimeProvider), vec![])
+            .with_exit_codes(ExitCodeMaps::from_spec("LINT:2=failed/3=retry").unwrap());
+        let run = |code: i32, exit_codes: Option<serde_json::Value>| {
+            let mut payload = serde_json::json!({
+                "command": "sh",
+                "args": ["-c", format!("exit {}", code)]
+            });
+            if let Some(exit_codes) = exit_codes {
+                payload["exit_codes"] = exit_codes;
+            }
+            Job::new_test(
+                "test_queue",
+                JobType::new("LINT"),
+                "src/lib.rs",
+                1,
+                JobPayload::new(payload),
+            )
+        };
+
+        // Job type mapping
+        let result = executor.execute(&run(2, None)).await.unwrap();
+        assert_eq!(result.status, ExecutionStatus::Failed);
+        assert_eq!(result.error_class, Some(ErrorClass::Fatal));
+        let result = executor.execute(&run(3, None)).await.unwrap();
+        assert_eq!(result.error_class, Some(ErrorClass::Retryable));
+
+        // The job's own mapping wins: 2 = "nothing to do"
+        let result = executor
+            .execute(&run(2, Some(serde_json::json!({"2": "success"}))))
+            .await
+            .unwrap();
+        assert_eq!(result.status, ExecutionStatus::Success);
+        assert_eq!(result.exit_code, Some(2));
+
+        // A broken mapping is rejected before anything runs
+        let err = executor
+            .execute(&run(0, Some(serde_json::json!({"2": "later"}))))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ExecutionError::InvalidPayload(_)), "{}", err);
     }
 
     #[tokio::test]
@@ -355,6 +737,150 @@ mod tests {
         let result = executor.execute(&job).await;
 
         assert!(matches!(result, Err(ExecutionError::Timeout(_))));
+        assert!(executor.running().is_empty(), "Abandoned child untracked");
+    }
+
+    #[tokio::test]
+    async fn test_running_lists_child_while_executing() {
+        let executor = Arc::new(SubprocessExecutor::new(
+            Arc::new(SystemTimeProvider),
+            vec![],
+        ));
+
+        let job = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "test::subject",
+            1,
+            JobPayload::new(serde_json::json!({
+                "command": "sleep",
+                "args": ["0.5"]
+            })),
+        );
+        let job_id = job.id.clone();
+        let exec = tokio::spawn({
+            let executor = executor.clone();
+            async move { executor.execute(&job).await }
+        });
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let running = executor.running();
+        assert_eq!(running.len(), 1);
+        assert_eq!(running[0].job_id, job_id);
+        assert!(executor.is_alive(running[0].pid as i32));
+
+        exec.await.unwrap().unwrap();
+        assert!(executor.running().is_empty());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_resource_hints_injected() {
+        use crate::resource_hints::ResourceHintConfig;
+        use semantica_core::port::system_probe::mocks::MockSystemProbe;
+
+        let hints = ResourceHints::new(
+            Arc::new(MockSystemProbe::new(100.0)), // User fully active
+            ResourceHintConfig {
+                max_jobs: 8,
+                min_jobs: 2,
+                busy_cpu_percent: 30.0,
+                nice: Some(5),
+                cpu_affinity: None,
+            },
+        );
+        let executor = SubprocessExecutor::new(Arc::new(SystemTimeProvider), vec![])
+            .with_resource_hints(hints);
+
+        let mut job = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "test::subject",
+            1,
+            JobPayload::new(serde_json::json!({
+                "command": "sh",
+                "args": ["-c", "echo $CARGO_BUILD_JOBS $MAKEFLAGS $(nice)"]
+            })),
+        );
+        job.execution_mode = Some(ExecutionMode::Subprocess);
+
+        let result = executor.execute(&job).await.unwrap();
+
+        // `nice` without arguments prints the current niceness
+        let base_nice: i32 =
+            String::from_utf8_lossy(&std::process::Command::new("nice").output().unwrap().stdout)
+                .trim()
+                .parse()
+                .unwrap();
+        assert_eq!(
+            result.stdout.unwrap_or_default().trim(),
+            format!("2 -j2 {}", (base_nice + 5).min(19))
+        );
+        let manifest = result.environment.expect("manifest recorded");
+        assert!(manifest.env_hashes.contains_key("CARGO_BUILD_JOBS"));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_resource_hints_apply_to_forked_children() {
+        use crate::resource_hints::ResourceHintConfig;
+        use semantica_core::port::system_probe::mocks::MockSystemProbe;
+
+        let executor = SubprocessExecutor::new(Arc::new(SystemTimeProvider), vec![])
+            .with_resource_hints(ResourceHints::new(
+                Arc::new(MockSystemProbe::new(0.0)),
+                ResourceHintConfig {
+                    max_jobs: 8,
+                    min_jobs: 2,
+                    busy_cpu_percent: 30.0,
+                    nice: Some(5),
+                    cpu_affinity: Some(vec![0]),
+                },
+            ));
+
+        // A grandchild forked by the job's shell is pinned as well
+        let mut job = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "test::subject",
+            1,
+            JobPayload::new(serde_json::json!({
+                "command": "sh",
+                "args": ["-c", "sh -c 'grep Cpus_allowed_list /proc/self/status'"]
+            })),
+        );
+        job.execution_mode = Some(ExecutionMode::Subprocess);
+        let result = executor.execute(&job).await.unwrap();
+        assert!(result.stdout.unwrap_or_default().trim().ends_with("\t0"));
+
+        // A missing command is still reported as one, not as exit code 127
+        job.payload = JobPayload::new(serde_json::json!({"command": "no-such-command-xyz"}));
+        let err = executor.execute(&job).await.unwrap_err();
+        assert!(matches!(err, ExecutionError::Fatal(_)), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_job_id_and_rpc_url_passed_to_child() {
+        let executor = SubprocessExecutor::new(Arc::new(SystemTimeProvider), vec![])
+            .with_rpc_url("http://127.0.0.1:9527");
+
+        let mut job = Job::new_test(
+            "test_queue",
+            JobType::new("TEST"),
+            "test::subject",
+            1,
+            JobPayload::new(serde_json::json!({
+                "command": "sh",
+                "args": ["-c", "echo $SEMANTICA_JOB_ID $SEMANTICA_RPC_URL"]
+            })),
+        );
+        job.execution_mode = Some(ExecutionMode::Subprocess);
+
+        let result = executor.execute(&job).await.unwrap();
+        assert_eq!(
+            result.stdout.unwrap_or_default().trim(),
+            format!("{} http://127.0.0.1:9527", job.id)
+        );
     }
 
     #[tokio::test]
@@ -1,65 +1,28 @@
 // System probe implementation (Phase 2)
 // reason: sysinfo for cross-platform system monitoring (ADR-001)
 use async_trait::async_trait;
+use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
-use std::time::{Duration, Instant};
-use sysinfo::{Disks, System};
+use std::time::Duration;
+use sysinfo::{Disks, Pid, System};
 use tracing::debug;
 
+use crate::idle_tracker::IdleTracker;
+use crate::probe_config::DiskMetrics;
+use crate::procfs_probe::battery_status;
 use semantica_core::application::worker::constants::IDLE_TRACKER_MAX_SAMPLES;
 use semantica_core::port::system_probe::{SystemMetrics, SystemProbe};
 
+/// Ancestors walked when attributing a process to a job (guards against cycles)
+const MAX_PROCESS_DEPTH: usize = 64;
+
 /// System probe implementation using sysinfo
 ///
 /// Tracks CPU usage over time to detect idle state
 pub struct SystemProbeImpl {
     system: Arc<Mutex<System>>,
     idle_tracker: Arc<Mutex<IdleTracker>>,
-}
-
-/// Tracks CPU usage history for idle detection
-struct IdleTracker {
-    samples: Vec<(Instant, f32)>,
-    max_samples: usize,
-}
-
-impl IdleTracker {
-    fn new(max_samples: usize) -> Self {
-        Self {
-            samples: Vec::new(),
-            max_samples,
-        }
-    }
-
-    /// Record a CPU usage sample
-    fn record(&mut self, cpu_usage: f32) {
-        let now = Instant::now();
-        self.samples.push((now, cpu_usage));
-
-        // Keep only recent samples
-        if self.samples.len() > self.max_samples {
-            self.samples.remove(0);
-        }
-    }
-
-    /// Check if CPU has been below threshold for given duration
-    fn is_idle(&self, threshold: f32, duration: Duration) -> bool {
-        let cutoff = Instant::now() - duration;
-
-        // Check all samples within the time window
-        let recent_samples: Vec<&(Instant, f32)> = self
-            .samples
-            .iter()
-            .filter(|(time, _)| *time >= cutoff)
-            .collect();
-
-        if recent_samples.is_empty() {
-            return false;
-        }
-
-        // All samples must be below threshold
-        recent_samples.iter().all(|(_, cpu)| *cpu < threshold)
-    }
+    disk_metrics: DiskMetrics,
 }
 
 impl SystemProbeImpl {
@@ -71,10 +34,17 @@ impl SystemProbeImpl {
     /// ```
     pub fn new() -> Self {
         Self {
-            system: Arc::new(Mutex::new(System::new_all())),
+            system: Arc::new(Mutex::new(System::new())),
             idle_tracker: Arc::new(Mutex::new(IdleTracker::new(IDLE_TRACKER_MAX_SAMPLES))),
+            disk_metrics: DiskMetrics::Always,
         }
     }
+
+    /// When to scan disks on each poll (the scan is the most expensive metric)
+    pub fn with_disk_metrics(mut self, disk_metrics: DiskMetrics) -> Self {
+        self.disk_metrics = disk_metrics;
+        self
+    }
 }
 
 impl Default for SystemProbeImpl {
@@ -83,13 +53,27 @@ impl Default for SystemProbeImpl {
     }
 }
 
+/// Used and total space of the first disk (GB)
+pub(crate) fn disk_usage_gb() -> (u64, u64) {
+    let disks = Disks::new_with_refreshed_list();
+    if let Some(disk) = disks.first() {
+        let total = disk.total_space() / 1024 / 1024 / 1024;
+        let available = disk.available_space() / 1024 / 1024 / 1024;
+        let used = total - available;
+        (used, total)
+    } else {
+        (0, 0)
+    }
+}
+
 #[async_trait]
 impl SystemProbe for SystemProbeImpl {
     async fn get_metrics(&self) -> SystemMetrics {
         let mut sys = self.system.lock().unwrap();
 
-        // Refresh system information
-        sys.refresh_all();
+        // Only what is reported: refresh_all also walks every process
+        sys.refresh_cpu();
+        sys.refresh_memory();
 
         // CPU usage (global average)
         let cpu_usage_percent = sys.global_cpu_info().cpu_usage();
@@ -98,21 +82,16 @@ impl SystemProbe for SystemProbeImpl {
         let memory_used_mb = sys.used_memory() / 1024 / 1024;
         let memory_total_mb = sys.total_memory() / 1024 / 1024;
 
+        // Battery (sysinfo has none: read from /sys/class/power_supply)
+        let (battery_percent, is_charging) = battery_status();
+
         // Disk (first disk)
-        let disks = Disks::new_with_refreshed_list();
-        let (disk_used_gb, disk_total_gb) = if let Some(disk) = disks.first() {
-            let total = disk.total_space() / 1024 / 1024 / 1024;
-            let available = disk.available_space() / 1024 / 1024 / 1024;
-            let used = total - available;
-            (used, total)
+        let (disk_used_gb, disk_total_gb) = if self.disk_metrics.should_collect(is_charging) {
+            disk_usage_gb()
         } else {
             (0, 0)
         };
 
-        // Battery (not supported by sysinfo yet, placeholder)
-        let battery_percent = None;
-        let is_charging = None;
-
         // Record CPU sample for idle tracking
         let mut tracker = self.idle_tracker.lock().unwrap();
         tracker.record(cpu_usage_percent);
@@ -152,6 +131,37 @@ impl SystemProbe for SystemProbeImpl {
 
         is_idle
     }
+
+    async fn process_tree_memory_mb(&self, pids: &[u32]) -> u64 {
+        if pids.is_empty() {
+            return 0;
+        }
+        let roots: HashSet<Pid> = pids.iter().map(|&pid| Pid::from_u32(pid)).collect();
+
+        let mut sys = self.system.lock().unwrap();
+        sys.refresh_processes();
+
+        // Threads share their process's memory: count processes only
+        let bytes: u64 = sys
+            .processes()
+            .values()
+            .filter(|process| process.thread_kind().is_none())
+            .filter(|process| {
+                let mut current = Some(process.pid());
+                for _ in 0..MAX_PROCESS_DEPTH {
+                    match current {
+                        Some(pid) if roots.contains(&pid) => return true,
+                        Some(pid) => current = sys.process(pid).and_then(|p| p.parent()),
+                        None => return false,
+                    }
+                }
+                false
+            })
+            .map(|process| process.memory())
+            .sum();
+
+        bytes / 1024 / 1024
+    }
 }
 
 #[cfg(test)]
@@ -169,6 +179,27 @@ mod tests {
         assert!(metrics.memory_total_mb > 0);
     }
 
+    #[tokio::test]
+    async fn test_process_tree_memory_includes_children() {
+        let probe = SystemProbeImpl::new();
+        let mut child = std::process::Command::new("sleep")
+            .arg("5")
+            .spawn()
+            .unwrap();
+
+        let own = probe.process_tree_memory_mb(&[std::process::id()]).await;
+        let child_only = probe.process_tree_memory_mb(&[child.id()]).await;
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        assert!(own > 0, "Test process has resident memory");
+        assert!(
+            own >= child_only,
+            "Tree of the test process includes its child"
+        );
+        assert_eq!(probe.process_tree_memory_mb(&[]).await, 0);
+    }
+
     #[tokio::test]
     async fn test_idle_detection() {
         let probe = SystemProbeImpl::new();
@@ -185,24 +216,4 @@ mod tests {
         // We can't assert true/false since it depends on actual CPU usage
         // Just verify it doesn't panic
     }
-
-    #[test]
-    fn test_idle_tracker() {
-        let mut tracker = IdleTracker::new(10);
-
-        // Record low CPU usage samples
-        for _ in 0..5 {
-            tracker.record(5.0);
-            std::thread::sleep(Duration::from_millis(10));
-        }
-
-        // Should be idle with threshold 10.0 and duration 1s
-        assert!(tracker.is_idle(10.0, Duration::from_secs(1)));
-
-        // Add high CPU sample
-        tracker.record(95.0);
-
-        // Should NOT be idle anymore
-        assert!(!tracker.is_idle(10.0, Duration::from_secs(1)));
-    }
 }
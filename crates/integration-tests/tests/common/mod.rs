@@ -0,0 +1,117 @@
+//! Shared setup for the integration test suites
+//!
+//! 각 테스트 파일이 `mod common;`으로 가져다 쓰는 DB 준비 코드와 테스트용 구현체
+
+#![allow(dead_code)]
+
+use async_trait::async_trait;
+use semantica_core::domain::Job;
+use semantica_core::port::{
+    EnvironmentManifest, ExecutionError, ExecutionResult, ExecutionStatus, TaskExecutor,
+    TimeProvider,
+};
+use semantica_infra_sqlite::{create_pool, run_migrations};
+use sqlx::SqlitePool;
+use std::sync::atomic::{AtomicI64, Ordering};
+use tokio::sync::Notify;
+
+/// In-memory database with every migration applied
+pub async fn setup_pool() -> SqlitePool {
+    let pool = create_pool(":memory:").await.unwrap();
+    run_migrations(&pool).await.unwrap();
+    pool
+}
+
+/// Clock that only moves when the test stores a new value
+pub struct StepTime(pub AtomicI64);
+
+impl TimeProvider for StepTime {
+    fn now_millis(&self) -> i64 {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+fn result(status: ExecutionStatus, exit_code: i32) -> ExecutionResult {
+    ExecutionResult {
+        status,
+        duration_ms: 0,
+        exit_code: Some(exit_code),
+        stdout: None,
+        stderr: None,
+        environment: None,
+        log_path: None,
+        error_class: None,
+    }
+}
+
+/// BUILD blocks until released, everything else finishes at once
+pub struct GatedExecutor {
+    pub release: Notify,
+}
+
+#[async_trait]
+impl TaskExecutor for GatedExecutor {
+    async fn execute(&self, job: &Job) -> Result<ExecutionResult, ExecutionError> {
+        if job.job_type.as_str() == "BUILD" {
+            self.release.notified().await;
+        }
+        Ok(result(ExecutionStatus::Success, 0))
+    }
+
+    async fn kill(&self, _pid: i32) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    fn is_alive(&self, _pid: i32) -> bool {
+        false
+    }
+}
+
+/// BUILD never finishes, everything else at once
+pub struct HangingExecutor;
+
+#[async_trait]
+impl TaskExecutor for HangingExecutor {
+    async fn execute(&self, job: &Job) -> Result<ExecutionResult, ExecutionError> {
+        if job.job_type.as_str() == "BUILD" {
+            std::future::pending::<()>().await;
+        }
+        Ok(result(ExecutionStatus::Success, 0))
+    }
+
+    async fn kill(&self, _pid: i32) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    fn is_alive(&self, _pid: i32) -> bool {
+        false
+    }
+}
+
+/// Fails every run on "machine-b"
+pub struct FailingExecutor;
+
+#[async_trait]
+impl TaskExecutor for FailingExecutor {
+    async fn execute(&self, _job: &Job) -> Result<ExecutionResult, ExecutionError> {
+        Ok(ExecutionResult {
+            environment: Some(EnvironmentManifest {
+                executor: "subprocess".to_string(),
+                executor_version: "1".to_string(),
+                hostname: Some("machine-b".to_string()),
+                os: "linux".to_string(),
+                arch: "x86_64".to_string(),
+                env_hashes: Default::default(),
+            }),
+            ..result(ExecutionStatus::Failed, 1)
+        })
+    }
+
+    async fn kill(&self, _pid: i32) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    fn is_alive(&self, _pid: i32) -> bool {
+        false
+    }
+}
@@ -36,6 +36,11 @@ async fn test_concurrent_pop_no_duplicates() {
             subject_key: format!("file-{}.rs", i),
             payload: serde_json::json!({"id": i}),
             priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
         };
         job_ids.push(service.enqueue(req).await.unwrap());
     }
@@ -104,6 +109,11 @@ async fn test_malicious_input_validation() {
         subject_key: "test.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let result1 = service.enqueue(req1).await;
     assert!(result1.is_err(), "Should reject queue name > 255 bytes");
@@ -115,6 +125,11 @@ async fn test_malicious_input_validation() {
         subject_key: "test.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let result2 = service.enqueue(req2).await;
     // Should either reject or safely escape (both OK)
@@ -131,6 +146,11 @@ async fn test_malicious_input_validation() {
         subject_key: "test\0.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let result3 = service.enqueue(req3).await;
     assert!(result3.is_err(), "Should reject null byte in subject_key");
@@ -145,6 +165,11 @@ async fn test_malicious_input_validation() {
         subject_key: "test.rs".to_string(),
         payload: large_payload,
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let result4 = service.enqueue(req4).await;
     assert!(result4.is_err(), "Should reject payload > 10MB");
@@ -175,6 +200,11 @@ async fn test_boundary_values() {
         subject_key: "max-priority.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 100, // Valid range: -100 to 100
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let id1 = service.enqueue(req1).await.unwrap();
     let job1 = job_repo.find_by_id(&id1).await.unwrap().unwrap();
@@ -187,6 +217,11 @@ async fn test_boundary_values() {
         subject_key: "min-priority.rs".to_string(),
         payload: serde_json::json!({}),
         priority: -100,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let id2 = service.enqueue(req2).await.unwrap();
     let job2 = job_repo.find_by_id(&id2).await.unwrap().unwrap();
@@ -203,6 +238,11 @@ async fn test_boundary_values() {
         subject_key: "invalid.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 101, // Out of range
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     assert!(
         service.enqueue(req_invalid).await.is_err(),
@@ -216,6 +256,11 @@ async fn test_boundary_values() {
         subject_key: "same-subject".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
 
     // Enqueue many times for same subject_key
@@ -272,6 +317,11 @@ async fn test_supersede_concurrent_enqueue() {
                 subject_key: "same-file.rs".to_string(),
                 payload: serde_json::json!({"version": i}),
                 priority: 0,
+                idempotency_key: None,
+                traceparent: None,
+                job_class: None,
+                fallback_payloads: Vec::new(),
+                options: Default::default(),
             };
             svc.enqueue(req).await.unwrap()
         });
@@ -418,3 +468,41 @@ async fn test_max_attempts_zero() {
 
     println!("✅ Max attempts = 0: Correctly fails without retry");
 }
+
+/// Critical Test: System clock set backwards
+/// 시계가 뒤로 가서 started_at이 "미래"인 RUNNING job도 재시작 시 복구되는가?
+#[tokio::test]
+async fn test_recovery_tolerates_clock_set_backwards() {
+    use semantica_core::application::recovery::RecoveryService;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::port::task_executor::mocks::MockTaskExecutor;
+
+    let pool = create_pool(":memory:").await.unwrap();
+    run_migrations(&pool).await.unwrap();
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+
+    // Started one hour "from now": the clock jumped back after an NTP sync
+    let mut job = Job::new_test(
+        "default",
+        JobType::new("INDEX"),
+        "src/a.rs",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job.state = JobState::Running;
+    job.started_at = Some(time_provider.now_millis() + 60 * 60 * 1000);
+    job_repo.insert(&job).await.unwrap();
+
+    let recovery = RecoveryService::new(
+        job_repo.clone(),
+        Arc::new(MockTaskExecutor::new_success()),
+        time_provider,
+        None,
+    );
+    assert_eq!(recovery.recover_orphaned_jobs().await.unwrap(), 1);
+
+    let recovered = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_ne!(recovered.state, JobState::Running);
+}
@@ -0,0 +1,239 @@
+//! Dead-Letter Queue Tests
+//!
+//! 최종 실패 작업의 DLQ 적재, 재투입, poison 작업 격리 검증
+
+use semantica_core::domain::JobState;
+use semantica_core::port::job_repository::JobRepository;
+use semantica_core::port::time_provider::SystemTimeProvider;
+use semantica_core::port::TimeProvider;
+use semantica_infra_sqlite::SqliteJobRepository;
+use std::sync::Arc;
+
+mod common;
+
+/// Dead-letter queue
+/// 최종 실패한 job이 마지막 오류와 함께 DLQ에 남고, requeue하면 DLQ에서 빠져 다시 실행되는가?
+#[tokio::test]
+async fn test_failed_job_lands_in_dead_letter_queue() {
+    use semantica_core::application::dev_task::dead_letter::{self, RequeueTarget};
+    use semantica_core::application::lease::LeaseService;
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::LeaseOutcome;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::error::AppError;
+
+    let pool = common::setup_pool().await;
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let leases = LeaseService::new(
+        job_repo.clone(),
+        Arc::new(RetryPolicy::new(time_provider.clone(), 1000)),
+        time_provider.clone(),
+    );
+
+    let job = Job::new_test(
+        "external",
+        JobType::new("PY_INDEX"),
+        "repo/a.py",
+        1,
+        JobPayload::new(serde_json::json!({"path": "repo/a.py"})),
+    );
+    job_repo.insert(&job).await.unwrap();
+
+    leases
+        .lease("external", "worker-a", None)
+        .await
+        .unwrap()
+        .expect("job should be leased");
+    let state = leases
+        .complete(
+            &job.id,
+            "worker-a",
+            LeaseOutcome::Failed {
+                error: "SyntaxError: invalid syntax".to_string(),
+                retryable: false,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(state, JobState::Failed, "not retryable");
+
+    let dead = dead_letter::list(job_repo.as_ref(), Some("external"), 10)
+        .await
+        .unwrap();
+    assert_eq!(dead.len(), 1);
+    assert_eq!(dead[0].job_id, job.id);
+    assert_eq!(dead[0].queue, "external");
+    assert_eq!(dead[0].last_error, "SyntaxError: invalid syntax");
+
+    // Requeue: back to QUEUED, out of the DLQ; a second requeue is reported, not fatal
+    let report = dead_letter::requeue(
+        job_repo.as_ref(),
+        time_provider.as_ref(),
+        RequeueTarget::Jobs(vec![job.id.clone(), job.id.clone()]),
+        "cli",
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(report.requeued.len(), 1);
+    assert_eq!(report.requeued[0].state, JobState::Queued);
+    assert!(matches!(report.errors[..], [(_, AppError::Conflict(_))]));
+    assert!(dead_letter::list(job_repo.as_ref(), None, 10)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // Fails again: purge drops the entry but keeps the FAILED job
+    leases.lease("external", "worker-a", None).await.unwrap();
+    leases
+        .complete(
+            &job.id,
+            "worker-a",
+            LeaseOutcome::Failed {
+                error: "still broken".to_string(),
+                retryable: false,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        dead_letter::purge(job_repo.as_ref(), None, None)
+            .await
+            .unwrap(),
+        1
+    );
+    let job = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(job.state, JobState::Failed);
+}
+
+/// Requeueing stale dead letters
+/// DLQ에서 requeue할 때 superseded job은 거부하고, TTL이 지난 job은 바로 만료되지 않는가?
+#[tokio::test]
+async fn test_dead_letter_requeue_skips_superseded_and_restarts_ttl() {
+    use semantica_core::application::dev_task::dead_letter::{self, RequeueTarget};
+    use semantica_core::domain::{DeadLetter, Job, JobPayload, JobType};
+    use semantica_core::error::AppError;
+
+    let pool = common::setup_pool().await;
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = SqliteJobRepository::new(pool, time_provider.clone());
+
+    let dead_job = |subject: &str| {
+        let mut job = Job::new_test(
+            "default",
+            JobType::new("BUILD"),
+            subject,
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job.state = JobState::Failed;
+        job.ttl_ms = Some(60_000);
+        job
+    };
+    let stale = dead_job("repo/a");
+    let expired = dead_job("repo/b");
+    for job in [&stale, &expired] {
+        job_repo.insert(job).await.unwrap();
+        job_repo
+            .record_dead_letter(&DeadLetter::new(job, "boom", time_provider.now_millis()))
+            .await
+            .unwrap();
+    }
+
+    // repo/a was enqueued again after it died
+    let newer = Job::new_test(
+        "default",
+        JobType::new("BUILD"),
+        "repo/a",
+        2,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job_repo.insert(&newer).await.unwrap();
+
+    let report = dead_letter::requeue(
+        &job_repo,
+        time_provider.as_ref(),
+        RequeueTarget::All { queue: None },
+        "cli",
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(report.requeued.len(), 1);
+    assert_eq!(report.requeued[0].id, expired.id);
+    assert!(matches!(&report.errors[..], [(id, AppError::Conflict(_))] if *id == stale.id));
+
+    // The requeued job waits from now, not from its original enqueue
+    let swept = job_repo
+        .expire_queued(time_provider.now_millis(), "sweeper")
+        .await
+        .unwrap();
+    assert!(swept.is_empty());
+    let stored = job_repo.find_by_id(&expired.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Queued);
+}
+
+/// Poison-job detection
+/// 반복해서 panic하는 job이 attempts와 별도로 세어지고, 한도에 닿으면 재시도 대신 격리되는가?
+#[tokio::test]
+async fn test_repeatedly_panicking_job_is_quarantined() {
+    use semantica_core::application::dev_task::dead_letter;
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::scheduler::Scheduler;
+    use semantica_core::application::worker::Worker;
+    use semantica_core::domain::job_event::actor;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::port::system_probe::mocks::MockSystemProbe;
+    use semantica_core::port::task_executor::mocks::MockTaskExecutor;
+    use semantica_core::port::SystemProbe;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let probe: Arc<dyn SystemProbe> = Arc::new(MockSystemProbe::new(25.0));
+    let executor = Arc::new(MockTaskExecutor::new_panic_inducing("index corrupted"));
+    let worker = Worker::new(
+        "default",
+        job_repo.clone(),
+        executor.clone(),
+        probe.clone(),
+        Arc::new(RetryPolicy::new(time_provider.clone(), 1000).with_max_panics(2)),
+        Arc::new(Scheduler::new(probe, time_provider.clone())),
+        time_provider,
+    );
+
+    let job = Job::new_test(
+        "default",
+        JobType::new("INDEX"),
+        "src/lib.rs",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job_repo.insert(&job).await.unwrap();
+
+    // First panic: requeued without using up an attempt
+    assert!(worker.process_next_job().await.unwrap());
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Queued);
+    assert_eq!((stored.attempts, stored.panic_count), (0, 1));
+
+    // Second panic: poisoned, quarantined instead of retried
+    assert!(worker.process_next_job().await.unwrap());
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Failed);
+    assert_eq!(stored.panic_count, 2);
+    assert_eq!(executor.call_count(), 2);
+    assert!(!worker.process_next_job().await.unwrap(), "not retried");
+
+    let events = job_repo.list_events(&job.id).await.unwrap();
+    let warning = events.last().unwrap();
+    assert_eq!(warning.actor, actor::QUARANTINE);
+    assert!(warning.reason.as_deref().unwrap().starts_with("poisoned"));
+    let dead = dead_letter::list(job_repo.as_ref(), None, 10)
+        .await
+        .unwrap();
+    assert_eq!(dead.len(), 1);
+    assert!(dead[0].last_error.contains("panicked 2 times"));
+}
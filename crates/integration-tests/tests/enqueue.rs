@@ -0,0 +1,382 @@
+//! Enqueue Tests
+//!
+//! 멱등 키, trace context, coalescing, 옵션 저장, 원격 forwarding 등 enqueue 경로 검증
+
+use semantica_core::application::dev_task::DevTaskService;
+use semantica_core::application::dev_task::EnqueueRequest;
+use semantica_core::domain::JobState;
+use semantica_core::port::job_repository::JobRepository;
+use semantica_core::port::time_provider::SystemTimeProvider;
+use semantica_core::port::TimeProvider;
+use semantica_infra_sqlite::SqliteJobRepository;
+use std::sync::Arc;
+
+mod common;
+
+/// Idempotent enqueue
+/// 같은 idempotency_key로 재전송(SDK 오프라인 저널 replay)해도 job이 하나만 생기는가?
+#[tokio::test]
+async fn test_idempotent_enqueue_replay() {
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(
+        pool.clone(),
+        time_provider.clone(),
+    ));
+
+    let service = DevTaskService::new(
+        job_repo.clone(),
+        Arc::new(semantica_core::port::id_provider::UuidProvider),
+        time_provider,
+    );
+
+    let req = EnqueueRequest {
+        job_type: "TEST".to_string(),
+        queue: "default".to_string(),
+        subject_key: "replayed.rs".to_string(),
+        payload: serde_json::json!({}),
+        priority: 0,
+        idempotency_key: Some("editor-42".to_string()),
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
+    };
+
+    let first = service.enqueue(req.clone()).await.unwrap();
+    let replayed = service.enqueue(req.clone()).await.unwrap();
+
+    assert_eq!(first, replayed, "Replay must resolve to the original job");
+    assert_eq!(
+        job_repo
+            .count_by_state("default", JobState::Queued)
+            .await
+            .unwrap(),
+        1,
+        "Replay must not create a second job"
+    );
+
+    // The job is gone (collected without its key): a replay enqueues it again
+    sqlx::query("DELETE FROM jobs WHERE id = ?")
+        .bind(&first)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let after_gc = service.enqueue(req).await.unwrap();
+    assert_ne!(after_gc, first);
+    assert!(job_repo.find_by_id(&after_gc).await.unwrap().is_some());
+
+    println!("✅ Idempotent enqueue: Replay deduplicated");
+}
+
+/// Trace context propagation
+/// 호출자가 넘긴 traceparent가 job에 저장되고, 잘못된 값은 거부되는가?
+#[tokio::test]
+async fn test_enqueue_stores_traceparent() {
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+
+    let service = DevTaskService::new(
+        job_repo.clone(),
+        Arc::new(semantica_core::port::id_provider::UuidProvider),
+        time_provider,
+    );
+
+    let mut req = EnqueueRequest {
+        job_type: "TEST".to_string(),
+        queue: "default".to_string(),
+        subject_key: "traced.rs".to_string(),
+        payload: serde_json::json!({}),
+        priority: 0,
+        idempotency_key: None,
+        traceparent: Some("00-4BF92F3577B34DA6A3CE929D0E0E4736-00F067AA0BA902B7-01".to_string()),
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
+    };
+
+    let job_id = service.enqueue(req.clone()).await.unwrap();
+    let job = job_repo.find_by_id(&job_id).await.unwrap().unwrap();
+    assert_eq!(
+        job.trace_id.as_deref(),
+        Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+        "traceparent must be stored in canonical form"
+    );
+
+    req.traceparent = Some("not-a-traceparent".to_string());
+    assert!(service.enqueue(req).await.is_err());
+
+    println!("✅ Trace context: traceparent stored with the job");
+}
+
+/// Queue forwarding to a remote daemon
+/// 원격 daemon이 죽어 있으면 job은 로컬 큐에 남고, 원격 종료 상태는 로컬로 반영되는가?
+#[tokio::test]
+async fn test_forwarded_job_mirrors_remote_state() {
+    use async_trait::async_trait;
+    use semantica_core::application::Forwarder;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::error::{AppError, Result};
+    use semantica_core::port::{RemoteDaemon, RemoteSubmit};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct FakeRemote {
+        reachable: Mutex<bool>,
+        submitted: Mutex<Vec<String>>,
+        state: Mutex<Option<JobState>>,
+    }
+
+    #[async_trait]
+    impl RemoteDaemon for FakeRemote {
+        fn endpoint(&self) -> &str {
+            "http://desktop:9527"
+        }
+
+        async fn submit(&self, _job: &Job, idempotency_key: &str) -> Result<RemoteSubmit> {
+            if !*self.reachable.lock().unwrap() {
+                return Err(AppError::Internal("connection refused".to_string()));
+            }
+            self.submitted
+                .lock()
+                .unwrap()
+                .push(idempotency_key.to_string());
+            Ok(RemoteSubmit::Accepted {
+                remote_job_id: "remote-1".to_string(),
+            })
+        }
+
+        async fn state(&self, _remote_job_id: &str) -> Result<Option<JobState>> {
+            Ok(self.state.lock().unwrap().clone())
+        }
+    }
+
+    let pool = common::setup_pool().await;
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let remote = Arc::new(FakeRemote::default());
+    let forwarder = Forwarder::new(job_repo.clone(), remote.clone(), "remote", time_provider);
+
+    let job = Job::new_test(
+        "remote",
+        JobType::new("BUILD"),
+        "workspace",
+        1,
+        JobPayload::new(serde_json::json!({"command": "cargo build"})),
+    );
+    job_repo.insert(&job).await.unwrap();
+
+    // Remote down: job stays queued locally
+    assert_eq!(forwarder.forward_once().await.unwrap(), 0);
+    let local = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(local.state, JobState::Queued);
+
+    *remote.reachable.lock().unwrap() = true;
+    assert_eq!(forwarder.forward_once().await.unwrap(), 1);
+    assert_eq!(
+        *remote.submitted.lock().unwrap(),
+        vec![format!("fwd:{}", job.id)]
+    );
+    let forward = job_repo.find_forward(&job.id).await.unwrap().unwrap();
+    assert_eq!(forward.remote_job_id, "remote-1");
+
+    // Remote still running: nothing mirrored
+    *remote.state.lock().unwrap() = Some(JobState::Running);
+    assert_eq!(forwarder.sync_once().await.unwrap(), 0);
+    let local = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(local.state, JobState::Running);
+
+    *remote.state.lock().unwrap() = Some(JobState::Done);
+    assert_eq!(forwarder.sync_once().await.unwrap(), 1);
+    let local = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(local.state, JobState::Done);
+    assert!(job_repo.list_open_forwards(10).await.unwrap().is_empty());
+}
+
+/// Enqueue coalescing
+/// 같은 subject로 짧은 시간에 연속 enqueue하면 마지막 payload를 가진 job 하나로 합쳐지는가?
+#[tokio::test]
+async fn test_burst_enqueues_are_coalesced() {
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+
+    let service = DevTaskService::new(
+        job_repo.clone(),
+        Arc::new(semantica_core::port::id_provider::UuidProvider),
+        time_provider,
+    )
+    .with_coalescing_window(std::time::Duration::from_secs(60));
+
+    let save = |n: i32| EnqueueRequest {
+        job_type: "INDEX_FILE".to_string(),
+        queue: "default".to_string(),
+        subject_key: "burst.rs".to_string(),
+        payload: serde_json::json!({ "save": n }),
+        priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
+    };
+
+    let first = service.enqueue(save(1)).await.unwrap();
+    for n in 2..=5 {
+        let id = service.enqueue(save(n)).await.unwrap();
+        assert_eq!(id, first, "Burst must be absorbed by the pending job");
+    }
+
+    let job = job_repo.find_by_id(&first).await.unwrap().unwrap();
+    assert_eq!(job.state, JobState::Queued);
+    assert_eq!(job.payload.as_value()["save"], 5, "Last payload wins");
+    assert_eq!(
+        job_repo
+            .count_by_state("default", JobState::Queued)
+            .await
+            .unwrap(),
+        1
+    );
+
+    // Once the job was picked up, the next save gets a fresh job
+    job_repo.pop_next("default").await.unwrap().unwrap();
+    let after_pop = service.enqueue(save(6)).await.unwrap();
+    assert_ne!(after_pop, first);
+
+    println!("✅ Enqueue coalescing: Burst absorbed into one job");
+}
+
+/// Enqueue coalescing keeps the latest options
+/// 합쳐진 enqueue의 schedule_at, max_attempts 같은 option이 버려지지 않고 pending job에 반영되는가?
+#[tokio::test]
+async fn test_coalesced_enqueue_applies_options() {
+    use semantica_core::application::dev_task::EnqueueOptions;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+
+    let service = DevTaskService::new(
+        job_repo.clone(),
+        Arc::new(semantica_core::port::id_provider::UuidProvider),
+        time_provider.clone(),
+    )
+    .with_coalescing_window(std::time::Duration::from_secs(60));
+
+    let save = |options: EnqueueOptions| EnqueueRequest {
+        job_type: "INDEX_FILE".to_string(),
+        queue: "default".to_string(),
+        subject_key: "burst.rs".to_string(),
+        payload: serde_json::json!({}),
+        priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options,
+    };
+
+    let first = service.enqueue(save(Default::default())).await.unwrap();
+    let in_an_hour = time_provider.now_millis() + 60 * 60 * 1000;
+    let merged = service
+        .enqueue(save(EnqueueOptions {
+            schedule_at: Some(in_an_hour),
+            ttl_ms: Some(2 * 60 * 60 * 1000),
+            max_attempts: Some(7),
+            wait_for_idle: true,
+            ..Default::default()
+        }))
+        .await
+        .unwrap();
+    assert_eq!(merged, first);
+
+    let job = job_repo.find_by_id(&first).await.unwrap().unwrap();
+    assert_eq!(job.schedule_at, Some(in_an_hour));
+    assert_eq!(job.ttl_ms, Some(2 * 60 * 60 * 1000));
+    assert_eq!(job.max_attempts, 7);
+    assert!(job.wait_for_idle);
+
+    // Scheduled an hour out: not run now
+    assert!(job_repo.pop_next("default").await.unwrap().is_none());
+}
+
+/// Enqueue options (schedule, retry, conditions, grouping) are stored on the job
+#[tokio::test]
+async fn test_enqueue_options_are_stored() {
+    use semantica_core::application::dev_task::EnqueueOptions;
+    use semantica_core::domain::ExecutionMode;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let service = DevTaskService::new(
+        job_repo.clone(),
+        Arc::new(semantica_core::port::id_provider::UuidProvider),
+        time_provider.clone(),
+    );
+
+    let now = time_provider.now_millis();
+    let request = |options: EnqueueOptions| EnqueueRequest {
+        job_type: "BUILD".to_string(),
+        queue: "default".to_string(),
+        subject_key: "repo".to_string(),
+        payload: serde_json::json!({}),
+        priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options,
+    };
+
+    let job_id = service
+        .enqueue(request(EnqueueOptions {
+            schedule_at: Some(now + 60_000),
+            deadline: Some(now + 120_000),
+            ttl_ms: Some(300_000),
+            max_attempts: Some(5),
+            backoff_factor: Some(1.5),
+            wait_for_idle: true,
+            require_charging: true,
+            user_tag: Some("nightly".to_string()),
+            execution_mode: Some("SUBPROCESS".to_string()),
+            parent_job_id: Some("parent-1".to_string()),
+            chain_group_id: Some("release".to_string()),
+        }))
+        .await
+        .unwrap();
+
+    let job = job_repo.find_by_id(&job_id).await.unwrap().unwrap();
+    assert_eq!(job.schedule_at, Some(now + 60_000));
+    assert_eq!(job.deadline, Some(now + 120_000));
+    assert_eq!(job.ttl_ms, Some(300_000));
+    assert_eq!(
+        job.queued_at,
+        Some(job.created_at),
+        "TTL counts from the enqueue"
+    );
+    assert_eq!(job.max_attempts, 5);
+    assert_eq!(job.backoff_factor, 1.5);
+    assert!(job.wait_for_idle && job.require_charging);
+    assert_eq!(job.user_tag.as_deref(), Some("nightly"));
+    assert_eq!(job.execution_mode, Some(ExecutionMode::Subprocess));
+    assert_eq!(job.parent_job_id.as_deref(), Some("parent-1"));
+    assert_eq!(job.chain_group_id.as_deref(), Some("release"));
+
+    // A deadline before the scheduled start can never be met
+    let err = service
+        .enqueue(request(EnqueueOptions {
+            schedule_at: Some(now + 60_000),
+            deadline: Some(now + 30_000),
+            ..Default::default()
+        }))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("deadline"));
+}
@@ -0,0 +1,486 @@
+//! Job Management Tests
+//!
+//! 취소, 삭제, snapshot, 진행률, artifact, subject 이력, throughput 기록 등 관리 API 검증
+
+use semantica_core::domain::JobState;
+use semantica_core::port::job_repository::JobRepository;
+use semantica_core::port::time_provider::SystemTimeProvider;
+use semantica_core::port::TimeProvider;
+use semantica_infra_sqlite::SqliteJobRepository;
+use std::sync::Arc;
+
+mod common;
+
+/// Cancel by subject_key glob
+/// 파일 삭제 시 `src/foo.rs::*` 의 QUEUED job만 한 번에 취소되고 이벤트가 남는가?
+#[tokio::test]
+async fn test_cancel_by_subject_pattern() {
+    use semantica_core::domain::{Job, JobPayload, JobType};
+
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = SqliteJobRepository::new(pool, time_provider);
+
+    let mut ids = Vec::new();
+    for (queue, subject, state) in [
+        ("default", "src/foo.rs::parse", JobState::Queued),
+        ("default", "src/foo.rs::render", JobState::Queued),
+        ("other", "src/foo.rs::lint", JobState::Queued),
+        ("default", "src/foo.rs::build", JobState::Running),
+        ("default", "src/foobar.rs::parse", JobState::Queued),
+    ] {
+        let mut job = Job::new_test(
+            queue,
+            JobType::new("INDEX"),
+            subject,
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job.state = state;
+        job_repo.insert(&job).await.unwrap();
+        ids.push(job.id);
+    }
+
+    let cancelled = job_repo
+        .cancel_by_subject(
+            Some("default"),
+            "src/foo.rs::*",
+            "cli",
+            Some("file deleted"),
+        )
+        .await
+        .unwrap();
+    let mut expected = vec![ids[0].clone(), ids[1].clone()];
+    let mut cancelled_sorted = cancelled.clone();
+    expected.sort();
+    cancelled_sorted.sort();
+    assert_eq!(
+        cancelled_sorted, expected,
+        "queue filter, RUNNING and non-matching untouched"
+    );
+
+    let job = job_repo.find_by_id(&ids[0]).await.unwrap().unwrap();
+    assert_eq!(job.state, JobState::Cancelled);
+    assert!(job.finished_at.is_some());
+    let events = job_repo.list_events(&ids[0]).await.unwrap();
+    assert_eq!(
+        events.last().unwrap().reason.as_deref(),
+        Some("file deleted")
+    );
+
+    for untouched in &ids[2..] {
+        let job = job_repo.find_by_id(untouched).await.unwrap().unwrap();
+        assert_ne!(job.state, JobState::Cancelled);
+    }
+
+    // Every queue
+    let cancelled = job_repo
+        .cancel_by_subject(None, "src/foo.rs::*", "cli", None)
+        .await
+        .unwrap();
+    assert_eq!(cancelled, vec![ids[2].clone()]);
+}
+
+/// Queue snapshot and restore
+/// 스냅샷 파일로 다른 엔진에 큐 상태(job, subject generation)를 그대로 재현할 수 있는가?
+#[tokio::test]
+async fn test_queue_snapshot_restore_roundtrip() {
+    use semantica_core::application::SnapshotService;
+    use semantica_core::domain::{Job, JobPayload, JobType, QueueSnapshot};
+    use semantica_core::port::{JobRepository, TransactionalJobRepository};
+
+    async fn engine() -> (Arc<SqliteJobRepository>, SnapshotService) {
+        let pool = common::setup_pool().await;
+        let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+        let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+        let service = SnapshotService::new(
+            job_repo.clone() as Arc<dyn JobRepository>,
+            job_repo.clone() as Arc<dyn TransactionalJobRepository>,
+            time_provider,
+        );
+        (job_repo, service)
+    }
+
+    let (source_repo, source) = engine().await;
+    let mut ids = Vec::new();
+    for (generation, queue, state) in [
+        (3, "code_intel", JobState::Queued),
+        (4, "code_intel", JobState::Running),
+        (5, "build", JobState::Queued),
+    ] {
+        let mut job = Job::new_test(
+            queue,
+            JobType::new("INDEX_FILE"),
+            "src/lib.rs",
+            generation,
+            JobPayload::new(serde_json::json!({"path": "src/lib.rs"})),
+        );
+        job.state = state;
+        source_repo.insert(&job).await.unwrap();
+        ids.push(job.id);
+    }
+    source_repo
+        .get_latest_generation("src/lib.rs")
+        .await
+        .unwrap();
+    source_repo.mark_superseded("src/lib.rs", 3).await.unwrap();
+
+    // Through a file, as the test harness and developers use it
+    let snapshot = source.capture(Some("code_intel"), false).await.unwrap();
+    assert_eq!(snapshot.jobs.len(), 2);
+    let path = std::env::temp_dir().join(format!("semantica-snapshot-{}.json", std::process::id()));
+    std::fs::write(&path, serde_json::to_vec(&snapshot).unwrap()).unwrap();
+    let loaded: QueueSnapshot = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let (target_repo, target) = engine().await;
+    let summary = target.restore(&loaded).await.unwrap();
+    assert_eq!(summary.jobs, 2);
+    assert_eq!(summary.subjects, 1);
+
+    let restored = target_repo.find_by_id(&ids[0]).await.unwrap().unwrap();
+    assert_eq!(restored.payload.as_value()["path"], "src/lib.rs");
+    // RUNNING has no worker after a restore
+    let was_running = target_repo.find_by_id(&ids[1]).await.unwrap().unwrap();
+    assert_eq!(was_running.state, JobState::Queued);
+    assert!(target_repo.find_by_id(&ids[2]).await.unwrap().is_none());
+    // The subject keeps the newest generation seen at the source (the build job's)
+    assert_eq!(
+        target_repo
+            .get_latest_generation("src/lib.rs")
+            .await
+            .unwrap(),
+        5
+    );
+
+    // Restoring twice is refused as a whole
+    assert!(target.restore(&loaded).await.is_err());
+}
+
+/// Purging a single job (job.delete.v1)
+/// 끝난 job은 이력과 함께 지워지고, QUEUED는 force일 때만, RUNNING은 절대 지워지지 않는가?
+#[tokio::test]
+async fn test_delete_job_purges_finished_jobs_only() {
+    use semantica_core::application::dev_task::delete;
+    use semantica_core::domain::{Job, JobEvent, JobPayload, JobType};
+    use semantica_core::error::AppError;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = SqliteJobRepository::new(pool, time_provider.clone());
+
+    let new_job = |state: JobState| {
+        let mut job = Job::new_test(
+            "default",
+            JobType::new("BUILD"),
+            "repo/app",
+            1,
+            JobPayload::new(serde_json::json!({"token": "pasted-secret"})),
+        );
+        job.state = state;
+        job
+    };
+
+    let mut done = new_job(JobState::Done);
+    done.artifacts = Some("file:///tmp/bundle.tar.gz".to_string());
+    job_repo.insert(&done).await.unwrap();
+    job_repo
+        .record_event(&JobEvent::new(
+            done.id.clone(),
+            JobState::Done,
+            "worker",
+            None,
+            time_provider.now_millis(),
+        ))
+        .await
+        .unwrap();
+
+    let deleted = delete::execute(&job_repo, None, &done.id, false)
+        .await
+        .unwrap();
+    assert_eq!(deleted.job.id, done.id);
+    // No storage configured: the artifact is reported, not silently dropped
+    assert_eq!(deleted.artifacts_left, vec!["file:///tmp/bundle.tar.gz"]);
+    assert!(job_repo.find_by_id(&done.id).await.unwrap().is_none());
+    assert!(job_repo.list_events(&done.id).await.unwrap().is_empty());
+
+    let err = delete::execute(&job_repo, None, &done.id, false)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AppError::NotFound(_)));
+
+    // QUEUED needs force
+    let queued = new_job(JobState::Queued);
+    job_repo.insert(&queued).await.unwrap();
+    let err = delete::execute(&job_repo, None, &queued.id, false)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AppError::Conflict(_)));
+    delete::execute(&job_repo, None, &queued.id, true)
+        .await
+        .unwrap();
+    assert!(job_repo.find_by_id(&queued.id).await.unwrap().is_none());
+
+    // RUNNING never, even with force
+    let running = new_job(JobState::Running);
+    job_repo.insert(&running).await.unwrap();
+    let err = delete::execute(&job_repo, None, &running.id, true)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("cancel it first"));
+    assert!(job_repo.find_by_id(&running.id).await.unwrap().is_some());
+}
+
+/// Progress reporting (job.report_progress.v1)
+/// RUNNING job만 진행률을 보고할 수 있고, 범위를 벗어난 값은 거부되는가?
+#[tokio::test]
+async fn test_report_progress_only_for_running_jobs() {
+    use semantica_core::application::dev_task::progress;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::error::AppError;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = SqliteJobRepository::new(pool, time_provider.clone());
+
+    let mut running = Job::new_test(
+        "default",
+        JobType::new("INDEX"),
+        "repo/app",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    running.state = JobState::Running;
+    job_repo.insert(&running).await.unwrap();
+
+    let job = progress::execute(
+        &job_repo,
+        time_provider.as_ref(),
+        &running.id,
+        40,
+        Some("parsed 400/1000 files".to_string()),
+    )
+    .await
+    .unwrap();
+    let reported = job.progress.expect("progress stored");
+    assert_eq!(reported.percent, 40);
+    assert_eq!(reported.message.as_deref(), Some("parsed 400/1000 files"));
+
+    let err = progress::execute(&job_repo, time_provider.as_ref(), &running.id, 101, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AppError::Validation(_)));
+
+    job_repo
+        .update_state(
+            &running.id,
+            JobState::Done,
+            Some(time_provider.now_millis()),
+        )
+        .await
+        .unwrap();
+    let err = progress::execute(&job_repo, time_provider.as_ref(), &running.id, 100, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AppError::Conflict(_)));
+
+    let err = progress::execute(
+        &job_repo,
+        time_provider.as_ref(),
+        &"missing".to_string(),
+        1,
+        None,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, AppError::NotFound(_)));
+}
+
+/// Artifact listing and chunked reads
+/// 메타데이터 없는 옛 artifact도 목록에 나오고, 등록된 artifact만 청크 단위로 읽히는가?
+#[tokio::test]
+async fn test_artifacts_list_and_read_in_chunks() {
+    use semantica_core::application::dev_task::artifacts;
+    use semantica_core::domain::{Artifact, Job, JobPayload, JobType};
+    use semantica_core::error::AppError;
+    use semantica_core::port::ArtifactStorage;
+    use semantica_infra_system::LocalArtifactStorage;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = SqliteJobRepository::new(pool, time_provider);
+    let dir = std::env::temp_dir().join(format!("semantica-artifact-read-{}", std::process::id()));
+    let storage = LocalArtifactStorage::new(&dir);
+
+    let mut job = Job::new_test(
+        "default",
+        JobType::new("BUILD"),
+        "src/lib.rs",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    // Registered before metadata was recorded
+    job.artifacts = Some("/old/report.txt".to_string());
+    job_repo.insert(&job).await.unwrap();
+
+    let content = b"0123456789".repeat(10);
+    let uri = storage.put("bundle.bin", content.clone()).await.unwrap();
+    job_repo
+        .add_artifact(&job.id, &Artifact::new(uri.clone(), &content, 1))
+        .await
+        .unwrap();
+
+    let listed = artifacts::list(&job_repo, &job.id).await.unwrap();
+    assert_eq!(listed.len(), 2);
+    assert_eq!(listed[0], Artifact::unknown("/old/report.txt"));
+    assert_eq!(listed[1].size_bytes, Some(100));
+
+    let mut read = Vec::new();
+    loop {
+        let chunk = artifacts::read(
+            &job_repo,
+            Some(&storage),
+            &job.id,
+            &uri,
+            read.len() as u64,
+            30,
+        )
+        .await
+        .unwrap();
+        assert!(chunk.data.len() <= 30);
+        read.extend(chunk.data);
+        if chunk.eof {
+            break;
+        }
+    }
+    assert_eq!(read, content);
+
+    // Only registered artifacts of this job are readable
+    let err = artifacts::read(&job_repo, Some(&storage), &job.id, "/etc/passwd", 0, 30)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AppError::NotFound(_)));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Subject result history
+/// 성공한 실행마다 subject 이력이 남고, 결과 해시 비교로 변경 여부를 알 수 있는가?
+#[tokio::test]
+async fn test_subject_history_records_successful_runs() {
+    use semantica_core::application::dev_task::subject_history;
+    use semantica_core::application::worker::Worker;
+    use semantica_core::domain::{Job, JobPayload, JobType, SubjectResult};
+
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider));
+    let worker = Worker::new_phase1("default", job_repo.clone()).with_subject_history_len(5);
+
+    // Two runs with the same (mock) output
+    for generation in 1..=2 {
+        let job = Job::new_test(
+            "default",
+            JobType::new("INDEX"),
+            "index::src/lib.rs",
+            generation,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job_repo.insert(&job).await.unwrap();
+        assert!(worker.process_next_job().await.unwrap());
+    }
+
+    let history = subject_history::list(job_repo.as_ref(), "index::src/lib.rs", 10)
+        .await
+        .unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].result.generation, 2);
+    assert_eq!(history[0].changed, Some(false), "same output as run 1");
+    assert_eq!(history[1].changed, None, "no run before the first");
+    assert!(history[0].result.result_hash.is_some());
+
+    // A run with different output
+    job_repo
+        .record_subject_result(
+            &SubjectResult {
+                subject_key: "index::src/lib.rs".to_string(),
+                generation: 3,
+                job_id: "external".to_string(),
+                result_hash: Some(SubjectResult::hash_output("new index")),
+                duration_ms: 5,
+                finished_at: i64::MAX,
+            },
+            5,
+        )
+        .await
+        .unwrap();
+    let history = subject_history::list(job_repo.as_ref(), "index::src/lib.rs", 1)
+        .await
+        .unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(
+        history[0].changed,
+        Some(true),
+        "compared with the unreturned run 2"
+    );
+
+    assert!(subject_history::list(job_repo.as_ref(), "", 10)
+        .await
+        .is_err());
+}
+
+/// Throughput history
+/// 연속된 snapshot이 겹치지 않는 구간을 세고, 저장된 이력으로 추이를 볼 수 있는가?
+#[tokio::test]
+async fn test_metrics_recorder_counts_each_window_once() {
+    use semantica_core::application::MetricsRecorder;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use std::time::Duration;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let recorder = MetricsRecorder::new(
+        job_repo.clone(),
+        time_provider.clone(),
+        Duration::from_secs(60),
+    );
+
+    for (i, state) in [JobState::Done, JobState::Failed, JobState::Queued]
+        .into_iter()
+        .enumerate()
+    {
+        let job = Job::new_test(
+            "default",
+            JobType::new("BUILD"),
+            format!("src/{}.rs", i),
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job_repo.insert(&job).await.unwrap();
+        if state != JobState::Queued {
+            job_repo
+                .update_state(&job.id, state, Some(time_provider.now_millis() - 1))
+                .await
+                .unwrap();
+        }
+    }
+
+    let first = recorder.record_once().await.unwrap();
+    assert_eq!((first.completed, first.failed, first.queued), (1, 1, 1));
+    assert_eq!(first.window_ms, 60_000, "first window is one interval");
+
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    let second = recorder.record_once().await.unwrap();
+    assert_eq!((second.completed, second.failed), (0, 0), "counted once");
+    assert_eq!(second.queued, 1);
+
+    let history = job_repo.list_metrics_snapshots(0, 10).await.unwrap();
+    assert_eq!(history, vec![first, second], "oldest first");
+}
@@ -0,0 +1,304 @@
+//! Lease Tests
+//!
+//! 외부 worker lease의 만료 회수, 예약 시각 준수, 최종 실패 bundle 검증
+
+use semantica_core::domain::JobState;
+use semantica_core::port::job_repository::JobRepository;
+use semantica_core::port::time_provider::SystemTimeProvider;
+use semantica_core::port::TimeProvider;
+use semantica_infra_sqlite::SqliteJobRepository;
+use std::sync::Arc;
+
+mod common;
+
+use common::StepTime;
+
+/// External worker lease expiry
+/// heartbeat 없이 죽은 외부 워커의 job을 reaper가 회수하고, 늦게 도착한 결과는 거부하는가?
+#[tokio::test]
+async fn test_expired_lease_is_reclaimed() {
+    use semantica_core::application::lease::LeaseService;
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::LeaseOutcome;
+    use semantica_core::domain::job_event::actor;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::error::AppError;
+    use std::sync::atomic::{AtomicI64, Ordering};
+
+    let pool = common::setup_pool().await;
+
+    let clock = Arc::new(StepTime(AtomicI64::new(1_000_000)));
+    let time_provider: Arc<dyn TimeProvider> = clock.clone();
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let leases = LeaseService::new(
+        job_repo.clone(),
+        Arc::new(RetryPolicy::new(time_provider.clone(), 1000)),
+        time_provider,
+    );
+
+    let mut job = Job::new_test(
+        "external",
+        JobType::new("PY_INDEX"),
+        "repo/a.py",
+        1,
+        JobPayload::new(serde_json::json!({"path": "repo/a.py"})),
+    );
+    job.created_at = 1_000_000;
+    job.max_attempts = 1;
+    job_repo.insert(&job).await.unwrap();
+
+    let (leased, lease) = leases
+        .lease("external", "worker-a", Some(5_000))
+        .await
+        .unwrap()
+        .expect("job should be leased");
+    assert_eq!(leased.id, job.id);
+    assert_eq!(lease.expires_at, 1_005_000);
+
+    // Only the lease holder may renew
+    let err = leases
+        .heartbeat(&job.id, "worker-b", None, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AppError::Conflict(_)));
+    leases
+        .heartbeat(&job.id, "worker-a", Some(5_000), Some("50%"))
+        .await
+        .unwrap();
+
+    // Worker dies: no heartbeat past expiry
+    clock.0.store(1_020_000, Ordering::SeqCst);
+    assert_eq!(leases.reap_expired().await.unwrap(), 1);
+
+    let reclaimed = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(reclaimed.state, JobState::Queued, "retry policy requeues");
+    assert_eq!(reclaimed.attempts, 1);
+    let events = job_repo.list_events(&job.id).await.unwrap();
+    assert_eq!(events.last().unwrap().actor, actor::LEASE_REAPER);
+
+    // Late result from the dead worker is rejected
+    let err = leases
+        .complete(
+            &job.id,
+            "worker-a",
+            LeaseOutcome::Succeeded { result: None },
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AppError::Conflict(_)));
+
+    // Held back until the retry backoff has passed
+    assert!(leases
+        .lease("external", "worker-b", None)
+        .await
+        .unwrap()
+        .is_none());
+    clock.0.store(1_030_000, Ordering::SeqCst);
+
+    // Another worker picks it up and finishes it
+    leases
+        .lease("external", "worker-b", None)
+        .await
+        .unwrap()
+        .expect("job should be leased again");
+    let state = leases
+        .complete(
+            &job.id,
+            "worker-b",
+            LeaseOutcome::Succeeded {
+                result: Some(serde_json::json!({"symbols": 3})),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(state, JobState::Done);
+    assert!(job_repo.find_lease(&job.id).await.unwrap().is_none());
+}
+
+/// Leasing a scheduled job
+/// 외부 워커가 schedule_at이 아직 오지 않은 job을 받지 않고, 시간이 되면 받는가?
+#[tokio::test]
+async fn test_lease_waits_for_schedule_at() {
+    use semantica_core::application::lease::LeaseService;
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use std::sync::atomic::{AtomicI64, Ordering};
+
+    let pool = common::setup_pool().await;
+
+    let clock = Arc::new(StepTime(AtomicI64::new(1_000_000)));
+    let time_provider: Arc<dyn TimeProvider> = clock.clone();
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let leases = LeaseService::new(
+        job_repo.clone(),
+        Arc::new(RetryPolicy::new(time_provider.clone(), 1000)),
+        time_provider,
+    );
+
+    let mut job = Job::new_test(
+        "external",
+        JobType::new("PY_INDEX"),
+        "repo/a.py",
+        1,
+        JobPayload::new(serde_json::json!({"path": "repo/a.py"})),
+    );
+    job.created_at = 1_000_000;
+    job.schedule_at = Some(1_060_000);
+    job_repo.insert(&job).await.unwrap();
+
+    assert!(leases
+        .lease("external", "worker-a", None)
+        .await
+        .unwrap()
+        .is_none());
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Queued);
+
+    clock.0.store(1_060_000, Ordering::SeqCst);
+    let (leased, _) = leases
+        .lease("external", "worker-a", None)
+        .await
+        .unwrap()
+        .expect("job is due");
+    assert_eq!(leased.id, job.id);
+}
+
+/// Failure bundle on final failure
+/// 최종 실패한 job에 진단 번들(job, 이벤트, 로그, 메트릭)이 artifact로 등록되는가?
+#[tokio::test]
+async fn test_final_failure_writes_bundle_artifact() {
+    use semantica_core::application::lease::LeaseService;
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::{FailureBundler, InterceptorChain, LeaseOutcome};
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::port::system_probe::mocks::MockSystemProbe;
+    use semantica_core::port::ArtifactStorage;
+    use semantica_infra_system::{LocalArtifactStorage, TarBundleWriter};
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let bundle_dir = std::env::temp_dir().join(format!("semantica-bundles-{}", std::process::id()));
+
+    let storage = Arc::new(LocalArtifactStorage::new(&bundle_dir));
+
+    let mut interceptors = InterceptorChain::new();
+    interceptors.register(Arc::new(FailureBundler::new(
+        job_repo.clone(),
+        Arc::new(MockSystemProbe::new(25.0)),
+        Arc::new(TarBundleWriter::new()),
+        storage.clone(),
+        time_provider.clone(),
+        "test",
+    )));
+    let leases = LeaseService::new(
+        job_repo.clone(),
+        Arc::new(RetryPolicy::new(time_provider.clone(), 1000)),
+        time_provider,
+    )
+    .with_interceptors(Arc::new(interceptors));
+
+    let job = Job::new_test(
+        "external",
+        JobType::new("BUILD"),
+        "repo/app",
+        1,
+        JobPayload::new(serde_json::json!({"target": "app"})),
+    );
+    job_repo.insert(&job).await.unwrap();
+
+    leases
+        .lease("external", "worker-a", None)
+        .await
+        .unwrap()
+        .expect("job should be leased");
+    let state = leases
+        .complete(
+            &job.id,
+            "worker-a",
+            LeaseOutcome::Failed {
+                error: "linker error".to_string(),
+                retryable: false,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(state, JobState::Failed);
+
+    let failed = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    let artifact = failed.artifacts.expect("bundle registered as artifact");
+    assert!(artifact.ends_with(".tar.gz"));
+    let content = storage
+        .get(&artifact)
+        .await
+        .unwrap()
+        .expect("bundle stored");
+    assert!(!content.is_empty());
+
+    std::fs::remove_dir_all(&bundle_dir).unwrap();
+}
+
+/// Restart recovery of leased jobs
+/// 재시작 복구가 다른 worker가 heartbeat 중인 lease는 건드리지 않고, 회수한 작업의 lease는 지우는가?
+#[tokio::test]
+async fn test_recovery_leaves_live_leases_to_their_workers() {
+    use semantica_core::application::recovery::RecoveryService;
+    use semantica_core::domain::{Job, JobLease, JobPayload, JobType};
+    use semantica_core::port::task_executor::mocks::MockTaskExecutor;
+    use std::sync::atomic::AtomicI64;
+
+    let pool = common::setup_pool().await;
+
+    let now = 10_000_000;
+    let clock = Arc::new(StepTime(AtomicI64::new(now)));
+    let time_provider: Arc<dyn TimeProvider> = clock.clone();
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+
+    // RUNNING for an hour, past the recovery window
+    let running = |subject: &str| {
+        let mut job = Job::new_test(
+            "external",
+            JobType::new("PY_INDEX"),
+            subject,
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job.state = JobState::Running;
+        job.started_at = Some(now - 60 * 60 * 1000);
+        job
+    };
+    let live = running("repo/live.py");
+    let expired = running("repo/expired.py");
+    let own = running("repo/own.py");
+    for (job, worker_id, expires_at) in [
+        (&live, "py-indexer-1", now + 30_000),
+        (&expired, "py-indexer-2", now - 1_000),
+        (&own, "semantica-worker-1", now + 30_000),
+    ] {
+        job_repo.insert(job).await.unwrap();
+        let mut lease = JobLease::new(job.id.clone(), worker_id, now - 60_000, 0);
+        lease.expires_at = expires_at;
+        job_repo.insert_lease(&lease).await.unwrap();
+    }
+
+    let recovery = RecoveryService::new(
+        job_repo.clone(),
+        Arc::new(MockTaskExecutor::new_success()),
+        time_provider,
+        None,
+    )
+    .with_worker_id("semantica-worker-1");
+    assert_eq!(recovery.recover_orphaned_jobs().await.unwrap(), 2);
+
+    // Still heartbeating: left to its worker (and the lease reaper)
+    let kept = job_repo.find_by_id(&live.id).await.unwrap().unwrap();
+    assert_eq!(kept.state, JobState::Running);
+    assert!(job_repo.find_lease(&live.id).await.unwrap().is_some());
+
+    for job in [&expired, &own] {
+        let recovered = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+        assert_eq!(recovered.state, JobState::Queued);
+        assert!(job_repo.find_lease(&job.id).await.unwrap().is_none());
+    }
+}
@@ -35,6 +35,11 @@ async fn test_enqueue_100_files() {
                 "path": format!("/repo/src/file_{}.rs", i)
             }),
             priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
         };
 
         let job_id = service.enqueue(req).await.unwrap();
@@ -80,6 +85,11 @@ async fn test_persistence_after_restart() {
                 subject_key: format!("file_{}.rs", i),
                 payload: serde_json::json!({"path": format!("/repo/file_{}.rs", i)}),
                 priority: 0,
+                idempotency_key: None,
+                traceparent: None,
+                job_class: None,
+                fallback_payloads: Vec::new(),
+                options: Default::default(),
             };
             service.enqueue(req).await.unwrap();
         }
@@ -131,6 +141,11 @@ async fn test_concurrent_enqueue_no_busy_error() {
                 subject_key: format!("task_{}_file_{}.rs", task_id, i),
                 payload: serde_json::json!({"path": format!("/repo/file_{}.rs", i)}),
                 priority: 0,
+                idempotency_key: None,
+                traceparent: None,
+                job_class: None,
+                fallback_payloads: Vec::new(),
+                options: Default::default(),
             };
 
             service.enqueue(req).await.expect("Enqueue should succeed");
@@ -165,6 +180,11 @@ async fn test_enqueue_api_functional() {
         subject_key: "main.rs".to_string(),
         payload: serde_json::json!({"path": "/repo/main.rs"}),
         priority: 10,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
 
     let job_id = service.enqueue(req).await.unwrap();
@@ -202,6 +222,11 @@ async fn test_cancel_api_functional() {
         subject_key: "test.rs".to_string(),
         payload: serde_json::json!({"path": "/repo/test.rs"}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
 
     let job_id = service.enqueue(req).await.unwrap();
@@ -236,6 +261,11 @@ async fn test_logs_tail_functional() {
         subject_key: "test.rs".to_string(),
         payload: serde_json::json!({"path": "/repo/test.rs"}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
 
     let job_id = service.enqueue(req).await.unwrap();
@@ -285,6 +315,11 @@ async fn test_supersede_logic() {
         subject_key: "main.rs".to_string(),
         payload: serde_json::json!({"path": "/repo/main.rs"}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let job_id_1 = service.enqueue(req1).await.unwrap();
 
@@ -295,6 +330,11 @@ async fn test_supersede_logic() {
         subject_key: "main.rs".to_string(),
         payload: serde_json::json!({"path": "/repo/main.rs", "updated": true}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let job_id_2 = service.enqueue(req2).await.unwrap();
 
@@ -55,6 +55,11 @@ async fn test_orphaned_pid_recovery() {
             subject_key: "orphan.sh".to_string(),
             payload: serde_json::json!({"command": "sleep", "args": ["1000"]}),
             priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
         };
         let job_id = service.enqueue(req).await.unwrap();
 
@@ -151,6 +156,11 @@ async fn test_subprocess_execution() {
             "args": ["Hello Phase 2"]
         }),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
 
     let job_id = service.enqueue(req).await.unwrap();
@@ -202,6 +212,11 @@ async fn test_recovery_marks_orphaned_subprocess_failed() {
         subject_key: "orphan.sh".to_string(),
         payload: serde_json::json!({"command": "sleep", "args": ["1000"]}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let job_id = service.enqueue(req).await.unwrap();
 
@@ -240,3 +255,65 @@ async fn test_recovery_marks_orphaned_subprocess_failed() {
     std::fs::remove_file(db_path).unwrap();
     println!("✅ DoD 2 (Extended): Recovery marks orphaned subprocess jobs as FAILED");
 }
+
+/// DoD 6: TTL/deadline expiry is recorded as a distinct terminal state (not FAILED)
+#[tokio::test]
+async fn test_expired_jobs_not_counted_as_failed() {
+    use semantica_core::application::worker::Worker;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::port::TimeProvider;
+
+    let pool = create_pool(":memory:").await.unwrap();
+    run_migrations(&pool).await.unwrap();
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let now = time_provider.now_millis();
+
+    // Queued for 10s with a 1s TTL
+    let mut ttl_job = Job::new_test(
+        "default",
+        JobType::new("TEST"),
+        "ttl.rs",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    ttl_job.created_at = now - 10_000;
+    ttl_job.ttl_ms = Some(1_000);
+    job_repo.insert(&ttl_job).await.unwrap();
+
+    // Deadline already passed
+    let mut deadline_job = Job::new_test(
+        "default",
+        JobType::new("TEST"),
+        "deadline.rs",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    deadline_job.deadline = Some(now - 1);
+    job_repo.insert(&deadline_job).await.unwrap();
+
+    let worker = Worker::new_phase1("default", job_repo.clone());
+    assert!(worker.process_next_job().await.unwrap());
+    assert!(worker.process_next_job().await.unwrap());
+
+    let ttl_job = job_repo.find_by_id(&ttl_job.id).await.unwrap().unwrap();
+    assert_eq!(ttl_job.state, JobState::SkippedTtl);
+    assert!(ttl_job.finished_at.is_some());
+
+    let deadline_job = job_repo
+        .find_by_id(&deadline_job.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(deadline_job.state, JobState::SkippedDeadline);
+
+    assert_eq!(
+        job_repo
+            .count_by_state("default", JobState::Failed)
+            .await
+            .unwrap(),
+        0
+    );
+
+    println!("✅ DoD 6: Expired jobs recorded separately from failures");
+}
@@ -265,6 +265,11 @@ async fn test_pop_time_supersede() {
         subject_key: "file.rs".to_string(),
         payload: serde_json::json!({"version": 1}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let job_id_v1 = service.enqueue(req1).await.unwrap();
 
@@ -275,6 +280,11 @@ async fn test_pop_time_supersede() {
         subject_key: "file.rs".to_string(),
         payload: serde_json::json!({"version": 2}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let job_id_v2 = service.enqueue(req2).await.unwrap();
 
@@ -285,6 +295,11 @@ async fn test_pop_time_supersede() {
         subject_key: "file.rs".to_string(),
         payload: serde_json::json!({"version": 3}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let job_id_v3 = service.enqueue(req3).await.unwrap();
 
@@ -30,6 +30,11 @@ async fn test_schedule_at_persists() {
         subject_key: "test.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
 
     let job_id = service.enqueue(req).await.unwrap();
@@ -67,6 +72,11 @@ async fn test_wait_for_idle_persists() {
         subject_key: "test.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
 
     let job_id = service.enqueue(req).await.unwrap();
@@ -103,6 +113,11 @@ async fn test_require_charging_persists() {
         subject_key: "test.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
 
     let job_id = service.enqueue(req).await.unwrap();
@@ -139,6 +154,11 @@ async fn test_wait_for_event_persists() {
         subject_key: "test.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
 
     let job_id = service.enqueue(req).await.unwrap();
@@ -175,6 +195,11 @@ async fn test_multiple_conditions_persist() {
         subject_key: "test.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
 
     let job_id = service.enqueue(req).await.unwrap();
@@ -218,6 +243,11 @@ async fn test_default_values() {
         subject_key: "test.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
 
     let job_id = service.enqueue(req).await.unwrap();
@@ -252,6 +282,11 @@ async fn test_fields_survive_state_transitions() {
         subject_key: "test.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
 
     let job_id = service.enqueue(req).await.unwrap();
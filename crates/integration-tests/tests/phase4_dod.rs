@@ -38,6 +38,11 @@ async fn test_tag_based_management() {
         subject_key: "file1.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let job_id_1 = service.enqueue(req1).await.unwrap();
 
@@ -47,6 +52,11 @@ async fn test_tag_based_management() {
         subject_key: "file2.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let job_id_2 = service.enqueue(req2).await.unwrap();
 
@@ -94,6 +104,11 @@ async fn test_chain_group_management() {
         subject_key: "project".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let parent_id = service.enqueue(parent_req).await.unwrap();
 
@@ -104,6 +119,11 @@ async fn test_chain_group_management() {
         subject_key: "tests".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let child_id = service.enqueue(child_req).await.unwrap();
 
@@ -155,6 +175,11 @@ async fn test_result_summary_storage() {
         subject_key: "file.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let job_id = service.enqueue(req).await.unwrap();
 
@@ -213,6 +238,11 @@ async fn test_maintenance_garbage_collection() {
         subject_key: "old.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let old_job_id = service.enqueue(req).await.unwrap();
 
@@ -230,6 +260,11 @@ async fn test_maintenance_garbage_collection() {
         subject_key: "recent.rs".to_string(),
         payload: serde_json::json!({}),
         priority: 0,
+        idempotency_key: None,
+        traceparent: None,
+        job_class: None,
+        fallback_payloads: Vec::new(),
+        options: Default::default(),
     };
     let recent_job_id = service.enqueue(req2).await.unwrap();
 
@@ -244,6 +279,7 @@ async fn test_maintenance_garbage_collection() {
         finished_job_retention_days: 7,
         max_db_size_mb: 1000.0,
         artifact_retention_days: 3,
+        ..Default::default()
     };
 
     let stats = maintenance.run_full_maintenance(&config).await.unwrap();
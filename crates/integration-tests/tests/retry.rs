@@ -0,0 +1,626 @@
+//! Retry Tests
+//!
+//! 자동/수동 재시도, 재시도 규칙과 마감, backoff, TTL 만료 검증
+
+use semantica_core::application::dev_task::DevTaskService;
+use semantica_core::application::dev_task::EnqueueRequest;
+use semantica_core::domain::JobState;
+use semantica_core::port::job_repository::JobRepository;
+use semantica_core::port::time_provider::SystemTimeProvider;
+use semantica_core::port::TimeProvider;
+use semantica_infra_sqlite::SqliteJobRepository;
+use std::sync::Arc;
+
+mod common;
+
+use common::{FailingExecutor, StepTime};
+
+/// Fallback payloads on retry
+/// 재시도마다 다음 fallback payload가 실행되고, 전환이 attempt별로 기록되는가?
+#[tokio::test]
+async fn test_retries_run_fallback_payloads() {
+    use semantica_core::application::lease::LeaseService;
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::LeaseOutcome;
+    use semantica_core::domain::job_event::actor;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let service = DevTaskService::new(
+        job_repo.clone(),
+        Arc::new(semantica_core::port::id_provider::UuidProvider),
+        time_provider.clone(),
+    );
+    let leases = LeaseService::new(
+        job_repo.clone(),
+        // No backoff: retries are leased right away
+        Arc::new(RetryPolicy::new(time_provider.clone(), 0)),
+        time_provider,
+    );
+
+    let job_id = service
+        .enqueue(EnqueueRequest {
+            job_type: "INDEX".to_string(),
+            queue: "code_intel".to_string(),
+            subject_key: "repo".to_string(),
+            payload: serde_json::json!({"mode": "incremental"}),
+            priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: vec![serde_json::json!({"mode": "full"})],
+            options: Default::default(),
+        })
+        .await
+        .unwrap();
+
+    let mut modes = Vec::new();
+    for _ in 0..3 {
+        let (job, _) = leases
+            .lease("code_intel", "worker-a", None)
+            .await
+            .unwrap()
+            .expect("job should be leased");
+        modes.push(job.payload.as_value()["mode"].clone());
+        leases
+            .complete(
+                &job_id,
+                "worker-a",
+                LeaseOutcome::Failed {
+                    error: "index corrupted".to_string(),
+                    retryable: true,
+                },
+            )
+            .await
+            .unwrap();
+    }
+    assert_eq!(modes, vec!["incremental", "full", "full"]);
+
+    // The stored primary payload is untouched, the switch is recorded once
+    let job = job_repo.find_by_id(&job_id).await.unwrap().unwrap();
+    assert_eq!(job.attempts, 3);
+    assert_eq!(job.payload.as_value()["mode"], "incremental");
+    assert_eq!(job.fallback_payloads.len(), 1);
+    let switches: Vec<_> = job_repo
+        .list_events(&job_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|e| e.actor == actor::RETRY)
+        .collect();
+    assert_eq!(switches.len(), 1);
+    assert_eq!(
+        switches[0].reason.as_deref(),
+        Some("attempt 2 runs fallback payload 1")
+    );
+
+    println!("✅ Fallback payloads: retries run the declared alternates");
+}
+
+/// Manual retry of a FAILED job (job.retry.v1)
+/// 실패한 job을 같은 id로 다시 큐에 넣고, 이력은 유지하며, FAILED가 아니면 거부하는가?
+#[tokio::test]
+async fn test_manual_retry_requeues_failed_job() {
+    use semantica_core::application::dev_task::rerun;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::error::AppError;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = SqliteJobRepository::new(pool, time_provider.clone());
+
+    let mut job = Job::new_test(
+        "default",
+        JobType::new("BUILD"),
+        "repo/app",
+        1,
+        JobPayload::new(serde_json::json!({"command": "make"})),
+    );
+    job.max_attempts = 3;
+    job_repo.insert(&job).await.unwrap();
+
+    // Not failed yet: rejected
+    let err = rerun::execute(&job_repo, time_provider.as_ref(), &job.id, "cli", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AppError::Conflict(_)));
+
+    job.state = JobState::Failed;
+    job.attempts = 3;
+    job.finished_at = Some(time_provider.now_millis());
+    job_repo.update(&job).await.unwrap();
+
+    let requeued = rerun::execute(
+        &job_repo,
+        time_provider.as_ref(),
+        &job.id,
+        "cli",
+        Some("flaky network".to_string()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(requeued.id, job.id, "same job, history kept");
+
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Queued);
+    assert_eq!(stored.attempts, 0);
+    assert!(stored.finished_at.is_none());
+
+    let events = job_repo.list_events(&job.id).await.unwrap();
+    let last = events.last().unwrap();
+    assert_eq!(last.state, JobState::Queued);
+    assert_eq!(last.actor, "cli");
+    assert_eq!(last.reason.as_deref(), Some("flaky network"));
+}
+
+/// Manual retry of a superseded job
+/// 같은 subject가 다시 enqueue된 뒤 옛 generation의 실패 job을 retry하면 거부되는가?
+#[tokio::test]
+async fn test_manual_retry_refuses_superseded_job() {
+    use semantica_core::application::dev_task::rerun;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::error::AppError;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = SqliteJobRepository::new(pool, time_provider.clone());
+
+    let mut job = Job::new_test(
+        "default",
+        JobType::new("BUILD"),
+        "repo/app",
+        1,
+        JobPayload::new(serde_json::json!({"command": "make"})),
+    );
+    job.state = JobState::Failed;
+    job_repo.insert(&job).await.unwrap();
+
+    // The subject was enqueued again after the failure
+    let newer = Job::new_test(
+        "default",
+        JobType::new("BUILD"),
+        "repo/app",
+        2,
+        JobPayload::new(serde_json::json!({"command": "make"})),
+    );
+    job_repo.insert(&newer).await.unwrap();
+
+    let err = rerun::execute(&job_repo, time_provider.as_ref(), &job.id, "cli", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AppError::Conflict(_)));
+
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Failed, "left as it was");
+}
+
+/// Manual retry restarts the TTL
+/// TTL이 이미 지난 실패 job을 retry해도 바로 SKIPPED_TTL로 만료되지 않고 pop되는가?
+#[tokio::test]
+async fn test_manual_retry_restarts_ttl() {
+    use semantica_core::application::dev_task::rerun;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = SqliteJobRepository::new(pool, time_provider.clone());
+
+    // Created long before its one-minute TTL ran out
+    let mut job = Job::new_test(
+        "default",
+        JobType::new("BUILD"),
+        "repo/app",
+        1,
+        JobPayload::new(serde_json::json!({"command": "make"})),
+    );
+    job.ttl_ms = Some(60_000);
+    job.state = JobState::Failed;
+    job_repo.insert(&job).await.unwrap();
+
+    let before = time_provider.now_millis();
+    rerun::execute(&job_repo, time_provider.as_ref(), &job.id, "cli", None)
+        .await
+        .unwrap();
+
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert!(stored.queued_since() >= before, "TTL counts from the retry");
+    // Still listed (and paginated) by its creation time
+    assert_eq!(stored.created_at, job.created_at);
+
+    let expired = job_repo
+        .expire_queued(time_provider.now_millis(), "sweeper")
+        .await
+        .unwrap();
+    assert!(expired.is_empty());
+
+    let popped = job_repo.pop_next("default").await.unwrap();
+    assert_eq!(popped.map(|j| j.id), Some(job.id));
+}
+
+/// 타입별 재시도 규칙이 작업의 max_attempts보다 우선하는가?
+/// 규칙이 있는 타입만 재시도 예산이 바뀌고, 나머지 타입은 기본 정책을 따르는가?
+#[tokio::test]
+async fn test_job_type_retry_rule_overrides_job_attempts() {
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::scheduler::Scheduler;
+    use semantica_core::application::worker::Worker;
+    use semantica_core::application::{RetryPolicies, RetryRule};
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::port::system_probe::mocks::MockSystemProbe;
+    use semantica_core::port::task_executor::mocks::MockTaskExecutor;
+    use semantica_core::port::SystemProbe;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let probe: Arc<dyn SystemProbe> = Arc::new(MockSystemProbe::new(25.0));
+    let executor = Arc::new(MockTaskExecutor::new_fail("connection refused"));
+    // No backoff: retries are popped right away
+    let retry_policies = Arc::new(RetryPolicies::new(Arc::new(RetryPolicy::new(
+        time_provider.clone(),
+        0,
+    ))));
+    retry_policies
+        .set(
+            "FETCH",
+            RetryRule {
+                max_attempts: Some(1),
+                base_delay_ms: None,
+                backoff_factor: None,
+            },
+        )
+        .unwrap();
+    let worker = Worker::new(
+        "default",
+        job_repo.clone(),
+        executor,
+        probe.clone(),
+        retry_policies.default_policy(),
+        Arc::new(Scheduler::new(probe, time_provider.clone())),
+        time_provider,
+    )
+    .with_retry_policies(retry_policies);
+
+    let fetch = Job::new_test(
+        "default",
+        JobType::new("FETCH"),
+        "https://example.com",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    let index = Job::new_test(
+        "default",
+        JobType::new("INDEX"),
+        "src/lib.rs",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job_repo.insert(&fetch).await.unwrap();
+    job_repo.insert(&index).await.unwrap();
+
+    // Two failures each: FETCH runs out of its 1-retry budget, INDEX keeps its own 3
+    for _ in 0..4 {
+        assert!(worker.process_next_job().await.unwrap());
+    }
+
+    let fetch = job_repo.find_by_id(&fetch.id).await.unwrap().unwrap();
+    assert_eq!(fetch.state, JobState::Failed);
+    assert_eq!(fetch.attempts, 1);
+    let index = job_repo.find_by_id(&index.id).await.unwrap().unwrap();
+    assert_eq!(index.state, JobState::Queued);
+    assert_eq!(index.attempts, 2);
+}
+
+/// 재시도 마감 시간을 넘길 재시도는 포기하고 이유를 남기는가?
+/// retryable 실패라도 다음 시도가 마감 뒤라면 FAILED + DLQ로 가는가?
+#[tokio::test]
+async fn test_retry_past_retry_deadline_fails_job() {
+    use semantica_core::application::dev_task::dead_letter;
+    use semantica_core::application::lease::LeaseService;
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::LeaseOutcome;
+    use semantica_core::domain::job_event::actor;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+
+    let pool = common::setup_pool().await;
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    // Retries back off 1s at least, so a 500 ms deadline rules out every retry
+    let leases = LeaseService::new(
+        job_repo.clone(),
+        Arc::new(RetryPolicy::new(time_provider.clone(), 1000).with_retry_deadline_ms(500)),
+        time_provider.clone(),
+    );
+
+    let job = Job::new_test(
+        "external",
+        JobType::new("FETCH"),
+        "https://example.com",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job_repo.insert(&job).await.unwrap();
+
+    leases
+        .lease("external", "worker-a", None)
+        .await
+        .unwrap()
+        .expect("job should be leased");
+    let state = leases
+        .complete(
+            &job.id,
+            "worker-a",
+            LeaseOutcome::Failed {
+                error: "connection reset".to_string(),
+                retryable: true,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(state, JobState::Failed);
+
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.attempts, 0, "not retried");
+    let events = job_repo.list_events(&job.id).await.unwrap();
+    let gave_up = events.last().unwrap();
+    assert_eq!(gave_up.actor, actor::RETRY);
+    assert!(gave_up
+        .reason
+        .as_deref()
+        .unwrap()
+        .starts_with("retry deadline exceeded"));
+
+    let dead = dead_letter::list(job_repo.as_ref(), None, 10)
+        .await
+        .unwrap();
+    assert_eq!(dead.len(), 1);
+    assert_eq!(dead[0].last_error, "connection reset");
+}
+
+/// 재시도가 backoff 지연을 지키는가?
+/// 실패 후 QUEUED가 되어도 next_attempt_at 전에는 pop되지 않는가?
+#[tokio::test]
+async fn test_retry_waits_out_backoff_delay() {
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::scheduler::Scheduler;
+    use semantica_core::application::worker::Worker;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::port::system_probe::mocks::MockSystemProbe;
+    use semantica_core::port::task_executor::mocks::MockTaskExecutor;
+    use semantica_core::port::SystemProbe;
+    use std::sync::atomic::{AtomicI64, Ordering};
+
+    let pool = common::setup_pool().await;
+
+    let clock = Arc::new(StepTime(AtomicI64::new(1_000_000)));
+    let time_provider: Arc<dyn TimeProvider> = clock.clone();
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let probe: Arc<dyn SystemProbe> = Arc::new(MockSystemProbe::new(25.0));
+    let executor = Arc::new(MockTaskExecutor::new_fail("connection refused"));
+    let worker = Worker::new(
+        "default",
+        job_repo.clone(),
+        executor.clone(),
+        probe.clone(),
+        Arc::new(RetryPolicy::new(time_provider.clone(), 60_000)),
+        Arc::new(Scheduler::new(probe, time_provider.clone())),
+        time_provider,
+    );
+
+    let mut job = Job::new_test(
+        "default",
+        JobType::new("FETCH"),
+        "https://example.com",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job.created_at = 1_000_000;
+    job_repo.insert(&job).await.unwrap();
+
+    assert!(worker.process_next_job().await.unwrap());
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Queued);
+    let next_attempt_at = stored.next_attempt_at.expect("backoff recorded");
+    assert!(
+        (1_054_000..=1_066_000).contains(&next_attempt_at),
+        "60s ± 10% jitter, got {}",
+        next_attempt_at
+    );
+
+    // Still backing off: nothing to pop
+    clock.0.store(next_attempt_at - 1, Ordering::SeqCst);
+    assert!(!worker.process_next_job().await.unwrap());
+    assert_eq!(executor.call_count(), 1);
+
+    clock.0.store(next_attempt_at, Ordering::SeqCst);
+    assert!(worker.process_next_job().await.unwrap());
+    assert_eq!(executor.call_count(), 2);
+}
+
+/// Executor가 fatal로 분류한 실패는 재시도하지 않는가?
+/// 재시도 예산이 남아 있어도 바로 FAILED + DLQ로 가고 이유가 남는가?
+#[tokio::test]
+async fn test_fatal_execution_error_skips_retries() {
+    use semantica_core::application::dev_task::dead_letter;
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::scheduler::Scheduler;
+    use semantica_core::application::worker::Worker;
+    use semantica_core::domain::job_event::actor;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::port::system_probe::mocks::MockSystemProbe;
+    use semantica_core::port::task_executor::mocks::MockTaskExecutor;
+    use semantica_core::port::SystemProbe;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let probe: Arc<dyn SystemProbe> = Arc::new(MockSystemProbe::new(25.0));
+    let executor = Arc::new(MockTaskExecutor::new_fatal("command 'rg' not found"));
+    let worker = Worker::new(
+        "default",
+        job_repo.clone(),
+        executor.clone(),
+        probe.clone(),
+        Arc::new(RetryPolicy::new(time_provider.clone(), 0)),
+        Arc::new(Scheduler::new(probe, time_provider.clone())),
+        time_provider,
+    );
+
+    let job = Job::new_test(
+        "default",
+        JobType::new("SEARCH"),
+        "src/lib.rs",
+        1,
+        JobPayload::new(serde_json::json!({"command": "rg"})),
+    );
+    job_repo.insert(&job).await.unwrap();
+
+    assert!(worker.process_next_job().await.unwrap());
+    assert!(!worker.process_next_job().await.unwrap(), "not retried");
+    assert_eq!(executor.call_count(), 1);
+
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Failed);
+    assert_eq!(stored.attempts, 0);
+    let events = job_repo.list_events(&job.id).await.unwrap();
+    let gave_up = events.last().unwrap();
+    assert_eq!(gave_up.actor, actor::RETRY);
+    assert!(gave_up
+        .reason
+        .as_deref()
+        .unwrap()
+        .starts_with("fatal error, not retried"));
+
+    let dead = dead_letter::list(job_repo.as_ref(), None, 10)
+        .await
+        .unwrap();
+    assert_eq!(dead.len(), 1);
+    assert!(dead[0].last_error.contains("command 'rg' not found"));
+}
+
+/// 큐에서 기다리는 동안 TTL/deadline이 지난 작업을 pop 없이 만료시키는가?
+/// 서비스와 아직 유효한 작업은 그대로 QUEUED로 남는가?
+#[tokio::test]
+async fn test_expiry_sweeper_expires_waiting_jobs() {
+    use semantica_core::application::ExpirySweeper;
+    use semantica_core::domain::job_event::actor;
+    use semantica_core::domain::{Job, JobClass, JobPayload, JobType};
+    use std::sync::atomic::{AtomicI64, Ordering};
+
+    let pool = common::setup_pool().await;
+
+    let clock = Arc::new(StepTime(AtomicI64::new(1_000_000)));
+    let time_provider: Arc<dyn TimeProvider> = clock.clone();
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let sweeper = ExpirySweeper::new(job_repo.clone(), time_provider);
+
+    let new_job = |subject: &str| {
+        let mut job = Job::new_test(
+            "default",
+            JobType::new("INDEX"),
+            subject,
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job.created_at = 1_000_000;
+        job
+    };
+    let mut ttl = new_job("a.rs");
+    ttl.ttl_ms = Some(60_000);
+    let mut deadline = new_job("b.rs");
+    deadline.deadline = Some(1_030_000);
+    let mut fresh = new_job("c.rs");
+    fresh.ttl_ms = Some(600_000);
+    let mut service = new_job("d.rs");
+    service.job_class = JobClass::Service;
+    service.ttl_ms = Some(1);
+    for job in [&ttl, &deadline, &fresh, &service] {
+        job_repo.insert(job).await.unwrap();
+    }
+
+    // Nothing due yet
+    assert!(sweeper.sweep_once().await.unwrap().is_empty());
+
+    clock.0.store(1_090_000, Ordering::SeqCst);
+    let expired = sweeper.sweep_once().await.unwrap();
+    assert_eq!(expired.len(), 2);
+    assert_eq!(sweeper.expired_total(), 2);
+
+    let state = |id: &str| {
+        let job_repo = job_repo.clone();
+        let id = id.to_string();
+        async move { job_repo.find_by_id(&id).await.unwrap().unwrap().state }
+    };
+    assert_eq!(state(&ttl.id).await, JobState::SkippedTtl);
+    assert_eq!(state(&deadline.id).await, JobState::SkippedDeadline);
+    assert_eq!(state(&fresh.id).await, JobState::Queued);
+    assert_eq!(
+        state(&service.id).await,
+        JobState::Queued,
+        "services never expire"
+    );
+
+    let events = job_repo.list_events(&ttl.id).await.unwrap();
+    assert_eq!(events.last().unwrap().actor, actor::EXPIRY_SWEEPER);
+    assert_eq!(
+        events.last().unwrap().reason.as_deref(),
+        Some("TTL passed while queued")
+    );
+    let stored = job_repo.find_by_id(&ttl.id).await.unwrap().unwrap();
+    assert_eq!(stored.finished_at, Some(1_090_000));
+
+    // Already expired jobs are not swept again
+    assert!(sweeper.sweep_once().await.unwrap().is_empty());
+}
+
+/// Environment manifest of a retried attempt
+/// 실패 후 재시도되는 attempt의 environment manifest가 result_summary에 남는가?
+#[tokio::test]
+async fn test_retried_attempt_keeps_environment_manifest() {
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::scheduler::Scheduler;
+    use semantica_core::application::worker::Worker;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::port::system_probe::mocks::MockSystemProbe;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let probe = Arc::new(MockSystemProbe::new(10.0));
+    let worker = Worker::new(
+        "default",
+        job_repo.clone(),
+        Arc::new(FailingExecutor),
+        probe.clone(),
+        Arc::new(RetryPolicy::new(time_provider.clone(), 0)),
+        Arc::new(Scheduler::new(probe, time_provider.clone())),
+        time_provider,
+    );
+
+    let mut job = Job::new_test(
+        "default",
+        JobType::new("BUILD"),
+        "app",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job.max_attempts = 3;
+    job_repo.insert(&job).await.unwrap();
+
+    assert!(worker.process_next_job().await.unwrap());
+
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Queued, "retried");
+    let summary: serde_json::Value =
+        serde_json::from_str(stored.result_summary.as_deref().expect("attempt recorded")).unwrap();
+    assert_eq!(summary["environment"]["hostname"], "machine-b");
+    assert_eq!(summary["exit_code"], 1);
+}
@@ -0,0 +1,1386 @@
+//! Worker Tests
+//!
+//! Worker 루프의 동시 실행, 큐 분배, lease, stall 감지, 취소, drain, shutdown 검증
+
+use semantica_core::application::dev_task::DevTaskService;
+use semantica_core::application::dev_task::EnqueueRequest;
+use semantica_core::domain::JobState;
+use semantica_core::port::job_repository::JobRepository;
+use semantica_core::port::time_provider::SystemTimeProvider;
+use semantica_core::port::TimeProvider;
+use semantica_infra_sqlite::SqliteJobRepository;
+use std::sync::Arc;
+
+mod common;
+
+use common::{GatedExecutor, HangingExecutor, StepTime};
+
+/// Worker starvation detector
+/// 워커가 놀고 있는데 오래된 QUEUED job이 있으면 한 번만 경보하고, 해소되면 복구되는가?
+#[tokio::test]
+async fn test_starvation_detector_alerts_once() {
+    use async_trait::async_trait;
+    use semantica_core::application::{StarvationDetector, Worker};
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::port::{Notification, Notifier};
+    use std::sync::atomic::{AtomicI64, Ordering};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<Notification>>);
+    #[async_trait]
+    impl Notifier for Recorder {
+        async fn notify(&self, notification: &Notification) -> semantica_core::error::Result<()> {
+            self.0.lock().unwrap().push(notification.clone());
+            Ok(())
+        }
+    }
+
+    let pool = common::setup_pool().await;
+
+    let clock = Arc::new(StepTime(AtomicI64::new(1_000_000)));
+    let time_provider: Arc<dyn TimeProvider> = clock.clone();
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let worker = Worker::new_phase1("stalled", job_repo.clone());
+    let recorder = Arc::new(Recorder::default());
+    let detector = StarvationDetector::new(
+        job_repo.clone(),
+        worker.activity(),
+        "stalled",
+        Duration::from_secs(60),
+        time_provider,
+    )
+    .with_notifier(recorder.clone());
+
+    let mut job = Job::new_test(
+        "stalled",
+        JobType::new("INDEX"),
+        "src/a.rs",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job.created_at = 1_000_000;
+    job_repo.insert(&job).await.unwrap();
+
+    // Within the threshold: healthy
+    assert_eq!(detector.check_once().await.unwrap(), None);
+
+    // Waited too long on an idle worker: alert once
+    clock.0.store(1_120_000, Ordering::SeqCst);
+    assert_eq!(detector.check_once().await.unwrap(), Some(120_000));
+    clock.0.store(1_180_000, Ordering::SeqCst);
+    assert!(detector.check_once().await.unwrap().is_some());
+    assert_eq!(recorder.0.lock().unwrap().len(), 1, "one alert per episode");
+    assert_eq!(recorder.0.lock().unwrap()[0].topic, "worker.starvation");
+
+    // The job gets processed: resolved
+    job_repo
+        .update_state(&job.id, JobState::Done, Some(1_180_000))
+        .await
+        .unwrap();
+    assert_eq!(detector.check_once().await.unwrap(), None);
+}
+
+/// Queue pause / resume
+/// 일시정지된 큐에서는 worker와 외부 워커 모두 pop하지 않고, 재개하면 바로 처리되는가?
+#[tokio::test]
+async fn test_paused_queue_is_not_popped() {
+    use semantica_core::application::lease::LeaseService;
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::worker::Worker;
+    use semantica_core::application::QueuePauseRegistry;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let pauses = Arc::new(QueuePauseRegistry::new());
+    let worker =
+        Worker::new_phase1("code_intel", job_repo.clone()).with_pause_registry(pauses.clone());
+    let leases = LeaseService::new(
+        job_repo.clone(),
+        Arc::new(RetryPolicy::new(time_provider.clone(), 1000)),
+        time_provider,
+    )
+    .with_pause_registry(pauses.clone());
+
+    let job = Job::new_test(
+        "code_intel",
+        JobType::new("INDEX"),
+        "src/a.rs",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job_repo.insert(&job).await.unwrap();
+
+    pauses.pause("code_intel");
+    assert!(!worker.process_next_job().await.unwrap());
+    assert!(worker.activity().is_paused());
+    assert!(leases
+        .lease("code_intel", "worker-a", None)
+        .await
+        .unwrap()
+        .is_none());
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Queued, "paused jobs stay queued");
+
+    pauses.resume("code_intel");
+    assert!(worker.process_next_job().await.unwrap());
+    assert!(!worker.activity().is_paused());
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Done);
+}
+
+/// Runtime CPU throttle threshold
+/// 재시작 없이 바꾼 CPU throttle 값이 다음 pop부터 바로 적용되는가?
+#[tokio::test]
+async fn test_runtime_cpu_threshold_applies_to_worker() {
+    use semantica_core::application::worker::Worker;
+    use semantica_core::application::RuntimeConfig;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider));
+    let runtime_config = Arc::new(RuntimeConfig::default());
+    // Phase 1 worker reports a constant 50% CPU usage
+    let worker =
+        Worker::new_phase1("default", job_repo.clone()).with_runtime_config(runtime_config.clone());
+
+    let job = Job::new_test(
+        "default",
+        JobType::new("INDEX"),
+        "src/a.rs",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job_repo.insert(&job).await.unwrap();
+
+    runtime_config
+        .set("cpu_throttle_percent", serde_json::json!(40.0))
+        .unwrap();
+    assert!(!worker.process_next_job().await.unwrap());
+    assert!(worker.activity().is_throttled());
+
+    runtime_config
+        .set("cpu_throttle_percent", serde_json::json!(90.0))
+        .unwrap();
+    assert!(worker.process_next_job().await.unwrap());
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Done);
+}
+
+/// Service job restart
+/// 종료된 service job이 backoff 후 다시 QUEUED로 돌아가고 deadline에 걸리지 않는가?
+#[tokio::test]
+async fn test_exited_service_is_requeued_with_backoff() {
+    use semantica_core::application::worker::Worker;
+    use semantica_core::domain::{Job, JobClass, JobPayload, JobType};
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let worker = Worker::new_phase1("services", job_repo.clone());
+
+    let mut job = Job::new_test(
+        "services",
+        JobType::new("LSP_SIDECAR"),
+        "rust-analyzer",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job.job_class = JobClass::Service;
+    // Already past its deadline: services are exempt
+    job.deadline = Some(time_provider.now_millis() - 1_000);
+    job_repo.insert(&job).await.unwrap();
+
+    assert!(worker.process_next_job().await.unwrap());
+
+    // The mock process exits at once, the supervisor requeues it in the background
+    let mut stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    for _ in 0..100 {
+        if stored.state == JobState::Queued {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    }
+    assert_eq!(stored.state, JobState::Queued);
+    assert_eq!(stored.attempts, 1);
+    assert!(stored.schedule_at.unwrap() > time_provider.now_millis());
+
+    let events = job_repo.list_events(&job.id).await.unwrap();
+    assert!(events.iter().any(|event| event.actor == "service"));
+
+    let services = job_repo.list_active_services().await.unwrap();
+    assert_eq!(services.len(), 1);
+    assert_eq!(services[0].id, job.id);
+}
+
+/// Worker loop panics
+/// 워커 루프가 panic으로 죽으면 supervisor가 기록하고 backoff 후 재시작하는가?
+#[tokio::test]
+async fn test_supervisor_restarts_panicked_worker() {
+    use async_trait::async_trait;
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::scheduler::Scheduler;
+    use semantica_core::application::worker::{shutdown_channel, Worker, WorkerSupervisor};
+    use semantica_core::port::system_probe::mocks::MockSystemProbe;
+    use semantica_core::port::system_probe::{SystemMetrics, SystemProbe};
+    use semantica_core::port::task_executor::mocks::MockTaskExecutor;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// Panics on the first metrics read, then behaves
+    struct FlakyProbe {
+        calls: AtomicUsize,
+        inner: MockSystemProbe,
+    }
+
+    #[async_trait]
+    impl SystemProbe for FlakyProbe {
+        async fn get_metrics(&self) -> SystemMetrics {
+            if self.calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                panic!("probe exploded");
+            }
+            self.inner.get_metrics().await
+        }
+
+        async fn is_idle(&self, cpu_threshold: f32, duration_secs: u64) -> bool {
+            self.inner.is_idle(cpu_threshold, duration_secs).await
+        }
+    }
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let probe: Arc<dyn SystemProbe> = Arc::new(FlakyProbe {
+        calls: AtomicUsize::new(0),
+        inner: MockSystemProbe::new(25.0),
+    });
+    let worker = Worker::new(
+        "default",
+        job_repo,
+        Arc::new(MockTaskExecutor::new_success()),
+        probe.clone(),
+        Arc::new(RetryPolicy::new(time_provider.clone(), 1000)),
+        Arc::new(Scheduler::new(probe, time_provider.clone())),
+        time_provider,
+    );
+    let activity = worker.activity();
+
+    let (shutdown_tx, shutdown_rx) = shutdown_channel();
+    let supervisor = tokio::spawn(
+        WorkerSupervisor::new(Arc::new(worker))
+            .with_backoff(Duration::from_millis(10), Duration::from_millis(50))
+            .run(shutdown_rx),
+    );
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while activity.restarts() == 0 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("worker was not restarted");
+
+    let exit = activity.last_exit().expect("exit recorded");
+    assert!(exit.reason.contains("probe exploded"), "{}", exit.reason);
+    assert!(!activity.is_down());
+
+    shutdown_tx.shutdown();
+    tokio::time::timeout(Duration::from_secs(5), supervisor)
+        .await
+        .expect("supervisor did not stop")
+        .unwrap();
+    assert_eq!(activity.restarts(), 1);
+}
+
+/// Lockdown (subprocess kill switch)
+/// lockdown 중에는 worker가 pop하지 않고 외부 워커의 lease도 명확한 에러로 거부되는가?
+#[tokio::test]
+async fn test_lockdown_refuses_execution() {
+    use semantica_core::application::lease::LeaseService;
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::worker::Worker;
+    use semantica_core::application::Lockdown;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::error::AppError;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let lockdown = Arc::new(Lockdown::new());
+    let worker = Worker::new_phase1("default", job_repo.clone()).with_lockdown(lockdown.clone());
+    let leases = LeaseService::new(
+        job_repo.clone(),
+        Arc::new(RetryPolicy::new(time_provider.clone(), 1000)),
+        time_provider,
+    )
+    .with_lockdown(lockdown.clone());
+
+    let job = Job::new_test(
+        "default",
+        JobType::new("BUILD"),
+        "src/a.rs",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job_repo.insert(&job).await.unwrap();
+
+    lockdown.engage("ops", Some("rogue template".to_string()), 0);
+    assert!(!worker.process_next_job().await.unwrap());
+    let refused = leases.lease("default", "worker-a", None).await;
+    assert!(
+        matches!(&refused, Err(AppError::Conflict(msg)) if msg.contains("rogue template")),
+        "lease refused with the reason: {:?}",
+        refused
+    );
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Queued, "jobs wait out the lockdown");
+
+    lockdown.lift();
+    assert!(worker.process_next_job().await.unwrap());
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Done);
+}
+
+/// Worker metrics
+/// worker가 pop 지연, 실행 시간, loop cycle을 metrics port로 보고하는가?
+#[tokio::test]
+async fn test_worker_reports_metrics() {
+    use semantica_core::application::worker::{shutdown_channel, Worker};
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::port::WorkerMetrics;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct Recorded(Mutex<Vec<String>>);
+
+    impl WorkerMetrics for Recorded {
+        fn record_cycle(&self, queue: &str, _duration: Duration, processed: bool) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("cycle {} {}", queue, processed));
+        }
+
+        fn record_pop(&self, queue: &str, _duration: Duration, found: bool) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("pop {} {}", queue, found));
+        }
+
+        fn record_execution(
+            &self,
+            queue: &str,
+            job_type: &str,
+            state: &JobState,
+            _duration: Duration,
+        ) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("execution {} {} {}", queue, job_type, state));
+        }
+    }
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider));
+    let metrics = Arc::new(Recorded::default());
+    let worker =
+        Arc::new(Worker::new_phase1("default", job_repo.clone()).with_metrics(metrics.clone()));
+
+    assert!(!worker.process_next_job().await.unwrap());
+    let job = Job::new_test(
+        "default",
+        JobType::new("BUILD"),
+        "src/lib.rs",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job_repo.insert(&job).await.unwrap();
+    assert!(worker.process_next_job().await.unwrap());
+    assert_eq!(
+        *metrics.0.lock().unwrap(),
+        vec![
+            "pop default false",
+            "pop default true",
+            "execution default BUILD DONE",
+        ]
+    );
+
+    // The loop reports every pass, idle ones included
+    metrics.0.lock().unwrap().clear();
+    let (shutdown_tx, shutdown_rx) = shutdown_channel();
+    let handle = tokio::spawn({
+        let worker = Arc::clone(&worker);
+        async move { worker.run(shutdown_rx).await }
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    shutdown_tx.shutdown();
+    handle.await.unwrap().unwrap();
+    let recorded = metrics.0.lock().unwrap();
+    assert!(recorded.contains(&"cycle default false".to_string()));
+}
+
+/// Worker concurrency
+/// 느린 BUILD가 실행 중이어도 빠른 INDEX 작업이 다른 slot에서 끝나고, shutdown은 실행 중인 작업을 기다리는가?
+#[tokio::test]
+async fn test_worker_runs_jobs_concurrently_and_drains_on_shutdown() {
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::scheduler::Scheduler;
+    use semantica_core::application::worker::{shutdown_channel, Worker};
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::port::system_probe::mocks::MockSystemProbe;
+
+    use std::time::Duration;
+    use tokio::sync::Notify;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let executor = Arc::new(GatedExecutor {
+        release: Notify::new(),
+    });
+    let probe = Arc::new(MockSystemProbe::new(10.0));
+    let worker = Arc::new(
+        Worker::new(
+            "default",
+            job_repo.clone(),
+            executor.clone(),
+            probe.clone(),
+            Arc::new(RetryPolicy::new(time_provider.clone(), 0)),
+            Arc::new(Scheduler::new(probe, time_provider.clone())),
+            time_provider,
+        )
+        .with_concurrency(2),
+    );
+    let activity = worker.activity();
+
+    // BUILD pops first and holds its slot
+    let mut build = Job::new_test(
+        "default",
+        JobType::new("BUILD"),
+        "app",
+        10,
+        JobPayload::new(serde_json::json!({})),
+    );
+    build.priority = 10;
+    let index = Job::new_test(
+        "default",
+        JobType::new("INDEX"),
+        "a.rs",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job_repo.insert(&build).await.unwrap();
+    job_repo.insert(&index).await.unwrap();
+
+    let state = |id: &str| {
+        let job_repo = job_repo.clone();
+        let id = id.to_string();
+        async move { job_repo.find_by_id(&id).await.unwrap().unwrap().state }
+    };
+
+    let (shutdown_tx, shutdown_rx) = shutdown_channel();
+    let handle = tokio::spawn(Arc::clone(&worker).run(shutdown_rx));
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while state(&index.id).await != JobState::Done {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("INDEX waited behind the running BUILD");
+    assert_eq!(state(&build.id).await, JobState::Running);
+    assert_eq!(activity.slots().len(), 2);
+    let occupied: Vec<String> = activity
+        .slots()
+        .into_iter()
+        .flatten()
+        .map(|occupant| occupant.job_type)
+        .collect();
+    assert_eq!(occupied, vec!["BUILD".to_string()]);
+
+    // Shutdown stops popping but waits for the running BUILD
+    shutdown_tx.shutdown();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(!handle.is_finished(), "worker stopped with a job running");
+
+    executor.release.notify_one();
+    tokio::time::timeout(Duration::from_secs(5), handle)
+        .await
+        .expect("worker did not stop after draining")
+        .unwrap()
+        .unwrap();
+    assert_eq!(state(&build.id).await, JobState::Done);
+    assert!(!activity.is_busy());
+}
+
+/// Multi-queue worker
+/// 한 워커가 여러 큐를 돌아가며 pop해서 한 큐에 작업이 몰려도 다른 큐가 진행되는가?
+#[tokio::test]
+async fn test_worker_serves_queues_round_robin() {
+    use semantica_core::application::worker::Worker;
+    use semantica_core::application::QueuePauseRegistry;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider));
+    let pauses = Arc::new(QueuePauseRegistry::new());
+    let worker = Worker::new_phase1("default", job_repo.clone())
+        .with_queues(["default", "build", " ", "default"])
+        .with_pause_registry(pauses.clone());
+    assert_eq!(worker.queues(), ["default", "build"]);
+
+    let insert = |queue: &str, subject: String| {
+        let job_repo = job_repo.clone();
+        let job = Job::new_test(
+            queue,
+            JobType::new("INDEX"),
+            subject,
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        async move {
+            job_repo.insert(&job).await.unwrap();
+            job.id
+        }
+    };
+    // A flood in default, one job each in build and an unserved queue
+    let mut flood = Vec::new();
+    for i in 0..3 {
+        flood.push(insert("default", format!("file-{}.rs", i)).await);
+    }
+    let build = insert("build", "app".to_string()).await;
+    let other = insert("other", "x".to_string()).await;
+    let state = |id: &str| {
+        let job_repo = job_repo.clone();
+        let id = id.to_string();
+        async move { job_repo.find_by_id(&id).await.unwrap().unwrap().state }
+    };
+
+    // default, then build: the flood does not hold build back
+    assert!(worker.process_next_job().await.unwrap());
+    assert!(worker.process_next_job().await.unwrap());
+    assert_eq!(state(&build).await, JobState::Done);
+    assert_eq!(state(&flood[0]).await, JobState::Done);
+    assert_eq!(state(&flood[1]).await, JobState::Queued);
+
+    // Pausing one queue leaves the others running
+    let paused = insert("build", "lib".to_string()).await;
+    pauses.pause("build");
+    assert!(worker.process_next_job().await.unwrap());
+    assert!(worker.process_next_job().await.unwrap());
+    assert_eq!(state(&flood[1]).await, JobState::Done);
+    assert_eq!(state(&flood[2]).await, JobState::Done);
+    assert_eq!(state(&paused).await, JobState::Queued);
+    assert!(!worker.process_next_job().await.unwrap());
+    assert!(!worker.activity().is_paused(), "only one queue is paused");
+
+    pauses.pause("default");
+    assert!(!worker.process_next_job().await.unwrap());
+    assert!(worker.activity().is_paused());
+    assert_eq!(state(&other).await, JobState::Queued, "queue not served");
+}
+
+/// Weighted fair dispatch across queues
+/// 큐 weight 비율대로 pop하고, 한 큐가 비면 남은 큐가 그 차례를 가져가는가?
+#[tokio::test]
+async fn test_worker_pops_queues_by_weight() {
+    use semantica_core::application::worker::Worker;
+    use semantica_core::domain::{Job, JobPayload, JobType, QueueWeights};
+
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider));
+    let weights = QueueWeights::from_spec("default:3,build").unwrap();
+    let worker = Worker::new_phase1("default", job_repo.clone()).with_queue_weights(&weights);
+    assert_eq!(worker.queues(), ["default", "build"]);
+
+    // Both queues flooded
+    for i in 0..8 {
+        for queue in ["default", "build"] {
+            let job = Job::new_test(
+                queue,
+                JobType::new("INDEX"),
+                format!("{}-{}.rs", queue, i),
+                1,
+                JobPayload::new(serde_json::json!({})),
+            );
+            job_repo.insert(&job).await.unwrap();
+        }
+    }
+    let done = |queue: &'static str| {
+        let job_repo = job_repo.clone();
+        async move {
+            job_repo
+                .count_by_state(queue, JobState::Done)
+                .await
+                .unwrap()
+        }
+    };
+
+    // 3:1 while both have work
+    for _ in 0..8 {
+        assert!(worker.process_next_job().await.unwrap());
+    }
+    assert_eq!(done("default").await, 6);
+    assert_eq!(done("build").await, 2);
+
+    // default runs dry: build gets every pop
+    for _ in 0..8 {
+        assert!(worker.process_next_job().await.unwrap());
+    }
+    assert_eq!(done("default").await, 8);
+    assert_eq!(done("build").await, 8);
+    assert!(!worker.process_next_job().await.unwrap());
+}
+
+/// Event-driven dispatch
+/// 놀고 있는 워커가 다음 poll을 기다리지 않고 enqueue 신호에 바로 깨어나는가?
+#[tokio::test]
+async fn test_enqueue_signal_wakes_idle_worker() {
+    use semantica_core::application::worker::{shutdown_channel, Worker};
+    use semantica_core::application::DispatchSignal;
+    use std::time::{Duration, Instant};
+
+    let pool = common::setup_pool().await;
+
+    let time_provider = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let dispatch = Arc::new(DispatchSignal::new());
+    let service = DevTaskService::new(
+        job_repo.clone(),
+        Arc::new(semantica_core::port::id_provider::UuidProvider),
+        time_provider,
+    )
+    .with_dispatch_signal(dispatch.clone());
+    // Without the signal the job would wait for the 10s safety-net poll
+    let worker = Arc::new(
+        Worker::new_phase1("default", job_repo.clone())
+            .with_dispatch_signal(dispatch)
+            .with_signalled_idle_poll(Duration::from_secs(10)),
+    );
+
+    let (shutdown_tx, shutdown_rx) = shutdown_channel();
+    let handle = tokio::spawn(Arc::clone(&worker).run(shutdown_rx));
+    // Let the worker find the queue empty and go idle
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let enqueued_at = Instant::now();
+    let job_id = service
+        .enqueue(EnqueueRequest {
+            job_type: "INDEX".to_string(),
+            queue: "default".to_string(),
+            subject_key: "a.rs".to_string(),
+            payload: serde_json::json!({}),
+            priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
+        })
+        .await
+        .unwrap();
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while job_repo.find_by_id(&job_id).await.unwrap().unwrap().state != JobState::Done {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("job was not processed");
+    assert!(
+        enqueued_at.elapsed() < Duration::from_millis(500),
+        "worker waited for its fallback poll ({:?})",
+        enqueued_at.elapsed()
+    );
+
+    shutdown_tx.shutdown();
+    handle.await.unwrap().unwrap();
+}
+
+/// Batch pop
+/// 동시 실행 워커가 빈 slot 수만큼 작업을 한 번의 pop으로 가져오는가?
+#[tokio::test]
+async fn test_concurrent_worker_claims_jobs_in_one_pop() {
+    use semantica_core::application::worker::{shutdown_channel, Worker};
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::port::WorkerMetrics;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    /// Outcome of every pop
+    #[derive(Default)]
+    struct Pops(Mutex<Vec<bool>>);
+
+    impl WorkerMetrics for Pops {
+        fn record_cycle(&self, _queue: &str, _duration: Duration, _processed: bool) {}
+
+        fn record_pop(&self, _queue: &str, _duration: Duration, found: bool) {
+            self.0.lock().unwrap().push(found);
+        }
+
+        fn record_execution(
+            &self,
+            _queue: &str,
+            _job_type: &str,
+            _state: &JobState,
+            _duration: Duration,
+        ) {
+        }
+    }
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider));
+    let mut ids = Vec::new();
+    for i in 0..4 {
+        let job = Job::new_test(
+            "default",
+            JobType::new("INDEX"),
+            format!("file-{}.rs", i),
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job_repo.insert(&job).await.unwrap();
+        ids.push(job.id);
+    }
+
+    let pops = Arc::new(Pops::default());
+    let worker = Arc::new(
+        Worker::new_phase1("default", job_repo.clone())
+            .with_concurrency(4)
+            .with_metrics(pops.clone()),
+    );
+    let (shutdown_tx, shutdown_rx) = shutdown_channel();
+    let handle = tokio::spawn(Arc::clone(&worker).run(shutdown_rx));
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let mut done = 0;
+            for id in &ids {
+                if job_repo.find_by_id(id).await.unwrap().unwrap().state == JobState::Done {
+                    done += 1;
+                }
+            }
+            if done == ids.len() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("jobs were not processed");
+    shutdown_tx.shutdown();
+    handle.await.unwrap().unwrap();
+
+    // All four slots were free: one pop claimed every job
+    let pops = pops.0.lock().unwrap();
+    assert_eq!(pops.iter().filter(|&&found| found).count(), 1);
+    assert_eq!(pops.first(), Some(&true));
+}
+
+/// Worker job leases
+/// 워커가 실행 중 job의 lease를 갱신하고, 워커가 죽으면 재시작 없이 reaper가 job을 회수하는가?
+#[tokio::test]
+async fn test_worker_lease_is_reclaimed_when_worker_dies() {
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::scheduler::Scheduler;
+    use semantica_core::application::worker::Worker;
+    use semantica_core::application::LeaseService;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::port::system_probe::mocks::MockSystemProbe;
+
+    use std::time::Duration;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let probe = Arc::new(MockSystemProbe::new(10.0));
+    let retry_policy = Arc::new(RetryPolicy::new(time_provider.clone(), 3));
+    let worker = Arc::new(
+        Worker::new(
+            "default",
+            job_repo.clone(),
+            Arc::new(HangingExecutor),
+            probe.clone(),
+            retry_policy.clone(),
+            Arc::new(Scheduler::new(probe, time_provider.clone())),
+            time_provider.clone(),
+        )
+        .with_lease("worker-a", Duration::from_millis(300)),
+    );
+    let reaper = LeaseService::new(job_repo.clone(), retry_policy, time_provider);
+
+    // A finished job gives its lease back
+    let index = Job::new_test(
+        "default",
+        JobType::new("INDEX"),
+        "a.rs",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job_repo.insert(&index).await.unwrap();
+    assert!(worker.process_next_job().await.unwrap());
+    let done = job_repo.find_by_id(&index.id).await.unwrap().unwrap();
+    assert_eq!(done.state, JobState::Done);
+    assert!(job_repo.find_lease(&index.id).await.unwrap().is_none());
+
+    // A running job's lease is renewed past its first expiry
+    let build = Job::new_test(
+        "default",
+        JobType::new("BUILD"),
+        "app",
+        1,
+        JobPayload::new(serde_json::json!({})),
+    );
+    job_repo.insert(&build).await.unwrap();
+    let running = tokio::spawn({
+        let worker = Arc::clone(&worker);
+        async move { worker.process_next_job().await }
+    });
+    let first = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            if let Some(lease) = job_repo.find_lease(&build.id).await.unwrap() {
+                break lease;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("BUILD was never leased");
+    assert!(first.is_held_by("worker-a"));
+    tokio::time::sleep(Duration::from_millis(450)).await;
+    let renewed = job_repo.find_lease(&build.id).await.unwrap().unwrap();
+    assert!(renewed.expires_at > first.expires_at);
+    assert_eq!(reaper.reap_expired().await.unwrap(), 0);
+
+    // The worker dies mid-job: renewals stop and the reaper requeues the job
+    running.abort();
+    let _ = running.await;
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    assert_eq!(reaper.reap_expired().await.unwrap(), 1);
+    let reclaimed = job_repo.find_by_id(&build.id).await.unwrap().unwrap();
+    assert_eq!(reclaimed.state, JobState::Queued);
+    assert!(job_repo.find_lease(&build.id).await.unwrap().is_none());
+}
+
+/// Stalled job detector
+/// 평소보다 훨씬 오래 RUNNING인 job을 (p95 또는 설정값 기준) 한 번만 경고하고, 설정하면 프로세스를 죽이는가?
+#[tokio::test]
+async fn test_stalled_job_detector_flags_and_kills_hung_jobs() {
+    use semantica_core::application::stalled::{ExpectationBasis, ExpectedDurations};
+    use semantica_core::application::StalledJobDetector;
+    use semantica_core::domain::job_event::actor;
+    use semantica_core::domain::{Job, JobPayload, JobType};
+    use semantica_core::port::task_executor::mocks::MockTaskExecutor;
+    use semantica_core::port::RunningProcess;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let now = time_provider.now_millis();
+
+    let job = |job_type: &str, subject: &str, state: JobState, started_at: i64| {
+        let mut job = Job::new_test(
+            "default",
+            JobType::new(job_type),
+            subject,
+            1,
+            JobPayload::new(serde_json::json!({})),
+        );
+        job.state = state;
+        job.started_at = Some(started_at);
+        job
+    };
+
+    // INDEX usually takes a second: its p95-derived expectation is the 1min floor
+    for i in 0..20 {
+        let mut done = job(
+            "INDEX",
+            &format!("done-{}.rs", i),
+            JobState::Done,
+            now - 10_000,
+        );
+        done.finished_at = Some(now - 9_000);
+        job_repo.insert(&done).await.unwrap();
+    }
+    let hung = job("INDEX", "hung.rs", JobState::Running, now - 2 * 60_000);
+    let busy = job("INDEX", "busy.rs", JobState::Running, now - 30_000);
+    // BUILD is configured to take up to 30min, LINT has no history
+    let build = job("BUILD", "app", JobState::Running, now - 10 * 60_000);
+    let lint = job("LINT", "lint", JobState::Running, now - 60 * 60_000);
+    for running in [&hung, &busy, &build, &lint] {
+        job_repo.insert(running).await.unwrap();
+    }
+
+    let executor = Arc::new(MockTaskExecutor::new_success());
+    executor.set_running(vec![RunningProcess {
+        job_id: hung.id.clone(),
+        pid: 4242,
+        started_at: now - 2 * 60_000,
+    }]);
+    let detector = StalledJobDetector::new(job_repo.clone(), time_provider)
+        .with_expected_durations(ExpectedDurations::from_spec("BUILD:1800").unwrap())
+        .with_auto_kill(executor.clone());
+
+    let stalled = detector.check_once().await.unwrap();
+    assert_eq!(stalled.len(), 1);
+    assert_eq!(stalled[0].job_id, hung.id);
+    assert_eq!(stalled[0].expected_ms, 60_000);
+    assert_eq!(stalled[0].basis, ExpectationBasis::P95);
+    assert!(stalled[0].killed);
+    assert_eq!(executor.killed_pids(), vec![4242]);
+
+    let events = job_repo.list_events(&hung.id).await.unwrap();
+    assert!(events.iter().any(|e| e.actor == actor::STALL_DETECTOR));
+
+    // Flagged once per job
+    assert!(detector.check_once().await.unwrap().is_empty());
+    assert_eq!(executor.killed_pids(), vec![4242]);
+    assert_eq!(detector.stalled_total(), 1);
+}
+
+/// Cancelling a RUNNING job kills its subprocess
+/// 실행 중인 job을 취소하면 subprocess가 실제로 죽고, job은 재시도 없이 CANCELLED로 남는가?
+#[tokio::test]
+async fn test_cancel_kills_running_subprocess() {
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::scheduler::Scheduler;
+    use semantica_core::application::worker::Worker;
+    use semantica_core::application::{RunningJobs, Termination};
+    use semantica_core::domain::{ExecutionMode, Job, JobPayload, JobType};
+    use semantica_core::port::system_probe::mocks::MockSystemProbe;
+    use semantica_core::port::TaskExecutor;
+    use semantica_infra_system::SubprocessExecutor;
+    use std::time::{Duration, Instant};
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let executor = Arc::new(SubprocessExecutor::new(
+        time_provider.clone(),
+        vec!["PATH".to_string()],
+    ));
+    let running_jobs = Arc::new(RunningJobs::new(executor.clone()));
+    let probe = Arc::new(MockSystemProbe::new(10.0));
+    let worker = Arc::new(
+        Worker::new(
+            "default",
+            job_repo.clone(),
+            executor.clone(),
+            probe.clone(),
+            // Retries would requeue the killed job
+            Arc::new(RetryPolicy::new(time_provider.clone(), 3)),
+            Arc::new(Scheduler::new(probe, time_provider.clone())),
+            time_provider.clone(),
+        )
+        .with_running_jobs(running_jobs.clone()),
+    );
+
+    let mut job = Job::new_test(
+        "default",
+        JobType::new("BUILD"),
+        "app",
+        1,
+        JobPayload::new(serde_json::json!({"command": "sleep", "args": ["30"]})),
+    );
+    job.execution_mode = Some(ExecutionMode::Subprocess);
+    job_repo.insert(&job).await.unwrap();
+
+    // Not executing yet: nothing to stop
+    assert_eq!(running_jobs.cancel(&job.id).await, Termination::NotRunning);
+
+    let started = Instant::now();
+    let execution = tokio::spawn({
+        let worker = Arc::clone(&worker);
+        async move { worker.process_next_job().await }
+    });
+    let pid = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            if let Some(process) = executor.running().into_iter().find(|p| p.job_id == job.id) {
+                break process.pid;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("sleep was never spawned");
+
+    // What dev.cancel.v1 does: flip the state, then stop the execution
+    job_repo
+        .update_state(
+            &job.id,
+            JobState::Cancelled,
+            Some(time_provider.now_millis()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        running_jobs.cancel(&job.id).await,
+        Termination::Killed { pid }
+    );
+    assert!(!executor.is_alive(pid as i32));
+
+    assert!(execution.await.unwrap().unwrap());
+    assert!(started.elapsed() < Duration::from_secs(10));
+    assert!(!running_jobs.is_running(&job.id));
+    let cancelled = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(cancelled.state, JobState::Cancelled);
+    assert_eq!(cancelled.attempts, 0);
+}
+
+/// Drain mode
+/// drain 중에는 새 작업을 pop하지 않고, 실행 중인 작업이 끝날 때까지 기다리는가?
+#[tokio::test]
+async fn test_drain_waits_for_in_flight_jobs_and_stops_popping() {
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::scheduler::Scheduler;
+    use semantica_core::application::worker::{shutdown_channel, Worker};
+    use semantica_core::application::{Drain, LeaseService};
+    use semantica_core::domain::{ExecutionMode, Job, JobPayload, JobType};
+    use semantica_core::port::system_probe::mocks::MockSystemProbe;
+    use semantica_infra_system::SubprocessExecutor;
+    use std::time::Duration;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let retry_policy = Arc::new(RetryPolicy::new(time_provider.clone(), 3));
+    let probe = Arc::new(MockSystemProbe::new(10.0));
+    let drain = Arc::new(Drain::new());
+    let worker = Arc::new(
+        Worker::new(
+            "default",
+            job_repo.clone(),
+            Arc::new(SubprocessExecutor::new(
+                time_provider.clone(),
+                vec!["PATH".to_string()],
+            )),
+            probe.clone(),
+            retry_policy.clone(),
+            Arc::new(Scheduler::new(probe, time_provider.clone())),
+            time_provider.clone(),
+        )
+        .with_drain(drain.clone()),
+    );
+    let lease_service = LeaseService::new(job_repo.clone(), retry_policy, time_provider.clone())
+        .with_drain(drain.clone());
+
+    let sleep_job = |subject: &str, seconds: &str| {
+        let mut job = Job::new_test(
+            "default",
+            JobType::new("BUILD"),
+            subject,
+            1,
+            JobPayload::new(serde_json::json!({"command": "sleep", "args": [seconds]})),
+        );
+        job.execution_mode = Some(ExecutionMode::Subprocess);
+        job
+    };
+    let slow = sleep_job("slow", "0.5");
+    job_repo.insert(&slow).await.unwrap();
+
+    let (shutdown_tx, shutdown_rx) = shutdown_channel();
+    let handle = tokio::spawn(Arc::clone(&worker).run(shutdown_rx));
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while drain.in_flight() == 0 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("slow job was never claimed");
+
+    assert!(drain.start(time_provider.now_millis()));
+    // Enqueues are still accepted, their jobs wait for the next daemon
+    let later = sleep_job("later", "0");
+    job_repo.insert(&later).await.unwrap();
+    assert!(lease_service
+        .lease("default", "external-1", None)
+        .await
+        .unwrap()
+        .is_none());
+
+    assert!(drain.wait_idle(Duration::from_secs(5)).await);
+    let state = |id| {
+        let job_repo = job_repo.clone();
+        async move { job_repo.find_by_id(&id).await.unwrap().unwrap().state }
+    };
+    assert_eq!(state(slow.id.clone()).await, JobState::Done);
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(state(later.id.clone()).await, JobState::Queued);
+
+    // Resumed: the waiting job runs
+    assert!(drain.stop());
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while state(later.id.clone()).await != JobState::Done {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("job was not popped after the drain ended");
+
+    shutdown_tx.shutdown();
+    handle.await.unwrap().unwrap();
+}
+
+/// Shutdown requeues in-flight jobs
+/// 종료 시 grace period 안에 끝나지 않은 작업의 subprocess를 죽이고 QUEUED로 되돌리는가?
+#[tokio::test]
+async fn test_shutdown_kills_and_requeues_in_flight_jobs() {
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::scheduler::Scheduler;
+    use semantica_core::application::worker::{shutdown_channel, Worker};
+    use semantica_core::domain::job_event::actor;
+    use semantica_core::domain::{ExecutionMode, Job, JobPayload, JobType};
+    use semantica_core::port::system_probe::mocks::MockSystemProbe;
+    use semantica_core::port::TaskExecutor;
+    use semantica_infra_system::SubprocessExecutor;
+    use std::time::{Duration, Instant};
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let executor = Arc::new(SubprocessExecutor::new(
+        time_provider.clone(),
+        vec!["PATH".to_string()],
+    ));
+    let probe = Arc::new(MockSystemProbe::new(10.0));
+    let worker = Arc::new(
+        Worker::new(
+            "default",
+            job_repo.clone(),
+            executor.clone(),
+            probe.clone(),
+            Arc::new(RetryPolicy::new(time_provider.clone(), 3)),
+            Arc::new(Scheduler::new(probe, time_provider.clone())),
+            time_provider.clone(),
+        )
+        .with_shutdown_grace(Duration::from_millis(100)),
+    );
+
+    let mut job = Job::new_test(
+        "default",
+        JobType::new("BUILD"),
+        "app",
+        1,
+        JobPayload::new(serde_json::json!({"command": "sleep", "args": ["30"]})),
+    );
+    job.execution_mode = Some(ExecutionMode::Subprocess);
+    job_repo.insert(&job).await.unwrap();
+
+    let (shutdown_tx, shutdown_rx) = shutdown_channel();
+    let handle = tokio::spawn(Arc::clone(&worker).run(shutdown_rx));
+    let pid = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            if let Some(process) = executor.running().into_iter().find(|p| p.job_id == job.id) {
+                break process.pid;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("sleep was never spawned");
+
+    let stopping = Instant::now();
+    shutdown_tx.shutdown();
+    tokio::time::timeout(Duration::from_secs(10), handle)
+        .await
+        .expect("worker did not stop")
+        .unwrap()
+        .unwrap();
+    assert!(stopping.elapsed() < Duration::from_secs(8));
+    assert!(!executor.is_alive(pid as i32));
+
+    // Back in the queue for the next start, the interrupted attempt does not count
+    let requeued = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(requeued.state, JobState::Queued);
+    assert_eq!(requeued.attempts, 0);
+    assert!(requeued.started_at.is_none());
+    let events = job_repo.list_events(&job.id).await.unwrap();
+    assert!(events
+        .iter()
+        .any(|event| event.actor == actor::SHUTDOWN && event.state == JobState::Queued));
+}
+
+/// Shutdown stops services
+/// 종료 시 실행 중인 service의 프로세스를 죽이고 바로 QUEUED로 되돌리는가?
+#[tokio::test]
+async fn test_shutdown_stops_and_requeues_services() {
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::scheduler::Scheduler;
+    use semantica_core::application::worker::{shutdown_channel, Worker};
+    use semantica_core::domain::job_event::actor;
+    use semantica_core::domain::{ExecutionMode, Job, JobClass, JobPayload, JobType};
+    use semantica_core::port::system_probe::mocks::MockSystemProbe;
+    use semantica_core::port::TaskExecutor;
+    use semantica_infra_system::SubprocessExecutor;
+    use std::time::Duration;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let executor = Arc::new(SubprocessExecutor::new(
+        time_provider.clone(),
+        vec!["PATH".to_string()],
+    ));
+    let probe = Arc::new(MockSystemProbe::new(10.0));
+    let worker = Arc::new(
+        Worker::new(
+            "services",
+            job_repo.clone(),
+            executor.clone(),
+            probe.clone(),
+            Arc::new(RetryPolicy::new(time_provider.clone(), 3)),
+            Arc::new(Scheduler::new(probe, time_provider.clone())),
+            time_provider.clone(),
+        )
+        .with_shutdown_grace(Duration::from_millis(100)),
+    );
+
+    let mut job = Job::new_test(
+        "services",
+        JobType::new("LSP_SIDECAR"),
+        "rust-analyzer",
+        1,
+        JobPayload::new(serde_json::json!({"command": "sleep", "args": ["30"]})),
+    );
+    job.job_class = JobClass::Service;
+    job.execution_mode = Some(ExecutionMode::Subprocess);
+    job_repo.insert(&job).await.unwrap();
+
+    let (shutdown_tx, shutdown_rx) = shutdown_channel();
+    let handle = tokio::spawn(Arc::clone(&worker).run(shutdown_rx));
+    let pid = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            if let Some(process) = executor.running().into_iter().find(|p| p.job_id == job.id) {
+                break process.pid;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("service was never spawned");
+
+    shutdown_tx.shutdown();
+    tokio::time::timeout(Duration::from_secs(10), handle)
+        .await
+        .expect("worker did not stop")
+        .unwrap()
+        .unwrap();
+    assert!(!executor.is_alive(pid as i32));
+    assert!(worker.running_jobs().job_ids().is_empty());
+
+    // Back in the queue at once, not held back by a restart delay
+    let requeued = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(requeued.state, JobState::Queued);
+    assert!(requeued.started_at.is_none());
+    assert!(requeued.schedule_at.is_none());
+    let events = job_repo.list_events(&job.id).await.unwrap();
+    assert!(events
+        .iter()
+        .any(|event| event.actor == actor::SHUTDOWN && event.state == JobState::Queued));
+}
+
+/// Cancelling a RUNNING service
+/// 실행 중인 service를 취소하면 프로세스를 죽이고 재시작 없이 CANCELLED로 남는가?
+#[tokio::test]
+async fn test_cancel_kills_running_service() {
+    use semantica_core::application::retry::RetryPolicy;
+    use semantica_core::application::scheduler::Scheduler;
+    use semantica_core::application::worker::{shutdown_channel, Termination, Worker};
+    use semantica_core::domain::{ExecutionMode, Job, JobClass, JobPayload, JobType};
+    use semantica_core::port::system_probe::mocks::MockSystemProbe;
+    use semantica_core::port::TaskExecutor;
+    use semantica_infra_system::SubprocessExecutor;
+    use std::time::Duration;
+
+    let pool = common::setup_pool().await;
+
+    let time_provider: Arc<dyn TimeProvider> = Arc::new(SystemTimeProvider);
+    let job_repo = Arc::new(SqliteJobRepository::new(pool, time_provider.clone()));
+    let executor = Arc::new(SubprocessExecutor::new(
+        time_provider.clone(),
+        vec!["PATH".to_string()],
+    ));
+    let probe = Arc::new(MockSystemProbe::new(10.0));
+    let worker = Arc::new(Worker::new(
+        "services",
+        job_repo.clone(),
+        executor.clone(),
+        probe.clone(),
+        Arc::new(RetryPolicy::new(time_provider.clone(), 3)),
+        Arc::new(Scheduler::new(probe, time_provider.clone())),
+        time_provider.clone(),
+    ));
+
+    let mut job = Job::new_test(
+        "services",
+        JobType::new("LSP_SIDECAR"),
+        "rust-analyzer",
+        1,
+        JobPayload::new(serde_json::json!({"command": "sleep", "args": ["30"]})),
+    );
+    job.job_class = JobClass::Service;
+    job.execution_mode = Some(ExecutionMode::Subprocess);
+    job_repo.insert(&job).await.unwrap();
+
+    let (shutdown_tx, shutdown_rx) = shutdown_channel();
+    let handle = tokio::spawn(Arc::clone(&worker).run(shutdown_rx));
+    let pid = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            if let Some(process) = executor.running().into_iter().find(|p| p.job_id == job.id) {
+                break process.pid;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("service was never spawned");
+
+    // What job.cancel.v1 does
+    job_repo
+        .update_state(
+            &job.id,
+            JobState::Cancelled,
+            Some(time_provider.now_millis()),
+        )
+        .await
+        .unwrap();
+    let termination = worker.running_jobs().cancel(&job.id).await;
+    assert_eq!(termination, Termination::Killed { pid });
+    assert!(!executor.is_alive(pid as i32));
+
+    // The supervisor sees the cancel and does not schedule a restart
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let stored = job_repo.find_by_id(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Cancelled);
+    assert!(worker.running_jobs().job_ids().is_empty());
+
+    shutdown_tx.shutdown();
+    handle.await.unwrap().unwrap();
+}
@@ -35,10 +35,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             queue: "default".to_string(),
             subject_key: "examples/simple.rs".to_string(),
             priority: 5,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
             payload: json!({
                 "path": "examples/simple.rs",
                 "mode": "full_index"
             }),
+            options: Default::default(),
         })
         .await?;
 
@@ -1,15 +1,66 @@
 //! Semantica Client Implementation
 
 use crate::error::{Result, SdkError};
+use crate::journal::OfflineJournal;
 use crate::types::{
-    CancelRequest, CancelResponse, EnqueueRequest, EnqueueResponse, TailLogsRequest,
-    TailLogsResponse,
+    ArchiveGetRequest, ArchiveGetResponse, ArtifactReadRequest, ArtifactReadResponse,
+    ArtifactsListRequest, ArtifactsListResponse, AuditListRequest, AuditListResponse,
+    CancelBySubjectRequest, CancelBySubjectResponse, CancelRequest, CancelResponse,
+    ConfigSetRequest, DeleteJobRequest, DeleteJobResponse, DlqListRequest, DlqListResponse,
+    DlqPurgeRequest, DlqPurgeResponse, DlqRequeueRequest, DlqRequeueResponse, EnqueueOutcome,
+    EnqueueRequest, EnqueueResponse, HealthCheckResponse, InspectRequest, InspectResponse,
+    ListJobsRequest, ListJobsResponse, MaintenanceRequest, MaintenanceResponse,
+    MetricsHistoryRequest, MetricsHistoryResponse, QueuesListResponse, ReplayReport,
+    ReportProgressRequest, ReportProgressResponse, RestoreRequest, RestoreResponse, RetryRequest,
+    RetryResponse, SnapshotRequest, StatsResponse, SubjectHistoryRequest, SubjectHistoryResponse,
+    TailLogsRequest, TailLogsResponse, WaitRequest, WaitResponse,
 };
+#[cfg(feature = "ws")]
+use crate::types::{FollowLogsRequest, JobStateChange, LogFollowEvent, SubscriptionFilter};
 use jsonrpsee::core::client::ClientT;
+#[cfg(feature = "ws")]
+use jsonrpsee::core::client::{Subscription, SubscriptionClientT};
+use jsonrpsee::core::traits::ToRpcParams;
 use jsonrpsee::http_client::{HttpClient, HttpClientBuilder};
-use jsonrpsee::rpc_params;
+#[cfg(feature = "ws")]
+use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::value::RawValue;
 use std::time::Duration;
 
+// One job.wait.v1 round, below the 30s request timeout
+const WAIT_ROUND: Duration = Duration::from_secs(25);
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Rate-limited calls are retried this often after the daemon's retry_after
+const DEFAULT_THROTTLE_RETRIES: u32 = 3;
+
+/// Longest single wait honored for a retry_after hint
+const MAX_THROTTLE_WAIT: Duration = Duration::from_secs(5);
+
+/// URL scheme of a daemon Unix socket (`unix:///path/to/semantica.sock`)
+const UNIX_SCHEME: &str = "unix://";
+
+/// Connection to the daemon
+enum Transport {
+    Http(Box<HttpClient>),
+    /// WebSocket over the daemon's Unix socket (one persistent connection)
+    #[cfg(feature = "ws")]
+    Unix(WsClient),
+}
+
+/// Sends a request struct as by-name params (`"params": {...}`), the form
+/// the daemon's handlers parse
+struct NamedParams<T>(T);
+
+impl<T: Serialize> ToRpcParams for NamedParams<T> {
+    fn to_rpc_params(self) -> std::result::Result<Option<Box<RawValue>>, serde_json::Error> {
+        serde_json::value::to_raw_value(&self.0).map(Some)
+    }
+}
+
 /// SemanticaTask Engine Client
 ///
 /// Provides a high-level interface to interact with the SemanticaTask daemon.
@@ -25,7 +76,14 @@ use std::time::Duration;
 /// # }
 /// ```
 pub struct SemanticaTaskClient {
-    client: HttpClient,
+    transport: Transport,
+    // Reopened as a WebSocket for subscriptions
+    #[cfg_attr(not(feature = "ws"), allow(dead_code))]
+    url: String,
+    journal: Option<OfflineJournal>,
+    // Identifies this client in job event history (`sdk:<client_id>`)
+    client_id: String,
+    throttle_retries: u32,
 }
 
 impl SemanticaTaskClient {
@@ -33,7 +91,8 @@ impl SemanticaTaskClient {
     ///
     /// # Arguments
     ///
-    /// * `url` - RPC endpoint URL (e.g., `http://127.0.0.1:9527`)
+    /// * `url` - RPC endpoint URL (e.g., `http://127.0.0.1:9527`, or
+    ///   `unix:///home/me/.semantica/semantica.sock` for the Unix socket)
     ///
     /// # Example
     ///
@@ -46,13 +105,102 @@ impl SemanticaTaskClient {
     /// ```
     pub async fn connect(url: impl AsRef<str>) -> Result<Self> {
         let url = url.as_ref();
+        if let Some(path) = url.strip_prefix(UNIX_SCHEME) {
+            return Self::connect_unix(path).await;
+        }
 
         let client = HttpClientBuilder::default()
-            .request_timeout(Duration::from_secs(30))
+            .request_timeout(REQUEST_TIMEOUT)
             .build(url)
             .map_err(|e| SdkError::Connection(format!("Failed to create client: {}", e)))?;
 
-        Ok(Self { client })
+        Ok(Self {
+            transport: Transport::Http(Box::new(client)),
+            url: url.to_string(),
+            journal: None,
+            client_id: uuid::Uuid::new_v4().to_string(),
+            throttle_retries: DEFAULT_THROTTLE_RETRIES,
+        })
+    }
+
+    /// Connect over the daemon's Unix socket (`SEMANTICA_RPC_SOCKET`)
+    ///
+    /// Access is governed by the socket file's permissions. Unlike HTTP, the
+    /// connection is established here, so an absent daemon fails immediately.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = SemanticaTaskClient::connect_unix("/home/me/.semantica/semantica.sock").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ws")]
+    pub async fn connect_unix(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let client = unix_ws_client(path).await?;
+
+        Ok(Self {
+            transport: Transport::Unix(client),
+            url: format!("{}{}", UNIX_SCHEME, path.display()),
+            journal: None,
+            client_id: uuid::Uuid::new_v4().to_string(),
+            throttle_retries: DEFAULT_THROTTLE_RETRIES,
+        })
+    }
+
+    /// The Unix socket speaks WebSocket, which this build left out
+    #[cfg(not(feature = "ws"))]
+    pub async fn connect_unix(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        Err(SdkError::Connection(format!(
+            "Unix socket {} needs the SDK's \"ws\" feature",
+            path.as_ref().display()
+        )))
+    }
+
+    /// Use a stable client id instead of the random one generated on connect
+    ///
+    /// The id is recorded as the actor (`sdk:<client_id>`) of cancellations.
+    pub fn with_client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.client_id = client_id.into();
+        self
+    }
+
+    /// Retry rate-limited calls up to `retries` times (0 = fail immediately)
+    ///
+    /// Each retry waits for the `retry_after_ms` the daemon sent with the
+    /// THROTTLED error (at most 5s); errors without that hint are not retried.
+    pub fn with_throttle_retries(mut self, retries: u32) -> Self {
+        self.throttle_retries = retries;
+        self
+    }
+
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    /// Enable offline queueing backed by a local journal file
+    ///
+    /// With a journal, [`enqueue_or_journal`](Self::enqueue_or_journal) stores requests
+    /// while the daemon is unreachable and [`replay_journal`](Self::replay_journal)
+    /// sends them once it is back.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = SemanticaTaskClient::connect("http://127.0.0.1:9527")
+    ///     .await?
+    ///     .with_offline_journal("/tmp/semantica-pending.jsonl");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_offline_journal(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.journal = Some(OfflineJournal::new(path));
+        self
     }
 
     /// Enqueue a new job
@@ -73,7 +221,12 @@ impl SemanticaTaskClient {
     ///     queue: "default".to_string(),
     ///     subject_key: "src/main.rs".to_string(),
     ///     priority: 0,
+    ///     idempotency_key: None,
+    ///     traceparent: None,
+    ///     job_class: None,
+    ///     fallback_payloads: Vec::new(),
     ///     payload: json!({"path": "src/main.rs"}),
+    ///     options: Default::default(),
     /// }).await?;
     ///
     /// println!("Job ID: {}", response.job_id);
@@ -81,12 +234,100 @@ impl SemanticaTaskClient {
     /// # }
     /// ```
     pub async fn enqueue(&self, request: EnqueueRequest) -> Result<EnqueueResponse> {
-        let params = rpc_params![request];
-        let response: EnqueueResponse = self.client.request("dev.enqueue.v1", params).await?;
+        let response: EnqueueResponse = self.call("dev.enqueue.v1", request).await?;
 
         Ok(response)
     }
 
+    /// Enqueue a job, journaling it locally if the daemon is unreachable
+    ///
+    /// Requires an offline journal and an `idempotency_key` on the request, so
+    /// a replay of a request the daemon already accepted cannot create a duplicate.
+    /// RPC errors (validation, rate limit, ...) are returned as-is and never journaled.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::{SemanticaTaskClient, EnqueueOutcome, EnqueueRequest};
+    /// # use serde_json::json;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527")
+    /// #     .await?
+    /// #     .with_offline_journal("/tmp/semantica-pending.jsonl");
+    /// let outcome = client.enqueue_or_journal(EnqueueRequest {
+    ///     job_type: "INDEX_FILE".to_string(),
+    ///     queue: "default".to_string(),
+    ///     subject_key: "src/main.rs".to_string(),
+    ///     priority: 0,
+    ///     idempotency_key: Some("editor-save-42".to_string()),
+    ///     traceparent: None,
+    ///     job_class: None,
+    ///     fallback_payloads: Vec::new(),
+    ///     payload: json!({"path": "src/main.rs"}),
+    ///     options: Default::default(),
+    /// }).await?;
+    ///
+    /// if let EnqueueOutcome::Journaled { .. } = outcome {
+    ///     // Later, when the daemon is back:
+    ///     client.replay_journal().await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn enqueue_or_journal(&self, request: EnqueueRequest) -> Result<EnqueueOutcome> {
+        let journal = self.journal()?;
+        let idempotency_key = request.idempotency_key.clone().ok_or_else(|| {
+            SdkError::Journal("Offline queueing requires an idempotency_key".to_string())
+        })?;
+
+        match self.enqueue(request.clone()).await {
+            Ok(response) => Ok(EnqueueOutcome::Enqueued(response)),
+            Err(e) if e.is_unreachable() => {
+                journal.append(&request).await?;
+                Ok(EnqueueOutcome::Journaled { idempotency_key })
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Replay journaled requests in order
+    ///
+    /// Stops at the first request that fails transiently (daemon unreachable,
+    /// rate-limited or failing on its side); that request and everything after
+    /// it stay in the journal. Requests the daemon rejects are dropped (retrying
+    /// would fail the same way) and reported.
+    pub async fn replay_journal(&self) -> Result<ReplayReport> {
+        let journal = self.journal()?;
+        let entries = journal.load().await?;
+        let mut report = ReplayReport::default();
+        let mut processed = 0;
+
+        for request in &entries {
+            match self.enqueue(request.clone()).await {
+                Ok(response) => report.replayed.push(response),
+                Err(e) if e.is_transient() => break,
+                Err(e) => report.rejected.push((
+                    request.idempotency_key.clone().unwrap_or_default(),
+                    e.to_string(),
+                )),
+            }
+            processed += 1;
+        }
+
+        journal.truncate_front(processed).await?;
+        report.pending = entries.len() - processed;
+
+        Ok(report)
+    }
+
+    fn journal(&self) -> Result<&OfflineJournal> {
+        self.journal.as_ref().ok_or_else(|| {
+            SdkError::Journal(
+                "Offline journal not configured (use with_offline_journal)".to_string(),
+            )
+        })
+    }
+
     /// Cancel a job
     ///
     /// # Arguments
@@ -105,15 +346,488 @@ impl SemanticaTaskClient {
     /// # }
     /// ```
     pub async fn cancel(&self, job_id: impl Into<String>) -> Result<CancelResponse> {
+        self.cancel_request(job_id.into(), None).await
+    }
+
+    /// Cancel a job, recording why in its event history
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// client.cancel_with_reason("job-123", "file deleted").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn cancel_with_reason(
+        &self,
+        job_id: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Result<CancelResponse> {
+        self.cancel_request(job_id.into(), Some(reason.into()))
+            .await
+    }
+
+    async fn cancel_request(
+        &self,
+        job_id: String,
+        reason: Option<String>,
+    ) -> Result<CancelResponse> {
         let request = CancelRequest {
+            job_id,
+            reason,
+            actor: Some(format!("sdk:{}", self.client_id)),
+        };
+        let response: CancelResponse = self.call("dev.cancel.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Cancel every QUEUED job whose subject_key matches a glob (atomically)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// // src/foo.rs was deleted
+    /// let response = client.cancel_by_subject("src/foo.rs::*", Some("file deleted")).await?;
+    /// println!("{} jobs cancelled", response.cancelled);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn cancel_by_subject(
+        &self,
+        subject_pattern: impl Into<String>,
+        reason: Option<&str>,
+    ) -> Result<CancelBySubjectResponse> {
+        let request = CancelBySubjectRequest {
+            subject_pattern: subject_pattern.into(),
+            queue: None,
+            reason: reason.map(str::to_string),
+            actor: Some(format!("sdk:{}", self.client_id)),
+        };
+        let response: CancelBySubjectResponse =
+            self.call("dev.cancel_by_subject.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Re-run a FAILED job
+    ///
+    /// The job is requeued in place with its attempts reset; its id and
+    /// event history are kept.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// let response = client.retry("job-123", Some("flaky network")).await?;
+    /// assert_eq!(response.state, "QUEUED");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn retry(
+        &self,
+        job_id: impl Into<String>,
+        reason: Option<&str>,
+    ) -> Result<RetryResponse> {
+        let request = RetryRequest {
+            job_id: job_id.into(),
+            reason: reason.map(str::to_string),
+            actor: Some(format!("sdk:{}", self.client_id)),
+            payload: None,
+        };
+        let response: RetryResponse = self.call("job.retry.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Re-run a finished job with a corrected payload
+    ///
+    /// Enqueues a copy of the job (same type, subject and options) linked to
+    /// it by `parent_job_id`; the original job is left as it is.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// let mut payload = client.inspect_with_payload("job-123").await?.payload.unwrap();
+    /// payload["args"] = serde_json::json!(["build", "--release"]);
+    /// let copy = client.retry_with_payload("job-123", payload, None).await?;
+    /// assert_eq!(copy.parent_job_id.as_deref(), Some("job-123"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn retry_with_payload(
+        &self,
+        job_id: impl Into<String>,
+        payload: serde_json::Value,
+        reason: Option<&str>,
+    ) -> Result<RetryResponse> {
+        let request = RetryRequest {
+            job_id: job_id.into(),
+            reason: reason.map(str::to_string),
+            actor: Some(format!("sdk:{}", self.client_id)),
+            payload: Some(payload),
+        };
+        let response: RetryResponse = self.call("job.retry.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Purge a job: its row, event history, artifacts and log file
+    ///
+    /// Only finished jobs, unless `force` (QUEUED jobs too). RUNNING jobs
+    /// must be cancelled first.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// let response = client.delete_job("job-123", false).await?;
+    /// assert!(response.leftovers.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_job(
+        &self,
+        job_id: impl Into<String>,
+        force: bool,
+    ) -> Result<DeleteJobResponse> {
+        let request = DeleteJobRequest {
+            job_id: job_id.into(),
+            force,
+            actor: Some(format!("sdk:{}", self.client_id)),
+        };
+        let response: DeleteJobResponse = self.call("job.delete.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Artifacts registered for a job, with size and checksum when recorded
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// for artifact in client.list_artifacts("job-123").await?.artifacts {
+    ///     println!("{} {:?}", artifact.uri, artifact.size_bytes);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_artifacts(&self, job_id: impl Into<String>) -> Result<ArtifactsListResponse> {
+        let request = ArtifactsListRequest {
+            job_id: job_id.into(),
+        };
+        let response: ArtifactsListResponse = self.call("job.artifacts.list.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Read one chunk of an artifact (None = daemon default size)
+    pub async fn read_artifact_chunk(
+        &self,
+        job_id: impl Into<String>,
+        uri: impl Into<String>,
+        offset: u64,
+        max_bytes: Option<usize>,
+    ) -> Result<ArtifactReadResponse> {
+        let request = ArtifactReadRequest {
+            job_id: job_id.into(),
+            uri: uri.into(),
+            offset,
+            max_bytes,
+        };
+        let response: ArtifactReadResponse = self.call("job.artifacts.read.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Read a whole artifact, chunk by chunk
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// let bundle = client
+    ///     .read_artifact("job-123", "/home/me/.semantica/artifacts/job-123-failure-1.tar.gz")
+    ///     .await?;
+    /// std::fs::write("bundle.tar.gz", bundle)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_artifact(&self, job_id: &str, uri: &str) -> Result<Vec<u8>> {
+        let mut content = Vec::new();
+        loop {
+            let chunk = self
+                .read_artifact_chunk(job_id, uri, content.len() as u64, None)
+                .await?;
+            content.extend(chunk.content()?);
+            if chunk.eof {
+                return Ok(content);
+            }
+        }
+    }
+
+    /// Recent successful results of a subject, newest first (None = daemon default)
+    ///
+    /// `changed` tells whether a run's output differs from the run before it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// let history = client.subject_history("index::src/main.rs", Some(2)).await?;
+    /// if history.entries.first().and_then(|e| e.changed) == Some(false) {
+    ///     println!("index unchanged since the previous run");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subject_history(
+        &self,
+        subject_key: impl Into<String>,
+        limit: Option<usize>,
+    ) -> Result<SubjectHistoryResponse> {
+        let request = SubjectHistoryRequest {
+            subject_key: subject_key.into(),
+            limit,
+        };
+        let response: SubjectHistoryResponse = self.call("subject.history.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Jobs that failed for good, newest first (None = all queues / daemon default)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// for dead in client.dlq_list(Some("code_intel"), Some(20)).await?.dead_letters {
+    ///     println!("{} after {} attempts: {}", dead.job_id, dead.attempts, dead.last_error);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dlq_list(
+        &self,
+        queue: Option<&str>,
+        limit: Option<usize>,
+    ) -> Result<DlqListResponse> {
+        let request = DlqListRequest {
+            queue: queue.map(str::to_string),
+            limit,
+        };
+        let response: DlqListResponse = self.call("dlq.list.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Re-run dead-lettered jobs with a fresh attempt budget (like `retry`)
+    ///
+    /// Jobs that are no longer FAILED are reported in `errors`, the others
+    /// are requeued. Use `dlq_requeue_all` to requeue a whole queue.
+    pub async fn dlq_requeue(
+        &self,
+        job_ids: Vec<String>,
+        reason: Option<&str>,
+    ) -> Result<DlqRequeueResponse> {
+        let request = DlqRequeueRequest {
+            job_ids,
+            reason: reason.map(str::to_string),
+            actor: Some(format!("sdk:{}", self.client_id)),
+            ..Default::default()
+        };
+        let response: DlqRequeueResponse = self.call("dlq.requeue.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Re-run every dead-lettered job (of `queue`), at most 1000 per call
+    pub async fn dlq_requeue_all(
+        &self,
+        queue: Option<&str>,
+        reason: Option<&str>,
+    ) -> Result<DlqRequeueResponse> {
+        let request = DlqRequeueRequest {
+            all: true,
+            queue: queue.map(str::to_string),
+            reason: reason.map(str::to_string),
+            actor: Some(format!("sdk:{}", self.client_id)),
+            ..Default::default()
+        };
+        let response: DlqRequeueResponse = self.call("dlq.requeue.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Drop dead-letter entries (of `queue`, dead before `before`); the FAILED jobs are kept
+    pub async fn dlq_purge(
+        &self,
+        queue: Option<&str>,
+        before: Option<i64>,
+    ) -> Result<DlqPurgeResponse> {
+        let request = DlqPurgeRequest {
+            queue: queue.map(str::to_string),
+            before,
+        };
+        let response: DlqPurgeResponse = self.call("dlq.purge.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Report the progress of a RUNNING job (0-100 plus an optional message)
+    ///
+    /// Meant for executors and job subprocesses; the latest report shows up in
+    /// `inspect`/`list_jobs` and is streamed to subscribers as a progress event.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// client
+    ///     .report_progress("job-123", 40, Some("parsed 400/1000 files".to_string()))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn report_progress(
+        &self,
+        job_id: impl Into<String>,
+        percent: u8,
+        message: Option<String>,
+    ) -> Result<ReportProgressResponse> {
+        let request = ReportProgressRequest {
+            job_id: job_id.into(),
+            percent,
+            message,
+        };
+        let response: ReportProgressResponse = self.call("job.report_progress.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Get job details and event history (who cancelled it and why, supersedes, ...)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// let job = client.inspect("job-123").await?;
+    /// for event in job.events {
+    ///     println!("{} by {}", event.state, event.actor);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn inspect(&self, job_id: impl Into<String>) -> Result<InspectResponse> {
+        let request = InspectRequest {
+            job_id: job_id.into(),
+            include_payload: false,
+        };
+        let response: InspectResponse = self.call("dev.inspect.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Inspect a job including its payload
+    pub async fn inspect_with_payload(&self, job_id: impl Into<String>) -> Result<InspectResponse> {
+        let request = InspectRequest {
+            job_id: job_id.into(),
+            include_payload: true,
+        };
+        let response: InspectResponse = self.call("dev.inspect.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// List jobs matching a filter, oldest first
+    ///
+    /// Pass `next_cursor` back as `after` to fetch the next page.
+    pub async fn list_jobs(&self, request: ListJobsRequest) -> Result<ListJobsResponse> {
+        let response: ListJobsResponse = self.call("dev.list.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Recorded mutating calls (enqueue, cancel, retry, admin changes), newest first
+    ///
+    /// Answers "who cancelled this job?": filter by `job_id` and `method`.
+    pub async fn audit_log(&self, request: AuditListRequest) -> Result<AuditListResponse> {
+        let response: AuditListResponse = self.call("admin.audit.list.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// A job already deleted by GC, read from the daemon's job archive
+    pub async fn archive_get(&self, job_id: impl Into<String>) -> Result<ArchiveGetResponse> {
+        let request = ArchiveGetRequest {
             job_id: job_id.into(),
         };
-        let params = rpc_params![request];
-        let response: CancelResponse = self.client.request("dev.cancel.v1", params).await?;
+        let response: ArchiveGetResponse = self.call("admin.archive.get.v1", request).await?;
 
         Ok(response)
     }
 
+    /// Wait until a job reaches a terminal state (or `timeout` elapses)
+    ///
+    /// Long-polls `job.wait.v1` in rounds shorter than the request timeout.
+    /// Check [`WaitResponse::terminal`] to tell completion from timeout.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # use std::time::Duration;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// let done = client.wait("job-123", Duration::from_secs(600)).await?;
+    /// println!("{} -> {}", done.job_id, done.state);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait(&self, job_id: impl Into<String>, timeout: Duration) -> Result<WaitResponse> {
+        let job_id = job_id.into();
+        let deadline = std::time::Instant::now() + timeout;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            let request = WaitRequest {
+                job_id: job_id.clone(),
+                timeout_ms: remaining.min(WAIT_ROUND).as_millis() as u64,
+            };
+            let response: WaitResponse = self.call("job.wait.v1", request).await?;
+
+            if response.terminal || remaining <= WAIT_ROUND {
+                return Ok(response);
+            }
+        }
+    }
+
     /// Tail job logs
     ///
     /// # Arguments
@@ -142,19 +856,517 @@ impl SemanticaTaskClient {
         let request = TailLogsRequest {
             job_id: job_id.into(),
             lines: lines.unwrap_or(50),
+            stream: None,
+        };
+        let response: TailLogsResponse = self.call("logs.tail.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Tail only one output stream of a job ("stdout" or "stderr")
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// let errors = client.tail_logs_stream("job-123", "stderr", Some(20)).await?;
+    /// for entry in errors.entries {
+    ///     println!("{} {}", entry.ts, entry.line);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn tail_logs_stream(
+        &self,
+        job_id: impl Into<String>,
+        stream: impl Into<String>,
+        lines: Option<usize>,
+    ) -> Result<TailLogsResponse> {
+        let request = TailLogsRequest {
+            job_id: job_id.into(),
+            lines: lines.unwrap_or(50),
+            stream: Some(stream.into()),
+        };
+        let response: TailLogsResponse = self.call("logs.tail.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Daemon statistics, schema version and capabilities
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// let stats = client.stats().await?;
+    /// println!("schema v{}, {} queued", stats.schema_version, stats.queued_jobs);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stats(&self) -> Result<StatsResponse> {
+        let response: StatsResponse = self.call("admin.stats.v1", serde_json::json!({})).await?;
+
+        Ok(response)
+    }
+
+    /// Throughput snapshots (jobs completed / failed per minute, queue depth)
+    ///
+    /// The daemon samples once a minute by default; the newest `limit`
+    /// snapshots taken after `since` are returned oldest first, ready to plot.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use semantica_task_sdk::{MetricsHistoryRequest, SemanticaTaskClient};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// let history = client.metrics_history(MetricsHistoryRequest::default()).await?;
+    /// for snapshot in &history.snapshots {
+    ///     println!("{} {:.1}/min", snapshot.taken_at, snapshot.completed_per_minute);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn metrics_history(
+        &self,
+        request: MetricsHistoryRequest,
+    ) -> Result<MetricsHistoryResponse> {
+        let response: MetricsHistoryResponse =
+            self.call("admin.metrics.history.v1", request).await?;
+
+        Ok(response)
+    }
+
+    /// Check database reachability, worker loop liveness and maintenance status
+    ///
+    /// Not rate limited. `ready == false` means the daemon answers but is wedged.
+    pub async fn health(&self) -> Result<HealthCheckResponse> {
+        let response: HealthCheckResponse =
+            self.call("health.check.v1", serde_json::json!({})).await?;
+
+        Ok(response)
+    }
+
+    /// OpenRPC document describing every method of the daemon's API
+    ///
+    /// Generated from the server's request/response types, for generating
+    /// bindings in other languages.
+    pub async fn discover(&self) -> Result<serde_json::Value> {
+        self.call("rpc.discover", serde_json::json!({})).await
+    }
+
+    /// List every queue with its job counts, oldest wait and last-hour throughput
+    pub async fn list_queues(&self) -> Result<QueuesListResponse> {
+        let response: QueuesListResponse = self
+            .call("admin.queues.list.v1", serde_json::json!({}))
+            .await?;
+
+        Ok(response)
+    }
+
+    /// Run maintenance (job GC, artifact GC, optional VACUUM) immediately
+    ///
+    /// # Arguments
+    ///
+    /// * `force_vacuum` - VACUUM even if the database is below the size threshold
+    pub async fn run_maintenance(&self, force_vacuum: bool) -> Result<MaintenanceResponse> {
+        let response: MaintenanceResponse = self
+            .call(
+                "admin.maintenance.v1",
+                MaintenanceRequest {
+                    force_vacuum,
+                    dry_run: false,
+                    retention_days: None,
+                },
+            )
+            .await?;
+
+        Ok(response)
+    }
+
+    /// Report what maintenance would delete with the given retention, deleting nothing
+    ///
+    /// `response.preview` lists the jobs, log files and artifacts; check it
+    /// before lowering retention on a busy daemon.
+    pub async fn preview_maintenance(&self, retention_days: i64) -> Result<MaintenanceResponse> {
+        let response: MaintenanceResponse = self
+            .call(
+                "admin.maintenance.v1",
+                MaintenanceRequest {
+                    force_vacuum: false,
+                    dry_run: true,
+                    retention_days: Some(retention_days),
+                },
+            )
+            .await?;
+
+        Ok(response)
+    }
+
+    /// Stream job state transitions as the daemon's worker updates jobs
+    ///
+    /// Opens a WebSocket to the same endpoint (`jobs.subscribe.v1`); events
+    /// missed while the connection is down are not replayed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// let mut changes = client.subscribe_state_changes().await?;
+    /// while let Some(change) = changes.next().await {
+    ///     let change = change?;
+    ///     println!("{} {:?} -> {}", change.job_id, change.old_state, change.new_state);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ws")]
+    pub async fn subscribe_state_changes(&self) -> Result<StateChangeStream> {
+        self.subscribe_state_changes_filtered(SubscriptionFilter::default())
+            .await
+    }
+
+    /// Stream only the events matching `filter` (filtered by the daemon)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::{SemanticaTaskClient, SubscriptionFilter};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// let filter = SubscriptionFilter {
+    ///     queue: Some("build".to_string()),
+    ///     kinds: Some(vec!["state".to_string()]),
+    ///     ..Default::default()
+    /// };
+    /// let mut changes = client.subscribe_state_changes_filtered(filter).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ws")]
+    pub async fn subscribe_state_changes_filtered(
+        &self,
+        filter: SubscriptionFilter,
+    ) -> Result<StateChangeStream> {
+        let client = self.ws_client().await?;
+        let subscription = client
+            .subscribe(
+                "jobs.subscribe.v1",
+                NamedParams(filter),
+                "jobs.unsubscribe.v1",
+            )
+            .await?;
+
+        Ok(StateChangeStream {
+            _client: client,
+            subscription,
+        })
+    }
+
+    /// Stream a job's log as it is written, ending once the job finished
+    ///
+    /// Sends the last `lines` lines already written first (like `tail -f`).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::{LogFollowEvent, SemanticaTaskClient};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// let mut log = client.follow_logs("job-123", 10, None).await?;
+    /// while let Some(event) = log.next().await {
+    ///     match event? {
+    ///         LogFollowEvent::Line { line, .. } => println!("{}", line),
+    ///         LogFollowEvent::End { state } => println!("finished: {}", state),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ws")]
+    pub async fn follow_logs(
+        &self,
+        job_id: impl Into<String>,
+        lines: usize,
+        stream: Option<String>,
+    ) -> Result<LogFollowStream> {
+        let request = FollowLogsRequest {
+            job_id: job_id.into(),
+            lines,
+            stream,
+        };
+        let client = self.ws_client().await?;
+        let subscription = client
+            .subscribe("logs.follow.v1", NamedParams(request), "logs.unfollow.v1")
+            .await?;
+
+        Ok(LogFollowStream {
+            _client: client,
+            subscription,
+            ended: false,
+        })
+    }
+
+    /// WebSocket to the daemon endpoint (subscriptions are not served over HTTP)
+    #[cfg(feature = "ws")]
+    async fn ws_client(&self) -> Result<WsClient> {
+        let client = match self.url.split_once("://") {
+            Some(("unix", path)) => unix_ws_client(std::path::Path::new(path)).await?,
+            Some((scheme, rest)) => {
+                let ws_url = match scheme {
+                    "https" => format!("wss://{}", rest),
+                    "http" => format!("ws://{}", rest),
+                    _ => self.url.clone(),
+                };
+                WsClientBuilder::default()
+                    .build(&ws_url)
+                    .await
+                    .map_err(|e| SdkError::Connection(format!("Failed to open WebSocket: {}", e)))?
+            }
+            None => {
+                return Err(SdkError::Connection(format!(
+                    "Invalid daemon URL '{}'",
+                    self.url
+                )))
+            }
+        };
+
+        Ok(client)
+    }
+
+    /// Capture jobs and subject generations of `queue` (None = every queue)
+    ///
+    /// The snapshot is returned as JSON, ready to be written to a file and
+    /// restored later with [`restore`](Self::restore).
+    pub async fn snapshot(&self, queue: Option<&str>) -> Result<serde_json::Value> {
+        let request = SnapshotRequest {
+            queue: queue.map(str::to_string),
+            anonymize: false,
+        };
+        let snapshot: serde_json::Value = self.call("admin.snapshot.v1", request).await?;
+
+        Ok(snapshot)
+    }
+
+    /// Like [`snapshot`](Self::snapshot), with payloads and env_vars replaced by
+    /// hashes and sizes so the dump can be shared without leaking code or secrets
+    pub async fn snapshot_anonymized(&self, queue: Option<&str>) -> Result<serde_json::Value> {
+        let request = SnapshotRequest {
+            queue: queue.map(str::to_string),
+            anonymize: true,
         };
-        let params = rpc_params![request];
-        let response: TailLogsResponse = self.client.request("logs.tail.v1", params).await?;
+        let snapshot: serde_json::Value = self.call("admin.snapshot.v1", request).await?;
+
+        Ok(snapshot)
+    }
+
+    /// Restore a snapshot into the daemon (all or nothing, job ids must be new)
+    pub async fn restore(&self, snapshot: serde_json::Value) -> Result<RestoreResponse> {
+        let response: RestoreResponse = self
+            .call("admin.restore.v1", RestoreRequest { snapshot })
+            .await?;
 
         Ok(response)
     }
+
+    /// Current runtime settings (CPU throttle, retention, rate limits, ...)
+    pub async fn config(&self) -> Result<serde_json::Value> {
+        let settings: serde_json::Value = self
+            .call("admin.config.get.v1", serde_json::json!({}))
+            .await?;
+
+        Ok(settings)
+    }
+
+    /// Change one runtime setting without restarting the daemon
+    ///
+    /// Returns every setting after the change. The value reverts to its
+    /// config file / env / default value when the daemon restarts or
+    /// reloads its config (SIGHUP).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use semantica_task_sdk::SemanticaTaskClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SemanticaTaskClient::connect("http://127.0.0.1:9527").await?;
+    /// client.set_config("cpu_throttle_percent", serde_json::json!(75.0)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_config(
+        &self,
+        key: impl Into<String>,
+        value: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let request = ConfigSetRequest {
+            key: key.into(),
+            value,
+        };
+        let response: serde_json::Value = self.call("admin.config.set.v1", request).await?;
+
+        Ok(response["settings"].clone())
+    }
+
+    async fn call<P: Serialize + Send, R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: P,
+    ) -> Result<R> {
+        // The daemon logs the call under this ID and returns it with errors
+        let mut params = serde_json::to_value(params)?;
+        if let serde_json::Value::Object(fields) = &mut params {
+            fields.insert(
+                "request_id".to_string(),
+                serde_json::Value::String(uuid::Uuid::new_v4().simple().to_string()),
+            );
+        }
+
+        let mut retries = 0;
+        loop {
+            let result = match &self.transport {
+                Transport::Http(client) => client.request(method, NamedParams(&params)).await,
+                #[cfg(feature = "ws")]
+                Transport::Unix(client) => client.request(method, NamedParams(&params)).await,
+            };
+            let err = match result {
+                Ok(response) => return Ok(response),
+                Err(e) => SdkError::from(e),
+            };
+
+            // Rate limited: wait as long as the daemon asked, then retry
+            match err.retry_after() {
+                Some(wait) if retries < self.throttle_retries => {
+                    retries += 1;
+                    tokio::time::sleep(wait.min(MAX_THROTTLE_WAIT)).await;
+                }
+                _ => return Err(err),
+            }
+        }
+    }
+}
+
+/// WebSocket client over a Unix socket connection
+#[cfg(all(feature = "ws", unix))]
+async fn unix_ws_client(path: &std::path::Path) -> Result<WsClient> {
+    let stream = tokio::net::UnixStream::connect(path).await.map_err(|e| {
+        SdkError::Connection(format!("Failed to connect to {}: {}", path.display(), e))
+    })?;
+    // The host is only used for the handshake's Host header
+    WsClientBuilder::default()
+        .request_timeout(REQUEST_TIMEOUT)
+        .build_with_stream("ws://localhost", stream)
+        .await
+        .map_err(|e| SdkError::Connection(format!("Failed to open WebSocket: {}", e)))
+}
+
+#[cfg(all(feature = "ws", not(unix)))]
+async fn unix_ws_client(path: &std::path::Path) -> Result<WsClient> {
+    Err(SdkError::Connection(format!(
+        "Unix socket {} is not supported on this platform",
+        path.display()
+    )))
+}
+
+/// Job state transitions pushed by the daemon (see `subscribe_state_changes`)
+#[cfg(feature = "ws")]
+pub struct StateChangeStream {
+    // Dropping the client closes the WebSocket
+    _client: WsClient,
+    subscription: Subscription<JobStateChange>,
+}
+
+#[cfg(feature = "ws")]
+impl StateChangeStream {
+    /// Next transition (None once the daemon closed the stream)
+    pub async fn next(&mut self) -> Option<Result<JobStateChange>> {
+        self.subscription
+            .next()
+            .await
+            .map(|change| change.map_err(SdkError::from))
+    }
+}
+
+/// Lines of a followed job log (see `follow_logs`)
+#[cfg(feature = "ws")]
+pub struct LogFollowStream {
+    // Dropping the client closes the WebSocket
+    _client: WsClient,
+    subscription: Subscription<LogFollowEvent>,
+    ended: bool,
+}
+
+#[cfg(feature = "ws")]
+impl LogFollowStream {
+    /// Next event (None after `End` or once the daemon closed the stream)
+    pub async fn next(&mut self) -> Option<Result<LogFollowEvent>> {
+        // The daemon drops the subscription after `End` without telling the client
+        if self.ended {
+            return None;
+        }
+        let event = self.subscription.next().await?;
+        self.ended = matches!(event, Ok(LogFollowEvent::End { .. }));
+        Some(event.map_err(SdkError::from))
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use serde_json::json;
+
     #[test]
     fn test_sdk_types() {
         // Basic smoke test to ensure SDK compiles
         // Integration tests require running daemon
     }
+
+    #[tokio::test]
+    async fn test_enqueue_or_journal_when_unreachable() {
+        let path = std::env::temp_dir().join(format!(
+            "semantica-client-journal-{}.jsonl",
+            uuid::Uuid::new_v4()
+        ));
+        // Port 1 is never served; connection is refused immediately
+        let client = SemanticaTaskClient::connect("http://127.0.0.1:1")
+            .await
+            .unwrap()
+            .with_offline_journal(&path);
+
+        let mut request = EnqueueRequest {
+            job_type: "INDEX_FILE".to_string(),
+            queue: "default".to_string(),
+            subject_key: "src/main.rs".to_string(),
+            payload: json!({}),
+            priority: 0,
+            idempotency_key: None,
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
+        };
+        assert!(matches!(
+            client.enqueue_or_journal(request.clone()).await,
+            Err(SdkError::Journal(_))
+        ));
+
+        request.idempotency_key = Some("save-1".to_string());
+        let outcome = client.enqueue_or_journal(request).await.unwrap();
+        assert!(matches!(
+            outcome,
+            EnqueueOutcome::Journaled { ref idempotency_key } if idempotency_key == "save-1"
+        ));
+
+        // Still unreachable: nothing replayed, entry kept
+        let report = client.replay_journal().await.unwrap();
+        assert!(report.replayed.is_empty());
+        assert_eq!(report.pending, 1);
+
+        let _ = std::fs::remove_file(&path);
+    }
 }
@@ -1,7 +1,14 @@
 //! SDK Error Types
 
+use std::time::Duration;
 use thiserror::Error;
 
+/// RPC error code of a rate-limited request
+const THROTTLED: i32 = 4003;
+
+/// RPC error codes from 5000 on are daemon-side failures (internal, database, system)
+const SERVER_ERROR_MIN: i32 = 5000;
+
 /// SDK Result type
 pub type Result<T> = std::result::Result<T, SdkError>;
 
@@ -11,8 +18,15 @@ pub enum SdkError {
     #[error("Connection error: {0}")]
     Connection(String),
 
-    #[error("RPC error ({code}): {message}")]
-    Rpc { code: i32, message: String },
+    #[error("RPC error ({code}): {message}{}", request_suffix(.request_id))]
+    Rpc {
+        code: i32,
+        message: String,
+        /// Wait suggested by the daemon before retrying (rate-limited requests)
+        retry_after_ms: Option<u64>,
+        /// ID of the failed request, as found in the daemon log
+        request_id: Option<String>,
+    },
 
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
@@ -23,23 +37,84 @@ pub enum SdkError {
     #[error("Transport error: {0}")]
     Transport(String),
 
+    #[error("Journal error: {0}")]
+    Journal(String),
+
     #[error("Other error: {0}")]
     Other(String),
 }
 
+impl SdkError {
+    /// Whether the daemon could not be reached (request may be retried later)
+    pub fn is_unreachable(&self) -> bool {
+        matches!(self, SdkError::Connection(_) | SdkError::Transport(_))
+    }
+
+    /// Whether the daemon rate-limited the request
+    pub fn is_throttled(&self) -> bool {
+        matches!(self, SdkError::Rpc { code, .. } if *code == THROTTLED)
+    }
+
+    /// Whether the same request may succeed later: the daemon was unreachable,
+    /// rate-limited it or failed on its side (a rejected request would not)
+    pub fn is_transient(&self) -> bool {
+        self.is_unreachable()
+            || self.is_throttled()
+            || matches!(self, SdkError::Rpc { code, .. } if *code >= SERVER_ERROR_MIN)
+    }
+
+    /// Request ID of a call the daemon rejected (search the daemon log for it)
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            SdkError::Rpc { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// How long to wait before retrying a rate-limited request
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            SdkError::Rpc {
+                code,
+                retry_after_ms: Some(ms),
+                ..
+            } if *code == THROTTLED => Some(Duration::from_millis(*ms)),
+            _ => None,
+        }
+    }
+}
+
+fn request_suffix(request_id: &Option<String>) -> String {
+    request_id
+        .as_deref()
+        .map(|id| format!(" (request {})", id))
+        .unwrap_or_default()
+}
+
 impl From<jsonrpsee::core::ClientError> for SdkError {
     fn from(e: jsonrpsee::core::ClientError) -> Self {
         match e {
-            jsonrpsee::core::ClientError::Call(call_err) => SdkError::Rpc {
-                code: call_err.code(),
-                message: call_err.message().to_string(),
-            },
+            jsonrpsee::core::ClientError::Call(call_err) => {
+                let data = call_err
+                    .data()
+                    .and_then(|data| serde_json::from_str::<serde_json::Value>(data.get()).ok())
+                    .unwrap_or_default();
+                SdkError::Rpc {
+                    code: call_err.code(),
+                    message: call_err.message().to_string(),
+                    retry_after_ms: data["retry_after_ms"].as_u64(),
+                    request_id: data["request_id"].as_str().map(str::to_string),
+                }
+            }
             jsonrpsee::core::ClientError::Transport(e) => {
                 SdkError::Transport(format!("Transport error: {}", e))
             }
             jsonrpsee::core::ClientError::RestartNeeded(_) => {
                 SdkError::Connection("Connection restart needed".to_string())
             }
+            jsonrpsee::core::ClientError::RequestTimeout => {
+                SdkError::Transport("Request timed out".to_string())
+            }
             jsonrpsee::core::ClientError::ParseError(e) => {
                 SdkError::Other(format!("Parse error: {}", e))
             }
@@ -47,3 +122,58 @@ impl From<jsonrpsee::core::ClientError> for SdkError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::types::ErrorObjectOwned;
+
+    #[test]
+    fn test_retry_after_from_throttled_error_data() {
+        let data = serde_json::json!({ "scope": "global", "retry_after_ms": 250 });
+        let err = SdkError::from(jsonrpsee::core::ClientError::Call(ErrorObjectOwned::owned(
+            THROTTLED,
+            "Rate limit exceeded. Please slow down.",
+            Some(data),
+        )));
+        assert!(err.is_throttled());
+        assert_eq!(err.retry_after(), Some(Duration::from_millis(250)));
+        assert_eq!(err.request_id(), None);
+
+        let not_found = SdkError::from(jsonrpsee::core::ClientError::Call(
+            ErrorObjectOwned::owned(4001, "gone", None::<()>),
+        ));
+        assert!(!not_found.is_throttled());
+        assert_eq!(not_found.retry_after(), None);
+    }
+
+    #[test]
+    fn test_transient_errors() {
+        let rpc = |code| SdkError::Rpc {
+            code,
+            message: String::new(),
+            retry_after_ms: None,
+            request_id: None,
+        };
+        assert!(SdkError::Connection("refused".to_string()).is_transient());
+        assert!(rpc(THROTTLED).is_transient());
+        assert!(rpc(5001).is_transient());
+        assert!(!rpc(4000).is_transient());
+        assert!(!SdkError::Journal("corrupt".to_string()).is_transient());
+    }
+
+    #[test]
+    fn test_request_id_from_error_data() {
+        let data = serde_json::json!({ "request_id": "3f2a" });
+        let err = SdkError::from(jsonrpsee::core::ClientError::Call(ErrorObjectOwned::owned(
+            4000,
+            "Payload too large",
+            Some(data),
+        )));
+        assert_eq!(err.request_id(), Some("3f2a"));
+        assert_eq!(
+            err.to_string(),
+            "RPC error (4000): Payload too large (request 3f2a)"
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! Offline Enqueue Journal
+//!
+//! Append-only JSON Lines file holding enqueue requests that could not reach
+//! the daemon. Entries are replayed in order once the daemon is back; the
+//! idempotency key on each entry makes a replay of an already-accepted request
+//! resolve to the original job instead of creating a duplicate.
+
+use crate::error::{Result, SdkError};
+use crate::types::EnqueueRequest;
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+/// Local journal of pending enqueue requests
+pub struct OfflineJournal {
+    path: PathBuf,
+    // Serializes append/rewrite within this process
+    lock: Mutex<()>,
+}
+
+impl OfflineJournal {
+    /// Open (or lazily create) a journal at `path`
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append a request to the end of the journal
+    pub async fn append(&self, request: &EnqueueRequest) -> Result<()> {
+        let _guard = self.lock.lock().await;
+
+        let mut line = serde_json::to_string(request)?;
+        line.push('\n');
+
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| journal_error("create directory for", &self.path, e))?;
+        }
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| journal_error("open", &self.path, e))?;
+        file.write_all(line.as_bytes())
+            .await
+            .map_err(|e| journal_error("append to", &self.path, e))?;
+        file.sync_data()
+            .await
+            .map_err(|e| journal_error("sync", &self.path, e))?;
+
+        Ok(())
+    }
+
+    /// Read all pending requests (oldest first)
+    pub async fn load(&self) -> Result<Vec<EnqueueRequest>> {
+        let _guard = self.lock.lock().await;
+        self.read_entries().await
+    }
+
+    /// Remove the first `count` entries (the ones successfully replayed)
+    ///
+    /// Entries appended after `load` are kept, so a concurrent `append` is never lost.
+    pub async fn truncate_front(&self, count: usize) -> Result<()> {
+        let _guard = self.lock.lock().await;
+
+        let remaining: Vec<EnqueueRequest> =
+            self.read_entries().await?.into_iter().skip(count).collect();
+
+        if remaining.is_empty() {
+            return match tokio::fs::remove_file(&self.path).await {
+                Ok(()) => Ok(()),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+                Err(e) => Err(journal_error("remove", &self.path, e)),
+            };
+        }
+
+        let mut contents = String::new();
+        for request in &remaining {
+            contents.push_str(&serde_json::to_string(request)?);
+            contents.push('\n');
+        }
+
+        // Write-then-rename so a crash never leaves a half-written journal
+        let tmp_path = self.path.with_extension("tmp");
+        tokio::fs::write(&tmp_path, contents)
+            .await
+            .map_err(|e| journal_error("write", &tmp_path, e))?;
+        tokio::fs::rename(&tmp_path, &self.path)
+            .await
+            .map_err(|e| journal_error("replace", &self.path, e))?;
+
+        Ok(())
+    }
+
+    async fn read_entries(&self) -> Result<Vec<EnqueueRequest>> {
+        let contents = match tokio::fs::read_to_string(&self.path).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(journal_error("read", &self.path, e)),
+        };
+
+        contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).map_err(SdkError::from))
+            .collect()
+    }
+}
+
+fn journal_error(action: &str, path: &Path, e: std::io::Error) -> SdkError {
+    SdkError::Journal(format!("Failed to {} {}: {}", action, path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(key: &str) -> EnqueueRequest {
+        EnqueueRequest {
+            job_type: "INDEX_FILE".to_string(),
+            queue: "default".to_string(),
+            subject_key: format!("{}.rs", key),
+            payload: json!({}),
+            priority: 0,
+            idempotency_key: Some(key.to_string()),
+            traceparent: None,
+            job_class: None,
+            fallback_payloads: Vec::new(),
+            options: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_append_load_truncate() {
+        let path =
+            std::env::temp_dir().join(format!("semantica-journal-{}.jsonl", uuid::Uuid::new_v4()));
+        let journal = OfflineJournal::new(&path);
+
+        assert!(journal.load().await.unwrap().is_empty());
+
+        journal.append(&request("a")).await.unwrap();
+        journal.append(&request("b")).await.unwrap();
+        journal.append(&request("c")).await.unwrap();
+
+        let entries = journal.load().await.unwrap();
+        let keys: Vec<_> = entries
+            .iter()
+            .map(|r| r.idempotency_key.clone().unwrap())
+            .collect();
+        assert_eq!(keys, vec!["a", "b", "c"]);
+
+        journal.truncate_front(2).await.unwrap();
+        let entries = journal.load().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].idempotency_key.as_deref(), Some("c"));
+
+        journal.truncate_front(1).await.unwrap();
+        assert!(!path.exists());
+    }
+}
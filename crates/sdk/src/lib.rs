@@ -19,7 +19,12 @@
 //!         queue: "default".to_string(),
 //!         subject_key: "src/main.rs".to_string(),
 //!         priority: 0,
+//!         idempotency_key: None,
+//!         traceparent: None,
+//!         job_class: None,
+//!         fallback_payloads: Vec::new(),
 //!         payload: json!({"path": "src/main.rs"}),
+//!         options: Default::default(),
 //!     }).await?;
 //!
 //!     println!("Job enqueued: {}", response.job_id);
@@ -27,14 +32,37 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! # Features
+//!
+//! - `ws` (default): WebSocket transport - the Unix socket, state change
+//!   subscriptions and log following. Without it the SDK only speaks
+//!   JSON-RPC over HTTP (`default-features = false` for a minimal build).
 
 mod client;
 mod error;
+mod journal;
 mod types;
 
 pub use client::SemanticaTaskClient;
+#[cfg(feature = "ws")]
+pub use client::{LogFollowStream, StateChangeStream};
 pub use error::{Result, SdkError};
+pub use journal::OfflineJournal;
 pub use types::{
-    CancelRequest, CancelResponse, EnqueueRequest, EnqueueResponse, TailLogsRequest,
-    TailLogsResponse,
+    ArchiveGetRequest, ArchiveGetResponse, ArtifactInfo, ArtifactReadRequest, ArtifactReadResponse,
+    ArtifactsListRequest, ArtifactsListResponse, AuditEntry, AuditListRequest, AuditListResponse,
+    CancelBySubjectRequest, CancelBySubjectResponse, CancelRequest, CancelResponse,
+    ConfigSetRequest, DatabaseHealth, DeadLetterInfo, DeleteJobRequest, DeleteJobResponse,
+    DlqListRequest, DlqListResponse, DlqPurgeRequest, DlqPurgeResponse, DlqRequeueError,
+    DlqRequeueRequest, DlqRequeueResponse, EnqueueOptions, EnqueueOutcome, EnqueueRequest,
+    EnqueueResponse, FollowLogsRequest, GcPreview, HealthCheckResponse, InspectRequest,
+    InspectResponse, JobEventInfo, JobProgress, JobStateChange, JobSummary, JobTypeStatsInfo,
+    ListJobsRequest, ListJobsResponse, LogEntry, LogFollowEvent, MaintenanceHealth,
+    MaintenanceRequest, MaintenanceResponse, MetricsHistoryRequest, MetricsHistoryResponse,
+    MetricsSnapshotInfo, QueueInfo, QueuesListResponse, ReplayReport, ReportProgressRequest,
+    ReportProgressResponse, RestoreRequest, RestoreResponse, RetryRequest, RetryResponse,
+    ServiceInfo, SnapshotRequest, StatsResponse, SubjectHistoryEntry, SubjectHistoryRequest,
+    SubjectHistoryResponse, SubscriptionFilter, TailLogsRequest, TailLogsResponse, WaitRequest,
+    WaitResponse, WaitSloInfo, WorkerHealth, WorkerSlot,
 };
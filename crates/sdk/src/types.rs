@@ -2,10 +2,11 @@
 //!
 //! Mirrors the JSON-RPC types from api-rpc crate.
 
+use base64::prelude::{Engine, BASE64_STANDARD};
 use serde::{Deserialize, Serialize};
 
 /// Request to enqueue a new job
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EnqueueRequest {
     pub job_type: String,
     pub queue: String,
@@ -13,6 +14,54 @@ pub struct EnqueueRequest {
     pub payload: serde_json::Value,
     #[serde(default)]
     pub priority: i32,
+    /// Re-sending the same key returns the original job (required for offline journaling)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    /// W3C traceparent of the caller's current span; the job's execution continues that trace
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub traceparent: Option<String>,
+    /// "SERVICE" for a job that runs indefinitely and is restarted when it exits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub job_class: Option<String>,
+    /// Payloads of the retries, in order (attempt 2 runs the first one, ...)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback_payloads: Vec<serde_json::Value>,
+    /// Scheduling, retry and grouping fields (sent flat, unset ones omitted)
+    #[serde(flatten, default)]
+    pub options: EnqueueOptions,
+}
+
+/// Optional job fields of [`EnqueueRequest`] (None/false = the daemon defaults)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EnqueueOptions {
+    /// Earliest start (epoch ms)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule_at: Option<i64>,
+    /// Skip the job if not finished by then (epoch ms)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<i64>,
+    /// Skip the job if still queued this long after enqueue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_ms: Option<i64>,
+    /// Total attempts including the first (default 3)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<i32>,
+    /// Retry delay multiplier per attempt (default 2.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backoff_factor: Option<f64>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub wait_for_idle: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub require_charging: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_tag: Option<String>,
+    /// "IN_PROCESS" (default) or "SUBPROCESS"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_mode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_job_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_group_id: Option<String>,
 }
 
 /// Response from enqueue operation
@@ -23,10 +72,37 @@ pub struct EnqueueResponse {
     pub queue: String,
 }
 
+/// Result of [`SemanticaTaskClient::enqueue_or_journal`](crate::SemanticaTaskClient::enqueue_or_journal)
+#[derive(Debug, Clone)]
+pub enum EnqueueOutcome {
+    /// Daemon accepted the job
+    Enqueued(EnqueueResponse),
+    /// Daemon unreachable; request stored in the offline journal for replay
+    Journaled { idempotency_key: String },
+}
+
+/// Result of replaying the offline journal
+#[derive(Debug, Clone, Default)]
+pub struct ReplayReport {
+    /// Requests accepted by the daemon (including ones it had already seen)
+    pub replayed: Vec<EnqueueResponse>,
+    /// Requests the daemon rejected (idempotency key, error), dropped from the journal
+    pub rejected: Vec<(String, String)>,
+    /// Requests still in the journal because the daemon became unreachable again,
+    /// rate-limited the replay or failed on its side
+    pub pending: usize,
+}
+
 /// Request to cancel a job
 #[derive(Debug, Clone, Serialize)]
 pub struct CancelRequest {
     pub job_id: String,
+    /// Recorded in the job's event history
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Who is cancelling (the SDK sends `sdk:<client_id>`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
 }
 
 /// Response from cancel operation
@@ -34,6 +110,390 @@ pub struct CancelRequest {
 pub struct CancelResponse {
     pub job_id: String,
     pub cancelled: bool,
+    /// The job was executing in the daemon's worker
+    #[serde(default)]
+    pub was_running: bool,
+    /// Its execution is over (subprocess killed and reaped, or aborted)
+    #[serde(default)]
+    pub terminated: bool,
+    /// Subprocess that was killed
+    #[serde(default)]
+    pub pid: Option<u32>,
+}
+
+/// Request to cancel QUEUED jobs by subject_key glob
+#[derive(Debug, Clone, Serialize)]
+pub struct CancelBySubjectRequest {
+    /// Glob over subject_key (`*`, `?`, `[...]`), e.g. `src/foo.rs::*`
+    pub subject_pattern: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+}
+
+/// Response from cancel-by-subject operation
+#[derive(Debug, Clone, Deserialize)]
+pub struct CancelBySubjectResponse {
+    pub cancelled: usize,
+    pub job_ids: Vec<String>,
+}
+
+/// Request to re-run a FAILED job
+#[derive(Debug, Clone, Serialize)]
+pub struct RetryRequest {
+    pub job_id: String,
+    /// Recorded in the job's event history
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Who is retrying (the SDK sends `sdk:<client_id>`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+    /// Enqueue a corrected copy of the finished job with this payload instead
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<serde_json::Value>,
+}
+
+/// Request to purge one job (job.delete.v1)
+#[derive(Debug, Clone, Serialize)]
+pub struct DeleteJobRequest {
+    pub job_id: String,
+    /// Also delete a QUEUED job
+    pub force: bool,
+    /// Who is deleting (the SDK sends `sdk:<client_id>`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+}
+
+/// Response from delete operation
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeleteJobResponse {
+    pub job_id: String,
+    /// State the job was in
+    pub state: String,
+    pub log_deleted: bool,
+    pub artifacts_deleted: usize,
+    /// Log file or artifacts that could not be removed
+    #[serde(default)]
+    pub leftovers: Vec<String>,
+}
+
+/// Request for a job's artifacts (job.artifacts.list.v1)
+#[derive(Debug, Clone, Serialize)]
+pub struct ArtifactsListRequest {
+    pub job_id: String,
+}
+
+/// A file registered for a job (e.g. a failure bundle)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArtifactInfo {
+    pub uri: String,
+    /// None for artifacts registered before metadata was recorded
+    pub size_bytes: Option<u64>,
+    /// SHA-256 of the content (hex)
+    pub checksum: Option<String>,
+    pub created_at: Option<i64>,
+}
+
+/// Artifacts of a job (job.artifacts.list.v1)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArtifactsListResponse {
+    pub job_id: String,
+    pub artifacts: Vec<ArtifactInfo>,
+}
+
+/// Request for a chunk of an artifact (job.artifacts.read.v1)
+#[derive(Debug, Clone, Serialize)]
+pub struct ArtifactReadRequest {
+    pub job_id: String,
+    pub uri: String,
+    pub offset: u64,
+    /// Chunk size (daemon default 65536, max 1048576)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_bytes: Option<usize>,
+}
+
+/// Chunk of an artifact (job.artifacts.read.v1)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArtifactReadResponse {
+    pub job_id: String,
+    pub artifact: ArtifactInfo,
+    pub offset: u64,
+    pub len: usize,
+    /// Content, base64 (see [`content`](Self::content))
+    pub data: String,
+    /// No content follows this chunk
+    pub eof: bool,
+}
+
+impl ArtifactReadResponse {
+    /// Decoded chunk content
+    pub fn content(&self) -> crate::Result<Vec<u8>> {
+        BASE64_STANDARD
+            .decode(&self.data)
+            .map_err(|e| crate::SdkError::Other(format!("Invalid artifact content: {}", e)))
+    }
+}
+
+/// Request for a subject's result history (subject.history.v1)
+#[derive(Debug, Clone, Serialize)]
+pub struct SubjectHistoryRequest {
+    pub subject_key: String,
+    /// Entries to return (daemon default 10, max 1000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+/// One successful run of a subject
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubjectHistoryEntry {
+    pub generation: i64,
+    pub job_id: String,
+    /// SHA-256 of the job's stdout (hex), None if no output was captured
+    pub result_hash: Option<String>,
+    pub duration_ms: i64,
+    pub finished_at: i64,
+    /// Result differs from the previous run (None if unknown)
+    pub changed: Option<bool>,
+}
+
+/// Recent results of a subject, newest first
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubjectHistoryResponse {
+    pub subject_key: String,
+    pub entries: Vec<SubjectHistoryEntry>,
+}
+
+/// Request for dead-lettered jobs (dlq.list.v1)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DlqListRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+    /// Entries to return (daemon default 50, max 1000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+/// A job that failed for good, with its last error
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeadLetterInfo {
+    pub job_id: String,
+    pub queue: String,
+    pub job_type: String,
+    pub subject_key: String,
+    pub attempts: i32,
+    pub last_error: String,
+    pub dead_at: i64,
+}
+
+/// Dead-lettered jobs, newest first
+#[derive(Debug, Clone, Deserialize)]
+pub struct DlqListResponse {
+    pub dead_letters: Vec<DeadLetterInfo>,
+}
+
+/// Request to re-run dead-lettered jobs (dlq.requeue.v1)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DlqRequeueRequest {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub job_ids: Vec<String>,
+    /// Requeue every dead-lettered job (of `queue` if set)
+    pub all: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+}
+
+/// A job that could not be requeued
+#[derive(Debug, Clone, Deserialize)]
+pub struct DlqRequeueError {
+    pub job_id: String,
+    pub error: String,
+}
+
+/// Requeued jobs and the ones that were skipped
+#[derive(Debug, Clone, Deserialize)]
+pub struct DlqRequeueResponse {
+    pub requeued: Vec<String>,
+    pub errors: Vec<DlqRequeueError>,
+}
+
+/// Request to drop dead-letter entries (dlq.purge.v1)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DlqPurgeRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+    /// Only entries dead before this timestamp (ms)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<i64>,
+}
+
+/// Number of dropped entries
+#[derive(Debug, Clone, Deserialize)]
+pub struct DlqPurgeResponse {
+    pub purged: u64,
+}
+
+/// Progress report of a running job (job.report_progress.v1)
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportProgressRequest {
+    pub job_id: String,
+    /// 0-100
+    pub percent: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Response from a progress report
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReportProgressResponse {
+    pub job_id: String,
+    pub percent: u8,
+    pub updated_at: i64,
+}
+
+/// Last progress reported by a running job
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct JobProgress {
+    /// 0-100
+    pub percent: u8,
+    #[serde(default)]
+    pub message: Option<String>,
+    pub updated_at: i64,
+}
+
+/// Response from retry operation
+#[derive(Debug, Clone, Deserialize)]
+pub struct RetryResponse {
+    /// The requeued job, or the corrected copy
+    pub job_id: String,
+    /// New state (QUEUED)
+    pub state: String,
+    /// Original job of a corrected copy
+    #[serde(default)]
+    pub parent_job_id: Option<String>,
+}
+
+/// Request to inspect a job
+#[derive(Debug, Clone, Serialize)]
+pub struct InspectRequest {
+    pub job_id: String,
+    /// Also return the payload
+    pub include_payload: bool,
+}
+
+/// Job details with its event history
+#[derive(Debug, Clone, Deserialize)]
+pub struct InspectResponse {
+    pub job_id: String,
+    pub job_type: String,
+    pub queue: String,
+    pub subject_key: String,
+    pub generation: i64,
+    pub state: String,
+    pub priority: i32,
+    pub attempts: i32,
+    /// Executions that panicked (the job is quarantined at the daemon's limit)
+    #[serde(default)]
+    pub panic_count: i32,
+    /// QUEUED retry waiting out its backoff until then (epoch ms)
+    #[serde(default)]
+    pub next_attempt_at: Option<i64>,
+    pub created_at: i64,
+    pub started_at: Option<i64>,
+    pub finished_at: Option<i64>,
+    /// TASK or SERVICE
+    #[serde(default)]
+    pub job_class: String,
+    /// Fallback payloads declared at enqueue (retry N runs fallback N)
+    #[serde(default)]
+    pub fallback_payloads: usize,
+    /// Trace context supplied at enqueue
+    #[serde(default)]
+    pub traceparent: Option<String>,
+    /// Only returned by [`inspect_with_payload`](crate::SemanticaTaskClient::inspect_with_payload)
+    #[serde(default)]
+    pub payload: Option<serde_json::Value>,
+    /// Last progress reported while RUNNING
+    #[serde(default)]
+    pub progress: Option<JobProgress>,
+    /// Oldest first
+    pub events: Vec<JobEventInfo>,
+}
+
+/// Filter of a job listing (every field optional)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ListJobsRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+    /// QUEUED, RUNNING, DONE, FAILED, ...
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_key: Option<String>,
+    /// `next_cursor` of the previous page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// Page size (default 50, max 500)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+/// One page of jobs, oldest first
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListJobsResponse {
+    pub jobs: Vec<JobSummary>,
+    /// Cursor of the next page (None = last page)
+    pub next_cursor: Option<String>,
+}
+
+/// Job as listed by `list_jobs`
+#[derive(Debug, Clone, Deserialize)]
+pub struct JobSummary {
+    pub job_id: String,
+    pub job_type: String,
+    pub queue: String,
+    pub subject_key: String,
+    pub state: String,
+    pub priority: i32,
+    pub attempts: i32,
+    pub created_at: i64,
+    pub started_at: Option<i64>,
+    pub finished_at: Option<i64>,
+    #[serde(default)]
+    pub progress: Option<JobProgress>,
+}
+
+/// Request to wait for a job to finish
+#[derive(Debug, Clone, Serialize)]
+pub struct WaitRequest {
+    pub job_id: String,
+    pub timeout_ms: u64,
+}
+
+/// Job state after waiting
+#[derive(Debug, Clone, Deserialize)]
+pub struct WaitResponse {
+    pub job_id: String,
+    pub state: String,
+    /// false = timed out before the job finished
+    pub terminal: bool,
+    pub result_summary: Option<String>,
+    pub finished_at: Option<i64>,
+}
+
+/// One recorded state change (e.g. cancellation with actor and reason)
+#[derive(Debug, Clone, Deserialize)]
+pub struct JobEventInfo {
+    pub state: String,
+    pub actor: String,
+    pub reason: Option<String>,
+    pub created_at: i64,
 }
 
 /// Request to tail job logs
@@ -42,6 +502,9 @@ pub struct TailLogsRequest {
     pub job_id: String,
     #[serde(default = "default_lines")]
     pub lines: usize,
+    /// Only lines of this stream ("stdout" | "stderr"), None = both
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<String>,
 }
 
 #[allow(dead_code)] // Used by serde via #[serde(default)]
@@ -55,4 +518,418 @@ pub struct TailLogsResponse {
     pub job_id: String,
     pub log_path: Option<String>,
     pub lines: Vec<String>,
+    /// Tagged lines with stream and timestamp (empty for untagged logs)
+    #[serde(default)]
+    pub entries: Vec<LogEntry>,
+}
+
+/// One captured output line
+#[derive(Debug, Clone, Deserialize)]
+pub struct LogEntry {
+    pub ts: i64,
+    /// "stdout" | "stderr"
+    pub stream: String,
+    pub line: String,
+}
+
+/// Request to follow a job's log (logs.follow.v1)
+#[derive(Debug, Clone, Serialize)]
+pub struct FollowLogsRequest {
+    pub job_id: String,
+    /// Lines already written to send first
+    pub lines: usize,
+    /// Only lines of this stream ("stdout" | "stderr"), None = both
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<String>,
+}
+
+/// One event of a followed log
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum LogFollowEvent {
+    /// A line of the log (entry is None for untagged lines from older logs)
+    Line {
+        line: String,
+        #[serde(default)]
+        entry: Option<LogEntry>,
+    },
+    /// The job finished ("DONE", "FAILED", ... or "DELETED"), no more lines follow
+    End { state: String },
+}
+
+/// Request for throughput snapshots (admin.metrics.history.v1)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MetricsHistoryRequest {
+    /// Only snapshots taken after this time (epoch ms)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<i64>,
+    /// Newest snapshots to return (daemon default 60, max 10080)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+/// Throughput over one sampling window (one minute by default)
+#[derive(Debug, Clone, Deserialize)]
+pub struct MetricsSnapshotInfo {
+    /// End of the window (epoch ms)
+    pub taken_at: i64,
+    pub window_ms: i64,
+    /// Jobs that finished DONE / FAILED during the window
+    pub completed: i64,
+    pub failed: i64,
+    pub completed_per_minute: f64,
+    pub failed_per_minute: f64,
+    /// QUEUED / RUNNING jobs of every queue at `taken_at`
+    pub queued: i64,
+    pub running: i64,
+}
+
+/// Throughput snapshots, oldest first
+#[derive(Debug, Clone, Deserialize)]
+pub struct MetricsHistoryResponse {
+    pub snapshots: Vec<MetricsSnapshotInfo>,
+}
+
+/// System statistics (admin.stats.v1)
+#[derive(Debug, Clone, Deserialize)]
+pub struct StatsResponse {
+    pub total_jobs: i64,
+    pub queued_jobs: i64,
+    pub running_jobs: i64,
+    pub done_jobs: i64,
+    pub failed_jobs: i64,
+    pub expired_jobs: i64,
+    pub db_size_bytes: i64,
+    pub uptime_seconds: i64,
+    pub schema_version: i64,
+    pub engine_version: String,
+    /// Enabled features and configured limits
+    pub capabilities: serde_json::Value,
+    /// Worker execution slots, by index
+    #[serde(default)]
+    pub slots: Vec<WorkerSlot>,
+    /// Times the worker loop was restarted after terminating unexpectedly
+    #[serde(default)]
+    pub worker_restarts: u64,
+    /// Queued/running service jobs
+    #[serde(default)]
+    pub services: Vec<ServiceInfo>,
+    /// Queue wait SLO compliance over the last 24h
+    #[serde(default)]
+    pub wait_slos: Vec<WaitSloInfo>,
+    /// Per job type breakdown, by job type
+    #[serde(default)]
+    pub job_types: Vec<JobTypeStatsInfo>,
+}
+
+/// Counts, failure rate and mean run time of one job type
+#[derive(Debug, Clone, Deserialize)]
+pub struct JobTypeStatsInfo {
+    pub job_type: String,
+    pub queued: i64,
+    pub running: i64,
+    pub done: i64,
+    pub failed: i64,
+    /// Skipped by TTL or deadline
+    pub expired: i64,
+    /// FAILED / (DONE + FAILED), 0.0 when none finished
+    pub failure_rate: f64,
+    /// Mean run time of DONE and FAILED jobs
+    pub avg_duration_ms: Option<f64>,
+}
+
+/// Liveness/readiness of the daemon (health.check.v1)
+#[derive(Debug, Clone, Deserialize)]
+pub struct HealthCheckResponse {
+    /// "ok", "degraded" or "unavailable"
+    pub status: String,
+    /// Database reachable and worker loop not wedged
+    pub ready: bool,
+    pub uptime_seconds: i64,
+    pub database: DatabaseHealth,
+    /// None when the daemon runs no worker
+    pub worker: Option<WorkerHealth>,
+    pub maintenance: MaintenanceHealth,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DatabaseHealth {
+    pub reachable: bool,
+    pub latency_ms: i64,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkerHealth {
+    /// Executing a job, or the loop ticked recently
+    pub alive: bool,
+    pub busy: bool,
+    pub throttled: bool,
+    pub paused: bool,
+    pub last_tick_at: Option<i64>,
+    pub last_tick_age_ms: Option<i64>,
+    /// The worker loop terminated and waits to be restarted
+    #[serde(default)]
+    pub restarting: bool,
+    /// Times the worker loop was restarted after terminating unexpectedly
+    #[serde(default)]
+    pub restarts: u64,
+    #[serde(default)]
+    pub last_exit_at: Option<i64>,
+    /// Panic message or error of the latest termination
+    #[serde(default)]
+    pub last_exit_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MaintenanceHealth {
+    pub last_run_at: Option<i64>,
+    pub last_run_age_ms: Option<i64>,
+    pub last_run_ok: Option<bool>,
+    pub errors: Vec<String>,
+}
+
+/// Every queue present in the jobs table (admin.queues.list.v1)
+#[derive(Debug, Clone, Deserialize)]
+pub struct QueuesListResponse {
+    pub queues: Vec<QueueInfo>,
+}
+
+/// Job counts and health of one queue
+#[derive(Debug, Clone, Deserialize)]
+pub struct QueueInfo {
+    pub queue: String,
+    pub queued: i64,
+    pub running: i64,
+    pub done: i64,
+    pub failed: i64,
+    /// Wait of the oldest due QUEUED job
+    pub oldest_queued_age_ms: Option<i64>,
+    /// DONE + FAILED jobs finished in the last hour
+    pub throughput_last_hour: i64,
+    pub paused: bool,
+}
+
+/// Job occupying a worker execution slot
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkerSlot {
+    pub slot: usize,
+    /// None = idle
+    pub job_id: Option<String>,
+    pub job_type: Option<String>,
+    pub subject_key: Option<String>,
+    pub running_ms: Option<i64>,
+}
+
+/// Long-running service job (restarted whenever it exits)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServiceInfo {
+    pub job_id: String,
+    pub job_type: String,
+    pub subject_key: String,
+    pub state: String,
+    /// Consecutive quick exits
+    pub restarts: i32,
+    pub started_at: Option<i64>,
+    pub next_restart_at: Option<i64>,
+}
+
+/// How often a queue's jobs waited longer than its target to start
+#[derive(Debug, Clone, Deserialize)]
+pub struct WaitSloInfo {
+    pub queue: String,
+    pub target_ms: i64,
+    /// Jobs started in the window
+    pub started: i64,
+    /// Started jobs that waited longer than the target
+    pub violations: i64,
+    /// Queued jobs already past the target
+    pub waiting_over: i64,
+    /// Share of started jobs that met the target
+    pub met_ratio: f64,
+    pub max_wait_ms: Option<i64>,
+    /// Offending jobs, longest wait first
+    pub job_ids: Vec<String>,
+}
+
+/// Filter of the audit log (every field optional)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AuditListRequest {
+    /// e.g. "dev.cancel.v1"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caller: Option<String>,
+    /// Only calls at or after this time (epoch ms)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<i64>,
+    /// Number of entries (default 50, max 1000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+/// Audited calls, newest first
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuditListResponse {
+    pub entries: Vec<AuditEntry>,
+}
+
+/// One recorded mutating RPC call
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuditEntry {
+    pub timestamp: i64,
+    pub method: String,
+    /// `actor` / `worker_id` param of the call ("unknown" when absent)
+    pub caller: String,
+    pub job_id: Option<String>,
+    /// Params with payloads elided
+    pub params: String,
+    /// None = succeeded
+    pub error_code: Option<i32>,
+    pub latency_ms: i64,
+}
+
+/// Request for a job deleted by GC (admin.archive.get.v1)
+#[derive(Debug, Clone, Serialize)]
+pub struct ArchiveGetRequest {
+    pub job_id: String,
+}
+
+/// A job read back from the daemon's job archive
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArchiveGetResponse {
+    pub job_id: String,
+    pub archived_at: i64,
+    /// Segment file holding the job
+    pub segment: String,
+    /// Job row as it was when archived
+    pub job: serde_json::Value,
+    pub events: Vec<JobEventInfo>,
+}
+
+/// Request to run maintenance now (admin.maintenance.v1)
+#[derive(Debug, Clone, Serialize)]
+pub struct MaintenanceRequest {
+    pub force_vacuum: bool,
+    /// Only report what would be deleted
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub dry_run: bool,
+    /// Keep finished jobs this many days (daemon default: 30)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention_days: Option<i64>,
+}
+
+/// Outcome of a manual maintenance run
+#[derive(Debug, Clone, Deserialize)]
+pub struct MaintenanceResponse {
+    pub vacuum_run: bool,
+    /// Deleted, or that would be deleted on a dry run
+    pub jobs_deleted: i64,
+    pub artifacts_deleted: i64,
+    pub db_size_before: i64,
+    pub db_size_after: i64,
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Dry run only: what a run would delete
+    #[serde(default)]
+    pub preview: Option<GcPreview>,
+    /// Dry run only: space a run (plus VACUUM) would reclaim
+    #[serde(default)]
+    pub bytes_reclaimable: Option<u64>,
+}
+
+/// Jobs, log files and artifacts a maintenance run would delete
+#[derive(Debug, Clone, Deserialize)]
+pub struct GcPreview {
+    pub job_ids: Vec<String>,
+    pub log_files: Vec<String>,
+    pub artifacts: Vec<String>,
+    pub log_bytes: u64,
+    /// Approximate size of the deleted job rows
+    pub row_bytes: u64,
+}
+
+/// One job state transition (`jobs.state_changed` notification)
+#[derive(Debug, Clone, Deserialize)]
+pub struct JobStateChange {
+    /// "state" (a transition) or "progress" (a running job reported progress)
+    #[serde(default = "default_update_kind")]
+    pub kind: String,
+    pub job_id: String,
+    pub queue: String,
+    #[serde(default)]
+    pub job_type: String,
+    #[serde(default)]
+    pub subject_key: String,
+    #[serde(default)]
+    pub user_tag: Option<String>,
+    #[serde(default)]
+    pub chain_group_id: Option<String>,
+    /// None for a newly enqueued job
+    pub old_state: Option<String>,
+    pub new_state: String,
+    /// Reported progress (progress events only)
+    #[serde(default)]
+    pub progress: Option<JobProgress>,
+    pub timestamp: i64,
+}
+
+fn default_update_kind() -> String {
+    "state".to_string()
+}
+
+/// Server-side filter of a state subscription (jobs.subscribe.v1)
+///
+/// Unset fields match every job; all set fields must match.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SubscriptionFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_type: Option<String>,
+    /// Jobs whose subject_key starts with this prefix
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_prefix: Option<String>,
+    /// Jobs with this user_tag
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// Jobs of this chain (chain_group_id)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain: Option<String>,
+    /// Event kinds to deliver ("state" | "progress"), None = all
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kinds: Option<Vec<String>>,
+}
+
+/// Request to capture a queue snapshot (admin.snapshot.v1)
+#[derive(Debug, Clone, Serialize)]
+pub struct SnapshotRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+    /// Replace payloads and env_vars with hashes/sizes (shareable dump)
+    pub anonymize: bool,
+}
+
+/// Request to restore a snapshot (admin.restore.v1)
+#[derive(Debug, Clone, Serialize)]
+pub struct RestoreRequest {
+    /// Snapshot as returned by `snapshot` (or read back from a snapshot file)
+    pub snapshot: serde_json::Value,
+}
+
+/// Outcome of a snapshot restore
+#[derive(Debug, Clone, Deserialize)]
+pub struct RestoreResponse {
+    pub jobs_restored: usize,
+    pub subjects_restored: usize,
+}
+
+/// Request to change one runtime setting (admin.config.set.v1)
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigSetRequest {
+    /// Setting name (e.g. "cpu_throttle_percent")
+    pub key: String,
+    pub value: serde_json::Value,
 }